@@ -0,0 +1,200 @@
+//! spreadsheet compare library.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use helper::coordinate::*;
+use structs::DefinedName;
+use structs::Spreadsheet;
+use structs::Worksheet;
+
+/// A difference found by `diff`.
+/// Cell differences hold the sheet name and the coordinate of the cell. ex) `"A1"`
+/// Defined names scoped to a sheet are named `"Sheet1!name"`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difference {
+    SheetAdded {
+        sheet_name: String,
+    },
+    SheetRemoved {
+        sheet_name: String,
+    },
+    CellValueChanged {
+        sheet_name: String,
+        coordinate: String,
+        before: String,
+        after: String,
+    },
+    CellFormulaChanged {
+        sheet_name: String,
+        coordinate: String,
+        before: String,
+        after: String,
+    },
+    CellStyleChanged {
+        sheet_name: String,
+        coordinate: String,
+    },
+    DefinedNameAdded {
+        name: String,
+        address: String,
+    },
+    DefinedNameRemoved {
+        name: String,
+        address: String,
+    },
+    DefinedNameChanged {
+        name: String,
+        before: String,
+        after: String,
+    },
+}
+
+/// Compare two spreadsheets.
+/// Sheets are matched by name and cells by coordinate.
+/// All worksheets must be deserialized.
+/// # Arguments
+/// * `before` - Spreadsheet
+/// * `after` - Spreadsheet
+/// # Return value
+/// * `Vec<Difference>` - sheets first, then cells row by row, then defined names.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::compare::*;
+/// let book_a = umya_spreadsheet::new_file();
+/// let mut book_b = book_a.clone();
+/// book_b.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_value("new");
+///
+/// let differences = diff(&book_a, &book_b);
+/// assert_eq!(differences.len(), 1);
+/// ```
+pub fn diff(before: &Spreadsheet, after: &Spreadsheet) -> Vec<Difference> {
+    let mut result: Vec<Difference> = Vec::new();
+
+    for worksheet in before.get_sheet_collection() {
+        if after.get_sheet_by_name(worksheet.get_name()).is_none() {
+            result.push(Difference::SheetRemoved {
+                sheet_name: worksheet.get_name().to_string(),
+            });
+        }
+    }
+    for worksheet in after.get_sheet_collection() {
+        if before.get_sheet_by_name(worksheet.get_name()).is_none() {
+            result.push(Difference::SheetAdded {
+                sheet_name: worksheet.get_name().to_string(),
+            });
+        }
+    }
+
+    for worksheet in before.get_sheet_collection() {
+        if let Some(v) = after.get_sheet_by_name(worksheet.get_name()) {
+            diff_worksheet(worksheet, v, &mut result);
+        }
+    }
+
+    let names_before = get_defined_name_map(before);
+    let names_after = get_defined_name_map(after);
+    for (name, address) in &names_before {
+        match names_after.get(name) {
+            Some(v) if v != address => result.push(Difference::DefinedNameChanged {
+                name: name.clone(),
+                before: address.clone(),
+                after: v.clone(),
+            }),
+            Some(_) => {}
+            None => result.push(Difference::DefinedNameRemoved {
+                name: name.clone(),
+                address: address.clone(),
+            }),
+        }
+    }
+    for (name, address) in &names_after {
+        if !names_before.contains_key(name) {
+            result.push(Difference::DefinedNameAdded {
+                name: name.clone(),
+                address: address.clone(),
+            });
+        }
+    }
+
+    result
+}
+
+fn diff_worksheet(before: &Worksheet, after: &Worksheet, result: &mut Vec<Difference>) {
+    let sheet_name = before.get_name();
+
+    // (row, col) of every cell in either sheet.
+    let mut coordinate_list: BTreeSet<(u32, u32)> = BTreeSet::new();
+    for cell in before
+        .get_cell_collection()
+        .into_iter()
+        .chain(after.get_cell_collection())
+    {
+        let coordinate = cell.get_coordinate();
+        coordinate_list.insert((*coordinate.get_row_num(), *coordinate.get_col_num()));
+    }
+
+    for (row, col) in coordinate_list {
+        let cell_before = before.get_cell((col, row));
+        let cell_after = after.get_cell((col, row));
+        let coordinate = coordinate_from_index(&col, &row);
+
+        let formula_before = cell_before.map_or("", |v| v.get_formula());
+        let formula_after = cell_after.map_or("", |v| v.get_formula());
+        if formula_before != formula_after {
+            result.push(Difference::CellFormulaChanged {
+                sheet_name: sheet_name.to_string(),
+                coordinate: coordinate.clone(),
+                before: formula_before.to_string(),
+                after: formula_after.to_string(),
+            });
+        }
+
+        let value_before = cell_before.map_or(String::new(), |v| v.get_value().into());
+        let value_after = cell_after.map_or(String::new(), |v| v.get_value().into());
+        if value_before != value_after {
+            result.push(Difference::CellValueChanged {
+                sheet_name: sheet_name.to_string(),
+                coordinate: coordinate.clone(),
+                before: value_before,
+                after: value_after,
+            });
+        }
+
+        if before.get_style((col, row)) != after.get_style((col, row)) {
+            result.push(Difference::CellStyleChanged {
+                sheet_name: sheet_name.to_string(),
+                coordinate,
+            });
+        }
+    }
+}
+
+// Defined names keyed by name, prefixed with the sheet name when scoped to a sheet.
+fn get_defined_name_map(spreadsheet: &Spreadsheet) -> BTreeMap<String, String> {
+    let worksheets = spreadsheet.get_sheet_collection();
+    let mut result: BTreeMap<String, String> = BTreeMap::new();
+    let mut insert = |scope: Option<&str>, defined_name: &DefinedName| {
+        let name = match scope {
+            Some(v) => format!("{}!{}", v, defined_name.get_name()),
+            None => defined_name.get_name().to_string(),
+        };
+        result.insert(name, defined_name.get_address());
+    };
+    for defined_name in spreadsheet.get_defined_names() {
+        let scope = if defined_name.has_local_sheet_id() {
+            worksheets
+                .get(*defined_name.get_local_sheet_id() as usize)
+                .map(|v| v.get_name())
+        } else {
+            None
+        };
+        insert(scope, defined_name);
+    }
+    for worksheet in worksheets {
+        for defined_name in worksheet.get_defined_names() {
+            insert(Some(worksheet.get_name()), defined_name);
+        }
+    }
+    result
+}
@@ -7,7 +7,11 @@ pub mod date;
 pub mod formula;
 pub mod html;
 pub mod number_format;
+pub mod ole;
 pub mod range;
+pub mod sanitize;
 pub mod string_helper;
 pub mod time_zone;
+pub(crate) mod trace;
 pub mod utils;
+pub mod value_detect;
@@ -7,6 +7,8 @@ pub(crate) const COMMENTS_NS: &str =
 pub(crate) const CONTYPES_NS: &str = "http://schemas.openxmlformats.org/package/2006/content-types";
 pub(crate) const COREPROPS_NS: &str =
     "http://schemas.openxmlformats.org/package/2006/metadata/core-properties";
+pub(crate) const CTRL_PROP_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/ctrlProp";
 pub(crate) const CUSTOMUI_NS: &str =
     "http://schemas.microsoft.com/office/2006/relationships/ui/extensibility";
 pub(crate) const DCMITYPE_NS: &str = "http://purl.org/dc/dcmitype/";
@@ -40,6 +42,8 @@ pub(crate) const PACKAGE_NS: &str =
 pub(crate) const REL_NS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
 pub(crate) const REL_OFC_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+pub(crate) const STRICT_REL_OFC_NS: &str =
+    "http://purl.oclc.org/ooxml/officeDocument/relationships";
 pub(crate) const PRINTER_SETTINGS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/printerSettings";
 pub(crate) const PIVOT_CACHE_DEF_NS: &str =
@@ -48,13 +52,64 @@ pub(crate) const STYLES_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles";
 pub(crate) const SHARED_STRINGS_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/sharedStrings";
+pub(crate) const SHEET_METADATA_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/sheetMetadata";
+pub(crate) const RICH_VALUE_REL_NS: &str =
+    "http://schemas.microsoft.com/office/2022/10/relationships/richValueRel";
+pub(crate) const RD_RICH_VALUE_NS: &str =
+    "http://schemas.microsoft.com/office/2017/06/relationships/rdRichValue";
+pub(crate) const RD_RICH_VALUE_STRUCTURE_NS: &str =
+    "http://schemas.microsoft.com/office/2017/06/relationships/rdRichValueStructure";
+pub(crate) const RD_RICH_VALUE_TYPES_NS: &str =
+    "http://schemas.microsoft.com/office/2017/06/relationships/rdRichValueTypes";
+pub(crate) const RD_ARRAY_NS: &str =
+    "http://schemas.microsoft.com/office/2017/06/relationships/rdArray";
+pub(crate) const RD_SUPPORTING_PROPERTY_BAG_NS: &str =
+    "http://schemas.microsoft.com/office/2017/06/relationships/rdSupportingPropertyBag";
+pub(crate) const RD_SUPPORTING_PROPERTY_BAG_STRUCTURE_NS: &str =
+    "http://schemas.microsoft.com/office/2017/06/relationships/rdSupportingPropertyBagStructure";
+pub(crate) const RICH_DATA_NS_LIST: [&str; 8] = [
+    SHEET_METADATA_NS,
+    RICH_VALUE_REL_NS,
+    RD_RICH_VALUE_NS,
+    RD_RICH_VALUE_STRUCTURE_NS,
+    RD_RICH_VALUE_TYPES_NS,
+    RD_ARRAY_NS,
+    RD_SUPPORTING_PROPERTY_BAG_NS,
+    RD_SUPPORTING_PROPERTY_BAG_STRUCTURE_NS,
+];
+/// Workbook relationships that are read into the object model (or dropped, like calcChain).
+pub(crate) const WORKBOOK_MODELED_NS_LIST: [&str; 7] = [
+    WORKSHEET_NS,
+    THEME_NS,
+    STYLES_NS,
+    SHARED_STRINGS_NS,
+    VBA_PROJECT_NS,
+    CONNECTIONS_NS,
+    CALC_CHAIN_NS,
+];
+/// Package relationships that are read into the object model.
+pub(crate) const ROOT_MODELED_NS_LIST: [&str; 4] =
+    [OFCDOC_NS, XPROPS_REL, COREPROPS_REL, CUSTOM_PROPS_REL];
 pub(crate) const SHEET_DRAWING_NS: &str =
     "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing";
 pub(crate) const SHEET_MAIN_NS: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+pub(crate) const RICH_DATA_NS: &str =
+    "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata";
+pub(crate) const RICH_DATA2_NS: &str =
+    "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata2";
+pub(crate) const RICH_VALUE_REL_MAIN_NS: &str =
+    "http://schemas.microsoft.com/office/spreadsheetml/2022/richvaluerel";
 pub(crate) const SHEET_MS_MAIN_NS: &str =
     "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main";
 pub(crate) const SHEETML_AC_NS: &str =
     "http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac";
+pub(crate) const CALC_CHAIN_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/calcChain";
+pub(crate) const CONNECTIONS_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/connections";
+pub(crate) const QUERY_TABLE_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/queryTable";
 pub(crate) const TABLE_NS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/table";
 pub(crate) const THEME_NS: &str =
@@ -81,12 +136,24 @@ pub(crate) const CUSTOM_PROPS_REL: &str =
 pub(crate) const COREPROPS_REL: &str =
     "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties";
 
+pub(crate) const CALC_CHAIN_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.calcChain+xml";
 pub(crate) const CHART_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
+pub(crate) const CHART_SHAPES_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml";
 pub(crate) const COMMENTS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml";
 pub(crate) const CORE_PROPS_TYPE: &str =
     "application/vnd.openxmlformats-package.core-properties+xml";
+pub(crate) const CTRL_PROP_TYPE: &str = "application/vnd.ms-excel.controlproperties+xml";
+pub(crate) const SHEET_METADATA_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata+xml";
+pub(crate) const RD_RICH_VALUE_TYPE: &str = "application/vnd.ms-excel.rdrichvalue+xml";
+pub(crate) const RD_RICH_VALUE_STRUCTURE_TYPE: &str =
+    "application/vnd.ms-excel.rdrichvaluestructure+xml";
+pub(crate) const RD_RICH_VALUE_TYPES_TYPE: &str = "application/vnd.ms-excel.rdrichvaluetypes+xml";
+pub(crate) const RICH_VALUE_REL_TYPE: &str = "application/vnd.ms-excel.richvaluerel+xml";
 pub(crate) const DRAWING_TYPE: &str = "application/vnd.openxmlformats-officedocument.drawing+xml";
 pub(crate) const OLE_OBJECT_TYPE: &str = "application/vnd.openxmlformats-officedocument.oleObject";
 pub(crate) const PRNTR_SETTINGS_TYPE: &str =
@@ -99,6 +166,10 @@ pub(crate) const SHEET_TYPE: &str =
 pub(crate) const STYLES_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml";
 pub(crate) const THEME_TYPE: &str = "application/vnd.openxmlformats-officedocument.theme+xml";
+pub(crate) const CONNECTIONS_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml";
+pub(crate) const QUERY_TABLE_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.queryTable+xml";
 pub(crate) const TABLE_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml";
 pub(crate) const VBA_TYPE: &str = "application/vnd.ms-office.vbaProject";
@@ -107,6 +178,10 @@ pub(crate) const VML_DRAWING_TYPE: &str =
 pub(crate) const WORKBOOK: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
 pub(crate) const WORKBOOK_MACRO_TYPE: &str = "application/vnd.ms-excel.sheet.macroEnabled.main+xml";
+pub(crate) const WORKBOOK_MACRO_TEMPLATE_TYPE: &str =
+    "application/vnd.ms-excel.template.macroEnabled.main+xml";
+pub(crate) const WORKBOOK_TEMPLATE_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml";
 pub(crate) const WORKBOOK_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml";
 pub(crate) const XPROPS_TYPE: &str =
@@ -115,24 +190,38 @@ pub(crate) const CUSTOM_PROPS_TYPE: &str =
     "application/vnd.openxmlformats-officedocument.custom-properties+xml";
 
 pub(crate) const PKG_CHARTS: &str = "xl/charts";
+pub(crate) const PKG_CTRL_PROPS: &str = "xl/ctrlProps";
 pub(crate) const PKG_DRAWINGS: &str = "xl/drawings";
 pub(crate) const PKG_DRAWINGS_RELS: &str = "xl/drawings/_rels/drawing";
 pub(crate) const PKG_EMBEDDINGS: &str = "xl/embeddings";
 pub(crate) const PKG_MEDIA: &str = "xl/media";
+pub(crate) const PKG_METADATA: &str = "xl/metadata.xml";
+pub(crate) const PKG_RD_RICH_VALUE: &str = "xl/richData/rdrichvalue.xml";
+pub(crate) const PKG_RD_RICH_VALUE_STRUCTURE: &str = "xl/richData/rdrichvaluestructure.xml";
+pub(crate) const PKG_RD_RICH_VALUE_TYPES: &str = "xl/richData/rdRichValueTypes.xml";
+pub(crate) const PKG_RICH_VALUE_REL: &str = "xl/richData/richValueRel.xml";
+pub(crate) const PKG_RICH_VALUE_REL_RELS: &str = "xl/richData/_rels/richValueRel.xml.rels";
+pub(crate) const PKG_CALC_CHAIN: &str = "xl/calcChain.xml";
 pub(crate) const PKG_PRNTR_SETTINGS: &str = "xl/printerSettings";
 pub(crate) const PKG_SHARED_STRINGS: &str = "xl/sharedStrings.xml";
 pub(crate) const PKG_SHEET: &str = "xl/worksheets/sheet";
 pub(crate) const PKG_SHEET_RELS: &str = "xl/worksheets/_rels/sheet";
 pub(crate) const PKG_STYLES: &str = "xl/styles.xml";
 pub(crate) const PKG_TABLES: &str = "xl/tables";
+pub(crate) const PKG_QUERY_TABLES: &str = "xl/queryTables";
 pub(crate) const PKG_THEME: &str = "xl/theme/theme1.xml";
 pub(crate) const PKG_WORKBOOK: &str = "xl/workbook.xml";
 pub(crate) const PKG_WORKBOOK_RELS: &str = "xl/_rels/workbook.xml.rels";
 pub(crate) const PKG_VBA_PROJECT: &str = "xl/vbaProject.bin";
 pub(crate) const PKG_VML_DRAWING_RELS: &str = "xl/drawings/_rels/vmlDrawing";
 
+pub(crate) const ARC_RELS: &str = "_rels/.rels";
 pub(crate) const ARC_APP: &str = "docProps/app.xml";
 pub(crate) const ARC_CORE: &str = "docProps/core.xml";
 pub(crate) const ARC_CUSTOM: &str = "docProps/custom.xml";
 
 pub(crate) const CONTENT_TYPES: &str = "[Content_Types].xml";
+/// Extensions written as `Default` in [Content_Types].xml.
+pub(crate) const DEFAULT_EXTENSIONS: [&str; 12] = [
+    "rels", "xml", "bin", "vml", "png", "jpg", "jpeg", "gif", "bmp", "tiff", "emf", "xlsx",
+];
@@ -42,6 +42,16 @@ where
         .sum::<u32>()
 }
 
+/// Convert a column letter to its index, starting at `1`.
+/// # Arguments
+/// * `column` - Column letter in upper case. ex) `"AB"`
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::coordinate::column_index_from_string;
+/// assert_eq!(column_index_from_string("A"), 1);
+/// assert_eq!(column_index_from_string("AB"), 28);
+/// assert_eq!(column_index_from_string("XFD"), 16384);
+/// ```
 pub fn column_index_from_string<S: AsRef<str>>(column: S) -> u32 {
     let column_c = column.as_ref();
     if column_c == "0" {
@@ -51,6 +61,17 @@ pub fn column_index_from_string<S: AsRef<str>>(column: S) -> u32 {
     alpha_to_index(column_c)
 }
 
+/// Convert a column index to its letter.
+/// # Arguments
+/// * `column_index` - Column index, starting at `1`.
+/// # Panics
+/// Panics if `column_index` is `0`.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::coordinate::string_from_column_index;
+/// assert_eq!(string_from_column_index(&1), "A");
+/// assert_eq!(string_from_column_index(&28), "AB");
+/// ```
 pub fn string_from_column_index(column_index: &u32) -> String {
     assert!(column_index >= &1u32, "Column number starts from 1.");
 
@@ -91,6 +112,12 @@ where
     .unwrap_or_default()
 }
 
+/// Convert `(col, row)` indexes to a cell reference.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::coordinate::coordinate_from_index;
+/// assert_eq!(coordinate_from_index(&28, &12), "AB12");
+/// ```
 pub fn coordinate_from_index(col: &u32, row: &u32) -> String {
     format!("{}{}", string_from_column_index(col), row)
 }
@@ -133,6 +160,18 @@ pub(crate) fn is_remove_coordinate(num: &u32, root_num: &u32, offset_num: &u32)
     false
 }
 
+// A coordinate inside the removed range moves to the start of the range.
+pub(crate) fn adjustment_remove_coordinate_clamp(
+    num: &u32,
+    root_num: &u32,
+    offset_num: &u32,
+) -> u32 {
+    if is_remove_coordinate(num, root_num, offset_num) {
+        return *root_num;
+    }
+    adjustment_remove_coordinate(num, root_num, offset_num)
+}
+
 pub type CellIndex = (Option<u32>, Option<u32>, Option<bool>, Option<bool>);
 
 /// Struct for representing cell coordinates with row and column numbers
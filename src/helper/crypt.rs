@@ -6,7 +6,7 @@ use cfb;
 use hmac::{Hmac, Mac};
 use quick_xml::events::{BytesDecl, Event};
 use quick_xml::Writer;
-use sha2::{Digest, Sha512};
+use sha2::{Digest, Sha256, Sha384, Sha512};
 use std::cmp::Ordering;
 use std::io;
 use std::io::Write;
@@ -26,6 +26,8 @@ const BLOCK_KEYS_DATA_INTEGRITY_HMAC_VALUE: &[u8] =
 const BLOCK_KEYS_KEY: &[u8] = &[0x14, 0x6e, 0x0b, 0xe7, 0xab, 0xac, 0xd0, 0xd6];
 const BLOCK_VERIFIER_HASH_INPUT: &[u8] = &[0xfe, 0xa7, 0xd2, 0x76, 0x3b, 0x4b, 0x9e, 0x79];
 const BLOCK_VERIFIER_HASH_VALUE: &[u8] = &[0xd7, 0xaa, 0x0f, 0x6d, 0x30, 0x61, 0x34, 0x4e];
+// The spin count is read from the file, so it is capped to keep a check from running for hours.
+const MAX_SPIN_COUNT: u32 = 10_000_000;
 
 pub fn encrypt_sheet_protection(password: &str, sheet_protection: &mut SheetProtection) {
     let key_salt_value = gen_random_16();
@@ -37,7 +39,8 @@ pub fn encrypt_sheet_protection(password: &str, sheet_protection: &mut SheetProt
         key_hash_algorithm,
         &key_salt_value,
         &key_spin_count,
-    );
+    )
+    .unwrap();
 
     let salt_value_str = STANDARD.encode(key_salt_value);
     let hash_value_str = STANDARD.encode(key);
@@ -59,7 +62,8 @@ pub fn encrypt_workbook_protection(password: &str, workbook_protection: &mut Wor
         key_hash_algorithm,
         &key_salt_value,
         &key_spin_count,
-    );
+    )
+    .unwrap();
 
     let salt_value_str = STANDARD.encode(key_salt_value);
     let hash_value_str = STANDARD.encode(key);
@@ -81,7 +85,8 @@ pub fn encrypt_revisions_protection(password: &str, workbook_protection: &mut Wo
         key_hash_algorithm,
         &key_salt_value,
         &key_spin_count,
-    );
+    )
+    .unwrap();
 
     let salt_value_str = STANDARD.encode(key_salt_value);
     let hash_value_str = STANDARD.encode(key);
@@ -93,6 +98,94 @@ pub fn encrypt_revisions_protection(password: &str, workbook_protection: &mut Wo
     workbook_protection.remove_revisions_password_raw();
 }
 
+/// Compute the hash of a password with a salt and a spin count,
+/// as stored in `hashValue` of sheet and workbook protection.
+/// # Arguments
+/// * `password` - Password.
+/// * `algorithm_name` - ex) `"SHA-512"`
+/// * `salt_value` - Salt encoded in base64.
+/// * `spin_count` - Number of hash iterations.
+/// # Return value
+/// * `Option<String>` - Hash encoded in base64. None if the algorithm or salt is not supported,
+/// or the spin count is above 10,000,000.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::crypt::*;
+/// let hash = get_password_hash("password", "SHA-512", "qkPhpbpxZX1dG0GjeHVn5w==", 1000).unwrap();
+/// assert!(verify_password_hash("password", "SHA-512", &hash, "qkPhpbpxZX1dG0GjeHVn5w==", 1000));
+/// ```
+pub fn get_password_hash(
+    password: &str,
+    algorithm_name: &str,
+    salt_value: &str,
+    spin_count: u32,
+) -> Option<String> {
+    if spin_count > MAX_SPIN_COUNT {
+        return None;
+    }
+    let salt_value = STANDARD.decode(salt_value).ok()?;
+    let key = convert_password_to_hash(
+        password,
+        algorithm_name,
+        &salt_value,
+        &(spin_count as usize),
+    )
+    .ok()?;
+    Some(STANDARD.encode(key))
+}
+
+/// Check a password against a hash made with a salt and a spin count.
+/// # Arguments
+/// * `password` - Password to check.
+/// * `algorithm_name` - ex) `"SHA-512"`
+/// * `hash_value` - Hash encoded in base64.
+/// * `salt_value` - Salt encoded in base64.
+/// * `spin_count` - Number of hash iterations.
+pub fn verify_password_hash(
+    password: &str,
+    algorithm_name: &str,
+    hash_value: &str,
+    salt_value: &str,
+    spin_count: u32,
+) -> bool {
+    get_password_hash(password, algorithm_name, salt_value, spin_count)
+        .map_or(false, |v| v == hash_value)
+}
+
+/// Compute the legacy 16 bit hash of a password, as stored in the `password` attribute of protection.
+/// Only the low byte of each character is used, like Excel does for single byte passwords.
+/// # Arguments
+/// * `password` - Password.
+/// # Return value
+/// * `String` - Hash in hexadecimal. ex) `"CBEB"`
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::crypt::*;
+/// assert_eq!(get_legacy_password_hash("test"), "CBEB");
+/// assert!(verify_legacy_password_hash("test", "cbeb"));
+/// ```
+pub fn get_legacy_password_hash(password: &str) -> String {
+    let bytes: Vec<u16> = password.encode_utf16().map(|v| v & 0xff).collect();
+    let mut verifier: u16 = 0;
+    for byte in bytes.iter().rev().chain([bytes.len() as u16].iter()) {
+        verifier = ((verifier >> 14) & 0x01) | ((verifier << 1) & 0x7fff);
+        verifier ^= byte;
+    }
+    verifier ^= 0xce4b;
+    format!("{:04X}", verifier)
+}
+
+/// Check a password against a legacy 16 bit hash.
+/// # Arguments
+/// * `password` - Password to check.
+/// * `hash_value` - Hash in hexadecimal.
+pub fn verify_legacy_password_hash(password: &str, hash_value: &str) -> bool {
+    match u16::from_str_radix(hash_value, 16) {
+        Ok(v) => format!("{:04X}", v) == get_legacy_password_hash(password),
+        Err(_) => false,
+    }
+}
+
 pub fn encrypt<P: AsRef<Path>>(filepath: &P, data: &[u8], password: &str) {
     // package params
     let package_key = gen_random_32();
@@ -443,7 +536,7 @@ fn convert_password_to_hash(
     hash_algorithm: &str,
     salt_value: &[u8],
     spin_count: &usize,
-) -> Vec<u8> {
+) -> Result<Vec<u8>, String> {
     // Password must be in unicode buffer
     let mut password_buffer: Vec<u8> = Vec::new();
     let v: Vec<u16> = password.encode_utf16().collect();
@@ -454,27 +547,26 @@ fn convert_password_to_hash(
     }
 
     // Generate the initial hash
-    let mut key = hash(hash_algorithm, vec![salt_value, &password_buffer]).unwrap();
+    let mut key = hash(hash_algorithm, vec![salt_value, &password_buffer])?;
 
     // Now regenerate until spin count
     for i in 0..*spin_count {
         let iterator = create_uint32_le_buffer(&(i as u32), None);
-        key = hash(hash_algorithm, vec![&key, &iterator]).unwrap();
+        key = hash(hash_algorithm, vec![&key, &iterator])?;
     }
 
-    key
+    Ok(key)
 }
 
 // Calculate a hash of the concatenated buffers with the given algorithm.
 fn hash(algorithm: &str, buffers: Vec<&[u8]>) -> Result<Vec<u8>, String> {
-    let mut digest = match algorithm {
-        "SHA512" | "SHA-512" => Sha512::new(),
-        _ => {
-            return Err(format!("algorithm {} not supported!", algorithm));
-        }
-    };
-    digest.update(&buffer_concat(buffers)[..]);
-    Ok(digest.finalize().to_vec())
+    let data = buffer_concat(buffers);
+    match algorithm {
+        "SHA512" | "SHA-512" => Ok(Sha512::digest(&data[..]).to_vec()),
+        "SHA384" | "SHA-384" => Ok(Sha384::digest(&data[..]).to_vec()),
+        "SHA256" | "SHA-256" => Ok(Sha256::digest(&data[..]).to_vec()),
+        _ => Err(format!("algorithm {} not supported!", algorithm)),
+    }
 }
 
 fn gen_random_16() -> Vec<u8> {
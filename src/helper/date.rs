@@ -1,4 +1,4 @@
-use chrono::{Duration, NaiveDateTime};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 
 pub const CALENDAR_WINDOWS_1900: &str = "1900";
 pub const CALENDAR_MAC_1904: &str = "1904";
@@ -54,6 +54,55 @@ pub fn convert_date(
     convert_date_windows_1900(year, month, day, hours, minutes, seconds)
 }
 
+/// Convert an ISO 8601 date (`t="d"` cell of the strict variant) into an excel serial date.
+/// ex) `"2024-01-31"`, `"2024-01-31T12:30:00"`
+pub(crate) fn convert_iso_8601(value: &str) -> Option<f64> {
+    let value = value.trim_end_matches('Z');
+    let date_time = match NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f") {
+        Ok(v) => v,
+        Err(_) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?,
+    };
+    Some(convert_naive_date_time(&date_time))
+}
+
+/// Convert a date into an excel serial date.
+/// # Arguments
+/// * `value` - ex) 2024-01-31 becomes `45322.0`
+pub fn convert_naive_date(value: &NaiveDate) -> f64 {
+    convert_date(
+        value.year(),
+        value.month() as i32,
+        value.day() as i32,
+        0,
+        0,
+        0,
+    )
+}
+
+/// Convert a time of day into a fraction of a day.
+/// # Arguments
+/// * `value` - ex) 12:00:00 becomes `0.5`
+pub fn convert_naive_time(value: &NaiveTime) -> f64 {
+    (value.num_seconds_from_midnight() as f64 + value.nanosecond() as f64 / 1_000_000_000f64)
+        / 86400f64
+}
+
+/// Convert a date and time into an excel serial date.
+/// # Arguments
+/// * `value` - ex) 2024-01-31 12:00:00 becomes `45322.5`
+pub fn convert_naive_date_time(value: &NaiveDateTime) -> f64 {
+    convert_naive_date(&value.date()) + convert_naive_time(&value.time())
+}
+
+/// Convert a duration into a number of days.
+/// # Arguments
+/// * `value` - ex) 36 hours become `1.5`
+pub fn convert_duration(value: &Duration) -> f64 {
+    value.num_milliseconds() as f64 / 86_400_000f64
+}
+
 pub fn convert_date_windows_1900(
     year: i32,
     month: i32,
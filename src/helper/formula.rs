@@ -3,6 +3,7 @@ use helper::address::*;
 use helper::coordinate::*;
 use helper::coordinate::*;
 use helper::range::*;
+use structs::Locale;
 use structs::StringValue;
 
 /** PARTLY BASED ON: */
@@ -107,7 +108,7 @@ pub const ERRORS: &'static [&'static str] = &[
 const COMPARATORS_MULTI: &'static [&'static str] = &[">=", "<=", "<>"];
 
 lazy_static! {
-    pub static ref SCIENTIFIC_REGEX: Regex = Regex::new(r#"/^[1-9]{1}(\\.\\d+)?E{1}$/"#).unwrap();
+    pub static ref SCIENTIFIC_REGEX: Regex = Regex::new(r#"^[0-9.]+E$"#).unwrap();
 }
 
 pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken> {
@@ -169,9 +170,10 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 if ((index + 2) <= formula_length)
                     && (formula.chars().nth(index + 1).unwrap() == self::QUOTE_SINGLE)
                 {
-                    value = format!("{}{}", value, self::QUOTE_SINGLE);
+                    value = format!("{}{}{}", value, self::QUOTE_SINGLE, self::QUOTE_SINGLE);
                     index += 1;
                 } else {
+                    value = format!("{}{}", value, self::QUOTE_SINGLE);
                     in_path = false;
                 }
             } else {
@@ -190,7 +192,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 in_range = false;
             }
             value = format!("{}{}", value, formula.chars().nth(index).unwrap());
-            index;
+            index += 1;
 
             continue;
         }
@@ -216,10 +218,7 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
         // scientific notation check
         if self::OPERATORS_SN.contains(formula.chars().nth(index).unwrap()) {
             if value.len() > 1 {
-                if !SCIENTIFIC_REGEX
-                    .is_match(&formula.chars().nth(index).unwrap().to_string())
-                    .unwrap_or(false)
-                {
+                if SCIENTIFIC_REGEX.is_match(&value).unwrap_or(false) {
                     value = format!("{}{}", value, formula.chars().nth(index).unwrap());
                     index += 1;
 
@@ -255,7 +254,8 @@ pub(crate) fn parse_to_tokens<S: Into<String>>(formula: S) -> Vec<FormulaToken>
                 tokens1.push(obj);
                 value = String::from("");
             }
-            in_string = true;
+            in_path = true;
+            value = format!("{}{}", value, self::QUOTE_SINGLE);
             index += 1;
 
             continue;
@@ -873,6 +873,205 @@ pub fn adjustment_remove_formula_coordinate(
     render(token_list.as_ref())
 }
 
+/// Replace the references to `sheet_name` with `#REF!`.
+/// Returns `None` if the formula does not reference the sheet.
+pub(crate) fn remove_sheet_reference_formula(formula: &str, sheet_name: &str) -> Option<String> {
+    let mut token_list = parse_to_tokens(format!("={}", formula));
+    let mut is_changed = false;
+    for token in &mut token_list {
+        if token.get_token_type() == &FormulaTokenTypes::Operand
+            && token.get_token_sub_type() == &FormulaTokenSubTypes::Range
+        {
+            let (name, _) = split_address(token.get_value());
+            if name.eq_ignore_ascii_case(sheet_name) {
+                token.set_value("#REF!");
+                is_changed = true;
+            }
+        }
+    }
+    is_changed.then(|| render(&token_list))
+}
+
+/// Get the cell references of a formula as `(sheet_name, range)`.
+/// `sheet_name` is empty for references to the formula's own sheet.
+/// Defined names and references to other workbooks are not included.
+pub(crate) fn get_formula_references(formula: &str) -> Vec<(String, String)> {
+    lazy_static! {
+        static ref RANGE_REGEX: Regex = Regex::new(
+            r"^(\$?[A-Z]{1,3}\$?[0-9]+(:\$?[A-Z]{1,3}\$?[0-9]+)?|\$?[A-Z]{1,3}:\$?[A-Z]{1,3}|\$?[0-9]+:\$?[0-9]+)$"
+        )
+        .unwrap();
+    }
+    let mut result: Vec<(String, String)> = Vec::new();
+    for token in parse_to_tokens(format!("={}", formula)) {
+        if token.get_token_type() != &FormulaTokenTypes::Operand
+            || token.get_token_sub_type() != &FormulaTokenSubTypes::Range
+        {
+            continue;
+        }
+        let (sheet_name, range) = split_address(token.get_value());
+        if sheet_name.starts_with('[') {
+            continue;
+        }
+        let range = range.to_uppercase();
+        if RANGE_REGEX.is_match(&range).unwrap_or(false) {
+            result.push((sheet_name.to_string(), range));
+        }
+    }
+    result
+}
+
+/// Returns `true` if `(col, row)` is within `range`. ex) `"A1:B2"`, `"A:A"`, `"2:5"`
+pub(crate) fn is_in_range(range: &str, col: &u32, row: &u32) -> bool {
+    let (row_start, row_end, col_start, col_end) = get_start_and_end_point(range);
+    let is_in = |value: &u32, start: u32, end: u32| {
+        start == 0 || (start.min(end)..=start.max(end)).contains(value)
+    };
+    is_in(row, row_start, row_end) && is_in(col, col_start, col_end)
+}
+
+/// Point the references to `sheet_name` at `new_sheet_name`.
+/// Returns `None` if the formula does not reference the sheet.
+pub(crate) fn rename_sheet_reference_formula(
+    formula: &str,
+    sheet_name: &str,
+    new_sheet_name: &str,
+) -> Option<String> {
+    let mut token_list = parse_to_tokens(format!("={}", formula));
+    let mut is_changed = false;
+    for token in &mut token_list {
+        if token.get_token_type() == &FormulaTokenTypes::Operand
+            && token.get_token_sub_type() == &FormulaTokenSubTypes::Range
+        {
+            let (name, range) = split_address(token.get_value());
+            if name.eq_ignore_ascii_case(sheet_name) {
+                let value = format!("{}!{}", quote_sheet_name(new_sheet_name), range);
+                token.set_value(value);
+                is_changed = true;
+            }
+        }
+    }
+    is_changed.then(|| render(&token_list))
+}
+
+// Quote a sheet name for use in a formula when needed. ex) `'My Sheet'`
+fn quote_sheet_name(sheet_name: &str) -> String {
+    let is_plain = sheet_name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        && !sheet_name.starts_with(|c: char| c.is_ascii_digit());
+    if is_plain && !sheet_name.is_empty() {
+        return sheet_name.to_string();
+    }
+    format!("'{}'", sheet_name.replace('\'', "''"))
+}
+
+/// Convert a formula written with localized function names and separators
+/// to the canonical English form stored in the file.
+/// # Arguments
+/// * `formula` - ex) `"=SUMME(A1;2,5)"`
+/// * `locale` - Locale
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// use umya_spreadsheet::helper::formula::*;
+/// let mut locale = Locale::default();
+/// locale.set_decimal_separator(',').set_list_separator(';');
+/// locale.add_function_name("SUM", "SUMME");
+/// assert_eq!(delocalize_formula("=SUMME(A1;2,5)", &locale), "=SUM(A1,2.5)");
+/// assert_eq!(localize_formula("=SUM(A1,2.5)", &locale), "=SUMME(A1;2,5)");
+/// ```
+pub fn delocalize_formula(formula: &str, locale: &Locale) -> String {
+    let formula = replace_formula_separators(
+        formula,
+        (*locale.get_list_separator(), COMMA),
+        (*locale.get_decimal_separator(), '.'),
+    );
+    rename_formula_functions(&formula, |name| {
+        locale
+            .get_function_names()
+            .iter()
+            .find(|(_, v)| v.as_str() == name)
+            .map(|(k, _)| k.clone())
+    })
+}
+
+/// Convert a canonical English formula to localized function names and separators.
+/// # Arguments
+/// * `formula` - ex) `"=SUM(A1,2.5)"`
+/// * `locale` - Locale
+pub fn localize_formula(formula: &str, locale: &Locale) -> String {
+    let formula = rename_formula_functions(formula, |name| {
+        locale.get_function_names().get(name).cloned()
+    });
+    replace_formula_separators(
+        &formula,
+        (COMMA, *locale.get_list_separator()),
+        ('.', *locale.get_decimal_separator()),
+    )
+}
+
+// Rename the functions of a formula, with or without the leading `=`.
+fn rename_formula_functions<F>(formula: &str, rename: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let (prefix, body) = match formula.strip_prefix('=') {
+        Some(v) => ("=", v),
+        None => ("", formula),
+    };
+    let mut token_list = parse_to_tokens(format!("={}", body));
+    let mut is_changed = false;
+    for token in &mut token_list {
+        if token.get_token_type() == &FormulaTokenTypes::Function
+            && token.get_token_sub_type() == &FormulaTokenSubTypes::Start
+        {
+            if let Some(v) = rename(&token.get_value().to_uppercase()) {
+                token.set_value(v);
+                is_changed = true;
+            }
+        }
+    }
+    if !is_changed {
+        return formula.to_string();
+    }
+    format!("{}{}", prefix, render(&token_list))
+}
+
+// Swap the list and decimal separators outside of strings, sheet names and brackets.
+// The decimal separator is only swapped between two digits.
+fn replace_formula_separators(formula: &str, list: (char, char), decimal: (char, char)) -> String {
+    let chars: Vec<char> = formula.chars().collect();
+    let mut result = String::with_capacity(formula.len());
+    let mut closing: Option<char> = None;
+    for (i, c) in chars.iter().enumerate() {
+        if let Some(v) = closing {
+            if *c == v {
+                closing = None;
+            }
+            result.push(*c);
+            continue;
+        }
+        let is_between_digits = i > 0
+            && chars[i - 1].is_ascii_digit()
+            && matches!(chars.get(i + 1), Some(v) if v.is_ascii_digit());
+        match *c {
+            QUOTE_DOUBLE | QUOTE_SINGLE => {
+                closing = Some(*c);
+                result.push(*c);
+            }
+            BRACKET_OPEN => {
+                closing = Some(BRACKET_CLOSE);
+                result.push(*c);
+            }
+            v if v == decimal.0 && is_between_digits => result.push(decimal.1),
+            v if v == list.0 => result.push(list.1),
+            v => result.push(v),
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -896,4 +1095,54 @@ mod tests {
             formula
         );
     }
+
+    #[test]
+    fn references() {
+        assert_eq!(
+            get_formula_references("A1+$B$2"),
+            vec![
+                ("".to_string(), "A1".to_string()),
+                ("".to_string(), "$B$2".to_string())
+            ]
+        );
+        assert_eq!(
+            get_formula_references("Data!A1+1"),
+            vec![("Data".to_string(), "A1".to_string())]
+        );
+        assert_eq!(
+            get_formula_references("-A1-B2:C3"),
+            vec![
+                ("".to_string(), "A1".to_string()),
+                ("".to_string(), "B2:C3".to_string())
+            ]
+        );
+        assert!(get_formula_references("1.5E+3-2E-1").is_empty());
+
+        let formula = "=1.5E+3+A1";
+        assert_eq!(
+            format!("={}", render(parse_to_tokens(formula).as_ref())),
+            formula
+        );
+    }
+
+    #[test]
+    fn localize() {
+        let mut locale = Locale::default();
+        locale
+            .set_decimal_separator(',')
+            .set_list_separator(';')
+            .add_function_name("SUM", "SUMME")
+            .add_function_name("IF", "WENN");
+
+        let formula = "=WENN(SUMME(A1:A3;0,5)>1;\"a;b\";'My Sheet'!B1)";
+        let canonical = "=IF(SUM(A1:A3,0.5)>1,\"a;b\",'My Sheet'!B1)";
+        assert_eq!(delocalize_formula(formula, &locale), canonical);
+        assert_eq!(localize_formula(canonical, &locale), formula);
+
+        assert_eq!(delocalize_formula("summe(1;2)", &locale), "SUM(1,2)");
+        assert_eq!(
+            delocalize_formula("=SUMME(Table1[Col])", &locale),
+            "=SUM(Table1[Col])"
+        );
+    }
 }
@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
+use chrono::Datelike;
 use fancy_regex::Captures;
 use fancy_regex::Matches;
 use fancy_regex::Regex;
 use helper::date::*;
 use structs::Color;
+use structs::Locale;
 use structs::NumberingFormat;
 use thousands::Separable;
 
@@ -103,6 +105,16 @@ lazy_static! {
 }
 
 pub fn to_formatted_string<S: AsRef<str>, P: AsRef<str>>(value: S, format: P) -> String {
+    to_formatted_string_with_locale(value, format, &Locale::default())
+}
+
+/// Format a value like `to_formatted_string`, using the separators,
+/// currency symbol and month and day names of `locale`.
+pub fn to_formatted_string_with_locale<S: AsRef<str>, P: AsRef<str>>(
+    value: S,
+    format: P,
+    locale: &Locale,
+) -> String {
     let mut value: Cow<str> = Cow::Borrowed(value.as_ref());
     let format = Cow::Borrowed(format.as_ref());
 
@@ -114,7 +126,7 @@ pub fn to_formatted_string<S: AsRef<str>, P: AsRef<str>>(value: S, format: P) ->
     match &value.parse::<f64>() {
         // convert value
         Ok(val) if format == NumberingFormat::FORMAT_GENERAL => {
-            return val.to_string();
+            return locale.localize_number(&val.to_string());
         }
         Ok(_) if format == NumberingFormat::FORMAT_TEXT => return value.to_string(),
         Err(_) => return value.to_string(),
@@ -144,19 +156,94 @@ pub fn to_formatted_string<S: AsRef<str>, P: AsRef<str>>(value: S, format: P) ->
 
     if DATE_TIME_REGEX.is_match(&format).unwrap_or(false) {
         // datetime format
-        value = format_as_date(&value.parse::<f64>().unwrap(), &format);
+        value = format_as_date(&value.parse::<f64>().unwrap(), &format, locale);
     } else if format.starts_with('"') && format.ends_with('"') {
         let conv_format = format.trim_matches('"').parse::<f64>().unwrap();
-        value = Cow::Owned(conv_format.to_string());
+        value = Cow::Owned(locale.localize_number(&conv_format.to_string()));
     } else if PERCENT_DOLLAR_REGEX.is_match(&format).unwrap_or(false) {
         // % number format
-        value = format_as_percentage(&value.parse::<f64>().unwrap(), &format);
+        let result = format_as_percentage(&value.parse::<f64>().unwrap(), &format);
+        value = Cow::Owned(locale.localize_number(&result));
     } else {
-        value = format_as_number(&value.parse::<f64>().unwrap(), &format);
+        value = format_as_number(&value.parse::<f64>().unwrap(), &format, locale);
     }
     value.trim().to_string()
 }
 
+/// Round a value to the number of decimals its number format displays.
+/// Values with a General, text, date or scientific format are returned as they are.
+/// # Arguments
+/// * `value` - stored value.
+/// * `format` - number format code. ex) `"#,##0.00"`
+pub fn round_to_display_precision(value: f64, format: &str) -> f64 {
+    match get_display_precision(value, format) {
+        Some(decimals) => {
+            let factor = 10f64.powi(decimals);
+            (value * factor).round() / factor
+        }
+        None => value,
+    }
+}
+
+fn get_display_precision(value: f64, format: &str) -> Option<i32> {
+    if format == NumberingFormat::FORMAT_GENERAL || format == NumberingFormat::FORMAT_TEXT {
+        return None;
+    }
+    let sections: Vec<&str> = split(&SECTION_REGEX, format).collect();
+    let section = match sections.get(1) {
+        Some(v) if value < 0f64 => v,
+        _ => sections[0],
+    };
+
+    // drop quoted literals, escaped characters, fills, spacing and [..] blocks
+    let mut mask = String::new();
+    let mut chars = section.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                }
+            }
+            '[' => {
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                }
+            }
+            '\\' | '_' | '*' => {
+                chars.next();
+            }
+            _ => mask.push(c),
+        }
+    }
+    if mask.contains(|c: char| "hmsdyHMSDYeE@".contains(c)) {
+        return None;
+    }
+    if !mask.contains(|c: char| c == '0' || c == '#' || c == '?') {
+        return None;
+    }
+
+    let is_placeholder = |c: char| c == '0' || c == '#' || c == '?';
+    let (integer, fraction) = match mask.find('.') {
+        Some(i) => (&mask[..i], &mask[i + 1..]),
+        None => (mask.as_str(), ""),
+    };
+    let mut decimals = fraction.chars().take_while(|c| is_placeholder(*c)).count() as i32;
+    decimals += mask.matches('%').count() as i32 * 2;
+    // trailing commas scale the value by 1000 each
+    let scale = integer
+        .trim_end_matches(|c: char| !is_placeholder(c) && c != ',')
+        .chars()
+        .rev()
+        .take_while(|c| *c == ',')
+        .count() as i32;
+    Some(decimals - scale * 3)
+}
+
 fn format_as_percentage<'input>(value: &f64, format: &'input str) -> Cow<'input, str> {
     let mut value = value.to_string();
     let mut format = Cow::Borrowed(format);
@@ -283,7 +370,7 @@ fn split_format_compare(value: &f64, cond: &str, val: &f64, dfcond: &str, dfval:
     value >= check_val
 }
 
-fn format_as_date<'input>(value: &f64, format: &'input str) -> Cow<'input, str> {
+fn format_as_date<'input>(value: &f64, format: &'input str, locale: &Locale) -> Cow<'input, str> {
     let format = Cow::Borrowed(format);
 
     // strip off first part containing e.g. [$-F800] or [$USD-409]
@@ -342,10 +429,25 @@ fn format_as_date<'input>(value: &f64, format: &'input str) -> Cow<'input, str>
     });
 
     let date_obj = excel_to_date_time_object(value, None);
+
+    // month and day names are taken from the locale
+    let month = date_obj.month0() as usize;
+    let weekday = date_obj.weekday().num_days_from_sunday() as usize;
+    let mut format = format.into_owned();
+    for (spec, list, index) in [
+        ("%B", locale.get_month_names(), month),
+        ("%b", locale.get_month_abbreviations(), month),
+        ("%A", locale.get_day_names(), weekday),
+        ("%a", locale.get_day_abbreviations(), weekday),
+    ] {
+        if let Some(name) = list.get(index) {
+            format = format.replace(spec, &name.replace('%', "%%"));
+        }
+    }
     Cow::Owned(date_obj.format(&format).to_string())
 }
 
-fn format_as_number<'input>(value: &f64, format: &'input str) -> Cow<'input, str> {
+fn format_as_number<'input>(value: &f64, format: &'input str, locale: &Locale) -> Cow<'input, str> {
     lazy_static! {
         static ref THOUSANDS_SEP_REGEX: Regex = Regex::new(r#"(#,#|0,0)"#).unwrap();
         static ref SCALE_REGEX: Regex = Regex::new(r#"(#|0)(,+)"#).unwrap();
@@ -428,13 +530,19 @@ fn format_as_number<'input>(value: &f64, format: &'input str) -> Cow<'input, str
         }
     }
 
+    value = locale.localize_number(&value);
+
     let re = Regex::new(r"\$[^0-9]*").unwrap();
     if re.find(&format).ok().flatten().is_some() {
         let mut item: Vec<String> = Vec::new();
         for ite in re.captures(&format).ok().flatten().unwrap().iter() {
             item.push(ite.unwrap().as_str().to_string());
         }
-        value = format!("{}{}", item.get(0).unwrap(), value);
+        let currency = item
+            .get(0)
+            .unwrap()
+            .replacen('$', locale.get_currency_symbol(), 1);
+        value = format!("{}{}", currency, value);
         //    //  Currency or Accounting
         //    let currency_code = item.get(1).unwrap().to_string();
         //    value = Regex::new(r#"\[\$([^\]]*)\]"#).unwrap().replace_all(&value, currency_code.as_str()).to_string();
@@ -0,0 +1,355 @@
+use cfb;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use structs::XlsxError;
+use uuid::Uuid;
+
+// CLSID of the OLE Packager. {0003000C-0000-0000-C000-000000000046}
+const PACKAGE_CLSID: [u8; 16] = [
+    0x0C, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46,
+];
+
+/// Build an OLE compound file which wraps the file as a Packager object.
+/// # Arguments
+/// * `file_name` - File name of the embedded file.
+/// * `label` - Caption of the icon.
+/// * `data` - File data.
+pub fn make_package_object(
+    file_name: &str,
+    label: &str,
+    data: &[u8],
+) -> Result<Vec<u8>, XlsxError> {
+    let mut comp = cfb::CompoundFile::create(io::Cursor::new(Vec::new()))?;
+    comp.set_storage_clsid("/", Uuid::from_bytes_le(PACKAGE_CLSID))?;
+    comp.create_stream("\u{1}Ole10Native")?
+        .write_all(&make_ole10_native(file_name, label, data))?;
+    comp.create_stream("\u{1}CompObj")?
+        .write_all(&make_comp_obj())?;
+    comp.create_stream("\u{3}ObjInfo")?
+        .write_all(&[0x00, 0x00, 0x03, 0x00, 0x0D, 0x00])?;
+    comp.flush()?;
+    Ok(comp.into_inner().into_inner())
+}
+
+/// Get the original file out of an embedded object.
+/// Packager objects are unwrapped, and documents stored as a stream of an OLE storage are pulled out.
+/// Other data is returned as is.
+/// # Arguments
+/// * `data` - Data of the embedded object.
+/// # Return value
+/// * `(String, Vec<u8>)` - File name (empty when unknown) and file data.
+pub fn get_embedded_file(data: &[u8]) -> (String, Vec<u8>) {
+    let mut comp = match cfb::CompoundFile::open(io::Cursor::new(data)) {
+        Ok(v) => v,
+        Err(_) => return (String::new(), data.to_vec()),
+    };
+    if let Some(v) = read_stream(&mut comp, "\u{1}Ole10Native") {
+        if let Some(result) = read_ole10_native(&v) {
+            return result;
+        }
+    }
+    for stream_name in ["Package", "CONTENTS"] {
+        if let Some(v) = read_stream(&mut comp, stream_name) {
+            return (String::new(), v);
+        }
+    }
+    (String::new(), data.to_vec())
+}
+
+/// Detect the file type from the data.
+/// # Arguments
+/// * `data` - File data.
+/// # Return value
+/// * `&str` - Extension. ex) `"pdf"`, `"docx"`. `"bin"` when unknown.
+pub fn detect_file_type(data: &[u8]) -> &'static str {
+    if data.starts_with(b"%PDF") {
+        return "pdf";
+    }
+    if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+        return "png";
+    }
+    if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return "jpg";
+    }
+    if data.starts_with(b"GIF8") {
+        return "gif";
+    }
+    if data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]) {
+        return "ole";
+    }
+    if data.starts_with(b"PK\x03\x04") {
+        let archive = match zip::ZipArchive::new(io::Cursor::new(data)) {
+            Ok(v) => v,
+            Err(_) => return "zip",
+        };
+        for name in archive.file_names() {
+            if name.starts_with("word/") {
+                return "docx";
+            }
+            if name.starts_with("xl/") {
+                return "xlsx";
+            }
+            if name.starts_with("ppt/") {
+                return "pptx";
+            }
+        }
+        return "zip";
+    }
+    "bin"
+}
+
+/// Build a preview image (EMF) of a document icon with the label under it.
+/// # Arguments
+/// * `label` - Caption of the icon.
+pub fn make_preview_emf(label: &str) -> Vec<u8> {
+    let chars: Vec<u16> = label.encode_utf16().collect();
+    let char_width: i32 = 7;
+    let width = (chars.len() as i32 * char_width + 16).max(80);
+    let height: i32 = 80;
+    let icon_left = width / 2 - 16;
+    let icon_right = width / 2 + 16;
+
+    let mut records: Vec<u8> = Vec::new();
+    let mut record_count: u32 = 0;
+
+    // EMR_RECTANGLE
+    add_record(
+        &mut records,
+        &mut record_count,
+        43,
+        &[icon_left, 8, icon_right, 48],
+    );
+    for y in [20, 28, 36] {
+        // EMR_MOVETOEX
+        add_record(&mut records, &mut record_count, 27, &[icon_left + 6, y]);
+        // EMR_LINETO
+        add_record(&mut records, &mut record_count, 54, &[icon_right - 6, y]);
+    }
+    // EMR_SETBKMODE (TRANSPARENT)
+    add_record(&mut records, &mut record_count, 18, &[1]);
+    // EMR_SETTEXTALIGN (TA_TOP | TA_CENTER)
+    add_record(&mut records, &mut record_count, 22, &[6]);
+
+    // EMR_EXTTEXTOUTW
+    if !chars.is_empty() {
+        let count = chars.len() as u32;
+        let string_size = (count * 2 + 3) & !3;
+        let offset_string: u32 = 76;
+        let offset_dx = offset_string + string_size;
+        push_u32(&mut records, 84);
+        push_u32(&mut records, offset_dx + count * 4);
+        for v in [0, 52, width, height] {
+            push_i32(&mut records, v);
+        }
+        push_u32(&mut records, 1);
+        records.extend_from_slice(&0f32.to_le_bytes());
+        records.extend_from_slice(&0f32.to_le_bytes());
+        push_i32(&mut records, width / 2);
+        push_i32(&mut records, 56);
+        push_u32(&mut records, count);
+        push_u32(&mut records, offset_string);
+        push_u32(&mut records, 0);
+        for v in [0, 0, -1, -1] {
+            push_i32(&mut records, v);
+        }
+        push_u32(&mut records, offset_dx);
+        for c in &chars {
+            records.extend_from_slice(&c.to_le_bytes());
+        }
+        records.resize(records.len() + (string_size - count * 2) as usize, 0);
+        for _ in &chars {
+            push_i32(&mut records, char_width);
+        }
+        record_count += 1;
+    }
+
+    // EMR_EOF
+    add_record(&mut records, &mut record_count, 14, &[0, 16, 20]);
+
+    // EMR_HEADER
+    let header_size: u32 = 108;
+    let mut result: Vec<u8> = Vec::new();
+    push_u32(&mut result, 1);
+    push_u32(&mut result, header_size);
+    for v in [0, 0, width - 1, height - 1] {
+        push_i32(&mut result, v);
+    }
+    for v in [0, 0, width * 2540 / 96, height * 2540 / 96] {
+        push_i32(&mut result, v);
+    }
+    push_u32(&mut result, 0x464D4520);
+    push_u32(&mut result, 0x10000);
+    push_u32(&mut result, header_size + records.len() as u32);
+    push_u32(&mut result, record_count + 1);
+    result.extend_from_slice(&1u16.to_le_bytes());
+    result.extend_from_slice(&0u16.to_le_bytes());
+    for v in [0, 0, 0] {
+        push_u32(&mut result, v);
+    }
+    for v in [1920, 1080, 508, 286] {
+        push_i32(&mut result, v);
+    }
+    for v in [0, 0, 0] {
+        push_u32(&mut result, v);
+    }
+    for v in [508000, 286000] {
+        push_i32(&mut result, v);
+    }
+    result.extend(records);
+    result
+}
+
+// Ole10Native stream of the Packager.
+fn make_ole10_native(file_name: &str, label: &str, data: &[u8]) -> Vec<u8> {
+    let mut native: Vec<u8> = Vec::new();
+    native.extend_from_slice(&2u16.to_le_bytes());
+    push_ansi_string(&mut native, label);
+    push_ansi_string(&mut native, file_name);
+    native.extend_from_slice(&0u16.to_le_bytes());
+    native.extend_from_slice(&3u16.to_le_bytes());
+    push_u32(&mut native, encode_ansi(file_name).len() as u32 + 1);
+    push_ansi_string(&mut native, file_name);
+    push_u32(&mut native, data.len() as u32);
+    native.extend_from_slice(data);
+    push_unicode_string(&mut native, file_name);
+    push_unicode_string(&mut native, label);
+    push_unicode_string(&mut native, file_name);
+
+    let mut result: Vec<u8> = Vec::new();
+    push_u32(&mut result, native.len() as u32);
+    result.extend(native);
+    result
+}
+
+fn read_stream(comp: &mut cfb::CompoundFile<io::Cursor<&[u8]>>, name: &str) -> Option<Vec<u8>> {
+    if !comp.is_stream(name) {
+        return None;
+    }
+    let mut result: Vec<u8> = Vec::new();
+    comp.open_stream(name).ok()?.read_to_end(&mut result).ok()?;
+    Some(result)
+}
+
+fn read_ole10_native(data: &[u8]) -> Option<(String, Vec<u8>)> {
+    let mut pos: usize = 6;
+    let _label = read_ansi_string(data, &mut pos)?;
+    let path = read_ansi_string(data, &mut pos)?;
+    pos += 2;
+    // 3: embedded file, 1: linked file
+    if read_u16(data, &mut pos)? != 3 {
+        return None;
+    }
+    let temp_path_size = read_u32(data, &mut pos)? as usize;
+    pos = pos.checked_add(temp_path_size)?;
+    let size = read_u32(data, &mut pos)? as usize;
+    let file_data = data.get(pos..pos.checked_add(size)?)?.to_vec();
+    pos += size;
+
+    // the unicode names follow the data in newer files.
+    let mut file_name = path;
+    let _temp_path = read_unicode_string(data, &mut pos);
+    let _label = read_unicode_string(data, &mut pos);
+    if let Some(v) = read_unicode_string(data, &mut pos) {
+        if !v.is_empty() {
+            file_name = v;
+        }
+    }
+    let file_name = file_name
+        .rsplit(['\\', '/'])
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    Some((file_name, file_data))
+}
+
+fn read_u16(data: &[u8], pos: &mut usize) -> Option<u16> {
+    let v = data.get(*pos..pos.checked_add(2)?)?;
+    *pos += 2;
+    Some(u16::from_le_bytes([v[0], v[1]]))
+}
+
+fn read_u32(data: &[u8], pos: &mut usize) -> Option<u32> {
+    let v = data.get(*pos..pos.checked_add(4)?)?;
+    *pos += 4;
+    Some(u32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+}
+
+fn read_ansi_string(data: &[u8], pos: &mut usize) -> Option<String> {
+    let rest = data.get(*pos..)?;
+    let end = rest.iter().position(|v| *v == 0)?;
+    *pos += end + 1;
+    Some(
+        encoding_rs::WINDOWS_1252
+            .decode_without_bom_handling(&rest[..end])
+            .0
+            .into_owned(),
+    )
+}
+
+fn read_unicode_string(data: &[u8], pos: &mut usize) -> Option<String> {
+    let count = read_u32(data, pos)? as usize;
+    let bytes = data.get(*pos..pos.checked_add(count.checked_mul(2)?)?)?;
+    *pos += count * 2;
+    let chars: Vec<u16> = bytes
+        .chunks(2)
+        .map(|v| u16::from_le_bytes([v[0], v[1]]))
+        .collect();
+    String::from_utf16(&chars).ok()
+}
+
+// CompObj stream of the Packager.
+fn make_comp_obj() -> Vec<u8> {
+    let mut result: Vec<u8> = vec![
+        0x01, 0x00, 0xFE, 0xFF, 0x03, 0x0A, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    result.extend_from_slice(&PACKAGE_CLSID);
+    for value in ["OLE Package", "", "Package"] {
+        match value.is_empty() {
+            true => push_u32(&mut result, 0),
+            false => {
+                push_u32(&mut result, value.len() as u32 + 1);
+                result.extend_from_slice(value.as_bytes());
+                result.push(0);
+            }
+        }
+    }
+    for v in [0x71B239F4, 0, 0, 0] {
+        push_u32(&mut result, v);
+    }
+    result
+}
+
+fn add_record(buf: &mut Vec<u8>, count: &mut u32, record_type: u32, params: &[i32]) {
+    push_u32(buf, record_type);
+    push_u32(buf, 8 + params.len() as u32 * 4);
+    for v in params {
+        push_i32(buf, *v);
+    }
+    *count += 1;
+}
+
+fn encode_ansi(value: &str) -> Vec<u8> {
+    encoding_rs::WINDOWS_1252.encode(value).0.into_owned()
+}
+
+fn push_ansi_string(buf: &mut Vec<u8>, value: &str) {
+    buf.extend(encode_ansi(value));
+    buf.push(0);
+}
+
+fn push_unicode_string(buf: &mut Vec<u8>, value: &str) {
+    let chars: Vec<u16> = value.encode_utf16().collect();
+    push_u32(buf, chars.len() as u32);
+    for c in chars {
+        buf.extend_from_slice(&c.to_le_bytes());
+    }
+}
+
+fn push_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn push_i32(buf: &mut Vec<u8>, value: i32) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
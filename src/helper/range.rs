@@ -5,8 +5,14 @@ use super::coordinate;
 /// `(col, row)`
 pub type BasicCellIndex = (u32, u32);
 
+/// Expand a range into the cells it covers.
 /// # Returns
 /// `Vec<(col, row)>`
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::range::get_coordinate_list;
+/// assert_eq!(get_coordinate_list("A1:B2"), vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+/// ```
 pub fn get_coordinate_list(range_str: &str) -> Vec<BasicCellIndex> {
     let (row_start, row_end, col_start, col_end) = get_start_and_end_point(range_str);
 
@@ -49,7 +55,7 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
                 col_end = v;
             }
             None => {
-                assert!(is_col_select, "Non-standard range.");
+                assert!(!is_col_select, "Non-standard range.");
             }
         };
 
@@ -58,7 +64,7 @@ pub fn get_start_and_end_point(range_str: &str) -> (u32, u32, u32, u32) {
                 row_end = v;
             }
             None => {
-                assert!(is_row_select, "Non-standard range.");
+                assert!(!is_row_select, "Non-standard range.");
             }
         }
     }
@@ -73,3 +79,51 @@ pub fn get_split_range(range: &str) -> Vec<&str> {
 pub fn get_join_range(coordinate_list: &[String]) -> String {
     coordinate_list.join(":")
 }
+
+/// Expand a range into the column indexes it covers.
+/// # Arguments
+/// * `range_str` - ex) `"B:D"`, `"B2:D5"`, `"$B"`
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::range::get_column_index_list;
+/// assert_eq!(get_column_index_list("B:D"), vec![2, 3, 4]);
+/// assert_eq!(get_column_index_list("D2:B5"), vec![2, 3, 4]);
+/// ```
+pub fn get_column_index_list(range_str: &str) -> Vec<u32> {
+    let (start, end) = get_index_pair(range_str, |(col, ..)| col);
+    match (start, end) {
+        (Some(start), Some(end)) => (start.min(end)..=start.max(end)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Expand a range into the row indexes it covers.
+/// # Arguments
+/// * `range_str` - ex) `"2:4"`, `"B2:D4"`, `"$2"`
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::range::get_row_index_list;
+/// assert_eq!(get_row_index_list("2:4"), vec![2, 3, 4]);
+/// assert_eq!(get_row_index_list("B2:D4"), vec![2, 3, 4]);
+/// ```
+pub fn get_row_index_list(range_str: &str) -> Vec<u32> {
+    let (start, end) = get_index_pair(range_str, |(_, row, ..)| row);
+    match (start, end) {
+        (Some(start), Some(end)) => (start.min(end)..=start.max(end)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn get_index_pair<F>(range_str: &str, f: F) -> (Option<u32>, Option<u32>)
+where
+    F: Fn(CellIndex) -> Option<u32>,
+{
+    let range_upper = range_str.to_uppercase();
+    let split = get_split_range(&range_upper);
+    let start = f(index_from_coordinate(split[0]));
+    let end = match split.get(1) {
+        Some(v) => f(index_from_coordinate(v)),
+        None => start,
+    };
+    (start, end)
+}
@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+/// First characters that make a spreadsheet application read a text as a formula.
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Remove the control characters that are not allowed in xml.
+/// Vertical tab and form feed, used as line breaks by some applications, become `\n`.
+/// Tab, line feed and carriage return are kept.
+/// # Arguments
+/// * `value` - ex) `"a\u{0B}b"` becomes `"a\nb"`
+pub fn strip_control_characters(value: &str) -> Cow<str> {
+    if !value.chars().any(is_invalid_xml_character) {
+        return Cow::Borrowed(value);
+    }
+    Cow::Owned(
+        value
+            .chars()
+            .filter_map(|c| match c {
+                '\u{0B}' | '\u{0C}' => Some('\n'),
+                c if is_invalid_xml_character(c) => None,
+                c => Some(c),
+            })
+            .collect(),
+    )
+}
+
+/// Prefix a text that would be read as a formula (`=`, `+`, `-`, `@`, tab or carriage return first) with `'`.
+/// Only meant for csv: in xlsx the `'` would become part of the text.
+/// A number, such as `"-5"`, is left as it is.
+/// # Arguments
+/// * `value` - ex) `"=HYPERLINK(...)"` becomes `"'=HYPERLINK(...)"`
+pub fn guard_formula_injection(value: &str) -> Cow<str> {
+    if value.starts_with(&FORMULA_PREFIXES[..]) && value.trim().parse::<f64>().is_err() {
+        return Cow::Owned(format!("'{}", value));
+    }
+    Cow::Borrowed(value)
+}
+
+/// `strip_control_characters` and then `guard_formula_injection`, for csv export.
+pub fn sanitize_text(value: &str) -> Cow<str> {
+    match strip_control_characters(value) {
+        Cow::Borrowed(v) => guard_formula_injection(v),
+        Cow::Owned(v) => Cow::Owned(guard_formula_injection(&v).into_owned()),
+    }
+}
+
+fn is_invalid_xml_character(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00}'..='\u{08}' | '\u{0B}' | '\u{0C}' | '\u{0E}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}'
+    )
+}
@@ -0,0 +1,27 @@
+//! Instrumentation with the `tracing` crate.
+//! The macros expand to nothing unless the `tracing` feature is enabled.
+//! Durations are reported by the subscriber when a span is closed
+//! (ex. `tracing_subscriber::fmt::format::FmtSpan::CLOSE`).
+
+/// Enters a debug span until the end of the current block.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _trace_span = ::tracing::debug_span!($($arg)+).entered();
+    };
+}
+
+/// Emits an event at the given level (`DEBUG`, `WARN`, ...).
+#[macro_export]
+#[doc(hidden)]
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::event!(::tracing::Level::$level, $($arg)+);
+    };
+}
+
+pub(crate) use trace_event;
+pub(crate) use trace_span;
@@ -0,0 +1,114 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use helper::date::convert_date;
+use structs::Locale;
+use structs::NumberingFormat;
+
+/// A typed value read out of a plain string by `detect_value`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DetectedValue {
+    Bool(bool),
+    /// Number and the format code that displays it like the source string.
+    Number(f64, String),
+}
+
+/// Read ISO dates, percentages, currency amounts and booleans out of a string.
+/// Plain numbers and other text return `None` and are left to `Cell::set_value`.
+/// # Arguments
+/// * `value` - ex) `"2024-01-31"`, `"12.5%"`, `"$1,234.50"`, `"true"`
+/// * `locale` - separators and currency symbol used to read numbers.
+pub fn detect_value(value: &str, locale: &Locale) -> Option<DetectedValue> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if value.eq_ignore_ascii_case("true") {
+        return Some(DetectedValue::Bool(true));
+    }
+    if value.eq_ignore_ascii_case("false") {
+        return Some(DetectedValue::Bool(false));
+    }
+    detect_date(value)
+        .or_else(|| detect_percentage(value, locale))
+        .or_else(|| detect_currency(value, locale))
+}
+
+fn detect_date(value: &str) -> Option<DetectedValue> {
+    if let Ok(v) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let serial = convert_date(v.year(), v.month() as i32, v.day() as i32, 0, 0, 0);
+        return Some(DetectedValue::Number(
+            serial,
+            NumberingFormat::FORMAT_DATE_YYYYMMDD.to_string(),
+        ));
+    }
+    for format in &[
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ] {
+        if let Ok(v) = NaiveDateTime::parse_from_str(value, format) {
+            let serial = convert_date(
+                v.year(),
+                v.month() as i32,
+                v.day() as i32,
+                v.hour() as i32,
+                v.minute() as i32,
+                v.second() as i32,
+            );
+            return Some(DetectedValue::Number(
+                serial,
+                String::from("yyyy-mm-dd hh:mm:ss"),
+            ));
+        }
+    }
+    None
+}
+
+fn detect_percentage(value: &str, locale: &Locale) -> Option<DetectedValue> {
+    let number = value.strip_suffix('%')?.trim_end();
+    let parsed = parse_plain_number(number, locale)?;
+    let format = if number.contains(*locale.get_decimal_separator()) {
+        NumberingFormat::FORMAT_PERCENTAGE_00
+    } else {
+        NumberingFormat::FORMAT_PERCENTAGE
+    };
+    Some(DetectedValue::Number(parsed / 100f64, format.to_string()))
+}
+
+fn detect_currency(value: &str, locale: &Locale) -> Option<DetectedValue> {
+    let symbol = locale.get_currency_symbol();
+    if symbol.is_empty() {
+        return None;
+    }
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(v) => (true, v.trim_start()),
+        None => (false, value),
+    };
+    let number = match unsigned.strip_prefix(symbol) {
+        Some(v) => v.trim_start(),
+        None => unsigned.strip_suffix(symbol)?.trim_end(),
+    };
+    let parsed = parse_plain_number(number, locale)?;
+    let format = match symbol {
+        "$" => NumberingFormat::FORMAT_CURRENCY_USD_SIMPLE.to_string(),
+        "€" => NumberingFormat::FORMAT_CURRENCY_EUR_SIMPLE.to_string(),
+        _ => format!(r##""{}"#,##0.00_-"##, symbol),
+    };
+    let parsed = if negative { -parsed } else { parsed };
+    Some(DetectedValue::Number(parsed, format))
+}
+
+fn parse_plain_number(value: &str, locale: &Locale) -> Option<f64> {
+    if value.is_empty() || value.starts_with(|c: char| c == '+' || c.is_whitespace()) {
+        return None;
+    }
+    if !value.chars().all(|c| {
+        c.is_ascii_digit()
+            || c == '-'
+            || c == *locale.get_decimal_separator()
+            || c == *locale.get_thousands_separator()
+    }) {
+        return None;
+    }
+    locale.parse_number(value)
+}
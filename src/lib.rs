@@ -115,6 +115,28 @@
 //! let path = std::path::Path::new("C:/spread_test_data/ccc.xlsx");
 //! let _ = writer::xlsx::write(&book, path);
 //! ```
+//! ### Thread Safety
+//! `Spreadsheet` and all the structs it holds are `Send` and `Sync`.
+//! A workbook can be moved to another thread, or shared behind `Arc<RwLock<...>>`.
+//! Shared data (styles, the shared string table) is held with `Arc` and `RwLock`, never `Rc` or `RefCell`.
+//! ```rust
+//! use std::sync::{Arc, RwLock};
+//! let book = Arc::new(RwLock::new(umya_spreadsheet::new_file()));
+//! let handles: Vec<_> = (1..=4u32)
+//!     .map(|row| {
+//!         let book = book.clone();
+//!         std::thread::spawn(move || {
+//!             let mut book = book.write().unwrap();
+//!             let worksheet = book.get_sheet_mut(&0).unwrap();
+//!             worksheet.get_cell_mut((1, row)).set_value_number(row);
+//!         })
+//!     })
+//!     .collect();
+//! for handle in handles {
+//!     handle.join().unwrap();
+//! }
+//! assert_eq!(book.read().unwrap().get_sheet(&0).unwrap().get_value("A4"), "4");
+//! ```
 
 #![allow(warnings)]
 #![allow(clippy::all)]
@@ -136,11 +158,17 @@ extern crate cfb;
 extern crate getrandom;
 extern crate hmac;
 extern crate html_parser;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 extern crate sha2;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+extern crate uuid;
 
 #[macro_use]
 extern crate lazy_static;
 
+pub mod compare;
 pub mod helper;
 pub mod reader;
 pub mod structs;
@@ -1,6 +1,7 @@
+use helper::const_str::{CUSTOM_PROPS_REL, REL_OFC_NS, STRICT_REL_OFC_NS, XPROPS_REL};
 use quick_xml::events::attributes::Attribute;
 use std::path::{Component, Path, PathBuf};
-use std::string::FromUtf8Error;
+pub(crate) use XlsxError;
 
 #[macro_export]
 macro_rules! xml_read_loop {
@@ -9,7 +10,7 @@ macro_rules! xml_read_loop {
         loop {
             let ev = match $reader.read_event_into(&mut buf) {
                 Ok(v) => v,
-                Err(e) => panic!("Error at position {}: {e:?}", $reader.buffer_position()),
+                Err(e) => return Err($crate::XlsxError::Xml(e)),
             };
 
             match ev {
@@ -27,7 +28,7 @@ pub(crate) use xml_read_loop;
 #[macro_export]
 macro_rules! set_string_from_xml {
     ($self:ident, $e:ident, $attr:ident, $xml_attr:expr) => {{
-        if let Some(v) = get_attribute($e, $xml_attr.as_bytes()) {
+        if let Some(v) = get_attribute($e, $xml_attr.as_bytes())? {
             $self.$attr.set_value_string(v);
         }
     }};
@@ -35,6 +36,17 @@ macro_rules! set_string_from_xml {
 
 pub(crate) use set_string_from_xml;
 
+#[macro_export]
+macro_rules! set_number_from_xml {
+    ($self:ident, $e:ident, $attr:ident, $xml_attr:expr) => {{
+        if let Some(v) = get_attribute($e, $xml_attr.as_bytes())? {
+            $self.$attr.set_value_string(v)?;
+        }
+    }};
+}
+
+pub(crate) use set_number_from_xml;
+
 pub(crate) fn normalize_path(path: &str) -> PathBuf {
     let path = Path::new(path);
     let mut components = path.components().peekable();
@@ -75,16 +87,52 @@ pub(crate) fn normalize_path_to_str(path: &str) -> String {
     ret.to_str().unwrap_or("").replace('\\', "/")
 }
 
-pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -> Option<String> {
+pub(crate) fn get_attribute(
+    e: &quick_xml::events::BytesStart<'_>,
+    key: &[u8],
+) -> Result<Option<String>, XlsxError> {
     e.attributes()
         .with_checks(false)
         .find_map(|attr| match attr {
-            Ok(ref attr) if attr.key.into_inner() == key => {
-                Some(get_attribute_value(attr).unwrap())
-            }
+            Ok(ref attr) if attr.key.into_inner() == key => Some(get_attribute_value(attr)),
             _ => None,
         })
+        .transpose()
+}
+pub(crate) fn get_required_attribute(
+    e: &quick_xml::events::BytesStart<'_>,
+    key: &[u8],
+) -> Result<String, XlsxError> {
+    get_attribute(e, key)?.ok_or_else(|| {
+        XlsxError::Format(format!(
+            "missing attribute `{}` in <{}>",
+            String::from_utf8_lossy(key),
+            String::from_utf8_lossy(e.name().into_inner())
+        ))
+    })
 }
-pub(crate) fn get_attribute_value(attr: &Attribute) -> Result<String, FromUtf8Error> {
-    String::from_utf8(attr.value.to_vec())
+
+/// Map a relationship type of the strict (ISO) variant to the transitional one.
+/// Other relationship types are returned as is.
+pub(crate) fn normalize_relationship_type(value: String) -> String {
+    match value.strip_prefix(STRICT_REL_OFC_NS) {
+        Some("/extendedProperties") => XPROPS_REL.to_string(),
+        Some("/customProperties") => CUSTOM_PROPS_REL.to_string(),
+        Some(v) => format!("{}{}", REL_OFC_NS, v),
+        None => value,
+    }
+}
+
+/// Error for an attribute or text value that cannot be parsed.
+pub(crate) fn parse_error<E: std::fmt::Display>(e: E) -> XlsxError {
+    XlsxError::Format(e.to_string())
+}
+
+/// Error for a document that ends before the end tag of `tag`.
+pub(crate) fn eof_error(tag: &str) -> XlsxError {
+    XlsxError::Format(format!("Could not find {} end element", tag))
+}
+
+pub(crate) fn get_attribute_value(attr: &Attribute) -> Result<String, XlsxError> {
+    Ok(attr.unescape_value()?.into_owned())
 }
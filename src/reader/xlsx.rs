@@ -7,18 +7,27 @@ use std::sync::Arc;
 use std::sync::RwLock;
 
 use super::driver;
+use crate::trace_event;
+use crate::trace_span;
 use helper::const_str::*;
 use structs::drawing::Theme;
+use structs::raw::RawPart;
+use structs::raw::RawRelationship;
 use structs::raw::RawWorksheet;
+use structs::Progress;
+use structs::ReadLimits;
 use structs::SharedStringTable;
+use structs::SheetMetadata;
 use structs::Spreadsheet;
 use structs::Stylesheet;
+use structs::WorkbookMetadata;
 use structs::Worksheet;
 use XlsxError;
 
 pub(crate) mod chart;
 pub(crate) mod comment;
 mod content_types;
+mod ctrl_prop;
 mod doc_props_app;
 mod doc_props_core;
 mod doc_props_custom;
@@ -43,26 +52,137 @@ pub fn read_reader<R: io::Read + io::Seek>(
     reader: R,
     with_sheet_read: bool,
 ) -> Result<Spreadsheet, XlsxError> {
+    read_reader_with_mode(reader, with_sheet_read, false, &ReadLimits::default())
+}
+
+/// read spreadsheet from arbitrary reader in recovery mode.
+/// Unparseable parts are skipped and reported in `Spreadsheet::get_read_warnings`.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader_with_recovery<R: io::Read + io::Seek>(
+    reader: R,
+) -> Result<Spreadsheet, XlsxError> {
+    read_reader_with_mode(reader, true, true, &ReadLimits::default())
+}
+
+/// read spreadsheet from arbitrary reader with limits.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `limits` - limits to enforce.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader_with_limits<R: io::Read + io::Seek>(
+    reader: R,
+    limits: &ReadLimits,
+) -> Result<Spreadsheet, XlsxError> {
+    read_reader_with_mode(reader, true, false, limits)
+}
+
+fn read_reader_with_mode<R: io::Read + io::Seek>(
+    reader: R,
+    with_sheet_read: bool,
+    recovery: bool,
+    limits: &ReadLimits,
+) -> Result<Spreadsheet, XlsxError> {
+    trace_span!("read_xlsx", with_sheet_read, recovery);
     let mut arv = zip::read::ZipArchive::new(reader)?;
+    trace_event!(DEBUG, parts = arv.len(), "opened package");
+    check_part_size(&mut arv, limits)?;
+    let mut warnings: Vec<String> = Vec::new();
+    let mut warnings_mut = if recovery { Some(&mut warnings) } else { None };
 
     let mut book = workbook::read(&mut arv)?;
-    doc_props_app::read(&mut arv, &mut book)?;
-    doc_props_core::read(&mut arv, &mut book)?;
-    doc_props_custom::read(&mut arv, &mut book)?;
-    vba_project_bin::read(&mut arv, &mut book)?;
-    content_types::read(&mut arv, &mut book)?;
+    book.set_read_limits(limits.clone());
+    let result = doc_props_app::read(&mut arv, &mut book);
+    recover_part(result, ARC_APP, &mut warnings_mut)?;
+    let result = doc_props_core::read(&mut arv, &mut book);
+    recover_part(result, ARC_CORE, &mut warnings_mut)?;
+    let result = doc_props_custom::read(&mut arv, &mut book);
+    recover_part(result, ARC_CUSTOM, &mut warnings_mut)?;
+    let result = vba_project_bin::read(&mut arv, &mut book);
+    recover_part(result, PKG_VBA_PROJECT, &mut warnings_mut)?;
+    let result = content_types::read(&mut arv, &mut book);
+    recover_part(result, CONTENT_TYPES, &mut warnings_mut)?;
     let workbook_rel = workbook_rels::read(&mut arv, &mut book)?;
 
     book.set_theme(Theme::get_default_value());
     for (_, type_value, rel_target) in &workbook_rel {
         if type_value == THEME_NS {
-            let theme = theme::read(&mut arv, rel_target)?;
-            book.set_theme(theme);
+            match theme::read(&mut arv, rel_target) {
+                Ok(theme) => {
+                    book.set_theme(theme);
+                }
+                Err(e) => {
+                    recover_part::<()>(Err(e), rel_target, &mut warnings_mut)?;
+                }
+            }
         }
     }
 
-    shared_strings::read(&mut arv, &mut book)?;
-    styles::read(&mut arv, &mut book)?;
+    for (_, type_value, rel_target) in &workbook_rel {
+        if RICH_DATA_NS_LIST.contains(&type_value.as_str()) {
+            let mut raw_part = RawPart::default();
+            let result = raw_part
+                .read(&mut arv, type_value, rel_target)
+                .and_then(|_| check_missing_part(raw_part.get_missing_part()))
+                .and_then(|_| book.add_rich_data_parts(raw_part));
+            recover_part(result, rel_target, &mut warnings_mut)?;
+        }
+        if type_value == CONNECTIONS_NS {
+            let mut raw_part = RawPart::default();
+            let result = raw_part
+                .read(&mut arv, type_value, rel_target)
+                .and_then(|_| check_missing_part(raw_part.get_missing_part()))
+                .and_then(|_| book.set_connections_part(raw_part));
+            recover_part(result, rel_target, &mut warnings_mut)?;
+        }
+    }
+
+    for (_, type_value, rel_target) in &workbook_rel {
+        if WORKBOOK_MODELED_NS_LIST.contains(&type_value.as_str())
+            || RICH_DATA_NS_LIST.contains(&type_value.as_str())
+        {
+            continue;
+        }
+        let mut raw_part = RawPart::default();
+        let result = raw_part
+            .read(&mut arv, type_value, rel_target)
+            .and_then(|_| check_missing_part(raw_part.get_missing_part()));
+        if recover_part(result, rel_target, &mut warnings_mut)? {
+            book.add_unknown_parts(raw_part);
+        }
+    }
+
+    let root_rel = match rels::read(&mut arv) {
+        Ok(v) => v,
+        Err(e) => {
+            recover_part::<()>(Err(e), ARC_RELS, &mut warnings_mut)?;
+            Vec::new()
+        }
+    };
+    for (_, type_value, rel_target) in &root_rel {
+        if ROOT_MODELED_NS_LIST.contains(&type_value.as_str()) {
+            continue;
+        }
+        let mut raw_part = RawPart::default();
+        let result = raw_part
+            .read_root(&mut arv, type_value, rel_target)
+            .and_then(|_| check_missing_part(raw_part.get_missing_part()));
+        if recover_part(result, rel_target, &mut warnings_mut)? {
+            book.add_unknown_root_parts(raw_part);
+        }
+    }
+
+    let result = shared_strings::read(&mut arv, &mut book, limits);
+    if !recover_part(result, PKG_SHARED_STRINGS, &mut warnings_mut)? {
+        book.set_shared_string_table(SharedStringTable::default());
+    }
+    let result = styles::read(&mut arv, &mut book);
+    if !recover_part(result, PKG_STYLES, &mut warnings_mut)? {
+        book.set_stylesheet_defalut_value();
+    }
 
     for sheet in book.get_sheet_collection_mut() {
         for (rel_id, _, rel_target) in &workbook_rel {
@@ -70,18 +190,84 @@ pub fn read_reader<R: io::Read + io::Seek>(
                 continue;
             }
             let mut raw_worksheet = RawWorksheet::default();
-            raw_worksheet.read(&mut arv, rel_target);
-            sheet.set_raw_data_of_worksheet(raw_worksheet);
+            // when recovering, a missing part of the sheet is reported where it is read.
+            let result = raw_worksheet.read(&mut arv, rel_target).and_then(|_| {
+                if recovery {
+                    return Ok(());
+                }
+                check_missing_part(raw_worksheet.get_missing_part())
+            });
+            if recover_part(result, rel_target, &mut warnings_mut)? {
+                sheet.set_raw_data_of_worksheet(raw_worksheet);
+            }
         }
     }
 
     if with_sheet_read {
-        book.read_sheet_collection();
+        book.read_sheet_collection_with_warnings(&mut warnings_mut)?;
     }
 
+    trace_event!(
+        DEBUG,
+        sheets = book.get_sheet_collection_no_check().len(),
+        warnings = warnings.len(),
+        "read workbook"
+    );
+    book.set_read_warnings(warnings);
     Ok(book)
 }
 
+// The declared size of a zip entry can not be trusted,
+// so each part is inflated up to the limit.
+fn check_part_size<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+    limits: &ReadLimits,
+) -> Result<(), XlsxError> {
+    let max_size = match limits.get_max_part_size() {
+        Some(v) => *v,
+        None => return Ok(()),
+    };
+    for index in 0..arv.len() {
+        let mut file = arv.by_index(index)?;
+        if file.size() > max_size
+            || io::copy(
+                &mut io::Read::take(&mut file, max_size + 1),
+                &mut io::sink(),
+            )? > max_size
+        {
+            return Err(XlsxError::LimitExceeded(format!(
+                "the part {} is larger than {} bytes",
+                file.name(),
+                max_size
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// In recovery mode, the error of a part is stored as a warning.
+/// Exceeding a `ReadLimits` limit is always an error.
+/// # Return value
+/// * `Result` - OK(true) if the part was read.
+fn recover_part<T>(
+    result: Result<T, XlsxError>,
+    part: &str,
+    warnings: &mut Option<&mut Vec<String>>,
+) -> Result<bool, XlsxError> {
+    match result {
+        Ok(_) => Ok(true),
+        Err(e @ XlsxError::LimitExceeded(_)) => Err(e),
+        Err(e) => match warnings {
+            Some(list) => {
+                trace_event!(WARN, part, error = %e, "skipped malformed part");
+                list.push(format!("{}: {}", part, e));
+                Ok(false)
+            }
+            None => Err(e),
+        },
+    }
+}
+
 /// read spreadsheet file.
 /// # Arguments
 /// * `path` - file path to read.
@@ -97,6 +283,197 @@ pub fn read<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
     read_reader(file, true)
 }
 
+/// read spreadsheet file in recovery mode.
+/// Unparseable parts (bad drawing xml, missing relationships, ...) are skipped
+/// and reported in `Spreadsheet::get_read_warnings`.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut book = umya_spreadsheet::reader::xlsx::read_with_recovery(path).unwrap();
+/// for warning in book.get_read_warnings() {
+///     println!("{}", warning);
+/// }
+/// ```
+pub fn read_with_recovery<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_with_recovery(file)
+}
+
+/// read spreadsheet from arbitrary reader, reporting the progress.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `progress` - called before the first worksheet and after each worksheet.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader_with_progress<R, F>(reader: R, mut progress: F) -> Result<Spreadsheet, XlsxError>
+where
+    R: io::Read + io::Seek,
+    F: FnMut(&Progress),
+{
+    let mut book = read_reader(reader, false)?;
+    book.read_sheet_collection_with_progress(&mut progress)?;
+    Ok(book)
+}
+
+/// read spreadsheet file, reporting the progress.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `progress` - called before the first worksheet and after each worksheet.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut book = umya_spreadsheet::reader::xlsx::read_with_progress(path, |progress| {
+///     println!("{}/{}", progress.get_parts_processed(), progress.get_parts_total());
+/// })
+/// .unwrap();
+/// ```
+pub fn read_with_progress<P, F>(path: P, progress: F) -> Result<Spreadsheet, XlsxError>
+where
+    P: AsRef<Path>,
+    F: FnMut(&Progress),
+{
+    let file = File::open(path)?;
+    read_reader_with_progress(file, progress)
+}
+
+/// read spreadsheet file with limits.
+/// Use it to read files from untrusted sources.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `limits` - limits to enforce.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is `XlsxError::LimitExceeded` when a limit is exceeded.
+/// # Examples
+/// ```
+/// let mut limits = umya_spreadsheet::ReadLimits::default();
+/// limits.set_max_cell_count(10);
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// if let Err(e) = umya_spreadsheet::reader::xlsx::read_with_limits(path, &limits) {
+///     println!("{}", e);
+/// }
+/// ```
+pub fn read_with_limits<P: AsRef<Path>>(
+    path: P,
+    limits: &ReadLimits,
+) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_with_limits(file, limits)
+}
+
+/// read spreadsheet from arbitrary reader, keeping only the cached result of each formula.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+pub fn read_reader_values_only<R: io::Read + io::Seek>(
+    reader: R,
+) -> Result<Spreadsheet, XlsxError> {
+    let mut book = read_reader(reader, true)?;
+    for worksheet in &mut book {
+        for cell in worksheet {
+            cell.get_cell_value_mut().remove_formula();
+        }
+    }
+    Ok(book)
+}
+
+/// read spreadsheet file, keeping only the cached result of each formula.
+/// Formula cells become plain values, as if pasted as values.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let mut book = umya_spreadsheet::reader::xlsx::read_values_only(path).unwrap();
+/// ```
+pub fn read_values_only<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_values_only(file)
+}
+
+/// read sheet list, defined names and document properties from arbitrary reader.
+/// Only the workbook, the document properties and the head of each worksheet are parsed.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// # Return value
+/// * `Result` - OK is WorkbookMetadata. Err is error message.
+pub fn read_reader_metadata<R: io::Read + io::Seek>(
+    reader: R,
+) -> Result<WorkbookMetadata, XlsxError> {
+    let mut arv = zip::read::ZipArchive::new(reader)?;
+    let mut book = workbook::read(&mut arv)?;
+    doc_props_app::read(&mut arv, &mut book)?;
+    doc_props_core::read(&mut arv, &mut book)?;
+    let workbook_rel = workbook_rels::read(&mut arv, &mut book)?;
+
+    let mut sheets: Vec<SheetMetadata> = Vec::new();
+    let mut defined_names = book.get_defined_names().clone();
+    for sheet in book.get_sheet_collection_no_check() {
+        let mut dimension = None;
+        for (rel_id, _, rel_target) in &workbook_rel {
+            if sheet.get_r_id() == rel_id {
+                dimension = worksheet::read_dimension(&mut arv, rel_target)?;
+            }
+        }
+        sheets.push(SheetMetadata::new(
+            sheet.get_name(),
+            sheet.get_sheet_state(),
+            dimension,
+        ));
+        defined_names.extend(sheet.get_defined_names().iter().cloned());
+    }
+
+    Ok(WorkbookMetadata::new(
+        sheets,
+        defined_names,
+        book.get_properties().clone(),
+    ))
+}
+
+/// read sheet list, defined names and document properties from a file.
+/// It is much faster than `read` for large files, since the cells are not parsed.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is WorkbookMetadata. Err is error message.
+/// # Examples
+/// ```
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let metadata = umya_spreadsheet::reader::xlsx::read_metadata(path).unwrap();
+/// for sheet in metadata.get_sheets() {
+///     println!("{} {:?}", sheet.get_name(), sheet.get_dimension());
+/// }
+/// ```
+pub fn read_metadata<P: AsRef<Path>>(path: P) -> Result<WorkbookMetadata, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_metadata(file)
+}
+
+/// read template file (xltx/xltm) as a regular workbook.
+/// The workbook is written with the workbook content type even if it is saved to a writer.
+/// # Arguments
+/// * `path` - file path to read.
+/// # Return value
+/// * `Result` - OK is Spreadsheet. Err is error message.
+/// # Examples
+/// ```no_run
+/// let path = std::path::Path::new("./tests/test_files/aaa.xltx");
+/// let mut book = umya_spreadsheet::reader::xlsx::read_template(path).unwrap();
+/// ```
+pub fn read_template<P: AsRef<Path>>(path: P) -> Result<Spreadsheet, XlsxError> {
+    let mut book = read(path)?;
+    book.set_is_template(false);
+    Ok(book)
+}
+
 /// lazy read spreadsheet file.
 /// Delays the loading of the worksheet until it is needed.
 /// When loading a file with a large amount of data, response improvement can be expected.
@@ -118,56 +495,172 @@ pub(crate) fn raw_to_deserialize_by_worksheet(
     worksheet: &mut Worksheet,
     shared_string_table: Arc<RwLock<SharedStringTable>>,
     stylesheet: &Stylesheet,
-) {
+    limits: &ReadLimits,
+    warnings: &mut Option<&mut Vec<String>>,
+) -> Result<(), XlsxError> {
     if worksheet.is_deserialized() {
-        return;
+        return Ok(());
     }
+    trace_span!("read_sheet", sheet = worksheet.get_name());
 
     let raw_data_of_worksheet = worksheet.get_raw_data_of_worksheet().clone();
     let shared_string_table = &*shared_string_table.read().unwrap();
-    worksheet::read(
+
+    let result = worksheet::read(
         worksheet,
         &raw_data_of_worksheet,
         shared_string_table,
         stylesheet,
-    )
-    .unwrap();
+        limits,
+    );
+    let target = raw_data_of_worksheet.get_worksheet_file().get_file_target();
+    let (legacy_drawing_hf_rid, control_list) = match result {
+        Ok(v) => v,
+        Err(e) => {
+            recover_part::<()>(Err(e), target, warnings)?;
+            (None, Vec::new())
+        }
+    };
 
     if let Some(v) = raw_data_of_worksheet.get_worksheet_relationships() {
         for relationship in v.get_relationship_list() {
             match relationship.get_type() {
                 // drawing, chart
                 DRAWINGS_NS => {
-                    drawing::read(
-                        worksheet,
-                        relationship.get_raw_file(),
-                        raw_data_of_worksheet.get_drawing_relationships(),
-                    )
-                    .unwrap();
+                    let result = read_part(relationship, || {
+                        drawing::read(
+                            worksheet,
+                            relationship.get_raw_file(),
+                            raw_data_of_worksheet.get_drawing_relationships(),
+                        )?;
+                        // unsupported charts are written back with the parts they refer to.
+                        for chart in worksheet
+                            .get_worksheet_drawing_mut()
+                            .get_chart_collection_mut()
+                        {
+                            let chart_space = chart.get_chart_space_mut();
+                            if let Some(raw_file) = chart_space.get_raw_data() {
+                                let list =
+                                    raw_data_of_worksheet.get_relationships_tree_of_file(raw_file);
+                                chart_space.set_raw_relationships(list);
+                            }
+                        }
+                        Ok(())
+                    });
+                    recover_part(
+                        result,
+                        relationship.get_raw_file().get_file_target(),
+                        warnings,
+                    )?;
                 }
                 // comment
                 COMMENTS_NS => {
-                    comment::read(worksheet, relationship.get_raw_file()).unwrap();
+                    let result = read_part(relationship, || {
+                        comment::read(worksheet, relationship.get_raw_file())
+                    });
+                    recover_part(
+                        result,
+                        relationship.get_raw_file().get_file_target(),
+                        warnings,
+                    )?;
                 }
                 // table
                 TABLE_NS => {
-                    table::read(worksheet, relationship.get_raw_file()).unwrap();
+                    let result = read_part(relationship, || {
+                        table::read(
+                            worksheet,
+                            relationship.get_raw_file(),
+                            raw_data_of_worksheet
+                                .get_relationships_of_file(relationship.get_raw_file()),
+                        )
+                    });
+                    recover_part(
+                        result,
+                        relationship.get_raw_file().get_file_target(),
+                        warnings,
+                    )?;
                 }
                 _ => {}
             }
         }
+        let mut form_control_shape_ids: Vec<u32> = Vec::new();
         for relationship in v.get_relationship_list() {
-            // vmlDrawing
-            if relationship.get_type() == VML_DRAWING_NS {
-                vml_drawing::read(
-                    worksheet,
-                    relationship.get_raw_file(),
-                    raw_data_of_worksheet.get_vml_drawing_relationships(),
-                )
-                .unwrap();
+            // vmlDrawing (header/footer pictures are read with the worksheet)
+            if relationship.get_type() == VML_DRAWING_NS
+                && legacy_drawing_hf_rid.as_deref() != Some(relationship.get_id())
+            {
+                let result = read_part(relationship, || {
+                    vml_drawing::read(
+                        worksheet,
+                        relationship.get_raw_file(),
+                        raw_data_of_worksheet
+                            .get_relationships_of_file(relationship.get_raw_file()),
+                        &mut form_control_shape_ids,
+                    )
+                });
+                recover_part(
+                    result,
+                    relationship.get_raw_file().get_file_target(),
+                    warnings,
+                )?;
             }
         }
+        // form control properties (the control refers to its vml shape and its ctrlProp part)
+        for (control_index, (shape_id, r_id)) in control_list.iter().enumerate() {
+            let index = form_control_shape_ids
+                .iter()
+                .position(|v| v == shape_id)
+                .unwrap_or(control_index);
+            let relationship = match v.get_relationship_by_rid(r_id) {
+                Ok(v) => v,
+                Err(e) => {
+                    recover_part::<()>(Err(e), target, warnings)?;
+                    continue;
+                }
+            };
+            if relationship.get_type() != CTRL_PROP_NS {
+                continue;
+            }
+            let result = read_part(relationship, || {
+                ctrl_prop::read(worksheet, relationship.get_raw_file(), index)
+            });
+            recover_part(
+                result,
+                relationship.get_raw_file().get_file_target(),
+                warnings,
+            )?;
+        }
     }
 
     worksheet.remove_raw_data_of_worksheet();
+    trace_event!(
+        DEBUG,
+        cells = worksheet.get_cell_collection().len(),
+        "read sheet"
+    );
+    Ok(())
+}
+
+fn check_missing_part(part: Option<&str>) -> Result<(), XlsxError> {
+    match part {
+        Some(v) => Err(XlsxError::Format(format!("missing part {}", v))),
+        None => Ok(()),
+    }
+}
+
+fn read_part<F: FnOnce() -> Result<(), XlsxError>>(
+    relationship: &RawRelationship,
+    f: F,
+) -> Result<(), XlsxError> {
+    trace_span!(
+        "read_part",
+        part = relationship.get_raw_file().get_file_target()
+    );
+    if relationship.get_raw_file().get_file_data().is_empty() {
+        return Err(XlsxError::Format(format!(
+            "missing part {}",
+            relationship.get_raw_file().get_file_target()
+        )));
+    }
+    f()
 }
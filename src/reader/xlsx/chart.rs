@@ -19,7 +19,7 @@ pub(crate) fn read(
         reader,
         Event::Start(ref e) => {
             if e.name().into_inner() == b"c:chartSpace" {
-                chart_space.set_attributes(&mut reader, e);
+                chart_space.set_attributes(&mut reader, e)?;
             }
         },
         Event::Eof => break,
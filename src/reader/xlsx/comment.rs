@@ -27,12 +27,12 @@ pub(crate) fn read(
         Event::Start(ref e) => {
             if e.name().into_inner() ==  b"comment" {
                 let mut obj = Comment::default();
-                obj.set_attributes(&mut reader, e, &authors);
+                obj.set_attributes(&mut reader, e, &authors)?;
                 worksheet.add_comments(obj);
             }
         },
         Event::Text(e) => {
-            value = e.unescape().unwrap().to_string();
+            value = e.unescape()?.to_string();
         },
         Event::End(ref e) => {
             if e.name().into_inner() == b"author" {
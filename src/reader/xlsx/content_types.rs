@@ -14,19 +14,32 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     let mut reader = Reader::from_reader(r);
     reader.config_mut().trim_text(true);
     let mut list: Vec<(String, String)> = Vec::new();
+    let mut default_list: Vec<(String, String)> = Vec::new();
 
     xml_read_loop!(
         reader,
         Event::Empty(ref e) => {
             if e.name().into_inner() == b"Override" {
-                let part_name = get_attribute(e, b"PartName").unwrap();
-                let content_type = get_attribute(e, b"ContentType").unwrap();
+                let part_name = get_required_attribute(e, b"PartName")?;
+                let content_type = get_required_attribute(e, b"ContentType")?;
+                if part_name == format!("/{}", PKG_WORKBOOK)
+                    && (content_type == WORKBOOK_TEMPLATE_TYPE
+                        || content_type == WORKBOOK_MACRO_TEMPLATE_TYPE)
+                {
+                    spreadsheet.set_is_template(true);
+                }
                 list.push((part_name, content_type));
             }
+            if e.name().into_inner() == b"Default" {
+                let extension = get_required_attribute(e, b"Extension")?;
+                let content_type = get_required_attribute(e, b"ContentType")?;
+                default_list.push((extension.to_lowercase(), content_type));
+            }
         },
         Event::Eof => break,
     );
 
     spreadsheet.set_backup_context_types(list);
+    spreadsheet.set_backup_default_context_types(default_list);
     Ok(())
 }
@@ -0,0 +1,38 @@
+use super::XlsxError;
+use crate::xml_read_loop;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::result;
+use structs::raw::RawFile;
+use structs::Worksheet;
+
+pub(crate) fn read(
+    worksheet: &mut Worksheet,
+    ctrl_prop_file: &RawFile,
+    index: usize,
+) -> result::Result<(), XlsxError> {
+    let data = std::io::Cursor::new(ctrl_prop_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    xml_read_loop!(
+        reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"formControlPr" {
+                    if let Some(form_control) = worksheet.get_form_controls_mut().get_mut(index) {
+                        form_control.set_control_properties(e)?;
+                    }
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"formControlPr" {
+                    if let Some(form_control) = worksheet.get_form_controls_mut().get_mut(index) {
+                        form_control.set_control_properties(e)?;
+                    }
+                }
+            },
+            Event::Eof => break,
+    );
+
+    Ok(())
+}
@@ -1 +1,43 @@
+use super::driver::*;
+use super::XlsxError;
+use helper::const_str::*;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::{io, result};
 
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::read::ZipArchive<R>,
+) -> result::Result<Vec<(String, String, String)>, XlsxError> {
+    let r = io::BufReader::new(match arv.by_name(ARC_RELS) {
+        Ok(v) => v,
+        Err(zip::result::ZipError::FileNotFound) => {
+            return Ok(Vec::new());
+        }
+        Err(e) => {
+            return Err(e.into());
+        }
+    });
+    let mut reader = Reader::from_reader(r);
+    reader.config_mut().trim_text(true);
+
+    let mut result: Vec<(String, String, String)> = Vec::new();
+
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"Relationship" {
+                let id_value = get_required_attribute(e, b"Id")?;
+                let type_value = normalize_relationship_type(get_required_attribute(e, b"Type")?);
+                let target_value = get_required_attribute(e, b"Target")?;
+                let target_value = target_value
+                    .strip_prefix('/')
+                    .map(|t| t.to_owned())
+                    .unwrap_or(target_value);
+                result.push((id_value, type_value, target_value));
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(result)
+}
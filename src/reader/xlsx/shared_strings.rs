@@ -1,16 +1,19 @@
 use crate::xml_read_loop;
 
+use super::driver::*;
 use super::XlsxError;
 use helper::const_str::*;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::{io, result};
+use structs::ReadLimits;
 use structs::SharedStringTable;
 use structs::Spreadsheet;
 
 pub(crate) fn read<R: io::Read + io::Seek>(
     arv: &mut zip::ZipArchive<R>,
     spreadsheet: &mut Spreadsheet,
+    limits: &ReadLimits,
 ) -> result::Result<(), XlsxError> {
     let r = io::BufReader::new(match arv.by_name(PKG_SHARED_STRINGS) {
         Ok(v) => v,
@@ -29,7 +32,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         Event::Start(ref e) => {
             if e.name().into_inner() == b"sst" {
                 let mut obj = SharedStringTable::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e, limits)?;
                 spreadsheet.set_shared_string_table(obj);
             }
         },
@@ -1,5 +1,6 @@
 use crate::xml_read_loop;
 
+use super::driver::*;
 use super::XlsxError;
 use helper::const_str::*;
 use quick_xml::events::Event;
@@ -23,7 +24,7 @@ pub fn read<R: io::Read + io::Seek>(
         Event::Start(ref e) => {
             if e.name().into_inner() == b"styleSheet" {
                 let mut obj = Stylesheet::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 obj.make_style();
                 spreadsheet.set_stylesheet(obj);
             }
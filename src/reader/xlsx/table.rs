@@ -1,16 +1,19 @@
 use super::driver::*;
 use super::XlsxError;
+use helper::const_str::*;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::result;
 use structs::raw::RawFile;
+use structs::raw::RawRelationships;
 use structs::Comment;
 use structs::Worksheet;
-use structs::{Table, TableColumn, TableStyleInfo};
+use structs::{QueryTable, Table, TableColumn, TableStyleInfo};
 
 pub(crate) fn read(
     worksheet: &mut Worksheet,
     table_file: &RawFile,
+    table_relationships: Option<&RawRelationships>,
 ) -> result::Result<(), XlsxError> {
     let data = std::io::Cursor::new(table_file.get_file_data());
     let mut reader = Reader::from_reader(data);
@@ -28,6 +31,14 @@ pub(crate) fn read(
                                 b"name" => {
                                     table_column.set_name(get_attribute_value(attr)?);
                                 }
+                                b"uniqueName" => {
+                                    table_column.set_unique_name(get_attribute_value(attr)?);
+                                }
+                                b"queryTableFieldId" => {
+                                    if let Ok(v) = get_attribute_value(attr)?.parse::<u32>() {
+                                        table_column.set_query_table_field_id(v);
+                                    }
+                                }
                                 _ => {}
                             },
                             _ => {}
@@ -111,11 +122,21 @@ pub(crate) fn read(
                 _ => (),
             },
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(e.into()),
             _ => (),
         }
         buf.clear();
     }
+    // query table
+    if let Some(relationships) = table_relationships {
+        for relationship in relationships.get_relationship_list() {
+            if relationship.get_type() == QUERY_TABLE_NS {
+                let mut query_table = QueryTable::default();
+                query_table.set_raw_data(relationship.get_raw_file().get_file_data())?;
+                table.set_query_table(query_table);
+            }
+        }
+    }
     // add the table to the sheet (if a few sanity checks pass)
     if table.is_ok() {
         worksheet.add_table(table);
@@ -1,3 +1,4 @@
+use super::driver::*;
 use super::XlsxError;
 use crate::xml_read_loop;
 use quick_xml::events::Event;
@@ -19,7 +20,7 @@ pub fn read<R: io::Read + io::Seek>(
         reader,
         Event::Start(ref e) => {
             if e.name().into_inner() == b"a:theme" {
-                theme.set_attributes(&mut reader, e);
+                theme.set_attributes(&mut reader, e)?;
             }
         },
         Event::Eof => break,
@@ -1,17 +1,27 @@
+use super::driver::*;
 use super::XlsxError;
 use crate::xml_read_loop;
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io::Cursor;
 use std::result;
+use std::str::FromStr;
 use structs::raw::RawFile;
 use structs::raw::RawRelationships;
+use structs::vml::spreadsheet::ObjectValues;
 use structs::vml::Shape;
+use structs::vml::ShapeTypeValues;
+use structs::FormControl;
+use structs::HeaderFooterImage;
+use structs::HeaderFooterImagePositionValues;
 use structs::Worksheet;
 
 pub(crate) fn read(
     worksheet: &mut Worksheet,
     drawing_file: &RawFile,
     drawing_relationships: Option<&RawRelationships>,
+    form_control_shape_ids: &mut Vec<u32>,
 ) -> result::Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.get_file_data());
     let mut reader = Reader::from_reader(data);
@@ -19,32 +29,133 @@ pub(crate) fn read(
 
     let mut ole_index = 0;
     let mut comment_index = 0;
+    let mut shape_types: Vec<(String, String)> = Vec::new();
 
     xml_read_loop!(
         reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"v:shape" {
+                    let shape_id = match get_attribute(e, b"o:spid")? {
+                        Some(v) => Some(v),
+                        None => get_attribute(e, b"id")?,
+                    }
+                    .and_then(|v| v.trim_start_matches("_x0000_s").parse::<u32>().ok());
                     let mut obj = Shape::default();
-                    obj.set_attributes(&mut reader, e, drawing_relationships);
-                    match obj.get_client_data().get_comment_column_target() {
-                        Some(_) => {
-                            worksheet
-                                .get_comments_mut()
-                                .get_mut(comment_index)
-                                .map(|comment| comment.set_shape(obj));
-                            comment_index += 1;
+                    obj.set_attributes(&mut reader, e, drawing_relationships)?;
+                    let client_data = obj.get_client_data();
+                    if client_data.is_form_control() {
+                        form_control_shape_ids.push(shape_id.unwrap_or_default());
+                        let mut form_control = FormControl::default();
+                        form_control.set_vml_shape(&obj);
+                        worksheet.add_form_control(form_control);
+                    } else if client_data.get_comment_column_target().is_some() {
+                        if let Some(comment) = worksheet.get_comments_mut().get_mut(comment_index) {
+                            comment.set_shape(obj);
+                        }
+                        comment_index += 1;
+                    } else if client_data.get_object_type() == &ObjectValues::Picture
+                        && ole_index < worksheet.get_ole_objects().get_ole_object().len()
+                    {
+                        worksheet.get_ole_objects_mut().get_ole_object_mut()[ole_index]
+                            .set_shape(obj);
+                        ole_index += 1;
+                    } else {
+                        // Shapes without a model are kept as read.
+                        let type_id = obj.get_type().trim_start_matches('#');
+                        if let Some((_, xml)) = shape_types.iter().find(|(id, _)| id == type_id) {
+                            obj.set_shape_type_xml(xml.as_str());
+                        }
+                        worksheet.add_vml_shape(obj);
+                    }
+                } else if e.name().into_inner() == b"v:shapetype" {
+                    if let Some(id) = get_attribute(e, b"id")? {
+                        if ShapeTypeValues::from_str(&id).is_err() {
+                            let xml = read_raw_element(&mut reader, e)?;
+                            shape_types.push((id, xml));
+                        }
+                    }
+                }
+            },
+            Event::Eof => break,
+    );
+
+    Ok(())
+}
+
+/// Copy an element and its children as they are written in the part.
+fn read_raw_element<R: std::io::BufRead>(
+    reader: &mut Reader<R>,
+    e: &BytesStart,
+) -> result::Result<String, XlsxError> {
+    let mut writer = Writer::new(Cursor::new(Vec::new()));
+    writer.write_event(Event::Start(e.borrow()));
+    let mut depth = 1;
+    let mut buf = Vec::new();
+    while depth > 0 {
+        let ev = reader.read_event_into(&mut buf)?;
+        match ev {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            Event::Eof => return Err(eof_error("v:shapetype")),
+            _ => {}
+        }
+        writer.write_event(ev);
+        buf.clear();
+    }
+    Ok(String::from_utf8_lossy(&writer.into_inner().into_inner()).into_owned())
+}
+
+pub(crate) fn read_header_footer(
+    worksheet: &mut Worksheet,
+    drawing_file: &RawFile,
+    drawing_relationships: Option<&RawRelationships>,
+) -> result::Result<(), XlsxError> {
+    let data = std::io::Cursor::new(drawing_file.get_file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    let mut image: Option<HeaderFooterImage> = None;
+
+    xml_read_loop!(
+        reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"v:shape" {
+                    let mut obj = HeaderFooterImage::default();
+                    if let Some(v) = get_attribute(e, b"id")? {
+                        if let Ok(position) = HeaderFooterImagePositionValues::from_str(&v) {
+                            obj.set_position(position);
                         }
-                        None => {
-                            worksheet
-                                .get_ole_objects_mut()
-                                .get_ole_object_mut()
-                                .get_mut(ole_index)
-                                .map(|ole_obj| ole_obj.set_shape(obj));
-                            ole_index += 1;
+                    }
+                    if let Some(v) = get_attribute(e, b"style")? {
+                        obj.set_style(&v);
+                    }
+                    image = Some(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"v:imagedata" {
+                    if let Some(obj) = image.as_mut() {
+                        if let Some(v) = get_attribute(e, b"o:title")? {
+                            obj.set_title(v);
+                        }
+                        if let (Some(relid), Some(rels)) =
+                            (get_attribute(e, b"o:relid")?, drawing_relationships)
+                        {
+                            let raw_file = rels.get_relationship_by_rid(&relid)?.get_raw_file();
+                            obj.get_image_mut()
+                                .set_image_name(raw_file.get_file_name())
+                                .set_image_data(raw_file.get_file_data().clone());
                         }
                     }
                 }
             },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"v:shape" {
+                    if let Some(obj) = image.take() {
+                        worksheet.get_header_footer_mut().add_image(obj);
+                    }
+                }
+            },
             Event::Eof => break,
     );
 
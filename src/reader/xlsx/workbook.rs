@@ -8,6 +8,7 @@ use quick_xml::Reader;
 use std::{io, result};
 
 use helper::const_str::*;
+use structs::CustomWorkbookView;
 use structs::DefinedName;
 use structs::Spreadsheet;
 use structs::WorkbookProtection;
@@ -30,37 +31,70 @@ pub(crate) fn read<R: io::Read + io::Seek>(
             match e.name().into_inner() {
                 b"workbookView" => {
                     let mut obj = WorkbookView::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     spreadsheet.set_workbook_view(obj);
                 }
+                b"customWorkbookView" => {
+                    let mut obj = CustomWorkbookView::default();
+                    obj.set_attributes(&mut reader, e, true)?;
+                    spreadsheet.add_custom_workbook_view(obj);
+                }
                 b"workbookProtection" => {
                     let mut obj = WorkbookProtection::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     spreadsheet.set_workbook_protection(obj);
                 }
                 b"sheet" => {
-                    let name_value = get_attribute(e, b"name").unwrap();
-                    let sheet_id_value = get_attribute(e, b"sheetId").unwrap();
-                    let r_id_value = get_attribute(e, b"r:id").unwrap();
+                    let name_value = get_required_attribute(e, b"name")?;
+                    let sheet_id_value = get_required_attribute(e, b"sheetId")?;
+                    let r_id_value = get_required_attribute(e, b"r:id")?;
                     let mut worksheet = Worksheet::default();
-                    worksheet.set_name(escape::unescape(&name_value).unwrap());
+                    worksheet.set_name(
+                        escape::unescape(&name_value).map_err(quick_xml::Error::from)?,
+                    );
                     worksheet.set_sheet_id(sheet_id_value);
                     worksheet.set_r_id(r_id_value);
-                    spreadsheet.add_sheet(worksheet);
+                    if let Some(v) = get_attribute(e, b"state")? {
+                        worksheet.set_sheet_state(v);
+                    }
+                    spreadsheet
+                        .add_sheet(worksheet)
+                        .map_err(|e| XlsxError::Format(e.to_string()))?;
+                }
+                b"workbookPr" => {
+                    if let Some(v) = get_attribute(e, b"codeName")? {
+                        spreadsheet.set_code_name(v);
+                    }
+                }
+                b"calcPr" => {
+                    if let Some(v) = get_attribute(e, b"fullPrecision")? {
+                        spreadsheet.set_precision_as_displayed(v == "0" || v == "false");
+                    }
+                    if let Some(v) = get_attribute(e, b"fullCalcOnLoad")? {
+                        spreadsheet.set_force_full_calculation(v == "1" || v == "true");
+                    }
                 }
                 b"pivotCache" => {
-                    let cache_id = get_attribute(e, b"cacheId").unwrap();
-                    let r_id = get_attribute(e, b"r:id").unwrap();
+                    let cache_id = get_required_attribute(e, b"cacheId")?;
+                    let r_id = get_required_attribute(e, b"r:id")?;
                     spreadsheet.add_pivot_caches((r_id, cache_id, String::from("")));
                 }
                 _ => (),
             }
         },
         Event::Start(ref e) => {
-            if e.name().into_inner() == b"definedName" {
-                let mut obj = DefinedName::default();
-                obj.set_attributes(&mut reader, e);
-                defined_names.push(obj);
+            match e.name().into_inner() {
+                b"definedName" => {
+                    let mut obj = DefinedName::default();
+                    obj.set_attributes(&mut reader, e)?;
+                    defined_names.push(obj);
+                }
+                b"customWorkbookView" => {
+                    let mut obj = CustomWorkbookView::default();
+                    obj.set_attributes(&mut reader, e, false)?;
+                    spreadsheet.add_custom_workbook_view(obj);
+                }
+                _ => (),
             }
         },
         Event::Eof => break
@@ -71,7 +105,12 @@ pub(crate) fn read<R: io::Read + io::Seek>(
             let local_sheet_id = defined_name.get_local_sheet_id().clone() as usize;
             spreadsheet
                 .get_sheet_mut(&local_sheet_id)
-                .unwrap()
+                .ok_or_else(|| {
+                    XlsxError::Format(format!(
+                        "definedName `{}` refers to a missing sheet",
+                        defined_name.get_name()
+                    ))
+                })?
                 .add_defined_names(defined_name.clone());
         } else {
             spreadsheet.add_defined_names(defined_name.clone());
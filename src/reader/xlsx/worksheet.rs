@@ -1,19 +1,24 @@
 use super::driver::*;
+use super::vml_drawing;
 use super::XlsxError;
 use hashbrown::HashMap;
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
+use std::io;
 
 use helper::formula::*;
 use structs::office2010::excel::DataValidations as DataValidations2010;
 use structs::raw::RawRelationships;
 use structs::raw::RawWorksheet;
+use structs::AutoFilter;
 use structs::Cells;
 use structs::Columns;
 use structs::ConditionalFormatting;
 use structs::DataValidations;
 use structs::Hyperlink;
+use structs::MediaObject;
 use structs::OleObjects;
+use structs::ReadLimits;
 use structs::Row;
 use structs::SharedStringTable;
 use structs::SheetProtection;
@@ -25,11 +30,15 @@ pub(crate) fn read(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
-) -> Result<(), XlsxError> {
+    limits: &ReadLimits,
+) -> Result<(Option<String>, Vec<(u32, String)>), XlsxError> {
     let data = std::io::Cursor::new(raw_data_of_worksheet.get_worksheet_file().get_file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
     let mut formula_shared_list: HashMap<u32, (String, Vec<FormulaToken>)> = HashMap::new();
+    let mut legacy_drawing_hf_rid: Option<String> = None;
+    // (shapeId, r:id) of the form controls
+    let mut control_list: Vec<(u32, String)> = Vec::new();
     xml_read_loop!(
         reader,
         Event::Start(ref e) => match e.name().into_inner() {
@@ -43,27 +52,25 @@ pub(crate) fn read(
                         Err(_) => {}
                     }
                 }
+                worksheet
+                    .get_sheet_properties_mut()
+                    .set_attributes(&mut reader, e)?;
             }
             b"sheetViews" => {
                 worksheet
                     .get_sheet_views_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
+                worksheet.set_active_cell_from_sheet_views();
             }
             b"sheetFormatPr" => {
                 worksheet
                     .get_sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
-            b"selection" => {
-                for a in e.attributes().with_checks(false) {
-                    match a {
-                        Ok(ref attr) if attr.key.0 == b"activeCell" => {
-                            worksheet.set_active_cell(get_attribute_value(attr)?);
-                        }
-                        Ok(_) => {}
-                        Err(_) => {}
-                    }
-                }
+            b"customSheetViews" => {
+                worksheet
+                    .get_custom_sheet_views_mut()
+                    .set_attributes(&mut reader, e)?;
             }
             b"row" => {
                 let mut obj = Row::default();
@@ -75,35 +82,39 @@ pub(crate) fn read(
                     stylesheet,
                     &mut formula_shared_list,
                     false,
-                );
+                    limits,
+                )?;
                 worksheet.set_row_dimension(obj);
+                limits.check_cell_count(worksheet.get_collection_to_hashmap().len())?;
             }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, false)?;
+                worksheet.set_auto_filter_crate(obj);
             }
             b"cols" => {
                 let mut obj = Columns::default();
-                obj.set_attributes(&mut reader, e, stylesheet);
+                obj.set_attributes(&mut reader, e, stylesheet)?;
                 worksheet.set_column_dimensions_crate(obj);
             }
             b"mergeCells" => {
                 worksheet
                     .get_merge_cells_crate_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"conditionalFormatting" => {
                 let mut obj = ConditionalFormatting::default();
-                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats());
+                obj.set_attributes(&mut reader, e, stylesheet.get_differential_formats())?;
                 worksheet.add_conditional_formatting_collection(obj);
             }
             b"dataValidations" => {
                 let mut obj = DataValidations::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.set_data_validations(obj);
             }
             b"x14:dataValidations" => {
                 let mut obj = DataValidations2010::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.set_data_validations_2010(obj);
             }
             b"oleObjects" => {
@@ -111,25 +122,33 @@ pub(crate) fn read(
                 obj.set_attributes(
                     &mut reader,
                     e,
-                    raw_data_of_worksheet.get_worksheet_relationships().unwrap(),
-                );
+                    raw_data_of_worksheet
+                        .get_worksheet_relationships()
+                        .ok_or_else(|| XlsxError::Format("missing relationships of oleObjects".to_string()))?,
+                )?;
                 worksheet.set_ole_objects(obj);
             }
             b"headerFooter" => {
                 worksheet
                     .get_header_footer_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"rowBreaks" => {
                 worksheet
                     .get_row_breaks_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"colBreaks" => {
                 worksheet
                     .get_column_breaks_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
+            b"ignoredErrors" => {
+                worksheet
+                    .get_ignored_errors_mut()
+                    .set_attributes(&mut reader, e)?;
+            }
+            b"control" => push_control(&mut control_list, e)?,
             _ => (),
         },
         Event::Empty(ref e) => match e.name().into_inner() {
@@ -143,27 +162,19 @@ pub(crate) fn read(
                         Err(_) => {}
                     }
                 }
+                worksheet
+                    .get_sheet_properties_mut()
+                    .set_attributes(&mut reader, e)?;
             }
             b"tabColor" => {
                 worksheet
                     .get_tab_color_mut()
-                    .set_attributes(&mut reader, e, true);
+                    .set_attributes(&mut reader, e, true)?;
             }
             b"sheetFormatPr" => {
                 worksheet
                     .get_sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
-            }
-            b"selection" => {
-                for a in e.attributes().with_checks(false) {
-                    match a {
-                        Ok(ref attr) if attr.key.0 == b"activeCell" => {
-                            worksheet.set_active_cell(get_attribute_value(attr)?);
-                        }
-                        Ok(_) => {}
-                        Err(_) => {}
-                    }
-                }
+                    .set_attributes(&mut reader, e)?;
             }
             b"row" => {
                 let mut obj = Row::default();
@@ -175,46 +186,90 @@ pub(crate) fn read(
                     stylesheet,
                     &mut formula_shared_list,
                     true,
-                );
+                    limits,
+                )?;
                 worksheet.set_row_dimension(obj);
+                limits.check_cell_count(worksheet.get_collection_to_hashmap().len())?;
             }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, true)?;
+                worksheet.set_auto_filter_crate(obj);
             }
             b"pageMargins" => {
                 worksheet
                     .get_page_margins_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"hyperlink" => {
-                let (coor, hyperlink) = get_hyperlink(e, raw_data_of_worksheet.get_worksheet_relationships());
+                let (coor, hyperlink) = get_hyperlink(e, raw_data_of_worksheet.get_worksheet_relationships())?;
                 worksheet.get_cell_mut(coor).set_hyperlink(hyperlink);
             }
             b"printOptions" => {
                 worksheet
                     .get_print_options_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"pageSetup" => {
                 worksheet.get_page_setup_mut().set_attributes(
                     &mut reader,
                     e,
                     raw_data_of_worksheet.get_worksheet_relationships(),
-                );
+                )?;
             }
             b"sheetProtection" => {
                 let mut obj = SheetProtection::default();
                 obj.set_attributes(
                     &mut reader,
                     e,
-                );
+                )?;
                 worksheet.set_sheet_protection(obj);
             }
+            b"picture" => {
+                if let Some(r_id) = get_attribute(e, b"r:id")? {
+                    if let Some(rels) = raw_data_of_worksheet.get_worksheet_relationships() {
+                        let raw_file = rels.get_relationship_by_rid(&r_id)?.get_raw_file();
+                        let mut obj = MediaObject::default();
+                        obj.set_image_name(raw_file.get_file_name())
+                            .set_image_data(raw_file.get_file_data().clone());
+                        worksheet.set_background_image_crate(obj);
+                    }
+                }
+            }
+            b"legacyDrawingHF" => {
+                if let Some(r_id) = get_attribute(e, b"r:id")? {
+                    if let Some(rels) = raw_data_of_worksheet.get_worksheet_relationships() {
+                        let relationship = rels.get_relationship_by_rid(&r_id)?;
+                        vml_drawing::read_header_footer(
+                            worksheet,
+                            relationship.get_raw_file(),
+                            raw_data_of_worksheet
+                                .get_relationships_of_file(relationship.get_raw_file()),
+                        )?;
+                    }
+                    legacy_drawing_hf_rid = Some(r_id);
+                }
+            }
+            b"control" => push_control(&mut control_list, e)?,
             _ => (),
         },
         Event::Eof => break,
     );
 
+    Ok((legacy_drawing_hf_rid, control_list))
+}
+
+fn push_control(control_list: &mut Vec<(u32, String)>, e: &BytesStart) -> Result<(), XlsxError> {
+    // mc:Fallback repeats the control of mc:Choice.
+    if let (Some(shape_id), Some(r_id)) =
+        (get_attribute(e, b"shapeId")?, get_attribute(e, b"r:id")?)
+    {
+        if let Ok(shape_id) = shape_id.parse::<u32>() {
+            if !control_list.iter().any(|(_, v)| v == &r_id) {
+                control_list.push((shape_id, r_id));
+            }
+        }
+    }
     Ok(())
 }
 
@@ -242,7 +297,8 @@ pub(crate) fn read_lite(
                     stylesheet,
                     &mut formula_shared_list,
                     false,
-                );
+                    &ReadLimits::default(),
+                )?;
             }
         },
         Event::Empty(ref e) => {
@@ -256,7 +312,8 @@ pub(crate) fn read_lite(
                     stylesheet,
                     &mut formula_shared_list,
                     true,
-                );
+                    &ReadLimits::default(),
+                )?;
             }
         },
         Event::Eof => break,
@@ -265,21 +322,51 @@ pub(crate) fn read_lite(
     Ok(cells)
 }
 
+/// Reads the `dimension` of a worksheet part.
+/// Parsing stops at `sheetData`, so the cells are not read.
+pub(crate) fn read_dimension<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+) -> Result<Option<String>, XlsxError> {
+    let r = io::BufReader::new(arv.by_name(&join_paths("xl", target))?);
+    let mut reader = Reader::from_reader(r);
+    reader.config_mut().trim_text(true);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) => match e.name().into_inner() {
+                b"dimension" => return Ok(get_attribute(e, b"ref")?),
+                b"sheetData" => return Ok(None),
+                _ => (),
+            },
+            Ok(Event::Eof) => return Ok(None),
+            Err(e) => return Err(e.into()),
+            _ => (),
+        }
+        buf.clear();
+    }
+}
+
 fn get_hyperlink(
     e: &quick_xml::events::BytesStart<'_>,
     raw_relationships: Option<&RawRelationships>,
-) -> (String, Hyperlink) {
+) -> Result<(String, Hyperlink), XlsxError> {
     let mut hyperlink = Hyperlink::default();
     let mut rid = String::from("");
 
-    let coordition = get_attribute(e, b"ref").unwrap_or_default();
-    if let Some(v) = get_attribute(e, b"location") {
+    let coordition = get_attribute(e, b"ref")?.unwrap_or_default();
+    if let Some(v) = get_attribute(e, b"location")? {
         hyperlink.set_url(v);
         hyperlink.set_location(true);
     }
-    if let Some(v) = get_attribute(e, b"r:id") {
-        let relationship = raw_relationships.unwrap().get_relationship_by_rid(&v);
+    if let Some(v) = get_attribute(e, b"r:id")? {
+        let relationship = raw_relationships
+            .ok_or_else(|| XlsxError::Format(format!("not found relationship as {}.", v)))?
+            .get_relationship_by_rid(&v)?;
         hyperlink.set_url(relationship.get_target());
     }
-    (coordition, hyperlink)
+    if let Some(v) = get_attribute(e, b"tooltip")? {
+        hyperlink.set_tooltip(v);
+    }
+    Ok((coordition, hyperlink))
 }
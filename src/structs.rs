@@ -94,6 +94,12 @@ pub use self::coordinate::*;
 mod range;
 pub use self::range::*;
 
+mod cell_range;
+pub use self::cell_range::*;
+
+mod clear_mode;
+pub use self::clear_mode::*;
+
 mod conditional_formatting;
 pub use self::conditional_formatting::*;
 
@@ -142,6 +148,9 @@ pub use self::int64_value::*;
 mod row;
 pub use self::row::*;
 
+mod row_mut;
+pub use self::row_mut::*;
+
 mod font_name;
 pub use self::font_name::*;
 
@@ -245,7 +254,16 @@ mod text;
 pub(crate) use self::text::*;
 
 mod phonetic_run;
-pub(crate) use self::phonetic_run::*;
+pub use self::phonetic_run::*;
+
+mod phonetic_properties;
+pub use self::phonetic_properties::*;
+
+mod phonetic_values;
+pub use self::phonetic_values::*;
+
+mod phonetic_alignment_values;
+pub use self::phonetic_alignment_values::*;
 
 mod gradient_fill;
 pub use self::gradient_fill::*;
@@ -346,6 +364,12 @@ pub use self::row_breaks::*;
 mod column_breaks;
 pub use self::column_breaks::*;
 
+mod ignored_error;
+pub use self::ignored_error::*;
+
+mod ignored_errors;
+pub use self::ignored_errors::*;
+
 mod sheet_view_values;
 pub use self::sheet_view_values::*;
 
@@ -364,9 +388,69 @@ pub(crate) use self::media_object::*;
 mod csv_writer_option;
 pub use self::csv_writer_option::*;
 
+mod locale;
+pub use self::locale::*;
+
+mod merge_option;
+pub use self::merge_option::*;
+
+mod progress;
+pub use self::progress::*;
+
+mod part_size;
+pub use self::part_size::*;
+
+mod size_estimate;
+pub use self::size_estimate::*;
+
+mod read_limits;
+pub use self::read_limits::*;
+
+mod custom_sheet_view;
+pub use self::custom_sheet_view::*;
+
+mod custom_sheet_views;
+pub use self::custom_sheet_views::*;
+
+mod filter_column;
+pub use self::filter_column::*;
+
+mod filters;
+pub use self::filters::*;
+
+mod filter;
+pub use self::filter::*;
+
+mod date_group_item;
+pub use self::date_group_item::*;
+
+mod custom_filters;
+pub use self::custom_filters::*;
+
+mod custom_filter;
+pub use self::custom_filter::*;
+
+mod top10;
+pub use self::top10::*;
+
+mod dynamic_filter;
+pub use self::dynamic_filter::*;
+
+mod color_filter;
+pub use self::color_filter::*;
+
+mod icon_filter;
+pub use self::icon_filter::*;
+
+mod custom_workbook_view;
+pub use self::custom_workbook_view::*;
+
 mod csv_encode_values;
 pub use self::csv_encode_values::*;
 
+mod csv_format_values;
+pub use self::csv_format_values::*;
+
 mod cell_raw_value;
 pub use self::cell_raw_value::*;
 
@@ -400,6 +484,21 @@ pub use self::formula::*;
 mod table;
 pub use self::table::*;
 
+mod query_table;
+pub use self::query_table::*;
+
+mod connection;
+pub use self::connection::*;
+
+mod metadata;
+pub use self::metadata::*;
+
+mod metadata_record;
+pub use self::metadata_record::*;
+
+mod template_values;
+pub use self::template_values::*;
+
 mod data_validation_values;
 pub use self::data_validation_values::*;
 
@@ -415,6 +514,9 @@ pub use self::data_validations::*;
 mod sheet_format_properties;
 pub use self::sheet_format_properties::*;
 
+mod sheet_properties;
+pub use self::sheet_properties::*;
+
 mod sheet_protection;
 pub use self::sheet_protection::*;
 
@@ -426,3 +528,57 @@ pub use self::cell_formula::*;
 
 mod cell_formula_values;
 pub use self::cell_formula_values::*;
+
+mod xlsx_writer_option;
+pub use self::xlsx_writer_option::*;
+
+mod calc_chain_values;
+pub use self::calc_chain_values::*;
+
+mod compatibility_values;
+pub use self::compatibility_values::*;
+
+mod header_footer_image_position_values;
+pub use self::header_footer_image_position_values::*;
+
+mod header_footer_image;
+pub use self::header_footer_image::*;
+
+mod text_box;
+pub use self::text_box::*;
+
+mod shape_type;
+pub use self::shape_type::*;
+
+mod connector_type;
+pub use self::connector_type::*;
+
+mod arrow_type;
+pub use self::arrow_type::*;
+
+mod form_control_values;
+pub use self::form_control_values::*;
+
+mod form_control;
+pub use self::form_control::*;
+
+mod validation_issue;
+pub use self::validation_issue::*;
+
+mod drawing_kind;
+pub use self::drawing_kind::*;
+
+mod drawings;
+pub use self::drawings::*;
+
+mod sheet_metadata;
+pub use self::sheet_metadata::*;
+
+mod workbook_metadata;
+pub use self::workbook_metadata::*;
+
+mod format_snapshot;
+pub use self::format_snapshot::*;
+
+mod column_stats;
+pub use self::column_stats::*;
@@ -5,7 +5,7 @@ use helper::coordinate::*;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWithSheet;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Address {
     sheet_name: String,
     range: Range,
@@ -70,11 +70,12 @@ impl Alignment {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, horizontal, "horizontal");
         set_string_from_xml!(self, e, vertical, "vertical");
         set_string_from_xml!(self, e, wrap_text, "wrapText");
-        set_string_from_xml!(self, e, text_rotation, "textRotation");
+        set_number_from_xml!(self, e, text_rotation, "textRotation");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
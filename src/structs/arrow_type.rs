@@ -0,0 +1,42 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ArrowType {
+    None,
+    Triangle,
+    Stealth,
+    Diamond,
+    Oval,
+    Arrow,
+}
+impl Default for ArrowType {
+    fn default() -> Self {
+        Self::None
+    }
+}
+impl EnumTrait for ArrowType {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::None => "none",
+            Self::Triangle => "triangle",
+            Self::Stealth => "stealth",
+            Self::Diamond => "diamond",
+            Self::Oval => "oval",
+            Self::Arrow => "arrow",
+        }
+    }
+}
+impl FromStr for ArrowType {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(Self::None),
+            "triangle" => Ok(Self::Triangle),
+            "stealth" => Ok(Self::Stealth),
+            "diamond" => Ok(Self::Diamond),
+            "oval" => Ok(Self::Oval),
+            "arrow" => Ok(Self::Arrow),
+            _ => Err(()),
+        }
+    }
+}
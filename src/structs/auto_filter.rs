@@ -1,9 +1,18 @@
+// autoFilter
+use super::FilterColumn;
 use super::Range;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
 use traits::AdjustmentCoordinate;
+use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
 pub struct AutoFilter {
     range: Range,
+    filter_column: Vec<FilterColumn>,
 }
 
 impl AutoFilter {
@@ -20,6 +29,77 @@ impl AutoFilter {
         range.set_range(value.into());
         self.range = range;
     }
+
+    pub fn get_filter_column_list(&self) -> &Vec<FilterColumn> {
+        &self.filter_column
+    }
+
+    pub fn get_filter_column_list_mut(&mut self) -> &mut Vec<FilterColumn> {
+        &mut self.filter_column
+    }
+
+    pub fn add_filter_column(&mut self, value: FilterColumn) -> &mut Self {
+        self.filter_column.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        self.set_range(get_attribute(e, b"ref")?.unwrap_or_default());
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    let mut obj = FilterColumn::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.add_filter_column(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    let mut obj = FilterColumn::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.add_filter_column(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"autoFilter" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("autoFilter"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // autoFilter
+        let is_empty = self.filter_column.is_empty();
+        write_start_tag(
+            writer,
+            "autoFilter",
+            vec![("ref", &self.range.get_range())],
+            is_empty,
+        );
+        if is_empty {
+            return;
+        }
+
+        // filterColumn
+        for filter_column in &self.filter_column {
+            filter_column.write_to(writer);
+        }
+
+        write_end_tag(writer, "autoFilter");
+    }
 }
 impl AdjustmentCoordinate for AutoFilter {
     fn adjustment_insert_coordinate(
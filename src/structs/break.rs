@@ -48,11 +48,12 @@ impl Break {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, id, "id");
-        set_string_from_xml!(self, e, max, "max");
-        set_string_from_xml!(self, e, min, "min");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, id, "id");
+        set_number_from_xml!(self, e, max, "max");
+        set_number_from_xml!(self, e, min, "min");
         set_string_from_xml!(self, e, manual_page_break, "man");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
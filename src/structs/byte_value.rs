@@ -1,4 +1,6 @@
-#[derive(Clone, Default, Debug)]
+use XlsxError;
+
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ByteValue {
     value: Option<u8>,
 }
@@ -19,8 +21,15 @@ impl ByteValue {
         self
     }
 
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut ByteValue {
-        self.set_value(value.into().parse::<u8>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut ByteValue, XlsxError> {
+        let value = value.into();
+        match value.parse::<u8>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn has_value(&self) -> bool {
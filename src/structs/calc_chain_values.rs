@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CalcChainValues {
+    Omit,
+    Regenerate,
+    ForceRecalculation,
+}
+impl Default for CalcChainValues {
+    fn default() -> Self {
+        Self::Omit
+    }
+}
+impl EnumTrait for CalcChainValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Omit => "omit",
+            Self::Regenerate => "regenerate",
+            Self::ForceRecalculation => "force_recalculation",
+        }
+    }
+}
+impl FromStr for CalcChainValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "omit" => Ok(Self::Omit),
+            "regenerate" => Ok(Self::Regenerate),
+            "force_recalculation" => Ok(Self::ForceRecalculation),
+            _ => Err(()),
+        }
+    }
+}
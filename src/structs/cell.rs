@@ -1,20 +1,29 @@
 use hashbrown::HashMap;
+use helper::date::convert_iso_8601;
 use helper::formula::*;
 use helper::number_format::*;
+use helper::ole::detect_file_type;
+use helper::value_detect::*;
+use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::borrow::Cow;
-use std::io::Cursor;
+use std::io;
 use std::sync::{Arc, RwLock};
 use structs::CellFormula;
 use structs::CellFormulaValues;
 use structs::CellRawValue;
 use structs::CellValue;
 use structs::Coordinate;
+use structs::Font;
 use structs::Hyperlink;
+use structs::Locale;
+use structs::MediaObject;
 use structs::NumberingFormat;
+use structs::PhoneticRun;
+use structs::ReadLimits;
 use structs::RichText;
 use structs::SharedStringItem;
 use structs::SharedStringTable;
@@ -23,15 +32,19 @@ use structs::Stylesheet;
 use structs::UInt32Value;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWith2Sheet;
+use traits::IntoCellValue;
+use traits::IntoSerialNumber;
 use writer::driver::*;
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct Cell {
     coordinate: Coordinate,
     pub(crate) cell_value: CellValue,
-    style: Style,
-    hyperlink: Option<Hyperlink>,
+    style: Arc<Style>,
+    hyperlink: Option<Box<Hyperlink>>,
     cell_meta_index: UInt32Value,
+    value_meta_index: UInt32Value,
+    in_cell_image: Option<Box<MediaObject>>,
 }
 impl Cell {
     pub fn get_cell_value(&self) -> &CellValue {
@@ -52,10 +65,22 @@ impl Cell {
     }
 
     pub fn get_style_mut(&mut self) -> &mut Style {
-        &mut self.style
+        Arc::make_mut(&mut self.style)
     }
 
     pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Arc::new(value);
+        self
+    }
+
+    /// Get the shared handle of the style.
+    pub fn get_style_shared(&self) -> &Arc<Style> {
+        &self.style
+    }
+
+    /// Set a shared style handle.
+    /// The style is copied on the first `get_style_mut`.
+    pub fn set_style_shared(&mut self, value: Arc<Style>) -> &mut Self {
         self.style = value;
         self
     }
@@ -69,7 +94,7 @@ impl Cell {
     }
 
     pub fn get_hyperlink(&self) -> Option<&Hyperlink> {
-        self.hyperlink.as_ref()
+        self.hyperlink.as_deref()
     }
 
     pub fn get_hyperlink_mut(&mut self) -> &mut Hyperlink {
@@ -81,7 +106,68 @@ impl Cell {
     }
 
     pub fn set_hyperlink(&mut self, value: Hyperlink) -> &mut Self {
-        self.hyperlink = Some(value);
+        self.hyperlink = Some(Box::new(value));
+        self
+    }
+
+    pub fn remove_hyperlink(&mut self) -> &mut Self {
+        self.hyperlink = None;
+        self
+    }
+
+    /// Set a link to a url with its display text, in the hyperlink font (underlined, theme hyperlink color).
+    /// # Arguments
+    /// * `url` - ex) `"https://example.com"`
+    /// * `text` - display text. ex) `"Click here"`
+    /// * `tooltip` - text shown on hover. An empty string means no tooltip.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet
+    ///     .get_cell_mut("A1")
+    ///     .set_hyperlink_url("https://example.com", "Click here", "Open example.com");
+    /// ```
+    pub fn set_hyperlink_url<S: Into<String>, T: Into<String>, U: Into<String>>(
+        &mut self,
+        url: S,
+        text: T,
+        tooltip: U,
+    ) -> &mut Self {
+        let mut hyperlink = Hyperlink::default();
+        hyperlink.set_url(url).set_tooltip(tooltip);
+        self.set_hyperlink_with_text(hyperlink, text)
+    }
+
+    /// Set a link to a place in the workbook with its display text, in the hyperlink font.
+    /// # Arguments
+    /// * `location` - ex) `"'Sheet2'!A1"` or a defined name.
+    /// * `text` - display text.
+    /// * `tooltip` - text shown on hover. An empty string means no tooltip.
+    pub fn set_hyperlink_location<S: Into<String>, T: Into<String>, U: Into<String>>(
+        &mut self,
+        location: S,
+        text: T,
+        tooltip: U,
+    ) -> &mut Self {
+        let mut hyperlink = Hyperlink::default();
+        hyperlink
+            .set_url(location)
+            .set_tooltip(tooltip)
+            .set_location(true);
+        self.set_hyperlink_with_text(hyperlink, text)
+    }
+
+    fn set_hyperlink_with_text<T: Into<String>>(
+        &mut self,
+        hyperlink: Hyperlink,
+        text: T,
+    ) -> &mut Self {
+        self.set_value_string(text);
+        self.set_hyperlink(hyperlink);
+        let font = self.get_style_mut().get_font_mut();
+        font.set_underline(Font::UNDERLINE_SINGLE);
+        font.get_color_mut().set_theme_index(10);
         self
     }
 
@@ -94,6 +180,56 @@ impl Cell {
         self
     }
 
+    /// Get Image placed in the cell.
+    /// Only the image set by `set_image` is returned.
+    pub fn get_image(&self) -> Option<&MediaObject> {
+        match self.cell_value.is_error() {
+            true => self.in_cell_image.as_deref(),
+            false => None,
+        }
+    }
+
+    /// Place a picture in the cell (in-cell image).
+    /// # Arguments
+    /// * `data` - image data. (png, jpg or gif)
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let data = std::fs::read("./images/sample1.png").unwrap();
+    /// book.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_image(&data);
+    /// ```
+    pub fn set_image(&mut self, data: &[u8]) -> &mut Self {
+        let mut image = MediaObject::default();
+        image.set_image_name(format!(
+            "image_{:x}.{}",
+            md5::Md5::digest(data),
+            detect_file_type(data)
+        ));
+        image.set_image_data(data.to_vec());
+        self.in_cell_image = Some(Box::new(image));
+        self.cell_value.set_error("#VALUE!");
+        self
+    }
+
+    /// Remove Image placed in the cell.
+    pub fn remove_image(&mut self) -> &mut Self {
+        if self.in_cell_image.take().is_some() {
+            self.cell_value.set_blank();
+        }
+        self
+    }
+
+    /// Get Value Metadata Index (vm).
+    /// Index of the value metadata (ex. rich value of a linked data type).
+    pub fn get_value_meta_index(&self) -> &u32 {
+        self.value_meta_index.get_value()
+    }
+
+    pub fn set_value_meta_index(&mut self, value: u32) -> &mut Self {
+        self.value_meta_index.set_value(value);
+        self
+    }
+
     pub fn get_value(&self) -> Cow<'static, str> {
         self.cell_value.get_value()
     }
@@ -119,6 +255,51 @@ impl Cell {
         self
     }
 
+    /// Set the value like `set_value`, reading numbers with the separators of `locale`.
+    /// # Arguments
+    /// * `value` - ex) `"1.234,5"` with a decimal comma locale.
+    /// * `locale` - Locale
+    pub fn set_value_with_locale<S: Into<String>>(
+        &mut self,
+        value: S,
+        locale: &Locale,
+    ) -> &mut Self {
+        let value = value.into();
+        match locale.parse_number(&value) {
+            Some(v) => self.set_value_number(v),
+            None => self.set_value(value),
+        }
+    }
+
+    /// Set the value like `set_value`, also reading ISO dates, percentages,
+    /// currency amounts and `true`/`false` into typed values.
+    /// Dates, percentages and currency amounts get a matching number format.
+    /// # Arguments
+    /// * `value` - ex) `"2024-01-31"`, `"12%"`, `"$1,234.50"`
+    /// * `locale` - Locale
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let locale = umya_spreadsheet::Locale::default();
+    /// let cell = book.get_sheet_mut(&0).unwrap().get_cell_mut("A1");
+    /// cell.set_value_detect("12%", &locale);
+    /// assert_eq!(cell.get_value(), "0.12");
+    /// ```
+    pub fn set_value_detect<S: Into<String>>(&mut self, value: S, locale: &Locale) -> &mut Self {
+        let value = value.into();
+        match detect_value(&value, locale) {
+            Some(DetectedValue::Bool(v)) => self.set_value_bool(v),
+            Some(DetectedValue::Number(v, format)) => {
+                self.set_value_number(v);
+                self.get_style_mut()
+                    .get_number_format_mut()
+                    .set_format_code(format);
+                self
+            }
+            None => self.set_value_with_locale(value, locale),
+        }
+    }
+
     pub(crate) fn set_value_crate<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.cell_value.set_value_crate(value);
         self
@@ -152,11 +333,51 @@ impl Cell {
         self
     }
 
+    /// Set a date, time, date and time or duration as a serial number.
+    /// A cell formatted as `General` also gets a format code that displays the value.
+    /// # Arguments
+    /// * `value` - ex) `NaiveDate`, `NaiveTime`, `NaiveDateTime` or `Duration` of chrono.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let cell = book.get_sheet_mut(&0).unwrap().get_cell_mut("A1");
+    /// cell.set_value_date_time(chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+    /// assert_eq!(cell.get_value(), "45322");
+    /// ```
+    pub fn set_value_date_time<T: IntoSerialNumber>(&mut self, value: T) -> &mut Self {
+        let format_code = value.get_format_code();
+        self.set_value_number(value.into_serial_number());
+        let is_general = match self.get_style().get_number_format() {
+            Some(v) => v.get_format_code() == NumberingFormat::FORMAT_GENERAL,
+            None => true,
+        };
+        if is_general {
+            self.get_style_mut()
+                .get_number_format_mut()
+                .set_format_code(format_code);
+        }
+        self
+    }
+
     pub fn set_rich_text(&mut self, value: RichText) -> &mut Self {
         self.cell_value.set_rich_text(value);
         self
     }
 
+    /// Set string value with phonetic runs (furigana).
+    /// # Arguments
+    /// * `value` - string value.
+    /// * `phonetic_runs` - phonetic runs.
+    pub fn set_value_with_phonetic<S: Into<String>>(
+        &mut self,
+        value: S,
+        phonetic_runs: Vec<PhoneticRun>,
+    ) -> &mut Self {
+        self.cell_value
+            .set_value_with_phonetic(value, phonetic_runs);
+        self
+    }
+
     pub fn set_error<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.cell_value.set_error(value);
         self
@@ -172,6 +393,30 @@ impl Cell {
         self
     }
 
+    /// Get the cached result of the formula.
+    /// Returns `None` if the cell has no formula or no cached result.
+    pub fn get_formula_cached_result(&self) -> Option<&CellRawValue> {
+        self.cell_value.get_formula_cached_result()
+    }
+
+    /// Set the cached result of the formula, shown by viewers that do not recalculate.
+    /// The formula is kept.
+    /// # Arguments
+    /// * `value` - ex) `42`, `"text"`, `true`
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A3").set_formula("SUM(A1:A2)").set_formula_result(42);
+    /// assert_eq!(worksheet.get_cell("A3").unwrap().get_formula(), "SUM(A1:A2)");
+    /// assert_eq!(worksheet.get_cell("A3").unwrap().get_value(), "42");
+    /// ```
+    pub fn set_formula_result<T: IntoCellValue>(&mut self, value: T) -> &mut Self {
+        self.cell_value.set_formula_result(value);
+        self
+    }
+
     pub fn set_blank(&mut self) -> &mut Self {
         self.cell_value.set_blank();
         self
@@ -198,6 +443,15 @@ impl Cell {
         self.cell_value.is_formula()
     }
 
+    /// Whether the cell exists only to carry a style.
+    /// It has no value, formula, hyperlink or image.
+    pub fn is_style_only(&self) -> bool {
+        self.cell_value.is_empty()
+            && self.hyperlink.is_none()
+            && self.in_cell_image.is_none()
+            && !self.value_meta_index.has_value()
+    }
+
     pub fn get_formula(&self) -> &str {
         self.cell_value.get_formula()
     }
@@ -249,12 +503,22 @@ impl Cell {
     }
 
     pub fn get_formatted_value(&self) -> String {
+        self.get_formatted_value_with_locale(&Locale::default())
+    }
+
+    /// Get the formatted value using the separators, currency symbol
+    /// and month and day names of `locale`.
+    pub fn get_formatted_value_with_locale(&self, locale: &Locale) -> String {
         let value = self.get_value();
 
         // convert value
         let result = match self.get_style().get_number_format() {
-            Some(nmuber_format) => to_formatted_string(&value, nmuber_format.get_format_code()),
-            None => to_formatted_string(&value, NumberingFormat::FORMAT_GENERAL),
+            Some(nmuber_format) => {
+                to_formatted_string_with_locale(&value, nmuber_format.get_format_code(), locale)
+            }
+            None => {
+                to_formatted_string_with_locale(&value, NumberingFormat::FORMAT_GENERAL, locale)
+            }
         };
         result
     }
@@ -274,39 +538,44 @@ impl Cell {
         stylesheet: &Stylesheet,
         empty_flag: bool,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+        limits: &ReadLimits,
+    ) -> Result<(), XlsxError> {
         let mut type_value: String = String::from("");
         let mut cell_reference: String = String::from("");
 
-        if let Some(v) = get_attribute(e, b"r") {
+        if let Some(v) = get_attribute(e, b"r")? {
             cell_reference = v;
             self.coordinate.set_coordinate(&cell_reference);
         }
 
-        if let Some(v) = get_attribute(e, b"s") {
-            let style = stylesheet.get_style(v.parse::<usize>().unwrap());
-            self.set_style(style);
+        if let Some(v) = get_attribute(e, b"s")? {
+            let style = stylesheet.get_style(v.parse::<usize>().map_err(parse_error)?);
+            self.set_style_shared(style);
         }
 
-        if let Some(v) = get_attribute(e, b"t") {
+        if let Some(v) = get_attribute(e, b"t")? {
             type_value = v;
         }
 
-        set_string_from_xml!(self, e, cell_meta_index, "cm");
+        set_number_from_xml!(self, e, cell_meta_index, "cm");
+        set_number_from_xml!(self, e, value_meta_index, "vm");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         let mut string_value: String = String::from("");
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+                Ok(Event::Text(e)) => {
+                    string_value = e.unescape()?.to_string();
+                    limits.check_string_length(&string_value)?;
+                }
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"f" => {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                     b"t" => {
@@ -323,17 +592,17 @@ impl Cell {
                 Ok(Event::Empty(ref e)) => {
                     if e.name().into_inner() == b"f" {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"v" => match type_value.as_str() {
                         "str" => {
-                            self.set_value_string(&string_value);
+                            self.cell_value.set_value_string_crate(&string_value);
                         }
                         "s" => {
-                            let index = string_value.parse::<usize>().unwrap();
+                            let index = string_value.parse::<usize>().map_err(parse_error)?;
                             let shared_string_item = shared_string_table
                                 .get_shared_string_item()
                                 .get(index)
@@ -347,9 +616,22 @@ impl Cell {
                         "e" => {
                             self.set_error(&string_value);
                         }
-                        "" | "n" => {
-                            self.set_value_crate(&string_value);
-                        }
+                        "d" => match convert_iso_8601(&string_value) {
+                            Some(f) => {
+                                self.cell_value.set_value_number_crate(f);
+                            }
+                            None => {
+                                self.set_value_crate(&string_value);
+                            }
+                        },
+                        "" | "n" => match string_value.parse::<f64>() {
+                            Ok(f) => {
+                                self.cell_value.set_value_number_crate(f);
+                            }
+                            Err(_) => {
+                                self.set_value_crate(&string_value);
+                            }
+                        },
                         _ => {}
                     },
                     b"is" => {
@@ -357,23 +639,43 @@ impl Cell {
                             self.set_value_crate(&string_value);
                         }
                     }
-                    b"c" => return,
+                    b"c" => return Ok(()),
                     b"t" => {
                         reader.config_mut().trim_text(true);
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "c"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("c")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
         }
     }
 
-    pub(crate) fn write_to(
+    /// Registers the style and shared string `write_to` would use,
+    /// so that the cell can later be written against a read-only copy.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn prepare_write(
         &self,
-        writer: &mut Writer<Cursor<Vec<u8>>>,
+        shared_string_table: &mut SharedStringTable,
+        stylesheet: &mut Stylesheet,
+    ) {
+        if self.cell_value.is_empty() && self.style.is_empty() {
+            return;
+        }
+        stylesheet.set_style_shared(self.get_style_shared());
+        if !self.cell_value.is_empty()
+            && !self.cell_value.is_value_empty()
+            && self.get_data_type_crate() == "s"
+        {
+            shared_string_table.register_cell(self.get_cell_value());
+        }
+    }
+
+    pub(crate) fn write_to<W: io::Write>(
+        &self,
+        writer: &mut Writer<W>,
         shared_string_table: &Arc<RwLock<SharedStringTable>>,
         stylesheet: &mut Stylesheet,
         formula_shared_list: &HashMap<&u32, (String, Option<String>)>,
@@ -396,7 +698,7 @@ impl Cell {
             attributes.push(("t", self.get_data_type_crate()));
         }
         let xf_index_str: String;
-        let xf_index = stylesheet.set_style(self.get_style());
+        let xf_index = stylesheet.set_style_shared(self.get_style_shared());
         if xf_index > 0 {
             xf_index_str = xf_index.to_string();
             attributes.push(("s", &xf_index_str));
@@ -404,8 +706,12 @@ impl Cell {
 
         let cell_meta_index_str = self.cell_meta_index.get_value_string();
         if self.cell_meta_index.has_value() {
-            // NOT SUPPORT
-            //attributes.push(("cm", &cell_meta_index_str));
+            attributes.push(("cm", &cell_meta_index_str));
+        }
+
+        let value_meta_index_str = self.value_meta_index.get_value_string();
+        if self.value_meta_index.has_value() {
+            attributes.push(("vm", &value_meta_index_str));
         }
 
         if empty_flag_value {
@@ -442,10 +748,7 @@ impl Cell {
                     let prm = if upper_value == "TRUE" { "1" } else { "0" };
                     write_text_node(writer, prm);
                 }
-                "e" => {
-                    let prm = "#VALUE!";
-                    write_text_node(writer, prm);
-                }
+                "e" => write_text_node(writer, self.get_value()),
                 _ => write_text_node(writer, self.get_value()),
             }
             write_end_tag(writer, "v");
@@ -183,11 +183,11 @@ impl CellFormat {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        set_string_from_xml!(self, e, number_format_id, "numFmtId");
-        set_string_from_xml!(self, e, font_id, "fontId");
-        set_string_from_xml!(self, e, fill_id, "fillId");
-        set_string_from_xml!(self, e, border_id, "borderId");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, number_format_id, "numFmtId");
+        set_number_from_xml!(self, e, font_id, "fontId");
+        set_number_from_xml!(self, e, fill_id, "fillId");
+        set_number_from_xml!(self, e, border_id, "borderId");
         set_string_from_xml!(self, e, apply_number_format, "applyNumberFormat");
         set_string_from_xml!(self, e, apply_border, "applyBorder");
         set_string_from_xml!(self, e, apply_font, "applyFont");
@@ -196,7 +196,7 @@ impl CellFormat {
         set_string_from_xml!(self, e, apply_protection, "applyProtection");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -205,12 +205,12 @@ impl CellFormat {
                 match e.name().into_inner(){
                     b"alignment" =>{
                         let mut obj = Alignment::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_alignment(obj);
                     },
                     b"protection" =>{
                         let mut obj = Protection::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_protection(obj);
                     },
                     _ =>{}
@@ -218,10 +218,10 @@ impl CellFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xf")
+            Event::Eof => return Err(eof_error("xf"))
         );
     }
 
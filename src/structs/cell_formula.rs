@@ -6,7 +6,7 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
-use std::io::Cursor;
+use std::io;
 use structs::BooleanValue;
 use structs::CellFormulaValues;
 use structs::EnumValue;
@@ -132,6 +132,31 @@ impl CellFormula {
         self
     }
 
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        if let Some(v) = remove_sheet_reference_formula(self.text.get_value_str(), sheet_name) {
+            self.text.set_value(v);
+        }
+        if let Some(v) = remove_sheet_reference_formula(self.text_view.get_value_str(), sheet_name)
+        {
+            self.text_view.set_value(v);
+        }
+    }
+
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if let Some(v) =
+            rename_sheet_reference_formula(self.text.get_value_str(), sheet_name, new_sheet_name)
+        {
+            self.text.set_value(v);
+        }
+        if let Some(v) = rename_sheet_reference_formula(
+            self.text_view.get_value_str(),
+            sheet_name,
+            new_sheet_name,
+        ) {
+            self.text_view.set_value(v);
+        }
+    }
+
     pub fn set_text_view<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.text_view.set_value(value);
         self
@@ -144,7 +169,7 @@ impl CellFormula {
         is_empty: bool,
         cell_reference_str: &str,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, bx, "bx");
         set_string_from_xml!(self, e, data_table_2d, "dt2D");
         set_string_from_xml!(self, e, data_table_row, "dtr");
@@ -154,20 +179,20 @@ impl CellFormula {
         set_string_from_xml!(self, e, r1, "r1");
         set_string_from_xml!(self, e, r2, "r2");
         set_string_from_xml!(self, e, reference, "ref");
-        set_string_from_xml!(self, e, shared_index, "si");
+        set_number_from_xml!(self, e, shared_index, "si");
 
         if !is_empty {
             xml_read_loop!(
                 reader,
                 Event::Text(e) => {
-                    self.text.set_value(e.unescape().unwrap().to_string());
+                    self.text.set_value(e.unescape()?.to_string());
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"f" {
                         break;
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "f")
+                Event::Eof => return Err(eof_error("f"))
             );
         }
 
@@ -211,11 +236,12 @@ impl CellFormula {
                 }
             }
         }
+        Ok(())
     }
 
-    pub(crate) fn write_to(
+    pub(crate) fn write_to<W: io::Write>(
         &self,
-        writer: &mut Writer<Cursor<Vec<u8>>>,
+        writer: &mut Writer<W>,
         coordinate: &str,
         formula_shared_list: &HashMap<&u32, (String, Option<String>)>,
     ) {
@@ -302,7 +328,7 @@ impl AdjustmentCoordinateWith2Sheet for CellFormula {
     ) {
         if let Some(v) = self.text.get_value() {
             let formula = adjustment_insert_formula_coordinate(
-                &mut parse_to_tokens(v),
+                &mut parse_to_tokens(format!("={}", v)),
                 root_col_num,
                 offset_col_num,
                 root_row_num,
@@ -311,7 +337,7 @@ impl AdjustmentCoordinateWith2Sheet for CellFormula {
                 self_sheet_name,
                 false,
             );
-            self.text.set_value(format!("={}", formula));
+            self.text.set_value(formula);
         }
     }
 
@@ -326,7 +352,7 @@ impl AdjustmentCoordinateWith2Sheet for CellFormula {
     ) {
         if let Some(v) = self.text.get_value() {
             let formula = adjustment_remove_formula_coordinate(
-                &mut parse_to_tokens(v),
+                &mut parse_to_tokens(format!("={}", v)),
                 root_col_num,
                 offset_col_num,
                 root_row_num,
@@ -335,7 +361,7 @@ impl AdjustmentCoordinateWith2Sheet for CellFormula {
                 self_sheet_name,
                 false,
             );
-            self.text.set_value(format!("={}", formula));
+            self.text.set_value(formula);
         }
     }
 }
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::str::FromStr;
+use structs::Coordinate;
+use XlsxError;
+
+/// A rectangular range of cells. ex) `"A1:B2"`
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let range: CellRange = "A1:C3".parse().unwrap();
+/// assert_eq!(range.get_start().to_string(), "A1");
+/// assert_eq!(range.get_coordinate_list().len(), 9);
+/// assert_eq!(range.to_string(), "A1:C3");
+///
+/// let mut book = new_file();
+/// let worksheet = book.get_sheet_mut(&0).unwrap();
+/// worksheet.set_style_by_range(&range, Style::default());
+/// ```
+#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CellRange {
+    start: Coordinate,
+    end: Coordinate,
+}
+impl CellRange {
+    pub fn get_start(&self) -> &Coordinate {
+        &self.start
+    }
+
+    pub fn get_start_mut(&mut self) -> &mut Coordinate {
+        &mut self.start
+    }
+
+    pub fn set_start(&mut self, value: Coordinate) -> &mut Self {
+        self.start = value;
+        self
+    }
+
+    pub fn get_end(&self) -> &Coordinate {
+        &self.end
+    }
+
+    pub fn get_end_mut(&mut self) -> &mut Coordinate {
+        &mut self.end
+    }
+
+    pub fn set_end(&mut self, value: Coordinate) -> &mut Self {
+        self.end = value;
+        self
+    }
+
+    /// Check if the coordinate is within the range.
+    pub fn contains<T: Into<Coordinate>>(&self, coordinate: T) -> bool {
+        let coordinate = coordinate.into();
+        let (col_start, row_start, col_end, row_end) = self.get_bounds();
+        (col_start..=col_end).contains(coordinate.get_col_num())
+            && (row_start..=row_end).contains(coordinate.get_row_num())
+    }
+
+    /// Get the `(col, row)` of every cell in the range, row by row.
+    pub fn get_coordinate_list(&self) -> Vec<(u32, u32)> {
+        let (col_start, row_start, col_end, row_end) = self.get_bounds();
+        let mut result = Vec::new();
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                result.push((col, row));
+            }
+        }
+        result
+    }
+
+    // (col_start, row_start, col_end, row_end), normalized so that start <= end.
+    fn get_bounds(&self) -> (u32, u32, u32, u32) {
+        let (col_a, row_a) = (*self.start.get_col_num(), *self.start.get_row_num());
+        let (col_b, row_b) = (*self.end.get_col_num(), *self.end.get_row_num());
+        (
+            col_a.min(col_b),
+            row_a.min(row_b),
+            col_a.max(col_b),
+            row_a.max(row_b),
+        )
+    }
+}
+
+impl fmt::Display for CellRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.start == self.end {
+            return write!(f, "{}", self.start);
+        }
+        write!(f, "{}:{}", self.start, self.end)
+    }
+}
+
+impl FromStr for CellRange {
+    type Err = XlsxError;
+
+    /// Parse a range. ex) `"A1:B2"`, `"$A$1:$B$2"`, `"C3"`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split(':');
+        let start: Coordinate = match split.next() {
+            Some(v) => v
+                .parse()
+                .map_err(|_| XlsxError::Coordinate(s.to_string()))?,
+            None => return Err(XlsxError::Coordinate(s.to_string())),
+        };
+        let end: Coordinate = match split.next() {
+            Some(v) => v
+                .parse()
+                .map_err(|_| XlsxError::Coordinate(s.to_string()))?,
+            None => start.clone(),
+        };
+        if split.next().is_some() {
+            return Err(XlsxError::Coordinate(s.to_string()));
+        }
+        Ok(CellRange { start, end })
+    }
+}
+
+impl From<(u32, u32, u32, u32)> for CellRange {
+    /// `(col_start, row_start, col_end, row_end)`, all starting at `1`.
+    fn from(value: (u32, u32, u32, u32)) -> Self {
+        CellRange {
+            start: (value.0, value.1).into(),
+            end: (value.2, value.3).into(),
+        }
+    }
+}
+
+impl From<&CellRange> for String {
+    fn from(value: &CellRange) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<CellRange> for String {
+    fn from(value: CellRange) -> Self {
+        value.to_string()
+    }
+}
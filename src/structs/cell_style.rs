@@ -47,10 +47,11 @@ impl CellStyle {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
-        set_string_from_xml!(self, e, builtin_id, "builtinId");
-        set_string_from_xml!(self, e, format_id, "xfId");
+        set_number_from_xml!(self, e, builtin_id, "builtinId");
+        set_number_from_xml!(self, e, format_id, "xfId");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -30,22 +30,22 @@ impl CellStyles {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"cellStyle" {
                     let mut obj = CellStyle::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_cell_style(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cellStyles" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cellStyles")
+            Event::Eof => return Err(eof_error("cellStyles"))
         );
     }
 
@@ -1,3 +1,4 @@
+use super::PhoneticRun;
 use super::RichText;
 use super::SharedStringItem;
 use super::Text;
@@ -8,11 +9,12 @@ use std::str::FromStr;
 use structs::CellFormula;
 use structs::CellRawValue;
 use traits::AdjustmentCoordinateWith2Sheet;
+use traits::IntoCellValue;
 
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct CellValue {
     pub(crate) raw_value: CellRawValue,
-    pub(crate) formula: Option<CellFormula>,
+    pub(crate) formula: Option<Box<CellFormula>>,
 }
 impl CellValue {
     pub fn get_data_type(&self) -> &str {
@@ -24,7 +26,11 @@ impl CellValue {
     }
 
     pub(crate) fn get_data_type_crate(&self) -> &str {
-        self.raw_value.get_data_type()
+        // the string result of a formula is stored inline, not in the shared string table.
+        match (&self.formula, &self.raw_value) {
+            (Some(_), CellRawValue::String(_)) | (Some(_), CellRawValue::RichText(_)) => "str",
+            _ => self.raw_value.get_data_type(),
+        }
     }
 
     pub fn get_value(&self) -> Cow<'static, str> {
@@ -81,6 +87,11 @@ impl CellValue {
         self
     }
 
+    pub(crate) fn set_value_string_crate<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.raw_value = CellRawValue::String(value.into());
+        self
+    }
+
     pub fn set_value_bool(&mut self, value: bool) -> &mut Self {
         self.raw_value = CellRawValue::Bool(value);
         self.remove_formula();
@@ -92,6 +103,11 @@ impl CellValue {
         self
     }
 
+    pub(crate) fn set_value_number_crate(&mut self, value: f64) -> &mut Self {
+        self.raw_value = CellRawValue::Numeric(value);
+        self
+    }
+
     pub fn set_value_number<T>(&mut self, value: T) -> &mut Self
     where
         T: Into<f64>,
@@ -101,12 +117,45 @@ impl CellValue {
         self
     }
 
+    pub fn set_raw_value(&mut self, value: CellRawValue) -> &mut Self {
+        self.raw_value = value;
+        self.remove_formula();
+        self
+    }
+
     pub fn set_rich_text(&mut self, value: RichText) -> &mut Self {
         self.raw_value = CellRawValue::RichText(value);
         self.remove_formula();
         self
     }
 
+    /// Set string value with phonetic runs (furigana).
+    /// # Arguments
+    /// * `value` - string value.
+    /// * `phonetic_runs` - phonetic runs. `start_base`/`end_base` are character indexes of `value`.
+    pub fn set_value_with_phonetic<S: Into<String>>(
+        &mut self,
+        value: S,
+        phonetic_runs: Vec<PhoneticRun>,
+    ) -> &mut Self {
+        let mut rich_text = RichText::default();
+        rich_text.set_text(value);
+        for run in phonetic_runs {
+            rich_text.add_phonetic_run(run);
+        }
+        self.set_rich_text(rich_text)
+    }
+
+    /// Get phonetic runs (furigana).
+    /// # Return value
+    /// * `Option<&Vec<PhoneticRun>>` - None when the value is not a rich text.
+    pub fn get_phonetic_runs(&self) -> Option<&Vec<PhoneticRun>> {
+        match &self.raw_value {
+            CellRawValue::RichText(v) => Some(v.get_phonetic_runs()),
+            _ => None,
+        }
+    }
+
     pub fn set_blank(&mut self) -> &mut Self {
         self.raw_value = CellRawValue::Empty;
         self.remove_formula();
@@ -125,21 +174,33 @@ impl CellValue {
     }
 
     pub fn get_formula_obj(&self) -> Option<&CellFormula> {
-        self.formula.as_ref()
+        self.formula.as_deref()
     }
 
     pub fn set_formula<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let mut obj = CellFormula::default();
         obj.set_text(value.into());
-        self.formula = Some(obj);
+        self.formula = Some(Box::new(obj));
         self
     }
 
     pub fn set_formula_obj(&mut self, value: CellFormula) -> &mut Self {
-        self.formula = Some(value);
+        self.formula = Some(Box::new(value));
         self
     }
 
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        if let Some(v) = &mut self.formula {
+            v.remove_sheet_reference(sheet_name);
+        }
+    }
+
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if let Some(v) = &mut self.formula {
+            v.rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+    }
+
     pub fn remove_formula(&mut self) -> &mut Self {
         self.formula = None;
         self
@@ -150,6 +211,22 @@ impl CellValue {
         self
     }
 
+    /// Get the cached result of the formula.
+    /// Returns `None` if the cell has no formula or no cached result.
+    pub fn get_formula_cached_result(&self) -> Option<&CellRawValue> {
+        if self.is_formula() && !self.raw_value.is_empty() {
+            return Some(&self.raw_value);
+        }
+        None
+    }
+
+    /// Set the cached result of the formula, shown by viewers that do not recalculate.
+    /// The formula is kept.
+    pub fn set_formula_result<T: IntoCellValue>(&mut self, value: T) -> &mut Self {
+        self.raw_value = value.into_cell_value();
+        self
+    }
+
     pub fn set_error<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.set_value_crate(value);
         self
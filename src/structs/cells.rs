@@ -1,6 +1,8 @@
 use super::Cell;
 use super::CellValue;
+use super::ClearMode;
 use super::Style;
+use hashbrown::hash_map;
 use hashbrown::HashMap;
 use helper::coordinate::*;
 use helper::range::*;
@@ -8,6 +10,8 @@ use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWith2Sheet;
 use traits::AdjustmentCoordinateWithSheet;
 
+/// Cell collection of a worksheet.
+/// Cells are keyed by `(row, col)`, so a lookup by coordinate is O(1).
 #[derive(Clone, Default, Debug)]
 pub struct Cells {
     map: HashMap<(u32, u32), Cell>,
@@ -90,11 +94,32 @@ impl Cells {
         (col_max, row_max)
     }
 
+    /// Get Lowest Column and Row Index.
+    /// # Return value
+    /// *`(u32, u32)` - (column, row). (0, 0) when there is no cell.
+    pub fn get_lowest_column_and_row(&self) -> (u32, u32) {
+        let mut col_min: u32 = 0;
+        let mut row_min: u32 = 0;
+        for key in self.map.keys() {
+            if col_min == 0 || key.1 < col_min {
+                col_min = key.1;
+            }
+            if row_min == 0 || key.0 < row_min {
+                row_min = key.0;
+            }
+        }
+        (col_min, row_min)
+    }
+
     /// Has Hyperlink
     pub fn has_hyperlink(&self) -> bool {
         self.map.values().any(|c| c.get_hyperlink().is_some())
     }
 
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
     pub fn get<T>(&self, coordinate: T) -> Option<&Cell>
     where
         T: Into<CellCoordinates>,
@@ -165,6 +190,66 @@ impl Cells {
         self.map.remove(&k).is_some()
     }
 
+    /// Clear the cells within the bounds. `0` as an end means unbounded.
+    pub(crate) fn clear_range(
+        &mut self,
+        (row_start, row_end, col_start, col_end): (u32, u32, u32, u32),
+        mode: ClearMode,
+    ) {
+        if mode == ClearMode::All {
+            let row_end = if row_end == 0 { u32::MAX } else { row_end };
+            let col_end = if col_end == 0 { u32::MAX } else { col_end };
+            self.map.retain(|(row, col), _| {
+                !((row_start..=row_end).contains(row) && (col_start..=col_end).contains(col))
+            });
+            return;
+        }
+        for cell in self.get_range_mut((row_start, row_end, col_start, col_end)) {
+            match mode {
+                ClearMode::Contents => {
+                    cell.get_cell_value_mut().set_blank();
+                }
+                ClearMode::Formats => {
+                    cell.set_style_shared(Default::default());
+                }
+                ClearMode::Hyperlinks => {
+                    cell.remove_hyperlink();
+                }
+                ClearMode::All => {}
+            }
+        }
+    }
+
+    /// Existing cells within `(row_start, row_end, col_start, col_end)`.
+    /// An end of `0` means the range is unbounded.
+    pub(crate) fn get_range_mut(
+        &mut self,
+        (row_start, row_end, col_start, col_end): (u32, u32, u32, u32),
+    ) -> impl Iterator<Item = &mut Cell> {
+        let row_end = if row_end == 0 { u32::MAX } else { row_end };
+        let col_end = if col_end == 0 { u32::MAX } else { col_end };
+        self.map
+            .iter_mut()
+            .filter(move |((row, col), _)| {
+                (row_start..=row_end).contains(row) && (col_start..=col_end).contains(col)
+            })
+            .map(|(_, cell)| cell)
+    }
+
+    pub(crate) fn get_range(
+        &self,
+        (row_start, row_end, col_start, col_end): (u32, u32, u32, u32),
+    ) -> impl Iterator<Item = &Cell> {
+        let row_end = if row_end == 0 { u32::MAX } else { row_end };
+        let col_end = if col_end == 0 { u32::MAX } else { col_end };
+        self.map
+            .iter()
+            .filter(move |((row, col), _)| {
+                (row_start..=row_end).contains(row) && (col_start..=col_end).contains(col)
+            })
+            .map(|(_, cell)| cell)
+    }
+
     pub fn get_cell_by_range(&self, range: &str) -> Vec<Option<&Cell>> {
         let mut result: Vec<Option<&Cell>> = Vec::new();
         let range_upper = range.to_uppercase();
@@ -208,6 +293,24 @@ impl Cells {
             .collect()
     }
 }
+/// Iterates over the cells in no particular order.
+/// Use `get_collection_sorted` for row order.
+impl<'a> IntoIterator for &'a Cells {
+    type Item = &'a Cell;
+    type IntoIter = hash_map::Values<'a, (u32, u32), Cell>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.values()
+    }
+}
+impl<'a> IntoIterator for &'a mut Cells {
+    type Item = &'a mut Cell;
+    type IntoIter = hash_map::ValuesMut<'a, (u32, u32), Cell>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.values_mut()
+    }
+}
 impl AdjustmentCoordinate for Cells {
     fn adjustment_insert_coordinate(
         &mut self,
@@ -15,15 +15,21 @@ use structs::drawing::charts::CategoryAxisData;
 use structs::drawing::charts::ChartSpace;
 use structs::drawing::charts::ChartText;
 use structs::drawing::charts::CrossBetweenValues;
+use structs::drawing::charts::CrossesAt;
+use structs::drawing::charts::CrossesValues;
 use structs::drawing::charts::DisplayBlanksAsValues;
 use structs::drawing::charts::DoughnutChart;
+use structs::drawing::charts::Explosion;
 use structs::drawing::charts::GroupingValues;
 use structs::drawing::charts::InvertIfNegative;
 use structs::drawing::charts::Layout;
+use structs::drawing::charts::LayoutTargetValues;
 use structs::drawing::charts::LegendPositionValues;
 use structs::drawing::charts::Line3DChart;
 use structs::drawing::charts::LineChart;
 use structs::drawing::charts::MajorGridlines;
+use structs::drawing::charts::MaxAxisValue;
+use structs::drawing::charts::MinAxisValue;
 use structs::drawing::charts::OfPieChart;
 use structs::drawing::charts::OfPieValues;
 use structs::drawing::charts::OrientationValues;
@@ -61,6 +67,7 @@ use structs::drawing::RunProperties;
 use structs::ChartType;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWithSheet;
+use traits::IntoSerialNumber;
 
 /// ## Supported chart types
 /// * AreaChart
@@ -130,7 +137,7 @@ use traits::AdjustmentCoordinateWithSheet;
 /// chart
 ///     .set_grouping(umya_spreadsheet::drawing::charts::GroupingValues::Standard);
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Chart {
     two_cell_anchor: Box<TwoCellAnchor>,
     default_language: String,
@@ -350,6 +357,329 @@ impl Chart {
         self.two_cell_anchor.get_from_marker().get_coordinate()
     }
 
+    /// Get the cell of the bottom right corner.
+    pub fn get_to_coordinate(&self) -> String {
+        self.two_cell_anchor.get_to_marker().get_coordinate()
+    }
+
+    /// Whether the chart type is supported.
+    /// An unsupported chart keeps its original XML and is written back unchanged,
+    /// so edits through this struct are not saved.
+    pub fn is_support(&self) -> bool {
+        self.get_chart_space().get_raw_data().is_none()
+    }
+
+    /// Get the chart type.
+    /// For a combination chart, the type of the first chart in the plot area.
+    pub fn get_chart_type(&self) -> Option<ChartType> {
+        let plot_area = self.get_chart_space().get_chart().get_plot_area();
+        if plot_area.get_line_chart().is_some() {
+            return Some(ChartType::LineChart);
+        }
+        if plot_area.get_line_3d_chart().is_some() {
+            return Some(ChartType::Line3DChart);
+        }
+        if plot_area.get_pie_chart().is_some() {
+            return Some(ChartType::PieChart);
+        }
+        if plot_area.get_pie_3d_chart().is_some() {
+            return Some(ChartType::Pie3DChart);
+        }
+        if plot_area.get_doughnut_chart().is_some() {
+            return Some(ChartType::DoughnutChart);
+        }
+        if plot_area.get_scatter_chart().is_some() {
+            return Some(ChartType::ScatterChart);
+        }
+        if plot_area.get_bar_chart().is_some() {
+            return Some(ChartType::BarChart);
+        }
+        if plot_area.get_bar_3d_chart().is_some() {
+            return Some(ChartType::Bar3DChart);
+        }
+        if plot_area.get_radar_chart().is_some() {
+            return Some(ChartType::RadarChart);
+        }
+        if plot_area.get_bubble_chart().is_some() {
+            return Some(ChartType::BubbleChart);
+        }
+        if plot_area.get_area_chart().is_some() {
+            return Some(ChartType::AreaChart);
+        }
+        if plot_area.get_area_3d_chart().is_some() {
+            return Some(ChartType::Area3DChart);
+        }
+        if plot_area.get_of_pie_chart().is_some() {
+            return Some(ChartType::OfPieChart);
+        }
+        None
+    }
+
+    /// Get the title text.
+    pub fn get_title(&self) -> Option<String> {
+        let chart_text = self
+            .get_chart_space()
+            .get_chart()
+            .get_title()?
+            .get_chart_text()?;
+        let text = chart_text
+            .get_rich_text()
+            .get_paragraph()
+            .iter()
+            .map(|paragraph| {
+                paragraph
+                    .get_run()
+                    .iter()
+                    .map(|run| run.get_text())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        Some(text)
+    }
+
+    /// Get the value range of each series.
+    /// # Return value
+    /// * `Vec<String>` - ex) `["Sheet1!$A$1:$A$10", "Sheet1!$B$1:$B$10"]`
+    pub fn get_series_formulas(&self) -> Vec<String> {
+        self.get_chart_space()
+            .get_chart()
+            .get_plot_area()
+            .get_area_chart_series_all()
+            .into_iter()
+            .map(|series| {
+                if let Some(v) = series.get_values() {
+                    return v.get_number_reference().get_formula().get_address_str();
+                }
+                if let Some(v) = series.get_y_values() {
+                    return v.get_number_reference().get_formula().get_address_str();
+                }
+                String::new()
+            })
+            .collect()
+    }
+
+    /// Retarget the value range of a series.
+    /// # Arguments
+    /// * `index` - Index of the series.
+    /// * `formula` - Value range. ex) "Sheet1!$C$1:$C$10"
+    pub fn set_series_formula(&mut self, index: usize, formula: &str) -> &mut Self {
+        if let Some(series) = self
+            .get_plot_area_mut()
+            .get_area_chart_series_all_mut()
+            .into_iter()
+            .nth(index)
+        {
+            if let Some(v) = series.get_values_mut() {
+                v.get_number_reference_mut()
+                    .get_formula_mut()
+                    .set_address_str(formula);
+            } else if let Some(v) = series.get_y_values_mut() {
+                v.get_number_reference_mut()
+                    .get_formula_mut()
+                    .set_address_str(formula);
+            }
+        }
+        self
+    }
+
+    /// Point the series ranges at another sheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name referenced now. ex) "Old"
+    /// * `new_sheet_name` - Sheet name to reference. ex) "New"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// for chart in worksheet.get_chart_collection_mut() {
+    ///     chart.replace_sheet_name("Old", "New").shift_series_ranges(10, 0);
+    /// }
+    /// ```
+    pub fn replace_sheet_name(&mut self, sheet_name: &str, new_sheet_name: &str) -> &mut Self {
+        for formula in self.get_plot_area_mut().get_formula_mut() {
+            formula.rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+        self
+    }
+
+    /// Move the series ranges by the number of rows and columns.
+    /// # Arguments
+    /// * `rows` - Number of rows. Negative moves up.
+    /// * `cols` - Number of columns. Negative moves left.
+    pub fn shift_series_ranges(&mut self, rows: i32, cols: i32) -> &mut Self {
+        for formula in self.get_plot_area_mut().get_formula_mut() {
+            formula.offset_address(rows, cols);
+        }
+        self
+    }
+
+    /// Set the angle of the first slice of pie and doughnut charts.
+    /// # Arguments
+    /// * `value` - Angle in degrees. Values above 360 are clamped.
+    pub fn set_first_slice_angle(&mut self, value: u16) -> &mut Self {
+        let value = value.min(360);
+        let plot_area = self.get_plot_area_mut();
+        if let Some(v) = plot_area.get_pie_chart_mut() {
+            v.get_first_slice_angle_mut().set_val(value);
+        }
+        if let Some(v) = plot_area.get_doughnut_chart_mut() {
+            v.get_first_slice_angle_mut().set_val(value);
+        }
+        self
+    }
+
+    /// Set the hole size of doughnut charts.
+    /// # Arguments
+    /// * `value` - Percentage of the radius. Clamped to 10-90.
+    pub fn set_hole_size(&mut self, value: u8) -> &mut Self {
+        if let Some(v) = self.get_plot_area_mut().get_doughnut_chart_mut() {
+            v.get_hole_size_mut().set_val(value.clamp(10, 90));
+        }
+        self
+    }
+
+    /// Pull a single slice out of a pie or doughnut chart.
+    /// # Arguments
+    /// * `series_index` - Index of the series.
+    /// * `point_index` - Index of the point in the series.
+    /// * `value` - Distance as a percentage of the radius.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// for chart in worksheet.get_chart_collection_mut() {
+    ///     chart.set_first_slice_angle(90).set_point_explosion(0, 2, 25);
+    /// }
+    /// ```
+    pub fn set_point_explosion(
+        &mut self,
+        series_index: usize,
+        point_index: u32,
+        value: u32,
+    ) -> &mut Self {
+        if let Some(series) = self
+            .get_plot_area_mut()
+            .get_area_chart_series_all_mut()
+            .into_iter()
+            .nth(series_index)
+        {
+            let mut explosion = Explosion::default();
+            explosion.set_val(value);
+            series
+                .get_data_point_mut(point_index)
+                .set_explosion(explosion);
+        }
+        self
+    }
+
+    /// Place the plot area manually inside the chart.
+    /// Every value is a fraction of the chart size.
+    /// # Arguments
+    /// * `x` - Left edge. ex) 0.1
+    /// * `y` - Top edge. ex) 0.1
+    /// * `width` - Width of the inner plot area. ex) 0.8
+    /// * `height` - Height of the inner plot area. ex) 0.7
+    pub fn set_plot_area_layout(&mut self, x: f64, y: f64, width: f64, height: f64) -> &mut Self {
+        self.get_plot_area_mut()
+            .get_layout_mut()
+            .set_layout_target(LayoutTargetValues::Inner)
+            .set_position(x, y)
+            .set_size(width, height);
+        self
+    }
+
+    /// Set how empty cells are plotted.
+    /// # Arguments
+    /// * `value` - Gap, Span or Zero.
+    pub fn set_display_blanks_as(&mut self, value: DisplayBlanksAsValues) -> &mut Self {
+        self.get_chart_space_mut()
+            .get_chart_mut()
+            .get_display_blanks_as_mut()
+            .set_val(value);
+        self
+    }
+
+    /// Set where the value axes cross their crossing axis.
+    pub fn set_value_axis_crosses(&mut self, value: CrossesValues) -> &mut Self {
+        for axis in self.get_plot_area_mut().get_value_axis_mut() {
+            axis.get_crosses_mut().set_val(value.clone());
+            axis.remove_crosses_at();
+        }
+        self
+    }
+
+    /// Set the value at which the value axes cross their crossing axis.
+    /// # Arguments
+    /// * `value` - Value on the crossing axis. Dates, times and durations of chrono become serial numbers.
+    pub fn set_value_axis_crosses_at<T: IntoSerialNumber>(&mut self, value: T) -> &mut Self {
+        let value = value.into_serial_number();
+        for axis in self.get_plot_area_mut().get_value_axis_mut() {
+            let mut obj = CrossesAt::default();
+            obj.set_val(value);
+            axis.set_crosses_at(obj);
+        }
+        self
+    }
+
+    /// Set where the category axes cross their crossing axis.
+    pub fn set_category_axis_crosses(&mut self, value: CrossesValues) -> &mut Self {
+        for axis in self.get_plot_area_mut().get_category_axis_mut() {
+            axis.get_crosses_mut().set_val(value.clone());
+            axis.remove_crosses_at();
+        }
+        self
+    }
+
+    /// Set the value at which the category axes cross their crossing axis.
+    /// # Arguments
+    /// * `value` - Value on the crossing axis. Dates, times and durations of chrono become serial numbers.
+    pub fn set_category_axis_crosses_at<T: IntoSerialNumber>(&mut self, value: T) -> &mut Self {
+        let value = value.into_serial_number();
+        for axis in self.get_plot_area_mut().get_category_axis_mut() {
+            let mut obj = CrossesAt::default();
+            obj.set_val(value);
+            axis.set_crosses_at(obj);
+        }
+        self
+    }
+
+    /// Set the minimum and maximum of the value axes.
+    /// # Arguments
+    /// * `min` - Minimum. Dates, times and durations of chrono become serial numbers.
+    /// * `max` - Maximum.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::structs::drawing::spreadsheet::MarkerType;
+    /// let mut from_marker = MarkerType::default();
+    /// let mut to_marker = MarkerType::default();
+    /// from_marker.set_coordinate("C1");
+    /// to_marker.set_coordinate("D11");
+    /// let mut chart = umya_spreadsheet::structs::Chart::default();
+    /// chart.new_chart(
+    ///     umya_spreadsheet::structs::ChartType::ScatterChart,
+    ///     from_marker,
+    ///     to_marker,
+    ///     vec!["Sheet1!$A$1:$A$10", "Sheet1!$B$1:$B$10"],
+    /// );
+    /// chart.set_value_axis_range(
+    ///     chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+    /// );
+    /// ```
+    pub fn set_value_axis_range<T: IntoSerialNumber>(&mut self, min: T, max: T) -> &mut Self {
+        let min = min.into_serial_number();
+        let max = max.into_serial_number();
+        for axis in self.get_plot_area_mut().get_value_axis_mut() {
+            let mut obj = MinAxisValue::default();
+            obj.set_val(min);
+            axis.get_scaling_mut().set_min_axis_value(obj);
+            let mut obj = MaxAxisValue::default();
+            obj.set_val(max);
+            axis.get_scaling_mut().set_max_axis_value(obj);
+        }
+        self
+    }
+
     pub(crate) fn get_col(&self) -> &u32 {
         self.two_cell_anchor.get_from_marker().get_col()
     }
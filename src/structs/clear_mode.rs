@@ -0,0 +1,12 @@
+/// What `Worksheet::clear_range` removes from each cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClearMode {
+    /// Values and formulas.
+    Contents,
+    /// Styles.
+    Formats,
+    /// Hyperlinks.
+    Hyperlinks,
+    /// Everything; the cells are removed.
+    All,
+}
@@ -213,25 +213,22 @@ impl Color {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for a in e.attributes().with_checks(false) {
             match a {
                 Ok(ref attr) => match attr.key.0 {
                     b"indexed" => {
-                        self.indexed
-                            .set_value_string(get_attribute_value(attr).unwrap());
+                        self.indexed.set_value_string(get_attribute_value(attr)?)?;
                     }
                     b"theme" => {
                         self.theme_index
-                            .set_value_string(get_attribute_value(attr).unwrap());
+                            .set_value_string(get_attribute_value(attr)?)?;
                     }
                     b"rgb" => {
-                        self.argb
-                            .set_value_string(get_attribute_value(attr).unwrap());
+                        self.argb.set_value_string(get_attribute_value(attr)?);
                     }
                     b"tint" => {
-                        self.tint
-                            .set_value_string(get_attribute_value(attr).unwrap());
+                        self.tint.set_value_string(get_attribute_value(attr)?);
                     }
                     _ => {}
                 },
@@ -240,24 +237,21 @@ impl Color {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"color" => return,
-                    b"fgColor" => return,
-                    b"bgColor" => return,
-                    b"tabColor" => return,
+                    b"color" => return Ok(()),
+                    b"fgColor" => return Ok(()),
+                    b"bgColor" => return Ok(()),
+                    b"tabColor" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!(
-                    "Error: Could not find {} end element",
-                    "color,fgColor,bgColor,tabColor"
-                ),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("color,fgColor,bgColor,tabColor")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
@@ -0,0 +1,58 @@
+// colorFilter
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct ColorFilter {
+    differential_format_id: UInt32Value,
+    cell_color: BooleanValue,
+}
+
+impl ColorFilter {
+    pub fn get_differential_format_id(&self) -> &u32 {
+        self.differential_format_id.get_value()
+    }
+
+    pub fn set_differential_format_id(&mut self, value: u32) -> &mut Self {
+        self.differential_format_id.set_value(value);
+        self
+    }
+
+    pub fn get_cell_color(&self) -> &bool {
+        self.cell_color.get_value()
+    }
+
+    pub fn set_cell_color(&mut self, value: bool) -> &mut Self {
+        self.cell_color.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, differential_format_id, "dxfId");
+        set_string_from_xml!(self, e, cell_color, "cellColor");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // colorFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let differential_format_id = self.differential_format_id.get_value_string();
+        if self.differential_format_id.has_value() {
+            attributes.push(("dxfId", &differential_format_id));
+        }
+        if self.cell_color.has_value() {
+            attributes.push(("cellColor", self.cell_color.get_value_string()));
+        }
+        write_start_tag(writer, "colorFilter", attributes, true);
+    }
+}
@@ -30,20 +30,20 @@ impl Colors {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"mruColors" {
-                    self.mru_colors.set_attributes(reader, e);
+                    self.mru_colors.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colors" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colors")
+            Event::Eof => return Err(eof_error("colors"))
         );
     }
 
@@ -7,6 +7,7 @@ use md5::Digest;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use reader::driver::*;
+use std::sync::Arc;
 use structs::Cells;
 use traits::AdjustmentValue;
 
@@ -31,7 +32,7 @@ pub struct Column {
     pub(crate) width: DoubleValue,
     pub(crate) hidden: BooleanValue,
     pub(crate) best_fit: BooleanValue,
-    style: Style,
+    style: Arc<Style>,
     auto_width: BooleanValue,
 }
 
@@ -44,7 +45,7 @@ impl Default for Column {
             width,
             hidden: BooleanValue::default(),
             best_fit: BooleanValue::default(),
-            style: Style::default(),
+            style: Arc::default(),
             auto_width: BooleanValue::default(),
         }
     }
@@ -92,10 +93,23 @@ impl Column {
     }
 
     pub fn get_style_mut(&mut self) -> &mut Style {
-        &mut self.style
+        Arc::make_mut(&mut self.style)
     }
 
+    /// Set the default style of the column.
+    /// Cells created in the column afterwards start with this style.
     pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Arc::new(value);
+        self
+    }
+
+    /// Get the shared handle of the style.
+    pub fn get_style_shared(&self) -> &Arc<Style> {
+        &self.style
+    }
+
+    /// Set a shared style handle.
+    pub fn set_style_shared(&mut self, value: Arc<Style>) -> &mut Self {
         self.style = value;
         self
     }
@@ -162,15 +176,16 @@ impl Column {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, width, "width");
         set_string_from_xml!(self, e, hidden, "hidden");
         set_string_from_xml!(self, e, best_fit, "bestFit");
 
-        if let Some(v) = get_attribute(e, b"style") {
-            let style = stylesheet.get_style(v.parse::<usize>().unwrap());
-            self.set_style(style);
+        if let Some(v) = get_attribute(e, b"style")? {
+            let style = stylesheet.get_style(v.parse::<usize>().map_err(parse_error)?);
+            self.set_style_shared(style);
         }
+        Ok(())
     }
 }
 impl AdjustmentValue for Column {
@@ -0,0 +1,79 @@
+use hashbrown::HashSet;
+use structs::CellRawValue;
+
+/// Statistics of a cell range, taken with `Worksheet::column_stats`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ColumnStats {
+    count: usize,
+    numeric_count: usize,
+    min: Option<f64>,
+    max: Option<f64>,
+    sum: f64,
+    distinct_values: Vec<String>,
+    distinct_set: HashSet<String>,
+}
+impl ColumnStats {
+    /// Number of cells that are not empty.
+    pub fn get_count(&self) -> &usize {
+        &self.count
+    }
+
+    /// Number of numeric cells.
+    pub fn get_numeric_count(&self) -> &usize {
+        &self.numeric_count
+    }
+
+    /// Smallest numeric value. (None if there is no numeric cell.)
+    pub fn get_min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// Largest numeric value. (None if there is no numeric cell.)
+    pub fn get_max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// Sum of the numeric values.
+    pub fn get_sum(&self) -> &f64 {
+        &self.sum
+    }
+
+    /// Mean of the numeric values. (None if there is no numeric cell.)
+    pub fn get_mean(&self) -> Option<f64> {
+        match self.numeric_count {
+            0 => None,
+            n => Some(self.sum / n as f64),
+        }
+    }
+
+    /// Values of the cells that are not empty, without duplicates, in order of appearance.
+    pub fn get_distinct_values(&self) -> &Vec<String> {
+        &self.distinct_values
+    }
+
+    pub(crate) fn add_value(&mut self, value: &CellRawValue) -> &mut Self {
+        if value.is_empty() {
+            return self;
+        }
+        self.count += 1;
+        let number = match value {
+            CellRawValue::Numeric(v) => Some(*v),
+            CellRawValue::Lazy(v) => v.parse::<f64>().ok(),
+            _ => None,
+        };
+        if let Some(v) = number {
+            self.numeric_count += 1;
+            self.sum += v;
+            self.min = Some(self.min.map_or(v, |min| min.min(v)));
+            self.max = Some(self.max.map_or(v, |max| max.max(v)));
+        }
+        let text = match value {
+            CellRawValue::Lazy(v) => v.clone(),
+            _ => value.to_string(),
+        };
+        if self.distinct_set.insert(text.clone()) {
+            self.distinct_values.push(text);
+        }
+        self
+    }
+}
@@ -70,15 +70,15 @@ impl Columns {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"col" {
                     let mut obj = Column::default();
-                    obj.set_attributes(reader, e, stylesheet);
-                    let min = get_attribute(e, b"min").unwrap().parse::<u32>().unwrap();
-                    let max = get_attribute(e, b"max").unwrap().parse::<u32>().unwrap();
+                    obj.set_attributes(reader, e, stylesheet)?;
+                    let min = get_required_attribute(e, b"min")?.parse::<u32>().map_err(parse_error)?;
+                    let max = get_required_attribute(e, b"max")?.parse::<u32>().map_err(parse_error)?;
                     for i in min..=max {
                         obj.set_col_num(i);
                         self.set_column(obj.clone());
@@ -87,10 +87,10 @@ impl Columns {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cols" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cols")
+            Event::Eof => return Err(eof_error("cols"))
         );
     }
 
@@ -166,7 +166,7 @@ impl Columns {
         }
         attributes.push(("customWidth", "1"));
         let xf_index_str: String;
-        let xf_index = stylesheet.set_style(column.get_style());
+        let xf_index = stylesheet.set_style_shared(column.get_style_shared());
         if xf_index > 0 {
             xf_index_str = xf_index.to_string();
             attributes.push(("style", &xf_index_str));
@@ -79,11 +79,11 @@ impl Comment {
         reader: &mut Reader<R>,
         e: &BytesStart,
         authors: &[String],
-    ) {
-        let coordinate = get_attribute(e, b"ref").unwrap();
+    ) -> Result<(), XlsxError> {
+        let coordinate = get_required_attribute(e, b"ref")?;
         self.get_coordinate_mut().set_coordinate(coordinate);
 
-        let author_id = get_attribute(e, b"authorId")
+        let author_id = get_attribute(e, b"authorId")?
             .unwrap()
             .parse::<usize>()
             .unwrap();
@@ -94,15 +94,15 @@ impl Comment {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    self.get_text_mut().set_attributes_text(reader, e);
+                    self.get_text_mut().set_attributes_text(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"comment" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "comment")
+            Event::Eof => return Err(eof_error("comment"))
         );
     }
 }
@@ -0,0 +1,30 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CompatibilityValues {
+    Excel2016,
+    Minimal,
+}
+impl Default for CompatibilityValues {
+    fn default() -> Self {
+        Self::Excel2016
+    }
+}
+impl EnumTrait for CompatibilityValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Excel2016 => "excel2016",
+            Self::Minimal => "minimal",
+        }
+    }
+}
+impl FromStr for CompatibilityValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "excel2016" => Ok(Self::Excel2016),
+            "minimal" => Ok(Self::Minimal),
+            _ => Err(()),
+        }
+    }
+}
@@ -56,8 +56,8 @@ impl ConditionalFormatting {
         reader: &mut Reader<R>,
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
-    ) {
-        if let Some(v) = get_attribute(e, b"sqref") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"sqref")? {
             self.sequence_of_references.set_sqref(v);
         }
 
@@ -66,23 +66,23 @@ impl ConditionalFormatting {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
                     let mut obj = ConditionalFormattingRule::default();
-                    obj.set_attributes(reader, e, differential_formats, true);
+                    obj.set_attributes(reader, e, differential_formats, true)?;
                     self.conditional_collection.push(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
                     let mut obj = ConditionalFormattingRule::default();
-                    obj.set_attributes(reader, e, differential_formats, false);
+                    obj.set_attributes(reader, e, differential_formats, false)?;
                     self.conditional_collection.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"conditionalFormatting" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "conditionalFormatting")
+            Event::Eof => return Err(eof_error("conditionalFormatting"))
         );
     }
 
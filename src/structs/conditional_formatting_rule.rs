@@ -220,34 +220,40 @@ impl ConditionalFormattingRule {
         self
     }
 
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if let Some(v) = &mut self.formula {
+            v.rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, operator, "operator");
 
-        if let Some(v) = get_attribute(e, b"dxfId") {
-            let dxf_id = v.parse::<usize>().unwrap();
+        if let Some(v) = get_attribute(e, b"dxfId")? {
+            let dxf_id = v.parse::<usize>().map_err(parse_error)?;
             let style = differential_formats.get_style(dxf_id);
             self.set_style(style);
         }
 
-        set_string_from_xml!(self, e, priority, "priority");
+        set_number_from_xml!(self, e, priority, "priority");
         set_string_from_xml!(self, e, percent, "percent");
         set_string_from_xml!(self, e, bottom, "bottom");
-        set_string_from_xml!(self, e, rank, "rank");
+        set_number_from_xml!(self, e, rank, "rank");
         set_string_from_xml!(self, e, stop_if_true, "stopIfTrue");
-        set_string_from_xml!(self, e, std_dev, "stdDev");
+        set_number_from_xml!(self, e, std_dev, "stdDev");
         set_string_from_xml!(self, e, time_period, "timePeriod");
         set_string_from_xml!(self, e, above_average, "aboveAverage");
         set_string_from_xml!(self, e, equal_average, "equalAverage");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -256,22 +262,22 @@ impl ConditionalFormattingRule {
                 match e.name().into_inner() {
                     b"colorScale" => {
                         let mut obj = ColorScale::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.color_scale = Some(obj);
                     }
                     b"dataBar" => {
                         let mut obj = DataBar::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.data_bar = Some(obj);
                     }
                     b"iconSet" => {
                         let mut obj = IconSet::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.icon_set = Some(obj);
                     }
                     b"formula" => {
                         let mut obj = Formula::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula = Some(obj);
                     }
                     _ => (),
@@ -279,10 +285,10 @@ impl ConditionalFormattingRule {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cfRule")
+            Event::Eof => return Err(eof_error("cfRule"))
         );
     }
 
@@ -0,0 +1,111 @@
+// connection
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use reader::driver::*;
+use structs::BooleanValue;
+use structs::StringValue;
+use structs::UInt32Value;
+
+/// External data connection (xl/connections.xml). Read only.
+#[derive(Clone, Default, Debug)]
+pub struct Connection {
+    id: UInt32Value,
+    name: StringValue,
+    description: StringValue,
+    connection_type: UInt32Value,
+    refresh_on_load: BooleanValue,
+    connection_string: StringValue,
+    command: StringValue,
+}
+
+impl Connection {
+    pub fn get_id(&self) -> &u32 {
+        self.id.get_value()
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    pub fn get_description(&self) -> &str {
+        self.description.get_value_str()
+    }
+
+    /// Get connection type.
+    /// 1: ODBC, 2: DAO, 4: Web query, 5: OLE DB, 6: Text, 7: ADO ...
+    pub fn get_connection_type(&self) -> &u32 {
+        self.connection_type.get_value()
+    }
+
+    pub fn get_refresh_on_load(&self) -> &bool {
+        self.refresh_on_load.get_value()
+    }
+
+    /// Get connection string (dbPr@connection).
+    pub fn get_connection_string(&self) -> &str {
+        self.connection_string.get_value_str()
+    }
+
+    /// Get command text (dbPr@command).
+    pub fn get_command(&self) -> &str {
+        self.command.get_value_str()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, description, "description");
+        set_number_from_xml!(self, e, connection_type, "type");
+        set_string_from_xml!(self, e, refresh_on_load, "refreshOnLoad");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"dbPr" {
+                    set_string_from_xml!(self, e, connection_string, "connection");
+                    set_string_from_xml!(self, e, command, "command");
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"connection" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("connection"))
+        );
+    }
+
+    pub(crate) fn read_list(data: &[u8]) -> Result<Vec<Connection>, XlsxError> {
+        let mut reader = Reader::from_reader(data);
+        reader.config_mut().trim_text(true);
+        let mut result: Vec<Connection> = Vec::new();
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"connection" {
+                    let mut obj = Connection::default();
+                    obj.set_attributes(&mut reader, e, true)?;
+                    result.push(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"connection" {
+                    let mut obj = Connection::default();
+                    obj.set_attributes(&mut reader, e, false)?;
+                    result.push(obj);
+                }
+            },
+            Event::Eof => break
+        );
+        Ok(result)
+    }
+}
@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConnectorType {
+    Straight,
+    Elbow,
+    Curved,
+}
+impl Default for ConnectorType {
+    fn default() -> Self {
+        Self::Straight
+    }
+}
+impl EnumTrait for ConnectorType {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Straight => "straightConnector1",
+            Self::Elbow => "bentConnector3",
+            Self::Curved => "curvedConnector3",
+        }
+    }
+}
+impl FromStr for ConnectorType {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "straightConnector1" => Ok(Self::Straight),
+            "bentConnector3" => Ok(Self::Elbow),
+            "curvedConnector3" => Ok(Self::Curved),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use super::ColumnReference;
 use super::RowReference;
 use helper::coordinate::*;
+use std::fmt;
+use std::str::FromStr;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentValue;
+use XlsxError;
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Coordinate {
@@ -10,17 +13,78 @@ pub struct Coordinate {
     row: RowReference,
 }
 
-impl ToString for Coordinate {
-    fn to_string(&self) -> String {
-        coordinate_from_index_with_lock(
-            self.column.get_num(),
-            self.row.get_num(),
-            self.column.get_is_lock(),
-            self.row.get_is_lock(),
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            coordinate_from_index_with_lock(
+                self.column.get_num(),
+                self.row.get_num(),
+                self.column.get_is_lock(),
+                self.row.get_is_lock(),
+            )
         )
     }
 }
 
+impl FromStr for Coordinate {
+    type Err = XlsxError;
+
+    /// Parse a cell reference. ex) `"AB12"`, `"$A$1"`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().to_uppercase();
+        if !is_coordinate(&value) {
+            return Err(XlsxError::Coordinate(s.to_string()));
+        }
+        let mut coordinate = Coordinate::default();
+        coordinate.set_coordinate(value);
+        Ok(coordinate)
+    }
+}
+
+impl From<(u32, u32)> for Coordinate {
+    /// `(col, row)`, both starting at `1`.
+    fn from(value: (u32, u32)) -> Self {
+        let mut coordinate = Coordinate::default();
+        coordinate.set_col_num(value.0).set_row_num(value.1);
+        coordinate
+    }
+}
+
+impl From<&Coordinate> for (u32, u32) {
+    fn from(value: &Coordinate) -> Self {
+        (*value.get_col_num(), *value.get_row_num())
+    }
+}
+
+impl From<&Coordinate> for CellCoordinates {
+    fn from(value: &Coordinate) -> Self {
+        (*value.get_col_num(), *value.get_row_num()).into()
+    }
+}
+
+impl From<Coordinate> for CellCoordinates {
+    fn from(value: Coordinate) -> Self {
+        (&value).into()
+    }
+}
+
+// `$A$1` style: 1-3 column letters and a row number, each optionally locked.
+fn is_coordinate(value: &str) -> bool {
+    let value = value.strip_prefix('$').unwrap_or(value);
+    let col_len = value.chars().take_while(|c| c.is_ascii_uppercase()).count();
+    if !(1..=3).contains(&col_len) || column_index_from_string(&value[..col_len]) > 16384 {
+        return false;
+    }
+    let row = &value[col_len..];
+    let row = row.strip_prefix('$').unwrap_or(row);
+    if row.is_empty() || !row.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    matches!(row.parse::<u32>(), Ok(1..=1048576))
+}
+
 impl Coordinate {
     pub fn get_col_num(&self) -> &u32 {
         self.column.get_num()
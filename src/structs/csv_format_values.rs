@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CsvFormatValues {
+    Csv,
+    Tsv,
+    FixedWidth,
+}
+impl Default for CsvFormatValues {
+    fn default() -> Self {
+        Self::Csv
+    }
+}
+impl EnumTrait for CsvFormatValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::FixedWidth => "fixed_width",
+        }
+    }
+}
+impl FromStr for CsvFormatValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            "fixed_width" => Ok(Self::FixedWidth),
+            _ => Err(()),
+        }
+    }
+}
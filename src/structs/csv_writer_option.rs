@@ -1,11 +1,17 @@
 use super::EnumValue;
 use structs::CsvEncodeValues;
+use structs::CsvFormatValues;
+use structs::Locale;
 
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct CsvWriterOption {
     pub(crate) csv_encode_values: EnumValue<CsvEncodeValues>,
+    pub(crate) csv_format_values: EnumValue<CsvFormatValues>,
+    pub(crate) column_widths: Vec<usize>,
     pub(crate) wrap_with_char: String,
     pub(crate) do_trim: bool,
+    pub(crate) do_sanitize: bool,
+    pub(crate) locale: Option<Locale>,
 }
 impl CsvWriterOption {
     pub fn get_csv_encode_value(&self) -> &CsvEncodeValues {
@@ -17,6 +23,27 @@ impl CsvWriterOption {
         self
     }
 
+    pub fn get_csv_format_value(&self) -> &CsvFormatValues {
+        self.csv_format_values.get_value()
+    }
+
+    /// Write comma (or locale list separator) separated, tab separated or fixed width columns.
+    pub fn set_csv_format_value(&mut self, value: CsvFormatValues) -> &mut Self {
+        self.csv_format_values.set_value(value);
+        self
+    }
+
+    pub fn get_column_widths(&self) -> &Vec<usize> {
+        &self.column_widths
+    }
+
+    /// Width of each column in characters for `CsvFormatValues::FixedWidth`.
+    /// Longer values are cut. Columns without a width get the width of their longest value.
+    pub fn set_column_widths(&mut self, value: Vec<usize>) -> &mut Self {
+        self.column_widths = value;
+        self
+    }
+
     pub fn get_wrap_with_char(&self) -> &str {
         &self.wrap_with_char
     }
@@ -34,4 +61,30 @@ impl CsvWriterOption {
         self.do_trim = value;
         self
     }
+
+    pub fn get_do_sanitize(&self) -> &bool {
+        &self.do_sanitize
+    }
+
+    /// Remove control characters from text cells and prefix a text read as a formula with `'`.
+    /// See `helper::sanitize::sanitize_text`.
+    pub fn set_do_sanitize(&mut self, value: bool) -> &mut Self {
+        self.do_sanitize = value;
+        self
+    }
+
+    pub fn get_locale(&self) -> Option<&Locale> {
+        self.locale.as_ref()
+    }
+
+    /// Use the list separator and decimal separator of `locale`.
+    pub fn set_locale(&mut self, value: Locale) -> &mut Self {
+        self.locale = Some(value);
+        self
+    }
+
+    pub fn remove_locale(&mut self) -> &mut Self {
+        self.locale = None;
+        self
+    }
 }
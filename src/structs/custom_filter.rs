@@ -0,0 +1,56 @@
+// customFilter
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomFilter {
+    operator: StringValue,
+    value: StringValue,
+}
+
+impl CustomFilter {
+    /// Get Operator.
+    /// ex) "equal", "lessThan", "greaterThanOrEqual", "notEqual"
+    pub fn get_operator(&self) -> &str {
+        self.operator.get_value_str()
+    }
+
+    pub fn set_operator<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.operator.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &str {
+        self.value.get_value_str()
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, operator, "operator");
+        set_string_from_xml!(self, e, value, "val");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.operator.has_value() {
+            attributes.push(("operator", self.operator.get_value_str()));
+        }
+        attributes.push(("val", self.value.get_value_str()));
+        write_start_tag(writer, "customFilter", attributes, true);
+    }
+}
@@ -0,0 +1,85 @@
+// customFilters
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::CustomFilter;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomFilters {
+    and: BooleanValue,
+    custom_filter: Vec<CustomFilter>,
+}
+
+impl CustomFilters {
+    pub fn get_and(&self) -> &bool {
+        self.and.get_value()
+    }
+
+    pub fn set_and(&mut self, value: bool) -> &mut Self {
+        self.and.set_value(value);
+        self
+    }
+
+    pub fn get_custom_filter_list(&self) -> &Vec<CustomFilter> {
+        &self.custom_filter
+    }
+
+    pub fn get_custom_filter_list_mut(&mut self) -> &mut Vec<CustomFilter> {
+        &mut self.custom_filter
+    }
+
+    pub fn add_custom_filter(&mut self, value: CustomFilter) -> &mut Self {
+        self.custom_filter.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, and, "and");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"customFilter" {
+                    let mut obj = CustomFilter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_custom_filter(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customFilters" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("customFilters"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilters
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.and.has_value() {
+            attributes.push(("and", self.and.get_value_string()));
+        }
+        write_start_tag(writer, "customFilters", attributes, false);
+
+        // customFilter
+        for custom_filter in &self.custom_filter {
+            custom_filter.write_to(writer);
+        }
+
+        write_end_tag(writer, "customFilters");
+    }
+}
@@ -51,30 +51,30 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::from("");
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"Properties" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Properties")
+            Event::Eof => return Err(eof_error("Properties"))
         );
     }
 
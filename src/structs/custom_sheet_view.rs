@@ -0,0 +1,585 @@
+// customSheetView
+use super::AutoFilter;
+use super::BooleanValue;
+use super::ColumnBreaks;
+use super::EnumValue;
+use super::HeaderFooter;
+use super::PageMargins;
+use super::PageSetup;
+use super::Pane;
+use super::PrintOptions;
+use super::RowBreaks;
+use super::Selection;
+use super::SheetViewValues;
+use super::StringValue;
+use super::UInt32Value;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// A saved view of a worksheet (filter, print and display settings).
+#[derive(Clone, Default, Debug)]
+pub struct CustomSheetView {
+    guid: StringValue,
+    scale: UInt32Value,
+    color_id: UInt32Value,
+    show_page_breaks: BooleanValue,
+    show_formulas: BooleanValue,
+    show_grid_lines: BooleanValue,
+    show_row_col: BooleanValue,
+    outline_symbols: BooleanValue,
+    zero_values: BooleanValue,
+    fit_to_page: BooleanValue,
+    print_area: BooleanValue,
+    filter: BooleanValue,
+    show_auto_filter: BooleanValue,
+    hidden_rows: BooleanValue,
+    hidden_columns: BooleanValue,
+    state: StringValue,
+    filter_unique: BooleanValue,
+    view: EnumValue<SheetViewValues>,
+    show_ruler: BooleanValue,
+    top_left_cell: StringValue,
+    pane: Option<Pane>,
+    selection: Option<Selection>,
+    row_breaks: Option<RowBreaks>,
+    column_breaks: Option<ColumnBreaks>,
+    page_margins: Option<PageMargins>,
+    print_options: Option<PrintOptions>,
+    page_setup: Option<PageSetup>,
+    header_footer: Option<HeaderFooter>,
+    auto_filter: Option<AutoFilter>,
+}
+
+impl CustomSheetView {
+    pub fn get_guid(&self) -> &str {
+        self.guid.get_value_str()
+    }
+
+    pub fn set_guid<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.guid.set_value(value);
+        self
+    }
+
+    pub fn get_scale(&self) -> &u32 {
+        self.scale.get_value()
+    }
+
+    pub fn set_scale(&mut self, value: u32) -> &mut Self {
+        self.scale.set_value(value);
+        self
+    }
+
+    pub fn get_color_id(&self) -> &u32 {
+        self.color_id.get_value()
+    }
+
+    pub fn set_color_id(&mut self, value: u32) -> &mut Self {
+        self.color_id.set_value(value);
+        self
+    }
+
+    pub fn get_show_page_breaks(&self) -> &bool {
+        self.show_page_breaks.get_value()
+    }
+
+    pub fn set_show_page_breaks(&mut self, value: bool) -> &mut Self {
+        self.show_page_breaks.set_value(value);
+        self
+    }
+
+    pub fn get_show_formulas(&self) -> &bool {
+        self.show_formulas.get_value()
+    }
+
+    pub fn set_show_formulas(&mut self, value: bool) -> &mut Self {
+        self.show_formulas.set_value(value);
+        self
+    }
+
+    pub fn get_show_grid_lines(&self) -> &bool {
+        self.show_grid_lines.get_value()
+    }
+
+    pub fn set_show_grid_lines(&mut self, value: bool) -> &mut Self {
+        self.show_grid_lines.set_value(value);
+        self
+    }
+
+    pub fn get_show_row_col(&self) -> &bool {
+        self.show_row_col.get_value()
+    }
+
+    pub fn set_show_row_col(&mut self, value: bool) -> &mut Self {
+        self.show_row_col.set_value(value);
+        self
+    }
+
+    pub fn get_outline_symbols(&self) -> &bool {
+        self.outline_symbols.get_value()
+    }
+
+    pub fn set_outline_symbols(&mut self, value: bool) -> &mut Self {
+        self.outline_symbols.set_value(value);
+        self
+    }
+
+    pub fn get_zero_values(&self) -> &bool {
+        self.zero_values.get_value()
+    }
+
+    pub fn set_zero_values(&mut self, value: bool) -> &mut Self {
+        self.zero_values.set_value(value);
+        self
+    }
+
+    pub fn get_fit_to_page(&self) -> &bool {
+        self.fit_to_page.get_value()
+    }
+
+    pub fn set_fit_to_page(&mut self, value: bool) -> &mut Self {
+        self.fit_to_page.set_value(value);
+        self
+    }
+
+    pub fn get_print_area(&self) -> &bool {
+        self.print_area.get_value()
+    }
+
+    pub fn set_print_area(&mut self, value: bool) -> &mut Self {
+        self.print_area.set_value(value);
+        self
+    }
+
+    pub fn get_filter(&self) -> &bool {
+        self.filter.get_value()
+    }
+
+    pub fn set_filter(&mut self, value: bool) -> &mut Self {
+        self.filter.set_value(value);
+        self
+    }
+
+    pub fn get_show_auto_filter(&self) -> &bool {
+        self.show_auto_filter.get_value()
+    }
+
+    pub fn set_show_auto_filter(&mut self, value: bool) -> &mut Self {
+        self.show_auto_filter.set_value(value);
+        self
+    }
+
+    pub fn get_hidden_rows(&self) -> &bool {
+        self.hidden_rows.get_value()
+    }
+
+    pub fn set_hidden_rows(&mut self, value: bool) -> &mut Self {
+        self.hidden_rows.set_value(value);
+        self
+    }
+
+    pub fn get_hidden_columns(&self) -> &bool {
+        self.hidden_columns.get_value()
+    }
+
+    pub fn set_hidden_columns(&mut self, value: bool) -> &mut Self {
+        self.hidden_columns.set_value(value);
+        self
+    }
+
+    pub fn get_state(&self) -> &str {
+        self.state.get_value_str()
+    }
+
+    pub fn set_state<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.state.set_value(value);
+        self
+    }
+
+    pub fn get_filter_unique(&self) -> &bool {
+        self.filter_unique.get_value()
+    }
+
+    pub fn set_filter_unique(&mut self, value: bool) -> &mut Self {
+        self.filter_unique.set_value(value);
+        self
+    }
+
+    pub fn get_view(&self) -> &SheetViewValues {
+        self.view.get_value()
+    }
+
+    pub fn set_view(&mut self, value: SheetViewValues) -> &mut Self {
+        self.view.set_value(value);
+        self
+    }
+
+    pub fn get_show_ruler(&self) -> &bool {
+        self.show_ruler.get_value()
+    }
+
+    pub fn set_show_ruler(&mut self, value: bool) -> &mut Self {
+        self.show_ruler.set_value(value);
+        self
+    }
+
+    pub fn get_top_left_cell(&self) -> &str {
+        self.top_left_cell.get_value_str()
+    }
+
+    pub fn set_top_left_cell<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.top_left_cell.set_value(value);
+        self
+    }
+
+    pub fn get_pane(&self) -> Option<&Pane> {
+        self.pane.as_ref()
+    }
+
+    pub fn get_pane_mut(&mut self) -> Option<&mut Pane> {
+        self.pane.as_mut()
+    }
+
+    pub fn set_pane(&mut self, value: Pane) -> &mut Self {
+        self.pane = Some(value);
+        self
+    }
+
+    pub fn get_selection(&self) -> Option<&Selection> {
+        self.selection.as_ref()
+    }
+
+    pub fn get_selection_mut(&mut self) -> Option<&mut Selection> {
+        self.selection.as_mut()
+    }
+
+    pub fn set_selection(&mut self, value: Selection) -> &mut Self {
+        self.selection = Some(value);
+        self
+    }
+
+    pub fn get_row_breaks(&self) -> Option<&RowBreaks> {
+        self.row_breaks.as_ref()
+    }
+
+    pub fn get_row_breaks_mut(&mut self) -> Option<&mut RowBreaks> {
+        self.row_breaks.as_mut()
+    }
+
+    pub fn set_row_breaks(&mut self, value: RowBreaks) -> &mut Self {
+        self.row_breaks = Some(value);
+        self
+    }
+
+    pub fn get_column_breaks(&self) -> Option<&ColumnBreaks> {
+        self.column_breaks.as_ref()
+    }
+
+    pub fn get_column_breaks_mut(&mut self) -> Option<&mut ColumnBreaks> {
+        self.column_breaks.as_mut()
+    }
+
+    pub fn set_column_breaks(&mut self, value: ColumnBreaks) -> &mut Self {
+        self.column_breaks = Some(value);
+        self
+    }
+
+    pub fn get_page_margins(&self) -> Option<&PageMargins> {
+        self.page_margins.as_ref()
+    }
+
+    pub fn get_page_margins_mut(&mut self) -> Option<&mut PageMargins> {
+        self.page_margins.as_mut()
+    }
+
+    pub fn set_page_margins(&mut self, value: PageMargins) -> &mut Self {
+        self.page_margins = Some(value);
+        self
+    }
+
+    pub fn get_print_options(&self) -> Option<&PrintOptions> {
+        self.print_options.as_ref()
+    }
+
+    pub fn get_print_options_mut(&mut self) -> Option<&mut PrintOptions> {
+        self.print_options.as_mut()
+    }
+
+    pub fn set_print_options(&mut self, value: PrintOptions) -> &mut Self {
+        self.print_options = Some(value);
+        self
+    }
+
+    pub fn get_page_setup(&self) -> Option<&PageSetup> {
+        self.page_setup.as_ref()
+    }
+
+    pub fn get_page_setup_mut(&mut self) -> Option<&mut PageSetup> {
+        self.page_setup.as_mut()
+    }
+
+    pub fn set_page_setup(&mut self, value: PageSetup) -> &mut Self {
+        self.page_setup = Some(value);
+        self
+    }
+
+    pub fn get_header_footer(&self) -> Option<&HeaderFooter> {
+        self.header_footer.as_ref()
+    }
+
+    pub fn get_header_footer_mut(&mut self) -> Option<&mut HeaderFooter> {
+        self.header_footer.as_mut()
+    }
+
+    pub fn set_header_footer(&mut self, value: HeaderFooter) -> &mut Self {
+        self.header_footer = Some(value);
+        self
+    }
+
+    pub fn get_auto_filter(&self) -> Option<&AutoFilter> {
+        self.auto_filter.as_ref()
+    }
+
+    pub fn get_auto_filter_mut(&mut self) -> Option<&mut AutoFilter> {
+        self.auto_filter.as_mut()
+    }
+
+    pub fn set_auto_filter(&mut self, value: AutoFilter) -> &mut Self {
+        self.auto_filter = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, guid, "guid");
+        set_number_from_xml!(self, e, scale, "scale");
+        set_number_from_xml!(self, e, color_id, "colorId");
+        set_string_from_xml!(self, e, show_page_breaks, "showPageBreaks");
+        set_string_from_xml!(self, e, show_formulas, "showFormulas");
+        set_string_from_xml!(self, e, show_grid_lines, "showGridLines");
+        set_string_from_xml!(self, e, show_row_col, "showRowCol");
+        set_string_from_xml!(self, e, outline_symbols, "outlineSymbols");
+        set_string_from_xml!(self, e, zero_values, "zeroValues");
+        set_string_from_xml!(self, e, fit_to_page, "fitToPage");
+        set_string_from_xml!(self, e, print_area, "printArea");
+        set_string_from_xml!(self, e, filter, "filter");
+        set_string_from_xml!(self, e, show_auto_filter, "showAutoFilter");
+        set_string_from_xml!(self, e, hidden_rows, "hiddenRows");
+        set_string_from_xml!(self, e, hidden_columns, "hiddenColumns");
+        set_string_from_xml!(self, e, state, "state");
+        set_string_from_xml!(self, e, filter_unique, "filterUnique");
+        set_string_from_xml!(self, e, view, "view");
+        set_string_from_xml!(self, e, show_ruler, "showRuler");
+        set_string_from_xml!(self, e, top_left_cell, "topLeftCell");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"pane" => {
+                        let mut obj = Pane::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_pane(obj);
+                    }
+                    b"selection" => {
+                        let mut obj = Selection::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_selection(obj);
+                    }
+                    b"pageMargins" => {
+                        let mut obj = PageMargins::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_page_margins(obj);
+                    }
+                    b"printOptions" => {
+                        let mut obj = PrintOptions::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_print_options(obj);
+                    }
+                    b"pageSetup" => {
+                        let mut obj = PageSetup::default();
+                        obj.set_attributes(reader, e, None)?;
+                        self.set_page_setup(obj);
+                    }
+                    b"autoFilter" => {
+                        let mut obj = AutoFilter::default();
+                        obj.set_attributes(reader, e, true)?;
+                        self.set_auto_filter(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"rowBreaks" => {
+                        let mut obj = RowBreaks::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_row_breaks(obj);
+                    }
+                    b"colBreaks" => {
+                        let mut obj = ColumnBreaks::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_column_breaks(obj);
+                    }
+                    b"headerFooter" => {
+                        let mut obj = HeaderFooter::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_header_footer(obj);
+                    }
+                    b"autoFilter" => {
+                        let mut obj = AutoFilter::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.set_auto_filter(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customSheetView" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("customSheetView"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        let empty_flag = self.pane.is_none()
+            && self.selection.is_none()
+            && self.row_breaks.is_none()
+            && self.column_breaks.is_none()
+            && self.page_margins.is_none()
+            && self.print_options.is_none()
+            && self.page_setup.is_none()
+            && self.header_footer.is_none()
+            && self.auto_filter.is_none();
+
+        // customSheetView
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.guid.has_value() {
+            attributes.push(("guid", self.guid.get_value_str()));
+        }
+        let scale = self.scale.get_value_string();
+        if self.scale.has_value() {
+            attributes.push(("scale", &scale));
+        }
+        let color_id = self.color_id.get_value_string();
+        if self.color_id.has_value() {
+            attributes.push(("colorId", &color_id));
+        }
+        if self.show_page_breaks.has_value() {
+            attributes.push(("showPageBreaks", self.show_page_breaks.get_value_string()));
+        }
+        if self.show_formulas.has_value() {
+            attributes.push(("showFormulas", self.show_formulas.get_value_string()));
+        }
+        if self.show_grid_lines.has_value() {
+            attributes.push(("showGridLines", self.show_grid_lines.get_value_string()));
+        }
+        if self.show_row_col.has_value() {
+            attributes.push(("showRowCol", self.show_row_col.get_value_string()));
+        }
+        if self.outline_symbols.has_value() {
+            attributes.push(("outlineSymbols", self.outline_symbols.get_value_string()));
+        }
+        if self.zero_values.has_value() {
+            attributes.push(("zeroValues", self.zero_values.get_value_string()));
+        }
+        if self.fit_to_page.has_value() {
+            attributes.push(("fitToPage", self.fit_to_page.get_value_string()));
+        }
+        if self.print_area.has_value() {
+            attributes.push(("printArea", self.print_area.get_value_string()));
+        }
+        if self.filter.has_value() {
+            attributes.push(("filter", self.filter.get_value_string()));
+        }
+        if self.show_auto_filter.has_value() {
+            attributes.push(("showAutoFilter", self.show_auto_filter.get_value_string()));
+        }
+        if self.hidden_rows.has_value() {
+            attributes.push(("hiddenRows", self.hidden_rows.get_value_string()));
+        }
+        if self.hidden_columns.has_value() {
+            attributes.push(("hiddenColumns", self.hidden_columns.get_value_string()));
+        }
+        if self.state.has_value() {
+            attributes.push(("state", self.state.get_value_str()));
+        }
+        if self.filter_unique.has_value() {
+            attributes.push(("filterUnique", self.filter_unique.get_value_string()));
+        }
+        if self.view.has_value() {
+            attributes.push(("view", self.view.get_value_string()));
+        }
+        if self.show_ruler.has_value() {
+            attributes.push(("showRuler", self.show_ruler.get_value_string()));
+        }
+        if self.top_left_cell.has_value() {
+            attributes.push(("topLeftCell", self.top_left_cell.get_value_str()));
+        }
+        write_start_tag(writer, "customSheetView", attributes, empty_flag);
+
+        if empty_flag {
+            return;
+        }
+
+        // pane
+        if let Some(v) = &self.pane {
+            v.write_to(writer);
+        }
+
+        // selection
+        if let Some(v) = &self.selection {
+            v.write_to(writer);
+        }
+
+        // rowBreaks
+        if let Some(v) = &self.row_breaks {
+            v.write_to(writer);
+        }
+
+        // colBreaks
+        if let Some(v) = &self.column_breaks {
+            v.write_to(writer);
+        }
+
+        // pageMargins
+        if let Some(v) = &self.page_margins {
+            v.write_to(writer);
+        }
+
+        // printOptions
+        if let Some(v) = &self.print_options {
+            v.write_to(writer);
+        }
+
+        // pageSetup
+        if let Some(v) = &self.page_setup {
+            v.write_to(writer, &mut 0);
+        }
+
+        // headerFooter
+        if let Some(v) = &self.header_footer {
+            v.write_to(writer);
+        }
+
+        // autoFilter
+        if let Some(v) = &self.auto_filter {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "customSheetView");
+    }
+}
@@ -0,0 +1,73 @@
+// customSheetViews
+use super::CustomSheetView;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomSheetViews {
+    custom_sheet_view_list: Vec<CustomSheetView>,
+}
+
+impl CustomSheetViews {
+    pub fn get_custom_sheet_view_list(&self) -> &Vec<CustomSheetView> {
+        &self.custom_sheet_view_list
+    }
+
+    pub fn get_custom_sheet_view_list_mut(&mut self) -> &mut Vec<CustomSheetView> {
+        &mut self.custom_sheet_view_list
+    }
+
+    pub fn add_custom_sheet_view(&mut self, value: CustomSheetView) -> &mut Self {
+        self.custom_sheet_view_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"customSheetView" {
+                    let mut obj = CustomSheetView::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.add_custom_sheet_view(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"customSheetView" {
+                    let mut obj = CustomSheetView::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.add_custom_sheet_view(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customSheetViews" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("customSheetViews"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customSheetViews
+        if self.custom_sheet_view_list.is_empty() {
+            return;
+        }
+        write_start_tag(writer, "customSheetViews", vec![], false);
+
+        // customSheetView
+        for custom_sheet_view in &self.custom_sheet_view_list {
+            custom_sheet_view.write_to(writer);
+        }
+
+        write_end_tag(writer, "customSheetViews");
+    }
+}
@@ -0,0 +1,402 @@
+// customWorkbookView
+use super::BooleanValue;
+use super::Int32Value;
+use super::StringValue;
+use super::UInt32Value;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+/// A saved view of the workbook window.
+/// The matching sheet settings are kept in `CustomSheetView` by `guid`.
+#[derive(Clone, Default, Debug)]
+pub struct CustomWorkbookView {
+    name: StringValue,
+    guid: StringValue,
+    auto_update: BooleanValue,
+    merge_interval: UInt32Value,
+    changes_saved_win: BooleanValue,
+    only_sync: BooleanValue,
+    personal_view: BooleanValue,
+    include_print_settings: BooleanValue,
+    include_hidden_row_col: BooleanValue,
+    maximized: BooleanValue,
+    minimized: BooleanValue,
+    show_horizontal_scroll: BooleanValue,
+    show_vertical_scroll: BooleanValue,
+    show_sheet_tabs: BooleanValue,
+    x_window: Int32Value,
+    y_window: Int32Value,
+    window_width: UInt32Value,
+    window_height: UInt32Value,
+    tab_ratio: UInt32Value,
+    active_sheet_id: UInt32Value,
+    show_formula_bar: BooleanValue,
+    show_statusbar: BooleanValue,
+    show_comments: StringValue,
+    show_objects: StringValue,
+}
+
+impl CustomWorkbookView {
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    pub fn get_guid(&self) -> &str {
+        self.guid.get_value_str()
+    }
+
+    pub fn set_guid<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.guid.set_value(value);
+        self
+    }
+
+    pub fn get_auto_update(&self) -> &bool {
+        self.auto_update.get_value()
+    }
+
+    pub fn set_auto_update(&mut self, value: bool) -> &mut Self {
+        self.auto_update.set_value(value);
+        self
+    }
+
+    pub fn get_merge_interval(&self) -> &u32 {
+        self.merge_interval.get_value()
+    }
+
+    pub fn set_merge_interval(&mut self, value: u32) -> &mut Self {
+        self.merge_interval.set_value(value);
+        self
+    }
+
+    pub fn get_changes_saved_win(&self) -> &bool {
+        self.changes_saved_win.get_value()
+    }
+
+    pub fn set_changes_saved_win(&mut self, value: bool) -> &mut Self {
+        self.changes_saved_win.set_value(value);
+        self
+    }
+
+    pub fn get_only_sync(&self) -> &bool {
+        self.only_sync.get_value()
+    }
+
+    pub fn set_only_sync(&mut self, value: bool) -> &mut Self {
+        self.only_sync.set_value(value);
+        self
+    }
+
+    pub fn get_personal_view(&self) -> &bool {
+        self.personal_view.get_value()
+    }
+
+    pub fn set_personal_view(&mut self, value: bool) -> &mut Self {
+        self.personal_view.set_value(value);
+        self
+    }
+
+    pub fn get_include_print_settings(&self) -> &bool {
+        self.include_print_settings.get_value()
+    }
+
+    pub fn set_include_print_settings(&mut self, value: bool) -> &mut Self {
+        self.include_print_settings.set_value(value);
+        self
+    }
+
+    pub fn get_include_hidden_row_col(&self) -> &bool {
+        self.include_hidden_row_col.get_value()
+    }
+
+    pub fn set_include_hidden_row_col(&mut self, value: bool) -> &mut Self {
+        self.include_hidden_row_col.set_value(value);
+        self
+    }
+
+    pub fn get_maximized(&self) -> &bool {
+        self.maximized.get_value()
+    }
+
+    pub fn set_maximized(&mut self, value: bool) -> &mut Self {
+        self.maximized.set_value(value);
+        self
+    }
+
+    pub fn get_minimized(&self) -> &bool {
+        self.minimized.get_value()
+    }
+
+    pub fn set_minimized(&mut self, value: bool) -> &mut Self {
+        self.minimized.set_value(value);
+        self
+    }
+
+    pub fn get_show_horizontal_scroll(&self) -> &bool {
+        self.show_horizontal_scroll.get_value()
+    }
+
+    pub fn set_show_horizontal_scroll(&mut self, value: bool) -> &mut Self {
+        self.show_horizontal_scroll.set_value(value);
+        self
+    }
+
+    pub fn get_show_vertical_scroll(&self) -> &bool {
+        self.show_vertical_scroll.get_value()
+    }
+
+    pub fn set_show_vertical_scroll(&mut self, value: bool) -> &mut Self {
+        self.show_vertical_scroll.set_value(value);
+        self
+    }
+
+    pub fn get_show_sheet_tabs(&self) -> &bool {
+        self.show_sheet_tabs.get_value()
+    }
+
+    pub fn set_show_sheet_tabs(&mut self, value: bool) -> &mut Self {
+        self.show_sheet_tabs.set_value(value);
+        self
+    }
+
+    pub fn get_x_window(&self) -> &i32 {
+        self.x_window.get_value()
+    }
+
+    pub fn set_x_window(&mut self, value: i32) -> &mut Self {
+        self.x_window.set_value(value);
+        self
+    }
+
+    pub fn get_y_window(&self) -> &i32 {
+        self.y_window.get_value()
+    }
+
+    pub fn set_y_window(&mut self, value: i32) -> &mut Self {
+        self.y_window.set_value(value);
+        self
+    }
+
+    pub fn get_window_width(&self) -> &u32 {
+        self.window_width.get_value()
+    }
+
+    pub fn set_window_width(&mut self, value: u32) -> &mut Self {
+        self.window_width.set_value(value);
+        self
+    }
+
+    pub fn get_window_height(&self) -> &u32 {
+        self.window_height.get_value()
+    }
+
+    pub fn set_window_height(&mut self, value: u32) -> &mut Self {
+        self.window_height.set_value(value);
+        self
+    }
+
+    pub fn get_tab_ratio(&self) -> &u32 {
+        self.tab_ratio.get_value()
+    }
+
+    pub fn set_tab_ratio(&mut self, value: u32) -> &mut Self {
+        self.tab_ratio.set_value(value);
+        self
+    }
+
+    pub fn get_active_sheet_id(&self) -> &u32 {
+        self.active_sheet_id.get_value()
+    }
+
+    pub fn set_active_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.active_sheet_id.set_value(value);
+        self
+    }
+
+    pub fn get_show_formula_bar(&self) -> &bool {
+        self.show_formula_bar.get_value()
+    }
+
+    pub fn set_show_formula_bar(&mut self, value: bool) -> &mut Self {
+        self.show_formula_bar.set_value(value);
+        self
+    }
+
+    pub fn get_show_statusbar(&self) -> &bool {
+        self.show_statusbar.get_value()
+    }
+
+    pub fn set_show_statusbar(&mut self, value: bool) -> &mut Self {
+        self.show_statusbar.set_value(value);
+        self
+    }
+
+    pub fn get_show_comments(&self) -> &str {
+        self.show_comments.get_value_str()
+    }
+
+    pub fn set_show_comments<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.show_comments.set_value(value);
+        self
+    }
+
+    pub fn get_show_objects(&self) -> &str {
+        self.show_objects.get_value_str()
+    }
+
+    pub fn set_show_objects<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.show_objects.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, guid, "guid");
+        set_string_from_xml!(self, e, auto_update, "autoUpdate");
+        set_number_from_xml!(self, e, merge_interval, "mergeInterval");
+        set_string_from_xml!(self, e, changes_saved_win, "changesSavedWin");
+        set_string_from_xml!(self, e, only_sync, "onlySync");
+        set_string_from_xml!(self, e, personal_view, "personalView");
+        set_string_from_xml!(self, e, include_print_settings, "includePrintSettings");
+        set_string_from_xml!(self, e, include_hidden_row_col, "includeHiddenRowCol");
+        set_string_from_xml!(self, e, maximized, "maximized");
+        set_string_from_xml!(self, e, minimized, "minimized");
+        set_string_from_xml!(self, e, show_horizontal_scroll, "showHorizontalScroll");
+        set_string_from_xml!(self, e, show_vertical_scroll, "showVerticalScroll");
+        set_string_from_xml!(self, e, show_sheet_tabs, "showSheetTabs");
+        set_number_from_xml!(self, e, x_window, "xWindow");
+        set_number_from_xml!(self, e, y_window, "yWindow");
+        set_number_from_xml!(self, e, window_width, "windowWidth");
+        set_number_from_xml!(self, e, window_height, "windowHeight");
+        set_number_from_xml!(self, e, tab_ratio, "tabRatio");
+        set_number_from_xml!(self, e, active_sheet_id, "activeSheetId");
+        set_string_from_xml!(self, e, show_formula_bar, "showFormulaBar");
+        set_string_from_xml!(self, e, show_statusbar, "showStatusbar");
+        set_string_from_xml!(self, e, show_comments, "showComments");
+        set_string_from_xml!(self, e, show_objects, "showObjects");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customWorkbookView" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("customWorkbookView"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customWorkbookView
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.name.has_value() {
+            attributes.push(("name", self.name.get_value_str()));
+        }
+        if self.guid.has_value() {
+            attributes.push(("guid", self.guid.get_value_str()));
+        }
+        if self.auto_update.has_value() {
+            attributes.push(("autoUpdate", self.auto_update.get_value_string()));
+        }
+        let merge_interval = self.merge_interval.get_value_string();
+        if self.merge_interval.has_value() {
+            attributes.push(("mergeInterval", &merge_interval));
+        }
+        if self.changes_saved_win.has_value() {
+            attributes.push(("changesSavedWin", self.changes_saved_win.get_value_string()));
+        }
+        if self.only_sync.has_value() {
+            attributes.push(("onlySync", self.only_sync.get_value_string()));
+        }
+        if self.personal_view.has_value() {
+            attributes.push(("personalView", self.personal_view.get_value_string()));
+        }
+        if self.include_print_settings.has_value() {
+            attributes.push((
+                "includePrintSettings",
+                self.include_print_settings.get_value_string(),
+            ));
+        }
+        if self.include_hidden_row_col.has_value() {
+            attributes.push((
+                "includeHiddenRowCol",
+                self.include_hidden_row_col.get_value_string(),
+            ));
+        }
+        if self.maximized.has_value() {
+            attributes.push(("maximized", self.maximized.get_value_string()));
+        }
+        if self.minimized.has_value() {
+            attributes.push(("minimized", self.minimized.get_value_string()));
+        }
+        if self.show_horizontal_scroll.has_value() {
+            attributes.push((
+                "showHorizontalScroll",
+                self.show_horizontal_scroll.get_value_string(),
+            ));
+        }
+        if self.show_vertical_scroll.has_value() {
+            attributes.push((
+                "showVerticalScroll",
+                self.show_vertical_scroll.get_value_string(),
+            ));
+        }
+        if self.show_sheet_tabs.has_value() {
+            attributes.push(("showSheetTabs", self.show_sheet_tabs.get_value_string()));
+        }
+        let x_window = self.x_window.get_value_string();
+        if self.x_window.has_value() {
+            attributes.push(("xWindow", &x_window));
+        }
+        let y_window = self.y_window.get_value_string();
+        if self.y_window.has_value() {
+            attributes.push(("yWindow", &y_window));
+        }
+        let window_width = self.window_width.get_value_string();
+        if self.window_width.has_value() {
+            attributes.push(("windowWidth", &window_width));
+        }
+        let window_height = self.window_height.get_value_string();
+        if self.window_height.has_value() {
+            attributes.push(("windowHeight", &window_height));
+        }
+        let tab_ratio = self.tab_ratio.get_value_string();
+        if self.tab_ratio.has_value() {
+            attributes.push(("tabRatio", &tab_ratio));
+        }
+        let active_sheet_id = self.active_sheet_id.get_value_string();
+        if self.active_sheet_id.has_value() {
+            attributes.push(("activeSheetId", &active_sheet_id));
+        }
+        if self.show_formula_bar.has_value() {
+            attributes.push(("showFormulaBar", self.show_formula_bar.get_value_string()));
+        }
+        if self.show_statusbar.has_value() {
+            attributes.push(("showStatusbar", self.show_statusbar.get_value_string()));
+        }
+        if self.show_comments.has_value() {
+            attributes.push(("showComments", self.show_comments.get_value_str()));
+        }
+        if self.show_objects.has_value() {
+            attributes.push(("showObjects", self.show_objects.get_value_str()));
+        }
+        write_start_tag(writer, "customWorkbookView", attributes, true);
+    }
+}
@@ -5,6 +5,7 @@ use super::DataValidationValues;
 use super::EnumValue;
 use super::SequenceOfReferences;
 use super::StringValue;
+use helper::formula::rename_sheet_reference_formula;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -121,46 +122,56 @@ impl DataValidation {
         self
     }
 
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        for formula in [&mut self.formula1, &mut self.formula2] {
+            if let Some(v) =
+                rename_sheet_reference_formula(formula.get_value_str(), sheet_name, new_sheet_name)
+            {
+                formula.set_value(v);
+            }
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"type") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"type")? {
             self.r#type.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"operator") {
+        if let Some(v) = get_attribute(e, b"operator")? {
             self.operator.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"allowBlank") {
+        if let Some(v) = get_attribute(e, b"allowBlank")? {
             self.allow_blank.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"showInputMessage") {
+        if let Some(v) = get_attribute(e, b"showInputMessage")? {
             self.show_input_message.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"showErrorMessage") {
+        if let Some(v) = get_attribute(e, b"showErrorMessage")? {
             self.show_error_message.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"promptTitle") {
+        if let Some(v) = get_attribute(e, b"promptTitle")? {
             self.prompt_title.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"prompt") {
+        if let Some(v) = get_attribute(e, b"prompt")? {
             self.prompt.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"sqref") {
+        if let Some(v) = get_attribute(e, b"sqref")? {
             self.sequence_of_references.set_sqref(v);
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut value: String = String::new();
@@ -168,7 +179,7 @@ impl DataValidation {
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Text(e)) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"formula1" => {
@@ -177,11 +188,11 @@ impl DataValidation {
                     b"formula2" => {
                         self.formula2.set_value_string(std::mem::take(&mut value));
                     }
-                    b"dataValidation" => return,
+                    b"dataValidation" => return Ok(()),
                     _ => {}
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "dataValidation"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("dataValidation")),
+                Err(e) => return Err(e.into()),
                 _ => {}
             }
             buf.clear();
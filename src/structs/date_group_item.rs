@@ -0,0 +1,131 @@
+// dateGroupItem
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::StringValue;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct DateGroupItem {
+    year: UInt32Value,
+    month: UInt32Value,
+    day: UInt32Value,
+    hour: UInt32Value,
+    minute: UInt32Value,
+    second: UInt32Value,
+    date_time_grouping: StringValue,
+}
+
+impl DateGroupItem {
+    pub fn get_year(&self) -> &u32 {
+        self.year.get_value()
+    }
+
+    pub fn set_year(&mut self, value: u32) -> &mut Self {
+        self.year.set_value(value);
+        self
+    }
+
+    pub fn get_month(&self) -> &u32 {
+        self.month.get_value()
+    }
+
+    pub fn set_month(&mut self, value: u32) -> &mut Self {
+        self.month.set_value(value);
+        self
+    }
+
+    pub fn get_day(&self) -> &u32 {
+        self.day.get_value()
+    }
+
+    pub fn set_day(&mut self, value: u32) -> &mut Self {
+        self.day.set_value(value);
+        self
+    }
+
+    pub fn get_hour(&self) -> &u32 {
+        self.hour.get_value()
+    }
+
+    pub fn set_hour(&mut self, value: u32) -> &mut Self {
+        self.hour.set_value(value);
+        self
+    }
+
+    pub fn get_minute(&self) -> &u32 {
+        self.minute.get_value()
+    }
+
+    pub fn set_minute(&mut self, value: u32) -> &mut Self {
+        self.minute.set_value(value);
+        self
+    }
+
+    pub fn get_second(&self) -> &u32 {
+        self.second.get_value()
+    }
+
+    pub fn set_second(&mut self, value: u32) -> &mut Self {
+        self.second.set_value(value);
+        self
+    }
+
+    /// Get Date Time Grouping.
+    /// ex) "year", "month", "day", "hour", "minute", "second"
+    pub fn get_date_time_grouping(&self) -> &str {
+        self.date_time_grouping.get_value_str()
+    }
+
+    pub fn set_date_time_grouping<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.date_time_grouping.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, year, "year");
+        set_number_from_xml!(self, e, month, "month");
+        set_number_from_xml!(self, e, day, "day");
+        set_number_from_xml!(self, e, hour, "hour");
+        set_number_from_xml!(self, e, minute, "minute");
+        set_number_from_xml!(self, e, second, "second");
+        set_string_from_xml!(self, e, date_time_grouping, "dateTimeGrouping");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dateGroupItem
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let year = self.year.get_value_string();
+        attributes.push(("year", &year));
+        let month = self.month.get_value_string();
+        if self.month.has_value() {
+            attributes.push(("month", &month));
+        }
+        let day = self.day.get_value_string();
+        if self.day.has_value() {
+            attributes.push(("day", &day));
+        }
+        let hour = self.hour.get_value_string();
+        if self.hour.has_value() {
+            attributes.push(("hour", &hour));
+        }
+        let minute = self.minute.get_value_string();
+        if self.minute.has_value() {
+            attributes.push(("minute", &minute));
+        }
+        let second = self.second.get_value_string();
+        if self.second.has_value() {
+            attributes.push(("second", &second));
+        }
+        attributes.push(("dateTimeGrouping", self.date_time_grouping.get_value_str()));
+        write_start_tag(writer, "dateGroupItem", attributes, true);
+    }
+}
@@ -3,6 +3,7 @@ use super::BooleanValue;
 use super::StringValue;
 use super::UInt32Value;
 use helper::address::*;
+use helper::formula::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -152,6 +153,29 @@ impl DefinedName {
         result
     }
 
+    /// Returns `true` if any address points at `sheet_name`.
+    pub(crate) fn is_sheet_reference(&self, sheet_name: &str) -> bool {
+        self.address
+            .iter()
+            .any(|v| v.get_sheet_name().eq_ignore_ascii_case(sheet_name))
+    }
+
+    /// Point the addresses on `sheet_name` at `new_sheet_name`.
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        for address in &mut self.address {
+            if address.get_sheet_name().eq_ignore_ascii_case(sheet_name) {
+                address.set_sheet_name(new_sheet_name);
+            }
+        }
+        if let Some(v) = rename_sheet_reference_formula(
+            self.string_value.get_value_str(),
+            sheet_name,
+            new_sheet_name,
+        ) {
+            self.string_value.set_value(v);
+        }
+    }
+
     pub(crate) fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let value = value.into();
         for address in &mut self.address {
@@ -164,24 +188,24 @@ impl DefinedName {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
-        set_string_from_xml!(self, e, local_sheet_id, "localSheetId");
+        set_number_from_xml!(self, e, local_sheet_id, "localSheetId");
         set_string_from_xml!(self, e, hidden, "hidden");
 
         let mut value: String = String::from("");
         xml_read_loop!(
             reader,
                 Event::Text(e) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"definedName" {
                         self.set_address(value.clone());
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "definedName")
+                Event::Eof => return Err(eof_error("definedName"))
         );
     }
 
@@ -74,8 +74,8 @@ impl DiagonalBorder {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"style") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"style")? {
             self.style.set_value_string(v);
         }
 
@@ -84,7 +84,7 @@ impl DiagonalBorder {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"color" => {
-                        &mut self.color.set_attributes(reader, e);
+                        &mut self.color.set_attributes(reader, e)?;
                     }
                     _ => (),
                 },
@@ -92,8 +92,8 @@ impl DiagonalBorder {
                     b"diagonal" => return,
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "diagonal"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("diagonal")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
@@ -134,29 +134,29 @@ impl DifferentialFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"font" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_font(obj);
                     }
                     b"fill" => {
                         let mut obj = Fill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_fill(obj);
                     }
                     b"border" => {
                         let mut obj = Borders::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_borders(obj);
                     }
                     b"alignment" => {
                         let mut obj = Alignment::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_alignment(obj);
                     }
                     _ => (),
@@ -164,10 +164,10 @@ impl DifferentialFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxf")
+            Event::Eof => return Err(eof_error("dxf"))
         );
     }
 
@@ -53,22 +53,22 @@ impl DifferentialFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"dxf" {
                     let mut obj = DifferentialFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_differential_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxfs")
+            Event::Eof => return Err(eof_error("dxfs"))
         );
     }
 
@@ -30,6 +30,9 @@ pub use self::style_matrix_reference_type::*;
 mod outline;
 pub use self::outline::*;
 
+mod head_end;
+pub use self::head_end::*;
+
 mod tail_end;
 pub use self::tail_end::*;
 
@@ -287,3 +290,6 @@ pub use self::positive_size_2d_type::*;
 
 mod point_2d_type;
 pub use self::point_2d_type::*;
+
+mod hyperlink_click;
+pub use self::hyperlink_click::*;
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct AdjustValueList {
     shape_guide_collection: Vec<ShapeGuide>,
 }
@@ -33,23 +33,23 @@ impl AdjustValueList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:gd" {
                     let mut shape_guide = ShapeGuide::default();
-                    shape_guide.set_name(get_attribute(e, b"name").unwrap());
-                    shape_guide.set_fmla(get_attribute(e, b"fmla").unwrap());
+                    shape_guide.set_name(get_required_attribute(e, b"name")?);
+                    shape_guide.set_fmla(get_required_attribute(e, b"fmla")?);
                     self.add_shape_guide_collection(shape_guide);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:avLst")
+            Event::Eof => return Err(eof_error("a:avLst"))
         );
     }
 
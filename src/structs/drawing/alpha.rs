@@ -6,7 +6,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Alpha {
     val: String,
 }
@@ -23,8 +23,9 @@ impl Alpha {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(get_required_attribute(e, b"val")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
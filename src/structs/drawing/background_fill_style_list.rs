@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct BackgroundFillStyleList {
     solid_fill: Vec<SolidFill>,
     gradient_fill_collection: Vec<GradientFill>,
@@ -54,19 +54,19 @@ impl BackgroundFillStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:solidFill" => {
                     let mut obj = SolidFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.solid_fill.push(obj);
                 }
                 b"a:gradFill" => {
                     let mut obj = GradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.gradient_fill_collection.push(obj);
                 }
                 _ => (),
@@ -74,10 +74,10 @@ impl BackgroundFillStyleList {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:bgFillStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "bgFillStyleLst")
+            Event::Eof => return Err(eof_error("bgFillStyleLst"))
         );
     }
 
@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct BevelBottom {
     width: Int64Value,
     height: Int64Value,
@@ -48,10 +48,11 @@ impl BevelBottom {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, width, "w");
-        set_string_from_xml!(self, e, height, "h");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, width, "w");
+        set_number_from_xml!(self, e, height, "h");
         set_string_from_xml!(self, e, preset, "prst");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -9,7 +9,7 @@ use structs::raw::RawRelationships;
 use structs::MediaObject;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Blip {
     image: MediaObject,
     cstate: String,
@@ -44,30 +44,30 @@ impl Blip {
         e: &BytesStart,
         drawing_relationships: &RawRelationships,
         empty_flag: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"cstate") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"cstate")? {
             self.set_cstate(v);
         }
 
-        let picture_id = get_attribute(e, b"r:embed").unwrap();
-        let relationship = drawing_relationships.get_relationship_by_rid(&picture_id);
+        let picture_id = get_required_attribute(e, b"r:embed")?;
+        let relationship = drawing_relationships.get_relationship_by_rid(&picture_id)?;
         self.get_image_mut()
             .set_image_name(relationship.get_raw_file().get_file_name());
         self.get_image_mut()
             .set_image_data(relationship.get_raw_file().get_file_data().clone());
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:blip" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:blip")
+            Event::Eof => return Err(eof_error("a:blip"))
         );
     }
 
@@ -11,7 +11,7 @@ use std::io::Cursor;
 use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct BlipFill {
     rotate_with_shape: BooleanValue,
     blip: Blip,
@@ -73,7 +73,7 @@ impl BlipFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
         xml_read_loop!(
@@ -81,11 +81,13 @@ impl BlipFill {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:blip" => {
-                        self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), false);
+                        let relationships = drawing_relationships.ok_or_else(|| {
+                            XlsxError::Format("missing relationships of a:blip".to_string())
+                        })?;
+                        self.blip.set_attributes(reader, e, relationships, false)?;
                         }
                     b"a:stretch" => {
-                        self.stretch.set_attributes(reader, e);
+                        self.stretch.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -93,12 +95,14 @@ impl BlipFill {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:blip" => {
-                        self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), true);
+                        let relationships = drawing_relationships.ok_or_else(|| {
+                            XlsxError::Format("missing relationships of a:blip".to_string())
+                        })?;
+                        self.blip.set_attributes(reader, e, relationships, true)?;
                         }
                     b"a:srcRect" => {
                         let mut source_rectangle = SourceRectangle::default();
-                        source_rectangle.set_attributes(reader, e);
+                        source_rectangle.set_attributes(reader, e)?;
                         self.set_source_rectangle(source_rectangle);
                     }
                     _ => (),
@@ -106,10 +110,10 @@ impl BlipFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:blipFill" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:blipFill")
+            Event::Eof => return Err(eof_error("a:blipFill"))
         );
     }
 
@@ -11,7 +11,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct BodyProperties {
     vert_overflow: StringValue,
     horz_overflow: StringValue,
@@ -117,11 +117,11 @@ impl BodyProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false) {
             if let Ok(attr) = attr {
                 let key = attr.key.into_inner();
-                let value = get_attribute_value(&attr).unwrap();
+                let value = get_attribute_value(&attr)?;
                 match key {
                     b"vertOverflow" => {
                         self.set_vert_overflow(value);
@@ -139,16 +139,16 @@ impl BodyProperties {
                         self.wrap.set_value_string(value);
                     }
                     b"lIns" => {
-                        self.left_inset.set_value_string(value);
+                        self.left_inset.set_value_string(value)?;
                     }
                     b"tIns" => {
-                        self.top_inset.set_value_string(value);
+                        self.top_inset.set_value_string(value)?;
                     }
                     b"rIns" => {
-                        self.right_inset.set_value_string(value);
+                        self.right_inset.set_value_string(value)?;
                     }
                     b"bIns" => {
-                        self.bottom_inset.set_value_string(value);
+                        self.bottom_inset.set_value_string(value)?;
                     }
                     _ => {}
                 }
@@ -156,7 +156,7 @@ impl BodyProperties {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -164,16 +164,16 @@ impl BodyProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:spAutoFit" {
                     let mut obj = ShapeAutoFit::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_auto_fit(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:bodyPr" {
-                     return
+                     return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:bodyPr")
+            Event::Eof => return Err(eof_error("a:bodyPr"))
         );
     }
 
@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Camera {
     preset: EnumValue<PresetCameraValues>,
     rotation: Option<Rotation>,
@@ -43,11 +43,11 @@ impl Camera {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset, "prst");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -55,16 +55,16 @@ impl Camera {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:rot" {
                     let mut obj = Rotation::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.rotation = Some(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:camera" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:camera")
+            Event::Eof => return Err(eof_error("a:camera"))
         );
     }
 
@@ -154,6 +154,12 @@ pub use self::category_axis::*;
 mod scaling;
 pub use self::scaling::*;
 
+mod max_axis_value;
+pub use self::max_axis_value::*;
+
+mod min_axis_value;
+pub use self::min_axis_value::*;
+
 mod orientation;
 pub use self::orientation::*;
 
@@ -178,6 +184,9 @@ pub use self::crossing_axis::*;
 mod crosses;
 pub use self::crosses::*;
 
+mod crosses_at;
+pub use self::crosses_at::*;
+
 mod auto_labeled;
 pub use self::auto_labeled::*;
 
@@ -426,3 +435,6 @@ pub use self::area_chart_series_list::*;
 
 mod series_text;
 pub use self::series_text::*;
+
+mod data_point;
+pub use self::data_point::*;
@@ -3,6 +3,7 @@ use super::Bubble3D;
 use super::BubbleSize;
 use super::CategoryAxisData;
 use super::DataLabels;
+use super::DataPoint;
 use super::Explosion;
 use super::Formula;
 use super::Index;
@@ -19,11 +20,12 @@ use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct AreaChartSeries {
     index: Index,
     order: Order,
@@ -32,6 +34,7 @@ pub struct AreaChartSeries {
     invert_if_negative: Option<InvertIfNegative>,
     marker: Option<Marker>,
     shape_properties: Option<ShapeProperties>,
+    data_points: Vec<DataPoint>,
     category_axis_data: Option<CategoryAxisData>,
     values: Option<Values>,
     x_values: Option<XValues>,
@@ -238,6 +241,40 @@ impl AreaChartSeries {
         self
     }
 
+    pub fn get_data_points(&self) -> &Vec<DataPoint> {
+        &self.data_points
+    }
+
+    pub fn get_data_points_mut(&mut self) -> &mut Vec<DataPoint> {
+        &mut self.data_points
+    }
+
+    pub fn add_data_point(&mut self, value: DataPoint) -> &mut Self {
+        self.data_points.push(value);
+        self
+    }
+
+    /// Get the data point of the index in mutable.
+    /// A data point is added if missing.
+    /// # Arguments
+    /// * `index` - Index of the point (0-based).
+    pub fn get_data_point_mut(&mut self, index: u32) -> &mut DataPoint {
+        let position = match self
+            .data_points
+            .iter()
+            .position(|v| v.get_index().get_val() == &index)
+        {
+            Some(v) => v,
+            None => {
+                let mut obj = DataPoint::default();
+                obj.get_index_mut().set_val(index);
+                self.data_points.push(obj);
+                self.data_points.len() - 1
+            }
+        };
+        &mut self.data_points[position]
+    }
+
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
 
@@ -265,92 +302,97 @@ impl AreaChartSeries {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:v" => {
                     let mut obj = SeriesText::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_series_text(obj);
                 }
                 b"c:marker" => {
                     let mut obj = Marker::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_marker(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
+                b"c:dPt" => {
+                    let mut obj = DataPoint::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_data_point(obj);
+                }
                 b"c:cat" => {
                     let mut obj = CategoryAxisData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_category_axis_data(obj);
                 }
                 b"c:val" => {
                     let mut obj = Values::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_values(obj);
                 }
                 b"c:xVal" => {
                     let mut obj = XValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_x_values(obj);
                 }
                 b"c:yVal" => {
                     let mut obj = YValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_y_values(obj);
                 }
                 b"c:bubbleSize" => {
                     let mut obj = BubbleSize::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_size(obj);
                 }
                 b"c:dLbls" => {
                     let mut obj = DataLabels::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_data_labels(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:idx" => {
-                    self.index.set_attributes(reader, e);
+                    self.index.set_attributes(reader, e)?;
                 }
                 b"c:order" => {
-                    self.order.set_attributes(reader, e);
+                    self.order.set_attributes(reader, e)?;
                 }
                 b"c:explosion" => {
                     let mut obj = Explosion::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_explosion(obj);
                 }
                 b"c:invertIfNegative" => {
                     let mut obj = InvertIfNegative::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_invert_if_negative(obj);
                 }
                 b"c:bubble3D" => {
                     let mut obj = Bubble3D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_3d(obj);
                 }
                 b"c:smooth" => {
                     let mut obj = Smooth::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_smooth(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:ser" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:ser"),
+            Event::Eof => return Err(eof_error("c:ser")),
         );
     }
 
@@ -369,11 +411,6 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
-        // c:explosion
-        if let Some(v) = &self.explosion {
-            v.write_to(writer);
-        }
-
         // c:invertIfNegative
         if let Some(v) = &self.invert_if_negative {
             v.write_to(writer);
@@ -389,6 +426,16 @@ impl AreaChartSeries {
             v.write_to(writer);
         }
 
+        // c:explosion
+        if let Some(v) = &self.explosion {
+            v.write_to(writer);
+        }
+
+        // c:dPt
+        for v in &self.data_points {
+            v.write_to(writer);
+        }
+
         // c:dLbls
         if let Some(v) = &self.data_labels {
             v.write_to(writer);
@@ -1,6 +1,6 @@
 use super::AreaChartSeries;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct AreaChartSeriesList {
     area_chart_series: Vec<AreaChartSeries>,
 }
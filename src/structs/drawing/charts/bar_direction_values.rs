@@ -1,6 +1,6 @@
 use super::super::super::EnumTrait;
 use std::str::FromStr;
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum BarDirectionValues {
     Bar,
     Column,
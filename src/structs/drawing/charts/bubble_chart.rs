@@ -14,7 +14,7 @@ use std::io::Cursor;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct BubbleChart {
     vary_colors: VaryColors,
     area_chart_series_list: AreaChartSeriesList,
@@ -112,19 +112,19 @@ impl BubbleChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.get_area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -132,17 +132,17 @@ impl BubbleChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:bubbleScale" => {
-                        self.bubble_scale.set_attributes(reader, e);
+                        self.bubble_scale.set_attributes(reader, e)?;
                     }
                     b"c:showNegBubbles" => {
-                        self.show_negative_bubbles.set_attributes(reader, e);
+                        self.show_negative_bubbles.set_attributes(reader, e)?;
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -150,10 +150,10 @@ impl BubbleChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:bubbleChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:bubbleChart")
+            Event::Eof => return Err(eof_error("c:bubbleChart"))
         );
     }
 
@@ -5,6 +5,7 @@ use super::AutoLabeled;
 use super::AxisId;
 use super::AxisPosition;
 use super::Crosses;
+use super::CrossesAt;
 use super::CrossingAxis;
 use super::Delete;
 use super::LabelAlignment;
@@ -21,10 +22,11 @@ use super::Title;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct CategoryAxis {
     axis_id: AxisId,
     scaling: Scaling,
@@ -37,6 +39,7 @@ pub struct CategoryAxis {
     tick_label_position: TickLabelPosition,
     crossing_axis: CrossingAxis,
     crosses: Crosses,
+    crosses_at: Option<CrossesAt>,
     auto_labeled: AutoLabeled,
     label_alignment: LabelAlignment,
     label_offset: LabelOffset,
@@ -189,6 +192,26 @@ impl CategoryAxis {
         self
     }
 
+    pub fn get_crosses_at(&self) -> Option<&CrossesAt> {
+        self.crosses_at.as_ref()
+    }
+
+    pub fn get_crosses_at_mut(&mut self) -> Option<&mut CrossesAt> {
+        self.crosses_at.as_mut()
+    }
+
+    /// Set the value where the crossing axis crosses.
+    /// It takes precedence over `crosses` when written.
+    pub fn set_crosses_at(&mut self, value: CrossesAt) -> &mut Self {
+        self.crosses_at = Some(value);
+        self
+    }
+
+    pub fn remove_crosses_at(&mut self) -> &mut Self {
+        self.crosses_at = None;
+        self
+    }
+
     pub fn get_auto_labeled(&self) -> &AutoLabeled {
         &self.auto_labeled
     }
@@ -271,85 +294,90 @@ impl CategoryAxis {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:title" => {
                     let mut obj = Title::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_title(obj);
                 }
                 b"c:scaling" => {
-                    self.scaling.set_attributes(reader, e);
+                    self.scaling.set_attributes(reader, e)?;
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:txPr" => {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_major_gridlines(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:axId" => {
-                    self.axis_id.set_attributes(reader, e);
+                    self.axis_id.set_attributes(reader, e)?;
                 }
                 b"c:delete" => {
-                    self.delete.set_attributes(reader, e);
+                    self.delete.set_attributes(reader, e)?;
                 }
                 b"c:axPos" => {
-                    self.axis_position.set_attributes(reader, e);
+                    self.axis_position.set_attributes(reader, e)?;
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_major_gridlines(obj);
                 }
                 b"c:majorTickMark" => {
-                    self.major_tick_mark.set_attributes(reader, e);
+                    self.major_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:minorTickMark" => {
-                    self.minor_tick_mark.set_attributes(reader, e);
+                    self.minor_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:tickLblPos" => {
-                    self.tick_label_position.set_attributes(reader, e);
+                    self.tick_label_position.set_attributes(reader, e)?;
                 }
                 b"c:crossAx" => {
-                    self.crossing_axis.set_attributes(reader, e);
+                    self.crossing_axis.set_attributes(reader, e)?;
                 }
                 b"c:crosses" => {
-                    self.crosses.set_attributes(reader, e);
+                    self.crosses.set_attributes(reader, e)?;
+                }
+                b"c:crossesAt" => {
+                    let mut obj = CrossesAt::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_crosses_at(obj);
                 }
                 b"c:auto" => {
-                    self.auto_labeled.set_attributes(reader, e);
+                    self.auto_labeled.set_attributes(reader, e)?;
                 }
                 b"c:lblAlgn" => {
-                    self.label_alignment.set_attributes(reader, e);
+                    self.label_alignment.set_attributes(reader, e)?;
                 }
                 b"c:lblOffset" => {
-                    self.label_offset.set_attributes(reader, e);
+                    self.label_offset.set_attributes(reader, e)?;
                 }
                 b"c:noMultiLvlLbl" => {
-                    self.no_multi_level_labels.set_attributes(reader, e);
+                    self.no_multi_level_labels.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:catAx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:catAx"),
+            Event::Eof => return Err(eof_error("c:catAx")),
         );
     }
 
@@ -401,8 +429,11 @@ impl CategoryAxis {
         // c:crossAx
         self.crossing_axis.write_to(writer);
 
-        // c:crosses
-        self.crosses.write_to(writer);
+        // c:crosses, c:crossesAt
+        match &self.crosses_at {
+            Some(v) => v.write_to(writer),
+            None => self.crosses.write_to(writer),
+        }
 
         // c:auto
         self.auto_labeled.write_to(writer);
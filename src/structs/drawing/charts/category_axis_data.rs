@@ -9,7 +9,7 @@ use std::io::Cursor;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct CategoryAxisData {
     string_reference: Option<StringReference>,
     string_literal: Option<StringLiteral>,
@@ -56,19 +56,19 @@ impl CategoryAxisData {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:strRef" => {
                         let mut obj = StringReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_reference(obj);
                     }
                     b"c:strLit" => {
                         let mut obj = StringLiteral::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_literal(obj);
                     }
                     _ => (),
@@ -76,10 +76,10 @@ impl CategoryAxisData {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:cat" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:cat")
+            Event::Eof => return Err(eof_error("c:cat"))
         );
     }
 
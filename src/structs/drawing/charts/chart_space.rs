@@ -11,13 +11,16 @@ use helper::const_str::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use structs::office2010::drawing::charts::Style;
+use structs::raw::RawFile;
+use structs::raw::RawRelationships;
 use structs::Spreadsheet;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ChartSpace {
     date1904: Date1904,
     editing_language: EditingLanguage,
@@ -26,6 +29,8 @@ pub struct ChartSpace {
     chart: Chart,
     shape_properties: Option<ShapeProperties>,
     print_settings: Option<PrintSettings>,
+    raw_data: Option<RawFile>,
+    raw_relationships: Vec<RawRelationships>,
 }
 
 impl ChartSpace {
@@ -120,52 +125,73 @@ impl ChartSpace {
         self
     }
 
+    /// The original chart part, kept when the chart type is not supported.
+    pub(crate) fn get_raw_data(&self) -> Option<&RawFile> {
+        self.raw_data.as_ref()
+    }
+
+    pub(crate) fn set_raw_data(&mut self, value: RawFile) -> &mut Self {
+        self.raw_data = Some(value);
+        self
+    }
+
+    /// The relationships of the original chart part (style, colors, user shapes, ...)
+    /// and of the parts it refers to, the chart's own first.
+    pub(crate) fn get_raw_relationships(&self) -> &Vec<RawRelationships> {
+        &self.raw_relationships
+    }
+
+    pub(crate) fn set_raw_relationships(&mut self, value: Vec<RawRelationships>) -> &mut Self {
+        self.raw_relationships = value;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"mc:AlternateContent" => {
                     let mut obj = Style::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_style(obj);
                 }
                 b"c:chart" => {
-                    self.chart.set_attributes(reader, e);
+                    self.chart.set_attributes(reader, e)?;
                 }
                 b"c:printSettings" => {
                     let mut obj = PrintSettings::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_print_settings(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:date1904" => {
-                    self.date1904.set_attributes(reader, e);
+                    self.date1904.set_attributes(reader, e)?;
                 }
                 b"c:lang" => {
-                    self.editing_language.set_attributes(reader, e);
+                    self.editing_language.set_attributes(reader, e)?;
                 }
                 b"c:roundedCorners" => {
-                    self.rounded_corners.set_attributes(reader, e);
+                    self.rounded_corners.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:chartSpace" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:chartSpace"),
+            Event::Eof => return Err(eof_error("c:chartSpace")),
         );
     }
 
@@ -0,0 +1,43 @@
+// c:crossesAt
+use super::super::super::DoubleValue;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CrossesAt {
+    val: DoubleValue,
+}
+impl CrossesAt {
+    pub fn get_val(&self) -> &f64 {
+        self.val.get_value()
+    }
+
+    pub fn set_val(&mut self, value: f64) -> &mut CrossesAt {
+        self.val.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        self.val
+            .set_value_string(get_required_attribute(e, b"val")?);
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:crossesAt
+        write_start_tag(
+            writer,
+            "c:crossesAt",
+            vec![("val", &self.val.get_value_string())],
+            true,
+        );
+    }
+}
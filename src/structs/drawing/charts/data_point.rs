@@ -0,0 +1,189 @@
+// c:dPt
+use super::Bubble3D;
+use super::Explosion;
+use super::Index;
+use super::InvertIfNegative;
+use super::Marker;
+use super::ShapeProperties;
+use crate::xml_read_loop;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct DataPoint {
+    index: Index,
+    invert_if_negative: Option<InvertIfNegative>,
+    marker: Option<Marker>,
+    bubble_3d: Option<Bubble3D>,
+    explosion: Option<Explosion>,
+    shape_properties: Option<ShapeProperties>,
+}
+
+impl DataPoint {
+    pub fn get_index(&self) -> &Index {
+        &self.index
+    }
+
+    pub fn get_index_mut(&mut self) -> &mut Index {
+        &mut self.index
+    }
+
+    pub fn set_index(&mut self, value: Index) -> &mut Self {
+        self.index = value;
+        self
+    }
+
+    pub fn get_invert_if_negative(&self) -> Option<&InvertIfNegative> {
+        self.invert_if_negative.as_ref()
+    }
+
+    pub fn get_invert_if_negative_mut(&mut self) -> Option<&mut InvertIfNegative> {
+        self.invert_if_negative.as_mut()
+    }
+
+    pub fn set_invert_if_negative(&mut self, value: InvertIfNegative) -> &mut Self {
+        self.invert_if_negative = Some(value);
+        self
+    }
+
+    pub fn get_marker(&self) -> Option<&Marker> {
+        self.marker.as_ref()
+    }
+
+    pub fn get_marker_mut(&mut self) -> Option<&mut Marker> {
+        self.marker.as_mut()
+    }
+
+    pub fn set_marker(&mut self, value: Marker) -> &mut Self {
+        self.marker = Some(value);
+        self
+    }
+
+    pub fn get_bubble_3d(&self) -> Option<&Bubble3D> {
+        self.bubble_3d.as_ref()
+    }
+
+    pub fn get_bubble_3d_mut(&mut self) -> Option<&mut Bubble3D> {
+        self.bubble_3d.as_mut()
+    }
+
+    pub fn set_bubble_3d(&mut self, value: Bubble3D) -> &mut Self {
+        self.bubble_3d = Some(value);
+        self
+    }
+
+    pub fn get_explosion(&self) -> Option<&Explosion> {
+        self.explosion.as_ref()
+    }
+
+    pub fn get_explosion_mut(&mut self) -> Option<&mut Explosion> {
+        self.explosion.as_mut()
+    }
+
+    pub fn set_explosion(&mut self, value: Explosion) -> &mut Self {
+        self.explosion = Some(value);
+        self
+    }
+
+    pub fn get_shape_properties(&self) -> Option<&ShapeProperties> {
+        self.shape_properties.as_ref()
+    }
+
+    pub fn get_shape_properties_mut(&mut self) -> Option<&mut ShapeProperties> {
+        self.shape_properties.as_mut()
+    }
+
+    pub fn set_shape_properties(&mut self, value: ShapeProperties) -> &mut Self {
+        self.shape_properties = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"c:marker" => {
+                    let mut obj = Marker::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_marker(obj);
+                }
+                b"c:spPr" => {
+                    let mut obj = ShapeProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_shape_properties(obj);
+                }
+                _ => (),
+            },
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"c:idx" => {
+                    self.index.set_attributes(reader, e)?;
+                }
+                b"c:invertIfNegative" => {
+                    let mut obj = InvertIfNegative::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_invert_if_negative(obj);
+                }
+                b"c:bubble3D" => {
+                    let mut obj = Bubble3D::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_bubble_3d(obj);
+                }
+                b"c:explosion" => {
+                    let mut obj = Explosion::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_explosion(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"c:dPt" {
+                    return Ok(());
+                }
+            },
+            Event::Eof => return Err(eof_error("c:dPt")),
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // c:dPt
+        write_start_tag(writer, "c:dPt", vec![], false);
+
+        // c:idx
+        self.index.write_to(writer);
+
+        // c:invertIfNegative
+        if let Some(v) = &self.invert_if_negative {
+            v.write_to(writer);
+        }
+
+        // c:marker
+        if let Some(v) = &self.marker {
+            v.write_to(writer);
+        }
+
+        // c:bubble3D
+        if let Some(v) = &self.bubble_3d {
+            v.write_to(writer);
+        }
+
+        // c:explosion
+        if let Some(v) = &self.explosion {
+            v.write_to(writer);
+        }
+
+        // c:spPr
+        if let Some(v) = &self.shape_properties {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "c:dPt");
+    }
+}
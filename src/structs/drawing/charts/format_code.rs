@@ -2,12 +2,13 @@
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
 use crate::xml_read_loop;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct FormatCode {
     text: String,
 }
@@ -26,18 +27,18 @@ impl FormatCode {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_text(e.unescape().unwrap());
+                self.set_text(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:formatCode" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:formatCode"),
+            Event::Eof => return Err(eof_error("c:formatCode")),
         );
     }
 
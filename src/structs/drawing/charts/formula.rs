@@ -4,14 +4,16 @@ use crate::xml_read_loop;
 use super::super::super::Address;
 use super::super::super::StringValue;
 use helper::address::*;
+use helper::formula::rename_sheet_reference_formula;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Formula {
     address: Address,
     string_value: StringValue,
@@ -55,22 +57,61 @@ impl Formula {
         self
     }
 
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if self.string_value.has_value() {
+            if let Some(v) = rename_sheet_reference_formula(
+                self.string_value.get_value_str(),
+                sheet_name,
+                new_sheet_name,
+            ) {
+                self.string_value.set_value(v);
+            }
+            return;
+        }
+        if self
+            .address
+            .get_sheet_name()
+            .eq_ignore_ascii_case(sheet_name)
+        {
+            self.address.set_sheet_name(new_sheet_name);
+        }
+    }
+
+    /// Move the range by the number of rows and columns.
+    /// The range stops at the first row or column.
+    pub(crate) fn offset_address(&mut self, rows: i32, cols: i32) {
+        let offset = |num: u32, value: i32| (num as i64 + value as i64).max(1) as u32;
+        let range = self.address.get_range_mut();
+        if let Some(v) = range.get_coordinate_start_col_mut() {
+            v.set_num(offset(*v.get_num(), cols));
+        }
+        if let Some(v) = range.get_coordinate_end_col_mut() {
+            v.set_num(offset(*v.get_num(), cols));
+        }
+        if let Some(v) = range.get_coordinate_start_row_mut() {
+            v.set_num(offset(*v.get_num(), rows));
+        }
+        if let Some(v) = range.get_coordinate_end_row_mut() {
+            v.set_num(offset(*v.get_num(), rows));
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_address_str(e.unescape().unwrap());
+                self.set_address_str(e.unescape()?);
             },
             Event::End(ref e) => {
                if  e.name().0 == b"c:f" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:f"),
+            Event::Eof => return Err(eof_error("c:f")),
         );
     }
 
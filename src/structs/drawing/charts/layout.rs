@@ -1,13 +1,23 @@
 // c:layout
+use super::Height;
+use super::LayoutModeValues;
+use super::LayoutTarget;
+use super::LayoutTargetValues;
+use super::Left;
+use super::LeftMode;
 use super::ManualLayout;
+use super::Top;
+use super::TopMode;
+use super::Width;
 use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Layout {
     manual_layout: Option<ManualLayout>,
 }
@@ -26,6 +36,54 @@ impl Layout {
         self
     }
 
+    /// Set whether the manual layout sizes the plot area inside or outside the axis labels.
+    pub fn set_layout_target(&mut self, value: LayoutTargetValues) -> &mut Layout {
+        let mut obj = LayoutTarget::default();
+        obj.set_val(value);
+        self.get_manual_layout_or_default().set_layout_target(obj);
+        self
+    }
+
+    /// Set the position of the top left corner.
+    /// # Arguments
+    /// * `x` - Fraction of the chart width from the left edge. ex) 0.1
+    /// * `y` - Fraction of the chart height from the top edge. ex) 0.1
+    pub fn set_position(&mut self, x: f64, y: f64) -> &mut Layout {
+        let manual_layout = self.get_manual_layout_or_default();
+        let mut left_mode = LeftMode::default();
+        left_mode.set_val(LayoutModeValues::Edge);
+        manual_layout.set_left_mode(left_mode);
+        let mut top_mode = TopMode::default();
+        top_mode.set_val(LayoutModeValues::Edge);
+        manual_layout.set_top_mode(top_mode);
+        let mut left = Left::default();
+        left.set_val(x);
+        manual_layout.set_left(left);
+        let mut top = Top::default();
+        top.set_val(y);
+        manual_layout.set_top(top);
+        self
+    }
+
+    /// Set the size.
+    /// # Arguments
+    /// * `width` - Fraction of the chart width. ex) 0.8
+    /// * `height` - Fraction of the chart height. ex) 0.7
+    pub fn set_size(&mut self, width: f64, height: f64) -> &mut Layout {
+        let manual_layout = self.get_manual_layout_or_default();
+        let mut obj = Width::default();
+        obj.set_val(width);
+        manual_layout.set_width(obj);
+        let mut obj = Height::default();
+        obj.set_val(height);
+        manual_layout.set_height(obj);
+        self
+    }
+
+    fn get_manual_layout_or_default(&mut self) -> &mut ManualLayout {
+        self.manual_layout.get_or_insert_with(ManualLayout::default)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.manual_layout.is_none()
     }
@@ -35,9 +93,9 @@ impl Layout {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -45,16 +103,16 @@ impl Layout {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:manualLayout" {
                     let mut obj = ManualLayout::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_manual_layout(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:layout" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:layout"),
+            Event::Eof => return Err(eof_error("c:layout")),
         );
     }
 
@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct LayoutTarget {
     val: EnumValue<LayoutTargetValues>,
 }
@@ -26,15 +26,17 @@ impl LayoutTarget {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val
+            .set_value_string(get_required_attribute(e, b"val")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
-        // c:hMode
+        // c:layoutTarget
         write_start_tag(
             writer,
-            "c:hMode",
+            "c:layoutTarget",
             vec![("val", self.val.get_value_string())],
             true,
         );
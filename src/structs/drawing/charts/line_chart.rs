@@ -12,11 +12,12 @@ use super::VaryColors;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct LineChart {
     grouping: Grouping,
     vary_colors: VaryColors,
@@ -128,47 +129,47 @@ impl LineChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:ser" => {
                     let mut obj = AreaChartSeries::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.get_area_chart_series_list_mut()
                         .add_area_chart_series(obj);
                 }
                 b"c:dLbls" => {
-                    self.data_labels.set_attributes(reader, e);
+                    self.data_labels.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:grouping" => {
-                    self.grouping.set_attributes(reader, e);
+                    self.grouping.set_attributes(reader, e)?;
                 }
                 b"c:varyColors" => {
-                    self.vary_colors.set_attributes(reader, e);
+                    self.vary_colors.set_attributes(reader, e)?;
                 }
                 b"c:marker" => {
-                    self.show_marker.set_attributes(reader, e);
+                    self.show_marker.set_attributes(reader, e)?;
                 }
                 b"c:smooth" => {
-                    self.smooth.set_attributes(reader, e);
+                    self.smooth.set_attributes(reader, e)?;
                 }
                 b"c:axId" => {
                     let mut obj = AxisId::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_axis_id(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:lineChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:lineChart"),
+            Event::Eof => return Err(eof_error("c:lineChart")),
         );
     }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct MajorGridlines {
     shape_properties: Option<ShapeProperties>,
 }
@@ -31,9 +31,9 @@ impl MajorGridlines {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -41,16 +41,16 @@ impl MajorGridlines {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:spPr" {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:majorGridlines" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:majorGridlines")
+            Event::Eof => return Err(eof_error("c:majorGridlines"))
         );
     }
 
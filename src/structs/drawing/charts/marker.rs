@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Marker {
     symbol: Option<Symbol>,
 }
@@ -31,9 +31,9 @@ impl Marker {
         reader: &mut Reader<R>,
         _: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -41,16 +41,16 @@ impl Marker {
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:symbol" {
                     let mut obj = Symbol::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_symbol(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:marker" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:marker")
+            Event::Eof => return Err(eof_error("c:marker"))
         );
     }
 
@@ -6,11 +6,12 @@ use super::NumberingCache;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NumberReference {
     formula: Formula,
     numbering_cache: NumberingCache,
@@ -47,24 +48,24 @@ impl NumberReference {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:f" => {
-                    self.formula.set_attributes(reader, e);
+                    self.formula.set_attributes(reader, e)?;
                 }
                 b"c:numCache" => {
-                    self.numbering_cache.set_attributes(reader, e);
+                    self.numbering_cache.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:numRef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numRef"),
+            Event::Eof => return Err(eof_error("c:numRef")),
         );
     }
 
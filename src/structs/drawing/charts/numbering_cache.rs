@@ -4,12 +4,13 @@ use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use structs::Address;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NumberingCache {
     format_code: FormatCode,
 }
@@ -32,20 +33,20 @@ impl NumberingCache {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:formatCode" {
-                    self.format_code.set_attributes(reader, e);
+                    self.format_code.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                if e.name().0 == b"c:numCache" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numCache"),
+            Event::Eof => return Err(eof_error("c:numCache")),
         );
     }
 
@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NumberingFormat {
     format_code: StringValue,
     source_linked: BooleanValue,
@@ -36,11 +36,12 @@ impl NumberingFormat {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.format_code
-            .set_value_string(get_attribute(e, b"formatCode").unwrap());
+            .set_value_string(get_required_attribute(e, b"formatCode")?);
         self.source_linked
-            .set_value_string(get_attribute(e, b"sourceLinked").unwrap());
+            .set_value_string(get_required_attribute(e, b"sourceLinked")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -3,10 +3,11 @@ use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NumericValue {
     text: String,
 }
@@ -25,18 +26,18 @@ impl NumericValue {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_text(e.unescape().unwrap());
+                self.set_text(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:v" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:v"),
+            Event::Eof => return Err(eof_error("c:v")),
         );
     }
 
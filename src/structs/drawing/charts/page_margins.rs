@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PageMargins {
     bottom: DoubleValue,
     left: DoubleValue,
@@ -75,16 +75,18 @@ impl PageMargins {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.bottom
-            .set_value_string(get_attribute(e, b"b").unwrap());
-        self.left.set_value_string(get_attribute(e, b"l").unwrap());
-        self.right.set_value_string(get_attribute(e, b"r").unwrap());
-        self.top.set_value_string(get_attribute(e, b"t").unwrap());
+            .set_value_string(get_required_attribute(e, b"b")?);
+        self.left.set_value_string(get_required_attribute(e, b"l")?);
+        self.right
+            .set_value_string(get_required_attribute(e, b"r")?);
+        self.top.set_value_string(get_required_attribute(e, b"t")?);
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .set_value_string(get_required_attribute(e, b"header")?);
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .set_value_string(get_required_attribute(e, b"footer")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
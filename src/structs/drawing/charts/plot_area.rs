@@ -1,6 +1,7 @@
 // c:plotArea
 use super::Area3DChart;
 use super::AreaChart;
+use super::AreaChartSeries;
 use super::AreaChartSeriesList;
 use super::Bar3DChart;
 use super::BarChart;
@@ -24,12 +25,13 @@ use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use structs::Spreadsheet;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PlotArea {
     layout: Layout,
     line_chart: Option<LineChart>,
@@ -372,6 +374,106 @@ impl PlotArea {
         panic! {"Non-ChartSeriesList."};
     }
 
+    /// (This method is crate only.)
+    /// Series of all the charts in the plot area.
+    pub(crate) fn get_area_chart_series_all(&self) -> Vec<&AreaChartSeries> {
+        vec![
+            self.line_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.line_3d_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.pie_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.pie_3d_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.doughnut_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.scatter_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.bar_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.bar_3d_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.radar_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.bubble_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.area_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.area_3d_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+            self.of_pie_chart
+                .as_ref()
+                .map(|v| v.get_area_chart_series_list()),
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|v| v.get_area_chart_series())
+        .collect()
+    }
+
+    /// (This method is crate only.)
+    /// Series of all the charts in the plot area in mutable.
+    pub(crate) fn get_area_chart_series_all_mut(&mut self) -> Vec<&mut AreaChartSeries> {
+        vec![
+            self.line_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.line_3d_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.pie_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.pie_3d_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.doughnut_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.scatter_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.bar_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.bar_3d_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.radar_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.bubble_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.area_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.area_3d_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+            self.of_pie_chart
+                .as_mut()
+                .map(|v| v.get_area_chart_series_list_mut()),
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|v| v.get_area_chart_series_mut())
+        .collect()
+    }
+
     pub fn get_formula_mut(&mut self) -> Vec<&mut Formula> {
         let mut result: Vec<&mut Formula> = Vec::default();
         if let Some(v) = &mut self.line_chart {
@@ -527,106 +629,106 @@ impl PlotArea {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:layout" => {
-                    self.layout.set_attributes(reader, e, false);
+                    self.layout.set_attributes(reader, e, false)?;
                 }
                 b"c:lineChart" => {
                     let mut obj = LineChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_chart(obj);
                 }
                 b"c:line3DChart" => {
                     let mut obj = Line3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_3d_chart(obj);
                 }
                 b"c:pieChart" => {
                     let mut obj = PieChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pie_chart(obj);
                 }
                 b"c:pie3DChart" => {
                     let mut obj = Pie3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pie_3d_chart(obj);
                 }
                 b"c:doughnutChart" => {
                     let mut obj = DoughnutChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_doughnut_chart(obj);
                 }
                 b"c:scatterChart" => {
                     let mut obj = ScatterChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_scatter_chart(obj);
                 }
                 b"c:barChart" => {
                     let mut obj = BarChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bar_chart(obj);
                 }
                 b"c:bar3DChart" => {
                     let mut obj = Bar3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bar_3d_chart(obj);
                 }
                 b"c:radarChart" => {
                     let mut obj = RadarChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_radar_chart(obj);
                 }
                 b"c:bubbleChart" => {
                     let mut obj = BubbleChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_chart(obj);
                 }
                 b"c:areaChart" => {
                     let mut obj = AreaChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_area_chart(obj);
                 }
                 b"c:area3DChart" => {
                     let mut obj = Area3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_area_3d_chart(obj);
                 }
                 b"c:ofPieChart" => {
                     let mut obj = OfPieChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_of_pie_chart(obj);
                 }
                 b"c:catAx" => {
                     let mut obj = CategoryAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_category_axis(obj);
                 }
                 b"c:valAx" => {
                     let mut obj = ValueAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_value_axis(obj);
                 }
                 b"c:serAx" => {
                     let mut obj = SeriesAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_series_axis(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:plotArea" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:plotArea"),
+            Event::Eof => return Err(eof_error("c:plotArea")),
         );
     }
 
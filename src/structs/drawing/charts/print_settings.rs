@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PrintSettings {
     header_footer: HeaderFooter,
     page_margins: PageMargins,
@@ -60,31 +60,31 @@ impl PrintSettings {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                 b"c:headerFooter" => {
-                    self.header_footer.set_attributes(reader, e);
+                    self.header_footer.set_attributes(reader, e)?;
                 }
                 b"c:pageSetup" => {
-                    self.page_setup.set_attributes(reader, e);
+                    self.page_setup.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:pageMargins" {
-                    self.page_margins.set_attributes(reader, e);
+                    self.page_margins.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:printSettings" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:printSettings")
+            Event::Eof => return Err(eof_error("c:printSettings"))
         );
     }
 
@@ -6,10 +6,11 @@ use crate::xml_read_loop;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct RichText {
     body_properties: BodyProperties,
     list_style: ListStyle,
@@ -57,18 +58,18 @@ impl RichText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"a:p" => {
                     let mut paragraph = Paragraph::default();
-                    paragraph.set_attributes(reader, e);
+                    paragraph.set_attributes(reader, e)?;
                     self.add_paragraph(paragraph);
                 }
                 b"a:bodyPr" => {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, false);
+                    body_properties.set_attributes(reader, e, false)?;
                     self.set_body_properties(body_properties);
                 }
                 _ => (),
@@ -76,16 +77,16 @@ impl RichText {
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:bodyPr" {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, true);
+                    body_properties.set_attributes(reader, e, true)?;
                     self.set_body_properties(body_properties);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:rich" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:rich"),
+            Event::Eof => return Err(eof_error("c:rich")),
         );
     }
 
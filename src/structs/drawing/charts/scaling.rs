@@ -1,4 +1,6 @@
 // c:scaling
+use super::MaxAxisValue;
+use super::MinAxisValue;
 use super::Orientation;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
@@ -7,9 +9,11 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Scaling {
     orientation: Orientation,
+    max_axis_value: Option<MaxAxisValue>,
+    min_axis_value: Option<MinAxisValue>,
 }
 
 impl Scaling {
@@ -26,24 +30,71 @@ impl Scaling {
         self
     }
 
+    pub fn get_max_axis_value(&self) -> Option<&MaxAxisValue> {
+        self.max_axis_value.as_ref()
+    }
+
+    pub fn get_max_axis_value_mut(&mut self) -> Option<&mut MaxAxisValue> {
+        self.max_axis_value.as_mut()
+    }
+
+    pub fn set_max_axis_value(&mut self, value: MaxAxisValue) -> &mut Self {
+        self.max_axis_value = Some(value);
+        self
+    }
+
+    pub fn remove_max_axis_value(&mut self) -> &mut Self {
+        self.max_axis_value = None;
+        self
+    }
+
+    pub fn get_min_axis_value(&self) -> Option<&MinAxisValue> {
+        self.min_axis_value.as_ref()
+    }
+
+    pub fn get_min_axis_value_mut(&mut self) -> Option<&mut MinAxisValue> {
+        self.min_axis_value.as_mut()
+    }
+
+    pub fn set_min_axis_value(&mut self, value: MinAxisValue) -> &mut Self {
+        self.min_axis_value = Some(value);
+        self
+    }
+
+    pub fn remove_min_axis_value(&mut self) -> &mut Self {
+        self.min_axis_value = None;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
-            Event::Empty(ref e) => {
-                if e.name().0 == b"c:orientation" {
-                    self.orientation.set_attributes(reader, e);
+            Event::Empty(ref e) => match e.name().0 {
+                b"c:orientation" => {
+                    self.orientation.set_attributes(reader, e)?;
+                }
+                b"c:max" => {
+                    let mut obj = MaxAxisValue::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_max_axis_value(obj);
                 }
+                b"c:min" => {
+                    let mut obj = MinAxisValue::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_min_axis_value(obj);
+                }
+                _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:scaling" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:scaling")
+            Event::Eof => return Err(eof_error("c:scaling"))
         );
     }
 
@@ -54,6 +105,16 @@ impl Scaling {
         // c:orientation
         self.orientation.write_to(writer);
 
+        // c:max
+        if let Some(v) = &self.max_axis_value {
+            v.write_to(writer);
+        }
+
+        // c:min
+        if let Some(v) = &self.min_axis_value {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "c:scaling");
     }
 }
@@ -7,7 +7,7 @@ use std::io::Cursor;
 use structs::StringValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SeriesText {
     value: StringValue,
 }
@@ -26,18 +26,18 @@ impl SeriesText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_value(e.unescape().unwrap());
+                self.set_value(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:tx" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:tx")
+            Event::Eof => return Err(eof_error("c:tx"))
         );
     }
 
@@ -8,7 +8,7 @@ use structs::Address;
 use structs::Spreadsheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct StringCache {}
 
 impl StringCache {
@@ -16,15 +16,15 @@ impl StringCache {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().0 == b"c:strCache" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strCache")
+            Event::Eof => return Err(eof_error("c:strCache"))
         );
     }
 
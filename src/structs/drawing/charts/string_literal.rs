@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct StringLiteral {
     string_point_list: Vec<StringPoint>,
 }
@@ -30,22 +30,22 @@ impl StringLiteral {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:pt" {
                     let mut obj = StringPoint::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_string_point_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strLit" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strLit")
+            Event::Eof => return Err(eof_error("c:strLit"))
         );
     }
 
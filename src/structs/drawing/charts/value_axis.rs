@@ -3,6 +3,7 @@ use super::AxisId;
 use super::AxisPosition;
 use super::CrossBetween;
 use super::Crosses;
+use super::CrossesAt;
 use super::CrossingAxis;
 use super::Delete;
 use super::MajorGridlines;
@@ -21,7 +22,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ValueAxis {
     axis_id: AxisId,
     scaling: Scaling,
@@ -35,6 +36,7 @@ pub struct ValueAxis {
     tick_label_position: TickLabelPosition,
     crossing_axis: CrossingAxis,
     crosses: Crosses,
+    crosses_at: Option<CrossesAt>,
     cross_between: CrossBetween,
     shape_properties: Option<ShapeProperties>,
     text_properties: Option<TextProperties>,
@@ -197,6 +199,26 @@ impl ValueAxis {
         self
     }
 
+    pub fn get_crosses_at(&self) -> Option<&CrossesAt> {
+        self.crosses_at.as_ref()
+    }
+
+    pub fn get_crosses_at_mut(&mut self) -> Option<&mut CrossesAt> {
+        self.crosses_at.as_mut()
+    }
+
+    /// Set the value where the crossing axis crosses.
+    /// It takes precedence over `crosses` when written.
+    pub fn set_crosses_at(&mut self, value: CrossesAt) -> &mut Self {
+        self.crosses_at = Some(value);
+        self
+    }
+
+    pub fn remove_crosses_at(&mut self) -> &mut Self {
+        self.crosses_at = None;
+        self
+    }
+
     pub fn get_cross_between(&self) -> &CrossBetween {
         &self.cross_between
     }
@@ -240,79 +262,84 @@ impl ValueAxis {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:scaling" => {
-                    self.scaling.set_attributes(reader, e);
+                    self.scaling.set_attributes(reader, e)?;
                 }
                 b"c:title" => {
                     let mut obj = Title::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_title(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:txPr" => {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_major_gridlines(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
                 b"c:axId" => {
-                    self.axis_id.set_attributes(reader, e);
+                    self.axis_id.set_attributes(reader, e)?;
                 }
                 b"c:delete" => {
-                    self.delete.set_attributes(reader, e);
+                    self.delete.set_attributes(reader, e)?;
                 }
                 b"c:axPos" => {
-                    self.axis_position.set_attributes(reader, e);
+                    self.axis_position.set_attributes(reader, e)?;
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_major_gridlines(obj);
                 }
                 b"c:numFmt" => {
-                    self.numbering_format.set_attributes(reader, e);
+                    self.numbering_format.set_attributes(reader, e)?;
                 }
                 b"c:majorTickMark" => {
-                    self.major_tick_mark.set_attributes(reader, e);
+                    self.major_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:minorTickMark" => {
-                    self.minor_tick_mark.set_attributes(reader, e);
+                    self.minor_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:tickLblPos" => {
-                    self.tick_label_position.set_attributes(reader, e);
+                    self.tick_label_position.set_attributes(reader, e)?;
                 }
                 b"c:crossAx" => {
-                    self.crossing_axis.set_attributes(reader, e);
+                    self.crossing_axis.set_attributes(reader, e)?;
                 }
                 b"c:crosses" => {
-                    self.crosses.set_attributes(reader, e);
+                    self.crosses.set_attributes(reader, e)?;
+                }
+                b"c:crossesAt" => {
+                    let mut obj = CrossesAt::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_crosses_at(obj);
                 }
                 b"c:crossBetween" => {
-                    self.cross_between.set_attributes(reader, e);
+                    self.cross_between.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:valAx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:valAx")
+            Event::Eof => return Err(eof_error("c:valAx"))
         );
     }
 
@@ -367,8 +394,11 @@ impl ValueAxis {
         // c:crossAx
         self.crossing_axis.write_to(writer);
 
-        // c:crosses
-        self.crosses.write_to(writer);
+        // c:crosses, c:crossesAt
+        match &self.crosses_at {
+            Some(v) => v.write_to(writer),
+            None => self.crosses.write_to(writer),
+        }
 
         // c:crossBetween
         self.cross_between.write_to(writer);
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Color2Type {
     rgb_color_model_hex: Option<RgbColorModelHex>,
     system_color: Option<SystemColor>,
@@ -52,19 +52,19 @@ impl Color2Type {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                 b"a:srgbClr" => {
                     let mut obj = RgbColorModelHex::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.rgb_color_model_hex = Some(obj);
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.system_color = Some(obj);
                 }
                 _ => (),
@@ -74,12 +74,12 @@ impl Color2Type {
                 match e.name().into_inner() {
                 b"a:srgbClr" => {
                     let mut obj = RgbColorModelHex::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.rgb_color_model_hex = Some(obj);
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.system_color = Some(obj);
                 }
                 _ => (),
@@ -87,22 +87,22 @@ impl Color2Type {
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                b"a:accent1" => return,
-                b"a:accent2" => return,
-                b"a:accent3" => return,
-                b"a:accent4" => return,
-                b"a:accent5" => return,
-                b"a:accent6" => return,
-                b"a:dk1" => return,
-                b"a:dk2" => return,
-                b"a:folHlink" => return,
-                b"a:hlink" => return,
-                b"a:lt1" => return,
-                b"a:lt2" => return,
+                b"a:accent1" => return Ok(()),
+                b"a:accent2" => return Ok(()),
+                b"a:accent3" => return Ok(()),
+                b"a:accent4" => return Ok(()),
+                b"a:accent5" => return Ok(()),
+                b"a:accent6" => return Ok(()),
+                b"a:dk1" => return Ok(()),
+                b"a:dk2" => return Ok(()),
+                b"a:folHlink" => return Ok(()),
+                b"a:hlink" => return Ok(()),
+                b"a:lt1" => return Ok(()),
+                b"a:lt2" => return Ok(()),
                 _ => (),
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Color2Type")
+            Event::Eof => return Err(eof_error("Color2Type"))
         );
     }
 
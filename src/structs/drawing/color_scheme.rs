@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ColorScheme {
     name: StringValue,
     accent1: Color2Type,
@@ -200,7 +200,7 @@ impl ColorScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
 
         xml_read_loop!(
@@ -208,50 +208,50 @@ impl ColorScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:accent1" => {
-                        self.accent1.set_attributes(reader, e);
+                        self.accent1.set_attributes(reader, e)?;
                     }
                     b"a:accent2" => {
-                        self.accent2.set_attributes(reader, e);
+                        self.accent2.set_attributes(reader, e)?;
                     }
                     b"a:accent3" => {
-                        self.accent3.set_attributes(reader, e);
+                        self.accent3.set_attributes(reader, e)?;
                     }
                     b"a:accent4" => {
-                        self.accent4.set_attributes(reader, e);
+                        self.accent4.set_attributes(reader, e)?;
                     }
                     b"a:accent5" => {
-                        self.accent5.set_attributes(reader, e);
+                        self.accent5.set_attributes(reader, e)?;
                     }
                     b"a:accent6" => {
-                        self.accent6.set_attributes(reader, e);
+                        self.accent6.set_attributes(reader, e)?;
                     }
                     b"a:dk1" => {
-                        self.dk1.set_attributes(reader, e);
+                        self.dk1.set_attributes(reader, e)?;
                     }
                     b"a:dk2" => {
-                        self.dk2.set_attributes(reader, e);
+                        self.dk2.set_attributes(reader, e)?;
                     }
                     b"a:folHlink" => {
-                        self.fol_hlink.set_attributes(reader, e);
+                        self.fol_hlink.set_attributes(reader, e)?;
                     }
                     b"a:hlink" => {
-                        self.hlink.set_attributes(reader, e);
+                        self.hlink.set_attributes(reader, e)?;
                     }
                     b"a:lt1" => {
-                        self.lt1.set_attributes(reader, e);
+                        self.lt1.set_attributes(reader, e)?;
                     }
                     b"a:lt2" => {
-                        self.lt2.set_attributes(reader, e);
+                        self.lt2.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:clrScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:clrScheme")
+            Event::Eof => return Err(eof_error("a:clrScheme"))
         );
     }
 
@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct EffectList {
     glow: Option<Glow>,
     outer_shadow: Option<OuterShadow>,
@@ -58,9 +58,9 @@ impl EffectList {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -68,7 +68,7 @@ impl EffectList {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:softEdge" {
                     let mut obj = SoftEdge::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_soft_edge(obj);
                 }
             },
@@ -76,12 +76,12 @@ impl EffectList {
                 match e.name().into_inner() {
                     b"a:glow" => {
                         let mut obj = Glow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_glow(obj);
                     }
                     b"a:outerShdw" => {
                         let mut obj = OuterShadow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_outer_shadow(obj);
                     }
                     _ => (),
@@ -89,10 +89,10 @@ impl EffectList {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectLst")
+            Event::Eof => return Err(eof_error("a:effectLst"))
         );
     }
 
@@ -9,7 +9,7 @@ use std::io::Cursor;
 use std::vec;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct EffectStyle {
     effect_list: Option<EffectList>,
     scene_3d_type: Option<Scene3DType>,
@@ -60,24 +60,24 @@ impl EffectStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:effectLst" => {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.effect_list = Some(obj);
                 }
                 b"a:scene3d" => {
                     let mut obj = Scene3DType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.scene_3d_type = Some(obj);
                 }
                 b"a:sp3d" => {
                     let mut obj = Shape3DType::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.shape_3d_type = Some(obj);
                 }
                 _ => (),
@@ -86,16 +86,16 @@ impl EffectStyle {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:effectLst" {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_effect_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectStyle" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectStyle")
+            Event::Eof => return Err(eof_error("a:effectStyle"))
         );
     }
 
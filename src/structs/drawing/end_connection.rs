@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct EndConnection {
     id: UInt32Value,
     index: UInt32Value,
@@ -33,10 +33,12 @@ impl EndConnection {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id
+            .set_value_string(get_required_attribute(e, b"id")?)?;
         self.index
-            .set_value_string(get_attribute(e, b"idx").unwrap());
+            .set_value_string(get_required_attribute(e, b"idx")?)?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
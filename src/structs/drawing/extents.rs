@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Extents {
     cx: Int64Value,
     cy: Int64Value,
@@ -35,9 +35,12 @@ impl Extents {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.cx.set_value_string(get_attribute(e, b"cx").unwrap());
-        self.cy.set_value_string(get_attribute(e, b"cy").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.cx
+            .set_value_string(get_required_attribute(e, b"cx")?)?;
+        self.cy
+            .set_value_string(get_required_attribute(e, b"cy")?)?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -5,10 +5,11 @@ use super::TextFontType;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct FontCollectionType {
     latin_font: TextFontType,
     east_asian_font: TextFontType,
@@ -103,43 +104,38 @@ impl FontCollectionType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"a:latin" => {
-                        self.latin_font.set_attributes(reader, e);
+                        self.latin_font.set_attributes(reader, e)?;
                     }
                     b"a:ea" => {
-                        self.east_asian_font.set_attributes(reader, e);
+                        self.east_asian_font.set_attributes(reader, e)?;
                     }
                     b"a:cs" => {
-                        self.complex_script_font.set_attributes(reader, e);
+                        self.complex_script_font.set_attributes(reader, e)?;
                     }
                     b"a:font" => {
                         let mut obj = SupplementalFont::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_supplemental_font_list(obj);
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"a:majorFont" => {
-                        return;
+                        return Ok(());
                     }
                     b"a:minorFont" => {
-                        return;
+                        return Ok(());
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => {
-                    panic!(
-                        "Error: Could not find {} end element",
-                        "a:majorFont, a:minorFont"
-                    )
-                }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("a:majorFont, a:minorFont")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
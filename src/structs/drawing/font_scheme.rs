@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct FontScheme {
     name: StringValue,
     major_font: FontCollectionType,
@@ -55,8 +55,8 @@ impl FontScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"name") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"name")? {
             self.name.set_value(v);
         }
 
@@ -65,20 +65,20 @@ impl FontScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:majorFont" => {
-                    self.major_font.set_attributes(reader, e);
+                    self.major_font.set_attributes(reader, e)?;
                 }
                 b"a:minorFont" => {
-                    self.minor_font.set_attributes(reader, e);
+                    self.minor_font.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fontScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fontScheme")
+            Event::Eof => return Err(eof_error("a:fontScheme"))
         );
     }
 
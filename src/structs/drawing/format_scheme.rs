@@ -11,7 +11,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct FormatScheme {
     name: StringValue,
     fill_style_list: FillStyleList,
@@ -82,8 +82,8 @@ impl FormatScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"name") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"name")? {
             self.name.set_value(v);
         }
 
@@ -93,22 +93,22 @@ impl FormatScheme {
                 match e.name().into_inner() {
                     b"a:fillStyleLst" => {
                         let mut obj = FillStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.fill_style_list = obj;
                     }
                     b"a:lnStyleLst" => {
                         let mut obj = LineStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.line_style_list = obj;
                     }
                     b"a:effectStyleLst" => {
                         let mut obj = EffectStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.effect_style_list = obj;
                     }
                     b"a:bgFillStyleLst" => {
                         let mut obj = BackgroundFillStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.background_fill_style_list = obj;
                     }
                     _ => (),
@@ -116,10 +116,10 @@ impl FormatScheme {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fmtScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fmtScheme")
+            Event::Eof => return Err(eof_error("a:fmtScheme"))
         );
     }
 
@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Glow {
     radius: Int64Value,
     scheme_color: Option<SchemeColor>,
@@ -36,25 +36,25 @@ impl Glow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.radius
-            .set_value_string(get_attribute(e, b"rad").unwrap());
+            .set_value_string(get_required_attribute(e, b"rad")?)?;
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut obj = SchemeColor::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_scheme_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:glow" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:glow")
+            Event::Eof => return Err(eof_error("a:glow"))
         );
     }
 
@@ -12,7 +12,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GradientFill {
     flip: EnumValue<TileFlipValues>,
     rotate_with_shape: BooleanValue,
@@ -83,7 +83,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, flip, "flip");
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
@@ -93,12 +93,12 @@ impl GradientFill {
                 match e.name().into_inner() {
                 b"a:lin" => {
                     let mut obj = LinearGradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_linear_gradient_fill(obj);
                 }
                 b"a:tileRect" => {
                     let mut obj = TileRectangle::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_tile_rectangle(obj);
                 }
                 _ => (),
@@ -106,15 +106,15 @@ impl GradientFill {
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:gsLst" {
-                    self.gradient_stop_list.set_attributes(reader, e);
+                    self.gradient_stop_list.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gradFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gradFill")
+            Event::Eof => return Err(eof_error("a:gradFill"))
         );
     }
 
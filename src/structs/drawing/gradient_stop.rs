@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GradientStop {
     position: i32,
     scheme_color: Option<SchemeColor>,
@@ -55,9 +55,9 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"pos") {
-            self.set_position(v.parse::<i32>().unwrap());
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"pos")? {
+            self.set_position(v.parse::<i32>().map_err(parse_error)?);
         }
 
         xml_read_loop!(
@@ -66,12 +66,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -81,12 +81,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -94,10 +94,10 @@ impl GradientStop {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gs")
+            Event::Eof => return Err(eof_error("a:gs"))
         );
     }
 
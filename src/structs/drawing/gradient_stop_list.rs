@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GradientStopList {
     gradient_stop: Vec<GradientStop>,
 }
@@ -35,22 +35,22 @@ impl GradientStopList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gsLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gsLst")
+            Event::Eof => return Err(eof_error("a:gsLst"))
         );
     }
 
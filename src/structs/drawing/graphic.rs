@@ -9,7 +9,7 @@ use structs::raw::RawRelationships;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Graphic {
     graphic_data: GraphicData,
 }
@@ -33,21 +33,21 @@ impl Graphic {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
                     self.graphic_data
-                        .set_attributes(reader, e, drawing_relationships);
+                        .set_attributes(reader, e, drawing_relationships)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphic" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphic")
+            Event::Eof => return Err(eof_error("a:graphic"))
         );
     }
 
@@ -11,7 +11,7 @@ use structs::raw::RawRelationships;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GraphicData {
     chart_space: ChartSpace,
 }
@@ -35,24 +35,28 @@ impl GraphicData {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"c:chart" {
-                    let chart_id = get_attribute(e, b"r:id").unwrap();
+                    let chart_id = get_required_attribute(e, b"r:id")?;
                     let relationship = drawing_relationships
                         .unwrap()
-                        .get_relationship_by_rid(&chart_id);
-                    chart::read(relationship.get_raw_file(), &mut self.chart_space);
+                        .get_relationship_by_rid(&chart_id)?;
+                    let raw_file = relationship.get_raw_file();
+                    let _ = chart::read(raw_file, &mut self.chart_space);
+                    if !self.chart_space.get_chart().get_plot_area().is_support() {
+                        self.chart_space.set_raw_data(raw_file.clone());
+                    }
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphicData")
+            Event::Eof => return Err(eof_error("a:graphicData"))
         );
     }
 
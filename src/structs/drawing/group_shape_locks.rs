@@ -7,7 +7,7 @@ use std::io::Cursor;
 use structs::BooleanValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GroupShapeLocks {
     no_change_aspect: BooleanValue,
     no_grouping: BooleanValue,
@@ -31,7 +31,7 @@ impl GroupShapeLocks {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, no_change_aspect, "noChangeAspect");
         set_string_from_xml!(self, e, no_grouping, "noGrp");
         set_string_from_xml!(self, e, no_move, "noMove");
@@ -39,6 +39,7 @@ impl GroupShapeLocks {
         set_string_from_xml!(self, e, no_rotation, "noRot");
         set_string_from_xml!(self, e, no_selection, "noSelect");
         set_string_from_xml!(self, e, no_ungrouping, "noUngrp");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -0,0 +1,100 @@
+// a:hlinkClick
+use helper::const_str::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::raw::RawRelationships;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct HyperlinkClick {
+    url: String,
+    tooltip: StringValue,
+}
+
+impl HyperlinkClick {
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn set_url<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.url = value.into();
+        self
+    }
+
+    pub fn get_tooltip(&self) -> &str {
+        self.tooltip.get_value_str()
+    }
+
+    pub fn set_tooltip<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.tooltip.set_value(value);
+        self
+    }
+
+    pub(crate) fn get_rid(&self, rel_list: &mut Vec<(String, String)>) -> i32 {
+        let find = rel_list
+            .iter()
+            .position(|(k, v)| k == "HYPERLINK" && v == &self.url);
+        match find {
+            Some(v) => (v + 1) as i32,
+            None => {
+                rel_list.push((String::from("HYPERLINK"), self.url.clone()));
+                rel_list.len() as i32
+            }
+        }
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        drawing_relationships: Option<&RawRelationships>,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        if let Some(r_id) = get_attribute(e, b"r:id")? {
+            if let Some(relationships) = drawing_relationships {
+                if let Some(relationship) = relationships
+                    .get_relationship_list()
+                    .iter()
+                    .find(|v| v.get_id() == r_id)
+                {
+                    self.set_url(relationship.get_target());
+                }
+            }
+        }
+        set_string_from_xml!(self, e, tooltip, "tooltip");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"a:hlinkClick" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("a:hlinkClick"))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
+        // a:hlinkClick
+        let r_id_str = format!("rId{}", self.get_rid(rel_list));
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("xmlns:r", REL_OFC_NS));
+        attributes.push(("r:id", r_id_str.as_str()));
+        if self.tooltip.has_value() {
+            attributes.push(("tooltip", self.tooltip.get_value_str()));
+        }
+        write_start_tag(writer, "a:hlinkClick", attributes, true);
+    }
+}
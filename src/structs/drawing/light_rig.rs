@@ -10,7 +10,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct LightRig {
     rig: EnumValue<LightRigValues>,
     definition: EnumValue<LightRigDirectionValues>,
@@ -54,12 +54,12 @@ impl LightRig {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rig, "rig");
         set_string_from_xml!(self, e, definition, "dir");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -67,16 +67,16 @@ impl LightRig {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:rot" {
                     let mut obj = Rotation::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.rotation = Some(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lightRig" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lightRig")
+            Event::Eof => return Err(eof_error("a:lightRig"))
         );
     }
 
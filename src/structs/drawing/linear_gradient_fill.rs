@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct LinearGradientFill {
     angle: Int32Value,
     scaled: BooleanValue,
@@ -37,9 +37,10 @@ impl LinearGradientFill {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, angle, "ang");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, angle, "ang");
         set_string_from_xml!(self, e, scaled, "scaled");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
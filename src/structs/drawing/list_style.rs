@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ListStyle {
     effect_list: Option<EffectList>,
     text_paragraph_properties_type: HashMap<String, TextParagraphPropertiesType>,
@@ -223,7 +223,7 @@ impl ListStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
@@ -234,52 +234,52 @@ impl ListStyle {
                     }
                     b"a:defPPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_default_paragraph_properties(obj);
                     }
                     b"a:lvl1pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level1_paragraph_properties(obj);
                     }
                     b"a:lvl2pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level2_paragraph_properties(obj);
                     }
                     b"a:lvl3pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level3_paragraph_properties(obj);
                     }
                     b"a:lvl4pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level4_paragraph_properties(obj);
                     }
                     b"a:lvl5pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level5_paragraph_properties(obj);
                     }
                     b"a:lvl6pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level6_paragraph_properties(obj);
                     }
                     b"a:lvl7pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level7_paragraph_properties(obj);
                     }
                     b"a:lvl8pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level8_paragraph_properties(obj);
                     }
                     b"a:lvl9pPr" => {
                         let mut obj = TextParagraphPropertiesType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_level9_paragraph_properties(obj);
                     }
                     _ => (),
@@ -287,10 +287,10 @@ impl ListStyle {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lstStyle" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lstStyle")
+            Event::Eof => return Err(eof_error("a:lstStyle"))
         );
     }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Offset {
     x: Int64Value,
     y: Int64Value,
@@ -33,9 +33,10 @@ impl Offset {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.x.set_value_string(get_attribute(e, b"x").unwrap());
-        self.y.set_value_string(get_attribute(e, b"y").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.x.set_value_string(get_required_attribute(e, b"x")?)?;
+        self.y.set_value_string(get_required_attribute(e, b"y")?)?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -10,7 +10,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct OuterShadow {
     blur_radius: StringValue,
     alignment: StringValue,
@@ -131,26 +131,26 @@ impl OuterShadow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"blurRad") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"blurRad")? {
             self.set_blur_radius(v);
         }
-        if let Some(v) = get_attribute(e, b"dist") {
+        if let Some(v) = get_attribute(e, b"dist")? {
             self.set_distance(v);
         }
-        if let Some(v) = get_attribute(e, b"dir") {
+        if let Some(v) = get_attribute(e, b"dir")? {
             self.set_direction(v);
         }
-        if let Some(v) = get_attribute(e, b"sx") {
+        if let Some(v) = get_attribute(e, b"sx")? {
             self.set_horizontal_ratio(v);
         }
-        if let Some(v) = get_attribute(e, b"sy") {
+        if let Some(v) = get_attribute(e, b"sy")? {
             self.set_vertical_ratio(v);
         }
-        if let Some(v) = get_attribute(e, b"algn") {
+        if let Some(v) = get_attribute(e, b"algn")? {
             self.set_alignment(v);
         }
-        if let Some(v) = get_attribute(e, b"rotWithShape") {
+        if let Some(v) = get_attribute(e, b"rotWithShape")? {
             self.set_rotate_with_shape(v);
         }
 
@@ -160,12 +160,12 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -175,17 +175,17 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:prstClr" => {
                         let mut obj = PresetColor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_preset_color(obj);
                     }
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -193,10 +193,10 @@ impl OuterShadow {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:outerShdw" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:outerShdw")
+            Event::Eof => return Err(eof_error("a:outerShdw"))
         );
     }
 
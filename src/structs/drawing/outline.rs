@@ -1,6 +1,7 @@
 // a:ln
 use super::Bevel;
 use super::GradientFill;
+use super::HeadEnd;
 use super::Miter;
 use super::NoFill;
 use super::PenAlignmentValues;
@@ -18,13 +19,14 @@ use structs::EnumValue;
 use structs::UInt32Value;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Outline {
     width: UInt32Value,
     cap_type: StringValue,
     compound_line_type: StringValue,
     solid_fill: Option<SolidFill>,
     gradient_fill: Option<GradientFill>,
+    head_end: Option<HeadEnd>,
     tail_end: Option<TailEnd>,
     no_fill: Option<NoFill>,
     bevel: Option<Bevel>,
@@ -88,6 +90,24 @@ impl Outline {
         self
     }
 
+    pub fn get_head_end(&self) -> Option<&HeadEnd> {
+        self.head_end.as_ref()
+    }
+
+    pub fn get_head_end_mut(&mut self) -> Option<&mut HeadEnd> {
+        self.head_end.as_mut()
+    }
+
+    pub fn set_head_end(&mut self, value: HeadEnd) -> &mut Self {
+        self.head_end = Some(value);
+        self
+    }
+
+    pub fn remove_head_end(&mut self) -> &mut Self {
+        self.head_end = None;
+        self
+    }
+
     pub fn get_tail_end(&self) -> Option<&TailEnd> {
         self.tail_end.as_ref()
     }
@@ -101,6 +121,11 @@ impl Outline {
         self
     }
 
+    pub fn remove_tail_end(&mut self) -> &mut Self {
+        self.tail_end = None;
+        self
+    }
+
     pub fn get_no_fill(&self) -> Option<&NoFill> {
         self.no_fill.as_ref()
     }
@@ -178,16 +203,16 @@ impl Outline {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"w") {
-            self.set_width(v.parse::<u32>().unwrap());
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"w")? {
+            self.set_width(v.parse::<u32>().map_err(parse_error)?);
         }
 
-        if let Some(v) = get_attribute(e, b"cap") {
+        if let Some(v) = get_attribute(e, b"cap")? {
             self.set_cap_type(v);
         }
 
-        if let Some(v) = get_attribute(e, b"cmpd") {
+        if let Some(v) = get_attribute(e, b"cmpd")? {
             self.set_compound_line_type(v);
         }
 
@@ -199,12 +224,12 @@ impl Outline {
                 match e.name().into_inner() {
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     _ => (),
@@ -212,34 +237,39 @@ impl Outline {
             },
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
+                    b"a:headEnd" => {
+                        let mut obj = HeadEnd::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_head_end(obj);
+                    }
                     b"a:tailEnd" => {
                         let mut obj = TailEnd::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_tail_end(obj);
                     }
                     b"a:noFill" => {
                         let mut obj = NoFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_no_fill(obj);
                     }
                     b"a:bevel" => {
                         let mut obj = Bevel::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_bevel(obj);
                     }
                     b"a:miter" => {
                         let mut obj = Miter::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_miter(obj);
                     }
                     b"a:prstDash" => {
                         let mut obj = PresetDash::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_preset_dash(obj);
                     }
                     b"a:round" => {
                         let mut obj = Round::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_round(obj);
                     }
                     _ => (),
@@ -247,10 +277,10 @@ impl Outline {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:ln" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:ln")
+            Event::Eof => return Err(eof_error("a:ln"))
         );
     }
 
@@ -287,6 +317,11 @@ impl Outline {
             v.write_to(writer);
         }
 
+        // a:headEnd
+        if let Some(v) = &self.head_end {
+            v.write_to(writer);
+        }
+
         // a:tailEnd
         if let Some(v) = &self.tail_end {
             v.write_to(writer);
@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Paragraph {
     paragraph_properties: ParagraphProperties,
     run: Vec<Run>,
@@ -59,22 +59,22 @@ impl Paragraph {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:pPr" => {
-                        self.paragraph_properties.set_attributes(reader, e, false);
+                        self.paragraph_properties.set_attributes(reader, e, false)?;
                     }
                     b"a:r" => {
                         let mut run = Run::default();
-                        run.set_attributes(reader, e);
+                        run.set_attributes(reader, e)?;
                         self.add_run(run);
                     }
                     b"a:endParaRPr" => {
                         let mut run_properties = RunProperties::default();
-                        run_properties.set_attributes(reader, e, false);
+                        run_properties.set_attributes(reader, e, false)?;
                         self.set_end_para_run_properties(run_properties);
                     }
                     _ => (),
@@ -83,11 +83,11 @@ impl Paragraph {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:pPr" => {
-                        self.paragraph_properties.set_attributes(reader, e, true);
+                        self.paragraph_properties.set_attributes(reader, e, true)?;
                     }
                     b"a:endParaRPr" => {
                         let mut run_properties = RunProperties::default();
-                        run_properties.set_attributes(reader, e, true);
+                        run_properties.set_attributes(reader, e, true)?;
                         self.set_end_para_run_properties(run_properties);
                     }
                     _ => (),
@@ -95,10 +95,10 @@ impl Paragraph {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:p" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:p")
+            Event::Eof => return Err(eof_error("a:p"))
         );
     }
 
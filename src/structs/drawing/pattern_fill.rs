@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PatternFill {
     preset: String,
     foreground_color: ForegroundColor,
@@ -65,8 +65,8 @@ impl PatternFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"prst") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"prst")? {
             self.set_preset(v);
         }
 
@@ -75,20 +75,20 @@ impl PatternFill {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:fgClr" => {
-                        self.foreground_color.set_attributes(reader, e);
+                        self.foreground_color.set_attributes(reader, e)?;
                     },
                     b"a:bgClr" => {
-                        self.background_color.set_attributes(reader, e);
+                        self.background_color.set_attributes(reader, e)?;
                     },
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:pattFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:pattFill")
+            Event::Eof => return Err(eof_error("a:pattFill"))
         );
     }
 
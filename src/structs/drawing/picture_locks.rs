@@ -6,7 +6,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PictureLocks {
     no_change_aspect: bool,
 }
@@ -24,12 +24,13 @@ impl PictureLocks {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"noChangeAspect") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"noChangeAspect")? {
             if v == "1" {
                 self.set_no_change_aspect(true);
             }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -8,7 +8,7 @@ use std::io::Cursor;
 use structs::Int64Value;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Point2DType {
     x: Int64Value,
     y: Int64Value,
@@ -35,9 +35,10 @@ impl Point2DType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, x, "x");
-        set_string_from_xml!(self, e, y, "y");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, x, "x");
+        set_number_from_xml!(self, e, y, "y");
+        Ok(())
     }
 
     pub(crate) fn write_to_off(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
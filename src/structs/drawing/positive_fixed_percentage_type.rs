@@ -25,8 +25,9 @@ impl PositiveFixedPercentageType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, val, "val");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, val, "val");
+        Ok(())
     }
 
     pub(crate) fn write_to_shade(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -8,7 +8,7 @@ use std::io::Cursor;
 use structs::Int64Value;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PositiveSize2DType {
     cx: Int64Value,
     cy: Int64Value,
@@ -35,9 +35,10 @@ impl PositiveSize2DType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, cx, "cx");
-        set_string_from_xml!(self, e, cy, "cy");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, cx, "cx");
+        set_number_from_xml!(self, e, cy, "cy");
+        Ok(())
     }
 
     pub(crate) fn write_to_ext(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
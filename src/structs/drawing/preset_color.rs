@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PresetColor {
     val: String,
     alpha: Option<Alpha>,
@@ -38,24 +38,24 @@ impl PresetColor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(get_required_attribute(e, b"val")?);
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:alpha" {
                     let mut alpha = Alpha::default();
-                    alpha.set_attributes(reader, e);
+                    alpha.set_attributes(reader, e)?;
                     self.set_alpha(alpha);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstClr")
+            Event::Eof => return Err(eof_error("a:prstClr"))
         );
     }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PresetGeometry {
     geometry: String,
     adjust_value_list: AdjustValueList,
@@ -227,22 +227,22 @@ impl PresetGeometry {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_geometry(get_attribute(e, b"prst").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_geometry(get_required_attribute(e, b"prst")?);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    self.get_adjust_value_list_mut().set_attributes(reader, e);
+                    self.get_adjust_value_list_mut().set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstGeom" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstGeom")
+            Event::Eof => return Err(eof_error("a:prstGeom"))
         );
     }
 
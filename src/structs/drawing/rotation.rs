@@ -46,10 +46,11 @@ impl Rotation {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, latitude, "lat");
-        set_string_from_xml!(self, e, longitude, "lon");
-        set_string_from_xml!(self, e, revolution, "rev");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, latitude, "lat");
+        set_number_from_xml!(self, e, longitude, "lon");
+        set_number_from_xml!(self, e, revolution, "rev");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -6,7 +6,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Run {
     text: String,
     run_properties: RunProperties,
@@ -37,28 +37,28 @@ impl Run {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"a:rPr" {
-                    self.run_properties.set_attributes(reader, e, false);
+                    self.run_properties.set_attributes(reader, e, false)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:rPr" {
-                    self.run_properties.set_attributes(reader, e, true);
+                    self.run_properties.set_attributes(reader, e, true)?;
                 }
             },
             Event::Text(e) => {
-                self.set_text(e.unescape().unwrap());
+                self.set_text(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"a:r" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:r")
+            Event::Eof => return Err(eof_error("a:r"))
         );
     }
 
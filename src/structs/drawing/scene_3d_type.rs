@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Scene3DType {
     camera: Option<Camera>,
     light_rig: Option<LightRig>,
@@ -37,19 +37,19 @@ impl Scene3DType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -59,12 +59,12 @@ impl Scene3DType {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -72,10 +72,10 @@ impl Scene3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:scene3d" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:scene3d")
+            Event::Eof => return Err(eof_error("a:scene3d"))
         );
     }
 
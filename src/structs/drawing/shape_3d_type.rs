@@ -10,7 +10,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Shape3DType {
     preset_material: EnumValue<PresetMaterialTypeValues>,
     bevel_top: Option<BevelTop>,
@@ -55,7 +55,7 @@ impl Shape3DType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset_material, "prstMaterial");
 
         xml_read_loop!(
@@ -64,12 +64,12 @@ impl Shape3DType {
                 match e.name().into_inner() {
                     b"a:bevelT" => {
                         let mut obj = BevelTop::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_bevel_top(obj);
                     }
                     b"a:bevelB" => {
                         let mut obj = BevelBottom::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_bevel_bottom(obj);
                     }
                     _ => (),
@@ -77,10 +77,10 @@ impl Shape3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:sp3d" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:sp3d")
+            Event::Eof => return Err(eof_error("a:sp3d"))
         );
     }
 
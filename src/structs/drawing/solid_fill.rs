@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SolidFill {
     scheme_color: Option<SchemeColor>,
     rgb_color_model_hex: Option<RgbColorModelHex>,
@@ -43,19 +43,19 @@ impl SolidFill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, false);
+                    scheme_color.set_attributes(reader, e, false)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, false);
+                    rgb_color_model_hex.set_attributes(reader, e, false)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 _ => (),
@@ -65,12 +65,12 @@ impl SolidFill {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, true);
+                    scheme_color.set_attributes(reader, e, true)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, true);
+                    rgb_color_model_hex.set_attributes(reader, e, true)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 _ => (),
@@ -78,10 +78,10 @@ impl SolidFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:solidFill" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:solidFill")
+            Event::Eof => return Err(eof_error("a:solidFill"))
         );
     }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SourceRectangle {
     t: StringValue,
     l: StringValue,
@@ -51,18 +51,19 @@ impl SourceRectangle {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         for a in e.attributes().with_checks(false) {
             if let Ok(attr) = a {
                 match attr.key.0 {
-                    b"t" => self.set_t(get_attribute_value(&attr).unwrap()),
-                    b"l" => self.set_l(get_attribute_value(&attr).unwrap()),
-                    b"r" => self.set_r(get_attribute_value(&attr).unwrap()),
-                    b"b" => self.set_b(get_attribute_value(&attr).unwrap()),
+                    b"t" => self.set_t(get_attribute_value(&attr)?),
+                    b"l" => self.set_l(get_attribute_value(&attr)?),
+                    b"r" => self.set_r(get_attribute_value(&attr)?),
+                    b"b" => self.set_b(get_attribute_value(&attr)?),
                     _ => {}
                 }
             }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
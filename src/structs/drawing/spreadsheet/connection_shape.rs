@@ -1,5 +1,6 @@
 // xdr:cxnSp
 use super::super::super::Anchor;
+use super::make_solid_fill;
 use super::NonVisualConnectionShapeProperties;
 use super::ShapeProperties;
 use super::ShapeStyle;
@@ -8,10 +9,21 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::drawing::EndConnection;
+use structs::drawing::HeadEnd;
+use structs::drawing::Outline;
+use structs::drawing::SchemeColor;
+use structs::drawing::SchemeColorValues;
+use structs::drawing::StartConnection;
+use structs::drawing::StyleMatrixReferenceType;
+use structs::drawing::TailEnd;
 use structs::raw::RawRelationships;
+use structs::ArrowType;
+use structs::ConnectorType;
+use structs::EnumTrait;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ConnectionShape {
     anchor: Anchor,
     non_visual_connection_shape_properties: NonVisualConnectionShapeProperties,
@@ -75,35 +87,140 @@ impl ConnectionShape {
         self.shape_style = value;
     }
 
+    /// Create a new connector.
+    /// It has a thin black line and no arrowheads.
+    /// # Arguments
+    /// * `connector_type` - ConnectorType.
+    pub fn new_connector(&mut self, connector_type: ConnectorType) -> &mut Self {
+        self.non_visual_connection_shape_properties
+            .get_non_visual_drawing_properties_mut()
+            .set_name("Connector 1");
+        self.set_connector_type(connector_type);
+        self.set_line("FF000000", 9525);
+
+        let mut shape_style = ShapeStyle::default();
+        shape_style.set_line_reference(make_style_reference("1", SchemeColorValues::Accent1));
+        shape_style.set_fill_reference(make_style_reference("0", SchemeColorValues::Accent1));
+        shape_style.set_effect_reference(make_style_reference("0", SchemeColorValues::Accent1));
+        shape_style.set_font_reference(make_style_reference("minor", SchemeColorValues::Text1));
+        self.set_shape_style(shape_style);
+        self
+    }
+
+    /// Set the connector geometry.
+    /// # Arguments
+    /// * `value` - ConnectorType.
+    pub fn set_connector_type(&mut self, value: ConnectorType) -> &mut Self {
+        self.shape_properties
+            .get_geometry_mut()
+            .set_geometry(value.get_value_string());
+        self
+    }
+
+    /// Set line color and width. Arrowheads are kept.
+    /// # Arguments
+    /// * `argb` - Color. ex) `"FF000000"`
+    /// * `width` - Width (EMU). ex) `12700` = 1pt
+    pub fn set_line<S: Into<String>>(&mut self, argb: S, width: u32) -> &mut Self {
+        let mut outline = Outline::default();
+        outline.set_width(width);
+        outline.set_solid_fill(make_solid_fill(argb.into()));
+        if let Some(v) = self.shape_properties.get_outline() {
+            if let Some(head_end) = v.get_head_end() {
+                outline.set_head_end(head_end.clone());
+            }
+            if let Some(tail_end) = v.get_tail_end() {
+                outline.set_tail_end(tail_end.clone());
+            }
+        }
+        self.shape_properties.set_outline(outline);
+        self
+    }
+
+    /// Set the arrowhead at the start of the line.
+    /// # Arguments
+    /// * `value` - ArrowType.
+    pub fn set_head_arrow(&mut self, value: ArrowType) -> &mut Self {
+        let mut head_end = HeadEnd::default();
+        head_end.set_type(value.get_value_string());
+        self.get_outline_mut().set_head_end(head_end);
+        self
+    }
+
+    /// Set the arrowhead at the end of the line.
+    /// # Arguments
+    /// * `value` - ArrowType.
+    pub fn set_tail_arrow(&mut self, value: ArrowType) -> &mut Self {
+        let mut tail_end = TailEnd::default();
+        tail_end.set_type(value.get_value_string());
+        self.get_outline_mut().set_tail_end(tail_end);
+        self
+    }
+
+    /// Glue the start of the connector to a shape.
+    /// # Arguments
+    /// * `id` - Id of the shape. (cNvPr id)
+    /// * `index` - Connection site of the shape. For a rectangle 0:top 1:left 2:bottom 3:right.
+    pub fn set_start_shape(&mut self, id: u32, index: u32) -> &mut Self {
+        let mut connection = StartConnection::default();
+        connection.set_id(id);
+        connection.set_index(index);
+        self.non_visual_connection_shape_properties
+            .get_non_visual_connector_shape_drawing_properties_mut()
+            .set_start_connection(connection);
+        self
+    }
+
+    /// Glue the end of the connector to a shape.
+    /// # Arguments
+    /// * `id` - Id of the shape. (cNvPr id)
+    /// * `index` - Connection site of the shape. For a rectangle 0:top 1:left 2:bottom 3:right.
+    pub fn set_end_shape(&mut self, id: u32, index: u32) -> &mut Self {
+        let mut connection = EndConnection::default();
+        connection.set_id(id);
+        connection.set_index(index);
+        self.non_visual_connection_shape_properties
+            .get_non_visual_connector_shape_drawing_properties_mut()
+            .set_end_connection(connection);
+        self
+    }
+
+    fn get_outline_mut(&mut self) -> &mut Outline {
+        if self.shape_properties.get_outline().is_none() {
+            self.shape_properties.set_outline(Outline::default());
+        }
+        self.shape_properties.get_outline_mut().unwrap()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvCxnSpPr" => {
                         self.non_visual_connection_shape_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     b"xdr:spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     b"xdr:style" => {
-                        self.shape_style.set_attributes(reader, e);
+                        self.shape_style.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cxnSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cxnSp")
+            Event::Eof => return Err(eof_error("xdr:cxnSp"))
         );
     }
 
@@ -116,7 +233,8 @@ impl ConnectionShape {
         write_start_tag(writer, "xdr:cxnSp", vec![("macro", "")], false);
 
         // xdr:nvCxnSpPr
-        self.non_visual_connection_shape_properties.write_to(writer);
+        self.non_visual_connection_shape_properties
+            .write_to(writer, rel_list);
 
         // xdr:spPr
         self.shape_properties.write_to(writer, rel_list);
@@ -127,3 +245,12 @@ impl ConnectionShape {
         write_end_tag(writer, "xdr:cxnSp");
     }
 }
+
+fn make_style_reference(index: &str, color: SchemeColorValues) -> StyleMatrixReferenceType {
+    let mut scheme_color = SchemeColor::default();
+    scheme_color.set_val(color);
+    let mut reference = StyleMatrixReferenceType::default();
+    reference.set_index(index);
+    reference.set_scheme_color(scheme_color);
+    reference
+}
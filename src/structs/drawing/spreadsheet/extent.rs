@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Extent {
     cx: Int64Value,
     cy: Int64Value,
@@ -36,9 +36,10 @@ impl Extent {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, cx, "cx");
-        set_string_from_xml!(self, e, cy, "cy");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, cx, "cx");
+        set_number_from_xml!(self, e, cy, "cy");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -69,7 +69,7 @@ impl FromMarker {
         &mut self,
         reader:&mut Reader<R>,
         _e:&BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value:String = String::from("");
         let mut buf = Vec::new();
         loop {
@@ -78,23 +78,23 @@ impl FromMarker {
                 Ok(Event::End(ref e)) => {
                     match e.name().into_inner() {
                         b"xdr:col" => {
-                            self.col = string_value.parse::<usize>().unwrap();
+                            self.col = string_value.parse::<usize>().map_err(parse_error)?;
                         },
                         b"xdr:colOff" => {
-                            self.col_off = string_value.parse::<usize>().unwrap();
+                            self.col_off = string_value.parse::<usize>().map_err(parse_error)?;
                         },
                         b"xdr:row" => {
-                            self.row = string_value.parse::<usize>().unwrap();
+                            self.row = string_value.parse::<usize>().map_err(parse_error)?;
                         },
                         b"xdr:rowOff" => {
-                            self.row_off = string_value.parse::<usize>().unwrap();
+                            self.row_off = string_value.parse::<usize>().map_err(parse_error)?;
                         },
                         b"xdr:from" => return,
                         _ => (),
                     }
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xdr:from"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("xdr:from")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
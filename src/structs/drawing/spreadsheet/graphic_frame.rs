@@ -12,7 +12,7 @@ use structs::raw::RawRelationships;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GraphicFrame {
     r#macro: StringValue,
     non_visual_graphic_frame_properties: NonVisualGraphicFrameProperties,
@@ -79,7 +79,7 @@ impl GraphicFrame {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#macro, "macro");
 
         xml_read_loop!(
@@ -88,24 +88,24 @@ impl GraphicFrame {
                 match e.name().into_inner() {
                     b"xdr:nvGraphicFramePr" => {
                         self.non_visual_graphic_frame_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     b"xdr:xfrm" => {
-                        self.transform.set_attributes(reader, e);
+                        self.transform.set_attributes(reader, e)?;
                     }
                     b"a:graphic" => {
                         self.graphic
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:graphicFrame" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:graphicFrame")
+            Event::Eof => return Err(eof_error("xdr:graphicFrame"))
         );
     }
 
@@ -123,7 +123,8 @@ impl GraphicFrame {
         );
 
         // xdr:nvGraphicFramePr
-        self.non_visual_graphic_frame_properties.write_to(writer);
+        self.non_visual_graphic_frame_properties
+            .write_to(writer, rel_list);
 
         // xdr:xfrm
         self.transform.write_to(writer);
@@ -1,4 +1,5 @@
 // xdr:grpSp
+use super::ConnectionShape;
 use super::GroupShapeProperties;
 use super::NonVisualGroupShapeProperties;
 use super::Picture;
@@ -8,15 +9,20 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::drawing::Point2DType;
+use structs::drawing::PositiveSize2DType;
+use structs::drawing::Transform2D;
 use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GroupShape {
     non_visual_group_shape_properties: NonVisualGroupShapeProperties,
     group_shape_properties: GroupShapeProperties,
     picture_collection: Vec<Picture>,
     shape_collection: Vec<Shape>,
+    connection_shape_collection: Vec<ConnectionShape>,
+    group_shape_collection: Vec<GroupShape>,
 }
 
 impl GroupShape {
@@ -70,41 +76,251 @@ impl GroupShape {
         self.shape_collection.push(value);
     }
 
+    pub fn get_connection_shape_collection(&self) -> &Vec<ConnectionShape> {
+        &self.connection_shape_collection
+    }
+
+    pub fn get_connection_shape_collection_mut(&mut self) -> &mut Vec<ConnectionShape> {
+        &mut self.connection_shape_collection
+    }
+
+    pub fn add_connection_shape_collection(&mut self, value: ConnectionShape) {
+        self.connection_shape_collection.push(value);
+    }
+
+    pub fn get_group_shape_collection(&self) -> &Vec<GroupShape> {
+        &self.group_shape_collection
+    }
+
+    pub fn get_group_shape_collection_mut(&mut self) -> &mut Vec<GroupShape> {
+        &mut self.group_shape_collection
+    }
+
+    pub fn add_group_shape_collection(&mut self, value: GroupShape) {
+        self.group_shape_collection.push(value);
+    }
+
+    /// Create a new empty group.
+    /// Members are placed with `add_shape` and `add_connector`, and the group is scaled to its anchor.
+    pub fn new_group(&mut self) -> &mut Self {
+        self.non_visual_group_shape_properties
+            .get_non_visual_drawing_properties_mut()
+            .set_name("Group 1");
+        let mut transform = Transform2D::default();
+        transform.set_child_offset(Point2DType::default());
+        transform.set_child_extents(PositiveSize2DType::default());
+        self.group_shape_properties.set_transform2d(transform);
+        self
+    }
+
+    /// Add a shape to the group.
+    /// # Arguments
+    /// * `value` - Shape. (see `Shape::new_shape`)
+    /// * `x` - Left of the shape in the group (EMU).
+    /// * `y` - Top of the shape in the group (EMU).
+    /// * `width` - Width of the shape (EMU).
+    /// * `height` - Height of the shape (EMU).
+    pub fn add_shape(
+        &mut self,
+        mut value: Shape,
+        x: i64,
+        y: i64,
+        width: i64,
+        height: i64,
+    ) -> &mut Self {
+        value
+            .get_shape_properties_mut()
+            .set_transform2d(self.make_child_transform(x, y, width, height));
+        self.add_shape_collection(value);
+        self
+    }
+
+    /// Add a connector to the group.
+    /// # Arguments
+    /// * `value` - ConnectionShape. (see `ConnectionShape::new_connector`)
+    /// * `x` - Left of the connector in the group (EMU).
+    /// * `y` - Top of the connector in the group (EMU).
+    /// * `width` - Width of the connector (EMU).
+    /// * `height` - Height of the connector (EMU).
+    pub fn add_connector(
+        &mut self,
+        mut value: ConnectionShape,
+        x: i64,
+        y: i64,
+        width: i64,
+        height: i64,
+    ) -> &mut Self {
+        value
+            .get_shape_properties_mut()
+            .set_transform2d(self.make_child_transform(x, y, width, height));
+        self.add_connection_shape_collection(value);
+        self
+    }
+
+    fn make_child_transform(&mut self, x: i64, y: i64, width: i64, height: i64) -> Transform2D {
+        if self.group_shape_properties.get_transform2d().is_none() {
+            self.new_group();
+        }
+        let group = self.group_shape_properties.get_transform2d_mut().unwrap();
+        let cx = (*group.get_extents().get_cx()).max(x + width);
+        let cy = (*group.get_extents().get_cy()).max(y + height);
+        let mut extents = PositiveSize2DType::default();
+        extents.set_cx(cx);
+        extents.set_cy(cy);
+        group.set_extents(extents.clone());
+        group.set_child_extents(extents);
+
+        let mut transform = Transform2D::default();
+        transform.get_offset_mut().set_x(x);
+        transform.get_offset_mut().set_y(y);
+        transform.get_extents_mut().set_cx(width);
+        transform.get_extents_mut().set_cy(height);
+        transform
+    }
+
+    pub(crate) fn get_max_id(&self) -> u32 {
+        let mut max_id = *self
+            .non_visual_group_shape_properties
+            .get_non_visual_drawing_properties()
+            .get_id();
+        for obj in &self.picture_collection {
+            let id = obj
+                .get_non_visual_picture_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        for obj in &self.shape_collection {
+            let id = obj
+                .get_non_visual_shape_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        for obj in &self.connection_shape_collection {
+            let id = obj
+                .get_non_visual_connection_shape_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        for obj in &self.group_shape_collection {
+            max_id = max_id.max(obj.get_max_id());
+        }
+        max_id
+    }
+
+    /// Clear the id of the group and of every member.
+    pub(crate) fn clear_id(&mut self) {
+        self.non_visual_group_shape_properties
+            .get_non_visual_drawing_properties_mut()
+            .set_id(0);
+        for obj in &mut self.picture_collection {
+            obj.get_non_visual_picture_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_id(0);
+        }
+        for obj in &mut self.shape_collection {
+            obj.get_non_visual_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_id(0);
+        }
+        for obj in &mut self.connection_shape_collection {
+            obj.get_non_visual_connection_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_id(0);
+        }
+        for obj in &mut self.group_shape_collection {
+            obj.clear_id();
+        }
+    }
+
+    /// Give an id to the group and to every member that has none.
+    pub(crate) fn set_missing_id(&mut self, next_id: &mut u32) {
+        let properties = self
+            .non_visual_group_shape_properties
+            .get_non_visual_drawing_properties_mut();
+        if properties.get_id() == &0 {
+            properties.set_id(*next_id);
+            *next_id += 1;
+        }
+        for obj in &mut self.picture_collection {
+            let properties = obj
+                .get_non_visual_picture_properties_mut()
+                .get_non_visual_drawing_properties_mut();
+            if properties.get_id() == &0 {
+                properties.set_id(*next_id);
+                *next_id += 1;
+            }
+        }
+        for obj in &mut self.shape_collection {
+            let properties = obj
+                .get_non_visual_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut();
+            if properties.get_id() == &0 {
+                properties.set_id(*next_id);
+                *next_id += 1;
+            }
+        }
+        for obj in &mut self.connection_shape_collection {
+            let properties = obj
+                .get_non_visual_connection_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut();
+            if properties.get_id() == &0 {
+                properties.set_id(*next_id);
+                *next_id += 1;
+            }
+        }
+        for obj in &mut self.group_shape_collection {
+            obj.set_missing_id(next_id);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvGrpSpPr" => {
-                        self.non_visual_group_shape_properties.set_attributes(reader, e);
+                        self.non_visual_group_shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     b"xdr:grpSpPr" => {
-                        self.group_shape_properties.set_attributes(reader, e);
+                        self.group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_picture_collection(obj);
                     }
                     b"xdr:sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_shape_collection(obj);
                     }
+                    b"xdr:cxnSp" => {
+                        let mut obj = ConnectionShape::default();
+                        obj.set_attributes(reader, e, drawing_relationships)?;
+                        self.add_connection_shape_collection(obj);
+                    }
+                    b"xdr:grpSp" => {
+                        let mut obj = GroupShape::default();
+                        obj.set_attributes(reader, e, drawing_relationships)?;
+                        self.add_group_shape_collection(obj);
+                    }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:grpSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSp")
+            Event::Eof => return Err(eof_error("xdr:grpSp"))
         );
     }
 
@@ -117,7 +333,9 @@ impl GroupShape {
         write_start_tag(writer, "xdr:grpSp", vec![], false);
 
         // xdr:nvGrpSpPr
-        &self.non_visual_group_shape_properties.write_to(writer);
+        &self
+            .non_visual_group_shape_properties
+            .write_to(writer, rel_list);
 
         // xdr:grpSpPr
         &self.group_shape_properties.write_to(writer);
@@ -132,6 +350,16 @@ impl GroupShape {
             obj.write_to(writer, rel_list, &0);
         }
 
+        // xdr:cxnSp
+        for obj in &self.connection_shape_collection {
+            obj.write_to(writer, rel_list);
+        }
+
+        // xdr:grpSp
+        for obj in &self.group_shape_collection {
+            obj.write_to(writer, rel_list);
+        }
+
         write_end_tag(writer, "xdr:grpSp");
     }
 }
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct GroupShapeProperties {
     transform2d: Option<Transform2D>,
 }
@@ -30,14 +30,14 @@ impl GroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     _ => (),
@@ -45,10 +45,10 @@ impl GroupShapeProperties {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"xdr:grpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSpPr")
+            Event::Eof => return Err(eof_error("xdr:grpSpPr"))
         );
     }
 
@@ -3,11 +3,12 @@ use helper::coordinate::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use traits::AdjustmentCoordinate;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct MarkerType {
     col: u32,
     col_off: usize,
@@ -75,31 +76,31 @@ impl MarkerType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::from("");
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+                Ok(Event::Text(e)) => string_value = e.unescape()?.to_string(),
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"xdr:col" => {
-                        self.col = string_value.parse::<u32>().unwrap();
+                        self.col = string_value.parse::<u32>().map_err(parse_error)?;
                     }
                     b"xdr:colOff" => {
-                        self.col_off = string_value.parse::<usize>().unwrap();
+                        self.col_off = string_value.parse::<usize>().map_err(parse_error)?;
                     }
                     b"xdr:row" => {
-                        self.row = string_value.parse::<u32>().unwrap();
+                        self.row = string_value.parse::<u32>().map_err(parse_error)?;
                     }
                     b"xdr:rowOff" => {
-                        self.row_off = string_value.parse::<usize>().unwrap();
+                        self.row_off = string_value.parse::<usize>().map_err(parse_error)?;
                     }
-                    b"xdr:from" => return,
-                    b"xdr:to" => return,
+                    b"xdr:from" => return Ok(()),
+                    b"xdr:to" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xdr:from,xdr:to"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("xdr:from,xdr:to")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
@@ -159,8 +160,16 @@ impl AdjustmentCoordinate for MarkerType {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        self.col = adjustment_remove_coordinate(&(&self.col + 1), root_col_num, offset_col_num) - 1;
-        self.row = adjustment_remove_coordinate(&(&self.row + 1), root_row_num, offset_row_num) - 1;
+        if is_remove_coordinate(&(&self.col + 1), root_col_num, offset_col_num) {
+            self.col_off = 0;
+        }
+        if is_remove_coordinate(&(&self.row + 1), root_row_num, offset_row_num) {
+            self.row_off = 0;
+        }
+        self.col =
+            adjustment_remove_coordinate_clamp(&(&self.col + 1), root_col_num, offset_col_num) - 1;
+        self.row =
+            adjustment_remove_coordinate_clamp(&(&self.row + 1), root_row_num, offset_row_num) - 1;
     }
 
     fn is_remove_coordinate(
@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualConnectorShapeDrawingProperties {
     start_connection: Option<StartConnection>,
     end_connection: Option<EndConnection>,
@@ -43,19 +43,19 @@ impl NonVisualConnectorShapeDrawingProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:stCxn" => {
                         let mut connection_type = StartConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_start_connection(connection_type);
                     }
                     b"a:endCxn" => {
                         let mut connection_type = EndConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_end_connection(connection_type);
                     }
                     _ => (),
@@ -63,10 +63,10 @@ impl NonVisualConnectorShapeDrawingProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvCxnSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvCxnSpPr")
+            Event::Eof => return Err(eof_error("xdr:cNvCxnSpPr"))
         );
     }
 
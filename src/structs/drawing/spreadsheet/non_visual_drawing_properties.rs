@@ -2,18 +2,21 @@
 use super::super::super::BooleanValue;
 use super::super::super::StringValue;
 use super::super::super::UInt32Value;
+use super::super::HyperlinkClick;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualDrawingProperties {
     id: UInt32Value,
     name: StringValue,
     hidden: BooleanValue,
+    hyperlink_click: Option<Box<HyperlinkClick>>,
 }
 
 impl NonVisualDrawingProperties {
@@ -44,34 +47,74 @@ impl NonVisualDrawingProperties {
         self
     }
 
+    /// Get the hyperlink opened by a click on the drawing.
+    pub fn get_hyperlink_click(&self) -> Option<&HyperlinkClick> {
+        self.hyperlink_click.as_deref()
+    }
+
+    pub fn get_hyperlink_click_mut(&mut self) -> Option<&mut HyperlinkClick> {
+        self.hyperlink_click.as_deref_mut()
+    }
+
+    pub fn set_hyperlink_click(&mut self, value: HyperlinkClick) -> &mut Self {
+        self.hyperlink_click = Some(Box::new(value));
+        self
+    }
+
+    pub fn remove_hyperlink_click(&mut self) -> &mut Self {
+        self.hyperlink_click = None;
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
+        drawing_relationships: Option<&RawRelationships>,
         empty_flg: bool,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id
+            .set_value_string(get_required_attribute(e, b"id")?)?;
         self.name
-            .set_value_string(get_attribute(e, b"name").unwrap());
+            .set_value_string(get_required_attribute(e, b"name")?);
         set_string_from_xml!(self, e, hidden, "hidden");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"a:hlinkClick" {
+                    let mut obj = HyperlinkClick::default();
+                    obj.set_attributes(reader, e, drawing_relationships, false)?;
+                    self.set_hyperlink_click(obj);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"a:hlinkClick" {
+                    let mut obj = HyperlinkClick::default();
+                    obj.set_attributes(reader, e, drawing_relationships, true)?;
+                    self.set_hyperlink_click(obj);
+                }
+            },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvPr")
+            Event::Eof => return Err(eof_error("xdr:cNvPr"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, ole_id: &usize) {
-        let with_inner = ole_id > &0;
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+        ole_id: &usize,
+    ) {
+        let with_inner = ole_id > &0 || self.hyperlink_click.is_some();
         // xdr:cNvPr
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let id = self.id.get_value_string();
@@ -82,7 +125,12 @@ impl NonVisualDrawingProperties {
         }
         write_start_tag(writer, "xdr:cNvPr", attributes, !with_inner);
 
-        if with_inner {
+        if let Some(v) = &self.hyperlink_click {
+            // a:hlinkClick
+            v.write_to(writer, rel_list);
+        }
+
+        if ole_id > &0 {
             let spid = format!("_x0000_s{}", ole_id);
             write_start_tag(writer, "a:extLst", vec![], false);
             write_start_tag(
@@ -95,6 +143,9 @@ impl NonVisualDrawingProperties {
 
             write_end_tag(writer, "a:ext");
             write_end_tag(writer, "a:extLst");
+        }
+
+        if with_inner {
             write_end_tag(writer, "xdr:cNvPr");
         }
     }
@@ -6,9 +6,10 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualGraphicFrameProperties {
     non_visual_drawing_properties: NonVisualDrawingProperties,
     non_visual_graphic_frame_drawing_properties: NonVisualGraphicFrameDrawingProperties,
@@ -55,18 +56,19 @@ impl NonVisualGraphicFrameProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, drawing_relationships, true)?;
                     },
                     b"xdr:cNvGraphicFramePr" => {
                         self.non_visual_graphic_frame_drawing_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                     },
                     _ => (),
                 }
@@ -74,24 +76,29 @@ impl NonVisualGraphicFrameProperties {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, false);
+                        .set_attributes(reader, e, drawing_relationships, false)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvGraphicFramePr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvGraphicFramePr")
+            Event::Eof => return Err(eof_error("xdr:nvGraphicFramePr"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
         // xdr:nvGraphicFramePr
         write_start_tag(writer, "xdr:nvGraphicFramePr", vec![], false);
 
         // xdr:cNvPr
-        self.non_visual_drawing_properties.write_to(writer, &0);
+        self.non_visual_drawing_properties
+            .write_to(writer, rel_list, &0);
 
         // xdr:cNvGraphicFramePr
         self.non_visual_graphic_frame_drawing_properties
@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualGroupShapeDrawingProperties {
     group_shape_locks: Option<GroupShapeLocks>,
 }
@@ -32,9 +32,9 @@ impl NonVisualGroupShapeDrawingProperties {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -42,16 +42,16 @@ impl NonVisualGroupShapeDrawingProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:grpSpLocks" {
                     let mut obj = GroupShapeLocks::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_group_shape_locks(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvGrpSpPr")
+            Event::Eof => return Err(eof_error("xdr:cNvGrpSpPr"))
         );
     }
 
@@ -6,9 +6,10 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualGroupShapeProperties {
     non_visual_drawing_properties: NonVisualDrawingProperties,
     non_visual_group_shape_drawing_properties: NonVisualGroupShapeDrawingProperties,
@@ -55,18 +56,19 @@ impl NonVisualGroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner(){
                     b"xdr:cNvPr" =>{
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, drawing_relationships, false)?;
                     }
                     b"a:cNvGrpSpPr"=> {
                         self.non_visual_group_shape_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                     }
                     _=>()
                 }
@@ -75,30 +77,35 @@ impl NonVisualGroupShapeProperties {
                 match e.name().into_inner() {
                     b"xdr:cNvPr" =>{
                         self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, drawing_relationships, true)?;
                     }
                     b"a:cNvGrpSpPr" =>{
                         self.non_visual_group_shape_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                     }
                     _ => ()
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvGrpSpPr")
+            Event::Eof => return Err(eof_error("xdr:nvGrpSpPr"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
         // xdr:nvGrpSpPr
         write_start_tag(writer, "xdr:nvGrpSpPr", vec![], false);
 
         // xdr:cNvPr
-        self.non_visual_drawing_properties.write_to(writer, &0);
+        self.non_visual_drawing_properties
+            .write_to(writer, rel_list, &0);
 
         // xdr:cNvGrpSpPr
         self.non_visual_group_shape_drawing_properties
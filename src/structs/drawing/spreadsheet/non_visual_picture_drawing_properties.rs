@@ -8,7 +8,7 @@ use std::io::Cursor;
 use structs::BooleanValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualPictureDrawingProperties {
     prefer_relative_resize: BooleanValue,
     picture_locks: Option<PictureLocks>,
@@ -40,11 +40,11 @@ impl NonVisualPictureDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, prefer_relative_resize, "preferRelativeResize");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -52,16 +52,16 @@ impl NonVisualPictureDrawingProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:picLocks" {
                     let mut obj = PictureLocks::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_picture_locks(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPicPr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvPicPr")
+            Event::Eof => return Err(eof_error("xdr:cNvPicPr"))
         );
     }
 
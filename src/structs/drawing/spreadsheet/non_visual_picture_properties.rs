@@ -6,9 +6,10 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualPictureProperties {
     non_visual_drawing_properties: NonVisualDrawingProperties,
     non_visual_picture_drawing_properties: NonVisualPictureDrawingProperties,
@@ -48,18 +49,19 @@ impl NonVisualPictureProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:cNvPicPr" => {
                         self.non_visual_picture_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                         }
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, drawing_relationships, false)?;
                         }
                     _ => (),
                 }
@@ -68,30 +70,35 @@ impl NonVisualPictureProperties {
                 match e.name().into_inner() {
                     b"xdr:cNvPicPr" => {
                         self.non_visual_picture_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                         }
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, drawing_relationships, true)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvPicPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvPicPr")
+            Event::Eof => return Err(eof_error("xdr:nvPicPr"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+    ) {
         // xdr:nvPicPr
         write_start_tag(writer, "xdr:nvPicPr", vec![], false);
 
         // xdr:cNvPr
-        self.non_visual_drawing_properties.write_to(writer, &0);
+        self.non_visual_drawing_properties
+            .write_to(writer, rel_list, &0);
 
         // xdr:cNvPicPr
         self.non_visual_picture_drawing_properties.write_to(writer);
@@ -5,11 +5,14 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::raw::RawRelationships;
+use structs::BooleanValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct NonVisualShapeProperties {
     non_visual_drawing_properties: NonVisualDrawingProperties,
+    text_box: BooleanValue,
 }
 
 impl NonVisualShapeProperties {
@@ -25,43 +28,75 @@ impl NonVisualShapeProperties {
         self.non_visual_drawing_properties = value;
     }
 
+    pub fn get_text_box(&self) -> &bool {
+        self.text_box.get_value()
+    }
+
+    pub fn set_text_box(&mut self, value: bool) -> &mut Self {
+        self.text_box.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+        drawing_relationships: Option<&RawRelationships>,
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
-                if e.name().into_inner() == b"xdr:cNvPr" {
-                    self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                match e.name().into_inner() {
+                    b"xdr:cNvPr" => {
+                        self.non_visual_drawing_properties
+                            .set_attributes(reader, e, drawing_relationships, true)?;
+                    }
+                    b"xdr:cNvSpPr" => {
+                        set_string_from_xml!(self, e, text_box, "txBox");
+                    }
+                    _ => (),
                 }
             },
             Event::Start(ref e) => {
-                if e.name().into_inner() == b"xdr:cNvPr" {
-                    self.non_visual_drawing_properties
-                        .set_attributes(reader, e, false);
+                match e.name().into_inner() {
+                    b"xdr:cNvPr" => {
+                        self.non_visual_drawing_properties
+                            .set_attributes(reader, e, drawing_relationships, false)?;
+                    }
+                    b"xdr:cNvSpPr" => {
+                        set_string_from_xml!(self, e, text_box, "txBox");
+                    }
+                    _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvSpPr")
+            Event::Eof => return Err(eof_error("xdr:nvSpPr"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, ole_id: &usize) {
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        rel_list: &mut Vec<(String, String)>,
+        ole_id: &usize,
+    ) {
         // xdr:nvSpPr
         write_start_tag(writer, "xdr:nvSpPr", vec![], false);
 
         // xdr:cNvPr
-        self.non_visual_drawing_properties.write_to(writer, ole_id);
+        self.non_visual_drawing_properties
+            .write_to(writer, rel_list, ole_id);
 
         // xdr:cNvSpPr
-        write_start_tag(writer, "xdr:cNvSpPr", vec![], true);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.text_box.has_value() {
+            attributes.push(("txBox", self.text_box.get_value_string()));
+        }
+        write_start_tag(writer, "xdr:cNvSpPr", attributes, true);
 
         write_end_tag(writer, "xdr:nvSpPr");
     }
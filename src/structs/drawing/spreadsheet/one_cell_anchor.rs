@@ -13,13 +13,14 @@ use structs::raw::RawRelationships;
 use traits::AdjustmentCoordinate;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct OneCellAnchor {
     from_marker: MarkerType,
     extent: Extent,
     group_shape: Option<GroupShape>,
     shape: Option<Shape>,
     picture: Option<Picture>,
+    z_order: i32,
 }
 
 impl OneCellAnchor {
@@ -88,6 +89,17 @@ impl OneCellAnchor {
         self
     }
 
+    /// Stacking order among the drawing objects of the worksheet.
+    /// Objects with a larger value are drawn on top.
+    pub fn get_z_order(&self) -> &i32 {
+        &self.z_order
+    }
+
+    pub fn set_z_order(&mut self, value: i32) -> &mut Self {
+        self.z_order = value;
+        self
+    }
+
     pub(crate) fn is_image(&self) -> bool {
         self.picture.is_some() || self.group_shape.is_some()
     }
@@ -97,27 +109,27 @@ impl OneCellAnchor {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSp" => {
                         let mut obj = GroupShape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_group_shape(obj);
                     }
                     b"xdr:sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_shape(obj);
                     }
                     b"xdr:pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_picture(obj);
                     }
                     _ => (),
@@ -125,15 +137,15 @@ impl OneCellAnchor {
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xdr:ext" {
-                    self.extent.set_attributes(reader, e);
+                    self.extent.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:oneCellAnchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:oneCellAnchor")
+            Event::Eof => return Err(eof_error("xdr:oneCellAnchor"))
         );
     }
 
@@ -10,7 +10,7 @@ use std::io::Cursor;
 use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Picture {
     non_visual_picture_properties: NonVisualPictureProperties,
     blip_fill: BlipFill,
@@ -59,30 +59,30 @@ impl Picture {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvPicPr" => {
-                        self.non_visual_picture_properties.set_attributes(reader, e);
+                        self.non_visual_picture_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     b"xdr:blipFill" => {
                         self.blip_fill
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     b"xdr:spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:pic" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:pic")
+            Event::Eof => return Err(eof_error("xdr:pic"))
         );
     }
 
@@ -95,7 +95,8 @@ impl Picture {
         write_start_tag(writer, "xdr:pic", vec![], false);
 
         // xdr:nvPicPr
-        self.non_visual_picture_properties.write_to(writer);
+        self.non_visual_picture_properties
+            .write_to(writer, rel_list);
 
         // xdr:blipFill
         self.blip_fill.write_to(writer, rel_list);
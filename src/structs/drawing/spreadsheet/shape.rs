@@ -9,10 +9,23 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::drawing::NoFill;
+use structs::drawing::Outline;
+use structs::drawing::Paragraph;
+use structs::drawing::RgbColorModelHex;
+use structs::drawing::Run;
+use structs::drawing::RunProperties;
+use structs::drawing::SolidFill;
+use structs::drawing::TextFontType;
+use structs::drawing::TextWrappingValues;
 use structs::raw::RawRelationships;
+use structs::EnumTrait;
+use structs::Font;
+use structs::RichText;
+use structs::ShapeType;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Shape {
     anchor: Anchor,
     non_visual_shape_properties: NonVisualShapeProperties,
@@ -82,30 +95,178 @@ impl Shape {
         self.text_body = Some(value);
     }
 
+    /// Initialize as a simple shape with a white fill and a thin black outline.
+    /// # Arguments
+    /// * `shape_type` - Preset geometry of the shape.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let mut shape = structs::drawing::spreadsheet::Shape::default();
+    /// shape.new_shape(ShapeType::FlowChartDecision);
+    /// shape.set_text("OK?");
+    /// shape.set_fill_color("FFDDEBF7");
+    ///
+    /// let mut from_marker = structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("B2");
+    /// let mut to_marker = structs::drawing::spreadsheet::MarkerType::default();
+    /// to_marker.set_coordinate("D5");
+    /// book.get_sheet_mut(&0).unwrap().add_shape(shape, from_marker, to_marker);
+    /// ```
+    pub fn new_shape(&mut self, shape_type: ShapeType) -> &mut Self {
+        self.non_visual_shape_properties
+            .get_non_visual_drawing_properties_mut()
+            .set_name("Shape 1");
+        self.set_shape_type(shape_type);
+
+        let mut text_body = TextBody::default();
+        text_body
+            .get_body_properties_mut()
+            .set_vert_overflow("clip")
+            .set_horz_overflow("clip")
+            .set_rtl_col("0")
+            .set_anchor("ctr")
+            .set_wrap(TextWrappingValues::Square);
+        text_body.add_paragraph(Paragraph::default());
+        self.set_text_body(text_body);
+
+        self.set_fill_color("FFFFFFFF");
+        self.set_border("FF000000", 9525);
+        self
+    }
+
+    /// Set the preset geometry.
+    /// # Arguments
+    /// * `value` - ShapeType.
+    pub fn set_shape_type(&mut self, value: ShapeType) -> &mut Self {
+        self.shape_properties
+            .get_geometry_mut()
+            .set_geometry(value.get_value_string());
+        self
+    }
+
+    /// Get the plain text. Paragraphs are joined with a line feed.
+    pub fn get_text(&self) -> String {
+        match &self.text_body {
+            Some(text_body) => text_body
+                .get_paragraph()
+                .iter()
+                .map(|paragraph| {
+                    paragraph
+                        .get_run()
+                        .iter()
+                        .map(|run| run.get_text())
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+            None => String::from(""),
+        }
+    }
+
+    /// Set plain text. A line feed starts a new paragraph.
+    /// # Arguments
+    /// * `value` - Text.
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let mut rich_text = RichText::default();
+        rich_text.set_text(value);
+        self.set_rich_text(&rich_text)
+    }
+
+    /// Set rich text. The font of each element (name, size, bold, italic, color) is kept.
+    /// A line feed starts a new paragraph.
+    /// # Arguments
+    /// * `value` - RichText.
+    pub fn set_rich_text(&mut self, value: &RichText) -> &mut Self {
+        let mut paragraph_list: Vec<Paragraph> = vec![Paragraph::default()];
+        for element in value.get_rich_text_elements() {
+            let run_properties = match element.get_font() {
+                Some(font) => make_run_properties(font),
+                None => RunProperties::default(),
+            };
+            for (i, line) in element.get_text().split('\n').enumerate() {
+                if i > 0 {
+                    paragraph_list.push(Paragraph::default());
+                }
+                if line.is_empty() {
+                    continue;
+                }
+                let mut run = Run::default();
+                run.set_text(line);
+                run.set_run_properties(run_properties.clone());
+                paragraph_list.last_mut().unwrap().add_run(run);
+            }
+        }
+
+        let text_body = self.text_body.get_or_insert(TextBody::default());
+        text_body.get_paragraph_mut().clear();
+        for paragraph in paragraph_list {
+            text_body.add_paragraph(paragraph);
+        }
+        self
+    }
+
+    /// Set fill color.
+    /// # Arguments
+    /// * `argb` - Color. ex) `"FFFFFF00"`
+    pub fn set_fill_color<S: Into<String>>(&mut self, argb: S) -> &mut Self {
+        self.shape_properties.remove_no_fill();
+        self.shape_properties
+            .set_solid_fill(make_solid_fill(argb.into()));
+        self
+    }
+
+    /// Remove fill (transparent background).
+    pub fn remove_fill(&mut self) -> &mut Self {
+        self.shape_properties.remove_solid_fill();
+        self.shape_properties.set_no_fill(NoFill::default());
+        self
+    }
+
+    /// Set border.
+    /// # Arguments
+    /// * `argb` - Color. ex) `"FF000000"`
+    /// * `width` - Width (EMU). ex) `12700` = 1pt
+    pub fn set_border<S: Into<String>>(&mut self, argb: S, width: u32) -> &mut Self {
+        let mut outline = Outline::default();
+        outline.set_width(width);
+        outline.set_solid_fill(make_solid_fill(argb.into()));
+        self.shape_properties.set_outline(outline);
+        self
+    }
+
+    /// Remove border.
+    pub fn remove_border(&mut self) -> &mut Self {
+        let mut outline = Outline::default();
+        outline.set_no_fill(NoFill::default());
+        self.shape_properties.set_outline(outline);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
                 Event::Start(ref e) => {
                     match e.name().into_inner() {
                         b"xdr:nvSpPr" => {
-                            self.non_visual_shape_properties.set_attributes(reader, e);
+                            self.non_visual_shape_properties.set_attributes(reader, e, drawing_relationships)?;
                         }
                         b"xdr:spPr" => {
-                            self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                            self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                         }
                         b"xdr:style" => {
                             let mut obj = ShapeStyle::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_shape_style(obj);
                         }
                         b"xdr:txBody" => {
                             let mut obj = TextBody::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_text_body(obj);
                         }
                         _ => (),
@@ -113,10 +274,10 @@ impl Shape {
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"xdr:sp" {
-                        return;
+                        return Ok(());
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "xdr:sp")
+                Event::Eof => return Err(eof_error("xdr:sp"))
         );
     }
 
@@ -135,7 +296,8 @@ impl Shape {
         );
 
         // xdr:nvSpPr
-        self.non_visual_shape_properties.write_to(writer, ole_id);
+        self.non_visual_shape_properties
+            .write_to(writer, rel_list, ole_id);
 
         // xdr:spPr
         self.shape_properties.write_to(writer, rel_list);
@@ -153,3 +315,38 @@ impl Shape {
         write_end_tag(writer, "xdr:sp");
     }
 }
+
+pub(crate) fn make_solid_fill(argb: String) -> SolidFill {
+    let rgb = match argb.len() {
+        8 => argb[2..].to_string(),
+        _ => argb,
+    };
+    let mut color = RgbColorModelHex::default();
+    color.set_val(rgb);
+    let mut solid_fill = SolidFill::default();
+    solid_fill.set_rgb_color_model_hex(color);
+    solid_fill
+}
+
+fn make_run_properties(font: &Font) -> RunProperties {
+    let mut run_properties = RunProperties::default();
+    if *font.get_bold() {
+        run_properties.set_bold("1");
+    }
+    if *font.get_italic() {
+        run_properties.set_italic("1");
+    }
+    if *font.get_size() > 0f64 {
+        run_properties.set_sz(((font.get_size() * 100f64) as i32).to_string());
+    }
+    let argb = font.get_color().get_argb();
+    if !argb.is_empty() {
+        run_properties.set_solid_fill(make_solid_fill(argb.to_string()));
+    }
+    if !font.get_name().is_empty() {
+        let mut latin_font = TextFontType::default();
+        latin_font.set_typeface(font.get_name());
+        run_properties.set_latin_font(latin_font);
+    }
+    run_properties
+}
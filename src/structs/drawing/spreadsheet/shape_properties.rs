@@ -15,7 +15,7 @@ use std::io::Cursor;
 use structs::raw::RawRelationships;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ShapeProperties {
     transform2d: Option<Transform2D>,
     preset_geometry: PresetGeometry,
@@ -79,6 +79,11 @@ impl ShapeProperties {
         self
     }
 
+    pub fn remove_solid_fill(&mut self) -> &mut Self {
+        self.solid_fill = None;
+        self
+    }
+
     pub fn get_outline(&self) -> Option<&Outline> {
         self.outline.as_ref()
     }
@@ -92,6 +97,11 @@ impl ShapeProperties {
         self
     }
 
+    pub fn remove_outline(&mut self) -> &mut Self {
+        self.outline = None;
+        self
+    }
+
     pub fn get_effect_list(&self) -> Option<&EffectList> {
         self.effect_list.as_ref()
     }
@@ -118,6 +128,11 @@ impl ShapeProperties {
         self
     }
 
+    pub fn remove_no_fill(&mut self) -> &mut Self {
+        self.no_fill = None;
+        self
+    }
+
     pub fn get_extension_list(&self) -> Option<&ExtensionList> {
         self.extension_list.as_ref()
     }
@@ -136,42 +151,42 @@ impl ShapeProperties {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     b"a:prstGeom" => {
-                        self.preset_geometry.set_attributes(reader, e);
+                        self.preset_geometry.set_attributes(reader, e)?;
                     }
                     b"a:blipFill" => {
                         let mut obj = BlipFill::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_blip_fill(obj);
                     }
                     b"a:ln" => {
                         let mut outline = Outline::default();
-                        outline.set_attributes(reader, e);
+                        outline.set_attributes(reader, e)?;
                         self.set_outline(outline);
                     }
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:effectLst" => {
                         let mut effect_list = EffectList::default();
-                        effect_list.set_attributes(reader, e, false);
+                        effect_list.set_attributes(reader, e, false)?;
                         self.set_effect_list(effect_list);
                     }
                     b"a:extLst" => {
                         let mut obj = ExtensionList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_extension_list(obj);
                     }
                     _ => (),
@@ -180,16 +195,16 @@ impl ShapeProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:noFill" {
                     let mut obj = NoFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_no_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:spPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:spPr")
+            Event::Eof => return Err(eof_error("xdr:spPr"))
         );
     }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ShapeStyle {
     line_reference: Option<StyleMatrixReferenceType>,
     fill_reference: Option<StyleMatrixReferenceType>,
@@ -52,29 +52,29 @@ impl ShapeStyle {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:lnRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_line_reference(style_matrix_reference_type);
                     }
                     b"a:fillRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_fill_reference(style_matrix_reference_type);
                     }
                     b"a:effectRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_effect_reference(style_matrix_reference_type);
                     }
                     b"a:fontRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, false);
+                        style_matrix_reference_type.set_attributes(reader, e, false)?;
                         self.set_font_reference(style_matrix_reference_type);
                     }
                     _ => (),
@@ -84,22 +84,22 @@ impl ShapeStyle {
                 match e.name().into_inner() {
                     b"a:lnRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_line_reference(style_matrix_reference_type);
                     }
                     b"a:fillRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_fill_reference(style_matrix_reference_type);
                     }
                     b"a:effectRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_effect_reference(style_matrix_reference_type);
                     }
                     b"a:fontRef" => {
                         let mut style_matrix_reference_type = StyleMatrixReferenceType::default();
-                        style_matrix_reference_type.set_attributes(reader, e, true);
+                        style_matrix_reference_type.set_attributes(reader, e, true)?;
                         self.set_font_reference(style_matrix_reference_type);
                     }
                     _ => (),
@@ -107,10 +107,10 @@ impl ShapeStyle {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:style" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:style")
+            Event::Eof => return Err(eof_error("xdr:style"))
         );
     }
 
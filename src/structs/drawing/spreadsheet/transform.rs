@@ -8,7 +8,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Transform {
     offset: Offset,
     extents: Extents,
@@ -72,8 +72,8 @@ impl Transform {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, rotation, "rot");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, rotation, "rot");
         set_string_from_xml!(self, e, horizontal_flip, "flipH");
         set_string_from_xml!(self, e, vertical_flip, "flipV");
 
@@ -82,20 +82,20 @@ impl Transform {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e);
+                        self.offset.set_attributes(reader, e)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e);
+                        self.extents.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:xfrm")
+            Event::Eof => return Err(eof_error("xdr:xfrm"))
         );
     }
 
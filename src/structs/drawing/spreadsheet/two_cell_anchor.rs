@@ -9,6 +9,7 @@ use super::Picture;
 use super::Shape;
 use helper::const_str::MC_NS;
 use helper::const_str::*;
+use helper::coordinate::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -20,7 +21,7 @@ use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct TwoCellAnchor {
     edit_as: EnumValue<EditAsValues>,
     from_marker: MarkerType,
@@ -31,6 +32,7 @@ pub struct TwoCellAnchor {
     connection_shape: Option<ConnectionShape>,
     picture: Option<Picture>,
     is_alternate_content: BooleanValue,
+    z_order: i32,
 }
 
 impl TwoCellAnchor {
@@ -134,6 +136,17 @@ impl TwoCellAnchor {
         self
     }
 
+    /// Stacking order among the drawing objects of the worksheet.
+    /// Objects with a larger value are drawn on top.
+    pub fn get_z_order(&self) -> &i32 {
+        &self.z_order
+    }
+
+    pub fn set_z_order(&mut self, value: i32) -> &mut Self {
+        self.z_order = value;
+        self
+    }
+
     pub fn get_is_alternate_content(&self) -> &bool {
         self.is_alternate_content.get_value()
     }
@@ -143,17 +156,6 @@ impl TwoCellAnchor {
         self
     }
 
-    pub(crate) fn is_support(&self) -> bool {
-        self.graphic_frame.as_ref().map_or(true, |v| {
-            v.get_graphic()
-                .get_graphic_data()
-                .get_chart_space()
-                .get_chart()
-                .get_plot_area()
-                .is_support()
-        })
-    }
-
     pub(crate) fn is_chart(&self) -> bool {
         self.graphic_frame.is_some()
     }
@@ -162,12 +164,20 @@ impl TwoCellAnchor {
         self.picture.is_some() || self.group_shape.is_some()
     }
 
+    pub(crate) fn is_text_box(&self) -> bool {
+        self.shape.as_ref().map_or(false, |v| {
+            *v.get_non_visual_shape_properties().get_text_box()
+                && v.get_text_body().is_some()
+                && v.get_shape_properties().get_blip_fill().is_none()
+        })
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, edit_as, "editAs");
 
         xml_read_loop!(
@@ -175,34 +185,34 @@ impl TwoCellAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"xdr:from" => {
-                    self.from_marker.set_attributes(reader, e);
+                    self.from_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:to" => {
-                    self.to_marker.set_attributes(reader, e);
+                    self.to_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:grpSp" => {
                     let mut obj = GroupShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_group_shape(obj);
                 }
                 b"xdr:graphicFrame" => {
                     let mut obj = GraphicFrame::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_graphic_frame(obj);
                 }
                 b"xdr:sp" => {
                     let mut obj = Shape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_shape(obj);
                 }
                 b"xdr:cxnSp" => {
                     let mut obj = ConnectionShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_connection_shape(obj);
                 }
                 b"xdr:pic" => {
                     let mut obj = Picture::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_picture(obj);
                 }
                 _ => (),
@@ -210,13 +220,35 @@ impl TwoCellAnchor {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:twoCellAnchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:twoCellAnchor")
+            Event::Eof => return Err(eof_error("xdr:twoCellAnchor"))
         );
     }
 
+    // Move the to marker by as much as the from marker moved from (col, row).
+    fn move_to_marker(&mut self, col: &u32, row: &u32) {
+        let to_col = (self.to_marker.get_col() + self.from_marker.get_col()).saturating_sub(*col);
+        let to_row = (self.to_marker.get_row() + self.from_marker.get_row()).saturating_sub(*row);
+        self.to_marker.set_col(to_col);
+        self.to_marker.set_row(to_row);
+    }
+
+    // Last column and row the anchor covers.
+    // A to marker with no offset ends at the edge of the previous cell.
+    fn get_last_cell(&self) -> (u32, u32) {
+        let mut col = *self.to_marker.get_col();
+        if self.to_marker.get_col_off() == &0 && &col > self.from_marker.get_col() {
+            col -= 1;
+        }
+        let mut row = *self.to_marker.get_row();
+        if self.to_marker.get_row_off() == &0 && &row > self.from_marker.get_row() {
+            row -= 1;
+        }
+        (col, row)
+    }
+
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
@@ -302,18 +334,34 @@ impl AdjustmentCoordinate for TwoCellAnchor {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        self.from_marker.adjustment_insert_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        );
-        self.to_marker.adjustment_insert_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        );
+        let col = *self.from_marker.get_col();
+        let row = *self.from_marker.get_row();
+        match self.get_edit_as() {
+            EditAsValues::Absolute => {}
+            EditAsValues::OneCell => {
+                self.from_marker.adjustment_insert_coordinate(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+                self.move_to_marker(&col, &row);
+            }
+            EditAsValues::TwoCell => {
+                self.from_marker.adjustment_insert_coordinate(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+                self.to_marker.adjustment_insert_coordinate(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            }
+        }
     }
 
     fn adjustment_remove_coordinate(
@@ -323,18 +371,34 @@ impl AdjustmentCoordinate for TwoCellAnchor {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        self.from_marker.adjustment_remove_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        );
-        self.to_marker.adjustment_remove_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        );
+        let col = *self.from_marker.get_col();
+        let row = *self.from_marker.get_row();
+        match self.get_edit_as() {
+            EditAsValues::Absolute => {}
+            EditAsValues::OneCell => {
+                self.from_marker.adjustment_remove_coordinate(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+                self.move_to_marker(&col, &row);
+            }
+            EditAsValues::TwoCell => {
+                self.from_marker.adjustment_remove_coordinate(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+                self.to_marker.adjustment_remove_coordinate(
+                    root_col_num,
+                    offset_col_num,
+                    root_row_num,
+                    offset_row_num,
+                );
+            }
+        }
     }
 
     fn is_remove_coordinate(
@@ -344,17 +408,29 @@ impl AdjustmentCoordinate for TwoCellAnchor {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) -> bool {
-        self.from_marker.is_remove_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        ) || self.to_marker.is_remove_coordinate(
-            root_col_num,
-            offset_col_num,
-            root_row_num,
-            offset_row_num,
-        )
+        match self.get_edit_as() {
+            EditAsValues::Absolute => false,
+            EditAsValues::OneCell => self.from_marker.is_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            ),
+            EditAsValues::TwoCell => {
+                // removed only when every column or every row it covers is removed.
+                let (col_end, row_end) = self.get_last_cell();
+                (is_remove_coordinate(
+                    &(self.from_marker.get_col() + 1),
+                    root_col_num,
+                    offset_col_num,
+                ) && is_remove_coordinate(&(col_end + 1), root_col_num, offset_col_num))
+                    || (is_remove_coordinate(
+                        &(self.from_marker.get_row() + 1),
+                        root_row_num,
+                        offset_row_num,
+                    ) && is_remove_coordinate(&(row_end + 1), root_row_num, offset_row_num))
+            }
+        }
     }
 }
 impl AdjustmentCoordinateWithSheet for TwoCellAnchor {
@@ -1,6 +1,7 @@
 // xdr:wsDr
 use super::ConnectionShape;
 use super::GraphicFrame;
+use super::GroupShape;
 use super::OneCellAnchor;
 use super::Picture;
 use super::Shape;
@@ -15,16 +16,18 @@ use structs::raw::RawRelationships;
 use structs::Chart;
 use structs::Image;
 use structs::OleObjects;
+use structs::TextBox;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWithSheet;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct WorksheetDrawing {
     image_collection: Vec<Image>,
     chart_collection: Vec<Chart>,
     one_cell_anchor_collection: Vec<OneCellAnchor>,
     two_cell_anchor_collection: Vec<TwoCellAnchor>,
+    text_box_collection: Vec<TextBox>,
 }
 
 impl WorksheetDrawing {
@@ -36,7 +39,10 @@ impl WorksheetDrawing {
         &mut self.image_collection
     }
 
-    pub fn add_image(&mut self, value: Image) -> &mut Self {
+    pub fn add_image(&mut self, mut value: Image) -> &mut Self {
+        if value.get_z_order() == &0 {
+            value.set_z_order(self.get_next_z_order());
+        }
         self.image_collection.push(value);
         self
     }
@@ -81,7 +87,12 @@ impl WorksheetDrawing {
         &mut self.chart_collection
     }
 
-    pub fn add_chart_collection(&mut self, value: Chart) -> &mut Self {
+    pub fn add_chart_collection(&mut self, mut value: Chart) -> &mut Self {
+        if value.get_two_cell_anchor().get_z_order() == &0 {
+            value
+                .get_two_cell_anchor_mut()
+                .set_z_order(self.get_next_z_order());
+        }
         self.chart_collection.push(value);
         self
     }
@@ -118,6 +129,36 @@ impl WorksheetDrawing {
         result
     }
 
+    pub fn get_text_box_collection(&self) -> &Vec<TextBox> {
+        &self.text_box_collection
+    }
+
+    pub fn get_text_box_collection_mut(&mut self) -> &mut Vec<TextBox> {
+        &mut self.text_box_collection
+    }
+
+    pub fn add_text_box(&mut self, mut value: TextBox) -> &mut Self {
+        if value.get_two_cell_anchor().get_z_order() == &0 {
+            value
+                .get_two_cell_anchor_mut()
+                .set_z_order(self.get_next_z_order());
+        }
+        self.text_box_collection.push(value);
+        self
+    }
+
+    pub fn get_text_box(&self, col: &u32, row: &u32) -> Option<&TextBox> {
+        self.text_box_collection
+            .iter()
+            .find(|&text_box| text_box.get_col() == &(col - 1) && text_box.get_row() == &(row - 1))
+    }
+
+    pub fn get_text_box_mut(&mut self, col: &u32, row: &u32) -> Option<&mut TextBox> {
+        self.text_box_collection
+            .iter_mut()
+            .find(|text_box| text_box.get_col() == &(col - 1) && text_box.get_row() == &(row - 1))
+    }
+
     pub fn get_one_cell_anchor_collection(&self) -> &Vec<OneCellAnchor> {
         &self.one_cell_anchor_collection
     }
@@ -126,7 +167,10 @@ impl WorksheetDrawing {
         &mut self.one_cell_anchor_collection
     }
 
-    pub fn add_one_cell_anchor_collection(&mut self, value: OneCellAnchor) -> &mut Self {
+    pub fn add_one_cell_anchor_collection(&mut self, mut value: OneCellAnchor) -> &mut Self {
+        if value.get_z_order() == &0 {
+            value.set_z_order(self.get_next_z_order());
+        }
         self.one_cell_anchor_collection.push(value);
         self
     }
@@ -139,7 +183,10 @@ impl WorksheetDrawing {
         &mut self.two_cell_anchor_collection
     }
 
-    pub fn add_two_cell_anchor_collection(&mut self, value: TwoCellAnchor) -> &mut Self {
+    pub fn add_two_cell_anchor_collection(&mut self, mut value: TwoCellAnchor) -> &mut Self {
+        if value.get_z_order() == &0 {
+            value.set_z_order(self.get_next_z_order());
+        }
         self.two_cell_anchor_collection.push(value);
         self
     }
@@ -149,6 +196,7 @@ impl WorksheetDrawing {
             || !self.image_collection.is_empty()
             || !self.one_cell_anchor_collection.is_empty()
             || !self.two_cell_anchor_collection.is_empty()
+            || !self.text_box_collection.is_empty()
     }
 
     pub fn get_graphic_frame_collection(&self) -> Vec<&GraphicFrame> {
@@ -246,6 +294,9 @@ impl WorksheetDrawing {
             let mut anchor = chart.get_two_cell_anchor_mut();
             result.push(anchor);
         }
+        for text_box in &mut self.text_box_collection {
+            result.push(text_box.get_two_cell_anchor_mut());
+        }
         for image in &mut self.image_collection {
             match image.get_two_cell_anchor_mut() {
                 Some(anchor) => {
@@ -267,13 +318,226 @@ impl WorksheetDrawing {
         result
     }
 
+    pub fn get_group_shape_collection(&self) -> Vec<&GroupShape> {
+        let mut result: Vec<&GroupShape> = Vec::new();
+        for image in &self.image_collection {
+            if let Some(v) = image
+                .get_two_cell_anchor()
+                .and_then(|v| v.get_group_shape())
+            {
+                result.push(v);
+            }
+            if let Some(v) = image
+                .get_one_cell_anchor()
+                .and_then(|v| v.get_group_shape())
+            {
+                result.push(v);
+            }
+        }
+        for two_cell_anchor in &self.two_cell_anchor_collection {
+            if let Some(v) = two_cell_anchor.get_group_shape() {
+                result.push(v);
+            }
+        }
+        for one_cell_anchor in &self.one_cell_anchor_collection {
+            if let Some(v) = one_cell_anchor.get_group_shape() {
+                result.push(v);
+            }
+        }
+        result
+    }
+
+    pub fn get_group_shape_collection_mut(&mut self) -> Vec<&mut GroupShape> {
+        let mut result: Vec<&mut GroupShape> = Vec::new();
+        for image in &mut self.image_collection {
+            let group_shape = match image.get_two_cell_anchor().is_some() {
+                true => image
+                    .get_two_cell_anchor_mut()
+                    .and_then(|v| v.get_group_shape_mut()),
+                false => image
+                    .get_one_cell_anchor_mut()
+                    .and_then(|v| v.get_group_shape_mut()),
+            };
+            if let Some(v) = group_shape {
+                result.push(v);
+            }
+        }
+        for two_cell_anchor in &mut self.two_cell_anchor_collection {
+            if let Some(v) = two_cell_anchor.get_group_shape_mut() {
+                result.push(v);
+            }
+        }
+        for one_cell_anchor in &mut self.one_cell_anchor_collection {
+            if let Some(v) = one_cell_anchor.get_group_shape_mut() {
+                result.push(v);
+            }
+        }
+        result
+    }
+
+    /// Draw the objects anchored at the cell on top of all others.
+    /// # Arguments
+    /// * `col` - Column of the top left anchor.
+    /// * `row` - Row of the top left anchor.
+    pub fn bring_to_front(&mut self, col: &u32, row: &u32) -> &mut Self {
+        let value = self.get_next_z_order();
+        self.set_z_order_at(col, row, value);
+        self
+    }
+
+    /// Draw the objects anchored at the cell below all others.
+    /// # Arguments
+    /// * `col` - Column of the top left anchor.
+    /// * `row` - Row of the top left anchor.
+    pub fn send_to_back(&mut self, col: &u32, row: &u32) -> &mut Self {
+        let value = self.get_z_order_list().into_iter().min().unwrap_or(0) - 1;
+        self.set_z_order_at(col, row, value);
+        self
+    }
+
+    fn set_z_order_at(&mut self, col: &u32, row: &u32, value: i32) {
+        let col = col - 1;
+        let row = row - 1;
+        for chart in &mut self.chart_collection {
+            if chart.get_col() == &col && chart.get_row() == &row {
+                chart.get_two_cell_anchor_mut().set_z_order(value);
+            }
+        }
+        for image in &mut self.image_collection {
+            if image.get_col() == &col && image.get_row() == &row {
+                image.set_z_order(value);
+            }
+        }
+        for anchor in &mut self.two_cell_anchor_collection {
+            if anchor.get_from_marker().get_col() == &col
+                && anchor.get_from_marker().get_row() == &row
+            {
+                anchor.set_z_order(value);
+            }
+        }
+        for text_box in &mut self.text_box_collection {
+            if text_box.get_col() == &col && text_box.get_row() == &row {
+                text_box.get_two_cell_anchor_mut().set_z_order(value);
+            }
+        }
+        for anchor in &mut self.one_cell_anchor_collection {
+            if anchor.get_from_marker().get_col() == &col
+                && anchor.get_from_marker().get_row() == &row
+            {
+                anchor.set_z_order(value);
+            }
+        }
+    }
+
+    fn get_z_order_list(&self) -> Vec<i32> {
+        let mut result: Vec<i32> = Vec::new();
+        for chart in &self.chart_collection {
+            result.push(*chart.get_two_cell_anchor().get_z_order());
+        }
+        for image in &self.image_collection {
+            result.push(*image.get_z_order());
+        }
+        for anchor in &self.two_cell_anchor_collection {
+            result.push(*anchor.get_z_order());
+        }
+        for text_box in &self.text_box_collection {
+            result.push(*text_box.get_two_cell_anchor().get_z_order());
+        }
+        for anchor in &self.one_cell_anchor_collection {
+            result.push(*anchor.get_z_order());
+        }
+        result
+    }
+
+    fn get_next_z_order(&self) -> i32 {
+        self.get_z_order_list()
+            .into_iter()
+            .max()
+            .unwrap_or(0)
+            .max(0)
+            + 1
+    }
+
+    /// Charts in the order they are written to the drawing part.
+    pub(crate) fn get_chart_collection_in_z_order(&self) -> Vec<&Chart> {
+        let mut result: Vec<&Chart> = self.chart_collection.iter().collect();
+        result.sort_by_key(|chart| *chart.get_two_cell_anchor().get_z_order());
+        result
+    }
+
+    pub(crate) fn get_next_shape_id(&self) -> u32 {
+        let mut max_id = 1;
+        for group_shape in self.get_group_shape_collection() {
+            max_id = max_id.max(group_shape.get_max_id());
+        }
+        for shape in self
+            .text_box_collection
+            .iter()
+            .filter_map(|v| v.get_shape())
+        {
+            let id = shape
+                .get_non_visual_shape_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        for shape in self.get_shape_collection() {
+            let id = shape
+                .get_non_visual_shape_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        for connection_shape in self.get_connection_shape_collection() {
+            let id = connection_shape
+                .get_non_visual_connection_shape_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        let pictures = self
+            .image_collection
+            .iter()
+            .filter_map(|v| match v.get_two_cell_anchor() {
+                Some(anchor) => anchor.get_picture(),
+                None => v
+                    .get_one_cell_anchor()
+                    .and_then(|anchor| anchor.get_picture()),
+            })
+            .chain(
+                self.two_cell_anchor_collection
+                    .iter()
+                    .filter_map(|v| v.get_picture()),
+            );
+        for picture in pictures {
+            let id = picture
+                .get_non_visual_picture_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        let graphic_frames = self
+            .chart_collection
+            .iter()
+            .filter_map(|v| v.get_two_cell_anchor().get_graphic_frame())
+            .chain(self.get_graphic_frame_collection());
+        for graphic_frame in graphic_frames {
+            let id = graphic_frame
+                .get_non_visual_graphic_frame_properties()
+                .get_non_visual_drawing_properties()
+                .get_id();
+            max_id = max_id.max(*id);
+        }
+        max_id + 1
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
         ole_objects: &mut OleObjects,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut ole_index = 0;
         let mut is_alternate_content = false;
 
@@ -289,7 +553,7 @@ impl WorksheetDrawing {
                             continue;
                         }
                         let mut obj = OneCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_image() {
                             let mut image = Image::default();
                             image.set_one_cell_anchor(obj);
@@ -308,24 +572,26 @@ impl WorksheetDrawing {
                                 reader,
                                 e,
                                 drawing_relationships,
-                            );
+                            )?;
                             ole_index += 1;
                             continue;
                         }
                         let mut obj = TwoCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
-                        if obj.is_support() {
-                            if obj.is_chart() {
-                                let mut chart = Chart::default();
-                                chart.set_two_cell_anchor(obj);
-                                self.add_chart_collection(chart);
-                            } else if obj.is_image() {
-                                let mut image = Image::default();
-                                image.set_two_cell_anchor(obj);
-                                self.add_image(image);
-                            } else {
-                                self.add_two_cell_anchor_collection(obj);
-                            }
+                        obj.set_attributes(reader, e, drawing_relationships)?;
+                        if obj.is_chart() {
+                            let mut chart = Chart::default();
+                            chart.set_two_cell_anchor(obj);
+                            self.add_chart_collection(chart);
+                        } else if obj.is_image() {
+                            let mut image = Image::default();
+                            image.set_two_cell_anchor(obj);
+                            self.add_image(image);
+                        } else if obj.is_text_box() {
+                            let mut text_box = TextBox::default();
+                            text_box.set_two_cell_anchor(obj);
+                            self.add_text_box(text_box);
+                        } else {
+                            self.add_two_cell_anchor_collection(obj);
                         }
                     }
                     _ => (),
@@ -337,12 +603,12 @@ impl WorksheetDrawing {
                     b"mc:AlternateContent" => {
                         is_alternate_content = false;
                     }
-                    b"xdr:wsDr" => return,
+                    b"xdr:wsDr" => return Ok(()),
                     _ => (),
                 }
             },
 
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:wsDr")
+            Event::Eof => return Err(eof_error("xdr:wsDr"))
         );
     }
 
@@ -363,20 +629,39 @@ impl WorksheetDrawing {
             false,
         );
 
-        // xdr:twoCellAnchor
+        // xdr:twoCellAnchor, xdr:oneCellAnchor
+        // Anchors are written back to front, so the last one is drawn on top.
+        let mut anchor_list: Vec<(&i32, Option<&TwoCellAnchor>, Option<&OneCellAnchor>)> =
+            Vec::new();
         for chart in &self.chart_collection {
-            chart.get_two_cell_anchor().write_to(writer, rel_list, &0);
+            let anchor = chart.get_two_cell_anchor();
+            anchor_list.push((anchor.get_z_order(), Some(anchor), None));
         }
         for image in &self.image_collection {
-            image.write_to(writer, rel_list);
-        }
-        for two_cell_anchor in &self.two_cell_anchor_collection {
-            two_cell_anchor.write_to(writer, rel_list, &0);
-        }
-
-        // xdr:oneCellAnchor
-        for one_cell_anchor in &self.one_cell_anchor_collection {
-            one_cell_anchor.write_to(writer, rel_list);
+            anchor_list.push((
+                image.get_z_order(),
+                image.get_two_cell_anchor(),
+                image.get_one_cell_anchor(),
+            ));
+        }
+        for anchor in &self.two_cell_anchor_collection {
+            anchor_list.push((anchor.get_z_order(), Some(anchor), None));
+        }
+        for text_box in &self.text_box_collection {
+            let anchor = text_box.get_two_cell_anchor();
+            anchor_list.push((anchor.get_z_order(), Some(anchor), None));
+        }
+        for anchor in &self.one_cell_anchor_collection {
+            anchor_list.push((anchor.get_z_order(), None, Some(anchor)));
+        }
+        anchor_list.sort_by_key(|(z_order, _, _)| **z_order);
+        for (_, two_cell_anchor, one_cell_anchor) in anchor_list {
+            if let Some(anchor) = two_cell_anchor {
+                anchor.write_to(writer, rel_list, &0);
+            }
+            if let Some(anchor) = one_cell_anchor {
+                anchor.write_to(writer, rel_list);
+            }
         }
 
         // mc:AlternateContent
@@ -384,7 +669,7 @@ impl WorksheetDrawing {
         for ole_object in ole_objects.get_ole_object() {
             ole_object
                 .get_two_cell_anchor()
-                .write_to(writer, rel_list, &0);
+                .write_to(writer, rel_list, &ole_id);
             ole_id += 1;
         }
 
@@ -431,6 +716,14 @@ impl AdjustmentCoordinate for WorksheetDrawing {
                 offset_row_num,
             );
         }
+        for text_box in &mut self.text_box_collection {
+            text_box.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
     fn adjustment_remove_coordinate(
@@ -484,6 +777,17 @@ impl AdjustmentCoordinate for WorksheetDrawing {
                 offset_row_num,
             );
         }
+        &mut self.text_box_collection.retain(|k| {
+            !(k.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num))
+        });
+        for text_box in &mut self.text_box_collection {
+            text_box.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
 impl AdjustmentCoordinateWithSheet for WorksheetDrawing {
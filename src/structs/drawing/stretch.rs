@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Stretch {
     fill_rectangle: Option<FillRectangle>,
 }
@@ -29,22 +29,22 @@ impl Stretch {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:fillRect" {
                     let mut fill_rectangle = FillRectangle::default();
-                    fill_rectangle.set_attributes(reader, e);
+                    fill_rectangle.set_attributes(reader, e)?;
                     self.set_fill_rectangle(fill_rectangle);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:stretch" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:stretch")
+            Event::Eof => return Err(eof_error("a:stretch"))
         );
     }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct StyleMatrixReferenceType {
     index: String,
     scheme_color: Option<SchemeColor>,
@@ -35,11 +35,11 @@ impl StyleMatrixReferenceType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.set_index(get_attribute(e, b"idx").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_index(get_required_attribute(e, b"idx")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -47,35 +47,35 @@ impl StyleMatrixReferenceType {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, false);
+                    scheme_color.set_attributes(reader, e, false)?;
                     self.set_scheme_color(scheme_color);
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, true);
+                    scheme_color.set_attributes(reader, e, true)?;
                     self.set_scheme_color(scheme_color);
                 }
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
                     b"a:lnRef" => {
-                        return;
+                        return Ok(());
                     }
                     b"a:fillRef" => {
-                        return;
+                        return Ok(());
                     }
                     b"a:effectRef" => {
-                        return;
+                        return Ok(());
                     }
                     b"a:fontRef" => {
-                        return;
+                        return Ok(());
                     }
                     _ => (),
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lnRef")
+            Event::Eof => return Err(eof_error("a:lnRef"))
         );
     }
 
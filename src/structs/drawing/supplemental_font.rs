@@ -7,7 +7,7 @@ use std::io::Cursor;
 use structs::StringValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SupplementalFont {
     script: StringValue,
     typeface: StringValue,
@@ -36,9 +36,10 @@ impl SupplementalFont {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, script, "script");
         set_string_from_xml!(self, e, typeface, "typeface");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -7,7 +7,7 @@ use std::io::Cursor;
 use structs::StringValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct TailEnd {
     t_type: StringValue,
     width: StringValue,
@@ -43,18 +43,19 @@ impl TailEnd {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"type") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"type")? {
             self.set_type(v);
         }
 
-        if let Some(v) = get_attribute(e, b"w") {
+        if let Some(v) = get_attribute(e, b"w")? {
             self.set_width(v);
         }
 
-        if let Some(v) = get_attribute(e, b"len") {
+        if let Some(v) = get_attribute(e, b"len")? {
             self.set_length(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
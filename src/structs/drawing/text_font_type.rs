@@ -6,7 +6,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct TextFontType {
     typeface: StringValue,
     pitch_family: StringValue,
@@ -55,19 +55,20 @@ impl TextFontType {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"typeface") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"typeface")? {
             self.set_typeface(v);
         }
-        if let Some(v) = get_attribute(e, b"pitchFamily") {
+        if let Some(v) = get_attribute(e, b"pitchFamily")? {
             self.set_pitch_family(v);
         }
-        if let Some(v) = get_attribute(e, b"charset") {
+        if let Some(v) = get_attribute(e, b"charset")? {
             self.set_charset(v);
         }
-        if let Some(v) = get_attribute(e, b"panose") {
+        if let Some(v) = get_attribute(e, b"panose")? {
             self.set_panose(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to_latin(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -13,7 +13,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct TextParagraphPropertiesType {
     right_to_left: BooleanValue,
     alignment: EnumValue<TextAlignmentTypeValues>,
@@ -93,7 +93,7 @@ impl TextParagraphPropertiesType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, right_to_left, "rtl");
         set_string_from_xml!(self, e, alignment, "algn");
         set_string_from_xml!(self, e, font_alignment, "fontAlgn");
@@ -104,7 +104,7 @@ impl TextParagraphPropertiesType {
                 match e.name().into_inner() {
                     b"a:defRPr" => {
                         let mut obj = RunProperties::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_default_run_properties(obj);
                     }
                     _ => (),
@@ -114,17 +114,17 @@ impl TextParagraphPropertiesType {
                 match e.name().into_inner() {
                 b"a:spcBef" => {
                     let mut obj = SpaceBefore::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_before(obj);
                 }
                 b"a:spcAft" => {
                     let mut obj = SpaceAfter::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_after(obj);
                 }
                 b"a:defRPr" => {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_default_run_properties(obj);
                 }
                 _ => (),
@@ -132,20 +132,20 @@ impl TextParagraphPropertiesType {
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:defPPr"  => return,
-                    b"a:lvl1pPr" => return,
-                    b"a:lvl2pPr" => return,
-                    b"a:lvl3pPr" => return,
-                    b"a:lvl4pPr" => return,
-                    b"a:lvl5pPr" => return,
-                    b"a:lvl6pPr" => return,
-                    b"a:lvl7pPr" => return,
-                    b"a:lvl8pPr" => return,
-                    b"a:lvl9pPr" => return,
+                    b"a:defPPr"  => return Ok(()),
+                    b"a:lvl1pPr" => return Ok(()),
+                    b"a:lvl2pPr" => return Ok(()),
+                    b"a:lvl3pPr" => return Ok(()),
+                    b"a:lvl4pPr" => return Ok(()),
+                    b"a:lvl5pPr" => return Ok(()),
+                    b"a:lvl6pPr" => return Ok(()),
+                    b"a:lvl7pPr" => return Ok(()),
+                    b"a:lvl8pPr" => return Ok(()),
+                    b"a:lvl9pPr" => return Ok(()),
                     _ =>()
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lvl1pPr-lvl9pPr")
+            Event::Eof => return Err(eof_error("a:lvl1pPr-lvl9pPr"))
         );
     }
 
@@ -27,7 +27,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Theme {
     name: StringValue,
     theme_elements: ThemeElements,
@@ -527,8 +527,8 @@ impl Theme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"name") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"name")? {
             self.name.set_value(v);
         }
 
@@ -536,15 +536,15 @@ impl Theme {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    self.theme_elements.set_attributes(reader, e);
+                    self.theme_elements.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:theme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:theme")
+            Event::Eof => return Err(eof_error("a:theme"))
         );
     }
 
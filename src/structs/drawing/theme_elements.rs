@@ -9,7 +9,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ThemeElements {
     color_scheme: ColorScheme,
     font_scheme: FontScheme,
@@ -57,29 +57,29 @@ impl ThemeElements {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:clrScheme" => {
-                        self.color_scheme.set_attributes(reader, e);
+                        self.color_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fontScheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fmtScheme" => {
-                        self.format_scheme.set_attributes(reader, e);
+                        self.format_scheme.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:themeElements")
+            Event::Eof => return Err(eof_error("a:themeElements"))
         );
     }
 
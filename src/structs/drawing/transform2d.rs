@@ -9,7 +9,7 @@ use structs::drawing::Point2DType;
 use structs::drawing::PositiveSize2DType;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Transform2D {
     offset: Point2DType,
     extents: PositiveSize2DType,
@@ -97,16 +97,16 @@ impl Transform2D {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        if let Some(v) = get_attribute(e, b"rot") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"rot")? {
             self.set_rot(v);
         }
 
-        if let Some(v) = get_attribute(e, b"flipH") {
+        if let Some(v) = get_attribute(e, b"flipH")? {
             self.set_flip_h(v);
         }
 
-        if let Some(v) = get_attribute(e, b"flipV") {
+        if let Some(v) = get_attribute(e, b"flipV")? {
             self.set_flip_v(v);
         }
 
@@ -115,19 +115,19 @@ impl Transform2D {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e);
+                        self.offset.set_attributes(reader, e)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e);
+                        self.extents.set_attributes(reader, e)?;
                     }
                     b"a:chOff" => {
                         let mut obj = Point2DType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_child_offset(obj);
                     }
                     b"a:chExt" => {
                         let mut obj = PositiveSize2DType::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_child_extents(obj);
                     }
                     _ => (),
@@ -135,10 +135,10 @@ impl Transform2D {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:xfrm")
+            Event::Eof => return Err(eof_error("a:xfrm"))
         );
     }
 
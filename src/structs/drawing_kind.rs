@@ -0,0 +1,10 @@
+/// Kind of a drawing object listed by `Worksheet::get_drawings_mut`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DrawingKind {
+    Image,
+    Chart,
+    TextBox,
+    Shape,
+    ConnectionShape,
+    GroupShape,
+}
@@ -0,0 +1,810 @@
+use helper::coordinate::CellCoordinates;
+use structs::drawing::spreadsheet::GroupShape;
+use structs::drawing::spreadsheet::MarkerType;
+use structs::drawing::spreadsheet::OneCellAnchor;
+use structs::drawing::spreadsheet::TwoCellAnchor;
+use structs::drawing::spreadsheet::WorksheetDrawing;
+use structs::drawing::Point2DType;
+use structs::drawing::PositiveSize2DType;
+use structs::drawing::Transform2D;
+use structs::DrawingKind;
+
+// Size of a cell of the default width and height (EMU), used to place the members of a group.
+const CELL_WIDTH_EMU: i64 = 609600;
+const CELL_HEIGHT_EMU: i64 = 190500;
+
+/// All drawing objects of a worksheet (images, charts, text boxes and shapes) in one list.
+/// Objects are addressed by index, in the order images, charts, text boxes, then other shapes.
+/// Removing, duplicating or resizing an object can change the index of the others.
+/// # Examples
+/// ```rust
+/// let mut book = umya_spreadsheet::new_file();
+/// let mut drawings = book.get_sheet_mut(&0).unwrap().get_drawings_mut();
+/// for index in drawings.find("B3") {
+///     drawings.move_to(index, "D5");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Drawings<'a> {
+    worksheet_drawing: &'a mut WorksheetDrawing,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Slot {
+    Image(usize),
+    Chart(usize),
+    TextBox(usize),
+    TwoCellAnchor(usize),
+    OneCellAnchor(usize),
+}
+
+impl<'a> Drawings<'a> {
+    pub(crate) fn new(worksheet_drawing: &'a mut WorksheetDrawing) -> Self {
+        Self { worksheet_drawing }
+    }
+
+    /// Number of drawing objects.
+    pub fn len(&self) -> usize {
+        self.get_slot_list().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the kind of the object.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    pub fn get_kind(&self, index: usize) -> Option<DrawingKind> {
+        let drawing = &*self.worksheet_drawing;
+        match self.get_slot(index)? {
+            Slot::Image(i) => {
+                let image = &drawing.get_image_collection()[i];
+                match image.get_two_cell_anchor() {
+                    Some(anchor) => Some(get_two_cell_anchor_kind(anchor)),
+                    None => image.get_one_cell_anchor().map(get_one_cell_anchor_kind),
+                }
+            }
+            Slot::Chart(_) => Some(DrawingKind::Chart),
+            Slot::TextBox(_) => Some(DrawingKind::TextBox),
+            Slot::TwoCellAnchor(i) => Some(get_two_cell_anchor_kind(
+                &drawing.get_two_cell_anchor_collection()[i],
+            )),
+            Slot::OneCellAnchor(i) => Some(get_one_cell_anchor_kind(
+                &drawing.get_one_cell_anchor_collection()[i],
+            )),
+        }
+    }
+
+    /// Get the top left cell of the object.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    /// # Return value
+    /// * `Option<String>` - ex) `"B3"`
+    pub fn get_coordinate(&self, index: usize) -> Option<String> {
+        self.get_from_marker(index).map(|v| v.get_coordinate())
+    }
+
+    /// Get the bottom right cell of the object.
+    /// Objects anchored to a single cell have none.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    pub fn get_to_coordinate(&self, index: usize) -> Option<String> {
+        self.get_to_marker(index).map(|v| v.get_coordinate())
+    }
+
+    /// Find the objects whose top left corner is in the cell.
+    /// # Arguments
+    /// * `coordinate` - ex) `"B3"`
+    /// # Return value
+    /// * `Vec<usize>` - Index of the objects.
+    pub fn find<T>(&self, coordinate: T) -> Vec<usize>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        (0..self.len())
+            .filter(|index| {
+                self.get_from_marker(*index).map_or(false, |marker| {
+                    marker.get_col() == &col.saturating_sub(1)
+                        && marker.get_row() == &row.saturating_sub(1)
+                })
+            })
+            .collect()
+    }
+
+    /// Move the object to another cell, keeping its size.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    /// * `coordinate` - New top left cell. ex) `"D5"`
+    /// # Return value
+    /// * `bool` - false if there is no object at the index.
+    pub fn move_to<T>(&mut self, index: usize, coordinate: T) -> bool
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let (col, row) = (col.saturating_sub(1), row.saturating_sub(1));
+        let slot = match self.get_slot(index) {
+            Some(v) => v,
+            None => return false,
+        };
+        let drawing = &mut *self.worksheet_drawing;
+        match slot {
+            Slot::Image(i) => {
+                let image = &mut drawing.get_image_collection_mut()[i];
+                if let Some(anchor) = image.get_two_cell_anchor_mut() {
+                    move_two_cell_anchor(anchor, col, row);
+                }
+                if let Some(anchor) = image.get_one_cell_anchor_mut() {
+                    move_marker(anchor.get_from_marker_mut(), col, row);
+                }
+            }
+            Slot::Chart(i) => move_two_cell_anchor(
+                drawing.get_chart_collection_mut()[i].get_two_cell_anchor_mut(),
+                col,
+                row,
+            ),
+            Slot::TextBox(i) => move_two_cell_anchor(
+                drawing.get_text_box_collection_mut()[i].get_two_cell_anchor_mut(),
+                col,
+                row,
+            ),
+            Slot::TwoCellAnchor(i) => move_two_cell_anchor(
+                &mut drawing.get_two_cell_anchor_collection_mut()[i],
+                col,
+                row,
+            ),
+            Slot::OneCellAnchor(i) => move_marker(
+                drawing.get_one_cell_anchor_collection_mut()[i].get_from_marker_mut(),
+                col,
+                row,
+            ),
+        }
+        true
+    }
+
+    /// Stretch the object so that its bottom right corner is at the start of the cell.
+    /// An object anchored to a single cell is anchored to two cells afterwards.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    /// * `coordinate` - New bottom right cell. ex) `"F10"`
+    /// # Return value
+    /// * `bool` - false if there is no object at the index or the cell is above or left of its top left cell.
+    pub fn resize<T>(&mut self, index: usize, coordinate: T) -> bool
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let mut to_marker = MarkerType::default();
+        to_marker
+            .set_col(col.saturating_sub(1))
+            .set_row(row.saturating_sub(1));
+        let slot = match (self.get_slot(index), self.get_from_marker(index)) {
+            (Some(slot), Some(from_marker))
+                if from_marker.get_col() <= to_marker.get_col()
+                    && from_marker.get_row() <= to_marker.get_row() =>
+            {
+                slot
+            }
+            _ => return false,
+        };
+        let drawing = &mut *self.worksheet_drawing;
+        match slot {
+            Slot::Image(i) => {
+                let image = &mut drawing.get_image_collection_mut()[i];
+                if let Some(anchor) = image.get_one_cell_anchor().cloned() {
+                    image.remove_one_cell_anchor();
+                    image.set_two_cell_anchor(to_two_cell_anchor(anchor));
+                }
+                if let Some(anchor) = image.get_two_cell_anchor_mut() {
+                    anchor.set_to_marker(to_marker);
+                }
+            }
+            Slot::Chart(i) => {
+                drawing.get_chart_collection_mut()[i]
+                    .get_two_cell_anchor_mut()
+                    .set_to_marker(to_marker);
+            }
+            Slot::TextBox(i) => {
+                drawing.get_text_box_collection_mut()[i]
+                    .get_two_cell_anchor_mut()
+                    .set_to_marker(to_marker);
+            }
+            Slot::TwoCellAnchor(i) => {
+                drawing.get_two_cell_anchor_collection_mut()[i].set_to_marker(to_marker);
+            }
+            Slot::OneCellAnchor(i) => {
+                let anchor = drawing.get_one_cell_anchor_collection_mut().remove(i);
+                let mut anchor = to_two_cell_anchor(anchor);
+                anchor.set_to_marker(to_marker);
+                drawing.get_two_cell_anchor_collection_mut().push(anchor);
+            }
+        }
+        true
+    }
+
+    /// Remove the object.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    /// # Return value
+    /// * `bool` - false if there is no object at the index.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let slot = match self.get_slot(index) {
+            Some(v) => v,
+            None => return false,
+        };
+        let drawing = &mut *self.worksheet_drawing;
+        match slot {
+            Slot::Image(i) => {
+                drawing.get_image_collection_mut().remove(i);
+            }
+            Slot::Chart(i) => {
+                drawing.get_chart_collection_mut().remove(i);
+            }
+            Slot::TextBox(i) => {
+                drawing.get_text_box_collection_mut().remove(i);
+            }
+            Slot::TwoCellAnchor(i) => {
+                drawing.get_two_cell_anchor_collection_mut().remove(i);
+            }
+            Slot::OneCellAnchor(i) => {
+                drawing.get_one_cell_anchor_collection_mut().remove(i);
+            }
+        }
+        true
+    }
+
+    /// Copy the object to another cell.
+    /// The copy is drawn on top of all others, and copied shapes are given new ids.
+    /// # Arguments
+    /// * `index` - Index of the object.
+    /// * `coordinate` - Top left cell of the copy. ex) `"D5"`
+    /// # Return value
+    /// * `Option<usize>` - Index of the copy.
+    pub fn duplicate<T>(&mut self, index: usize, coordinate: T) -> Option<usize>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let slot = self.get_slot(index)?;
+        let mut next_id = self.worksheet_drawing.get_next_shape_id();
+        let drawing = &mut *self.worksheet_drawing;
+        let new_slot = match slot {
+            Slot::Image(i) => {
+                let mut value = drawing.get_image_collection()[i].clone();
+                if let Some(anchor) = value.get_two_cell_anchor_mut() {
+                    set_new_two_cell_anchor_id(anchor, &mut next_id);
+                    anchor.set_z_order(0);
+                }
+                if let Some(anchor) = value.get_one_cell_anchor_mut() {
+                    set_new_one_cell_anchor_id(anchor, &mut next_id);
+                    anchor.set_z_order(0);
+                }
+                drawing.add_image(value);
+                Slot::Image(drawing.get_image_collection().len() - 1)
+            }
+            Slot::Chart(i) => {
+                let mut value = drawing.get_chart_collection()[i].clone();
+                set_new_two_cell_anchor_id(value.get_two_cell_anchor_mut(), &mut next_id);
+                value.get_two_cell_anchor_mut().set_z_order(0);
+                drawing.add_chart_collection(value);
+                Slot::Chart(drawing.get_chart_collection().len() - 1)
+            }
+            Slot::TextBox(i) => {
+                let mut value = drawing.get_text_box_collection()[i].clone();
+                set_new_two_cell_anchor_id(value.get_two_cell_anchor_mut(), &mut next_id);
+                value.get_two_cell_anchor_mut().set_z_order(0);
+                drawing.add_text_box(value);
+                Slot::TextBox(drawing.get_text_box_collection().len() - 1)
+            }
+            Slot::TwoCellAnchor(i) => {
+                let mut value = drawing.get_two_cell_anchor_collection()[i].clone();
+                set_new_two_cell_anchor_id(&mut value, &mut next_id);
+                value.set_z_order(0);
+                drawing.add_two_cell_anchor_collection(value);
+                Slot::TwoCellAnchor(drawing.get_two_cell_anchor_collection().len() - 1)
+            }
+            Slot::OneCellAnchor(i) => {
+                let mut value = drawing.get_one_cell_anchor_collection()[i].clone();
+                set_new_one_cell_anchor_id(&mut value, &mut next_id);
+                value.set_z_order(0);
+                drawing.add_one_cell_anchor_collection(value);
+                Slot::OneCellAnchor(drawing.get_one_cell_anchor_collection().len() - 1)
+            }
+        };
+        let new_index = self.get_slot_list().iter().position(|v| v == &new_slot)?;
+        self.move_to(new_index, coordinate);
+        Some(new_index)
+    }
+
+    /// Put the shapes, connectors, pictures and groups anchored between two cells into a new group.
+    /// The members keep their place as long as the columns and rows between them have the default size.
+    /// # Arguments
+    /// * `index_list` - Index of the objects.
+    /// # Return value
+    /// * `Option<usize>` - Index of the group. None if fewer than two objects are given or one of them can not be grouped.
+    pub fn group(&mut self, index_list: &[usize]) -> Option<usize> {
+        let mut slot_list: Vec<Slot> = Vec::new();
+        for index in index_list {
+            let slot = self.get_slot(*index)?;
+            let is_groupable = match slot {
+                Slot::Image(i) => self.worksheet_drawing.get_image_collection()[i]
+                    .get_two_cell_anchor()
+                    .is_some(),
+                Slot::TwoCellAnchor(i) => self.worksheet_drawing.get_two_cell_anchor_collection()
+                    [i]
+                    .get_graphic_frame()
+                    .is_none(),
+                _ => false,
+            };
+            if !is_groupable {
+                return None;
+            }
+            if !slot_list.contains(&slot) {
+                slot_list.push(slot);
+            }
+        }
+        if slot_list.len() < 2 {
+            return None;
+        }
+        let drawing = &mut *self.worksheet_drawing;
+        let mut next_id = drawing.get_next_shape_id();
+        // removed from the back, so that the other indexes stay valid.
+        slot_list.sort_by_key(|v| match v {
+            Slot::Image(i) | Slot::TwoCellAnchor(i) => std::cmp::Reverse(*i),
+            _ => std::cmp::Reverse(0),
+        });
+        let mut anchor_list: Vec<TwoCellAnchor> = Vec::new();
+        for slot in slot_list {
+            match slot {
+                Slot::Image(i) => {
+                    let image = drawing.get_image_collection_mut().remove(i);
+                    anchor_list.push(image.get_two_cell_anchor().unwrap().clone());
+                }
+                Slot::TwoCellAnchor(i) => {
+                    anchor_list.push(drawing.get_two_cell_anchor_collection_mut().remove(i));
+                }
+                _ => {}
+            }
+        }
+        anchor_list.sort_by_key(|v| *v.get_z_order());
+
+        // the group covers all the members.
+        let mut from_marker = anchor_list[0].get_from_marker().clone();
+        let mut to_marker = anchor_list[0].get_to_marker().clone();
+        for anchor in &anchor_list {
+            let from = anchor.get_from_marker();
+            let to = anchor.get_to_marker();
+            if (from.get_col(), from.get_col_off())
+                < (from_marker.get_col(), from_marker.get_col_off())
+            {
+                from_marker
+                    .set_col(*from.get_col())
+                    .set_col_off(*from.get_col_off());
+            }
+            if (from.get_row(), from.get_row_off())
+                < (from_marker.get_row(), from_marker.get_row_off())
+            {
+                from_marker
+                    .set_row(*from.get_row())
+                    .set_row_off(*from.get_row_off());
+            }
+            if (to.get_col(), to.get_col_off()) > (to_marker.get_col(), to_marker.get_col_off()) {
+                to_marker
+                    .set_col(*to.get_col())
+                    .set_col_off(*to.get_col_off());
+            }
+            if (to.get_row(), to.get_row_off()) > (to_marker.get_row(), to_marker.get_row_off()) {
+                to_marker
+                    .set_row(*to.get_row())
+                    .set_row_off(*to.get_row_off());
+            }
+        }
+        let (left, top) = marker_to_emu(&from_marker);
+        let (right, bottom) = marker_to_emu(&to_marker);
+
+        let mut group = GroupShape::default();
+        group.new_group();
+        group
+            .get_non_visual_group_shape_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(next_id)
+            .set_name(format!("Group {}", next_id));
+        next_id += 1;
+        let mut transform = make_transform(left, top, right - left, bottom - top);
+        transform.set_child_offset(transform.get_offset().clone());
+        transform.set_child_extents(transform.get_extents().clone());
+        group
+            .get_group_shape_properties_mut()
+            .set_transform2d(transform);
+
+        for anchor in &anchor_list {
+            let (x, y) = marker_to_emu(anchor.get_from_marker());
+            let (x2, y2) = marker_to_emu(anchor.get_to_marker());
+            let transform = make_transform(x, y, x2 - x, y2 - y);
+            if let Some(v) = anchor.get_shape() {
+                let mut value = v.clone();
+                value.get_shape_properties_mut().set_transform2d(transform);
+                group.add_shape_collection(value);
+            } else if let Some(v) = anchor.get_connection_shape() {
+                let mut value = v.clone();
+                value.get_shape_properties_mut().set_transform2d(transform);
+                group.add_connection_shape_collection(value);
+            } else if let Some(v) = anchor.get_picture() {
+                let mut value = v.clone();
+                value.get_shape_properties_mut().set_transform2d(transform);
+                group.add_picture_collection(value);
+            } else if let Some(v) = anchor.get_group_shape() {
+                let mut value = v.clone();
+                set_group_position(&mut value, &transform);
+                group.add_group_shape_collection(value);
+            }
+        }
+        group.set_missing_id(&mut next_id);
+
+        let mut anchor = TwoCellAnchor::default();
+        anchor.set_from_marker(from_marker);
+        anchor.set_to_marker(to_marker);
+        anchor.set_group_shape(group);
+        drawing.add_two_cell_anchor_collection(anchor);
+        let new_slot = Slot::TwoCellAnchor(drawing.get_two_cell_anchor_collection().len() - 1);
+        self.get_slot_list().iter().position(|v| v == &new_slot)
+    }
+
+    /// Split the group into its members, each anchored between two cells.
+    /// # Arguments
+    /// * `index` - Index of the group.
+    /// # Return value
+    /// * `Vec<usize>` - Index of the members. Empty if the object is not a group.
+    pub fn ungroup(&mut self, index: usize) -> Vec<usize> {
+        let slot = self.get_slot(index);
+        let drawing = &mut *self.worksheet_drawing;
+        let anchor = match slot {
+            Some(Slot::Image(i))
+                if drawing.get_image_collection()[i]
+                    .get_two_cell_anchor()
+                    .map_or(false, |v| v.get_group_shape().is_some()) =>
+            {
+                let image = drawing.get_image_collection_mut().remove(i);
+                image.get_two_cell_anchor().unwrap().clone()
+            }
+            Some(Slot::TwoCellAnchor(i))
+                if drawing.get_two_cell_anchor_collection()[i]
+                    .get_group_shape()
+                    .is_some() =>
+            {
+                drawing.get_two_cell_anchor_collection_mut().remove(i)
+            }
+            _ => return Vec::new(),
+        };
+        let group = anchor.get_group_shape().unwrap();
+        let base = anchor.get_from_marker();
+
+        // maps a transform of the group's child space onto the sheet.
+        let group_transform = group
+            .get_group_shape_properties()
+            .get_transform2d()
+            .cloned()
+            .unwrap_or_default();
+        let (left, top) = marker_to_emu(base);
+        let child_offset = group_transform
+            .get_child_offset()
+            .cloned()
+            .unwrap_or_default();
+        let child_extents = group_transform
+            .get_child_extents()
+            .cloned()
+            .unwrap_or_else(|| group_transform.get_extents().clone());
+        let scale = |value: i64, extent: &i64, child_extent: &i64| match child_extent {
+            0 => value,
+            _ => value * extent / child_extent,
+        };
+        let place = |transform: Option<&Transform2D>| -> (MarkerType, MarkerType, Transform2D) {
+            let transform = transform.cloned().unwrap_or_default();
+            let x = scale(
+                transform.get_offset().get_x() - child_offset.get_x(),
+                group_transform.get_extents().get_cx(),
+                child_extents.get_cx(),
+            );
+            let y = scale(
+                transform.get_offset().get_y() - child_offset.get_y(),
+                group_transform.get_extents().get_cy(),
+                child_extents.get_cy(),
+            );
+            let width = scale(
+                *transform.get_extents().get_cx(),
+                group_transform.get_extents().get_cx(),
+                child_extents.get_cx(),
+            );
+            let height = scale(
+                *transform.get_extents().get_cy(),
+                group_transform.get_extents().get_cy(),
+                child_extents.get_cy(),
+            );
+            (
+                offset_marker(base, x, y),
+                offset_marker(base, x + width, y + height),
+                make_transform(left + x, top + y, width, height),
+            )
+        };
+
+        let mut anchor_list: Vec<TwoCellAnchor> = Vec::new();
+        let mut new_anchor = |from_marker: MarkerType, to_marker: MarkerType| {
+            let mut value = TwoCellAnchor::default();
+            value.set_from_marker(from_marker);
+            value.set_to_marker(to_marker);
+            value
+        };
+        for obj in group.get_shape_collection() {
+            let (from, to, transform) = place(obj.get_shape_properties().get_transform2d());
+            let mut value = obj.clone();
+            value.get_shape_properties_mut().set_transform2d(transform);
+            let mut anchor = new_anchor(from, to);
+            anchor.set_shape(value);
+            anchor_list.push(anchor);
+        }
+        for obj in group.get_connection_shape_collection() {
+            let (from, to, transform) = place(obj.get_shape_properties().get_transform2d());
+            let mut value = obj.clone();
+            value.get_shape_properties_mut().set_transform2d(transform);
+            let mut anchor = new_anchor(from, to);
+            anchor.set_connection_shape(value);
+            anchor_list.push(anchor);
+        }
+        for obj in group.get_picture_collection() {
+            let (from, to, transform) = place(obj.get_shape_properties().get_transform2d());
+            let mut value = obj.clone();
+            value.get_shape_properties_mut().set_transform2d(transform);
+            let mut anchor = new_anchor(from, to);
+            anchor.set_picture(value);
+            anchor_list.push(anchor);
+        }
+        for obj in group.get_group_shape_collection() {
+            let (from, to, transform) = place(obj.get_group_shape_properties().get_transform2d());
+            let mut value = obj.clone();
+            set_group_position(&mut value, &transform);
+            let mut anchor = new_anchor(from, to);
+            anchor.set_group_shape(value);
+            anchor_list.push(anchor);
+        }
+
+        let count = anchor_list.len();
+        for anchor in anchor_list {
+            drawing.add_two_cell_anchor_collection(anchor);
+        }
+        let anchor_count = drawing.get_two_cell_anchor_collection().len();
+        let slot_list = self.get_slot_list();
+        (anchor_count - count..anchor_count)
+            .filter_map(|i| slot_list.iter().position(|v| v == &Slot::TwoCellAnchor(i)))
+            .collect()
+    }
+
+    fn get_slot_list(&self) -> Vec<Slot> {
+        let drawing = &*self.worksheet_drawing;
+        let mut result: Vec<Slot> = Vec::new();
+        result.extend((0..drawing.get_image_collection().len()).map(Slot::Image));
+        result.extend((0..drawing.get_chart_collection().len()).map(Slot::Chart));
+        result.extend((0..drawing.get_text_box_collection().len()).map(Slot::TextBox));
+        result.extend((0..drawing.get_two_cell_anchor_collection().len()).map(Slot::TwoCellAnchor));
+        result.extend((0..drawing.get_one_cell_anchor_collection().len()).map(Slot::OneCellAnchor));
+        result
+    }
+
+    fn get_slot(&self, index: usize) -> Option<Slot> {
+        self.get_slot_list().get(index).copied()
+    }
+
+    fn get_from_marker(&self, index: usize) -> Option<&MarkerType> {
+        let drawing = &*self.worksheet_drawing;
+        match self.get_slot(index)? {
+            Slot::Image(i) => {
+                let image = &drawing.get_image_collection()[i];
+                match image.get_two_cell_anchor() {
+                    Some(anchor) => Some(anchor.get_from_marker()),
+                    None => image.get_one_cell_anchor().map(|v| v.get_from_marker()),
+                }
+            }
+            Slot::Chart(i) => Some(
+                drawing.get_chart_collection()[i]
+                    .get_two_cell_anchor()
+                    .get_from_marker(),
+            ),
+            Slot::TextBox(i) => Some(
+                drawing.get_text_box_collection()[i]
+                    .get_two_cell_anchor()
+                    .get_from_marker(),
+            ),
+            Slot::TwoCellAnchor(i) => {
+                Some(drawing.get_two_cell_anchor_collection()[i].get_from_marker())
+            }
+            Slot::OneCellAnchor(i) => {
+                Some(drawing.get_one_cell_anchor_collection()[i].get_from_marker())
+            }
+        }
+    }
+
+    fn get_to_marker(&self, index: usize) -> Option<&MarkerType> {
+        let drawing = &*self.worksheet_drawing;
+        match self.get_slot(index)? {
+            Slot::Image(i) => drawing.get_image_collection()[i].get_to_marker_type(),
+            Slot::Chart(i) => Some(
+                drawing.get_chart_collection()[i]
+                    .get_two_cell_anchor()
+                    .get_to_marker(),
+            ),
+            Slot::TextBox(i) => Some(
+                drawing.get_text_box_collection()[i]
+                    .get_two_cell_anchor()
+                    .get_to_marker(),
+            ),
+            Slot::TwoCellAnchor(i) => {
+                Some(drawing.get_two_cell_anchor_collection()[i].get_to_marker())
+            }
+            Slot::OneCellAnchor(_) => None,
+        }
+    }
+}
+
+fn get_two_cell_anchor_kind(anchor: &TwoCellAnchor) -> DrawingKind {
+    if anchor.get_graphic_frame().is_some() {
+        return DrawingKind::Chart;
+    }
+    if anchor.get_picture().is_some() {
+        return DrawingKind::Image;
+    }
+    if anchor.get_group_shape().is_some() {
+        return DrawingKind::GroupShape;
+    }
+    if anchor.get_connection_shape().is_some() {
+        return DrawingKind::ConnectionShape;
+    }
+    if anchor.is_text_box() {
+        return DrawingKind::TextBox;
+    }
+    DrawingKind::Shape
+}
+
+fn get_one_cell_anchor_kind(anchor: &OneCellAnchor) -> DrawingKind {
+    if anchor.get_picture().is_some() {
+        return DrawingKind::Image;
+    }
+    if anchor.get_group_shape().is_some() {
+        return DrawingKind::GroupShape;
+    }
+    DrawingKind::Shape
+}
+
+fn move_marker(marker: &mut MarkerType, col: u32, row: u32) {
+    marker.set_col(col).set_row(row);
+}
+
+fn move_two_cell_anchor(anchor: &mut TwoCellAnchor, col: u32, row: u32) {
+    let col_span = anchor
+        .get_to_marker()
+        .get_col()
+        .saturating_sub(*anchor.get_from_marker().get_col());
+    let row_span = anchor
+        .get_to_marker()
+        .get_row()
+        .saturating_sub(*anchor.get_from_marker().get_row());
+    move_marker(anchor.get_from_marker_mut(), col, row);
+    move_marker(anchor.get_to_marker_mut(), col + col_span, row + row_span);
+}
+
+fn marker_to_emu(marker: &MarkerType) -> (i64, i64) {
+    (
+        *marker.get_col() as i64 * CELL_WIDTH_EMU + *marker.get_col_off() as i64,
+        *marker.get_row() as i64 * CELL_HEIGHT_EMU + *marker.get_row_off() as i64,
+    )
+}
+
+// Move the marker by the distance (EMU), counting cells of the default size.
+fn offset_marker(marker: &MarkerType, x: i64, y: i64) -> MarkerType {
+    let mut result = marker.clone();
+    let (left, top) = marker_to_emu(marker);
+    if x != 0 {
+        let left = (left + x).max(0);
+        result
+            .set_col((left / CELL_WIDTH_EMU) as u32)
+            .set_col_off((left % CELL_WIDTH_EMU) as usize);
+    }
+    if y != 0 {
+        let top = (top + y).max(0);
+        result
+            .set_row((top / CELL_HEIGHT_EMU) as u32)
+            .set_row_off((top % CELL_HEIGHT_EMU) as usize);
+    }
+    result
+}
+
+fn make_transform(x: i64, y: i64, width: i64, height: i64) -> Transform2D {
+    let mut transform = Transform2D::default();
+    transform.get_offset_mut().set_x(x);
+    transform.get_offset_mut().set_y(y);
+    transform.get_extents_mut().set_cx(width.max(0));
+    transform.get_extents_mut().set_cy(height.max(0));
+    transform
+}
+
+// Place a group, keeping the space of its members.
+fn set_group_position(group: &mut GroupShape, transform: &Transform2D) {
+    let properties = group.get_group_shape_properties_mut();
+    match properties.get_transform2d_mut() {
+        Some(v) => {
+            v.set_offset(transform.get_offset().clone());
+            v.set_extents(transform.get_extents().clone());
+        }
+        None => {
+            let mut value = transform.clone();
+            value.set_child_offset(transform.get_offset().clone());
+            value.set_child_extents(transform.get_extents().clone());
+            properties.set_transform2d(value);
+        }
+    }
+}
+
+fn to_two_cell_anchor(anchor: OneCellAnchor) -> TwoCellAnchor {
+    let mut result = TwoCellAnchor::default();
+    result.set_from_marker(anchor.get_from_marker().clone());
+    result.set_z_order(*anchor.get_z_order());
+    if let Some(v) = anchor.get_group_shape() {
+        result.set_group_shape(v.clone());
+    }
+    if let Some(v) = anchor.get_shape() {
+        result.set_shape(v.clone());
+    }
+    if let Some(v) = anchor.get_picture() {
+        result.set_picture(v.clone());
+    }
+    result
+}
+
+fn set_new_two_cell_anchor_id(anchor: &mut TwoCellAnchor, next_id: &mut u32) {
+    if let Some(v) = anchor.get_picture_mut() {
+        v.get_non_visual_picture_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(*next_id);
+        *next_id += 1;
+    }
+    if let Some(v) = anchor.get_graphic_frame_mut() {
+        v.get_non_visual_graphic_frame_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(*next_id);
+        *next_id += 1;
+    }
+    if let Some(v) = anchor.get_shape_mut() {
+        v.get_non_visual_shape_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(*next_id);
+        *next_id += 1;
+    }
+    if let Some(v) = anchor.get_connection_shape_mut() {
+        v.get_non_visual_connection_shape_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(*next_id);
+        *next_id += 1;
+    }
+    if let Some(v) = anchor.get_group_shape_mut() {
+        v.clear_id();
+        v.set_missing_id(next_id);
+    }
+}
+
+fn set_new_one_cell_anchor_id(anchor: &mut OneCellAnchor, next_id: &mut u32) {
+    if let Some(v) = anchor.get_picture_mut() {
+        v.get_non_visual_picture_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(*next_id);
+        *next_id += 1;
+    }
+    if let Some(v) = anchor.get_shape_mut() {
+        v.get_non_visual_shape_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(*next_id);
+        *next_id += 1;
+    }
+    if let Some(v) = anchor.get_group_shape_mut() {
+        v.clear_id();
+        v.set_missing_id(next_id);
+    }
+}
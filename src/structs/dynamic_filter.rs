@@ -0,0 +1,101 @@
+// dynamicFilter
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::DoubleValue;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct DynamicFilter {
+    filter_type: StringValue,
+    value: DoubleValue,
+    value_iso: StringValue,
+    max_value: DoubleValue,
+    max_value_iso: StringValue,
+}
+
+impl DynamicFilter {
+    /// Get Filter Type.
+    /// ex) "aboveAverage", "today", "thisMonth", "Q1"
+    pub fn get_filter_type(&self) -> &str {
+        self.filter_type.get_value_str()
+    }
+
+    pub fn set_filter_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.filter_type.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &f64 {
+        self.value.get_value()
+    }
+
+    pub fn set_value(&mut self, value: f64) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub fn get_value_iso(&self) -> &str {
+        self.value_iso.get_value_str()
+    }
+
+    pub fn set_value_iso<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value_iso.set_value(value);
+        self
+    }
+
+    pub fn get_max_value(&self) -> &f64 {
+        self.max_value.get_value()
+    }
+
+    pub fn set_max_value(&mut self, value: f64) -> &mut Self {
+        self.max_value.set_value(value);
+        self
+    }
+
+    pub fn get_max_value_iso(&self) -> &str {
+        self.max_value_iso.get_value_str()
+    }
+
+    pub fn set_max_value_iso<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.max_value_iso.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, filter_type, "type");
+        set_string_from_xml!(self, e, value, "val");
+        set_string_from_xml!(self, e, value_iso, "valIso");
+        set_string_from_xml!(self, e, max_value, "maxVal");
+        set_string_from_xml!(self, e, max_value_iso, "maxValIso");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dynamicFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("type", self.filter_type.get_value_str()));
+        let value = self.value.get_value_string();
+        if self.value.has_value() {
+            attributes.push(("val", &value));
+        }
+        if self.value_iso.has_value() {
+            attributes.push(("valIso", self.value_iso.get_value_str()));
+        }
+        let max_value = self.max_value.get_value_string();
+        if self.max_value.has_value() {
+            attributes.push(("maxVal", &max_value));
+        }
+        if self.max_value_iso.has_value() {
+            attributes.push(("maxValIso", self.max_value_iso.get_value_str()));
+        }
+        write_start_tag(writer, "dynamicFilter", attributes, true);
+    }
+}
@@ -88,9 +88,9 @@ impl EmbeddedObjectProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
-        let r_id = get_attribute(e, b"r:id").unwrap();
-        let attached_file = relationships.get_relationship_by_rid(&r_id).get_raw_file();
+    ) -> Result<(), XlsxError> {
+        let r_id = get_required_attribute(e, b"r:id")?;
+        let attached_file = relationships.get_relationship_by_rid(&r_id)?.get_raw_file();
 
         self.get_image_mut()
             .set_image_name(attached_file.get_file_name());
@@ -104,15 +104,15 @@ impl EmbeddedObjectProperties {
             reader,
                 Event::Start(ref e) => {
                     if e.name().into_inner() == b"anchor" {
-                        self.object_anchor.set_attributes(reader, e);
+                        self.object_anchor.set_attributes(reader, e)?;
                     }
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"objectPr" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "objectPr")
+                Event::Eof => return Err(eof_error("objectPr"))
         );
     }
 
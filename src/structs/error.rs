@@ -67,6 +67,12 @@ pub enum XlsxError {
     Uft8(std::string::FromUtf8Error),
     /// Cell error
     CellError(String),
+    /// Malformed document
+    Format(String),
+    /// Invalid cell reference or range
+    Coordinate(String),
+    /// A limit of `ReadLimits` is exceeded
+    LimitExceeded(String),
 }
 
 from_err!(std::io::Error, XlsxError, Io);
@@ -83,6 +89,9 @@ impl fmt::Display for XlsxError {
             Zip(s) => write!(f, "ZipError: {}", s),
             Uft8(s) => write!(f, "Uft8Error: {}", s),
             CellError(e) => write!(f, "Unsupported cell error value '{e}'"),
+            Format(e) => write!(f, "FormatError: {e}"),
+            Coordinate(e) => write!(f, "Invalid coordinate '{e}'"),
+            LimitExceeded(e) => write!(f, "LimitExceeded: {e}"),
         }
     }
 }
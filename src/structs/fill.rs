@@ -97,13 +97,13 @@ impl Fill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"patternFill" {
                     let mut obj = PatternFill::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_pattern_fill(obj);
                 }
             },
@@ -111,12 +111,12 @@ impl Fill {
                 match e.name().into_inner() {
                     b"patternFill" => {
                         let mut obj = PatternFill::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_pattern_fill(obj);
                     }
                     b"gradientFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     _ => (),
@@ -124,10 +124,10 @@ impl Fill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fill")
+            Event::Eof => return Err(eof_error("fill"))
         );
     }
 
@@ -49,22 +49,22 @@ impl Fills {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"fill" {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fills" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fills")
+            Event::Eof => return Err(eof_error("fills"))
         );
     }
 
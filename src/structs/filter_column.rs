@@ -0,0 +1,249 @@
+// filterColumn
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::ColorFilter;
+use structs::CustomFilters;
+use structs::DynamicFilter;
+use structs::Filters;
+use structs::IconFilter;
+use structs::Top10;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct FilterColumn {
+    column_id: UInt32Value,
+    hidden_button: BooleanValue,
+    show_button: BooleanValue,
+    filters: Option<Filters>,
+    custom_filters: Option<CustomFilters>,
+    top10: Option<Top10>,
+    dynamic_filter: Option<DynamicFilter>,
+    color_filter: Option<ColorFilter>,
+    icon_filter: Option<IconFilter>,
+}
+
+impl FilterColumn {
+    /// Get Column Id.
+    /// The index of the column in the filter range, starting at 0.
+    pub fn get_column_id(&self) -> &u32 {
+        self.column_id.get_value()
+    }
+
+    pub fn set_column_id(&mut self, value: u32) -> &mut Self {
+        self.column_id.set_value(value);
+        self
+    }
+
+    pub fn get_hidden_button(&self) -> &bool {
+        self.hidden_button.get_value()
+    }
+
+    pub fn set_hidden_button(&mut self, value: bool) -> &mut Self {
+        self.hidden_button.set_value(value);
+        self
+    }
+
+    pub fn get_show_button(&self) -> &bool {
+        self.show_button.get_value()
+    }
+
+    pub fn set_show_button(&mut self, value: bool) -> &mut Self {
+        self.show_button.set_value(value);
+        self
+    }
+
+    pub fn get_filters(&self) -> Option<&Filters> {
+        self.filters.as_ref()
+    }
+
+    pub fn get_filters_mut(&mut self) -> Option<&mut Filters> {
+        self.filters.as_mut()
+    }
+
+    pub fn set_filters(&mut self, value: Filters) -> &mut Self {
+        self.filters = Some(value);
+        self
+    }
+
+    pub fn get_custom_filters(&self) -> Option<&CustomFilters> {
+        self.custom_filters.as_ref()
+    }
+
+    pub fn get_custom_filters_mut(&mut self) -> Option<&mut CustomFilters> {
+        self.custom_filters.as_mut()
+    }
+
+    pub fn set_custom_filters(&mut self, value: CustomFilters) -> &mut Self {
+        self.custom_filters = Some(value);
+        self
+    }
+
+    pub fn get_top10(&self) -> Option<&Top10> {
+        self.top10.as_ref()
+    }
+
+    pub fn get_top10_mut(&mut self) -> Option<&mut Top10> {
+        self.top10.as_mut()
+    }
+
+    pub fn set_top10(&mut self, value: Top10) -> &mut Self {
+        self.top10 = Some(value);
+        self
+    }
+
+    pub fn get_dynamic_filter(&self) -> Option<&DynamicFilter> {
+        self.dynamic_filter.as_ref()
+    }
+
+    pub fn get_dynamic_filter_mut(&mut self) -> Option<&mut DynamicFilter> {
+        self.dynamic_filter.as_mut()
+    }
+
+    pub fn set_dynamic_filter(&mut self, value: DynamicFilter) -> &mut Self {
+        self.dynamic_filter = Some(value);
+        self
+    }
+
+    pub fn get_color_filter(&self) -> Option<&ColorFilter> {
+        self.color_filter.as_ref()
+    }
+
+    pub fn get_color_filter_mut(&mut self) -> Option<&mut ColorFilter> {
+        self.color_filter.as_mut()
+    }
+
+    pub fn set_color_filter(&mut self, value: ColorFilter) -> &mut Self {
+        self.color_filter = Some(value);
+        self
+    }
+
+    pub fn get_icon_filter(&self) -> Option<&IconFilter> {
+        self.icon_filter.as_ref()
+    }
+
+    pub fn get_icon_filter_mut(&mut self) -> Option<&mut IconFilter> {
+        self.icon_filter.as_mut()
+    }
+
+    pub fn set_icon_filter(&mut self, value: IconFilter) -> &mut Self {
+        self.icon_filter = Some(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, column_id, "colId");
+        set_string_from_xml!(self, e, hidden_button, "hiddenButton");
+        set_string_from_xml!(self, e, show_button, "showButton");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"filters" => {
+                    let mut obj = Filters::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.set_filters(obj);
+                }
+                b"top10" => {
+                    let mut obj = Top10::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_top10(obj);
+                }
+                b"dynamicFilter" => {
+                    let mut obj = DynamicFilter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_dynamic_filter(obj);
+                }
+                b"colorFilter" => {
+                    let mut obj = ColorFilter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_color_filter(obj);
+                }
+                b"iconFilter" => {
+                    let mut obj = IconFilter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.set_icon_filter(obj);
+                }
+                _ => (),
+            },
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"filters" => {
+                    let mut obj = Filters::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_filters(obj);
+                }
+                b"customFilters" => {
+                    let mut obj = CustomFilters::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.set_custom_filters(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("filterColumn"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // filterColumn
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let column_id = self.column_id.get_value_string();
+        attributes.push(("colId", &column_id));
+        if self.hidden_button.has_value() {
+            attributes.push(("hiddenButton", self.hidden_button.get_value_string()));
+        }
+        if self.show_button.has_value() {
+            attributes.push(("showButton", self.show_button.get_value_string()));
+        }
+        write_start_tag(writer, "filterColumn", attributes, false);
+
+        // filters
+        if let Some(v) = &self.filters {
+            v.write_to(writer);
+        }
+
+        // customFilters
+        if let Some(v) = &self.custom_filters {
+            v.write_to(writer);
+        }
+
+        // top10
+        if let Some(v) = &self.top10 {
+            v.write_to(writer);
+        }
+
+        // dynamicFilter
+        if let Some(v) = &self.dynamic_filter {
+            v.write_to(writer);
+        }
+
+        // colorFilter
+        if let Some(v) = &self.color_filter {
+            v.write_to(writer);
+        }
+
+        // iconFilter
+        if let Some(v) = &self.icon_filter {
+            v.write_to(writer);
+        }
+
+        write_end_tag(writer, "filterColumn");
+    }
+}
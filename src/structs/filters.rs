@@ -0,0 +1,130 @@
+// filters
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::DateGroupItem;
+use structs::Filter;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Filters {
+    blank: BooleanValue,
+    calendar_type: StringValue,
+    filter: Vec<Filter>,
+    date_group_item: Vec<DateGroupItem>,
+}
+
+impl Filters {
+    pub fn get_blank(&self) -> &bool {
+        self.blank.get_value()
+    }
+
+    pub fn set_blank(&mut self, value: bool) -> &mut Self {
+        self.blank.set_value(value);
+        self
+    }
+
+    pub fn get_calendar_type(&self) -> &str {
+        self.calendar_type.get_value_str()
+    }
+
+    pub fn set_calendar_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.calendar_type.set_value(value);
+        self
+    }
+
+    pub fn get_filter_list(&self) -> &Vec<Filter> {
+        &self.filter
+    }
+
+    pub fn get_filter_list_mut(&mut self) -> &mut Vec<Filter> {
+        &mut self.filter
+    }
+
+    pub fn add_filter(&mut self, value: Filter) -> &mut Self {
+        self.filter.push(value);
+        self
+    }
+
+    pub fn get_date_group_item_list(&self) -> &Vec<DateGroupItem> {
+        &self.date_group_item
+    }
+
+    pub fn get_date_group_item_list_mut(&mut self) -> &mut Vec<DateGroupItem> {
+        &mut self.date_group_item
+    }
+
+    pub fn add_date_group_item(&mut self, value: DateGroupItem) -> &mut Self {
+        self.date_group_item.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, blank, "blank");
+        set_string_from_xml!(self, e, calendar_type, "calendarType");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"filter" => {
+                    let mut obj = Filter::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_filter(obj);
+                }
+                b"dateGroupItem" => {
+                    let mut obj = DateGroupItem::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_date_group_item(obj);
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filters" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("filters"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // filters
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.blank.has_value() {
+            attributes.push(("blank", self.blank.get_value_string()));
+        }
+        if self.calendar_type.has_value() {
+            attributes.push(("calendarType", self.calendar_type.get_value_str()));
+        }
+        let is_empty = self.filter.is_empty() && self.date_group_item.is_empty();
+        write_start_tag(writer, "filters", attributes, is_empty);
+        if is_empty {
+            return;
+        }
+
+        // filter
+        for filter in &self.filter {
+            filter.write_to(writer);
+        }
+
+        // dateGroupItem
+        for date_group_item in &self.date_group_item {
+            date_group_item.write_to(writer);
+        }
+
+        write_end_tag(writer, "filters");
+    }
+}
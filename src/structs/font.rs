@@ -16,6 +16,7 @@ use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use std::str::FromStr;
 use writer::driver::*;
@@ -199,9 +200,9 @@ impl Font {
     }
 
     pub fn set_underline<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        let obj = value.into();
-        self.font_underline
-            .set_val(UnderlineValues::from_str(&obj).unwrap());
+        if let Ok(v) = UnderlineValues::from_str(&value.into()) {
+            self.font_underline.set_val(v);
+        }
         self
     }
 
@@ -280,9 +281,9 @@ impl Font {
     }
 
     pub fn set_scheme<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        let obj = value.into();
-        self.font_scheme
-            .set_val(FontSchemeValues::from_str(&obj).unwrap());
+        if let Ok(v) = FontSchemeValues::from_str(&value.into()) {
+            self.font_scheme.set_val(v);
+        }
         self
     }
 
@@ -332,53 +333,53 @@ impl Font {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"name" => {
-                        self.font_name.set_attributes(reader, e);
+                        self.font_name.set_attributes(reader, e)?;
                     }
                     b"sz" => {
-                        self.font_size.set_attributes(reader, e);
+                        self.font_size.set_attributes(reader, e)?;
                     }
                     b"family" => {
-                        self.font_family_numbering.set_attributes(reader, e);
+                        self.font_family_numbering.set_attributes(reader, e)?;
                     }
                     b"b" => {
-                        self.font_bold.set_attributes(reader, e);
+                        self.font_bold.set_attributes(reader, e)?;
                     }
                     b"i" => {
-                        self.font_italic.set_attributes(reader, e);
+                        self.font_italic.set_attributes(reader, e)?;
                     }
                     b"u" => {
-                        self.font_underline.set_attributes(reader, e);
+                        self.font_underline.set_attributes(reader, e)?;
                     }
                     b"strike" => {
-                        self.font_strike.set_attributes(reader, e);
+                        self.font_strike.set_attributes(reader, e)?;
                     }
                     b"color" => {
-                        self.color.set_attributes(reader, e, true);
+                        self.color.set_attributes(reader, e, true)?;
                     }
                     b"charset" => {
-                        self.font_char_set.set_attributes(reader, e);
+                        self.font_char_set.set_attributes(reader, e)?;
                     }
                     b"scheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"vertAlign" => {
-                        self.vertical_text_alignment.set_attributes(reader, e);
+                        self.vertical_text_alignment.set_attributes(reader, e)?;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"font" => return,
-                    b"rPr" => return,
+                    b"font" => return Ok(()),
+                    b"rPr" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "font, rPr"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("font, rPr")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
@@ -49,7 +49,7 @@ impl Fonts {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -61,31 +61,28 @@ impl Fonts {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"font" {
                     let mut obj = Font::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_font(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fonts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fonts")
+            Event::Eof => return Err(eof_error("fonts"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, has_known_fonts: bool) {
         if !self.font.is_empty() {
             // fonts
-            write_start_tag(
-                writer,
-                "fonts",
-                vec![
-                    ("count", &self.font.len().to_string()),
-                    ("x14ac:knownFonts", "1"),
-                ],
-                false,
-            );
+            let count = self.font.len().to_string();
+            let mut attributes: Vec<(&str, &str)> = vec![("count", &count)];
+            if has_known_fonts {
+                attributes.push(("x14ac:knownFonts", "1"));
+            }
+            write_start_tag(writer, "fonts", attributes, false);
 
             // font
             for font in &self.font {
@@ -0,0 +1,580 @@
+use super::BooleanValue;
+use super::EnumTrait;
+use super::EnumValue;
+use super::FormControlValues;
+use super::StringValue;
+use helper::const_str::*;
+use quick_xml::events::BytesStart;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::drawing::spreadsheet::MarkerType;
+use structs::vml::spreadsheet::ObjectValues;
+use structs::vml::Shape;
+use traits::AdjustmentCoordinate;
+use writer::driver::*;
+
+/// A legacy form control (control + VML shape).
+/// ## Example
+/// ```rust
+/// extern crate umya_spreadsheet;
+/// let mut book = umya_spreadsheet::new_file();
+///
+/// // Add Button
+/// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("B2");
+/// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// to_marker.set_coordinate("D4");
+/// let mut button = umya_spreadsheet::structs::FormControl::default();
+/// button.new_button(from_marker, to_marker);
+/// button.set_text("Run");
+/// button.set_macro_name("Macro1");
+/// book.get_sheet_by_name_mut("Sheet1").unwrap().add_form_control(button);
+///
+/// // Add CheckBox linked to cell A1
+/// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("B6");
+/// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// to_marker.set_coordinate("D7");
+/// let mut check_box = umya_spreadsheet::structs::FormControl::default();
+/// check_box.new_check_box(from_marker, to_marker);
+/// check_box.set_text("Enabled");
+/// check_box.set_link_cell("$A$1");
+/// check_box.set_checked(true);
+/// book.get_sheet_by_name_mut("Sheet1").unwrap().add_form_control(check_box);
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct FormControl {
+    control_type: EnumValue<FormControlValues>,
+    name: StringValue,
+    text: StringValue,
+    macro_name: StringValue,
+    link_cell: StringValue,
+    checked: BooleanValue,
+    from_marker: MarkerType,
+    to_marker: MarkerType,
+}
+
+impl FormControl {
+    pub fn get_control_type(&self) -> &FormControlValues {
+        self.control_type.get_value()
+    }
+
+    pub fn set_control_type(&mut self, value: FormControlValues) -> &mut Self {
+        self.control_type.set_value(value);
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Get the caption.
+    pub fn get_text(&self) -> &str {
+        self.text.get_value_str()
+    }
+
+    /// Set the caption.
+    /// # Arguments
+    /// * `value` - Caption.
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text.set_value(value);
+        self
+    }
+
+    /// Get the name of the assigned macro.
+    pub fn get_macro_name(&self) -> &str {
+        self.macro_name.get_value_str()
+    }
+
+    /// Assign a macro. It runs when the control is clicked.
+    /// The workbook must be saved as xlsm with a VBA project that contains the macro.
+    /// # Arguments
+    /// * `value` - Macro name. ex) `"Macro1"`, `"Module1.Macro1"`
+    pub fn set_macro_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.macro_name.set_value(value);
+        self
+    }
+
+    pub fn remove_macro_name(&mut self) -> &mut Self {
+        self.macro_name.remove_value();
+        self
+    }
+
+    /// Get the cell that receives the state of the control.
+    pub fn get_link_cell(&self) -> &str {
+        self.link_cell.get_value_str()
+    }
+
+    /// Link the control to a cell.
+    /// A checkbox writes TRUE/FALSE, an option button writes the index of the selected button.
+    /// # Arguments
+    /// * `value` - Cell reference. ex) `"$A$1"`, `"Sheet2!$B$3"`
+    pub fn set_link_cell<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.link_cell.set_value(value);
+        self
+    }
+
+    pub fn remove_link_cell(&mut self) -> &mut Self {
+        self.link_cell.remove_value();
+        self
+    }
+
+    /// Get the checked state of a checkbox or option button.
+    pub fn get_checked(&self) -> &bool {
+        self.checked.get_value()
+    }
+
+    /// Set the checked state of a checkbox or option button.
+    /// # Arguments
+    /// * `value` - Checked state.
+    pub fn set_checked(&mut self, value: bool) -> &mut Self {
+        self.checked.set_value(value);
+        self
+    }
+
+    pub fn get_from_marker(&self) -> &MarkerType {
+        &self.from_marker
+    }
+
+    pub fn get_from_marker_mut(&mut self) -> &mut MarkerType {
+        &mut self.from_marker
+    }
+
+    pub fn set_from_marker(&mut self, value: MarkerType) -> &mut Self {
+        self.from_marker = value;
+        self
+    }
+
+    pub fn get_to_marker(&self) -> &MarkerType {
+        &self.to_marker
+    }
+
+    pub fn get_to_marker_mut(&mut self) -> &mut MarkerType {
+        &mut self.to_marker
+    }
+
+    pub fn set_to_marker(&mut self, value: MarkerType) -> &mut Self {
+        self.to_marker = value;
+        self
+    }
+
+    /// Create a new button between the two markers.
+    /// # Arguments
+    /// * `from_marker` - Top left of the button.
+    /// * `to_marker` - Bottom right of the button.
+    pub fn new_button(&mut self, from_marker: MarkerType, to_marker: MarkerType) -> &mut Self {
+        self.set_control_type(FormControlValues::Button);
+        self.set_name("Button 1");
+        self.set_text("Button 1");
+        self.set_from_marker(from_marker);
+        self.set_to_marker(to_marker);
+        self
+    }
+
+    /// Create a new checkbox between the two markers.
+    /// # Arguments
+    /// * `from_marker` - Top left of the checkbox.
+    /// * `to_marker` - Bottom right of the checkbox.
+    pub fn new_check_box(&mut self, from_marker: MarkerType, to_marker: MarkerType) -> &mut Self {
+        self.set_control_type(FormControlValues::CheckBox);
+        self.set_name("Check Box 1");
+        self.set_text("Check Box 1");
+        self.set_from_marker(from_marker);
+        self.set_to_marker(to_marker);
+        self
+    }
+
+    /// Create a new option button between the two markers.
+    /// Option buttons linked to the same cell work as a group.
+    /// # Arguments
+    /// * `from_marker` - Top left of the option button.
+    /// * `to_marker` - Bottom right of the option button.
+    pub fn new_option_button(
+        &mut self,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> &mut Self {
+        self.set_control_type(FormControlValues::OptionButton);
+        self.set_name("Option Button 1");
+        self.set_text("Option Button 1");
+        self.set_from_marker(from_marker);
+        self.set_to_marker(to_marker);
+        self
+    }
+
+    pub fn get_coordinate(&self) -> String {
+        self.from_marker.get_coordinate()
+    }
+
+    /// The macro reference as written to the file. ex) `[0]!Macro1`
+    fn get_macro_reference(&self) -> String {
+        let value = self.macro_name.get_value_str();
+        match value.contains('!') {
+            true => value.to_string(),
+            false => format!("[0]!{}", value),
+        }
+    }
+
+    pub(crate) fn set_vml_shape(&mut self, shape: &Shape) {
+        let client_data = shape.get_client_data();
+        match client_data.get_object_type() {
+            ObjectValues::Checkbox => self.set_control_type(FormControlValues::CheckBox),
+            ObjectValues::Radio => self.set_control_type(FormControlValues::OptionButton),
+            _ => self.set_control_type(FormControlValues::Button),
+        };
+        let anchor = client_data.get_anchor();
+        self.from_marker
+            .set_col(*anchor.get_left_column())
+            .set_col_off(*anchor.get_left_offset() as usize * 9525)
+            .set_row(*anchor.get_top_row())
+            .set_row_off(*anchor.get_top_offset() as usize * 9525);
+        self.to_marker
+            .set_col(*anchor.get_right_column())
+            .set_col_off(*anchor.get_right_offset() as usize * 9525)
+            .set_row(*anchor.get_bottom_row())
+            .set_row_off(*anchor.get_bottom_offset() as usize * 9525);
+        if let Some(v) = client_data.get_formula_macro() {
+            let value = v.get_value();
+            self.set_macro_name(value.strip_prefix("[0]!").unwrap_or(value));
+        }
+        if let Some(v) = client_data.get_formula_link() {
+            self.set_link_cell(v.get_value());
+        }
+        if let Some(v) = client_data.get_checked() {
+            self.set_checked(v.get_value() == &1);
+        }
+        if let Some(v) = shape.get_text_box() {
+            self.set_text(get_plain_text(v.get_innder()));
+        }
+    }
+
+    pub(crate) fn write_vml_shape(&self, writer: &mut Writer<Cursor<Vec<u8>>>, id: &usize) {
+        let is_button = self.control_type.get_value() == &FormControlValues::Button;
+
+        // v:shape
+        let id_str = format!("_x0000_s{}", id);
+        let mut attributes: Vec<(&str, &str)> = vec![
+            ("id", &id_str),
+            ("type", "#_x0000_t201"),
+            (
+                "style",
+                "position:absolute;margin-left:0;margin-top:0;width:72pt;height:24pt;z-index:1;mso-wrap-style:tight",
+            ),
+        ];
+        if is_button {
+            attributes.push(("o:button", "t"));
+            attributes.push(("fillcolor", "buttonFace [67]"));
+        } else {
+            attributes.push(("filled", "f"));
+            attributes.push(("fillcolor", "window [65]"));
+            attributes.push(("stroked", "f"));
+        }
+        attributes.push(("strokecolor", "windowText [64]"));
+        attributes.push(("o:insetmode", "auto"));
+        write_start_tag(writer, "v:shape", attributes, false);
+
+        if is_button {
+            // v:fill
+            write_start_tag(
+                writer,
+                "v:fill",
+                vec![("color2", "buttonFace [67]"), ("o:detectmouseclick", "t")],
+                true,
+            );
+        } else {
+            // v:path
+            write_start_tag(
+                writer,
+                "v:path",
+                vec![("shadowok", "t"), ("strokeok", "t"), ("fillok", "t")],
+                true,
+            );
+        }
+
+        // o:lock
+        write_start_tag(
+            writer,
+            "o:lock",
+            vec![("v:ext", "edit"), ("rotation", "t")],
+            true,
+        );
+
+        // v:textbox
+        write_start_tag(
+            writer,
+            "v:textbox",
+            vec![("style", "mso-direction-alt:auto"), ("o:singleclick", "f")],
+            false,
+        );
+        let text_align = match is_button {
+            true => "text-align:center",
+            false => "text-align:left",
+        };
+        write_start_tag(writer, "div", vec![("style", text_align)], false);
+        write_start_tag(
+            writer,
+            "font",
+            vec![("face", "Calibri"), ("size", "220"), ("color", "#000000")],
+            false,
+        );
+        write_text_node(writer, self.text.get_value_str());
+        write_end_tag(writer, "font");
+        write_end_tag(writer, "div");
+        write_end_tag(writer, "v:textbox");
+
+        // x:ClientData
+        let object_type = match self.control_type.get_value() {
+            FormControlValues::Button => ObjectValues::Button,
+            FormControlValues::CheckBox => ObjectValues::Checkbox,
+            FormControlValues::OptionButton => ObjectValues::Radio,
+        };
+        write_start_tag(
+            writer,
+            "x:ClientData",
+            vec![("ObjectType", object_type.get_value_string())],
+            false,
+        );
+
+        // x:Anchor
+        let anchor = format!(
+            "{}, {}, {}, {}, {}, {}, {}, {}",
+            self.from_marker.get_col(),
+            self.from_marker.get_col_off() / 9525,
+            self.from_marker.get_row(),
+            self.from_marker.get_row_off() / 9525,
+            self.to_marker.get_col(),
+            self.to_marker.get_col_off() / 9525,
+            self.to_marker.get_row(),
+            self.to_marker.get_row_off() / 9525,
+        );
+        write_start_tag(writer, "x:Anchor", vec![], false);
+        write_text_node(writer, &anchor);
+        write_end_tag(writer, "x:Anchor");
+
+        if is_button {
+            // x:PrintObject
+            write_start_tag(writer, "x:PrintObject", vec![], false);
+            write_text_node(writer, "False");
+            write_end_tag(writer, "x:PrintObject");
+        }
+
+        // x:AutoFill
+        write_start_tag(writer, "x:AutoFill", vec![], false);
+        write_text_node(writer, "False");
+        write_end_tag(writer, "x:AutoFill");
+
+        if !is_button {
+            // x:AutoLine
+            write_start_tag(writer, "x:AutoLine", vec![], false);
+            write_text_node(writer, "False");
+            write_end_tag(writer, "x:AutoLine");
+        }
+
+        // x:FmlaMacro
+        if self.macro_name.has_value() {
+            write_start_tag(writer, "x:FmlaMacro", vec![], false);
+            write_text_node(writer, self.get_macro_reference());
+            write_end_tag(writer, "x:FmlaMacro");
+        }
+
+        if is_button {
+            // x:TextHAlign
+            write_start_tag(writer, "x:TextHAlign", vec![], false);
+            write_text_node(writer, "Center");
+            write_end_tag(writer, "x:TextHAlign");
+        }
+
+        // x:TextVAlign
+        write_start_tag(writer, "x:TextVAlign", vec![], false);
+        write_text_node(writer, "Center");
+        write_end_tag(writer, "x:TextVAlign");
+
+        if !is_button {
+            // x:FmlaLink
+            if self.link_cell.has_value() {
+                write_start_tag(writer, "x:FmlaLink", vec![], false);
+                write_text_node(writer, self.link_cell.get_value_str());
+                write_end_tag(writer, "x:FmlaLink");
+            }
+
+            // x:Checked
+            if *self.checked.get_value() {
+                write_start_tag(writer, "x:Checked", vec![], false);
+                write_text_node(writer, "1");
+                write_end_tag(writer, "x:Checked");
+            }
+
+            // x:NoThreeD
+            write_start_tag(writer, "x:NoThreeD", vec![], true);
+        }
+
+        write_end_tag(writer, "x:ClientData");
+
+        write_end_tag(writer, "v:shape");
+    }
+
+    pub(crate) fn write_control(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        shape_id: &usize,
+        r_id: &usize,
+    ) {
+        // mc:AlternateContent
+        write_start_tag(
+            writer,
+            "mc:AlternateContent",
+            vec![("xmlns:mc", MC_NS)],
+            false,
+        );
+
+        // mc:Choice
+        write_start_tag(writer, "mc:Choice", vec![("Requires", "x14")], false);
+
+        // control
+        let shape_id_str = shape_id.to_string();
+        let r_id_str = format!("rId{}", r_id);
+        write_start_tag(
+            writer,
+            "control",
+            vec![
+                ("shapeId", &shape_id_str),
+                ("r:id", &r_id_str),
+                ("name", self.name.get_value_str()),
+            ],
+            false,
+        );
+
+        // controlPr
+        let macro_reference = self.get_macro_reference();
+        let mut attributes: Vec<(&str, &str)> = vec![("defaultSize", "0")];
+        if self.control_type.get_value() == &FormControlValues::Button {
+            attributes.push(("print", "0"));
+        }
+        attributes.push(("autoFill", "0"));
+        if self.control_type.get_value() != &FormControlValues::Button {
+            attributes.push(("autoLine", "0"));
+        }
+        attributes.push(("autoPict", "0"));
+        if self.macro_name.has_value() {
+            attributes.push(("macro", &macro_reference));
+        }
+        write_start_tag(writer, "controlPr", attributes, false);
+
+        // anchor
+        write_start_tag(writer, "anchor", vec![("moveWithCells", "1")], false);
+        self.from_marker.write_to(writer, "from");
+        self.to_marker.write_to(writer, "to");
+        write_end_tag(writer, "anchor");
+
+        write_end_tag(writer, "controlPr");
+        write_end_tag(writer, "control");
+        write_end_tag(writer, "mc:Choice");
+        write_end_tag(writer, "mc:AlternateContent");
+    }
+
+    pub(crate) fn set_control_properties(&mut self, e: &BytesStart) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, control_type, "objectType");
+        set_string_from_xml!(self, e, link_cell, "fmlaLink");
+        if let Some(v) = get_attribute(e, b"checked")? {
+            self.checked.set_value(v == "Checked");
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write_control_properties(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // formControlPr
+        let mut attributes: Vec<(&str, &str)> = vec![
+            ("xmlns", SHEET_MS_MAIN_NS),
+            ("objectType", self.control_type.get_value_string()),
+        ];
+        if *self.checked.get_value() {
+            attributes.push(("checked", "Checked"));
+        }
+        if self.link_cell.has_value() {
+            attributes.push(("fmlaLink", self.link_cell.get_value_str()));
+        }
+        attributes.push(("lockText", "1"));
+        if self.control_type.get_value() != &FormControlValues::Button {
+            attributes.push(("noThreeD", "1"));
+        }
+        write_start_tag(writer, "formControlPr", attributes, true);
+    }
+}
+impl AdjustmentCoordinate for FormControl {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.from_marker.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.to_marker.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.from_marker.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.to_marker.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.from_marker.is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
+
+fn get_plain_text(value: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+    for c in value.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
@@ -0,0 +1,33 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FormControlValues {
+    Button,
+    CheckBox,
+    OptionButton,
+}
+impl Default for FormControlValues {
+    fn default() -> Self {
+        Self::Button
+    }
+}
+impl EnumTrait for FormControlValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Button => "Button",
+            Self::CheckBox => "CheckBox",
+            Self::OptionButton => "Radio",
+        }
+    }
+}
+impl FromStr for FormControlValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Button" => Ok(Self::Button),
+            "CheckBox" => Ok(Self::CheckBox),
+            "Radio" => Ok(Self::OptionButton),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use structs::Style;
+
+/// Formatting of a cell range, taken with `Worksheet::capture_formatting`.
+/// Positions are kept relative to the top left cell of the range.
+#[derive(Clone, Default, Debug)]
+pub struct FormatSnapshot {
+    column_count: u32,
+    row_count: u32,
+    styles: Vec<(u32, u32, Arc<Style>)>,
+    row_heights: Vec<(u32, f64)>,
+    merge_cells: Vec<(u32, u32, u32, u32)>,
+}
+impl FormatSnapshot {
+    pub(crate) fn new(column_count: u32, row_count: u32) -> Self {
+        Self {
+            column_count,
+            row_count,
+            ..Default::default()
+        }
+    }
+
+    /// Number of columns in the range.
+    pub fn get_column_count(&self) -> &u32 {
+        &self.column_count
+    }
+
+    /// Number of rows in the range.
+    pub fn get_row_count(&self) -> &u32 {
+        &self.row_count
+    }
+
+    /// Styles by (column offset, row offset).
+    pub(crate) fn get_styles(&self) -> &Vec<(u32, u32, Arc<Style>)> {
+        &self.styles
+    }
+
+    pub(crate) fn add_style(&mut self, col: u32, row: u32, style: Arc<Style>) -> &mut Self {
+        self.styles.push((col, row, style));
+        self
+    }
+
+    /// Custom row heights by row offset.
+    pub(crate) fn get_row_heights(&self) -> &Vec<(u32, f64)> {
+        &self.row_heights
+    }
+
+    pub(crate) fn add_row_height(&mut self, row: u32, height: f64) -> &mut Self {
+        self.row_heights.push((row, height));
+        self
+    }
+
+    /// Merged cells as (start column, start row, end column, end row) offsets.
+    pub(crate) fn get_merge_cells(&self) -> &Vec<(u32, u32, u32, u32)> {
+        &self.merge_cells
+    }
+
+    pub(crate) fn add_merge_cell(
+        &mut self,
+        col_start: u32,
+        row_start: u32,
+        col_end: u32,
+        row_end: u32,
+    ) -> &mut Self {
+        self.merge_cells
+            .push((col_start, row_start, col_end, row_end));
+        self
+    }
+}
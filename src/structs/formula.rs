@@ -2,6 +2,7 @@
 use super::Address;
 use super::StringValue;
 use helper::address::*;
+use helper::formula::rename_sheet_reference_formula;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -9,7 +10,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Formula {
     address: Address,
     string_value: StringValue,
@@ -53,22 +54,42 @@ impl Formula {
         self
     }
 
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if self.string_value.has_value() {
+            if let Some(v) = rename_sheet_reference_formula(
+                self.string_value.get_value_str(),
+                sheet_name,
+                new_sheet_name,
+            ) {
+                self.string_value.set_value(v);
+            }
+            return;
+        }
+        if self
+            .address
+            .get_sheet_name()
+            .eq_ignore_ascii_case(sheet_name)
+        {
+            self.address.set_sheet_name(new_sheet_name);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_address_str(e.unescape().unwrap().to_string());
+                self.set_address_str(e.unescape()?.to_string());
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"formula" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "formula")
+            Event::Eof => return Err(eof_error("formula"))
         );
     }
 
@@ -79,28 +79,28 @@ impl FromMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::from("");
         xml_read_loop!(
             reader,
-            Event::Text(e) => string_value = e.unescape().unwrap().to_string(),
+            Event::Text(e) => string_value = e.unescape()?.to_string(),
             Event::End(ref e) => match e.name().into_inner() {
                 b"xdr:col" => {
-                    self.col = string_value.parse::<usize>().unwrap();
+                    self.col = string_value.parse::<usize>().map_err(parse_error)?;
                 }
                 b"xdr:colOff" => {
-                    self.col_off = string_value.parse::<usize>().unwrap();
+                    self.col_off = string_value.parse::<usize>().map_err(parse_error)?;
                 }
                 b"xdr:row" => {
-                    self.row = string_value.parse::<usize>().unwrap();
+                    self.row = string_value.parse::<usize>().map_err(parse_error)?;
                 }
                 b"xdr:rowOff" => {
-                    self.row_off = string_value.parse::<usize>().unwrap();
+                    self.row_off = string_value.parse::<usize>().map_err(parse_error)?;
                 }
-                b"from" => return,
+                b"from" => return Ok(()),
                 _ => (),
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "from")
+            Event::Eof => return Err(eof_error("from"))
         );
     }
 
@@ -54,7 +54,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, degree, "degree");
 
         xml_read_loop!(
@@ -62,16 +62,16 @@ impl GradientFill {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"stop" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"gradientFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "gradientFill")
+            Event::Eof => return Err(eof_error("gradientFill"))
         );
     }
 
@@ -53,7 +53,7 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, position, "position");
 
         xml_read_loop!(
@@ -61,16 +61,16 @@ impl GradientStop {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"color" {
                     let mut obj = Color::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"stop" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "stop")
+            Event::Eof => return Err(eof_error("stop"))
         );
     }
 
@@ -4,14 +4,17 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::HeaderFooterImage;
+use structs::MediaObject;
 use structs::OddFooter;
 use structs::OddHeader;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct HeaderFooter {
     odd_header: OddHeader,
     odd_footer: OddFooter,
+    images: Vec<HeaderFooterImage>,
 }
 
 impl HeaderFooter {
@@ -41,6 +44,36 @@ impl HeaderFooter {
         self
     }
 
+    pub fn get_images(&self) -> &Vec<HeaderFooterImage> {
+        &self.images
+    }
+
+    pub fn get_images_mut(&mut self) -> &mut Vec<HeaderFooterImage> {
+        &mut self.images
+    }
+
+    /// Add a picture to the header/footer.
+    /// The header or footer text must contain `&G` in the matching section.
+    /// # Arguments
+    /// * `value` - HeaderFooterImage.
+    pub fn add_image(&mut self, value: HeaderFooterImage) -> &mut Self {
+        self.images.push(value);
+        self
+    }
+
+    pub fn remove_images(&mut self) -> &mut Self {
+        self.images.clear();
+        self
+    }
+
+    pub(crate) fn has_image(&self) -> bool {
+        !self.images.is_empty()
+    }
+
+    pub(crate) fn get_media_object(&self) -> Vec<&MediaObject> {
+        self.images.iter().map(|image| image.get_image()).collect()
+    }
+
     pub(crate) fn has_param(&self) -> bool {
         self.odd_header.has_param() || self.odd_footer.has_param()
     }
@@ -49,26 +82,26 @@ impl HeaderFooter {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"oddHeader" => {
-                    self.odd_header.set_attributes(reader, e);
+                    self.odd_header.set_attributes(reader, e)?;
                 }
                 b"oddFooter" => {
-                    self.odd_footer.set_attributes(reader, e);
+                    self.odd_footer.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"headerFooter" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "headerFooter")
+            Event::Eof => return Err(eof_error("headerFooter"))
         );
     }
 
@@ -0,0 +1,178 @@
+use image::GenericImageView;
+use quick_xml::Writer;
+use std::fs::File;
+use std::io::Cursor;
+use std::io::Read;
+use structs::EnumTrait;
+use structs::HeaderFooterImagePositionValues;
+use structs::MediaObject;
+use writer::driver::*;
+
+/// Picture shown by the `&G` code of a header or footer.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct HeaderFooterImage {
+    position: HeaderFooterImagePositionValues,
+    image: MediaObject,
+    title: String,
+    width: f64,
+    height: f64,
+}
+/// ## Example
+/// ```rust
+/// extern crate umya_spreadsheet;
+/// use umya_spreadsheet::*;
+/// let mut book = new_file();
+/// let worksheet = book.get_sheet_mut(&0).unwrap();
+///
+/// // The picture is drawn where `&G` appears in the header text.
+/// worksheet
+///     .get_header_footer_mut()
+///     .get_odd_header_mut()
+///     .set_value("&C&G");
+///
+/// let mut image = HeaderFooterImage::default();
+/// image.new_image("./images/sample1.png", HeaderFooterImagePositionValues::CenterHeader);
+/// worksheet.get_header_footer_mut().add_image(image);
+/// ```
+impl HeaderFooterImage {
+    pub fn get_position(&self) -> &HeaderFooterImagePositionValues {
+        &self.position
+    }
+
+    pub fn set_position(&mut self, value: HeaderFooterImagePositionValues) -> &mut Self {
+        self.position = value;
+        self
+    }
+
+    pub fn get_image(&self) -> &MediaObject {
+        &self.image
+    }
+
+    pub fn get_image_mut(&mut self) -> &mut MediaObject {
+        &mut self.image
+    }
+
+    pub fn set_image(&mut self, value: MediaObject) -> &mut Self {
+        self.image = value;
+        self
+    }
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn set_title<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.title = value.into();
+        self
+    }
+
+    /// Get Width (pt).
+    pub fn get_width(&self) -> &f64 {
+        &self.width
+    }
+
+    /// Set Width (pt).
+    /// # Arguments
+    /// * `value` - Width (pt).
+    pub fn set_width(&mut self, value: f64) -> &mut Self {
+        self.width = value;
+        self
+    }
+
+    /// Get Height (pt).
+    pub fn get_height(&self) -> &f64 {
+        &self.height
+    }
+
+    /// Set Height (pt).
+    /// # Arguments
+    /// * `value` - Height (pt).
+    pub fn set_height(&mut self, value: f64) -> &mut Self {
+        self.height = value;
+        self
+    }
+
+    /// Load an image file and size it at its pixel size (96dpi).
+    /// # Arguments
+    /// * `path` - Image file path.
+    /// * `position` - Section of the header/footer showing the picture.
+    pub fn new_image(&mut self, path: &str, position: HeaderFooterImagePositionValues) {
+        let path_obj = std::path::Path::new(path);
+        let image_name = path_obj.file_name().unwrap().to_str().unwrap();
+
+        let img = image::open(path_obj).unwrap();
+        let (width, height) = img.dimensions();
+
+        let mut file = File::open(path).unwrap();
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf).unwrap();
+
+        self.image.set_image_name(image_name).set_image_data(buf);
+        self.title = path_obj.file_stem().unwrap().to_str().unwrap().to_string();
+        self.width = width as f64 * 0.75;
+        self.height = height as f64 * 0.75;
+        self.position = position;
+    }
+
+    pub(crate) fn get_style(&self) -> String {
+        format!(
+            "position:absolute;margin-left:0;margin-top:0;width:{}pt;height:{}pt;z-index:1",
+            self.width, self.height
+        )
+    }
+
+    pub(crate) fn write_vml_shape(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        id: &usize,
+        r_id: &usize,
+    ) {
+        // v:shape
+        let spid = format!("_x0000_s{}", id);
+        let style = self.get_style();
+        write_start_tag(
+            writer,
+            "v:shape",
+            vec![
+                ("id", self.position.get_value_string()),
+                ("o:spid", &spid),
+                ("type", "#_x0000_t75"),
+                ("style", &style),
+            ],
+            false,
+        );
+
+        // v:imagedata
+        let r_id_str = format!("rId{}", r_id);
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("o:relid", &r_id_str));
+        if !self.title.is_empty() {
+            attributes.push(("o:title", &self.title));
+        }
+        write_start_tag(writer, "v:imagedata", attributes, true);
+
+        // o:lock
+        write_start_tag(
+            writer,
+            "o:lock",
+            vec![("v:ext", "edit"), ("rotation", "t")],
+            true,
+        );
+
+        write_end_tag(writer, "v:shape");
+    }
+
+    pub(crate) fn set_style(&mut self, value: &str) -> &mut Self {
+        for style in value.split(';') {
+            if let Some((key, val)) = style.split_once(':') {
+                let val = val.trim().trim_end_matches("pt");
+                match key.trim() {
+                    "width" => self.width = val.parse::<f64>().unwrap_or_default(),
+                    "height" => self.height = val.parse::<f64>().unwrap_or_default(),
+                    _ => {}
+                }
+            }
+        }
+        self
+    }
+}
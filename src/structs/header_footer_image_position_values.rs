@@ -0,0 +1,42 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HeaderFooterImagePositionValues {
+    LeftHeader,
+    CenterHeader,
+    RightHeader,
+    LeftFooter,
+    CenterFooter,
+    RightFooter,
+}
+impl Default for HeaderFooterImagePositionValues {
+    fn default() -> Self {
+        Self::CenterHeader
+    }
+}
+impl EnumTrait for HeaderFooterImagePositionValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::LeftHeader => "LH",
+            Self::CenterHeader => "CH",
+            Self::RightHeader => "RH",
+            Self::LeftFooter => "LF",
+            Self::CenterFooter => "CF",
+            Self::RightFooter => "RF",
+        }
+    }
+}
+impl FromStr for HeaderFooterImagePositionValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "LH" => Ok(Self::LeftHeader),
+            "CH" => Ok(Self::CenterHeader),
+            "RH" => Ok(Self::RightHeader),
+            "LF" => Ok(Self::LeftFooter),
+            "CF" => Ok(Self::CenterFooter),
+            "RF" => Ok(Self::RightFooter),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// iconFilter
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::StringValue;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct IconFilter {
+    icon_set: StringValue,
+    icon_id: UInt32Value,
+}
+
+impl IconFilter {
+    /// Get Icon Set.
+    /// ex) "3Arrows", "3TrafficLights1", "5Rating"
+    pub fn get_icon_set(&self) -> &str {
+        self.icon_set.get_value_str()
+    }
+
+    pub fn set_icon_set<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.icon_set.set_value(value);
+        self
+    }
+
+    pub fn get_icon_id(&self) -> &u32 {
+        self.icon_id.get_value()
+    }
+
+    pub fn set_icon_id(&mut self, value: u32) -> &mut Self {
+        self.icon_id.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, icon_set, "iconSet");
+        set_number_from_xml!(self, e, icon_id, "iconId");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // iconFilter
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        attributes.push(("iconSet", self.icon_set.get_value_str()));
+        let icon_id = self.icon_id.get_value_string();
+        if self.icon_id.has_value() {
+            attributes.push(("iconId", &icon_id));
+        }
+        write_start_tag(writer, "iconFilter", attributes, true);
+    }
+}
@@ -47,19 +47,19 @@ impl IconSet {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
                 Event::Empty(ref e) => {
                     match e.name().into_inner() {
                         b"cfvo" => {
                             let mut obj = ConditionalFormatValueObject::default();
-                            obj.set_attributes(reader, e, true);
+                            obj.set_attributes(reader, e, true)?;
                             self.cfvo_collection.push(obj);
                         }
                         b"color" => {
                             let mut obj = Color::default();
-                            obj.set_attributes(reader, e, true);
+                            obj.set_attributes(reader, e, true)?;
                             self.color_collection.push(obj);
                         }
                         _ => (),
@@ -67,10 +67,10 @@ impl IconSet {
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"dataBar" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "dataBar")
+                Event::Eof => return Err(eof_error("dataBar"))
         );
     }
 
@@ -0,0 +1,253 @@
+// ignoredError
+use super::BooleanValue;
+use super::SequenceOfReferences;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use traits::AdjustmentCoordinate;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct IgnoredError {
+    sequence_of_references: SequenceOfReferences,
+    eval_error: BooleanValue,
+    two_digit_text_year: BooleanValue,
+    number_stored_as_text: BooleanValue,
+    formula: BooleanValue,
+    formula_range: BooleanValue,
+    unlocked_formula: BooleanValue,
+    empty_cell_reference: BooleanValue,
+    list_data_validation: BooleanValue,
+    calculated_column: BooleanValue,
+}
+
+impl IgnoredError {
+    pub fn get_sequence_of_references(&self) -> &SequenceOfReferences {
+        &self.sequence_of_references
+    }
+
+    pub fn get_sequence_of_references_mut(&mut self) -> &mut SequenceOfReferences {
+        &mut self.sequence_of_references
+    }
+
+    pub fn set_sequence_of_references(&mut self, value: SequenceOfReferences) -> &mut Self {
+        self.sequence_of_references = value;
+        self
+    }
+
+    /// Set target range.
+    /// # Arguments
+    /// * `value` - ex) `"A1:B10 D1"`
+    pub fn set_sqref<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sequence_of_references.set_sqref(value);
+        self
+    }
+
+    /// Ignore errors when cells contain formulas that result in an error.
+    pub fn get_eval_error(&self) -> &bool {
+        self.eval_error.get_value()
+    }
+
+    pub fn set_eval_error(&mut self, value: bool) -> &mut Self {
+        self.eval_error.set_value(value);
+        self
+    }
+
+    /// Ignore errors when formulas contain text formatted cells with years represented as 2 digits.
+    pub fn get_two_digit_text_year(&self) -> &bool {
+        self.two_digit_text_year.get_value()
+    }
+
+    pub fn set_two_digit_text_year(&mut self, value: bool) -> &mut Self {
+        self.two_digit_text_year.set_value(value);
+        self
+    }
+
+    /// Ignore errors when numbers are formatted as text or are preceded by an apostrophe.
+    pub fn get_number_stored_as_text(&self) -> &bool {
+        self.number_stored_as_text.get_value()
+    }
+
+    pub fn set_number_stored_as_text(&mut self, value: bool) -> &mut Self {
+        self.number_stored_as_text.set_value(value);
+        self
+    }
+
+    /// Ignore errors when a formula in a region of the worksheet differs from other formulas in the same region.
+    pub fn get_formula(&self) -> &bool {
+        self.formula.get_value()
+    }
+
+    pub fn set_formula(&mut self, value: bool) -> &mut Self {
+        self.formula.set_value(value);
+        self
+    }
+
+    /// Ignore errors when formulas omit certain cells in a region.
+    pub fn get_formula_range(&self) -> &bool {
+        self.formula_range.get_value()
+    }
+
+    pub fn set_formula_range(&mut self, value: bool) -> &mut Self {
+        self.formula_range.set_value(value);
+        self
+    }
+
+    /// Ignore errors when unlocked cells contain formulas.
+    pub fn get_unlocked_formula(&self) -> &bool {
+        self.unlocked_formula.get_value()
+    }
+
+    pub fn set_unlocked_formula(&mut self, value: bool) -> &mut Self {
+        self.unlocked_formula.set_value(value);
+        self
+    }
+
+    /// Ignore errors when formulas refer to empty cells.
+    pub fn get_empty_cell_reference(&self) -> &bool {
+        self.empty_cell_reference.get_value()
+    }
+
+    pub fn set_empty_cell_reference(&mut self, value: bool) -> &mut Self {
+        self.empty_cell_reference.set_value(value);
+        self
+    }
+
+    /// Ignore errors when a cell's value in a Table does not comply with the Data Validation rules specified.
+    pub fn get_list_data_validation(&self) -> &bool {
+        self.list_data_validation.get_value()
+    }
+
+    pub fn set_list_data_validation(&mut self, value: bool) -> &mut Self {
+        self.list_data_validation.set_value(value);
+        self
+    }
+
+    /// Ignore errors when cells contain a value different from a calculated column formula.
+    pub fn get_calculated_column(&self) -> &bool {
+        self.calculated_column.get_value()
+    }
+
+    pub fn set_calculated_column(&mut self, value: bool) -> &mut Self {
+        self.calculated_column.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"sqref")? {
+            self.sequence_of_references.set_sqref(v);
+        }
+        set_string_from_xml!(self, e, eval_error, "evalError");
+        set_string_from_xml!(self, e, two_digit_text_year, "twoDigitTextYear");
+        set_string_from_xml!(self, e, number_stored_as_text, "numberStoredAsText");
+        set_string_from_xml!(self, e, formula, "formula");
+        set_string_from_xml!(self, e, formula_range, "formulaRange");
+        set_string_from_xml!(self, e, unlocked_formula, "unlockedFormula");
+        set_string_from_xml!(self, e, empty_cell_reference, "emptyCellReference");
+        set_string_from_xml!(self, e, list_data_validation, "listDataValidation");
+        set_string_from_xml!(self, e, calculated_column, "calculatedColumn");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // ignoredError
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let sqref = self.sequence_of_references.get_sqref();
+        attributes.push(("sqref", &sqref));
+        if *self.eval_error.get_value() {
+            attributes.push(("evalError", self.eval_error.get_value_string()));
+        }
+        if *self.two_digit_text_year.get_value() {
+            attributes.push((
+                "twoDigitTextYear",
+                self.two_digit_text_year.get_value_string(),
+            ));
+        }
+        if *self.number_stored_as_text.get_value() {
+            attributes.push((
+                "numberStoredAsText",
+                self.number_stored_as_text.get_value_string(),
+            ));
+        }
+        if *self.formula.get_value() {
+            attributes.push(("formula", self.formula.get_value_string()));
+        }
+        if *self.formula_range.get_value() {
+            attributes.push(("formulaRange", self.formula_range.get_value_string()));
+        }
+        if *self.unlocked_formula.get_value() {
+            attributes.push(("unlockedFormula", self.unlocked_formula.get_value_string()));
+        }
+        if *self.empty_cell_reference.get_value() {
+            attributes.push((
+                "emptyCellReference",
+                self.empty_cell_reference.get_value_string(),
+            ));
+        }
+        if *self.list_data_validation.get_value() {
+            attributes.push((
+                "listDataValidation",
+                self.list_data_validation.get_value_string(),
+            ));
+        }
+        if *self.calculated_column.get_value() {
+            attributes.push((
+                "calculatedColumn",
+                self.calculated_column.get_value_string(),
+            ));
+        }
+        write_start_tag(writer, "ignoredError", attributes, true);
+    }
+}
+impl AdjustmentCoordinate for IgnoredError {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.sequence_of_references.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.sequence_of_references.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.sequence_of_references.is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
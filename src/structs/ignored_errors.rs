@@ -0,0 +1,108 @@
+// ignoredErrors
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::IgnoredError;
+use traits::AdjustmentCoordinate;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct IgnoredErrors {
+    ignored_error_list: Vec<IgnoredError>,
+}
+
+impl IgnoredErrors {
+    pub fn get_ignored_error_list(&self) -> &Vec<IgnoredError> {
+        &self.ignored_error_list
+    }
+
+    pub fn get_ignored_error_list_mut(&mut self) -> &mut Vec<IgnoredError> {
+        &mut self.ignored_error_list
+    }
+
+    pub fn add_ignored_error_list(&mut self, value: IgnoredError) -> &mut Self {
+        self.ignored_error_list.push(value);
+        self
+    }
+
+    pub(crate) fn has_param(&self) -> bool {
+        !self.ignored_error_list.is_empty()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"ignoredError" {
+                    let mut obj = IgnoredError::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_ignored_error_list(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"ignoredErrors" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("ignoredErrors"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if self.has_param() {
+            // ignoredErrors
+            write_start_tag(writer, "ignoredErrors", vec![], false);
+
+            // ignoredError
+            for obj in self.get_ignored_error_list() {
+                obj.write_to(writer);
+            }
+
+            write_end_tag(writer, "ignoredErrors");
+        }
+    }
+}
+impl AdjustmentCoordinate for IgnoredErrors {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        for ignored_error in &mut self.ignored_error_list {
+            ignored_error.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.ignored_error_list.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for ignored_error in &mut self.ignored_error_list {
+            ignored_error.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
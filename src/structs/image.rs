@@ -10,12 +10,13 @@ use structs::drawing::spreadsheet::OneCellAnchor;
 use structs::drawing::spreadsheet::Picture;
 use structs::drawing::spreadsheet::TwoCellAnchor;
 use structs::drawing::FillRectangle;
+use structs::drawing::HyperlinkClick;
 use structs::drawing::PresetGeometry;
 use structs::drawing::Stretch;
 use structs::MediaObject;
 use traits::AdjustmentCoordinate;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Image {
     two_cell_anchor: Box<Option<TwoCellAnchor>>,
     one_cell_anchor: Box<Option<OneCellAnchor>>,
@@ -180,6 +181,146 @@ impl Image {
         STANDARD.encode(self.get_image_data())
     }
 
+    /// Get the size of the embedded image.
+    /// Only the header of the image is read.
+    /// # Return value
+    /// * `Option<(u32, u32)>` - width and height in pixels. None when the data can not be decoded.
+    pub fn get_natural_size(&self) -> Option<(u32, u32)> {
+        let data = self.get_media_object().first()?.get_image_data();
+        image::ImageReader::new(Cursor::new(data))
+            .with_guessed_format()
+            .ok()?
+            .into_dimensions()
+            .ok()
+    }
+
+    /// Get the displayed size of the image.
+    /// # Arguments
+    /// * `dpi` - dots per inch used to convert from EMU. ex) 96.0
+    /// # Return value
+    /// * `Option<(f64, f64)>` - width and height in pixels. None when `dpi` is not positive.
+    pub fn get_size_in_pixels(&self, dpi: f64) -> Option<(f64, f64)> {
+        if dpi <= 0f64 {
+            return None;
+        }
+        let (cx, cy) = match self.get_one_cell_anchor() {
+            Some(anchor) => (*anchor.get_extent().get_cx(), *anchor.get_extent().get_cy()),
+            None => {
+                let extents = self
+                    .get_two_cell_anchor()?
+                    .get_picture()?
+                    .get_shape_properties()
+                    .get_transform2d()?
+                    .get_extents();
+                (*extents.get_cx(), *extents.get_cy())
+            }
+        };
+        Some((emu_to_pixels(cx, dpi), emu_to_pixels(cy, dpi)))
+    }
+
+    /// Set the displayed size of the image.
+    /// Only images anchored to one cell (like those added by `new_image`) can be resized,
+    /// the size of an image anchored to two cells depends on the cells it spans.
+    /// # Arguments
+    /// * `width` - width in pixels.
+    /// * `height` - height in pixels.
+    /// * `dpi` - dots per inch used to convert to EMU. ex) 96.0
+    /// # Return value
+    /// * `Result<&mut Self, &'static str>` - Err when `dpi` is not positive or the image is not anchored to one cell.
+    /// # Examples
+    /// ```
+    /// let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// marker.set_coordinate("B3");
+    /// let mut image = umya_spreadsheet::structs::Image::default();
+    /// image.new_image("./images/sample1.png", marker);
+    /// image.set_size_in_pixels(200, 100, 96.0).unwrap();
+    /// assert_eq!(image.get_size_in_pixels(96.0), Some((200.0, 100.0)));
+    /// ```
+    pub fn set_size_in_pixels(
+        &mut self,
+        width: u32,
+        height: u32,
+        dpi: f64,
+    ) -> Result<&mut Self, &'static str> {
+        if dpi <= 0f64 {
+            return Err("dpi must be positive.");
+        }
+        let anchor = self
+            .get_one_cell_anchor_mut()
+            .ok_or("only an image anchored to one cell can be resized.")?;
+        anchor
+            .get_extent_mut()
+            .set_cx(pixels_to_emu(width, dpi))
+            .set_cy(pixels_to_emu(height, dpi));
+        Ok(self)
+    }
+
+    /// Set the displayed size of the image to the size of the embedded image.
+    /// # Arguments
+    /// * `dpi` - dots per inch of the image. ex) 96.0
+    /// # Return value
+    /// * `Result<&mut Self, &'static str>` - Err when the image can not be resized or decoded.
+    pub fn reset_size(&mut self, dpi: f64) -> Result<&mut Self, &'static str> {
+        let (width, height) = self
+            .get_natural_size()
+            .ok_or("the image can not be decoded.")?;
+        self.set_size_in_pixels(width, height, dpi)
+    }
+
+    /// Set the displayed width of the image and keep its aspect ratio.
+    /// # Arguments
+    /// * `width` - width in pixels.
+    /// * `dpi` - dots per inch used to convert to EMU. ex) 96.0
+    /// # Return value
+    /// * `Result<&mut Self, &'static str>` - Err when the image can not be resized or decoded.
+    pub fn set_width_keep_ratio(
+        &mut self,
+        width: u32,
+        dpi: f64,
+    ) -> Result<&mut Self, &'static str> {
+        let (natural_width, natural_height) = match self.get_natural_size() {
+            Some((w, h)) if w > 0 => (w, h),
+            _ => return Err("the image can not be decoded."),
+        };
+        let height = (width as f64 * natural_height as f64 / natural_width as f64).round();
+        self.set_size_in_pixels(width, height as u32, dpi)
+    }
+
+    /// Get the URL opened by a click on the image.
+    pub fn get_hyperlink(&self) -> Option<&str> {
+        self.get_two_cell_anchor()
+            .and_then(|v| v.get_picture())
+            .or_else(|| self.get_one_cell_anchor().and_then(|v| v.get_picture()))
+            .and_then(|v| {
+                v.get_non_visual_picture_properties()
+                    .get_non_visual_drawing_properties()
+                    .get_hyperlink_click()
+            })
+            .map(|v| v.get_url())
+    }
+
+    /// Open a URL by a click on the image.
+    /// # Arguments
+    /// * `url` - URL. ex) "https://example.com"
+    pub fn set_hyperlink<S: Into<String>>(&mut self, url: S) -> &mut Self {
+        let mut hyperlink_click = HyperlinkClick::default();
+        hyperlink_click.set_url(url);
+        let picture = match self.two_cell_anchor.as_mut().as_mut() {
+            Some(v) => v.get_picture_mut(),
+            None => self
+                .one_cell_anchor
+                .as_mut()
+                .as_mut()
+                .and_then(|v| v.get_picture_mut()),
+        };
+        if let Some(v) = picture {
+            v.get_non_visual_picture_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_hyperlink_click(hyperlink_click);
+        }
+        self
+    }
+
     pub fn get_coordinate(&self) -> String {
         self.get_from_marker_type().get_coordinate()
     }
@@ -208,6 +349,23 @@ impl Image {
             .map(|anchor| anchor.get_to_marker())
     }
 
+    pub(crate) fn get_z_order(&self) -> &i32 {
+        match self.get_two_cell_anchor() {
+            Some(anchor) => anchor.get_z_order(),
+            None => self.get_one_cell_anchor().map_or(&0, |v| v.get_z_order()),
+        }
+    }
+
+    pub(crate) fn set_z_order(&mut self, value: i32) -> &mut Self {
+        if let Some(anchor) = self.get_two_cell_anchor_mut() {
+            anchor.set_z_order(value);
+        }
+        if let Some(anchor) = self.get_one_cell_anchor_mut() {
+            anchor.set_z_order(value);
+        }
+        self
+    }
+
     pub(crate) fn get_media_object(&self) -> Vec<&MediaObject> {
         let mut result: Vec<&MediaObject> = Vec::new();
         if let Some(anchor) = self.get_two_cell_anchor() {
@@ -372,3 +530,11 @@ impl AdjustmentCoordinate for Image {
         false
     }
 }
+
+fn pixels_to_emu(value: u32, dpi: f64) -> i64 {
+    (value as f64 * 914400f64 / dpi).round() as i64
+}
+
+fn emu_to_pixels(value: i64, dpi: f64) -> f64 {
+    value as f64 * dpi / 914400f64
+}
@@ -1,3 +1,5 @@
+use XlsxError;
+
 #[derive(Clone, Default, Debug)]
 pub struct Int16Value {
     #[allow(dead_code)]
@@ -20,8 +22,15 @@ impl Int16Value {
         self
     }
 
-    pub(crate) fn _set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int16Value {
-        self._set_value(value.into().parse::<i16>().unwrap())
+    pub(crate) fn _set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut Int16Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<i16>() {
+            Ok(v) => Ok(self._set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn _has_value(&self) -> bool {
@@ -1,3 +1,5 @@
+use XlsxError;
+
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Int32Value {
     value: Option<i32>,
@@ -19,8 +21,15 @@ impl Int32Value {
         self
     }
 
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.set_value(value.into().parse::<i32>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut Self, XlsxError> {
+        let value = value.into();
+        match value.parse::<i32>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn has_value(&self) -> bool {
@@ -1,4 +1,6 @@
-#[derive(Clone, Default, Debug)]
+use XlsxError;
+
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Int64Value {
     value: Option<i64>,
 }
@@ -19,8 +21,15 @@ impl Int64Value {
         self
     }
 
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int64Value {
-        self.set_value(value.into().parse::<i64>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut Int64Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<i64>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn has_value(&self) -> bool {
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+
+/// Regional settings used when parsing and rendering values.
+/// The default value is English (United States).
+/// # Examples
+/// ```
+/// use umya_spreadsheet::*;
+/// let mut locale = Locale::default();
+/// locale.set_decimal_separator(',');
+/// locale.set_thousands_separator('.');
+/// locale.set_list_separator(';');
+/// locale.set_currency_symbol("€");
+///
+/// let mut book = new_file();
+/// let worksheet = book.get_sheet_mut(&0).unwrap();
+/// worksheet.get_cell_mut("A1").set_value_with_locale("1.234,56", &locale);
+/// assert_eq!(worksheet.get_cell("A1").unwrap().get_value(), "1234.56");
+/// ```
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Locale {
+    decimal_separator: char,
+    thousands_separator: char,
+    list_separator: char,
+    currency_symbol: String,
+    month_names: Vec<String>,
+    month_abbreviations: Vec<String>,
+    day_names: Vec<String>,
+    day_abbreviations: Vec<String>,
+    function_names: BTreeMap<String, String>,
+}
+impl Default for Locale {
+    fn default() -> Self {
+        let to_vec = |list: &[&str]| list.iter().map(|v| v.to_string()).collect();
+        Self {
+            decimal_separator: '.',
+            thousands_separator: ',',
+            list_separator: ',',
+            currency_symbol: String::from("$"),
+            month_names: to_vec(&[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ]),
+            month_abbreviations: to_vec(&[
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ]),
+            day_names: to_vec(&[
+                "Sunday",
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+            ]),
+            day_abbreviations: to_vec(&["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]),
+            function_names: BTreeMap::new(),
+        }
+    }
+}
+impl Locale {
+    pub fn get_decimal_separator(&self) -> &char {
+        &self.decimal_separator
+    }
+
+    pub fn set_decimal_separator(&mut self, value: char) -> &mut Self {
+        self.decimal_separator = value;
+        self
+    }
+
+    pub fn get_thousands_separator(&self) -> &char {
+        &self.thousands_separator
+    }
+
+    pub fn set_thousands_separator(&mut self, value: char) -> &mut Self {
+        self.thousands_separator = value;
+        self
+    }
+
+    /// Separator between values in CSV output and between formula arguments.
+    pub fn get_list_separator(&self) -> &char {
+        &self.list_separator
+    }
+
+    pub fn set_list_separator(&mut self, value: char) -> &mut Self {
+        self.list_separator = value;
+        self
+    }
+
+    /// Symbol used in place of `$` in number formats.
+    pub fn get_currency_symbol(&self) -> &str {
+        &self.currency_symbol
+    }
+
+    pub fn set_currency_symbol<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.currency_symbol = value.into();
+        self
+    }
+
+    /// Month names, January first.
+    pub fn get_month_names(&self) -> &Vec<String> {
+        &self.month_names
+    }
+
+    pub fn set_month_names(&mut self, value: Vec<String>) -> &mut Self {
+        self.month_names = value;
+        self
+    }
+
+    /// Short month names, January first.
+    pub fn get_month_abbreviations(&self) -> &Vec<String> {
+        &self.month_abbreviations
+    }
+
+    pub fn set_month_abbreviations(&mut self, value: Vec<String>) -> &mut Self {
+        self.month_abbreviations = value;
+        self
+    }
+
+    /// Day of week names, Sunday first.
+    pub fn get_day_names(&self) -> &Vec<String> {
+        &self.day_names
+    }
+
+    pub fn set_day_names(&mut self, value: Vec<String>) -> &mut Self {
+        self.day_names = value;
+        self
+    }
+
+    /// Short day of week names, Sunday first.
+    pub fn get_day_abbreviations(&self) -> &Vec<String> {
+        &self.day_abbreviations
+    }
+
+    pub fn set_day_abbreviations(&mut self, value: Vec<String>) -> &mut Self {
+        self.day_abbreviations = value;
+        self
+    }
+
+    /// Localized function names, keyed by the English name.
+    pub fn get_function_names(&self) -> &BTreeMap<String, String> {
+        &self.function_names
+    }
+
+    pub fn set_function_names(&mut self, value: BTreeMap<String, String>) -> &mut Self {
+        self.function_names = value;
+        self
+    }
+
+    /// Add a localized function name.
+    /// # Arguments
+    /// * `name` - English name. ex) `"SUM"`
+    /// * `localized_name` - ex) `"SUMME"`
+    pub fn add_function_name<S: Into<String>, T: Into<String>>(
+        &mut self,
+        name: S,
+        localized_name: T,
+    ) -> &mut Self {
+        self.function_names.insert(
+            name.into().to_uppercase(),
+            localized_name.into().to_uppercase(),
+        );
+        self
+    }
+
+    /// Parse a number written with this locale's separators. ex) `"1.234,5"`
+    pub fn parse_number(&self, value: &str) -> Option<f64> {
+        let normalized: String = value
+            .trim()
+            .chars()
+            .filter(|c| *c != self.thousands_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+        normalized.parse::<f64>().ok()
+    }
+
+    /// Swap the `.` and `,` of an English formatted number for this locale's separators.
+    /// Only separators between two digits are swapped, so the literal text of a format is kept.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut locale = Locale::default();
+    /// locale.set_decimal_separator(',');
+    /// locale.set_thousands_separator('.');
+    /// assert_eq!(locale.localize_number("1,234.5 pcs. ea."), "1.234,5 pcs. ea.");
+    /// ```
+    pub fn localize_number(&self, value: &str) -> String {
+        let chars: Vec<char> = value.chars().collect();
+        let is_digit = |index: Option<usize>| {
+            index
+                .and_then(|v| chars.get(v))
+                .map_or(false, |v| v.is_ascii_digit())
+        };
+        chars
+            .iter()
+            .enumerate()
+            .map(|(index, c)| {
+                if !is_digit(index.checked_sub(1)) || !is_digit(Some(index + 1)) {
+                    return *c;
+                }
+                match c {
+                    '.' => self.decimal_separator,
+                    ',' => self.thousands_separator,
+                    _ => *c,
+                }
+            })
+            .collect()
+    }
+}
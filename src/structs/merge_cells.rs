@@ -57,20 +57,20 @@ impl MergeCells {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"mergeCell" {
-                    self.add_range(get_attribute(e, b"ref").unwrap());
+                    self.add_range(get_required_attribute(e, b"ref")?);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mergeCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mergeCells")
+            Event::Eof => return Err(eof_error("mergeCells"))
         );
     }
 
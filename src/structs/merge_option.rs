@@ -0,0 +1,37 @@
+/// Options of `Spreadsheet::merge`.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct MergeOption {
+    rename_sheet: bool,
+    copy_defined_names: bool,
+}
+impl Default for MergeOption {
+    fn default() -> Self {
+        Self {
+            rename_sheet: true,
+            copy_defined_names: true,
+        }
+    }
+}
+impl MergeOption {
+    /// Rename a sheet whose name is already used. ex) `"Sheet1 (2)"`
+    /// When `false`, a duplicate sheet name is an error.
+    pub fn get_rename_sheet(&self) -> &bool {
+        &self.rename_sheet
+    }
+
+    pub fn set_rename_sheet(&mut self, value: bool) -> &mut Self {
+        self.rename_sheet = value;
+        self
+    }
+
+    /// Copy the workbook defined names.
+    /// A name already defined in the merged workbook is not copied.
+    pub fn get_copy_defined_names(&self) -> &bool {
+        &self.copy_defined_names
+    }
+
+    pub fn set_copy_defined_names(&mut self, value: bool) -> &mut Self {
+        self.copy_defined_names = value;
+        self
+    }
+}
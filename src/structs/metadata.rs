@@ -0,0 +1,138 @@
+// metadata
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use reader::driver::*;
+use structs::MetadataRecord;
+
+/// Cell and value metadata of the workbook (xl/metadata.xml). Read only.
+/// Cells refer to it with their `cm` (`Cell::get_cell_meta_index`)
+/// and `vm` (`Cell::get_value_meta_index`) attributes.
+/// The part itself is written back as it was read.
+#[derive(Clone, Default, Debug)]
+pub struct Metadata {
+    metadata_types: Vec<String>,
+    cell_metadata: Vec<MetadataRecord>,
+    value_metadata: Vec<MetadataRecord>,
+    dynamic_arrays: Vec<bool>,
+}
+
+impl Metadata {
+    /// Get the names of the metadata types. ex) `XLDAPR`, `XLRICHVALUE`
+    pub fn get_metadata_types(&self) -> &Vec<String> {
+        &self.metadata_types
+    }
+
+    pub fn get_cell_metadata(&self) -> &Vec<MetadataRecord> {
+        &self.cell_metadata
+    }
+
+    pub fn get_value_metadata(&self) -> &Vec<MetadataRecord> {
+        &self.value_metadata
+    }
+
+    /// Get the cell metadata referred to by a cell.
+    /// # Arguments
+    /// * `cell_meta_index` - `cm` of the cell (1-based).
+    pub fn get_cell_metadata_by_index(&self, cell_meta_index: &u32) -> Option<&MetadataRecord> {
+        match cell_meta_index {
+            0 => None,
+            _ => self.cell_metadata.get(*cell_meta_index as usize - 1),
+        }
+    }
+
+    /// Get the value metadata referred to by a cell.
+    /// # Arguments
+    /// * `value_meta_index` - `vm` of the cell (1-based).
+    pub fn get_value_metadata_by_index(&self, value_meta_index: &u32) -> Option<&MetadataRecord> {
+        match value_meta_index {
+            0 => None,
+            _ => self.value_metadata.get(*value_meta_index as usize - 1),
+        }
+    }
+
+    /// True when the cell metadata marks a dynamic array formula.
+    /// Formulas such as LET, LAMBDA, FILTER or SEQUENCE are stored this way.
+    /// # Arguments
+    /// * `cell_meta_index` - `cm` of the cell (1-based).
+    pub fn is_dynamic_array(&self, cell_meta_index: &u32) -> bool {
+        match self.get_cell_metadata_by_index(cell_meta_index) {
+            Some(v) if v.get_type_name() == "XLDAPR" => self
+                .dynamic_arrays
+                .get(*v.get_value_index() as usize)
+                .copied()
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn read(data: &[u8]) -> Result<Self, XlsxError> {
+        let mut reader = Reader::from_reader(data);
+        reader.config_mut().trim_text(true);
+        let mut obj = Self::default();
+        let mut future_name = String::new();
+        let mut in_cell_metadata = false;
+        let mut in_value_metadata = false;
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"metadataType" => {
+                        obj.metadata_types
+                            .push(get_attribute(e, b"name")?.unwrap_or_default());
+                    }
+                    b"rc" => {
+                        let record = obj.make_record(e)?;
+                        if in_cell_metadata {
+                            obj.cell_metadata.push(record);
+                        } else if in_value_metadata {
+                            obj.value_metadata.push(record);
+                        }
+                    }
+                    b"xda:dynamicArrayProperties" if future_name == "XLDAPR" => {
+                        let value = get_attribute(e, b"fDynamic")?
+                            .map_or(false, |v| v == "1" || v == "true");
+                        obj.dynamic_arrays.push(value);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"futureMetadata" => {
+                        future_name = get_attribute(e, b"name")?.unwrap_or_default();
+                    }
+                    b"cellMetadata" => in_cell_metadata = true,
+                    b"valueMetadata" => in_value_metadata = true,
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                match e.name().into_inner() {
+                    b"futureMetadata" => future_name.clear(),
+                    b"cellMetadata" => in_cell_metadata = false,
+                    b"valueMetadata" => in_value_metadata = false,
+                    _ => (),
+                }
+            },
+            Event::Eof => break
+        );
+        Ok(obj)
+    }
+
+    fn make_record(&self, e: &BytesStart) -> Result<MetadataRecord, XlsxError> {
+        let type_index = get_attribute(e, b"t")?
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+        let type_name = match type_index {
+            0 => "",
+            _ => self
+                .metadata_types
+                .get(type_index - 1)
+                .map_or("", |v| v.as_str()),
+        };
+        let value_index = get_attribute(e, b"v")?
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(0);
+        Ok(MetadataRecord::new(type_name, value_index))
+    }
+}
@@ -0,0 +1,29 @@
+// rc
+use structs::StringValue;
+use structs::UInt32Value;
+
+/// A cell or value metadata record (rc) of xl/metadata.xml. Read only.
+#[derive(Clone, Default, Debug)]
+pub struct MetadataRecord {
+    type_name: StringValue,
+    value_index: UInt32Value,
+}
+
+impl MetadataRecord {
+    pub(crate) fn new(type_name: &str, value_index: u32) -> Self {
+        let mut obj = Self::default();
+        obj.type_name.set_value(type_name);
+        obj.value_index.set_value(value_index);
+        obj
+    }
+
+    /// Get the name of the metadata type. ex) `XLDAPR`, `XLRICHVALUE`
+    pub fn get_type_name(&self) -> &str {
+        self.type_name.get_value_str()
+    }
+
+    /// Get the index of the value in the metadata of this type (0-based).
+    pub fn get_value_index(&self) -> &u32 {
+        self.value_index.get_value()
+    }
+}
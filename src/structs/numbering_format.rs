@@ -6,12 +6,13 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use std::sync::Arc;
 use writer::driver::*;
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct NumberingFormat {
     number_format_id: u32,
-    format_code: String,
+    format_code: Arc<str>,
     is_build_in: bool,
 }
 
@@ -19,7 +20,7 @@ impl Default for NumberingFormat {
     fn default() -> Self {
         Self {
             number_format_id: 0,
-            format_code: NumberingFormat::FORMAT_GENERAL.to_string(),
+            format_code: SHARED_BUILT_IN_FORMAT_CODES[&0].clone(),
             is_build_in: true,
         }
     }
@@ -76,15 +77,10 @@ impl NumberingFormat {
     }
 
     pub fn set_number_format_id(&mut self, value: u32) -> &mut Self {
-        let format_code_result = FILL_BUILT_IN_FORMAT_CODES.iter().find_map(|(key, val)| {
-            if key == &value {
-                Some(val.clone())
-            } else {
-                None
-            }
-        });
-
-        self.format_code = format_code_result.expect("Not Found NumberFormatId.");
+        self.format_code = SHARED_BUILT_IN_FORMAT_CODES
+            .get(&value)
+            .cloned()
+            .expect("Not Found NumberFormatId.");
         self.number_format_id = value;
         self.is_build_in = true;
         self
@@ -107,21 +103,23 @@ impl NumberingFormat {
     /// .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_DATE_XLSX17);
     /// ```
     pub fn set_format_code<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.format_code = value.into();
-        for (index, format) in FILL_BUILT_IN_FORMAT_CODES.iter() {
-            if &self.format_code == format {
+        let value = value.into();
+        for (index, format) in SHARED_BUILT_IN_FORMAT_CODES.iter() {
+            if &*value == &**format {
+                self.format_code = format.clone();
                 self.number_format_id = *index;
                 self.is_build_in = true;
                 return self;
             }
         }
+        self.format_code = value.into();
         self.number_format_id = 999999;
         self.is_build_in = false;
         self
     }
 
     pub(crate) fn set_format_code_crate<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.format_code = value.into();
+        self.format_code = value.into().into();
         self
     }
 
@@ -134,22 +132,23 @@ impl NumberingFormat {
     }
 
     pub(crate) fn get_hash_code(&self) -> String {
-        format!("{:x}", md5::Md5::digest(&self.format_code))
+        format!("{:x}", md5::Md5::digest(self.format_code.as_bytes()))
     }
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.number_format_id = get_attribute(e, b"numFmtId")
+    ) -> Result<(), XlsxError> {
+        self.number_format_id = get_attribute(e, b"numFmtId")?
             .unwrap()
             .parse::<u32>()
             .unwrap();
-        self.format_code = escape::unescape(get_attribute(e, b"formatCode").unwrap().as_str())
+        self.format_code = escape::unescape(get_required_attribute(e, b"formatCode")?.as_str())
             .unwrap()
-            .to_string();
+            .into();
         self.is_build_in = false;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, number_format_id: &u32) {
@@ -159,7 +158,7 @@ impl NumberingFormat {
             "numFmt",
             vec![
                 ("numFmtId", number_format_id.to_string().as_str()),
-                ("formatCode", &self.format_code),
+                ("formatCode", &*self.format_code),
             ],
             true,
         );
@@ -167,6 +166,12 @@ impl NumberingFormat {
 }
 
 lazy_static! {
+    /// Built-in format codes shared by every `NumberingFormat` that uses them.
+    static ref SHARED_BUILT_IN_FORMAT_CODES: HashMap<u32, Arc<str>> = FILL_BUILT_IN_FORMAT_CODES
+        .iter()
+        .map(|(key, val)| (*key, Arc::from(val.as_str())))
+        .collect();
+
     pub(crate) static ref FILL_BUILT_IN_FORMAT_CODES: HashMap<u32, String> = {
         let mut map:HashMap<u32, String> = HashMap::new();
         // General
@@ -35,10 +35,9 @@ impl NumberingFormats {
     }
 
     pub(crate) fn get_build_in_formats(&mut self) {
-        for (index, code) in super::numbering_format::FILL_BUILT_IN_FORMAT_CODES.iter() {
+        for index in super::numbering_format::FILL_BUILT_IN_FORMAT_CODES.keys() {
             let mut obj = NumberingFormat::default();
-            obj.set_number_format_id_crate(*index)
-                .set_format_code_crate(code.clone());
+            obj.set_number_format_id(*index);
             self.set_numbering_format(obj);
         }
     }
@@ -75,22 +74,22 @@ impl NumberingFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"numFmt" {
                     let mut obj = NumberingFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_numbering_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"numFmts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "numFmts")
+            Event::Eof => return Err(eof_error("numFmts"))
         );
     }
 
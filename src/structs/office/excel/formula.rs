@@ -8,7 +8,7 @@ use std::vec;
 use structs::Address;
 use writer::driver::*;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct Formula {
     value: Address,
 }
@@ -26,17 +26,23 @@ impl Formula {
         self
     }
 
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if self.value.get_sheet_name().eq_ignore_ascii_case(sheet_name) {
+            self.value.set_sheet_name(new_sheet_name);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::from("");
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Text(e)) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"xm:f" => {
@@ -44,12 +50,12 @@ impl Formula {
                         obj.set_address(value);
                         self.value = obj;
                         value = String::from("");
-                        return;
+                        return Ok(());
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xm:f"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("xm:f")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
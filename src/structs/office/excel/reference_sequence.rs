@@ -30,13 +30,13 @@ impl ReferenceSequence {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::from("");
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Text(e)) => {
-                    value = e.unescape().unwrap().to_string();
+                    value = e.unescape()?.to_string();
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"xm:sqref" => {
@@ -44,12 +44,12 @@ impl ReferenceSequence {
                         obj.set_coordinate(value);
                         self.value = obj;
                         value = String::from("");
-                        return;
+                        return Ok(());
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xm:sqref"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("xm:sqref")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
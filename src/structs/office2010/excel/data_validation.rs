@@ -141,42 +141,53 @@ impl DataValidation {
         self
     }
 
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        if let Some(v) = &mut self.formula1 {
+            v.get_value_mut()
+                .rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+        if let Some(v) = &mut self.formula2 {
+            v.get_value_mut()
+                .rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        if let Some(v) = get_attribute(e, b"type") {
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"type")? {
             self.r#type.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"operator") {
+        if let Some(v) = get_attribute(e, b"operator")? {
             self.operator.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"allowBlank") {
+        if let Some(v) = get_attribute(e, b"allowBlank")? {
             self.allow_blank.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"showInputMessage") {
+        if let Some(v) = get_attribute(e, b"showInputMessage")? {
             self.show_input_message.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"showErrorMessage") {
+        if let Some(v) = get_attribute(e, b"showErrorMessage")? {
             self.show_error_message.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"promptTitle") {
+        if let Some(v) = get_attribute(e, b"promptTitle")? {
             self.prompt_title.set_value_string(v);
         }
 
-        if let Some(v) = get_attribute(e, b"prompt") {
+        if let Some(v) = get_attribute(e, b"prompt")? {
             self.prompt.set_value_string(v);
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
@@ -185,27 +196,27 @@ impl DataValidation {
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"x14:formula1" => {
                         let mut obj = DataValidationForumla1::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula1 = Some(obj);
                     }
                     b"x14:formula2" => {
                         let mut obj = DataValidationForumla2::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula2 = Some(obj);
                     }
                     b"xm:sqref" => {
                         let mut obj = ReferenceSequence::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.reference_sequence = obj;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"x14:dataValidation" => return,
+                    b"x14:dataValidation" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "x14:dataValidation"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("x14:dataValidation")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
@@ -1,14 +1,30 @@
 use super::EmbeddedObjectProperties;
 use super::StringValue;
 use helper::const_str::MC_NS;
+use helper::ole::*;
+use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::drawing::spreadsheet::EditAsValues;
+use structs::drawing::spreadsheet::MarkerType;
+use structs::drawing::spreadsheet::Shape as DrawingShape;
 use structs::drawing::spreadsheet::TwoCellAnchor;
 use structs::raw::RawRelationships;
+use structs::vml::spreadsheet::Anchor;
+use structs::vml::spreadsheet::AutoSizePicture;
+use structs::vml::spreadsheet::ClipboardFormat;
+use structs::vml::spreadsheet::ClipboardFormatValues;
+use structs::vml::spreadsheet::ObjectValues;
+use structs::vml::spreadsheet::ResizeWithCells;
+use structs::vml::Fill;
+use structs::vml::ImageData;
 use structs::vml::Shape;
+use structs::MediaObject;
+use structs::ShapeType;
+use structs::XlsxError;
 use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
@@ -101,6 +117,193 @@ impl OleObject {
         self
     }
 
+    /// Get the original file data of the embedded object.
+    /// Package objects and documents stored in an OLE storage are unwrapped.
+    pub fn get_embedded_file_data(&self) -> Option<Vec<u8>> {
+        self.get_payload().map(|(_, data)| data)
+    }
+
+    /// Get the original file name of the embedded object.
+    /// Only package objects keep the file name.
+    pub fn get_embedded_file_name(&self) -> Option<String> {
+        self.get_payload()
+            .map(|(file_name, _)| file_name)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Get the type of the embedded file.
+    /// It is the extension of the original file name, or is detected from the data.
+    /// # Return value
+    /// * `Option<String>` - Extension. ex) `"pdf"`, `"docx"`
+    pub fn get_embedded_file_type(&self) -> Option<String> {
+        let (file_name, data) = self.get_payload()?;
+        if let Some((_, extension)) = file_name.rsplit_once('.') {
+            return Some(extension.to_lowercase());
+        }
+        Some(detect_file_type(&data).to_string())
+    }
+
+    fn get_payload(&self) -> Option<(String, Vec<u8>)> {
+        let data = self.object_data.as_ref()?;
+        match self.is_bin() {
+            true => Some(get_embedded_file(data)),
+            false => Some((String::new(), data.clone())),
+        }
+    }
+
+    /// Embed a file as a package object shown as an icon.
+    /// # Arguments
+    /// * `file_name` - File name of the embedded file. ex) `"report.pdf"`
+    /// * `data` - File data.
+    /// * `icon` - Preview image shown on the sheet. A document icon with the label is generated when `None`.
+    /// * `label` - Caption of the icon.
+    /// * `from_marker` - Top left of the object.
+    /// * `to_marker` - Bottom right of the object.
+    /// # Return value
+    /// * `Result<&mut Self, XlsxError>` - Err when the package object can not be built.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// from_marker.set_coordinate("B2");
+    /// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    /// to_marker.set_coordinate("C5");
+    /// let mut ole_object = umya_spreadsheet::structs::OleObject::default();
+    /// ole_object
+    ///     .embed_file("memo.txt", b"hello", None, "memo.txt", from_marker, to_marker)
+    ///     .unwrap();
+    /// book.get_sheet_mut(&0)
+    ///     .unwrap()
+    ///     .get_ole_objects_mut()
+    ///     .set_ole_object(ole_object);
+    /// ```
+    pub fn embed_file(
+        &mut self,
+        file_name: &str,
+        data: &[u8],
+        icon: Option<MediaObject>,
+        label: &str,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> Result<&mut Self, XlsxError> {
+        let object_data = make_package_object(file_name, label, data)?;
+        self.set_requires("x14");
+        self.set_prog_id("Package");
+        self.set_object_extension("bin");
+        self.set_object_data(object_data);
+
+        let icon = icon.unwrap_or_else(|| {
+            let image_data = make_preview_emf(label);
+            let mut obj = MediaObject::default();
+            obj.set_image_name(format!("ole_{:x}.emf", md5::Md5::digest(&image_data)));
+            obj.set_image_data(image_data);
+            obj
+        });
+
+        // objectPr
+        let mut properties = EmbeddedObjectProperties::default();
+        properties.set_default_size(false);
+        properties.set_auto_pict(false);
+        properties.set_image(icon.clone());
+        let object_anchor = properties.get_object_anchor_mut();
+        object_anchor.set_move_with_cells(true);
+        object_anchor
+            .get_from_marker_mut()
+            .set_col(*from_marker.get_col() as usize)
+            .set_col_off(*from_marker.get_col_off())
+            .set_row(*from_marker.get_row() as usize)
+            .set_row_off(*from_marker.get_row_off());
+        object_anchor
+            .get_to_marker_mut()
+            .set_col(*to_marker.get_col() as usize)
+            .set_col_off(*to_marker.get_col_off())
+            .set_row(*to_marker.get_row() as usize)
+            .set_row_off(*to_marker.get_row_off());
+        self.set_embedded_object_properties(properties);
+
+        // vml shape
+        let mut shape = Shape::default();
+        shape
+            .set_type("#_x0000_t75")
+            .set_style(
+                "position:absolute;margin-left:0;margin-top:0;width:72pt;height:60pt;z-index:1",
+            )
+            .set_filled(true)
+            .set_fill_color("window [65]")
+            .set_stroked(true)
+            .set_stroke_color("windowText [64]");
+        let mut fill = Fill::default();
+        fill.set_color_2("window [65]");
+        shape.set_fill(fill);
+        let mut image_data = ImageData::default();
+        image_data.set_image_name(icon.get_image_name());
+        image_data.set_title("");
+        shape.set_image_data(image_data);
+        let client_data = shape.get_client_data_mut();
+        client_data.set_object_type(ObjectValues::Picture);
+        client_data.set_resize_with_cells(ResizeWithCells::default());
+        let mut anchor = Anchor::default();
+        anchor.set_left_column(*from_marker.get_col());
+        anchor.set_left_offset((*from_marker.get_col_off() / 9525) as u32);
+        anchor.set_top_row(*from_marker.get_row());
+        anchor.set_top_offset((*from_marker.get_row_off() / 9525) as u32);
+        anchor.set_right_column(*to_marker.get_col());
+        anchor.set_right_offset((*to_marker.get_col_off() / 9525) as u32);
+        anchor.set_bottom_row(*to_marker.get_row());
+        anchor.set_bottom_offset((*to_marker.get_row_off() / 9525) as u32);
+        client_data.set_anchor(anchor);
+        let mut clipboard_format = ClipboardFormat::default();
+        clipboard_format.set_value(ClipboardFormatValues::Picture);
+        client_data.set_clipboard_format(clipboard_format);
+        client_data.set_auto_size_picture(AutoSizePicture::default());
+        self.set_shape(shape);
+
+        // drawing
+        let mut drawing_shape = DrawingShape::default();
+        drawing_shape
+            .get_non_visual_shape_properties_mut()
+            .get_non_visual_drawing_properties_mut()
+            .set_id(1025)
+            .set_name("Object 1")
+            .set_hidden(true);
+        drawing_shape.set_shape_type(ShapeType::Rectangle);
+        let mut two_cell_anchor = TwoCellAnchor::default();
+        two_cell_anchor
+            .set_is_alternate_content(true)
+            .set_edit_as(EditAsValues::OneCell)
+            .set_from_marker(from_marker)
+            .set_to_marker(to_marker)
+            .set_shape(drawing_shape);
+        self.set_two_cell_anchor(two_cell_anchor);
+        Ok(self)
+    }
+
+    /// Embed a file read from the path as a package object shown as an icon.
+    /// # Arguments
+    /// * `path` - Path of the file to embed.
+    /// * `icon` - Preview image shown on the sheet. A document icon with the label is generated when `None`.
+    /// * `label` - Caption of the icon.
+    /// * `from_marker` - Top left of the object.
+    /// * `to_marker` - Bottom right of the object.
+    /// # Return value
+    /// * `Result<&mut Self, XlsxError>` - Err when the file can not be read.
+    pub fn embed_file_from_path(
+        &mut self,
+        path: &str,
+        icon: Option<MediaObject>,
+        label: &str,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> Result<&mut Self, XlsxError> {
+        let path_obj = std::path::Path::new(path);
+        let file_name = path_obj
+            .file_name()
+            .and_then(|v| v.to_str())
+            .ok_or_else(|| XlsxError::Format(format!("no file name in {}", path)))?;
+        let data = std::fs::read(path_obj)?;
+        self.embed_file(file_name, &data, icon, label, from_marker, to_marker)
+    }
+
     pub(crate) fn is_bin(&self) -> bool {
         &self.object_extension == "bin"
     }
@@ -114,7 +317,7 @@ impl OleObject {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut alternate_content: &str = "";
 
         xml_read_loop!(
@@ -131,18 +334,18 @@ impl OleObject {
                     b"oleObject" => {
                         if alternate_content == "Choice" {
                             self.prog_id
-                                .set_value_string(get_attribute(e, b"progId").unwrap());
+                                .set_value_string(get_required_attribute(e, b"progId")?);
 
-                            let r_id = get_attribute(e, b"r:id").unwrap();
+                            let r_id = get_required_attribute(e, b"r:id")?;
                             let attached_file =
-                                relationships.get_relationship_by_rid(&r_id).get_raw_file();
+                                relationships.get_relationship_by_rid(&r_id)?.get_raw_file();
                             self.set_object_extension(attached_file.get_extension());
                             self.set_object_data(attached_file.get_file_data().clone());
                         }
                     }
                     b"objectPr" => {
                         let mut obj = EmbeddedObjectProperties::default();
-                        obj.set_attributes(reader, e, relationships);
+                        obj.set_attributes(reader, e, relationships)?;
                         self.set_embedded_object_properties(obj);
                     }
                     _ => (),
@@ -150,10 +353,10 @@ impl OleObject {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mc:AlternateContent")
+            Event::Eof => return Err(eof_error("mc:AlternateContent"))
         );
     }
 
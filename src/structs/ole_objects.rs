@@ -1,11 +1,14 @@
 // oleObjects
+use super::MediaObject;
 use super::OleObject;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use structs::drawing::spreadsheet::MarkerType;
 use structs::raw::RawRelationships;
+use structs::XlsxError;
 use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
@@ -27,34 +30,58 @@ impl OleObjects {
         self
     }
 
+    /// Embed a file as a package object shown as an icon.
+    /// See [`OleObject::embed_file`].
+    pub fn embed_file(
+        &mut self,
+        file_name: &str,
+        data: &[u8],
+        icon: Option<MediaObject>,
+        label: &str,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> Result<&mut OleObject, XlsxError> {
+        let number = self.ole_object.len() + 1;
+        let mut obj = OleObject::default();
+        obj.embed_file(file_name, data, icon, label, from_marker, to_marker)?;
+        if let Some(v) = obj.get_two_cell_anchor_mut().get_shape_mut() {
+            v.get_non_visual_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_id(1024 + number as u32)
+                .set_name(format!("Object {}", number));
+        }
+        self.set_ole_object(obj);
+        Ok(self.ole_object.last_mut().unwrap())
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
                     let mut obj = OleObject::default();
-                    obj.set_attributes(reader, e, relationships);
+                    obj.set_attributes(reader, e, relationships)?;
                     self.set_ole_object(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"oleObjects" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oleObjects")
+            Event::Eof => return Err(eof_error("oleObjects"))
         );
     }
 
     pub(crate) fn write_to(
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
-        r_id: &usize,
+        r_id: &mut usize,
         ole_id: &usize,
     ) {
         if !self.ole_object.is_empty() {
@@ -62,11 +89,10 @@ impl OleObjects {
             write_start_tag(writer, "oleObjects", vec![], false);
 
             // mc:AlternateContent
-            let mut r = *r_id;
             let mut o = *ole_id;
             for obj in &self.ole_object {
-                obj.write_to(writer, &r, &o);
-                r += 2;
+                obj.write_to(writer, r_id, &o);
+                *r_id += 2;
                 o += 1;
             }
 
@@ -7,7 +7,7 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PageMargins {
     left: DoubleValue,
     right: DoubleValue,
@@ -75,18 +75,20 @@ impl PageMargins {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.left
-            .set_value_string(get_attribute(e, b"left").unwrap());
+            .set_value_string(get_required_attribute(e, b"left")?);
         self.right
-            .set_value_string(get_attribute(e, b"right").unwrap());
-        self.top.set_value_string(get_attribute(e, b"top").unwrap());
+            .set_value_string(get_required_attribute(e, b"right")?);
+        self.top
+            .set_value_string(get_required_attribute(e, b"top")?);
         self.bottom
-            .set_value_string(get_attribute(e, b"bottom").unwrap());
+            .set_value_string(get_required_attribute(e, b"bottom")?);
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .set_value_string(get_required_attribute(e, b"header")?);
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .set_value_string(get_required_attribute(e, b"footer")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
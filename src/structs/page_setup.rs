@@ -1,3 +1,4 @@
+use helper::const_str::*;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -9,7 +10,7 @@ use structs::OrientationValues;
 use structs::UInt32Value;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct PageSetup {
     paper_size: UInt32Value,
     orientation: EnumValue<OrientationValues>,
@@ -85,6 +86,7 @@ impl PageSetup {
         self
     }
 
+    /// Get the printer settings part (`xl/printerSettings/*.bin`).
     pub fn get_object_data(&self) -> Option<&Vec<u8>> {
         self.object_data.as_ref()
     }
@@ -98,6 +100,11 @@ impl PageSetup {
         self
     }
 
+    pub fn remove_object_data(&mut self) -> &mut Self {
+        self.object_data = None;
+        self
+    }
+
     pub(crate) fn has_param(&self) -> bool {
         self.paper_size.has_value()
             || self.orientation.has_value()
@@ -114,22 +121,27 @@ impl PageSetup {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: Option<&RawRelationships>,
-    ) {
-        set_string_from_xml!(self, e, paper_size, "paperSize");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, paper_size, "paperSize");
         set_string_from_xml!(self, e, orientation, "orientation");
-        set_string_from_xml!(self, e, scale, "scale");
-        set_string_from_xml!(self, e, fit_to_height, "fitToHeight");
-        set_string_from_xml!(self, e, fit_to_width, "fitToWidth");
-        set_string_from_xml!(self, e, horizontal_dpi, "horizontalDpi");
-        set_string_from_xml!(self, e, vertical_dpi, "verticalDpi");
-
-        if let Some(r_id) = get_attribute(e, b"r:id") {
-            let attached_file = relationships
-                .unwrap()
-                .get_relationship_by_rid(&r_id)
-                .get_raw_file();
-            self.set_object_data(attached_file.get_file_data().clone());
+        set_number_from_xml!(self, e, scale, "scale");
+        set_number_from_xml!(self, e, fit_to_height, "fitToHeight");
+        set_number_from_xml!(self, e, fit_to_width, "fitToWidth");
+        set_number_from_xml!(self, e, horizontal_dpi, "horizontalDpi");
+        set_number_from_xml!(self, e, vertical_dpi, "verticalDpi");
+
+        // printer settings part (xl/printerSettings/*.bin)
+        if let Some(r_id) = get_attribute(e, b"r:id")? {
+            let relationship = relationships.and_then(|v| {
+                v.get_relationship_list()
+                    .iter()
+                    .find(|r| r.get_id() == r_id && r.get_type() == PRINTER_SETTINGS_NS)
+            });
+            if let Some(v) = relationship {
+                self.set_object_data(v.get_raw_file().get_file_data().clone());
+            }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut usize) {
@@ -0,0 +1,31 @@
+/// Size of a part of the package, reported by `writer::xlsx::estimate`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct PartSize {
+    path: String,
+    size: u64,
+    compressed_size: u64,
+}
+impl PartSize {
+    pub(crate) fn new<S: Into<String>>(path: S, size: u64, compressed_size: u64) -> Self {
+        Self {
+            path: path.into(),
+            size,
+            compressed_size,
+        }
+    }
+
+    /// Path in the package. ex) `"xl/worksheets/sheet1.xml"`
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    /// Uncompressed bytes.
+    pub fn get_size(&self) -> &u64 {
+        &self.size
+    }
+
+    /// Bytes in the package after compression.
+    pub fn get_compressed_size(&self) -> &u64 {
+        &self.compressed_size
+    }
+}
@@ -99,11 +99,11 @@ impl PatternFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, pattern_type, "patternType");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -112,12 +112,12 @@ impl PatternFill {
                 match e.name().into_inner() {
                     b"fgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_foreground_color(obj);
                     }
                     b"bgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_background_color(obj);
                     }
                     _ => (),
@@ -125,10 +125,10 @@ impl PatternFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"patternFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "patternFill")
+            Event::Eof => return Err(eof_error("patternFill"))
         );
     }
 
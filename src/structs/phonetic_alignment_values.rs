@@ -0,0 +1,36 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum PhoneticAlignmentValues {
+    Center,
+    Distributed,
+    Left,
+    NoControl,
+}
+impl Default for PhoneticAlignmentValues {
+    fn default() -> Self {
+        Self::Left
+    }
+}
+impl EnumTrait for PhoneticAlignmentValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Center => "center",
+            Self::Distributed => "distributed",
+            Self::Left => "left",
+            Self::NoControl => "noControl",
+        }
+    }
+}
+impl FromStr for PhoneticAlignmentValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "center" => Ok(Self::Center),
+            "distributed" => Ok(Self::Distributed),
+            "left" => Ok(Self::Left),
+            "noControl" => Ok(Self::NoControl),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// phoneticPr
+use super::EnumValue;
+use super::PhoneticAlignmentValues;
+use super::PhoneticValues;
+use super::UInt32Value;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+pub struct PhoneticProperties {
+    font_id: UInt32Value,
+    phonetic_type: EnumValue<PhoneticValues>,
+    alignment: EnumValue<PhoneticAlignmentValues>,
+}
+
+impl PhoneticProperties {
+    pub fn get_font_id(&self) -> &u32 {
+        self.font_id.get_value()
+    }
+
+    pub fn set_font_id(&mut self, value: u32) -> &mut Self {
+        self.font_id.set_value(value);
+        self
+    }
+
+    pub fn get_phonetic_type(&self) -> &PhoneticValues {
+        self.phonetic_type.get_value()
+    }
+
+    pub fn set_phonetic_type(&mut self, value: PhoneticValues) -> &mut Self {
+        self.phonetic_type.set_value(value);
+        self
+    }
+
+    pub fn get_alignment(&self) -> &PhoneticAlignmentValues {
+        self.alignment.get_value()
+    }
+
+    pub fn set_alignment(&mut self, value: PhoneticAlignmentValues) -> &mut Self {
+        self.alignment.set_value(value);
+        self
+    }
+
+    pub(crate) fn get_hash_code(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.font_id.get_value_string(),
+            self.phonetic_type.get_value_string(),
+            self.alignment.get_value_string()
+        )
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, font_id, "fontId");
+        set_string_from_xml!(self, e, phonetic_type, "type");
+        set_string_from_xml!(self, e, alignment, "alignment");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // phoneticPr
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        let font_id = self.font_id.get_value_string();
+        attributes.push(("fontId", &font_id));
+        if self.phonetic_type.has_value() {
+            attributes.push(("type", self.phonetic_type.get_value_string()));
+        }
+        if self.alignment.has_value() {
+            attributes.push(("alignment", self.alignment.get_value_string()));
+        }
+        write_start_tag(writer, "phoneticPr", attributes, true);
+    }
+}
@@ -1,29 +1,100 @@
-// si
+// rPh
+use super::Text;
+use super::UInt32Value;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use writer::driver::*;
 
-#[derive(Default, Debug)]
-pub(crate) struct PhoneticRun {}
+#[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
+pub struct PhoneticRun {
+    text: Text,
+    start_base: UInt32Value,
+    end_base: UInt32Value,
+}
 
 impl PhoneticRun {
+    /// Get phonetic text (furigana).
+    pub fn get_text(&self) -> &str {
+        self.text.get_value()
+    }
+
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.text.set_value(value);
+        self
+    }
+
+    /// Get index of the first base character the phonetic text applies to.
+    pub fn get_start_base(&self) -> &u32 {
+        self.start_base.get_value()
+    }
+
+    pub fn set_start_base(&mut self, value: u32) -> &mut Self {
+        self.start_base.set_value(value);
+        self
+    }
+
+    /// Get index after the last base character the phonetic text applies to.
+    pub fn get_end_base(&self) -> &u32 {
+        self.end_base.get_value()
+    }
+
+    pub fn set_end_base(&mut self, value: u32) -> &mut Self {
+        self.end_base.set_value(value);
+        self
+    }
+
+    pub(crate) fn get_hash_code(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.text.get_hash_code(),
+            self.start_base.get_value_string(),
+            self.end_base.get_value_string()
+        )
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
-        _e: &BytesStart,
-    ) {
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, start_base, "sb");
+        set_number_from_xml!(self, e, end_base, "eb");
+
         xml_read_loop!(
             reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"t" {
+                    let mut obj = Text::default();
+                    obj.set_attributes(reader, e)?;
+                    self.text = obj;
+                }
+            },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rPh" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rPh")
+            Event::Eof => return Err(eof_error("rPh"))
         );
     }
 
-    pub(crate) fn _write_to(&self, _writer: &mut Writer<Cursor<Vec<u8>>>) {}
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // rPh
+        let start_base = self.start_base.get_value_string();
+        let end_base = self.end_base.get_value_string();
+        write_start_tag(
+            writer,
+            "rPh",
+            vec![("sb", &start_base), ("eb", &end_base)],
+            false,
+        );
+
+        // t
+        self.text.write_to(writer);
+
+        write_end_tag(writer, "rPh");
+    }
 }
@@ -0,0 +1,36 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum PhoneticValues {
+    FullwidthKatakana,
+    HalfwidthKatakana,
+    Hiragana,
+    NoConversion,
+}
+impl Default for PhoneticValues {
+    fn default() -> Self {
+        Self::FullwidthKatakana
+    }
+}
+impl EnumTrait for PhoneticValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::FullwidthKatakana => "fullwidthKatakana",
+            Self::HalfwidthKatakana => "halfwidthKatakana",
+            Self::Hiragana => "Hiragana",
+            Self::NoConversion => "noConversion",
+        }
+    }
+}
+impl FromStr for PhoneticValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "fullwidthKatakana" => Ok(Self::FullwidthKatakana),
+            "halfwidthKatakana" => Ok(Self::HalfwidthKatakana),
+            "Hiragana" => Ok(Self::Hiragana),
+            "noConversion" => Ok(Self::NoConversion),
+            _ => Err(()),
+        }
+    }
+}
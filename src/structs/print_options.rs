@@ -10,6 +10,8 @@ use writer::driver::*;
 pub struct PrintOptions {
     horizontal_centered: BooleanValue,
     vertical_centered: BooleanValue,
+    headings: BooleanValue,
+    grid_lines: BooleanValue,
 }
 
 impl PrintOptions {
@@ -31,17 +33,51 @@ impl PrintOptions {
         self
     }
 
+    /// Get Headings.
+    /// Whether the row and column headings are printed.
+    pub fn get_headings(&self) -> &bool {
+        self.headings.get_value()
+    }
+
+    /// Set Headings.
+    /// # Arguments
+    /// * `value` - true: print the row and column headings.
+    pub fn set_headings(&mut self, value: bool) -> &mut Self {
+        self.headings.set_value(value);
+        self
+    }
+
+    /// Get Grid Lines.
+    /// Whether the gridlines are printed.
+    pub fn get_grid_lines(&self) -> &bool {
+        self.grid_lines.get_value()
+    }
+
+    /// Set Grid Lines.
+    /// # Arguments
+    /// * `value` - true: print the gridlines.
+    pub fn set_grid_lines(&mut self, value: bool) -> &mut Self {
+        self.grid_lines.set_value(value);
+        self
+    }
+
     pub(crate) fn has_param(&self) -> bool {
-        self.horizontal_centered.has_value() || self.vertical_centered.has_value()
+        self.horizontal_centered.has_value()
+            || self.vertical_centered.has_value()
+            || self.headings.has_value()
+            || self.grid_lines.has_value()
     }
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, horizontal_centered, "horizontalCentered");
         set_string_from_xml!(self, e, vertical_centered, "verticalCentered");
+        set_string_from_xml!(self, e, headings, "headings");
+        set_string_from_xml!(self, e, grid_lines, "gridLines");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -60,6 +96,12 @@ impl PrintOptions {
                     self.vertical_centered.get_value_string(),
                 ));
             }
+            if self.headings.has_value() {
+                attributes.push(("headings", self.headings.get_value_string()));
+            }
+            if self.grid_lines.has_value() {
+                attributes.push(("gridLines", self.grid_lines.get_value_string()));
+            }
             write_start_tag(writer, "printOptions", attributes, true);
         }
     }
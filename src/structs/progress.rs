@@ -0,0 +1,32 @@
+/// Progress of a read or a write, passed to the progress observer.
+/// A part is a worksheet.
+#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Progress {
+    parts_processed: usize,
+    parts_total: usize,
+    bytes: u64,
+}
+impl Progress {
+    pub(crate) fn new(parts_processed: usize, parts_total: usize, bytes: u64) -> Self {
+        Self {
+            parts_processed,
+            parts_total,
+            bytes,
+        }
+    }
+
+    /// Number of worksheets read or written so far.
+    pub fn get_parts_processed(&self) -> &usize {
+        &self.parts_processed
+    }
+
+    /// Number of worksheets in the workbook.
+    pub fn get_parts_total(&self) -> &usize {
+        &self.parts_total
+    }
+
+    /// Uncompressed bytes of the xml parsed (read) or added to the package (write) so far.
+    pub fn get_bytes(&self) -> &u64 {
+        &self.bytes
+    }
+}
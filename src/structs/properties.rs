@@ -26,6 +26,8 @@ pub struct Properties {
     company: StringValue,
     revision: StringValue,
     version: StringValue,
+    application: StringValue,
+    app_version: StringValue,
     custom_properties: CustomProperties,
 }
 impl Default for Properties {
@@ -48,6 +50,8 @@ impl Default for Properties {
             company: StringValue::default(),
             revision: StringValue::default(),
             version: StringValue::default(),
+            application: StringValue::default(),
+            app_version: StringValue::default(),
             custom_properties: CustomProperties::default(),
         }
     }
@@ -62,6 +66,20 @@ impl Properties {
         self
     }
 
+    /// Get Author.
+    /// Alias of [`Properties::get_creator`].
+    pub fn get_author(&self) -> &str {
+        self.get_creator()
+    }
+
+    /// Set Author.
+    /// Alias of [`Properties::set_creator`].
+    /// # Arguments
+    /// * `value` - author name.
+    pub fn set_author<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.set_creator(value)
+    }
+
     pub fn get_last_modified_by(&self) -> &str {
         &self.last_modified_by.get_value_str()
     }
@@ -170,6 +188,34 @@ impl Properties {
         self
     }
 
+    /// Get Application.
+    /// Returns "Microsoft Excel" when not set.
+    pub fn get_application(&self) -> &str {
+        self.application.get_value().unwrap_or("Microsoft Excel")
+    }
+
+    /// Set Application.
+    /// # Arguments
+    /// * `value` - name of the application that created the file.
+    pub fn set_application<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.application.set_value(value);
+        self
+    }
+
+    /// Get App Version.
+    /// Returns "14.0300" when not set.
+    pub fn get_app_version(&self) -> &str {
+        self.app_version.get_value().unwrap_or("14.0300")
+    }
+
+    /// Set App Version.
+    /// # Arguments
+    /// * `value` - version of the application (ex. "16.0300").
+    pub fn set_app_version<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.app_version.set_value(value);
+        self
+    }
+
     pub fn get_custom_properties(&self) -> &CustomProperties {
         &self.custom_properties
     }
@@ -187,12 +233,12 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::from("");
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                value = e.unescape().unwrap().to_string();
+                value = e.unescape()?.to_string();
             },
             Event::End(ref e) => match e.name().into_inner() {
                 b"dc:title" => {self.set_title(std::mem::take(&mut value));},
@@ -210,7 +256,7 @@ impl Properties {
                 b"Company" => {self.set_company(std::mem::take(&mut value));},
                 _ => {}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -218,7 +264,7 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::from("");
         xml_read_loop!(
             reader,
@@ -226,18 +272,22 @@ impl Properties {
                 match e.name().into_inner(){
                     b"Manager" => {value = String::from("");},
                     b"Company" => {value = String::from("");},
+                    b"Application" => {value = String::from("");},
+                    b"AppVersion" => {value = String::from("");},
                     _ => {}
                 }
             },
             Event::Text(e) => {
-                value = e.unescape().unwrap().to_string();
+                value = e.unescape()?.to_string();
             },
             Event::End(ref e) => match e.name().into_inner() {
                 b"Manager" => {self.set_manager(std::mem::take(&mut value));}
                 b"Company" => {self.set_company(std::mem::take(&mut value));}
+                b"Application" => {self.set_application(std::mem::take(&mut value));}
+                b"AppVersion" => {self.set_app_version(std::mem::take(&mut value));}
                 _ =>{}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -245,10 +295,11 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = CustomProperties::default();
-        obj.set_attributes(reader, e);
+        obj.set_attributes(reader, e)?;
         self.set_custom_properties(obj);
+        Ok(())
     }
 
     pub(crate) fn write_to_core(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -373,7 +424,7 @@ impl Properties {
 
         // Application
         write_start_tag(writer, "Application", vec![], false);
-        write_text_node(writer, "Microsoft Excel");
+        write_text_node(writer, self.get_application());
         write_end_tag(writer, "Application");
 
         // DocSecurity
@@ -470,7 +521,7 @@ impl Properties {
 
         // AppVersion
         write_start_tag(writer, "AppVersion", vec![], false);
-        write_text_node(writer, "14.0300");
+        write_text_node(writer, self.get_app_version());
         write_end_tag(writer, "AppVersion");
 
         write_end_tag(writer, "Properties");
@@ -0,0 +1,72 @@
+// queryTable
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use reader::driver::*;
+use structs::BooleanValue;
+use structs::StringValue;
+use structs::UInt32Value;
+
+/// Query table of a table refreshed from an external connection.
+/// The part is kept as is.
+#[derive(Clone, Default, Debug)]
+pub struct QueryTable {
+    name: StringValue,
+    connection_id: UInt32Value,
+    auto_format_id: UInt32Value,
+    refresh_on_load: BooleanValue,
+    raw_data: Vec<u8>,
+}
+
+impl QueryTable {
+    pub fn get_name(&self) -> &str {
+        self.name.get_value_str()
+    }
+
+    /// Get id of the connection (see `Spreadsheet::get_connections`).
+    pub fn get_connection_id(&self) -> &u32 {
+        self.connection_id.get_value()
+    }
+
+    pub fn get_auto_format_id(&self) -> &u32 {
+        self.auto_format_id.get_value()
+    }
+
+    pub fn get_refresh_on_load(&self) -> &bool {
+        self.refresh_on_load.get_value()
+    }
+
+    pub(crate) fn get_raw_data(&self) -> &Vec<u8> {
+        &self.raw_data
+    }
+
+    pub(crate) fn set_raw_data(&mut self, data: &[u8]) -> Result<&mut Self, XlsxError> {
+        self.raw_data = data.to_vec();
+
+        let mut reader = Reader::from_reader(data);
+        reader.config_mut().trim_text(true);
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"queryTable" {
+                    self.set_attributes(e)?;
+                    return Ok(self);
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"queryTable" {
+                    self.set_attributes(e)?;
+                    return Ok(self);
+                }
+            },
+            Event::Eof => return Err(eof_error("queryTable"))
+        );
+    }
+
+    fn set_attributes(&mut self, e: &BytesStart) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        set_number_from_xml!(self, e, connection_id, "connectionId");
+        set_number_from_xml!(self, e, auto_format_id, "autoFormatId");
+        set_string_from_xml!(self, e, refresh_on_load, "refreshOnLoad");
+        Ok(())
+    }
+}
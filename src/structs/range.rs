@@ -4,7 +4,7 @@ use helper::coordinate::*;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentValue;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Range {
     start_col: Option<ColumnReference>,
     start_row: Option<RowReference>,
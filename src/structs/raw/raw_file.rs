@@ -5,7 +5,7 @@ use structs::StringValue;
 use structs::WriterManager;
 use XlsxError;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub(crate) struct RawFile {
     file_target: StringValue,
     file_data: Vec<u8>,
@@ -61,14 +61,22 @@ impl RawFile {
         arv: &mut zip::read::ZipArchive<R>,
         base_path: &str,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         let path_str = join_paths(base_path, target);
-        let mut r = io::BufReader::new(arv.by_name(&path_str).unwrap());
         let mut buf = Vec::new();
-        r.read_to_end(&mut buf).unwrap();
+        match arv.by_name(&path_str) {
+            Ok(v) => {
+                io::BufReader::new(v).read_to_end(&mut buf)?;
+            }
+            // a missing part is kept empty and is not written back.
+            // (the reader fails on it unless it recovers from broken parts.)
+            Err(zip::result::ZipError::FileNotFound) => {}
+            Err(e) => return Err(e.into()),
+        }
 
         self.set_file_target(path_str);
         self.set_file_data(&buf);
+        Ok(())
     }
 
     pub(crate) fn write_to<W: io::Seek + io::Write>(
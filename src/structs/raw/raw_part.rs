@@ -0,0 +1,127 @@
+use std::io;
+use structs::raw::RawFile;
+use structs::raw::RawRelationships;
+use structs::StringValue;
+use structs::WriterManager;
+use structs::XlsxError;
+
+/// A workbook part that is kept as is (with its relationships).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RawPart {
+    r_type: StringValue,
+    part_file: RawFile,
+    relationships_list: Vec<RawRelationships>,
+}
+impl RawPart {
+    pub(crate) fn get_type(&self) -> &str {
+        self.r_type.get_value_str()
+    }
+
+    pub(crate) fn set_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.r_type.set_value(value);
+        self
+    }
+
+    pub(crate) fn get_part_file(&self) -> &RawFile {
+        &self.part_file
+    }
+
+    pub(crate) fn get_part_file_mut(&mut self) -> &mut RawFile {
+        &mut self.part_file
+    }
+
+    /// Target from the workbook. ex) `richData/rdrichvalue.xml`
+    pub(crate) fn get_target(&self) -> String {
+        let target = self.part_file.get_file_target();
+        match target.strip_prefix("xl/") {
+            Some(v) => v.to_string(),
+            None => format!("../{}", target),
+        }
+    }
+
+    /// Target from the package root. ex) `customUI/customUI14.xml`
+    pub(crate) fn get_root_target(&self) -> &str {
+        self.part_file.get_file_target()
+    }
+
+    pub(crate) fn get_relationships_list(&self) -> &Vec<RawRelationships> {
+        &self.relationships_list
+    }
+
+    /// Target of a part that is not in the package.
+    pub(crate) fn get_missing_part(&self) -> Option<&str> {
+        if self.part_file.get_file_data().is_empty() {
+            return Some(self.part_file.get_file_target());
+        }
+        self.relationships_list
+            .iter()
+            .find_map(|v| v.get_missing_part())
+    }
+
+    pub(crate) fn read<R: io::Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<R>,
+        r_type: &str,
+        target: &str,
+    ) -> Result<(), XlsxError> {
+        self.read_from(arv, r_type, "xl", target)
+    }
+
+    /// Read a part referenced from the package root (_rels/.rels).
+    pub(crate) fn read_root<R: io::Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<R>,
+        r_type: &str,
+        target: &str,
+    ) -> Result<(), XlsxError> {
+        self.read_from(arv, r_type, ".", target)
+    }
+
+    fn read_from<R: io::Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<R>,
+        r_type: &str,
+        base_path: &str,
+        target: &str,
+    ) -> Result<(), XlsxError> {
+        self.set_type(r_type);
+        self.get_part_file_mut()
+            .set_attributes(arv, base_path, target)?;
+
+        let base_path = self.get_part_file().get_path();
+        let target = self.get_part_file().make_rel_name();
+        self.read_rawrelationships(arv, &base_path, &target)
+    }
+
+    fn read_rawrelationships<R: io::Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<R>,
+        base_path: &str,
+        target: &str,
+    ) -> Result<(), XlsxError> {
+        let mut obj = RawRelationships::default();
+        if obj.set_attributes(arv, base_path, target)? {
+            for relationship in obj.get_relationship_list() {
+                if relationship.get_target_mode() == "External" {
+                    continue;
+                }
+                let rels_base_path = relationship.get_raw_file().get_path();
+                let rels_target = relationship.get_raw_file().make_rel_name();
+                self.read_rawrelationships(arv, &rels_base_path, &rels_target)?;
+            }
+            self.relationships_list.push(obj);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn write<W: io::Seek + io::Write>(
+        &self,
+        writer_mng: &mut WriterManager<W>,
+    ) -> Result<(), XlsxError> {
+        self.get_part_file().write_to(writer_mng)?;
+        for relationships in self.get_relationships_list() {
+            relationships.write_to(writer_mng, None)?;
+        }
+        Ok(())
+    }
+}
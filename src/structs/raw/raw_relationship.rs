@@ -10,7 +10,7 @@ use structs::WriterManager;
 use structs::XlsxError;
 use writer::driver::*;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) struct RawRelationship {
     id: StringValue,
     r_type: StringValue,
@@ -75,18 +75,21 @@ impl RawRelationship {
         e: &BytesStart,
         arv: &mut zip::read::ZipArchive<A>,
         base_path: &str,
-    ) {
-        self.set_id(get_attribute(e, b"Id").unwrap());
-        self.set_type(get_attribute(e, b"Type").unwrap());
-        self.set_target(get_attribute(e, b"Target").unwrap());
-        if let Some(v) = get_attribute(e, b"TargetMode") {
+    ) -> Result<(), XlsxError> {
+        self.set_id(get_required_attribute(e, b"Id")?);
+        self.set_type(normalize_relationship_type(get_required_attribute(
+            e, b"Type",
+        )?));
+        self.set_target(get_required_attribute(e, b"Target")?);
+        if let Some(v) = get_attribute(e, b"TargetMode")? {
             self.set_target_mode(v);
         }
         if self.get_target_mode() != "External" {
             let target = self.get_target().to_string();
             self.get_raw_file_mut()
-                .set_attributes(arv, base_path, &target);
+                .set_attributes(arv, base_path, &target)?;
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
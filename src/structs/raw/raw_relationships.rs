@@ -11,7 +11,7 @@ use structs::WriterManager;
 use structs::XlsxError;
 use writer::driver::*;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) struct RawRelationships {
     file_target: StringValue,
     relationship_list: Vec<RawRelationship>,
@@ -41,13 +41,24 @@ impl RawRelationships {
         &mut self.relationship_list
     }
 
-    pub(crate) fn get_relationship_by_rid(&self, r_id: &str) -> &RawRelationship {
-        for relationship in self.get_relationship_list() {
-            if relationship.get_id() == r_id {
-                return relationship;
-            }
-        }
-        panic!("not found relationship as {}.", r_id);
+    pub(crate) fn get_relationship_by_rid(
+        &self,
+        r_id: &str,
+    ) -> Result<&RawRelationship, XlsxError> {
+        self.get_relationship_list()
+            .iter()
+            .find(|relationship| relationship.get_id() == r_id)
+            .ok_or_else(|| XlsxError::Format(format!("not found relationship as {}.", r_id)))
+    }
+
+    /// Target of a referenced part that is not in the package.
+    pub(crate) fn get_missing_part(&self) -> Option<&str> {
+        self.relationship_list
+            .iter()
+            .filter(|v| v.get_target_mode() != "External")
+            .map(|v| v.get_raw_file())
+            .find(|v| v.get_file_data().is_empty())
+            .map(|v| v.get_file_target())
     }
 
     pub(crate) fn add_relationship_list(&mut self, value: RawRelationship) -> &mut Self {
@@ -60,19 +71,19 @@ impl RawRelationships {
         arv: &mut zip::read::ZipArchive<R>,
         base_path: &str,
         target: &str,
-    ) -> bool {
+    ) -> Result<bool, XlsxError> {
         let data = {
             let path_str = join_paths(base_path, target);
             let file_path = match arv.by_name(&path_str) {
                 Ok(v) => v,
                 Err(_) => {
-                    return false;
+                    return Ok(false);
                 }
             };
             self.set_file_target(path_str);
             let mut r = io::BufReader::new(file_path);
             let mut buf = Vec::new();
-            r.read_to_end(&mut buf).unwrap();
+            r.read_to_end(&mut buf)?;
             std::io::Cursor::new(buf)
         };
         let mut reader = Reader::from_reader(data);
@@ -83,14 +94,14 @@ impl RawRelationships {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"Relationship" {
                     let mut obj = RawRelationship::default();
-                    obj.set_attributes(&mut reader, e, arv, base_path);
+                    obj.set_attributes(&mut reader, e, arv, base_path)?;
                     self.add_relationship_list(obj);
                 }
             },
             Event::Eof => break
         );
 
-        true
+        Ok(true)
     }
 
     pub(crate) fn write_to<W: io::Seek + io::Write>(
@@ -46,25 +46,54 @@ impl RawWorksheet {
         })
     }
 
-    pub(crate) fn get_vml_drawing_relationships(&self) -> Option<&RawRelationships> {
-        self.get_relationships_list().iter().find(|&relationships| {
-            relationships
-                .get_file_target()
-                .starts_with(PKG_VML_DRAWING_RELS)
-        })
+    pub(crate) fn get_relationships_of_file(
+        &self,
+        raw_file: &RawFile,
+    ) -> Option<&RawRelationships> {
+        let file_target = format!("{}/{}", raw_file.get_path(), raw_file.make_rel_name());
+        self.get_relationships_list()
+            .iter()
+            .find(|&relationships| relationships.get_file_target() == file_target)
+    }
+
+    /// The relationships of `raw_file` and, in turn, of the parts they refer to.
+    pub(crate) fn get_relationships_tree_of_file(
+        &self,
+        raw_file: &RawFile,
+    ) -> Vec<RawRelationships> {
+        let mut result: Vec<RawRelationships> = Vec::new();
+        if let Some(relationships) = self.get_relationships_of_file(raw_file) {
+            result.push(relationships.clone());
+            for relationship in relationships.get_relationship_list() {
+                if relationship.get_target_mode() != "External" {
+                    result.extend(self.get_relationships_tree_of_file(relationship.get_raw_file()));
+                }
+            }
+        }
+        result
+    }
+
+    /// Target of a part of the worksheet that is not in the package.
+    pub(crate) fn get_missing_part(&self) -> Option<&str> {
+        if self.worksheet_file.get_file_data().is_empty() {
+            return Some(self.worksheet_file.get_file_target());
+        }
+        self.relationships_list
+            .iter()
+            .find_map(|v| v.get_missing_part())
     }
 
     pub(crate) fn read<R: io::Read + io::Seek>(
         &mut self,
         arv: &mut zip::read::ZipArchive<R>,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.get_worksheet_file_mut()
-            .set_attributes(arv, "xl", target);
+            .set_attributes(arv, "xl", target)?;
 
         let base_path = self.get_worksheet_file().get_path();
         let target = self.get_worksheet_file().make_rel_name();
-        self.read_rawrelationships(arv, &base_path, &target);
+        self.read_rawrelationships(arv, &base_path, &target)
     }
 
     pub(crate) fn read_rawrelationships<R: io::Read + io::Seek>(
@@ -72,16 +101,17 @@ impl RawWorksheet {
         arv: &mut zip::read::ZipArchive<R>,
         base_path: &str,
         target: &str,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = RawRelationships::default();
-        if obj.set_attributes(arv, base_path, target) {
+        if obj.set_attributes(arv, base_path, target)? {
             for relationship in obj.get_relationship_list() {
                 let rels_base_path = relationship.get_raw_file().get_path();
                 let rels_target = relationship.get_raw_file().make_rel_name();
-                self.read_rawrelationships(arv, &rels_base_path, &rels_target);
+                self.read_rawrelationships(arv, &rels_base_path, &rels_target)?;
             }
             self.set_relationships(obj);
         }
+        Ok(())
     }
 
     pub(crate) fn write<W: io::Seek + io::Write>(
@@ -0,0 +1,94 @@
+use structs::XlsxError;
+
+/// Limits enforced while reading a file, to accept untrusted files safely.
+/// A limit that is not set is not checked. Exceeding a limit is `XlsxError::LimitExceeded`.
+/// # Examples
+/// ```
+/// let mut limits = umya_spreadsheet::ReadLimits::default();
+/// limits.set_max_part_size(50 * 1024 * 1024);
+/// limits.set_max_cell_count(1_000_000);
+/// limits.set_max_string_length(32_767);
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let book = umya_spreadsheet::reader::xlsx::read_with_limits(path, &limits).unwrap();
+/// ```
+#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ReadLimits {
+    max_part_size: Option<u64>,
+    max_cell_count: Option<usize>,
+    max_string_length: Option<usize>,
+}
+impl ReadLimits {
+    /// Get Max Part Size.
+    pub fn get_max_part_size(&self) -> Option<&u64> {
+        self.max_part_size.as_ref()
+    }
+
+    /// Set Max Part Size.
+    /// # Arguments
+    /// * `value` - Maximum uncompressed size in bytes of each part of the package.
+    pub fn set_max_part_size(&mut self, value: u64) -> &mut Self {
+        self.max_part_size = Some(value);
+        self
+    }
+
+    pub fn remove_max_part_size(&mut self) -> &mut Self {
+        self.max_part_size = None;
+        self
+    }
+
+    /// Get Max Cell Count.
+    pub fn get_max_cell_count(&self) -> Option<&usize> {
+        self.max_cell_count.as_ref()
+    }
+
+    /// Set Max Cell Count.
+    /// # Arguments
+    /// * `value` - Maximum number of cells of each worksheet.
+    pub fn set_max_cell_count(&mut self, value: usize) -> &mut Self {
+        self.max_cell_count = Some(value);
+        self
+    }
+
+    pub fn remove_max_cell_count(&mut self) -> &mut Self {
+        self.max_cell_count = None;
+        self
+    }
+
+    /// Get Max String Length.
+    pub fn get_max_string_length(&self) -> Option<&usize> {
+        self.max_string_length.as_ref()
+    }
+
+    /// Set Max String Length.
+    /// # Arguments
+    /// * `value` - Maximum number of characters of a shared string or a cell value.
+    pub fn set_max_string_length(&mut self, value: usize) -> &mut Self {
+        self.max_string_length = Some(value);
+        self
+    }
+
+    pub fn remove_max_string_length(&mut self) -> &mut Self {
+        self.max_string_length = None;
+        self
+    }
+
+    pub(crate) fn check_cell_count(&self, count: usize) -> Result<(), XlsxError> {
+        match self.max_cell_count {
+            Some(v) if count > v => Err(XlsxError::LimitExceeded(format!(
+                "the worksheet has more than {} cells",
+                v
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_string_length(&self, value: &str) -> Result<(), XlsxError> {
+        match self.max_string_length {
+            Some(v) if value.chars().count() > v => Err(XlsxError::LimitExceeded(format!(
+                "a string is longer than {} characters",
+                v
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use super::PhoneticProperties;
+use super::PhoneticRun;
 use super::TextElement;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
@@ -12,6 +14,8 @@ use writer::driver::*;
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct RichText {
     rich_text_elements: Vec<TextElement>,
+    phonetic_runs: Vec<PhoneticRun>,
+    phonetic_properties: Option<PhoneticProperties>,
 }
 
 impl RichText {
@@ -49,11 +53,64 @@ impl RichText {
         self
     }
 
+    /// Get Phonetic Runs (furigana).
+    pub fn get_phonetic_runs(&self) -> &Vec<PhoneticRun> {
+        &self.phonetic_runs
+    }
+
+    /// Get Phonetic Runs (furigana) in mutable.
+    pub fn get_phonetic_runs_mut(&mut self) -> &mut Vec<PhoneticRun> {
+        &mut self.phonetic_runs
+    }
+
+    /// Add Phonetic Run (furigana).
+    /// # Arguments
+    /// * `value` - PhoneticRun.
+    pub fn add_phonetic_run(&mut self, value: PhoneticRun) -> &mut Self {
+        self.phonetic_runs.push(value);
+        self
+    }
+
+    /// Get Phonetic Properties.
+    pub fn get_phonetic_properties(&self) -> Option<&PhoneticProperties> {
+        self.phonetic_properties.as_ref()
+    }
+
+    /// Get Phonetic Properties in mutable.
+    pub fn get_phonetic_properties_mut(&mut self) -> &mut PhoneticProperties {
+        self.phonetic_properties
+            .get_or_insert_with(PhoneticProperties::default)
+    }
+
+    /// Set Phonetic Properties.
+    /// # Arguments
+    /// * `value` - PhoneticProperties.
+    pub fn set_phonetic_properties(&mut self, value: PhoneticProperties) -> &mut Self {
+        self.phonetic_properties = Some(value);
+        self
+    }
+
+    /// Remove Phonetic Properties.
+    pub fn remove_phonetic_properties(&mut self) -> &mut Self {
+        self.phonetic_properties = None;
+        self
+    }
+
+    pub(crate) fn has_phonetic(&self) -> bool {
+        !self.phonetic_runs.is_empty()
+    }
+
     pub(crate) fn get_hash_code(&self) -> String {
         let mut value = String::from("");
         for ele in &self.rich_text_elements {
             write!(value, "{}", ele.get_hash_code());
         }
+        for ele in &self.phonetic_runs {
+            write!(value, "{}", ele.get_hash_code());
+        }
+        if let Some(v) = &self.phonetic_properties {
+            write!(value, "{}", v.get_hash_code());
+        }
         format!("{:x}", md5::Md5::digest(&value))
     }
 
@@ -61,22 +118,22 @@ impl RichText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"r" {
                     let mut obj = TextElement::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_rich_text_elements(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "text")
+            Event::Eof => return Err(eof_error("text"))
         );
     }
 
@@ -2,6 +2,7 @@ use super::BooleanValue;
 use super::Cell;
 use super::Cells;
 use super::DoubleValue;
+use super::ReadLimits;
 use super::SharedStringTable;
 use super::Style;
 use super::Stylesheet;
@@ -12,7 +13,8 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
-use std::io::Cursor;
+use std::io;
+use std::sync::Arc;
 use traits::AdjustmentValue;
 use writer::driver::*;
 
@@ -24,7 +26,7 @@ pub struct Row {
     thick_bot: BooleanValue,
     custom_height: BooleanValue,
     hidden: BooleanValue,
-    style: Style,
+    style: Arc<Style>,
 }
 
 impl Row {
@@ -88,10 +90,23 @@ impl Row {
     }
 
     pub fn get_style_mut(&mut self) -> &mut Style {
-        &mut self.style
+        Arc::make_mut(&mut self.style)
     }
 
+    /// Set the default style of the row.
+    /// Cells created in the row afterwards start with this style.
     pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Arc::new(value);
+        self
+    }
+
+    /// Get the shared handle of the style.
+    pub fn get_style_shared(&self) -> &Arc<Style> {
+        &self.style
+    }
+
+    /// Set a shared style handle.
+    pub fn set_style_shared(&mut self, value: Arc<Style>) -> &mut Self {
         self.style = value;
         self
     }
@@ -105,26 +120,27 @@ impl Row {
         stylesheet: &Stylesheet,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
         empty_flag: bool,
-    ) {
-        set_string_from_xml!(self, e, row_num, "r");
+        limits: &ReadLimits,
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, row_num, "r");
         set_string_from_xml!(self, e, height, "ht");
         set_string_from_xml!(self, e, thick_bot, "thickBot");
         set_string_from_xml!(self, e, custom_height, "customHeight");
         set_string_from_xml!(self, e, hidden, "hidden");
 
-        if let Some(v) = get_attribute(e, b"x14ac:dyDescent") {
+        if let Some(v) = get_attribute(e, b"x14ac:dyDescent")? {
             if !v.is_empty() {
                 self.descent.set_value_string(v);
             }
         }
 
-        if let Some(v) = get_attribute(e, b"s") {
-            let style = stylesheet.get_style(v.parse::<usize>().unwrap());
-            self.set_style(style);
+        if let Some(v) = get_attribute(e, b"s")? {
+            let style = stylesheet.get_style(v.parse::<usize>().map_err(parse_error)?);
+            self.set_style_shared(style);
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -132,35 +148,36 @@ impl Row {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"c" {
                     let mut obj = Cell::default();
-                    obj.set_attributes(reader, e, shared_string_table, stylesheet, true, formula_shared_list);
+                    obj.set_attributes(reader, e, shared_string_table, stylesheet, true, formula_shared_list, limits)?;
                     cells.set_fast(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c" {
                     let mut obj = Cell::default();
-                    obj.set_attributes(reader, e, shared_string_table, stylesheet, false, formula_shared_list);
+                    obj.set_attributes(reader, e, shared_string_table, stylesheet, false, formula_shared_list, limits)?;
                     cells.set_fast(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "row")
+            Event::Eof => return Err(eof_error("row"))
         );
     }
 
-    pub(crate) fn write_to(
+    pub(crate) fn write_to<W: io::Write>(
         &self,
-        writer: &mut Writer<Cursor<Vec<u8>>>,
+        writer: &mut Writer<W>,
         stylesheet: &mut Stylesheet,
         spans: String,
         empty_flag: bool,
+        has_dy_descent: bool,
     ) {
         let xf_index_str: String;
-        let xf_index = stylesheet.set_style(self.get_style());
+        let xf_index = stylesheet.set_style_shared(self.get_style_shared());
 
         // row
         let mut attributes: Vec<(&str, &str)> = Vec::new();
@@ -186,7 +203,7 @@ impl Row {
             attributes.push(("hidden", self.hidden.get_value_string()));
         }
         let descent = self.descent.get_value_string();
-        if self.descent.has_value() {
+        if has_dy_descent && self.descent.has_value() {
             attributes.push(("x14ac:dyDescent", &descent));
         }
 
@@ -34,21 +34,21 @@ impl RowBreaks {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"brk" {
                     let mut obj = Break::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_break_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rowBreaks" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rowBreaks")
+            Event::Eof => return Err(eof_error("rowBreaks"))
         );
     }
 
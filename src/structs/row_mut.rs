@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use structs::Cell;
+use structs::Row;
+use structs::Style;
+use structs::Worksheet;
+use traits::IntoCellValue;
+
+/// Mutable access to one row of a worksheet.
+/// Get it with `Worksheet::get_row_mut`.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let worksheet = book.get_sheet_mut(&0).unwrap();
+/// let mut row = worksheet.get_row_mut(&5);
+/// row.set_height(30.0).set_values(&["Name", "Price"]);
+/// assert_eq!(row.iter_cells().count(), 2);
+/// assert_eq!(worksheet.get_value("B5"), "Price");
+/// ```
+pub struct RowMut<'a> {
+    worksheet: &'a mut Worksheet,
+    row_num: u32,
+}
+impl<'a> RowMut<'a> {
+    pub(crate) fn new(worksheet: &'a mut Worksheet, row_num: u32) -> Self {
+        Self { worksheet, row_num }
+    }
+
+    pub fn get_row_num(&self) -> &u32 {
+        &self.row_num
+    }
+
+    /// Get the row dimension.
+    pub fn get_row_dimension(&self) -> Option<&Row> {
+        self.worksheet.get_row_dimension(&self.row_num)
+    }
+
+    /// Get the row dimension in mutable. It is created if missing.
+    pub fn get_row_dimension_mut(&mut self) -> &mut Row {
+        self.worksheet.get_row_dimension_mut(&self.row_num)
+    }
+
+    pub fn set_height(&mut self, value: f64) -> &mut Self {
+        self.get_row_dimension_mut().set_height(value);
+        self
+    }
+
+    pub fn hide(&mut self) -> &mut Self {
+        self.get_row_dimension_mut().set_hidden(true);
+        self
+    }
+
+    pub fn unhide(&mut self) -> &mut Self {
+        self.get_row_dimension_mut().set_hidden(false);
+        self
+    }
+
+    /// Set the style of the row and of every cell already in it.
+    /// # Arguments
+    /// * `value` - Style.
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        let style = Arc::new(value);
+        let row_num = self.row_num;
+        for cell in self.worksheet.get_cell_collection_mut() {
+            if cell.get_coordinate().get_row_num() == &row_num {
+                cell.set_style_shared(style.clone());
+            }
+        }
+        self.get_row_dimension_mut().set_style_shared(style);
+        self
+    }
+
+    /// Cells of the row, left to right.
+    pub fn iter_cells(&self) -> impl Iterator<Item = &Cell> {
+        let mut cells = self.worksheet.get_collection_by_row(&self.row_num);
+        cells.sort_by_key(|v| *v.get_coordinate().get_col_num());
+        cells.into_iter()
+    }
+
+    /// Cells of the row in mutable, left to right.
+    pub fn iter_cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        let row_num = self.row_num;
+        let mut cells: Vec<&mut Cell> = self
+            .worksheet
+            .get_cell_collection_mut()
+            .into_iter()
+            .filter(|v| v.get_coordinate().get_row_num() == &row_num)
+            .collect();
+        cells.sort_by_key(|v| *v.get_coordinate().get_col_num());
+        cells.into_iter()
+    }
+
+    /// Set values from column A to the right.
+    /// # Arguments
+    /// * `values` - Values. ex) `&[1, 2, 3]` or `&["a", "b"]`
+    pub fn set_values<V>(&mut self, values: &[V]) -> &mut Self
+    where
+        V: IntoCellValue + Clone,
+    {
+        self.worksheet.set_values((1, self.row_num), &[values]);
+        self
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Clone, Default, Debug)]
+use XlsxError;
+
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct SByteValue {
     value: Option<i8>,
 }
@@ -16,8 +18,15 @@ impl SByteValue {
         self
     }
 
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut SByteValue {
-        self.set_value(value.into().parse::<i8>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut SByteValue, XlsxError> {
+        let value = value.into();
+        match value.parse::<i8>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn _has_value(&self) -> bool {
@@ -2,6 +2,7 @@ use super::Coordinate;
 use super::EnumValue;
 use super::PaneValues;
 use super::SequenceOfReferences;
+use helper::formula::is_in_range;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -52,38 +53,65 @@ impl Selection {
         self
     }
 
+    /// Whether the selected ranges contain the cell.
+    /// # Arguments
+    /// * `col` - Column number.
+    /// * `row` - Row number.
+    pub fn contains(&self, col: &u32, row: &u32) -> bool {
+        self.sequence_of_references
+            .get_range_collection()
+            .iter()
+            .any(|range| is_in_range(&range.get_range(), col, row))
+    }
+
+    /// (This method is crate only.)
+    /// Name of the pane of the selection ("topLeft" when omitted).
+    pub(crate) fn get_pane_str(&self) -> &str {
+        if self.pane.has_value() {
+            self.pane.get_value_string()
+        } else {
+            "topLeft"
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, pane, "pane");
 
-        if let Some(v) = get_attribute(e, b"activeCell") {
+        if let Some(v) = get_attribute(e, b"activeCell")? {
             let mut obj = Coordinate::default();
             obj.set_coordinate(v);
             self.set_active_cell(obj);
         }
 
-        if let Some(v) = get_attribute(e, b"sqref") {
+        if let Some(v) = get_attribute(e, b"sqref")? {
             self.sequence_of_references.set_sqref(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // selection
         let mut attributes: Vec<(&str, &str)> = Vec::new();
 
-        let mut active_cell_id = 0;
-        if let Some(active_cell) = &self.active_cell {
-            for range in self.sequence_of_references.get_range_collection() {
-                let range_str = range.get_range();
-                if range_str.contains(active_cell.to_string().as_str()) {
-                    break;
-                }
-                active_cell_id += 1;
-            }
-        }
+        let active_cell_id = match &self.active_cell {
+            Some(active_cell) => self
+                .sequence_of_references
+                .get_range_collection()
+                .iter()
+                .position(|range| {
+                    is_in_range(
+                        &range.get_range(),
+                        active_cell.get_col_num(),
+                        active_cell.get_row_num(),
+                    )
+                })
+                .unwrap_or(0),
+            None => 0,
+        };
 
         if self.pane.has_value() {
             attributes.push(("pane", self.pane.get_value_string()));
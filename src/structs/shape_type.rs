@@ -0,0 +1,114 @@
+use super::EnumTrait;
+use std::str::FromStr;
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShapeType {
+    Rectangle,
+    RoundRectangle,
+    Ellipse,
+    Triangle,
+    RightTriangle,
+    Diamond,
+    Parallelogram,
+    Trapezoid,
+    Pentagon,
+    Hexagon,
+    Octagon,
+    Star5,
+    Heart,
+    Cloud,
+    RightArrow,
+    LeftArrow,
+    UpArrow,
+    DownArrow,
+    LeftRightArrow,
+    Chevron,
+    HomePlate,
+    WedgeRectCallout,
+    WedgeEllipseCallout,
+    FlowChartProcess,
+    FlowChartDecision,
+    FlowChartTerminator,
+    FlowChartDocument,
+    FlowChartInputOutput,
+    FlowChartPredefinedProcess,
+    FlowChartConnector,
+}
+impl Default for ShapeType {
+    fn default() -> Self {
+        Self::Rectangle
+    }
+}
+impl EnumTrait for ShapeType {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Rectangle => "rect",
+            Self::RoundRectangle => "roundRect",
+            Self::Ellipse => "ellipse",
+            Self::Triangle => "triangle",
+            Self::RightTriangle => "rtTriangle",
+            Self::Diamond => "diamond",
+            Self::Parallelogram => "parallelogram",
+            Self::Trapezoid => "trapezoid",
+            Self::Pentagon => "pentagon",
+            Self::Hexagon => "hexagon",
+            Self::Octagon => "octagon",
+            Self::Star5 => "star5",
+            Self::Heart => "heart",
+            Self::Cloud => "cloud",
+            Self::RightArrow => "rightArrow",
+            Self::LeftArrow => "leftArrow",
+            Self::UpArrow => "upArrow",
+            Self::DownArrow => "downArrow",
+            Self::LeftRightArrow => "leftRightArrow",
+            Self::Chevron => "chevron",
+            Self::HomePlate => "homePlate",
+            Self::WedgeRectCallout => "wedgeRectCallout",
+            Self::WedgeEllipseCallout => "wedgeEllipseCallout",
+            Self::FlowChartProcess => "flowChartProcess",
+            Self::FlowChartDecision => "flowChartDecision",
+            Self::FlowChartTerminator => "flowChartTerminator",
+            Self::FlowChartDocument => "flowChartDocument",
+            Self::FlowChartInputOutput => "flowChartInputOutput",
+            Self::FlowChartPredefinedProcess => "flowChartPredefinedProcess",
+            Self::FlowChartConnector => "flowChartConnector",
+        }
+    }
+}
+impl FromStr for ShapeType {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "rect" => Ok(Self::Rectangle),
+            "roundRect" => Ok(Self::RoundRectangle),
+            "ellipse" => Ok(Self::Ellipse),
+            "triangle" => Ok(Self::Triangle),
+            "rtTriangle" => Ok(Self::RightTriangle),
+            "diamond" => Ok(Self::Diamond),
+            "parallelogram" => Ok(Self::Parallelogram),
+            "trapezoid" => Ok(Self::Trapezoid),
+            "pentagon" => Ok(Self::Pentagon),
+            "hexagon" => Ok(Self::Hexagon),
+            "octagon" => Ok(Self::Octagon),
+            "star5" => Ok(Self::Star5),
+            "heart" => Ok(Self::Heart),
+            "cloud" => Ok(Self::Cloud),
+            "rightArrow" => Ok(Self::RightArrow),
+            "leftArrow" => Ok(Self::LeftArrow),
+            "upArrow" => Ok(Self::UpArrow),
+            "downArrow" => Ok(Self::DownArrow),
+            "leftRightArrow" => Ok(Self::LeftRightArrow),
+            "chevron" => Ok(Self::Chevron),
+            "homePlate" => Ok(Self::HomePlate),
+            "wedgeRectCallout" => Ok(Self::WedgeRectCallout),
+            "wedgeEllipseCallout" => Ok(Self::WedgeEllipseCallout),
+            "flowChartProcess" => Ok(Self::FlowChartProcess),
+            "flowChartDecision" => Ok(Self::FlowChartDecision),
+            "flowChartTerminator" => Ok(Self::FlowChartTerminator),
+            "flowChartDocument" => Ok(Self::FlowChartDocument),
+            "flowChartInputOutput" => Ok(Self::FlowChartInputOutput),
+            "flowChartPredefinedProcess" => Ok(Self::FlowChartPredefinedProcess),
+            "flowChartConnector" => Ok(Self::FlowChartConnector),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,13 +1,16 @@
 // si
+use super::PhoneticProperties;
 use super::PhoneticRun;
 use super::RichText;
 use super::Text;
 use super::TextElement;
+use helper::sanitize::strip_control_characters;
 use md5::Digest;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
+use std::borrow::Cow;
 use std::hash::Hasher;
 use std::io::Cursor;
 use writer::driver::*;
@@ -57,6 +60,30 @@ impl SharedStringItem {
         self
     }
 
+    /// Copy of the item without the control characters not allowed in xml, if it has any.
+    pub(crate) fn get_stripped(&self) -> Option<Self> {
+        let mut item = self.clone();
+        let mut is_changed = false;
+        if let Some(v) = &mut item.text {
+            if let Cow::Owned(value) = strip_control_characters(v.get_value()) {
+                v.set_value(value);
+                is_changed = true;
+            }
+        }
+        if let Some(v) = &mut item.rich_text {
+            for element in v.get_rich_text_elements_mut() {
+                if let Cow::Owned(value) = strip_control_characters(element.get_text()) {
+                    element.set_text(value);
+                    is_changed = true;
+                }
+            }
+        }
+        match is_changed {
+            true => Some(item),
+            false => None,
+        }
+    }
+
     pub(crate) fn get_hash_u64(&self) -> u64 {
         let mut h = AHasher::default();
         let content = format!(
@@ -76,8 +103,10 @@ impl SharedStringItem {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut vec_text_element: Vec<TextElement> = Vec::new();
+        let mut vec_phonetic_run: Vec<PhoneticRun> = Vec::new();
+        let mut phonetic_properties: Option<PhoneticProperties> = None;
 
         xml_read_loop!(
             reader,
@@ -85,32 +114,56 @@ impl SharedStringItem {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_text(obj);
                     }
                     b"r" => {
                         let mut obj = TextElement::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         vec_text_element.push(obj);
                     }
                     b"rPh" => {
                         let mut obj = PhoneticRun::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
+                        vec_phonetic_run.push(obj);
                     }
                     _ => (),
                 }
             },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"phoneticPr" {
+                    let mut obj = PhoneticProperties::default();
+                    obj.set_attributes(reader, e)?;
+                    phonetic_properties = Some(obj);
+                }
+            },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"si" {
+                    if !vec_phonetic_run.is_empty() && vec_text_element.is_empty() {
+                        // keep furigana of plain text as a single run.
+                        if let Some(v) = self.text.take() {
+                            let mut obj = TextElement::default();
+                            obj.set_text(v.get_value());
+                            vec_text_element.push(obj);
+                        }
+                    }
                     if !vec_text_element.is_empty() {
                         let mut obj = RichText::default();
                         obj.set_rich_text_elements(vec_text_element);
+                        if !vec_phonetic_run.is_empty() {
+                            for run in vec_phonetic_run {
+                                obj.add_phonetic_run(run);
+                            }
+                            if let Some(v) = phonetic_properties {
+                                obj.set_phonetic_properties(v);
+                            }
+                        }
                         self.set_rich_text(obj);
                     }
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "si")
+            Event::Eof => return Err(eof_error("si"))
         );
     }
 
@@ -125,10 +178,31 @@ impl SharedStringItem {
 
         // r
         if let Some(v) = &self.rich_text {
-            v.write_to_none(writer);
+            let is_plain = v
+                .get_rich_text_elements()
+                .iter()
+                .all(|x| x.get_run_properties().is_none());
+            if !(self.text.is_some() && v.has_phonetic() && is_plain) {
+                v.write_to_none(writer);
+            }
+        }
+
+        // rPh
+        if let Some(v) = &self.rich_text {
+            for obj in v.get_phonetic_runs() {
+                obj.write_to(writer);
+            }
         }
 
-        write_start_tag(writer, "phoneticPr", vec![("fontId", "1")], true);
+        // phoneticPr
+        match self
+            .rich_text
+            .as_ref()
+            .and_then(|v| v.get_phonetic_properties())
+        {
+            Some(v) => v.write_to(writer),
+            None => write_start_tag(writer, "phoneticPr", vec![("fontId", "1")], true),
+        }
 
         write_end_tag(writer, "si");
     }
@@ -1,6 +1,7 @@
 // sst
 use super::drawing::Theme;
 use super::CellValue;
+use super::ReadLimits;
 use super::SharedStringItem;
 use drawing::charts::View3D;
 use hashbrown::HashMap;
@@ -39,7 +40,11 @@ impl SharedStringTable {
 
     pub(crate) fn set_cell(&mut self, value: &CellValue) -> usize {
         self.regist_count += 1;
+        self.register_cell(value)
+    }
 
+    /// Looks up (or adds) the item for `value` without counting a reference.
+    pub(crate) fn register_cell(&mut self, value: &CellValue) -> usize {
         let mut shared_string_item = SharedStringItem::default();
         if let Some(v) = value.get_text() {
             shared_string_item.set_text(v);
@@ -65,14 +70,21 @@ impl SharedStringTable {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+        limits: &ReadLimits,
+    ) -> Result<(), XlsxError> {
         let mut n: usize = 0;
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"si" {
                     let mut shared_string_item = SharedStringItem::default();
-                    shared_string_item.set_attributes(reader, e);
+                    shared_string_item.set_attributes(reader, e)?;
+                    if let Some(v) = shared_string_item.get_text() {
+                        limits.check_string_length(v.get_value())?;
+                    }
+                    if let Some(v) = shared_string_item.get_rich_text() {
+                        limits.check_string_length(&v.get_text())?;
+                    }
 
                     // set ThemeColor
                     if let Some(v) = shared_string_item.get_rich_text_mut() {
@@ -92,14 +104,14 @@ impl SharedStringTable {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sst")
+            Event::Eof => return Err(eof_error("sst"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, is_text_sanitized: bool) {
         // sst
         write_start_tag(
             writer,
@@ -117,7 +129,10 @@ impl SharedStringTable {
 
         // si
         for obj in &self.shared_string_item {
-            obj.write_to(writer);
+            match is_text_sanitized.then(|| obj.get_stripped()).flatten() {
+                Some(v) => v.write_to(writer),
+                None => obj.write_to(writer),
+            }
         }
 
         write_end_tag(writer, "sst");
@@ -115,19 +115,20 @@ impl SheetFormatProperties {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, base_column_width, "baseColWidth");
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, base_column_width, "baseColWidth");
         set_string_from_xml!(self, e, custom_height, "customHeight");
         set_string_from_xml!(self, e, default_column_width, "defaultColWidth");
         set_string_from_xml!(self, e, default_row_height, "defaultRowHeight");
         set_string_from_xml!(self, e, dy_descent, "x14ac:dyDescent");
-        set_string_from_xml!(self, e, outline_level_column, "outlineLevelCol");
-        set_string_from_xml!(self, e, outline_level_row, "outlineLevelRow");
+        set_number_from_xml!(self, e, outline_level_column, "outlineLevelCol");
+        set_number_from_xml!(self, e, outline_level_row, "outlineLevelRow");
         set_string_from_xml!(self, e, thick_bottom, "thickBottom");
         set_string_from_xml!(self, e, thick_top, "thickTop");
+        Ok(())
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, has_dy_descent: bool) {
         // sheetFormatPr
         let mut attributes: Vec<(&str, &str)> = Vec::new();
         let str_base_column_width = self.base_column_width.get_value_string();
@@ -151,7 +152,7 @@ impl SheetFormatProperties {
         }
 
         let str_dy_descent = self.dy_descent.get_value_string();
-        if self.dy_descent.has_value() {
+        if has_dy_descent && self.dy_descent.has_value() {
             attributes.push(("x14ac:dyDescent", &str_dy_descent));
         }
 
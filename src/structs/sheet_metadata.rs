@@ -0,0 +1,36 @@
+/// Sheet entry of `WorkbookMetadata`.
+#[derive(Clone, Default, Debug)]
+pub struct SheetMetadata {
+    name: String,
+    sheet_state: String,
+    dimension: Option<String>,
+}
+impl SheetMetadata {
+    pub(crate) fn new(name: &str, sheet_state: &str, dimension: Option<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            sheet_state: sheet_state.to_string(),
+            dimension,
+        }
+    }
+
+    /// Sheet name.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sheet state (`hidden`, `veryHidden`, or empty for a visible sheet).
+    pub fn get_sheet_state(&self) -> &str {
+        &self.sheet_state
+    }
+
+    /// Whether the sheet is visible.
+    pub fn is_visible(&self) -> bool {
+        self.sheet_state.is_empty() || self.sheet_state == "visible"
+    }
+
+    /// Used range written in the sheet (ex. "A1:C10"), if any.
+    pub fn get_dimension(&self) -> Option<&str> {
+        self.dimension.as_deref()
+    }
+}
@@ -0,0 +1,166 @@
+// sheetPr
+use super::BooleanValue;
+use super::StringValue;
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use reader::driver::*;
+
+/// Attributes of the sheet properties (sheetPr).
+/// The code name is set with `Worksheet::set_code_name` and the tab color with `Worksheet::set_tab_color`.
+#[derive(Clone, Default, Debug)]
+pub struct SheetProperties {
+    sync_horizontal: BooleanValue,
+    sync_vertical: BooleanValue,
+    sync_reference: StringValue,
+    transition_evaluation: BooleanValue,
+    transition_entry: BooleanValue,
+    published: BooleanValue,
+    filter_mode: BooleanValue,
+    enable_format_conditions_calculation: BooleanValue,
+}
+
+impl SheetProperties {
+    /// Scroll the windows of the sheet horizontally together.
+    pub fn get_sync_horizontal(&self) -> &bool {
+        self.sync_horizontal.get_value()
+    }
+
+    pub fn set_sync_horizontal(&mut self, value: bool) -> &mut Self {
+        self.sync_horizontal.set_value(value);
+        self
+    }
+
+    /// Scroll the windows of the sheet vertically together.
+    pub fn get_sync_vertical(&self) -> &bool {
+        self.sync_vertical.get_value()
+    }
+
+    pub fn set_sync_vertical(&mut self, value: bool) -> &mut Self {
+        self.sync_vertical.set_value(value);
+        self
+    }
+
+    /// Top left cell the synchronized windows scroll from. ex) `"A1"`
+    pub fn get_sync_reference(&self) -> &str {
+        self.sync_reference.get_value_str()
+    }
+
+    pub fn set_sync_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sync_reference.set_value(value);
+        self
+    }
+
+    /// Use the Lotus 1-2-3 expression evaluation rules (Transition formula evaluation).
+    pub fn get_transition_evaluation(&self) -> &bool {
+        self.transition_evaluation.get_value()
+    }
+
+    pub fn set_transition_evaluation(&mut self, value: bool) -> &mut Self {
+        self.transition_evaluation.set_value(value);
+        self
+    }
+
+    /// Use the Lotus 1-2-3 formula entry rules (Transition formula entry).
+    pub fn get_transition_entry(&self) -> &bool {
+        self.transition_entry.get_value()
+    }
+
+    pub fn set_transition_entry(&mut self, value: bool) -> &mut Self {
+        self.transition_entry.set_value(value);
+        self
+    }
+
+    /// The sheet is published. The default value is true.
+    pub fn get_published(&self) -> &bool {
+        match self.published.has_value() {
+            true => self.published.get_value(),
+            false => &true,
+        }
+    }
+
+    pub fn set_published(&mut self, value: bool) -> &mut Self {
+        self.published.set_value(value);
+        self
+    }
+
+    /// An auto filter is applied to the sheet.
+    pub fn get_filter_mode(&self) -> &bool {
+        self.filter_mode.get_value()
+    }
+
+    pub fn set_filter_mode(&mut self, value: bool) -> &mut Self {
+        self.filter_mode.set_value(value);
+        self
+    }
+
+    /// Conditional formatting is calculated. The default value is true.
+    pub fn get_enable_format_conditions_calculation(&self) -> &bool {
+        match self.enable_format_conditions_calculation.has_value() {
+            true => self.enable_format_conditions_calculation.get_value(),
+            false => &true,
+        }
+    }
+
+    pub fn set_enable_format_conditions_calculation(&mut self, value: bool) -> &mut Self {
+        self.enable_format_conditions_calculation.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, sync_horizontal, "syncHorizontal");
+        set_string_from_xml!(self, e, sync_vertical, "syncVertical");
+        set_string_from_xml!(self, e, sync_reference, "syncRef");
+        set_string_from_xml!(self, e, transition_evaluation, "transitionEvaluation");
+        set_string_from_xml!(self, e, transition_entry, "transitionEntry");
+        set_string_from_xml!(self, e, published, "published");
+        set_string_from_xml!(self, e, filter_mode, "filterMode");
+        set_string_from_xml!(
+            self,
+            e,
+            enable_format_conditions_calculation,
+            "enableFormatConditionsCalculation"
+        );
+        Ok(())
+    }
+
+    /// (This method is crate only.)
+    /// Attributes of sheetPr, without codeName.
+    pub(crate) fn get_attributes(&self) -> Vec<(&str, &str)> {
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.sync_horizontal.has_value() {
+            attributes.push(("syncHorizontal", self.sync_horizontal.get_value_string()));
+        }
+        if self.sync_vertical.has_value() {
+            attributes.push(("syncVertical", self.sync_vertical.get_value_string()));
+        }
+        if self.sync_reference.has_value() {
+            attributes.push(("syncRef", self.sync_reference.get_value_str()));
+        }
+        if self.transition_evaluation.has_value() {
+            attributes.push((
+                "transitionEvaluation",
+                self.transition_evaluation.get_value_string(),
+            ));
+        }
+        if self.transition_entry.has_value() {
+            attributes.push(("transitionEntry", self.transition_entry.get_value_string()));
+        }
+        if self.published.has_value() {
+            attributes.push(("published", self.published.get_value_string()));
+        }
+        if self.filter_mode.has_value() {
+            attributes.push(("filterMode", self.filter_mode.get_value_string()));
+        }
+        if self.enable_format_conditions_calculation.has_value() {
+            attributes.push((
+                "enableFormatConditionsCalculation",
+                self.enable_format_conditions_calculation.get_value_string(),
+            ));
+        }
+        attributes
+    }
+}
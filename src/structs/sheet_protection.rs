@@ -235,15 +235,32 @@ impl SheetProtection {
         self
     }
 
+    /// Check a password against the protection.
+    pub fn verify_password(&self, password: &str) -> bool {
+        if self.hash_value.has_value() {
+            return verify_password_hash(
+                password,
+                self.algorithm_name.get_value_str(),
+                self.hash_value.get_value_str(),
+                self.salt_value.get_value_str(),
+                *self.spin_count.get_value(),
+            );
+        }
+        if self.password.has_value() {
+            return verify_legacy_password_hash(password, self.password.get_value_str());
+        }
+        password.is_empty()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, algorithm_name, "algorithmName");
         set_string_from_xml!(self, e, hash_value, "hashValue");
         set_string_from_xml!(self, e, salt_value, "saltValue");
-        set_string_from_xml!(self, e, spin_count, "spinCount");
+        set_number_from_xml!(self, e, spin_count, "spinCount");
         set_string_from_xml!(self, e, password, "password");
         set_string_from_xml!(self, e, sheet, "sheet");
         set_string_from_xml!(self, e, objects, "objects");
@@ -261,6 +278,7 @@ impl SheetProtection {
         set_string_from_xml!(self, e, select_locked_cells, "selectLockedCells");
         set_string_from_xml!(self, e, select_unlocked_cells, "selectUnlockedCells");
         set_string_from_xml!(self, e, sort, "sort");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,8 +1,13 @@
 // sheetView
 use super::BooleanValue;
+use super::Coordinate;
+use super::EnumTrait;
 use super::EnumValue;
 use super::Pane;
+use super::PaneStateValues;
+use super::PaneValues;
 use super::Selection;
+use super::SequenceOfReferences;
 use super::SheetViewValues;
 use super::StringValue;
 use super::UInt32Value;
@@ -59,6 +64,68 @@ impl SheetView {
         self
     }
 
+    pub fn remove_pane(&mut self) -> &mut Self {
+        self.pane = None;
+        self
+    }
+
+    /// Freeze the top rows and the left columns.
+    /// The pane, its top left cell, the selections and the frozen state are set together.
+    /// Passing 0 for both removes the pane.
+    /// # Arguments
+    /// * `rows` - Number of rows to freeze.
+    /// * `cols` - Number of columns to freeze.
+    pub fn freeze_panes(&mut self, rows: u32, cols: u32) -> &mut Self {
+        if rows == 0 && cols == 0 {
+            self.pane = None;
+            self.selection
+                .retain(|selection| selection.get_pane_str() == "topLeft");
+            return self;
+        }
+
+        let active_pane = match (rows, cols) {
+            (0, _) => PaneValues::TopRight,
+            (_, 0) => PaneValues::BottomLeft,
+            _ => PaneValues::BottomRight,
+        };
+        let mut top_left_cell = Coordinate::default();
+        top_left_cell.set_col_num(cols + 1).set_row_num(rows + 1);
+
+        let mut pane = Pane::default();
+        if cols > 0 {
+            pane.set_horizontal_split(cols as f64);
+        }
+        if rows > 0 {
+            pane.set_vertical_split(rows as f64);
+        }
+        pane.set_top_left_cell(top_left_cell.clone())
+            .set_active_pane(active_pane.clone())
+            .set_state(PaneStateValues::Frozen);
+        self.pane = Some(pane);
+
+        let mut pane_list: Vec<(PaneValues, u32, u32)> = Vec::new();
+        if rows > 0 && cols > 0 {
+            pane_list.push((PaneValues::TopRight, cols + 1, 1));
+            pane_list.push((PaneValues::BottomLeft, 1, rows + 1));
+        }
+        pane_list.push((active_pane, cols + 1, rows + 1));
+
+        self.selection.clear();
+        for (pane, col, row) in pane_list {
+            let mut coordinate = Coordinate::default();
+            coordinate.set_col_num(col).set_row_num(row);
+            let mut sequence_of_references = SequenceOfReferences::default();
+            sequence_of_references.set_sqref(coordinate.to_string());
+            let mut selection = Selection::default();
+            selection
+                .set_pane(pane)
+                .set_active_cell(coordinate)
+                .set_sequence_of_references(sequence_of_references);
+            self.selection.push(selection);
+        }
+        self
+    }
+
     pub fn get_view(&self) -> &SheetViewValues {
         self.view.get_value()
     }
@@ -126,24 +193,105 @@ impl SheetView {
         self
     }
 
+    /// Get the selection of the active pane.
+    pub fn get_active_selection(&self) -> Option<&Selection> {
+        let active_pane = self.get_active_pane_str();
+        self.selection
+            .iter()
+            .find(|selection| selection.get_pane_str() == active_pane)
+    }
+
+    /// Get the active cell.
+    pub fn get_active_cell(&self) -> Option<&Coordinate> {
+        self.get_active_selection()
+            .and_then(|selection| selection.get_active_cell())
+    }
+
+    /// Set the active cell.
+    /// The selection is reduced to the cell unless it already contains the cell.
+    /// # Arguments
+    /// * `cell` - Cell ex) "C5"
+    pub fn set_active_cell(&mut self, cell: &str) -> &mut Self {
+        let mut coordinate = Coordinate::default();
+        coordinate.set_coordinate(cell);
+        let selection = self.get_active_selection_mut();
+        if !selection.contains(coordinate.get_col_num(), coordinate.get_row_num()) {
+            let mut sequence_of_references = SequenceOfReferences::default();
+            sequence_of_references.set_sqref(coordinate.to_string());
+            selection.set_sequence_of_references(sequence_of_references);
+        }
+        selection.set_active_cell(coordinate);
+        self
+    }
+
+    /// Set the selected ranges.
+    /// The active cell is moved to the first range unless the ranges already contain it.
+    /// # Arguments
+    /// * `ranges` - Ranges separated by a space. ex) "A1:B3 D5:D8"
+    pub fn set_selected_ranges(&mut self, ranges: &str) -> &mut Self {
+        let mut sequence_of_references = SequenceOfReferences::default();
+        sequence_of_references.set_sqref(ranges);
+        let selection = self.get_active_selection_mut();
+        selection.set_sequence_of_references(sequence_of_references);
+        let is_contained = match selection.get_active_cell() {
+            Some(v) => selection.contains(v.get_col_num(), v.get_row_num()),
+            None => false,
+        };
+        if !is_contained {
+            let first_range = ranges.split(' ').next().unwrap_or_default();
+            let first_cell = first_range.split(':').next().unwrap_or_default();
+            let mut coordinate = Coordinate::default();
+            coordinate.set_coordinate(first_cell);
+            selection.set_active_cell(coordinate);
+        }
+        self
+    }
+
+    fn get_active_pane_str(&self) -> &str {
+        match &self.pane {
+            Some(v) => v.get_active_pane().get_value_string(),
+            None => "topLeft",
+        }
+    }
+
+    fn get_active_selection_mut(&mut self) -> &mut Selection {
+        let active_pane = self.get_active_pane_str().to_string();
+        let index = match self
+            .selection
+            .iter()
+            .position(|selection| selection.get_pane_str() == active_pane)
+        {
+            Some(v) => v,
+            None => {
+                let mut selection = Selection::default();
+                if let Some(v) = &self.pane {
+                    selection.set_pane(v.get_active_pane().clone());
+                }
+                self.selection.push(selection);
+                self.selection.len() - 1
+            }
+        };
+        &mut self.selection[index]
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, tab_selected, "tabSelected");
-        set_string_from_xml!(self, e, workbook_view_id, "workbookViewId");
+        set_number_from_xml!(self, e, workbook_view_id, "workbookViewId");
         set_string_from_xml!(self, e, view, "view");
-        set_string_from_xml!(self, e, zoom_scale, "zoomScale");
-        set_string_from_xml!(self, e, zoom_scale_normal, "zoomScaleNormal");
-        set_string_from_xml!(
+        set_number_from_xml!(self, e, zoom_scale, "zoomScale");
+        set_number_from_xml!(self, e, zoom_scale_normal, "zoomScaleNormal");
+        set_number_from_xml!(
             self,
             e,
             zoom_scale_page_layout_view,
             "zoomScalePageLayoutView"
         );
-        set_string_from_xml!(
+        set_number_from_xml!(
             self,
             e,
             zoom_scale_sheet_layout_view,
@@ -152,7 +300,7 @@ impl SheetView {
         set_string_from_xml!(self, e, top_left_cell, "topLeftCell");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -161,12 +309,12 @@ impl SheetView {
                 match e.name().into_inner() {
                     b"pane" => {
                         let mut obj = Pane::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_pane(obj);
                     }
                     b"selection" => {
                         let mut obj = Selection::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_selection(obj);
                     }
                     _ => (),
@@ -174,10 +322,10 @@ impl SheetView {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sheetView" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sheetView")
+            Event::Eof => return Err(eof_error("sheetView"))
         );
     }
 
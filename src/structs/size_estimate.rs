@@ -0,0 +1,75 @@
+use helper::const_str::*;
+use structs::PartSize;
+
+/// Sizes of the parts of a workbook, taken with `writer::xlsx::estimate`.
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct SizeEstimate {
+    parts: Vec<PartSize>,
+    sheet_paths: Vec<(String, String)>,
+    file_size: u64,
+}
+impl SizeEstimate {
+    pub(crate) fn new(
+        parts: Vec<PartSize>,
+        sheet_paths: Vec<(String, String)>,
+        file_size: u64,
+    ) -> Self {
+        Self {
+            parts,
+            sheet_paths,
+            file_size,
+        }
+    }
+
+    /// Every part of the package.
+    pub fn get_parts(&self) -> &Vec<PartSize> {
+        &self.parts
+    }
+
+    /// Get a part.
+    /// # Arguments
+    /// * `path` - Path in the package. ex) `"xl/styles.xml"`
+    pub fn get_part(&self, path: &str) -> Option<&PartSize> {
+        self.parts.iter().find(|v| v.get_path() == path)
+    }
+
+    /// Get the worksheet xml part of a sheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name.
+    pub fn get_sheet(&self, sheet_name: &str) -> Option<&PartSize> {
+        self.sheet_paths
+            .iter()
+            .find(|(name, _)| name == sheet_name)
+            .and_then(|(_, path)| self.get_part(path))
+    }
+
+    /// Uncompressed bytes of the shared string table. (0 if there is none.)
+    pub fn get_shared_strings_size(&self) -> u64 {
+        self.get_part(PKG_SHARED_STRINGS)
+            .map_or(0, |v| *v.get_size())
+    }
+
+    /// Uncompressed bytes of the stylesheet.
+    pub fn get_styles_size(&self) -> u64 {
+        self.get_part(PKG_STYLES).map_or(0, |v| *v.get_size())
+    }
+
+    /// Bytes of the images and other media files.
+    pub fn get_media_size(&self) -> u64 {
+        self.parts
+            .iter()
+            .filter(|v| v.get_path().starts_with(PKG_MEDIA))
+            .map(|v| *v.get_size())
+            .sum()
+    }
+
+    /// Uncompressed bytes of all the parts.
+    pub fn get_total_size(&self) -> u64 {
+        self.parts.iter().map(|v| *v.get_size()).sum()
+    }
+
+    /// Bytes of the xlsx file.
+    pub fn get_file_size(&self) -> &u64 {
+        &self.file_size
+    }
+}
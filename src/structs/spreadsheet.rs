@@ -1,25 +1,43 @@
 use crate::StringValue;
 use helper::address::*;
+use helper::const_str::SHEET_METADATA_NS;
 use helper::coordinate::*;
+use helper::formula::{get_formula_references, is_in_range};
+use helper::number_format::round_to_display_precision;
+use helper::range::{get_coordinate_list, get_start_and_end_point};
 use reader::xlsx::*;
+use std::ops::{Index, IndexMut};
 use std::sync::Arc;
 use std::sync::RwLock;
 use structs::drawing::Theme;
+use structs::raw::RawPart;
 use structs::Address;
+use structs::Cell;
 use structs::CellValue;
 use structs::Cells;
+use structs::Connection;
+use structs::CustomWorkbookView;
 use structs::DefinedName;
+use structs::MergeOption;
+use structs::Metadata;
+use structs::Progress;
 use structs::Properties;
+use structs::ReadLimits;
 use structs::SharedStringTable;
 use structs::Stylesheet;
+use structs::TemplateValues;
+use structs::ValidationIssue;
+use structs::ValidationIssueKind;
 use structs::WorkbookProtection;
 use structs::WorkbookView;
 use structs::Worksheet;
+use structs::XlsxError;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWithSheet;
 
 /// A Spreadsheet Object.
 /// The starting point of all struct.
+/// It is `Send` and `Sync`.
 #[derive(Clone, Default, Debug)]
 pub struct Spreadsheet {
     properties: Properties,
@@ -31,10 +49,23 @@ pub struct Spreadsheet {
     stylesheet: Stylesheet,
     shared_string_table: Arc<RwLock<SharedStringTable>>,
     workbook_view: WorkbookView,
+    custom_workbook_views: Vec<CustomWorkbookView>,
     backup_context_types: Vec<(String, String)>,
     pivot_caches: Vec<(String, String, String)>,
     workbook_protection: Option<WorkbookProtection>,
     defined_names: Vec<DefinedName>,
+    is_template: bool,
+    precision_as_displayed: bool,
+    force_full_calculation: bool,
+    rich_data_parts: Vec<RawPart>,
+    metadata: Option<Metadata>,
+    connections: Vec<Connection>,
+    connections_part: Option<RawPart>,
+    read_warnings: Vec<String>,
+    read_limits: ReadLimits,
+    unknown_parts: Vec<RawPart>,
+    unknown_root_parts: Vec<RawPart>,
+    backup_default_context_types: Vec<(String, String)>,
 }
 
 impl Spreadsheet {
@@ -230,10 +261,163 @@ impl Spreadsheet {
         self.macros_code.is_some()
     }
 
+    /// Get the VBA project (xl/vbaProject.bin).
+    /// # Return value
+    /// * `Option<&Vec<u8>>` - VBA project raw data.
+    pub fn get_vba_project(&self) -> Option<&Vec<u8>> {
+        self.get_macros_code()
+    }
+
+    /// Set the VBA project (xl/vbaProject.bin) to make a macro-enabled workbook.
+    /// The data can be taken from an existing xlsm file.
+    /// Save the workbook with the `.xlsm` extension.
+    /// # Arguments
+    /// * `value` - VBA project raw data.
+    /// # Examples
+    /// ```
+    /// let source = umya_spreadsheet::reader::xlsx::read("./tests/test_files/aaa.xlsm").unwrap();
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.set_vba_project(source.get_vba_project().unwrap().clone());
+    /// let _ = umya_spreadsheet::writer::xlsx::write(&book, "./tests/result_files/zzz_vba.xlsm");
+    /// ```
+    pub fn set_vba_project(&mut self, value: Vec<u8>) -> &mut Self {
+        self.set_macros_code(value)
+    }
+
+    /// Get Is Template.
+    /// True when the workbook was read from a template (xltx/xltm).
+    pub fn get_is_template(&self) -> &bool {
+        &self.is_template
+    }
+
+    /// Set Is Template.
+    /// When true, the workbook is written as a template (xltx/xltm).
+    /// # Arguments
+    /// * `value` - true: template. false: workbook.
+    pub fn set_is_template(&mut self, value: bool) -> &mut Self {
+        self.is_template = value;
+        self
+    }
+
+    /// Fill the `{{placeholder}}` tokens of every worksheet.
+    /// See `Worksheet::fill_template`.
+    /// Rows are inserted and removed like `insert_new_row` and `remove_row`,
+    /// so references from other sheets follow the moved rows.
+    /// # Arguments
+    /// * `values` - TemplateValues
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_value("{{title}}");
+    /// let mut values = umya_spreadsheet::TemplateValues::default();
+    /// values.set_value("title", "Report");
+    /// book.fill_template(&values);
+    /// assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "Report");
+    /// ```
+    pub fn fill_template(&mut self, values: &TemplateValues) -> &mut Self {
+        self.read_sheet_collection();
+        for index in 0..self.work_sheet_collection.len() {
+            let sheet_name = self.work_sheet_collection[index].get_name().to_string();
+            for (row, list) in self.work_sheet_collection[index].get_template_rows(values) {
+                let count = values.get_records(&list).map_or(0, |v| v.len()) as u32;
+                if count == 0 {
+                    self.remove_row(&sheet_name, &row, &1);
+                    continue;
+                }
+                if count > 1 {
+                    self.insert_new_row(&sheet_name, &(row + 1), &(count - 1));
+                }
+                self.work_sheet_collection[index].fill_template_row(&row, &list, values);
+            }
+            self.work_sheet_collection[index].fill_template_values(values);
+        }
+        self
+    }
+
+    /// Get Precision As Displayed.
+    /// True when Excel calculates with the displayed values (calcPr fullPrecision="0").
+    pub fn get_precision_as_displayed(&self) -> &bool {
+        &self.precision_as_displayed
+    }
+
+    /// Set Precision As Displayed.
+    /// Mirrors Excel's "Set precision as displayed" option.
+    /// Stored values are not changed; call `round_to_display_precision` to round them.
+    /// # Arguments
+    /// * `value` - true: calculate with the displayed values.
+    pub fn set_precision_as_displayed(&mut self, value: bool) -> &mut Self {
+        self.precision_as_displayed = value;
+        self
+    }
+
+    /// Get Force Full Calculation.
+    /// True when Excel recalculates every formula when the file is opened (calcPr fullCalcOnLoad).
+    pub fn get_force_full_calculation(&self) -> &bool {
+        &self.force_full_calculation
+    }
+
+    /// Set Force Full Calculation.
+    /// Use it when the cached results of the formulas are missing or out of date,
+    /// ex) after formulas were set with `set_formula`, as they are not calculated by this crate.
+    /// # Arguments
+    /// * `value` - true: recalculate when opened.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_formula("SUM(B1:B3)");
+    /// book.set_force_full_calculation(true);
+    /// ```
+    pub fn set_force_full_calculation(&mut self, value: bool) -> &mut Self {
+        self.force_full_calculation = value;
+        self
+    }
+
+    /// Round every number to the decimals shown by its number format,
+    /// so that no hidden decimals are stored.
+    /// Cells with a General, text, date or scientific format are left as they are.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let cell = book.get_sheet_mut(&0).unwrap().get_cell_mut("A1");
+    /// cell.set_value_number(1.005);
+    /// cell.get_style_mut().get_number_format_mut().set_format_code("0.0");
+    /// book.round_to_display_precision();
+    /// assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "1");
+    /// ```
+    pub fn round_to_display_precision(&mut self) -> &mut Self {
+        for worksheet in self.get_sheet_collection_mut() {
+            for cell in worksheet.get_cell_collection_mut() {
+                let value = match cell.get_value_number() {
+                    Some(v) => v,
+                    None => continue,
+                };
+                let rounded = match cell.get_style().get_number_format() {
+                    Some(v) => round_to_display_precision(value, v.get_format_code()),
+                    None => continue,
+                };
+                if rounded != value {
+                    cell.cell_value.set_value_number_crate(rounded);
+                }
+            }
+        }
+        self
+    }
+
+    /// Remove the control characters not allowed in xml from the text cells of every worksheet.
+    /// See `Worksheet::sanitize_text`.
+    /// # Return value
+    /// * `usize` - number of changed cells.
+    pub fn sanitize_text(&mut self) -> usize {
+        self.get_sheet_collection_mut()
+            .iter_mut()
+            .map(|worksheet| worksheet.sanitize_text())
+            .sum()
+    }
+
     /// Set codeName property of workbook
     ///
     /// May be useful when importing VBA/macros code from another workbook
-    /// and only used when writing book with macros code
+    /// and is written when it is set or the book has macros code
     ///
     /// Default one is `ThisWorkbook`.
     ///
@@ -263,6 +447,7 @@ impl Spreadsheet {
     /// * `value` - Stylesheet
     pub(crate) fn set_stylesheet(&mut self, value: Stylesheet) -> &mut Self {
         self.stylesheet = value;
+        self.update_default_style();
         self
     }
 
@@ -270,6 +455,17 @@ impl Spreadsheet {
     /// Set Default Value Stylesheet.
     pub(crate) fn set_stylesheet_defalut_value(&mut self) -> &mut Self {
         self.stylesheet.set_defalut_value();
+        self.update_default_style();
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Pass the style cells fall back to on to the worksheets.
+    pub(crate) fn update_default_style(&mut self) -> &mut Self {
+        let style = self.stylesheet.get_default_style();
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.set_default_style_crate(style.clone());
+        }
         self
     }
 
@@ -317,21 +513,124 @@ impl Spreadsheet {
 
     /// deserialize by all worksheet.
     pub fn read_sheet_collection(&mut self) -> &mut Self {
+        self.try_read_sheet_collection().unwrap()
+    }
+
+    /// deserialize by all worksheet.
+    /// # Return value
+    /// * `Result` - Err is returned when a worksheet is malformed.
+    pub fn try_read_sheet_collection(&mut self) -> Result<&mut Self, XlsxError> {
+        self.read_sheet_collection_with_warnings(&mut None)
+    }
+
+    pub(crate) fn read_sheet_collection_with_warnings(
+        &mut self,
+        warnings: &mut Option<&mut Vec<String>>,
+    ) -> Result<&mut Self, XlsxError> {
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
+        let read_limits = self.read_limits.clone();
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            let is_collect = warnings.is_some();
+            let results: Vec<Result<Vec<String>, XlsxError>> = self
+                .work_sheet_collection
+                .par_iter_mut()
+                .map(|worksheet| {
+                    let mut list: Vec<String> = Vec::new();
+                    let mut sheet_warnings = if is_collect { Some(&mut list) } else { None };
+                    raw_to_deserialize_by_worksheet(
+                        worksheet,
+                        shared_string_table.clone(),
+                        &stylesheet,
+                        &read_limits,
+                        &mut sheet_warnings,
+                    )?;
+                    Ok(list)
+                })
+                .collect();
+            for result in results {
+                let list = result?;
+                if let Some(v) = warnings.as_mut() {
+                    v.extend(list);
+                }
+            }
+        }
+
+        #[cfg(not(feature = "rayon"))]
         for worksheet in &mut self.work_sheet_collection {
-            raw_to_deserialize_by_worksheet(worksheet, shared_string_table.clone(), &stylesheet);
+            raw_to_deserialize_by_worksheet(
+                worksheet,
+                shared_string_table.clone(),
+                &stylesheet,
+                &read_limits,
+                warnings,
+            )?;
         }
-        self
+
+        Ok(self)
+    }
+
+    // Deserialize the worksheets one by one, reporting each to `progress`.
+    pub(crate) fn read_sheet_collection_with_progress(
+        &mut self,
+        progress: &mut dyn FnMut(&Progress),
+    ) -> Result<&mut Self, XlsxError> {
+        let shared_string_table = self.get_shared_string_table();
+        let stylesheet = self.get_stylesheet().clone();
+        let read_limits = self.read_limits.clone();
+        let parts_total = self.work_sheet_collection.len();
+        let mut bytes = 0u64;
+        progress(&Progress::new(0, parts_total, bytes));
+        for (index, worksheet) in self.work_sheet_collection.iter_mut().enumerate() {
+            if !worksheet.is_deserialized() {
+                bytes += worksheet
+                    .get_raw_data_of_worksheet()
+                    .get_worksheet_file()
+                    .get_file_data()
+                    .len() as u64;
+            }
+            raw_to_deserialize_by_worksheet(
+                worksheet,
+                shared_string_table.clone(),
+                &stylesheet,
+                &read_limits,
+                &mut None,
+            )?;
+            progress(&Progress::new(index + 1, parts_total, bytes));
+        }
+        Ok(self)
     }
 
     /// deserialize a worksheet.
+    /// # Panics
+    /// Panics when the index is out of range or the worksheet is malformed.
+    /// Use `try_read_sheet` to get the error instead.
     pub fn read_sheet(&mut self, index: usize) -> &mut Self {
+        self.try_read_sheet(index).unwrap()
+    }
+
+    /// deserialize a worksheet.
+    /// # Return value
+    /// * `Result` - Err is returned when the index is out of range or the worksheet is malformed.
+    pub fn try_read_sheet(&mut self, index: usize) -> Result<&mut Self, XlsxError> {
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
-        let worksheet = self.work_sheet_collection.get_mut(index).unwrap();
-        raw_to_deserialize_by_worksheet(worksheet, shared_string_table, &stylesheet);
-        self
+        let read_limits = self.read_limits.clone();
+        let worksheet = self
+            .work_sheet_collection
+            .get_mut(index)
+            .ok_or_else(|| XlsxError::Format(format!("sheet index {} is out of range", index)))?;
+        raw_to_deserialize_by_worksheet(
+            worksheet,
+            shared_string_table,
+            &stylesheet,
+            &read_limits,
+            &mut None,
+        )?;
+        Ok(self)
     }
 
     pub(crate) fn find_sheet_index_by_name(&self, sheet_name: &str) -> Option<usize> {
@@ -382,13 +681,37 @@ impl Spreadsheet {
     /// * `index` - sheet index
     /// # Return value
     /// * `Option<&mut Worksheet>`.
+    /// # Panics
+    /// Panics when the worksheet is malformed. Use `try_get_sheet_mut` to get the error instead.
     pub fn get_sheet_mut(&mut self, index: &usize) -> Option<&mut Worksheet> {
+        self.try_get_sheet_mut(index).unwrap()
+    }
+
+    /// Get Work Sheet in mutable.
+    /// # Arguments
+    /// * `index` - sheet index
+    /// # Return value
+    /// * `Result<Option<&mut Worksheet>, XlsxError>` - Err is returned when the worksheet is malformed.
+    pub fn try_get_sheet_mut(
+        &mut self,
+        index: &usize,
+    ) -> Result<Option<&mut Worksheet>, XlsxError> {
         let shared_string_table = self.get_shared_string_table();
         let stylesheet = self.get_stylesheet().clone();
-        self.work_sheet_collection.get_mut(*index).map(|v| {
-            raw_to_deserialize_by_worksheet(v, shared_string_table, &stylesheet);
-            v
-        })
+        let read_limits = self.read_limits.clone();
+        match self.work_sheet_collection.get_mut(*index) {
+            Some(v) => {
+                raw_to_deserialize_by_worksheet(
+                    v,
+                    shared_string_table,
+                    &stylesheet,
+                    &read_limits,
+                    &mut None,
+                )?;
+                Ok(Some(v))
+            }
+            None => Ok(None),
+        }
     }
 
     /// Get Work Sheet in mutable.
@@ -401,8 +724,20 @@ impl Spreadsheet {
             .and_then(move |index| self.get_sheet_mut(&index))
     }
 
+    /// Set Active Work Sheet.
+    /// The tab of the active sheet is selected and the tabs of the other loaded sheets are deselected.
+    /// # Arguments
+    /// * `index` - sheet index
     pub fn set_active_sheet(&mut self, index: u32) -> &mut Self {
         self.get_workbook_view_mut().set_active_tab(index);
+        for (i, worksheet) in self.work_sheet_collection.iter_mut().enumerate() {
+            if !worksheet.is_deserialized() {
+                continue;
+            }
+            for sheet_view in worksheet.get_sheet_views_mut().get_sheet_view_list_mut() {
+                sheet_view.set_tab_selected(i == index as usize);
+            }
+        }
         self
     }
 
@@ -427,14 +762,222 @@ impl Spreadsheet {
     /// * `value` - Work Sheet
     /// # Return value
     /// * `Result<&mut Worksheet, &'static str>` - OK:added work sheet. Err:Error.
-    pub fn add_sheet(&mut self, value: Worksheet) -> Result<&mut Worksheet, &'static str> {
+    pub fn add_sheet(&mut self, mut value: Worksheet) -> Result<&mut Worksheet, &'static str> {
         let title = value.get_name();
         Spreadsheet::check_sheet_name(self, title)?;
+        value.set_default_style_crate(self.stylesheet.get_default_style());
         self.work_sheet_collection.push(value);
         Ok(self.work_sheet_collection.last_mut().unwrap())
     }
 
+    /// Merge workbooks into a new one.
+    /// The sheets of each workbook are appended in order to a copy of the first workbook.
+    /// Cells keep their styles, and formulas, defined names, charts, conditional formatting
+    /// and data validations follow renamed sheets. Tables are renamed to stay unique.
+    /// # Arguments
+    /// * `books` - workbooks to merge.
+    /// * `option` - MergeOption
+    /// # Return value
+    /// * `Result<Spreadsheet, &'static str>` - OK:merged workbook. Err:Error.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let book_a = new_file();
+    /// let book_b = new_file();
+    /// let book = Spreadsheet::merge(&[&book_a, &book_b], &MergeOption::default()).unwrap();
+    /// assert_eq!(book.get_sheet(&1).unwrap().get_name(), "Sheet1 (2)");
+    /// ```
+    pub fn merge(
+        books: &[&Spreadsheet],
+        option: &MergeOption,
+    ) -> Result<Spreadsheet, &'static str> {
+        let (first, others) = books.split_first().ok_or("no spreadsheet to merge.")?;
+        let mut result = (*first).clone();
+        result.read_sheet_collection();
+
+        for book in others {
+            let mut book = (*book).clone();
+            book.read_sheet_collection();
+
+            // resolve sheet name collisions.
+            let mut used_names: Vec<String> = result
+                .work_sheet_collection
+                .iter()
+                .chain(&book.work_sheet_collection)
+                .map(|v| v.get_name().to_string())
+                .collect();
+            let mut rename_list: Vec<(String, String)> = Vec::new();
+            for worksheet in &book.work_sheet_collection {
+                let name = worksheet.get_name();
+                if result.check_sheet_name(name).is_ok() {
+                    continue;
+                }
+                if !*option.get_rename_sheet() {
+                    return Err("name duplicate.");
+                }
+                let new_name = Spreadsheet::get_unused_sheet_name(name, &used_names);
+                used_names.push(new_name.clone());
+                rename_list.push((name.to_string(), new_name));
+            }
+            for (name, new_name) in &rename_list {
+                for worksheet in &mut book.work_sheet_collection {
+                    worksheet.rename_sheet_reference(name, new_name);
+                }
+                for defined_name in &mut book.defined_names {
+                    defined_name.rename_sheet_reference(name, new_name);
+                }
+                if let Some(index) = book.find_sheet_index_by_name(name) {
+                    book.work_sheet_collection[index].set_name(new_name.clone());
+                }
+            }
+
+            // copy defined names.
+            let offset = result.work_sheet_collection.len() as u32;
+            if *option.get_copy_defined_names() {
+                for mut defined_name in book.defined_names {
+                    if defined_name.has_local_sheet_id() {
+                        let local_sheet_id = defined_name.get_local_sheet_id() + offset;
+                        defined_name.set_local_sheet_id(local_sheet_id);
+                    } else if result
+                        .defined_names
+                        .iter()
+                        .any(|v| !v.has_local_sheet_id() && v.get_name() == defined_name.get_name())
+                    {
+                        continue;
+                    }
+                    result.defined_names.push(defined_name);
+                }
+            }
+
+            // table names are unique within the book.
+            for mut worksheet in book.work_sheet_collection {
+                result.make_table_names_unique(&mut worksheet);
+                result.work_sheet_collection.push(worksheet);
+            }
+        }
+        result.update_default_style();
+        Ok(result)
+    }
+
+    // ex) "Sheet1" -> "Sheet1 (2)", kept within the 31 character limit of a sheet name.
+    fn get_unused_sheet_name(sheet_name: &str, used_names: &[String]) -> String {
+        let mut index = 2;
+        loop {
+            let suffix = format!(" ({})", index);
+            let base: String = sheet_name.chars().take(31 - suffix.len()).collect();
+            let result = format!("{}{}", base, suffix);
+            if !used_names
+                .iter()
+                .any(|v| v.to_lowercase() == result.to_lowercase())
+            {
+                return result;
+            }
+            index += 1;
+        }
+    }
+
+    // ex) "Table1" -> "Table1_2", table names are unique within the book.
+    fn make_table_names_unique(&self, worksheet: &mut Worksheet) {
+        let mut used_names: Vec<String> = Vec::new();
+        for table in self
+            .work_sheet_collection
+            .iter()
+            .flat_map(|v| v.get_tables().iter())
+        {
+            used_names.push(table.get_name().to_lowercase());
+            used_names.push(table.get_display_name().to_lowercase());
+        }
+        for table in worksheet.get_tables_mut() {
+            let name = table.get_name().to_string();
+            let mut new_name = name.clone();
+            let mut index = 2;
+            while used_names.contains(&new_name.to_lowercase()) {
+                new_name = format!("{}_{}", name, index);
+                index += 1;
+            }
+            if new_name != name {
+                table.set_name(&new_name);
+                table.set_display_name(&new_name);
+            }
+            used_names.push(new_name.to_lowercase());
+        }
+    }
+
+    // length and characters allowed by Excel.
+    fn is_valid_sheet_name(sheet_name: &str) -> bool {
+        !sheet_name.is_empty()
+            && sheet_name.chars().count() <= 31
+            && !sheet_name.contains([':', '\\', '/', '?', '*', '[', ']'])
+            && !sheet_name.starts_with('\'')
+            && !sheet_name.ends_with('\'')
+    }
+
+    /// Duplicate Work Sheet.
+    /// The copy is added at the end and named like Excel does, ex) `"Report"` -> `"Report (2)"`.
+    /// Defined names scoped to the sheet are copied with it,
+    /// and copied tables are renamed to stay unique, ex) `"Table1"` -> `"Table1_2"`.
+    /// # Arguments
+    /// * `sheet_name` - name of the sheet to duplicate.
+    /// # Return value
+    /// * `Result<&mut Worksheet, &'static str>` - OK:added work sheet. Err:Error.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_value("Total");
+    /// let worksheet = book.duplicate_sheet("Sheet1").unwrap();
+    /// assert_eq!(worksheet.get_name(), "Sheet1 (2)");
+    /// assert_eq!(worksheet.get_value("A1"), "Total");
+    /// ```
+    pub fn duplicate_sheet(&mut self, sheet_name: &str) -> Result<&mut Worksheet, &'static str> {
+        self.read_sheet_collection();
+        let index = self
+            .find_sheet_index_by_name(sheet_name)
+            .ok_or("sheet not found.")?;
+        let used_names: Vec<String> = self
+            .work_sheet_collection
+            .iter()
+            .map(|v| v.get_name().to_string())
+            .collect();
+        // "Report (2)" is copied as "Report (3)", not "Report (2) (2)".
+        let base_name = match sheet_name
+            .strip_suffix(')')
+            .and_then(|v| v.rsplit_once(" ("))
+        {
+            Some((base, number)) if number.parse::<u32>().is_ok() => base,
+            _ => sheet_name,
+        };
+        let new_name = Spreadsheet::get_unused_sheet_name(base_name, &used_names);
+        if !Spreadsheet::is_valid_sheet_name(&new_name) {
+            return Err("invalid sheet name.");
+        }
+
+        let mut worksheet = self.work_sheet_collection[index].clone();
+        worksheet.set_sheet_id((self.work_sheet_collection.len() + 1).to_string());
+        worksheet.set_name(new_name);
+        self.make_table_names_unique(&mut worksheet);
+        for sheet_view in worksheet.get_sheet_views_mut().get_sheet_view_list_mut() {
+            sheet_view.set_tab_selected(false);
+        }
+
+        let new_index = self.work_sheet_collection.len() as u32;
+        let local_defined_names: Vec<DefinedName> = self
+            .defined_names
+            .iter()
+            .filter(|v| v.has_local_sheet_id() && v.get_local_sheet_id() == &(index as u32))
+            .cloned()
+            .collect();
+        for mut defined_name in local_defined_names {
+            defined_name.set_local_sheet_id(new_index);
+            self.defined_names.push(defined_name);
+        }
+
+        self.work_sheet_collection.push(worksheet);
+        Ok(self.work_sheet_collection.last_mut().unwrap())
+    }
+
     /// Remove Work Sheet.
+    /// Formulas and defined names that reference the sheet become `#REF!`,
+    /// and defined names scoped to the sheet are removed with it.
     /// # Arguments
     /// * `index` - sheet index
     /// # Return value
@@ -443,24 +986,57 @@ impl Spreadsheet {
         if self.work_sheet_collection.len() <= index {
             return Err("out of index.");
         }
-        self.work_sheet_collection.remove(index);
+        self.remove_sheet_and_references(index);
         Ok(())
     }
 
     /// Remove Work Sheet.
+    /// Formulas and defined names that reference the sheet become `#REF!`,
+    /// and defined names scoped to the sheet are removed with it.
     /// # Arguments
     /// * `sheet_name` - sheet name
     /// # Return value
     /// * `Result<(), &'static str>` - OK:removed worksheet. Err:Error.
     pub fn remove_sheet_by_name(&mut self, sheet_name: &str) -> Result<(), &'static str> {
-        let cnt_before = self.work_sheet_collection.len();
-        self.work_sheet_collection
-            .retain(|x| x.get_name() != sheet_name);
-        let cnt_after = self.work_sheet_collection.len();
-        if cnt_before == cnt_after {
-            return Err("out of index.");
+        match self.find_sheet_index_by_name(sheet_name) {
+            Some(index) => {
+                self.remove_sheet_and_references(index);
+                Ok(())
+            }
+            None => Err("out of index."),
+        }
+    }
+
+    fn remove_sheet_and_references(&mut self, index: usize) {
+        self.read_sheet_collection();
+        let sheet_name = self
+            .work_sheet_collection
+            .remove(index)
+            .get_name()
+            .to_string();
+
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.remove_sheet_reference(&sheet_name);
+        }
+
+        let index = index as u32;
+        self.defined_names
+            .retain(|v| !v.has_local_sheet_id() || v.get_local_sheet_id() != &index);
+        for defined_name in &mut self.defined_names {
+            if defined_name.has_local_sheet_id() && defined_name.get_local_sheet_id() > &index {
+                let local_sheet_id = defined_name.get_local_sheet_id() - 1;
+                defined_name.set_local_sheet_id(local_sheet_id);
+            }
+            if defined_name.is_sheet_reference(&sheet_name) {
+                defined_name.set_address("#REF!");
+            }
+        }
+
+        let active_tab = *self.get_workbook_view().get_active_tab();
+        if active_tab > index || active_tab as usize >= self.work_sheet_collection.len() {
+            self.get_workbook_view_mut()
+                .set_active_tab(active_tab.saturating_sub(1));
         }
-        Ok(())
     }
 
     /// Add New Work Sheet.
@@ -496,11 +1072,14 @@ impl Spreadsheet {
         worksheet
             .get_sheet_format_properties_mut()
             .set_defalut_value();
+        worksheet.set_default_style_crate(self.stylesheet.get_default_style());
         self.work_sheet_collection.push(worksheet);
         self.work_sheet_collection.last_mut().unwrap()
     }
 
     /// Set Sheet Name.
+    /// Formulas, defined names, conditional formatting, data validations and charts
+    /// that reference the sheet are updated to the new name.
     /// # Arguments
     /// * `index` - target sheet index
     /// * `sheet_name` - sheet name
@@ -513,12 +1092,19 @@ impl Spreadsheet {
     ) -> Result<(), &'static str> {
         let sheet_name_str = sheet_name.into();
         Spreadsheet::check_sheet_name(self, sheet_name_str.as_ref())?;
-        self.work_sheet_collection
-            .get_mut(index)
-            .map(|sheet| {
-                sheet.set_name(sheet_name_str);
-            })
-            .ok_or("sheet not found.")
+        let old_sheet_name = match self.work_sheet_collection.get(index) {
+            Some(v) => v.get_name().to_string(),
+            None => return Err("sheet not found."),
+        };
+        self.read_sheet_collection();
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.rename_sheet_reference(&old_sheet_name, &sheet_name_str);
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.rename_sheet_reference(&old_sheet_name, &sheet_name_str);
+        }
+        self.work_sheet_collection[index].set_name(sheet_name_str);
+        Ok(())
     }
 
     /// (This method is crate only.)
@@ -558,6 +1144,24 @@ impl Spreadsheet {
         self
     }
 
+    /// Get the saved custom workbook views.
+    pub fn get_custom_workbook_views(&self) -> &Vec<CustomWorkbookView> {
+        &self.custom_workbook_views
+    }
+
+    /// Get the saved custom workbook views in mutable.
+    pub fn get_custom_workbook_views_mut(&mut self) -> &mut Vec<CustomWorkbookView> {
+        &mut self.custom_workbook_views
+    }
+
+    /// Add a custom workbook view.
+    /// # Arguments
+    /// * `value` - CustomWorkbookView
+    pub fn add_custom_workbook_view(&mut self, value: CustomWorkbookView) -> &mut Self {
+        self.custom_workbook_views.push(value);
+        self
+    }
+
     /// (This method is crate only.)
     /// Has Defined Names.
     pub(crate) fn has_defined_names(&self) -> bool {
@@ -578,6 +1182,205 @@ impl Spreadsheet {
         self
     }
 
+    /// Check the workbook for problems that make Excel refuse to open the file.
+    /// # Return value
+    /// * `Vec<ValidationIssue>` - empty if no problem is found.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// assert!(book.validate().is_empty());
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues: Vec<ValidationIssue> = Vec::new();
+
+        // sheet names
+        let mut names: Vec<String> = Vec::new();
+        for worksheet in self.get_sheet_collection_no_check() {
+            let name = worksheet.get_name();
+            if !Spreadsheet::is_valid_sheet_name(name) {
+                issues.push(ValidationIssue::new(
+                    ValidationIssueKind::InvalidSheetName,
+                    name,
+                    format!("invalid sheet name `{}`", name),
+                ));
+            }
+            let lower_name = name.to_lowercase();
+            if names.contains(&lower_name) {
+                issues.push(ValidationIssue::new(
+                    ValidationIssueKind::DuplicateSheetName,
+                    name,
+                    format!("duplicate sheet name `{}`", name),
+                ));
+            }
+            names.push(lower_name);
+        }
+
+        // worksheets
+        for worksheet in self.get_sheet_collection_no_check() {
+            worksheet.validate(&mut issues);
+        }
+
+        // defined names
+        let mut defined_names: Vec<(&str, &DefinedName)> =
+            self.get_defined_names().iter().map(|v| ("", v)).collect();
+        for worksheet in self.get_sheet_collection_no_check() {
+            for defined_name in worksheet.get_defined_names() {
+                defined_names.push((worksheet.get_name(), defined_name));
+            }
+        }
+        for (sheet_name, defined_name) in defined_names {
+            for address in defined_name.get_address_obj() {
+                if !address.get_sheet_name().is_empty()
+                    && !names.contains(&address.get_sheet_name().to_lowercase())
+                {
+                    issues.push(ValidationIssue::new(
+                        ValidationIssueKind::BrokenRelationship,
+                        sheet_name,
+                        format!(
+                            "defined name `{}` refers to the missing sheet `{}`",
+                            defined_name.get_name(),
+                            address.get_sheet_name()
+                        ),
+                    ));
+                }
+            }
+        }
+
+        // workbook parts
+        for raw_part in self
+            .rich_data_parts
+            .iter()
+            .chain(self.connections_part.iter())
+        {
+            for relationships in raw_part.get_relationships_list() {
+                for relationship in relationships.get_relationship_list() {
+                    if relationship.get_target_mode() != "External"
+                        && relationship.get_raw_file().get_file_data().is_empty()
+                    {
+                        issues.push(ValidationIssue::new(
+                            ValidationIssueKind::BrokenRelationship,
+                            "",
+                            format!(
+                                "{} refers to the missing part {}",
+                                raw_part.get_target(),
+                                relationship.get_raw_file().get_file_target()
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        // pivot caches not used by any pivot table
+        let pivot_caches = self.get_pivot_caches();
+        for (_, cache_id, target) in &self.pivot_caches {
+            if !pivot_caches.iter().any(|(_, _, v)| v == target) {
+                issues.push(ValidationIssue::new(
+                    ValidationIssueKind::OrphanPart,
+                    "",
+                    format!("pivot cache {} ({}) is not used", cache_id, target),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// Get the warnings collected by `reader::xlsx::read_with_recovery`.
+    pub fn get_read_warnings(&self) -> &Vec<String> {
+        &self.read_warnings
+    }
+
+    pub(crate) fn set_read_warnings(&mut self, value: Vec<String>) -> &mut Self {
+        self.read_warnings = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Limits checked when a worksheet is deserialized.
+    pub(crate) fn set_read_limits(&mut self, value: ReadLimits) -> &mut Self {
+        self.read_limits = value;
+        self
+    }
+
+    /// Get the paths of the parts that are not modeled and are written back as is.
+    /// ex) `customXml/item1.xml`, `customUI/customUI14.xml`
+    pub fn get_unknown_part_names(&self) -> Vec<&str> {
+        self.unknown_parts
+            .iter()
+            .chain(self.unknown_root_parts.iter())
+            .map(|v| v.get_part_file().get_file_target())
+            .collect()
+    }
+
+    pub(crate) fn get_unknown_parts(&self) -> &Vec<RawPart> {
+        &self.unknown_parts
+    }
+
+    pub(crate) fn add_unknown_parts(&mut self, value: RawPart) -> &mut Self {
+        self.unknown_parts.push(value);
+        self
+    }
+
+    pub(crate) fn get_unknown_root_parts(&self) -> &Vec<RawPart> {
+        &self.unknown_root_parts
+    }
+
+    pub(crate) fn add_unknown_root_parts(&mut self, value: RawPart) -> &mut Self {
+        self.unknown_root_parts.push(value);
+        self
+    }
+
+    pub(crate) fn get_backup_default_context_types(&self) -> &Vec<(String, String)> {
+        &self.backup_default_context_types
+    }
+
+    pub(crate) fn set_backup_default_context_types(
+        &mut self,
+        value: Vec<(String, String)>,
+    ) -> &mut Self {
+        self.backup_default_context_types = value;
+        self
+    }
+
+    pub(crate) fn get_rich_data_parts(&self) -> &Vec<RawPart> {
+        &self.rich_data_parts
+    }
+
+    pub(crate) fn add_rich_data_parts(&mut self, value: RawPart) -> Result<(), XlsxError> {
+        if value.get_type() == SHEET_METADATA_NS {
+            self.metadata = Some(Metadata::read(value.get_part_file().get_file_data())?);
+        }
+        self.rich_data_parts.push(value);
+        Ok(())
+    }
+
+    /// Get the cell and value metadata (xl/metadata.xml) read from the file.
+    /// Dynamic array formulas (LET, LAMBDA ...) and linked data types refer to it.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// assert!(book.get_metadata().is_none());
+    /// ```
+    pub fn get_metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Get the external data connections (xl/connections.xml).
+    pub fn get_connections(&self) -> &Vec<Connection> {
+        &self.connections
+    }
+
+    pub(crate) fn get_connections_part(&self) -> Option<&RawPart> {
+        self.connections_part.as_ref()
+    }
+
+    pub(crate) fn set_connections_part(&mut self, value: RawPart) -> Result<(), XlsxError> {
+        self.connections = Connection::read_list(value.get_part_file().get_file_data())?;
+        self.connections_part = Some(value);
+        Ok(())
+    }
+
     pub(crate) fn get_pivot_caches(&self) -> Vec<(String, String, String)> {
         let mut result: Vec<(String, String, String)> = Vec::new();
         for (val1, val2, val3) in &self.pivot_caches {
@@ -627,6 +1430,210 @@ impl Spreadsheet {
         self
     }
 
+    /// Get the formula cells of the workbook that refer to a cell.
+    /// # Arguments
+    /// * `sheet_name` - sheet of the cell.
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or a `Coordinate`
+    /// # Return value
+    /// * `Vec<Address>` - the formula cells, in sheet order then row by row.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("B1").set_formula("A1*2");
+    /// let dependents = book.get_dependents("Sheet1", "A1");
+    /// assert_eq!(dependents[0].get_address(), "Sheet1!B1");
+    /// ```
+    pub fn get_dependents<T>(&self, sheet_name: &str, coordinate: T) -> Vec<Address>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let mut result: Vec<Address> = Vec::new();
+        for worksheet in self.get_sheet_collection() {
+            for cell in worksheet.get_cell_collection_sorted() {
+                if !cell.is_formula() {
+                    continue;
+                }
+                let is_dependent = get_formula_references(cell.get_formula()).iter().any(
+                    |(ref_sheet_name, range)| {
+                        let ref_sheet_name = if ref_sheet_name.is_empty() {
+                            worksheet.get_name()
+                        } else {
+                            ref_sheet_name.as_str()
+                        };
+                        ref_sheet_name.eq_ignore_ascii_case(sheet_name)
+                            && is_in_range(range, &col, &row)
+                    },
+                );
+                if is_dependent {
+                    let mut address = Address::default();
+                    address
+                        .set_sheet_name(worksheet.get_name())
+                        .get_range_mut()
+                        .set_range(cell.get_coordinate().get_coordinate());
+                    result.push(address);
+                }
+            }
+        }
+        result
+    }
+
+    /// Get the cell a defined name points to.
+    /// For a range, the top left cell of the first area is returned.
+    /// # Arguments
+    /// * `name` - defined name. ex) `"TaxRate"`
+    /// # Return value
+    /// * `Option<&Cell>` - `None` if the name or the cell does not exist.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.get_sheet_mut(&0).unwrap().get_cell_mut("B2").set_value_number(0.1);
+    /// book.get_sheet_mut(&0).unwrap().add_defined_name("TaxRate", "Sheet1!$B$2").unwrap();
+    /// let cell = book.get_cell_by_defined_name("TaxRate").unwrap();
+    /// assert_eq!(cell.get_value(), "0.1");
+    /// ```
+    pub fn get_cell_by_defined_name(&self, name: &str) -> Option<&Cell> {
+        let address = self.get_defined_name_addresses(name).into_iter().next()?;
+        let (row, _, col, _) = get_start_and_end_point(&address.get_range().get_range());
+        self.get_sheet_by_name(address.get_sheet_name())?
+            .get_cell((col.max(1), row.max(1)))
+    }
+
+    /// Get the cell a defined name points to in mutable.
+    /// The cell is created if missing.
+    /// # Arguments
+    /// * `name` - defined name. ex) `"TaxRate"`
+    /// # Return value
+    /// * `Option<&mut Cell>` - `None` if the name does not exist.
+    pub fn get_cell_by_defined_name_mut(&mut self, name: &str) -> Option<&mut Cell> {
+        let address = self.get_defined_name_addresses(name).into_iter().next()?;
+        let (row, _, col, _) = get_start_and_end_point(&address.get_range().get_range());
+        Some(
+            self.get_sheet_by_name_mut(address.get_sheet_name())?
+                .get_cell_mut((col.max(1), row.max(1))),
+        )
+    }
+
+    /// Get the existing cells of the range a defined name points to.
+    /// # Arguments
+    /// * `name` - defined name. ex) `"Prices"`
+    /// # Return value
+    /// * `Vec<&Cell>` - in area order, then row by row.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_number(1);
+    /// worksheet.get_cell_mut("A2").set_value_number(2);
+    /// worksheet.add_defined_name("Prices", "Sheet1!$A$1:$A$3").unwrap();
+    /// assert_eq!(book.get_range_by_defined_name("Prices").len(), 2);
+    /// ```
+    pub fn get_range_by_defined_name(&self, name: &str) -> Vec<&Cell> {
+        let mut result: Vec<&Cell> = Vec::new();
+        for address in self.get_defined_name_addresses(name) {
+            let worksheet = match self.get_sheet_by_name(address.get_sheet_name()) {
+                Some(v) => v,
+                None => continue,
+            };
+            let range = address.get_range().get_range();
+            for cell in worksheet.get_cell_collection_sorted() {
+                let coordinate = cell.get_coordinate();
+                if is_in_range(&range, coordinate.get_col_num(), coordinate.get_row_num()) {
+                    result.push(cell);
+                }
+            }
+        }
+        result
+    }
+
+    /// Get the cells of the range a defined name points to in mutable.
+    /// Missing cells of a bounded range are created.
+    /// Whole column and whole row ranges only return the existing cells.
+    /// # Arguments
+    /// * `name` - defined name. ex) `"Prices"`
+    /// # Return value
+    /// * `Vec<&mut Cell>` - in sheet order, then row by row.
+    pub fn get_range_by_defined_name_mut(&mut self, name: &str) -> Vec<&mut Cell> {
+        let address_list = self.get_defined_name_addresses(name);
+        for address in &address_list {
+            let range = address.get_range().get_range();
+            let (row_start, _, col_start, _) = get_start_and_end_point(&range);
+            let worksheet = match self.get_sheet_by_name_mut(address.get_sheet_name()) {
+                Some(v) => v,
+                None => continue,
+            };
+            if row_start != 0 && col_start != 0 {
+                for coordinate in get_coordinate_list(&range) {
+                    worksheet.get_cell_mut(coordinate);
+                }
+            }
+        }
+
+        let mut result: Vec<&mut Cell> = Vec::new();
+        for worksheet in &mut self.work_sheet_collection {
+            let range_list: Vec<String> = address_list
+                .iter()
+                .filter(|v| {
+                    v.get_sheet_name()
+                        .eq_ignore_ascii_case(worksheet.get_name())
+                })
+                .map(|v| v.get_range().get_range())
+                .collect();
+            if range_list.is_empty() {
+                continue;
+            }
+            let mut cells: Vec<&mut Cell> = worksheet
+                .get_cell_collection_mut()
+                .into_iter()
+                .filter(|cell| {
+                    let coordinate = cell.get_coordinate();
+                    range_list.iter().any(|range| {
+                        is_in_range(range, coordinate.get_col_num(), coordinate.get_row_num())
+                    })
+                })
+                .collect();
+            cells.sort_by_key(|v| {
+                (
+                    *v.get_coordinate().get_row_num(),
+                    *v.get_coordinate().get_col_num(),
+                )
+            });
+            result.extend(cells);
+        }
+        result
+    }
+
+    /// Resolve a defined name to addresses with the sheet name filled in.
+    /// Workbook scoped names win over sheet scoped names.
+    fn get_defined_name_addresses(&self, name: &str) -> Vec<Address> {
+        let mut defined_names: Vec<(&str, &DefinedName)> =
+            self.get_defined_names().iter().map(|v| ("", v)).collect();
+        for worksheet in self.get_sheet_collection() {
+            for defined_name in worksheet.get_defined_names() {
+                defined_names.push((worksheet.get_name(), defined_name));
+            }
+        }
+        let (sheet_name, defined_name) = match defined_names
+            .into_iter()
+            .find(|(_, v)| v.get_name().eq_ignore_ascii_case(name))
+        {
+            Some(v) => v,
+            None => return Vec::new(),
+        };
+        defined_name
+            .get_address_obj()
+            .iter()
+            .map(|v| {
+                let mut address = v.clone();
+                if address.get_sheet_name().is_empty() {
+                    address.set_sheet_name(sheet_name);
+                }
+                address
+            })
+            .collect()
+    }
+
     /// Get Defined Name (Vec).
     pub fn get_defined_names(&self) -> &Vec<DefinedName> {
         &self.defined_names
@@ -651,6 +1658,70 @@ impl Spreadsheet {
         self.defined_names.push(value);
     }
 }
+/// Iterates over the worksheets in tab order.
+impl<'a> IntoIterator for &'a Spreadsheet {
+    type Item = &'a Worksheet;
+    type IntoIter = std::slice::Iter<'a, Worksheet>;
+
+    /// Iterate over the worksheets.
+    /// A lazily read worksheet is not deserialized, so it has no cells yet.
+    fn into_iter(self) -> Self::IntoIter {
+        self.get_sheet_collection_no_check().iter()
+    }
+}
+impl<'a> IntoIterator for &'a mut Spreadsheet {
+    type Item = &'a mut Worksheet;
+    type IntoIter = std::slice::IterMut<'a, Worksheet>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.get_sheet_collection_mut().iter_mut()
+    }
+}
+impl Index<usize> for Spreadsheet {
+    type Output = Worksheet;
+
+    /// Get Work Sheet by index.
+    /// # Panics
+    /// Panics if the sheet does not exist.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = &book[0];
+    /// ```
+    fn index(&self, index: usize) -> &Worksheet {
+        self.get_sheet(&index)
+            .unwrap_or_else(|| panic!("Sheet index {} is out of range", index))
+    }
+}
+impl IndexMut<usize> for Spreadsheet {
+    fn index_mut(&mut self, index: usize) -> &mut Worksheet {
+        self.get_sheet_mut(&index)
+            .unwrap_or_else(|| panic!("Sheet index {} is out of range", index))
+    }
+}
+impl Index<&str> for Spreadsheet {
+    type Output = Worksheet;
+
+    /// Get Work Sheet by name.
+    /// # Panics
+    /// Panics if the sheet does not exist.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book["Sheet1"]["B3"].set_value("Hello");
+    /// assert_eq!(book["Sheet1"]["B3"].get_value(), "Hello");
+    /// ```
+    fn index(&self, sheet_name: &str) -> &Worksheet {
+        self.get_sheet_by_name(sheet_name)
+            .unwrap_or_else(|| panic!("Sheet '{}' not found", sheet_name))
+    }
+}
+impl IndexMut<&str> for Spreadsheet {
+    fn index_mut(&mut self, sheet_name: &str) -> &mut Worksheet {
+        self.get_sheet_by_name_mut(sheet_name)
+            .unwrap_or_else(|| panic!("Sheet '{}' not found", sheet_name))
+    }
+}
 impl AdjustmentCoordinateWithSheet for Spreadsheet {
     fn adjustment_insert_coordinate_with_sheet(
         &mut self,
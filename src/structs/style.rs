@@ -277,6 +277,95 @@ impl Style {
         def
     }
 
+    /// Fill the parts left unset with the default style.
+    pub(crate) fn set_missing_default_value(&mut self) -> &mut Self {
+        if self.font.is_none() {
+            self.font = Some(Font::get_default_value());
+        }
+        if self.fill.is_none() {
+            self.fill = Some(Fill::get_default_value());
+        }
+        if self.borders.is_none() {
+            self.borders = Some(Borders::get_default_value());
+        }
+        if self.numbering_format.is_none() {
+            self.numbering_format = Some(NumberingFormat::default());
+        }
+        self
+    }
+
+    /// Fill the parts left unset with the parts of the given style.
+    pub(crate) fn set_missing_value(&mut self, value: &Style) -> &mut Self {
+        if self.font.is_none() {
+            self.font.clone_from(&value.font);
+        }
+        if self.fill.is_none() {
+            self.fill.clone_from(&value.fill);
+        }
+        if self.borders.is_none() {
+            self.borders.clone_from(&value.borders);
+        }
+        if self.alignment.is_none() {
+            self.alignment.clone_from(&value.alignment);
+        }
+        if self.numbering_format.is_none() {
+            self.numbering_format.clone_from(&value.numbering_format);
+        }
+        if self.protection.is_none() {
+            self.protection.clone_from(&value.protection);
+        }
+        self
+    }
+
+    /// Whether the two styles look the same once the parts left unset are filled with the given default style.
+    /// The cell style the format is based on is not written, so it is left out.
+    pub(crate) fn is_same_effective(&self, other: &Style, default_style: &Style) -> bool {
+        let effective = |value: &Style| {
+            let mut style = value.clone();
+            style.set_missing_value(default_style);
+            style.set_missing_default_value();
+            style.format_id = UInt32Value::default();
+            style
+        };
+        effective(self) == effective(other)
+    }
+
+    /// Hash of the style once the parts left unset are filled with the default style.
+    pub(crate) fn get_hash_code(&self) -> String {
+        let mut style = self.clone();
+        style.set_missing_default_value();
+        format!(
+            "{:x}",
+            md5::Md5::digest(format!(
+                "{}{}{}{}{}{}",
+                style
+                    .font
+                    .as_ref()
+                    .map_or(String::new(), |v| v.get_hash_code()),
+                style
+                    .fill
+                    .as_ref()
+                    .map_or(String::new(), |v| v.get_hash_code()),
+                style
+                    .borders
+                    .as_ref()
+                    .map_or(String::new(), |v| v.get_hash_code()),
+                style
+                    .alignment
+                    .as_ref()
+                    .map_or(String::new(), |v| v.get_hash_code()),
+                style
+                    .numbering_format
+                    .as_ref()
+                    .map_or(String::new(), |v| v.get_hash_code()),
+                style
+                    .protection
+                    .as_ref()
+                    .map_or(String::new(), |v| v.get_hash_code()),
+            ))
+        )
+    }
+
     pub(crate) fn get_default_value_2() -> Self {
         let mut def = Self::default();
         def.set_font(Font::get_default_value());
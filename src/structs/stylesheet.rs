@@ -5,18 +5,21 @@ use super::CellFormats;
 use super::CellStyleFormats;
 use super::CellStyles;
 use super::Colors;
+use super::CompatibilityValues;
 use super::DifferentialFormats;
 use super::Fills;
 use super::Fonts;
 use super::NumberingFormats;
 use super::Protection;
 use super::Style;
+use hashbrown::HashMap;
 use helper::const_str::*;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
 use reader::driver::*;
 use std::io::Cursor;
+use std::sync::Arc;
 use writer::driver::*;
 
 #[derive(Clone, Default, Debug)]
@@ -30,7 +33,10 @@ pub(crate) struct Stylesheet {
     cell_styles: CellStyles,
     differential_formats: DifferentialFormats,
     colors: Colors,
-    maked_style_list: Vec<Style>,
+    maked_style_list: Vec<Arc<Style>>,
+    // xf index of each shared style handle, keyed by its address.
+    // The handle is kept so that the address is not reused.
+    shared_style_index: HashMap<usize, (Arc<Style>, u32)>,
 }
 
 impl Stylesheet {
@@ -151,10 +157,49 @@ impl Stylesheet {
         self
     }
 
-    pub(crate) fn get_style(&self, id: usize) -> Style {
+    pub(crate) fn get_style(&self, id: usize) -> Arc<Style> {
         self.maked_style_list.get(id).unwrap().clone()
     }
 
+    /// (This method is crate only.)
+    /// Get the style cells fall back to: the Normal cell style and the first cell format,
+    /// with the parts left unset taken from the first font, fill and border.
+    pub(crate) fn get_default_style(&self) -> Style {
+        let normal_id = self
+            .cell_styles
+            ._get_cell_style()
+            .iter()
+            .find(|v| v.get_builtin_id() == &0)
+            .map_or(0, |v| *v.get_format_id());
+        let mut style = self
+            .maked_style_list
+            .first()
+            .map(|v| v.as_ref().clone())
+            .unwrap_or_default();
+        if let Some(normal) = self
+            .cell_style_formats
+            .get_cell_format()
+            .get(normal_id as usize)
+        {
+            let mut normal_style = Style::default();
+            self.get_style_by_cell_format(&mut normal_style, &CellFormat::default(), normal);
+            style.set_missing_value(&normal_style);
+        }
+        let mut base = Style::default();
+        if let Some(v) = self.fonts.get_font().first() {
+            base.set_font(v.clone());
+        }
+        if let Some(v) = self.fills.get_fill().first() {
+            base.set_fill(v.clone());
+        }
+        if let Some(v) = self.borders.get_borders().first() {
+            base.set_borders(v.clone());
+        }
+        style.set_missing_value(&base);
+        style.set_missing_default_value();
+        style
+    }
+
     pub(crate) fn make_style(&mut self) -> &mut Self {
         for cell_format in self.cell_formats.get_cell_format() {
             let def_cell_format = self
@@ -166,7 +211,7 @@ impl Stylesheet {
 
             let mut style = Style::default();
             self.get_style_by_cell_format(&mut style, &def_cell_format, cell_format);
-            self.maked_style_list.push(style);
+            self.maked_style_list.push(Arc::new(style));
         }
 
         self
@@ -275,6 +320,18 @@ impl Stylesheet {
         }
     }
 
+    /// (This method is crate only.)
+    /// Same as `set_style`, but a handle shared by many cells is looked up only once.
+    pub(crate) fn set_style_shared(&mut self, style: &Arc<Style>) -> u32 {
+        let key = Arc::as_ptr(style) as usize;
+        if let Some((_, index)) = self.shared_style_index.get(&key) {
+            return *index;
+        }
+        let index = self.set_style(style);
+        self.shared_style_index.insert(key, (style.clone(), index));
+        index
+    }
+
     pub(crate) fn set_style(&mut self, style: &Style) -> u32 {
         let mut index = 0;
         let def_style = Style::default();
@@ -282,7 +339,7 @@ impl Stylesheet {
             return index;
         }
         for maked_style in &self.maked_style_list {
-            if style == maked_style {
+            if std::ptr::eq(style, maked_style.as_ref()) || style == maked_style.as_ref() {
                 return index;
             }
             index += 1;
@@ -327,7 +384,7 @@ impl Stylesheet {
             cell_format.set_apply_protection(true);
         }
 
-        self.maked_style_list.push(style.clone());
+        self.maked_style_list.push(Arc::new(style.clone()));
         self.cell_formats.set_cell_format(cell_format);
         index
     }
@@ -344,7 +401,7 @@ impl Stylesheet {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.numbering_formats.get_build_in_formats();
 
         xml_read_loop!(
@@ -352,63 +409,65 @@ impl Stylesheet {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"numFmts" => {
-                        self.numbering_formats.set_attributes(reader, e);
+                        self.numbering_formats.set_attributes(reader, e)?;
                     }
                     b"fonts" => {
-                        self.fonts.set_attributes(reader, e);
+                        self.fonts.set_attributes(reader, e)?;
                     }
                     b"fills" => {
-                        self.fills.set_attributes(reader, e);
+                        self.fills.set_attributes(reader, e)?;
                     }
                     b"borders" => {
-                        self.borders.set_attributes(reader, e);
+                        self.borders.set_attributes(reader, e)?;
                     }
                     b"cellStyleXfs" => {
-                        self.cell_style_formats.set_attributes(reader, e);
+                        self.cell_style_formats.set_attributes(reader, e)?;
                     }
                     b"cellXfs" => {
-                        self.cell_formats.set_attributes(reader, e);
+                        self.cell_formats.set_attributes(reader, e)?;
                     }
                     b"cellStyles" => {
-                        self.cell_styles.set_attributes(reader, e);
+                        self.cell_styles.set_attributes(reader, e)?;
                     }
                     b"dxfs" => {
-                        self.differential_formats.set_attributes(reader, e);
+                        self.differential_formats.set_attributes(reader, e)?;
                     }
                     b"colors" => {
-                        self.colors.set_attributes(reader, e);
+                        self.colors.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"styleSheet" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "styleSheet")
+            Event::Eof => return Err(eof_error("styleSheet"))
         );
     }
 
-    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        compatibility: &CompatibilityValues,
+    ) {
+        let is_minimal = compatibility == &CompatibilityValues::Minimal;
+
         // styleSheet
-        write_start_tag(
-            writer,
-            "styleSheet",
-            vec![
-                ("xmlns", SHEET_MAIN_NS),
-                ("xmlns:mc", MC_NS),
-                ("mc:Ignorable", "x14ac"),
-                ("xmlns:x14ac", SHEETML_AC_NS),
-            ],
-            false,
-        );
+        let mut attributes: Vec<(&str, &str)> = vec![("xmlns", SHEET_MAIN_NS)];
+        if !is_minimal {
+            attributes.push(("xmlns:mc", MC_NS));
+            attributes.push(("mc:Ignorable", "x14ac"));
+            attributes.push(("xmlns:x14ac", SHEETML_AC_NS));
+        }
+        write_start_tag(writer, "styleSheet", attributes, false);
 
         // numFmts
         self.numbering_formats.write_to(writer);
 
         // fonts
-        self.fonts.write_to(writer);
+        self.fonts.write_to(writer, !is_minimal);
 
         // fills
         self.fills.write_to(writer);
@@ -431,43 +490,45 @@ impl Stylesheet {
         // colors
         self.colors.write_to(writer);
 
-        // tableStyles
-        write_start_tag(
-            writer,
-            "tableStyles",
-            vec![
-                ("count", "0"),
-                ("defaultTableStyle", "TableStyleMedium2"),
-                ("defaultPivotStyle", "PivotStyleMedium9"),
-            ],
-            true,
-        );
-
-        // extLst
-        write_start_tag(writer, "extLst", vec![], false);
-
-        // ext
-        write_start_tag(
-            writer,
-            "ext",
-            vec![
-                ("uri", "{EB79DEF2-80B8-43e5-95BD-54CBDDF9020C}"),
-                ("xmlns:x14", SHEET_MS_MAIN_NS),
-            ],
-            false,
-        );
-
-        // x14:slicerStyles
-        write_start_tag(
-            writer,
-            "x14:slicerStyles",
-            vec![("defaultSlicerStyle", "SlicerStyleLight1")],
-            true,
-        );
-
-        write_end_tag(writer, "ext");
-
-        write_end_tag(writer, "extLst");
+        if !is_minimal {
+            // tableStyles
+            write_start_tag(
+                writer,
+                "tableStyles",
+                vec![
+                    ("count", "0"),
+                    ("defaultTableStyle", "TableStyleMedium2"),
+                    ("defaultPivotStyle", "PivotStyleMedium9"),
+                ],
+                true,
+            );
+
+            // extLst
+            write_start_tag(writer, "extLst", vec![], false);
+
+            // ext
+            write_start_tag(
+                writer,
+                "ext",
+                vec![
+                    ("uri", "{EB79DEF2-80B8-43e5-95BD-54CBDDF9020C}"),
+                    ("xmlns:x14", SHEET_MS_MAIN_NS),
+                ],
+                false,
+            );
+
+            // x14:slicerStyles
+            write_start_tag(
+                writer,
+                "x14:slicerStyles",
+                vec![("defaultSlicerStyle", "SlicerStyleLight1")],
+                true,
+            );
+
+            write_end_tag(writer, "ext");
+
+            write_end_tag(writer, "extLst");
+        }
 
         write_end_tag(writer, "styleSheet");
     }
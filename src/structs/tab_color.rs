@@ -178,29 +178,30 @@ impl TabColor {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         for a in e.attributes().with_checks(false) {
             match a {
                 Ok(ref attr) if attr.key.0 == b"indexed" => {
                     self.indexed
-                        .set_value_string(get_attribute_value(attr).unwrap());
+                        .set_value_string(get_attribute_value(attr)?)?;
                 }
                 Ok(ref attr) if attr.key.0 == b"theme" => {
                     self.theme_index
-                        .set_value_string(get_attribute_value(attr).unwrap());
+                        .set_value_string(get_attribute_value(attr)?)?;
                 }
                 Ok(ref attr) if attr.key.0 == b"rgb" => {
                     self.argb
-                        .set_value_string(get_attribute_value(attr).unwrap());
+                        .set_value_string(get_attribute_value(attr)?);
                 }
                 Ok(ref attr) if attr.key.0 == b"tint" => {
                     self.tint
-                        .set_value_string(get_attribute_value(attr).unwrap());
+                        .set_value_string(get_attribute_value(attr)?);
                 }
                 Ok(_) => {}
                 Err(_) => {}
             }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
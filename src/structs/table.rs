@@ -4,6 +4,7 @@ use quick_xml::{
 };
 
 use super::coordinate::*;
+use super::QueryTable;
 use crate::helper::coordinate::*;
 //use reader::driver::*;
 
@@ -14,6 +15,7 @@ pub struct Table {
     display_name: String,
     columns: Vec<TableColumn>,
     style_info: Option<TableStyleInfo>,
+    query_table: Option<QueryTable>,
 }
 impl Table {
     pub fn new<T>(name: &str, area: (T, T)) -> Self
@@ -29,6 +31,7 @@ impl Table {
             display_name: name,
             columns: Vec::<TableColumn>::default(),
             style_info: None,
+            query_table: None,
         }
     }
 
@@ -95,6 +98,19 @@ impl Table {
         self.style_info = style_info;
     }
 
+    /// Get the query table, if the table is refreshed from an external connection.
+    pub fn get_query_table(&self) -> Option<&QueryTable> {
+        self.query_table.as_ref()
+    }
+
+    pub fn has_query_table(&self) -> bool {
+        self.query_table.is_some()
+    }
+
+    pub(crate) fn set_query_table(&mut self, value: QueryTable) {
+        self.query_table = Some(value);
+    }
+
     fn cell_coord_to_coord<T>(cc: T) -> Coordinate
     where
         T: Into<CellCoordinates>,
@@ -110,11 +126,15 @@ impl Table {
 #[derive(Clone, Default, Debug)]
 pub struct TableColumn {
     name: String,
+    unique_name: String,
+    query_table_field_id: Option<u32>,
 }
 impl TableColumn {
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
+            unique_name: String::new(),
+            query_table_field_id: None,
         }
     }
 
@@ -125,6 +145,23 @@ impl TableColumn {
     pub fn set_name(&mut self, name: String) {
         self.name = name;
     }
+
+    pub fn get_unique_name(&self) -> &str {
+        self.unique_name.as_str()
+    }
+
+    pub(crate) fn set_unique_name(&mut self, unique_name: String) {
+        self.unique_name = unique_name;
+    }
+
+    /// Get id of the query table field bound to this column.
+    pub fn get_query_table_field_id(&self) -> Option<&u32> {
+        self.query_table_field_id.as_ref()
+    }
+
+    pub(crate) fn set_query_table_field_id(&mut self, value: u32) {
+        self.query_table_field_id = Some(value);
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -0,0 +1,158 @@
+/// Values for `Spreadsheet::fill_template` and `Worksheet::fill_template`.
+/// A `{{name}}` placeholder is replaced with the value set by `set_value`.
+/// A row with `{{list.field}}` placeholders is repeated once per record added to `list`.
+/// # Examples
+/// ```
+/// let mut values = umya_spreadsheet::TemplateValues::default();
+/// values.set_value("customer", "ACME");
+/// values.add_record("items", &[("name", "Pen"), ("price", "1.5")]);
+/// values.add_record("items", &[("name", "Ink"), ("price", "3")]);
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct TemplateValues {
+    values: Vec<(String, String)>,
+    lists: Vec<(String, Vec<Vec<(String, String)>>)>,
+}
+impl TemplateValues {
+    /// Get the value of a placeholder.
+    pub fn get_value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Set the value of a placeholder.
+    /// # Arguments
+    /// * `name` - ex) `"customer"` for `{{customer}}`
+    /// * `value` - replacement text.
+    pub fn set_value<S: Into<String>, V: Into<String>>(&mut self, name: S, value: V) -> &mut Self {
+        let name = name.into();
+        let value = value.into();
+        match self.values.iter_mut().find(|(k, _)| k == &name) {
+            Some(v) => v.1 = value,
+            None => self.values.push((name, value)),
+        }
+        self
+    }
+
+    /// Get the records of a list.
+    pub fn get_records(&self, list: &str) -> Option<&Vec<Vec<(String, String)>>> {
+        self.lists.iter().find(|(k, _)| k == list).map(|(_, v)| v)
+    }
+
+    /// Add a list without records.
+    /// The rows of a list without records are removed.
+    /// # Arguments
+    /// * `list` - ex) `"items"` for `{{items.name}}`
+    pub fn add_list<S: Into<String>>(&mut self, list: S) -> &mut Self {
+        let list = list.into();
+        if self.get_records(&list).is_none() {
+            self.lists.push((list, Vec::new()));
+        }
+        self
+    }
+
+    /// Add a record to a list.
+    /// # Arguments
+    /// * `list` - ex) `"items"` for `{{items.name}}`
+    /// * `fields` - field names and values of the record.
+    pub fn add_record<S: Into<String>>(&mut self, list: S, fields: &[(&str, &str)]) -> &mut Self {
+        let list = list.into();
+        let record: Vec<(String, String)> = fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        match self.lists.iter_mut().find(|(k, _)| k == &list) {
+            Some(v) => v.1.push(record),
+            None => self.lists.push((list, vec![record])),
+        }
+        self
+    }
+
+    /// (This method is crate only.)
+    /// True when the text is one placeholder and nothing else.
+    pub(crate) fn is_placeholder_only(text: &str) -> bool {
+        let text = text.trim();
+        text.starts_with("{{") && text.ends_with("}}") && get_placeholders(text).len() == 1
+    }
+
+    /// (This method is crate only.)
+    /// Get the list used by the `{{list.field}}` placeholders of a text.
+    pub(crate) fn find_list(&self, text: &str) -> Option<&str> {
+        get_placeholders(text).into_iter().find_map(|name| {
+            let list = name.split('.').next().unwrap_or("");
+            self.lists
+                .iter()
+                .find(|(k, _)| name.contains('.') && k == list)
+                .map(|(k, _)| k.as_str())
+        })
+    }
+
+    /// (This method is crate only.)
+    /// Replace the `{{name}}` placeholders of a text.
+    /// `None` is returned when no placeholder is replaced.
+    pub(crate) fn replace(&self, text: &str) -> Option<String> {
+        replace_placeholders(text, |name| self.get_value(name))
+    }
+
+    /// (This method is crate only.)
+    /// Replace the `{{list.field}}` placeholders of a text with a record of the list.
+    pub(crate) fn replace_record(&self, text: &str, list: &str, index: usize) -> Option<String> {
+        let record = self.get_records(list)?.get(index)?;
+        replace_placeholders(text, |name| {
+            let field = name.strip_prefix(list)?.strip_prefix('.')?;
+            record
+                .iter()
+                .find(|(k, _)| k == field)
+                .map(|(_, v)| v.as_str())
+        })
+    }
+}
+
+fn get_placeholders(text: &str) -> Vec<&str> {
+    let mut result: Vec<&str> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                result.push(after[..end].trim());
+                rest = &after[end + 2..];
+            }
+            None => break,
+        }
+    }
+    result
+}
+
+fn replace_placeholders<'a, F>(text: &str, f: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<&'a str>,
+{
+    let mut result = String::new();
+    let mut is_replaced = false;
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = match after.find("}}") {
+            Some(v) => v,
+            None => break,
+        };
+        result.push_str(&rest[..start]);
+        match f(after[..end].trim()) {
+            Some(v) => {
+                result.push_str(v);
+                is_replaced = true;
+            }
+            None => result.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+    result.push_str(rest);
+    if is_replaced {
+        Some(result)
+    } else {
+        None
+    }
+}
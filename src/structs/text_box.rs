@@ -0,0 +1,197 @@
+use structs::drawing::spreadsheet::MarkerType;
+use structs::drawing::spreadsheet::Shape;
+use structs::drawing::spreadsheet::TwoCellAnchor;
+use structs::RichText;
+use structs::ShapeType;
+use traits::AdjustmentCoordinate;
+
+/// ## Example
+/// ```rust
+/// extern crate umya_spreadsheet;
+/// let mut book = umya_spreadsheet::new_file();
+///
+/// // Add TextBox
+/// let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// from_marker.set_coordinate("B2");
+/// let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+/// to_marker.set_coordinate("E6");
+/// let mut text_box = umya_spreadsheet::structs::TextBox::default();
+/// text_box.new_text_box(from_marker, to_marker);
+/// text_box.set_text("Sales are up 12%");
+/// text_box.set_fill_color("FFFFF2CC");
+/// text_box.set_border("FFBF9000", 12700);
+/// book.get_sheet_by_name_mut("Sheet1").unwrap().add_text_box(text_box);
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextBox {
+    two_cell_anchor: Box<TwoCellAnchor>,
+}
+
+impl TextBox {
+    pub fn get_two_cell_anchor(&self) -> &TwoCellAnchor {
+        &self.two_cell_anchor
+    }
+
+    pub fn get_two_cell_anchor_mut(&mut self) -> &mut TwoCellAnchor {
+        &mut self.two_cell_anchor
+    }
+
+    pub fn set_two_cell_anchor(&mut self, value: TwoCellAnchor) -> &mut Self {
+        self.two_cell_anchor = Box::new(value);
+        self
+    }
+
+    /// Create a new text box between the two markers.
+    /// It has a white fill, a thin black border and no text.
+    /// # Arguments
+    /// * `from_marker` - Top left of the text box.
+    /// * `to_marker` - Bottom right of the text box.
+    pub fn new_text_box(&mut self, from_marker: MarkerType, to_marker: MarkerType) -> &mut Self {
+        let mut anchor = TwoCellAnchor::default();
+        anchor.set_from_marker(from_marker);
+        anchor.set_to_marker(to_marker);
+        anchor.set_shape(Self::make_shape());
+        self.set_two_cell_anchor(anchor);
+        self
+    }
+
+    fn make_shape() -> Shape {
+        let mut shape = Shape::default();
+        shape.new_shape(ShapeType::Rectangle);
+        shape
+            .get_non_visual_shape_properties_mut()
+            .set_text_box(true)
+            .get_non_visual_drawing_properties_mut()
+            .set_name("TextBox 1");
+        shape
+            .get_text_body_mut()
+            .unwrap()
+            .get_body_properties_mut()
+            .set_anchor("t");
+        shape
+    }
+
+    /// Get the shape of the text box.
+    /// None until `new_text_box` is called or the text box is edited.
+    pub fn get_shape(&self) -> Option<&Shape> {
+        self.two_cell_anchor.get_shape()
+    }
+
+    /// Get the shape of the text box in mutable.
+    /// The shape is created with the look `new_text_box` gives when there is none.
+    pub fn get_shape_mut(&mut self) -> &mut Shape {
+        if self.two_cell_anchor.get_shape().is_none() {
+            self.two_cell_anchor.set_shape(Self::make_shape());
+        }
+        self.two_cell_anchor.get_shape_mut().unwrap()
+    }
+
+    /// Get the plain text. Paragraphs are joined with a line feed.
+    pub fn get_text(&self) -> String {
+        self.get_shape().map_or(String::new(), |v| v.get_text())
+    }
+
+    /// Set plain text. A line feed starts a new paragraph.
+    /// # Arguments
+    /// * `value` - Text.
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.get_shape_mut().set_text(value);
+        self
+    }
+
+    /// Set rich text. The font of each element (name, size, bold, italic, color) is kept.
+    /// A line feed starts a new paragraph.
+    /// # Arguments
+    /// * `value` - RichText.
+    pub fn set_rich_text(&mut self, value: &RichText) -> &mut Self {
+        self.get_shape_mut().set_rich_text(value);
+        self
+    }
+
+    /// Set fill color.
+    /// # Arguments
+    /// * `argb` - Color. ex) `"FFFFFF00"`
+    pub fn set_fill_color<S: Into<String>>(&mut self, argb: S) -> &mut Self {
+        self.get_shape_mut().set_fill_color(argb);
+        self
+    }
+
+    /// Remove fill (transparent background).
+    pub fn remove_fill(&mut self) -> &mut Self {
+        self.get_shape_mut().remove_fill();
+        self
+    }
+
+    /// Set border.
+    /// # Arguments
+    /// * `argb` - Color. ex) `"FF000000"`
+    /// * `width` - Width (EMU). ex) `12700` = 1pt
+    pub fn set_border<S: Into<String>>(&mut self, argb: S, width: u32) -> &mut Self {
+        self.get_shape_mut().set_border(argb, width);
+        self
+    }
+
+    /// Remove border.
+    pub fn remove_border(&mut self) -> &mut Self {
+        self.get_shape_mut().remove_border();
+        self
+    }
+
+    pub fn get_coordinate(&self) -> String {
+        self.two_cell_anchor.get_from_marker().get_coordinate()
+    }
+
+    pub(crate) fn get_col(&self) -> &u32 {
+        self.two_cell_anchor.get_from_marker().get_col()
+    }
+
+    pub(crate) fn get_row(&self) -> &u32 {
+        self.two_cell_anchor.get_from_marker().get_row()
+    }
+}
+impl AdjustmentCoordinate for TextBox {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.two_cell_anchor.as_mut().adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) {
+        self.two_cell_anchor.as_mut().adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: &u32,
+        offset_col_num: &u32,
+        root_row_num: &u32,
+        offset_row_num: &u32,
+    ) -> bool {
+        self.two_cell_anchor.as_ref().is_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        )
+    }
+}
@@ -80,19 +80,19 @@ impl TextElement {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.text = obj;
                     }
                     b"rPr" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_run_properties(obj);
                     }
                     _ => (),
@@ -100,10 +100,10 @@ impl TextElement {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"r" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "r")
+            Event::Eof => return Err(eof_error("r"))
         );
     }
 
@@ -2,6 +2,7 @@
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
+use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
@@ -69,30 +70,30 @@ impl ToMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::from("");
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
-                Ok(Event::Text(e)) => string_value = e.unescape().unwrap().to_string(),
+                Ok(Event::Text(e)) => string_value = e.unescape()?.to_string(),
                 Ok(Event::End(ref e)) => match e.name().0 {
                     b"xdr:col" => {
-                        self.col = string_value.parse::<usize>().unwrap();
+                        self.col = string_value.parse::<usize>().map_err(parse_error)?;
                     }
                     b"xdr:colOff" => {
-                        self.col_off = string_value.parse::<usize>().unwrap();
+                        self.col_off = string_value.parse::<usize>().map_err(parse_error)?;
                     }
                     b"xdr:row" => {
-                        self.row = string_value.parse::<usize>().unwrap();
+                        self.row = string_value.parse::<usize>().map_err(parse_error)?;
                     }
                     b"xdr:rowOff" => {
-                        self.row_off = string_value.parse::<usize>().unwrap();
+                        self.row_off = string_value.parse::<usize>().map_err(parse_error)?;
                     }
-                    b"to" => return,
+                    b"to" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "to"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(eof_error("to")),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
@@ -0,0 +1,85 @@
+// top10
+use quick_xml::events::BytesStart;
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::BooleanValue;
+use structs::DoubleValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug)]
+pub struct Top10 {
+    top: BooleanValue,
+    percent: BooleanValue,
+    value: DoubleValue,
+    filter_value: DoubleValue,
+}
+
+impl Top10 {
+    pub fn get_top(&self) -> &bool {
+        self.top.get_value()
+    }
+
+    pub fn set_top(&mut self, value: bool) -> &mut Self {
+        self.top.set_value(value);
+        self
+    }
+
+    pub fn get_percent(&self) -> &bool {
+        self.percent.get_value()
+    }
+
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    pub fn get_value(&self) -> &f64 {
+        self.value.get_value()
+    }
+
+    pub fn set_value(&mut self, value: f64) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub fn get_filter_value(&self) -> &f64 {
+        self.filter_value.get_value()
+    }
+
+    pub fn set_filter_value(&mut self, value: f64) -> &mut Self {
+        self.filter_value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, top, "top");
+        set_string_from_xml!(self, e, percent, "percent");
+        set_string_from_xml!(self, e, value, "val");
+        set_string_from_xml!(self, e, filter_value, "filterVal");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // top10
+        let mut attributes: Vec<(&str, &str)> = Vec::new();
+        if self.top.has_value() {
+            attributes.push(("top", self.top.get_value_string()));
+        }
+        if self.percent.has_value() {
+            attributes.push(("percent", self.percent.get_value_string()));
+        }
+        let value = self.value.get_value_string();
+        attributes.push(("val", &value));
+        let filter_value = self.filter_value.get_value_string();
+        if self.filter_value.has_value() {
+            attributes.push(("filterVal", &filter_value));
+        }
+        write_start_tag(writer, "top10", attributes, true);
+    }
+}
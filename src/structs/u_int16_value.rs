@@ -1,4 +1,6 @@
-#[derive(Clone, Default, Debug)]
+use XlsxError;
+
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct UInt16Value {
     value: Option<u16>,
 }
@@ -16,8 +18,15 @@ impl UInt16Value {
         self
     }
 
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut UInt16Value {
-        self.set_value(value.into().parse::<u16>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut UInt16Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<u16>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn _has_value(&self) -> bool {
@@ -1,3 +1,5 @@
+use XlsxError;
+
 #[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct UInt32Value {
     value: Option<u32>,
@@ -16,8 +18,15 @@ impl UInt32Value {
         self
     }
 
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.set_value(value.into().parse::<u32>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut Self, XlsxError> {
+        let value = value.into();
+        match value.parse::<u32>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::Format(format!("invalid number `{}`", value))),
+        }
     }
 
     pub(crate) fn remove_value(&mut self) -> &mut Self {
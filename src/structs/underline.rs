@@ -31,9 +31,10 @@ impl Underline {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.set_val(UnderlineValues::default());
-        set_string_from_xml!(self, e, val, "val")
+        set_string_from_xml!(self, e, val, "val");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
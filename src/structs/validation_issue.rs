@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// Kind of a problem found by `Spreadsheet::validate`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationIssueKind {
+    /// Two sheets have the same name (case-insensitive).
+    DuplicateSheetName,
+    /// Sheet name is empty, too long or contains an invalid character.
+    InvalidSheetName,
+    /// Merged ranges overlap each other.
+    OverlappingMergeCells,
+    /// Reference beyond XFD1048576.
+    OutOfRangeReference,
+    /// Relationship or reference to a missing part or sheet.
+    BrokenRelationship,
+    /// Part that is not referenced and will be dropped on write.
+    OrphanPart,
+}
+
+/// A problem found by `Spreadsheet::validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    kind: ValidationIssueKind,
+    sheet_name: String,
+    message: String,
+}
+
+impl ValidationIssue {
+    pub(crate) fn new<S: Into<String>, M: Into<String>>(
+        kind: ValidationIssueKind,
+        sheet_name: S,
+        message: M,
+    ) -> Self {
+        Self {
+            kind,
+            sheet_name: sheet_name.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn get_kind(&self) -> &ValidationIssueKind {
+        &self.kind
+    }
+
+    /// Get the sheet name. (Empty for workbook level issues.)
+    pub fn get_sheet_name(&self) -> &str {
+        &self.sheet_name
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.sheet_name.is_empty() {
+            write!(f, "{:?}: {}", self.kind, self.message)
+        } else {
+            write!(f, "{:?} ({}): {}", self.kind, self.sheet_name, self.message)
+        }
+    }
+}
@@ -21,3 +21,9 @@ pub use self::path::*;
 
 mod text_box;
 pub use self::text_box::*;
+
+mod shape_type_values;
+pub use self::shape_type_values::*;
+
+mod vml_drawing;
+pub(crate) use self::vml_drawing::*;
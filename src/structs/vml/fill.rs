@@ -7,7 +7,7 @@ use structs::StringValue;
 use structs::TrueFalseValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Fill {
     color: StringValue,
     color_2: StringValue,
@@ -56,11 +56,12 @@ impl Fill {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, color_2, "color2");
         set_string_from_xml!(self, e, on, "on");
         set_string_from_xml!(self, e, focus_size, "focussize");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -7,7 +7,7 @@ use structs::raw::RawRelationships;
 use structs::StringValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ImageData {
     image_name: StringValue,
     title: StringValue,
@@ -37,16 +37,18 @@ impl ImageData {
         _reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
-        if let Some(relid) = get_attribute(e, b"o:relid") {
+    ) -> Result<(), XlsxError> {
+        if let Some(relid) = get_attribute(e, b"o:relid")? {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.get_relationship_by_rid(&relid);
+                let relationship = rel.get_relationship_by_rid(&relid)?;
                 self.image_name
                     .set_value_string(relationship.get_raw_file().get_file_name());
             }
         }
 
         set_string_from_xml!(self, e, title, "o:title");
+
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &usize) {
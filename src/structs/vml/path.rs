@@ -7,7 +7,7 @@ use std::io::Cursor;
 use structs::EnumValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Path {
     connection_point_type: EnumValue<ConnectValues>,
 }
@@ -25,8 +25,9 @@ impl Path {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, connection_point_type, "o:connecttype");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -7,7 +7,7 @@ use structs::StringValue;
 use structs::TrueFalseValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Shadow {
     on: TrueFalseValue,
     color: StringValue,
@@ -45,10 +45,11 @@ impl Shadow {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, on, "on");
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, obscured, "obscured");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
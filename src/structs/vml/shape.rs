@@ -19,7 +19,7 @@ use structs::TrueFalseValue;
 use traits::AdjustmentCoordinate;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Shape {
     style: StringValue,
     r_type: StringValue,
@@ -38,6 +38,7 @@ pub struct Shape {
     client_data: ClientData,
     optional_number: Int32Value,
     coordinate_size: StringValue,
+    shape_type_xml: String,
 }
 
 impl Shape {
@@ -222,21 +223,32 @@ impl Shape {
         self
     }
 
+    /// (This method is crate only.)
+    /// Definition (v:shapetype) of a shape type without a model, as read.
+    pub(crate) fn get_shape_type_xml(&self) -> &str {
+        &self.shape_type_xml
+    }
+
+    pub(crate) fn set_shape_type_xml<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.shape_type_xml = value.into();
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r_type, "type");
         set_string_from_xml!(self, e, style, "style");
         set_string_from_xml!(self, e, filled, "filled");
         set_string_from_xml!(self, e, fill_color, "fillcolor");
-        set_string_from_xml!(self, e, stroked, "stoked");
-        set_string_from_xml!(self, e, stroke_color, "stokecolor");
-        set_string_from_xml!(self, e, stroke_weight, "stokeweight");
+        set_string_from_xml!(self, e, stroked, "stroked");
+        set_string_from_xml!(self, e, stroke_color, "strokecolor");
+        set_string_from_xml!(self, e, stroke_weight, "strokeweight");
         set_string_from_xml!(self, e, inset_mode, "o:insetmode");
-        set_string_from_xml!(self, e, optional_number, "o:spt");
+        set_number_from_xml!(self, e, optional_number, "o:spt");
         set_string_from_xml!(self, e, coordinate_size, "coordsize");
 
         xml_read_loop!(
@@ -245,27 +257,27 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:fill" => {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_fill(obj);
                 }
                 b"v:shadow" => {
                     let mut obj = Shadow::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shadow(obj);
                 }
                 b"v:path" => {
                     let mut obj = Path::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_path(obj);
                 }
                 b"v:stroke" => {
                     let mut obj = Stroke::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_stroke(obj);
                 }
                 b"v:imagedata" => {
                     let mut obj = ImageData::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_image_data(obj);
                 }
                 _ => (),
@@ -275,12 +287,12 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:textbox" => {
                     let mut obj = TextBox::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_box(obj);
                 }
                 b"x:ClientData" => {
                     let mut obj = ClientData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_client_data(obj);
                 }
                 _ => (),
@@ -288,10 +300,10 @@ impl Shape {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"v:shape" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "v:shape")
+            Event::Eof => return Err(eof_error("v:shape"))
         );
     }
 
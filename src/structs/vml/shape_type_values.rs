@@ -0,0 +1,179 @@
+use quick_xml::Writer;
+use std::io::Cursor;
+use std::str::FromStr;
+use structs::EnumTrait;
+use writer::driver::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShapeTypeValues {
+    Picture,
+    TextBox,
+    Control,
+}
+impl Default for ShapeTypeValues {
+    fn default() -> Self {
+        Self::Picture
+    }
+}
+impl EnumTrait for ShapeTypeValues {
+    fn get_value_string(&self) -> &str {
+        match &self {
+            Self::Picture => "_x0000_t75",
+            Self::TextBox => "_x0000_t202",
+            Self::Control => "_x0000_t201",
+        }
+    }
+}
+impl FromStr for ShapeTypeValues {
+    type Err = ();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim_start_matches('#') {
+            "_x0000_t75" => Ok(Self::Picture),
+            "_x0000_t202" => Ok(Self::TextBox),
+            "_x0000_t201" => Ok(Self::Control),
+            _ => Err(()),
+        }
+    }
+}
+impl ShapeTypeValues {
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        match self {
+            Self::Picture => write_picture(writer),
+            Self::TextBox => write_text_box(writer),
+            Self::Control => write_control(writer),
+        }
+    }
+}
+
+fn write_picture(writer: &mut Writer<Cursor<Vec<u8>>>) {
+    // v:shapetype
+    write_start_tag(
+        writer,
+        "v:shapetype",
+        vec![
+            ("id", "_x0000_t75"),
+            ("coordsize", "21600,21600"),
+            ("o:spt", "75"),
+            ("o:preferrelative", "t"),
+            ("path", "m@4@5l@4@11@9@11@9@5xe"),
+            ("filled", "f"),
+            ("stroked", "f"),
+        ],
+        false,
+    );
+
+    // v:stroke
+    write_start_tag(writer, "v:stroke", vec![("joinstyle", "miter")], true);
+
+    // v:formulas
+    write_start_tag(writer, "v:formulas", vec![], false);
+    for eqn in &[
+        "if lineDrawn pixelLineWidth 0",
+        "sum @0 1 0",
+        "sum 0 0 @1",
+        "prod @2 1 2",
+        "prod @3 21600 pixelWidth",
+        "prod @3 21600 pixelHeight",
+        "sum @0 0 1",
+        "prod @6 1 2",
+        "prod @7 21600 pixelWidth",
+        "sum @8 21600 0",
+        "prod @7 21600 pixelHeight",
+        "sum @10 21600 0",
+    ] {
+        write_start_tag(writer, "v:f", vec![("eqn", *eqn)], true);
+    }
+    write_end_tag(writer, "v:formulas");
+
+    // v:path
+    write_start_tag(
+        writer,
+        "v:path",
+        vec![
+            ("o:extrusionok", "f"),
+            ("gradientshapeok", "t"),
+            ("o:connecttype", "rect"),
+        ],
+        true,
+    );
+
+    // o:lock
+    write_start_tag(
+        writer,
+        "o:lock",
+        vec![("v:ext", "edit"), ("aspectratio", "t")],
+        true,
+    );
+
+    write_end_tag(writer, "v:shapetype");
+}
+
+fn write_text_box(writer: &mut Writer<Cursor<Vec<u8>>>) {
+    // v:shapetype
+    write_start_tag(
+        writer,
+        "v:shapetype",
+        vec![
+            ("id", "_x0000_t202"),
+            ("coordsize", "21600,21600"),
+            ("o:spt", "202"),
+            ("path", "m,l,21600r21600,l21600,xe"),
+        ],
+        false,
+    );
+
+    // v:stroke
+    write_start_tag(writer, "v:stroke", vec![("joinstyle", "miter")], true);
+
+    // v:path
+    write_start_tag(
+        writer,
+        "v:path",
+        vec![("gradientshapeok", "t"), ("o:connecttype", "rect")],
+        true,
+    );
+
+    write_end_tag(writer, "v:shapetype");
+}
+
+fn write_control(writer: &mut Writer<Cursor<Vec<u8>>>) {
+    // v:shapetype
+    write_start_tag(
+        writer,
+        "v:shapetype",
+        vec![
+            ("id", "_x0000_t201"),
+            ("coordsize", "21600,21600"),
+            ("o:spt", "201"),
+            ("path", "m,l,21600r21600,l21600,xe"),
+        ],
+        false,
+    );
+
+    // v:stroke
+    write_start_tag(writer, "v:stroke", vec![("joinstyle", "miter")], true);
+
+    // v:path
+    write_start_tag(
+        writer,
+        "v:path",
+        vec![
+            ("shadowok", "f"),
+            ("o:extrusionok", "f"),
+            ("strokeok", "f"),
+            ("fillok", "f"),
+            ("o:connecttype", "rect"),
+        ],
+        true,
+    );
+
+    // o:lock
+    write_start_tag(
+        writer,
+        "o:lock",
+        vec![("v:ext", "edit"), ("shapetype", "t")],
+        true,
+    );
+
+    write_end_tag(writer, "v:shapetype");
+}
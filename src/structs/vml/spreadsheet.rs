@@ -33,3 +33,12 @@ pub use self::clipboard_format_values::*;
 
 mod auto_size_picture;
 pub use self::auto_size_picture::*;
+
+mod formula_macro;
+pub use self::formula_macro::*;
+
+mod formula_link;
+pub use self::formula_link::*;
+
+mod checked;
+pub use self::checked::*;
@@ -7,7 +7,7 @@ use std::io::Cursor;
 use traits::AdjustmentCoordinate;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Anchor {
     left_column: u32,
     left_offset: u32,
@@ -124,11 +124,11 @@ impl Anchor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                let text = e.unescape().unwrap();
+                let text = e.unescape()?;
                 let split_str: Vec<&str> = text.split(',').collect();
                 self.set_left_column(Self::get_number(split_str.first()));
                 self.set_left_offset(Self::get_number(split_str.get(1)));
@@ -141,10 +141,10 @@ impl Anchor {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Anchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Anchor")
+            Event::Eof => return Err(eof_error("x:Anchor"))
         );
     }
 
@@ -201,17 +201,38 @@ impl AdjustmentCoordinate for Anchor {
         root_row_num: &u32,
         offset_row_num: &u32,
     ) {
-        self.left_column =
-            adjustment_remove_coordinate(&(&self.left_column + 1), root_col_num, offset_col_num)
-                - 1;
-        self.right_column =
-            adjustment_remove_coordinate(&(&self.right_column + 1), root_col_num, offset_col_num)
-                - 1;
+        if is_remove_coordinate(&(&self.left_column + 1), root_col_num, offset_col_num) {
+            self.left_offset = 0;
+        }
+        if is_remove_coordinate(&(&self.right_column + 1), root_col_num, offset_col_num) {
+            self.right_offset = 0;
+        }
+        if is_remove_coordinate(&(&self.top_row + 1), root_row_num, offset_row_num) {
+            self.top_offset = 0;
+        }
+        if is_remove_coordinate(&(&self.bottom_row + 1), root_row_num, offset_row_num) {
+            self.bottom_offset = 0;
+        }
+
+        self.left_column = adjustment_remove_coordinate_clamp(
+            &(&self.left_column + 1),
+            root_col_num,
+            offset_col_num,
+        ) - 1;
+        self.right_column = adjustment_remove_coordinate_clamp(
+            &(&self.right_column + 1),
+            root_col_num,
+            offset_col_num,
+        ) - 1;
 
         self.top_row =
-            adjustment_remove_coordinate(&(&self.top_row + 1), root_row_num, offset_row_num) - 1;
-        self.bottom_row =
-            adjustment_remove_coordinate(&(&self.bottom_row + 1), root_row_num, offset_row_num) - 1;
+            adjustment_remove_coordinate_clamp(&(&self.top_row + 1), root_row_num, offset_row_num)
+                - 1;
+        self.bottom_row = adjustment_remove_coordinate_clamp(
+            &(&self.bottom_row + 1),
+            root_row_num,
+            offset_row_num,
+        ) - 1;
     }
 
     fn is_remove_coordinate(
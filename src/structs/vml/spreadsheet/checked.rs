@@ -0,0 +1,50 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::UInt32Value;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Checked {
+    value: UInt32Value,
+}
+
+impl Checked {
+    /// 0: unchecked, 1: checked, 2: mixed.
+    pub fn get_value(&self) -> &u32 {
+        self.value.get_value()
+    }
+
+    pub fn set_value(&mut self, value: u32) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.value.set_value_string(e.unescape()?)?;
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"x:Checked" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("x:Checked"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x:Checked
+        write_start_tag(writer, "x:Checked", vec![], false);
+        write_text_node(writer, self.value.get_value_string());
+        write_end_tag(writer, "x:Checked");
+    }
+}
@@ -1,9 +1,12 @@
 use super::Anchor;
 use super::AutoFill;
 use super::AutoSizePicture;
+use super::Checked;
 use super::ClipboardFormat;
 use super::CommentColumnTarget;
 use super::CommentRowTarget;
+use super::FormulaLink;
+use super::FormulaMacro;
 use super::MoveWithCells;
 use super::ObjectValues;
 use super::ResizeWithCells;
@@ -18,7 +21,7 @@ use traits::AdjustmentCoordinate;
 use traits::AdjustmentValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ClientData {
     object_type: EnumValue<ObjectValues>,
     move_with_cells: Option<MoveWithCells>,
@@ -30,6 +33,9 @@ pub struct ClientData {
     visible: Option<Visible>,
     clipboard_format: Option<ClipboardFormat>,
     auto_size_picture: Option<AutoSizePicture>,
+    formula_macro: Option<FormulaMacro>,
+    formula_link: Option<FormulaLink>,
+    checked: Option<Checked>,
 }
 
 impl ClientData {
@@ -159,11 +165,58 @@ impl ClientData {
         self
     }
 
+    pub fn get_formula_macro(&self) -> Option<&FormulaMacro> {
+        self.formula_macro.as_ref()
+    }
+
+    pub fn get_formula_macro_mut(&mut self) -> Option<&mut FormulaMacro> {
+        self.formula_macro.as_mut()
+    }
+
+    pub fn set_formula_macro(&mut self, value: FormulaMacro) -> &mut Self {
+        self.formula_macro = Some(value);
+        self
+    }
+
+    pub fn get_formula_link(&self) -> Option<&FormulaLink> {
+        self.formula_link.as_ref()
+    }
+
+    pub fn get_formula_link_mut(&mut self) -> Option<&mut FormulaLink> {
+        self.formula_link.as_mut()
+    }
+
+    pub fn set_formula_link(&mut self, value: FormulaLink) -> &mut Self {
+        self.formula_link = Some(value);
+        self
+    }
+
+    pub fn get_checked(&self) -> Option<&Checked> {
+        self.checked.as_ref()
+    }
+
+    pub fn get_checked_mut(&mut self) -> Option<&mut Checked> {
+        self.checked.as_mut()
+    }
+
+    pub fn set_checked(&mut self, value: Checked) -> &mut Self {
+        self.checked = Some(value);
+        self
+    }
+
+    pub(crate) fn is_form_control(&self) -> bool {
+        self.object_type.has_value()
+            && matches!(
+                self.object_type.get_value(),
+                ObjectValues::Button | ObjectValues::Checkbox | ObjectValues::Radio
+            )
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, object_type, "ObjectType");
 
         xml_read_loop!(
@@ -172,27 +225,27 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_size_picture(obj);
                     }
                     _ => (),
@@ -202,58 +255,73 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:Anchor" => {
                         let mut obj = Anchor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_anchor(obj);
                     }
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Row" => {
                         let mut obj = CommentRowTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_row_target(obj);
                     }
                     b"x:Column" => {
                         let mut obj = CommentColumnTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_column_target(obj);
                     }
                     b"x:CF" => {
                         let mut obj = ClipboardFormat::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_clipboard_format(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_size_picture(obj);
                     }
+                    b"x:FmlaMacro" => {
+                        let mut obj = FormulaMacro::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_formula_macro(obj);
+                    }
+                    b"x:FmlaLink" => {
+                        let mut obj = FormulaLink::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_formula_link(obj);
+                    }
+                    b"x:Checked" => {
+                        let mut obj = Checked::default();
+                        obj.set_attributes(reader, e)?;
+                        self.set_checked(obj);
+                    }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"x:ClientData" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:ClientData")
+            Event::Eof => return Err(eof_error("x:ClientData"))
         );
     }
 
@@ -309,6 +377,21 @@ impl ClientData {
             v.write_to(writer);
         }
 
+        // x:FmlaMacro
+        if let Some(v) = &self.formula_macro {
+            v.write_to(writer);
+        }
+
+        // x:FmlaLink
+        if let Some(v) = &self.formula_link {
+            v.write_to(writer);
+        }
+
+        // x:Checked
+        if let Some(v) = &self.checked {
+            v.write_to(writer);
+        }
+
         write_end_tag(writer, "x:ClientData");
     }
 }
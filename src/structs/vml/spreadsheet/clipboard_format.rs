@@ -7,7 +7,7 @@ use std::io::Cursor;
 use structs::EnumValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ClipboardFormat {
     value: EnumValue<ClipboardFormatValues>,
 }
@@ -26,18 +26,18 @@ impl ClipboardFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.set_value_string(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:CF" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:CF")
+            Event::Eof => return Err(eof_error("x:CF"))
         );
     }
 
@@ -8,7 +8,7 @@ use structs::UInt32Value;
 use traits::AdjustmentValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct CommentRowTarget {
     value: UInt32Value,
 }
@@ -41,18 +41,18 @@ impl CommentRowTarget {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.set_value_string(e.unescape()?)?;
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Row")
+            Event::Eof => return Err(eof_error("x:Row"))
         );
     }
 
@@ -0,0 +1,49 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use reader::driver::*;
+use std::io::Cursor;
+use structs::StringValue;
+use writer::driver::*;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct FormulaLink {
+    value: StringValue,
+}
+
+impl FormulaLink {
+    pub fn get_value(&self) -> &str {
+        self.value.get_value_str()
+    }
+
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                self.value.set_value_string(e.unescape()?);
+            },
+            Event::End(ref e) => {
+                if e.name().0 == b"x:FmlaLink" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(eof_error("x:FmlaLink"))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // x:FmlaLink
+        write_start_tag(writer, "x:FmlaLink", vec![], false);
+        write_text_node(writer, self.value.get_value_str());
+        write_end_tag(writer, "x:FmlaLink");
+    }
+}
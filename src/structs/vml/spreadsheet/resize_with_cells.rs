@@ -6,7 +6,7 @@ use std::io::Cursor;
 use structs::TrueFalseBlankValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ResizeWithCells {
     value: TrueFalseBlankValue,
 }
@@ -26,22 +26,22 @@ impl ResizeWithCells {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.value.set_value_string(e.unescape().unwrap());
+                self.value.set_value_string(e.unescape()?);
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:SizeWithCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:SizeWithCells")
+            Event::Eof => return Err(eof_error("x:SizeWithCells"))
         );
     }
 
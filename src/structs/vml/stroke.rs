@@ -6,7 +6,7 @@ use std::io::Cursor;
 use structs::StringValue;
 use writer::driver::*;
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct Stroke {
     color: StringValue,
     color_2: StringValue,
@@ -45,10 +45,11 @@ impl Stroke {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, color_2, "color2");
         set_string_from_xml!(self, e, dash_style, "dashstyle");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
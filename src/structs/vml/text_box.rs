@@ -6,7 +6,7 @@ use std::io::Cursor;
 use structs::StringValue;
 use writer::driver::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TextBox {
     style: StringValue,
     innder: StringValue,
@@ -45,7 +45,7 @@ impl TextBox {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, style, "style");
 
         let mut buf = Vec::new();
@@ -94,24 +94,25 @@ impl TextBox {
                     inner_text = format!("{}<{}>", inner_text, tag);
                 }
                 Ok(Event::Text(ref e)) => {
-                    let s = e.unescape().unwrap().to_string();
+                    let s = e.unescape()?.to_string();
                     inner_text = format!("{}{}", inner_text, s);
                 }
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"v:textbox" {
                         break;
                     }
-                    let s = std::str::from_utf8(e.name().into_inner()).unwrap();
+                    let s = String::from_utf8_lossy(e.name().into_inner());
                     inner_text = format!("{}</{}>", inner_text, s);
                 }
                 Ok(Event::Eof) => break,
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Err(e) => return Err(e.into()),
                 _ => (),
             }
             buf.clear();
         }
         //reader.check_end_names(true);
         self.set_innder(inner_text);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
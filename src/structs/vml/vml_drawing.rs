@@ -0,0 +1,142 @@
+use super::Shape;
+use super::ShapeTypeValues;
+use helper::const_str::*;
+use quick_xml::Writer;
+use std::io::Cursor;
+use structs::EnumTrait;
+use structs::FormControl;
+use structs::HeaderFooterImage;
+use writer::driver::*;
+
+enum VmlDrawingShape<'a> {
+    Shape(&'a Shape),
+    FormControl(&'a FormControl),
+    HeaderFooterImage(&'a HeaderFooterImage),
+}
+impl<'a> VmlDrawingShape<'a> {
+    fn get_image_name(&self) -> Option<&'a str> {
+        let image_name = match self {
+            Self::Shape(v) => v.get_image_data()?.get_image_name(),
+            Self::FormControl(_) => return None,
+            Self::HeaderFooterImage(v) => v.get_image().get_image_name(),
+        };
+        (!image_name.is_empty()).then_some(image_name)
+    }
+}
+
+/// A legacy drawing part (vmlDrawing).
+/// Comments, OLE objects, form controls, header/footer pictures and the other legacy shapes
+/// of a worksheet are collected here and written as one part.
+#[derive(Default)]
+pub(crate) struct VmlDrawing<'a> {
+    shapes: Vec<(Option<ShapeTypeValues>, VmlDrawingShape<'a>)>,
+}
+
+impl<'a> VmlDrawing<'a> {
+    pub(crate) fn add_shape(&mut self, value: &'a Shape, shape_type: ShapeTypeValues) -> &mut Self {
+        self.shapes
+            .push((Some(shape_type), VmlDrawingShape::Shape(value)));
+        self
+    }
+
+    /// Add a shape whose shape type is kept as read.
+    pub(crate) fn add_custom_shape(&mut self, value: &'a Shape) -> &mut Self {
+        self.shapes.push((None, VmlDrawingShape::Shape(value)));
+        self
+    }
+
+    pub(crate) fn add_form_control(&mut self, value: &'a FormControl) -> &mut Self {
+        self.shapes.push((
+            Some(ShapeTypeValues::Control),
+            VmlDrawingShape::FormControl(value),
+        ));
+        self
+    }
+
+    pub(crate) fn add_header_footer_image(&mut self, value: &'a HeaderFooterImage) -> &mut Self {
+        self.shapes.push((
+            Some(ShapeTypeValues::Picture),
+            VmlDrawingShape::HeaderFooterImage(value),
+        ));
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Names of the images referenced by the shapes, in relationship order.
+    pub(crate) fn get_image_names(&self) -> Vec<&'a str> {
+        self.shapes
+            .iter()
+            .filter_map(|(_, shape)| shape.get_image_name())
+            .collect()
+    }
+
+    /// Write the part.
+    /// # Arguments
+    /// * `id_map` - Block of shape ids. Shape ids start at `id_map * 1024 + 1`.
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, id_map: &usize) {
+        // xml
+        write_start_tag(
+            writer,
+            "xml",
+            vec![
+                ("xmlns:v", VML_NS),
+                ("xmlns:o", OFFICE_NS),
+                ("xmlns:x", EXCEL_NS),
+            ],
+            false,
+        );
+
+        // o:shapelayout
+        write_start_tag(writer, "o:shapelayout", vec![("v:ext", "edit")], false);
+
+        // o:idmap
+        write_start_tag(
+            writer,
+            "o:idmap",
+            vec![("v:ext", "edit"), ("data", &id_map.to_string())],
+            true,
+        );
+
+        write_end_tag(writer, "o:shapelayout");
+
+        let mut shape_types: Vec<&str> = Vec::new();
+        let mut id = id_map * 1024 + 1;
+        let mut r_id = 1;
+        for (shape_type, shape) in &self.shapes {
+            // v:shapetype
+            match shape_type {
+                Some(v) => {
+                    if !shape_types.contains(&v.get_value_string()) {
+                        v.write_to(writer);
+                        shape_types.push(v.get_value_string());
+                    }
+                }
+                None => {
+                    if let VmlDrawingShape::Shape(v) = shape {
+                        let type_id = v.get_type().trim_start_matches('#');
+                        if !shape_types.contains(&type_id) && !v.get_shape_type_xml().is_empty() {
+                            write_text_node_no_escape(writer, v.get_shape_type_xml());
+                            shape_types.push(type_id);
+                        }
+                    }
+                }
+            }
+
+            // v:shape
+            match shape {
+                VmlDrawingShape::Shape(v) => v.write_to(writer, &id, &r_id),
+                VmlDrawingShape::FormControl(v) => v.write_vml_shape(writer, &id),
+                VmlDrawingShape::HeaderFooterImage(v) => v.write_vml_shape(writer, &id, &r_id),
+            }
+            if shape.get_image_name().is_some() {
+                r_id += 1;
+            }
+            id += 1;
+        }
+
+        write_end_tag(writer, "xml");
+    }
+}
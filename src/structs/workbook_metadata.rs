@@ -0,0 +1,40 @@
+use structs::DefinedName;
+use structs::Properties;
+use structs::SheetMetadata;
+
+/// Sheet list, defined names and document properties of a workbook,
+/// read without parsing the cells.
+#[derive(Clone, Default, Debug)]
+pub struct WorkbookMetadata {
+    sheets: Vec<SheetMetadata>,
+    defined_names: Vec<DefinedName>,
+    properties: Properties,
+}
+impl WorkbookMetadata {
+    pub(crate) fn new(
+        sheets: Vec<SheetMetadata>,
+        defined_names: Vec<DefinedName>,
+        properties: Properties,
+    ) -> Self {
+        Self {
+            sheets,
+            defined_names,
+            properties,
+        }
+    }
+
+    /// Sheets in workbook order.
+    pub fn get_sheets(&self) -> &Vec<SheetMetadata> {
+        &self.sheets
+    }
+
+    /// Defined names of the workbook and of each sheet.
+    pub fn get_defined_names(&self) -> &Vec<DefinedName> {
+        &self.defined_names
+    }
+
+    /// Document properties (core and app).
+    pub fn get_properties(&self) -> &Properties {
+        &self.properties
+    }
+}
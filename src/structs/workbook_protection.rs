@@ -165,24 +165,59 @@ impl WorkbookProtection {
         self
     }
 
+    /// Check a password against the workbook structure protection.
+    pub fn verify_workbook_password(&self, password: &str) -> bool {
+        if self.workbook_hash_value.has_value() {
+            return verify_password_hash(
+                password,
+                self.workbook_algorithm_name.get_value_str(),
+                self.workbook_hash_value.get_value_str(),
+                self.workbook_salt_value.get_value_str(),
+                *self.workbook_spin_count.get_value(),
+            );
+        }
+        if self.workbook_password.has_value() {
+            return verify_legacy_password_hash(password, self.workbook_password.get_value_str());
+        }
+        password.is_empty()
+    }
+
+    /// Check a password against the revisions protection.
+    pub fn verify_revisions_password(&self, password: &str) -> bool {
+        if self.revisions_hash_value.has_value() {
+            return verify_password_hash(
+                password,
+                self.revisions_algorithm_name.get_value_str(),
+                self.revisions_hash_value.get_value_str(),
+                self.revisions_salt_value.get_value_str(),
+                *self.revisions_spin_count.get_value(),
+            );
+        }
+        if self.revisions_password.has_value() {
+            return verify_legacy_password_hash(password, self.revisions_password.get_value_str());
+        }
+        password.is_empty()
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, workbook_algorithm_name, "workbookAlgorithmName");
         set_string_from_xml!(self, e, workbook_hash_value, "workbookHashValue");
         set_string_from_xml!(self, e, workbook_salt_value, "workbookSaltValue");
-        set_string_from_xml!(self, e, workbook_spin_count, "workbookSpinCount");
+        set_number_from_xml!(self, e, workbook_spin_count, "workbookSpinCount");
         set_string_from_xml!(self, e, workbook_password, "workbookPassword");
         set_string_from_xml!(self, e, revisions_algorithm_name, "revisionsAlgorithmName");
         set_string_from_xml!(self, e, revisions_hash_value, "revisionsHashValue");
         set_string_from_xml!(self, e, revisions_salt_value, "revisionsSaltValue");
-        set_string_from_xml!(self, e, revisions_spin_count, "revisionsSpinCount");
+        set_number_from_xml!(self, e, revisions_spin_count, "revisionsSpinCount");
         set_string_from_xml!(self, e, revisions_password, "revisionsPassword");
         set_string_from_xml!(self, e, lock_revision, "lockRevision");
         set_string_from_xml!(self, e, lock_structure, "lockStructure");
         set_string_from_xml!(self, e, lock_windows, "lockWindows");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -1,3 +1,4 @@
+use super::Int32Value;
 use super::UInt32Value;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
@@ -9,6 +10,12 @@ use writer::driver::*;
 #[derive(Clone, Default, Debug)]
 pub struct WorkbookView {
     active_tab: UInt32Value,
+    first_sheet: UInt32Value,
+    x_window: Int32Value,
+    y_window: Int32Value,
+    window_width: UInt32Value,
+    window_height: UInt32Value,
+    tab_ratio: UInt32Value,
 }
 
 impl WorkbookView {
@@ -21,22 +28,136 @@ impl WorkbookView {
         self
     }
 
+    /// Get First Sheet.
+    /// Index of the first sheet tab shown in the tab bar.
+    pub fn get_first_sheet(&self) -> &u32 {
+        self.first_sheet.get_value()
+    }
+
+    /// Set First Sheet.
+    /// # Arguments
+    /// * `value` - index of the first visible sheet tab.
+    pub fn set_first_sheet(&mut self, value: u32) -> &mut Self {
+        self.first_sheet.set_value(value);
+        self
+    }
+
+    /// Get X Window.
+    pub fn get_x_window(&self) -> &i32 {
+        self.x_window.get_value()
+    }
+
+    /// Set X Window.
+    /// # Arguments
+    /// * `value` - left position of the window (twips).
+    pub fn set_x_window(&mut self, value: i32) -> &mut Self {
+        self.x_window.set_value(value);
+        self
+    }
+
+    /// Get Y Window.
+    pub fn get_y_window(&self) -> &i32 {
+        self.y_window.get_value()
+    }
+
+    /// Set Y Window.
+    /// # Arguments
+    /// * `value` - top position of the window (twips).
+    pub fn set_y_window(&mut self, value: i32) -> &mut Self {
+        self.y_window.set_value(value);
+        self
+    }
+
+    /// Get Window Width.
+    pub fn get_window_width(&self) -> &u32 {
+        self.window_width.get_value()
+    }
+
+    /// Set Window Width.
+    /// # Arguments
+    /// * `value` - width of the window (twips).
+    pub fn set_window_width(&mut self, value: u32) -> &mut Self {
+        self.window_width.set_value(value);
+        self
+    }
+
+    /// Get Window Height.
+    pub fn get_window_height(&self) -> &u32 {
+        self.window_height.get_value()
+    }
+
+    /// Set Window Height.
+    /// # Arguments
+    /// * `value` - height of the window (twips).
+    pub fn set_window_height(&mut self, value: u32) -> &mut Self {
+        self.window_height.set_value(value);
+        self
+    }
+
+    /// Get Tab Ratio.
+    /// Ratio between the tab bar and the horizontal scroll bar (1/1000).
+    pub fn get_tab_ratio(&self) -> &u32 {
+        match self.tab_ratio.has_value() {
+            true => self.tab_ratio.get_value(),
+            false => &600,
+        }
+    }
+
+    /// Set Tab Ratio.
+    /// # Arguments
+    /// * `value` - ratio in 1/1000 (0 - 1000).
+    pub fn set_tab_ratio(&mut self, value: u32) -> &mut Self {
+        self.tab_ratio.set_value(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, active_tab, "activeTab")
+    ) -> Result<(), XlsxError> {
+        set_number_from_xml!(self, e, active_tab, "activeTab");
+        set_number_from_xml!(self, e, first_sheet, "firstSheet");
+        set_number_from_xml!(self, e, x_window, "xWindow");
+        set_number_from_xml!(self, e, y_window, "yWindow");
+        set_number_from_xml!(self, e, window_width, "windowWidth");
+        set_number_from_xml!(self, e, window_height, "windowHeight");
+        set_number_from_xml!(self, e, tab_ratio, "tabRatio");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // selection
+        let x_window = match self.x_window.has_value() {
+            true => self.x_window.get_value_string(),
+            false => String::from("240"),
+        };
+        let y_window = match self.y_window.has_value() {
+            true => self.y_window.get_value_string(),
+            false => String::from("105"),
+        };
+        let window_width = match self.window_width.has_value() {
+            true => self.window_width.get_value_string(),
+            false => String::from("14805"),
+        };
+        let window_height = match self.window_height.has_value() {
+            true => self.window_height.get_value_string(),
+            false => String::from("8010"),
+        };
         let mut attributes = vec![
-            ("xWindow", "240"),
-            ("yWindow", "105"),
-            ("windowWidth", "14805"),
-            ("windowHeight", "8010"),
+            ("xWindow", x_window.as_str()),
+            ("yWindow", y_window.as_str()),
+            ("windowWidth", window_width.as_str()),
+            ("windowHeight", window_height.as_str()),
         ];
+        let tab_ratio = self.tab_ratio.get_value_string();
+        if self.tab_ratio.has_value() {
+            attributes.push(("tabRatio", &tab_ratio));
+        }
+        let first_sheet = self.first_sheet.get_value_string();
+        if self.first_sheet.has_value() {
+            attributes.push(("firstSheet", &first_sheet));
+        }
         let active_tab = self.active_tab.get_value_string();
         if self.active_tab.has_value() {
             attributes.push(("activeTab", &active_tab));
@@ -1,28 +1,56 @@
 use crate::traits;
 use crate::StringValue;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use helper::const_str::*;
 use helper::coordinate::*;
+use helper::formula::{
+    adjustment_insert_formula_coordinate, get_formula_references, is_in_range, parse_to_tokens,
+};
 use helper::range::*;
+use helper::sanitize::strip_control_characters;
+use md5::Digest;
 use reader::xlsx::worksheet::*;
+use std::borrow::Cow;
+use std::ops::{Index, IndexMut, RangeInclusive};
+use std::sync::Arc;
+use structs::drawing::spreadsheet::ConnectionShape;
+use structs::drawing::spreadsheet::GroupShape;
+use structs::drawing::spreadsheet::MarkerType;
+use structs::drawing::spreadsheet::Shape;
+use structs::drawing::spreadsheet::TwoCellAnchor;
 use structs::drawing::spreadsheet::WorksheetDrawing;
 use structs::office2010::excel::DataValidations as DataValidations2010;
 use structs::raw::RawWorksheet;
+use structs::vml::Shape as VmlShape;
+use structs::Address;
 use structs::AutoFilter;
+use structs::Border;
+use structs::BorderStyleValues;
 use structs::Cell;
+use structs::CellRawValue;
 use structs::CellValue;
 use structs::Cells;
 use structs::Chart;
+use structs::ClearMode;
 use structs::Color;
 use structs::Column;
 use structs::ColumnBreaks;
+use structs::ColumnStats;
 use structs::Columns;
 use structs::Comment;
+use structs::ConditionalFormatValues;
 use structs::ConditionalFormatting;
+use structs::ConditionalFormattingRule;
+use structs::CustomSheetViews;
 use structs::DataValidations;
 use structs::DefinedName;
+use structs::Drawings;
+use structs::FormControl;
+use structs::FormatSnapshot;
+use structs::Formula;
 use structs::HeaderFooter;
 use structs::Hyperlink;
+use structs::IgnoredErrors;
 use structs::Image;
 use structs::MediaObject;
 use structs::MergeCells;
@@ -33,18 +61,26 @@ use structs::PrintOptions;
 use structs::Range;
 use structs::Row;
 use structs::RowBreaks;
+use structs::RowMut;
 use structs::Rows;
 use structs::SharedStringTable;
 use structs::SheetFormatProperties;
+use structs::SheetProperties;
 use structs::SheetProtection;
+use structs::SheetView;
 use structs::SheetViews;
 use structs::Style;
 use structs::Stylesheet;
 use structs::Table;
+use structs::TemplateValues;
+use structs::TextBox;
+use structs::ValidationIssue;
+use structs::ValidationIssueKind;
 use traits::AdjustmentCoordinate;
 use traits::AdjustmentCoordinateWith2Sheet;
 use traits::AdjustmentCoordinateWithSheet;
 use traits::AdjustmentValue;
+use traits::IntoCellValue;
 
 /// A Worksheet Object.
 #[derive(Clone, Debug, Default)]
@@ -62,6 +98,7 @@ pub struct Worksheet {
     page_margins: PageMargins,
     header_footer: HeaderFooter,
     sheet_views: SheetViews,
+    custom_sheet_views: CustomSheetViews,
     conditional_formatting_collection: Vec<ConditionalFormatting>,
     merge_cells: MergeCells,
     auto_filter: Option<AutoFilter>,
@@ -74,11 +111,17 @@ pub struct Worksheet {
     print_options: PrintOptions,
     column_breaks: ColumnBreaks,
     row_breaks: RowBreaks,
+    ignored_errors: IgnoredErrors,
     tables: Vec<Table>,
     data_validations: Option<DataValidations>,
     data_validations_2010: Option<DataValidations2010>,
     sheet_format_properties: SheetFormatProperties,
+    sheet_properties: SheetProperties,
     sheet_protection: Option<SheetProtection>,
+    background_image: Option<MediaObject>,
+    form_controls: Vec<FormControl>,
+    vml_shapes: Vec<VmlShape>,
+    default_style: Style,
 }
 
 impl Worksheet {
@@ -88,7 +131,7 @@ impl Worksheet {
 
     /// Get value.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `String` - Value of the specified cell.
     /// # Examples
@@ -132,7 +175,7 @@ impl Worksheet {
 
     /// Get formatted value.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `String` - Formatted value of the specified cell.
     /// # Examples
@@ -152,6 +195,149 @@ impl Worksheet {
             .get_formatted_value_by_column_and_row(&col, &row)
     }
 
+    /// Get the cells and ranges the formula of a cell refers to.
+    /// References without a sheet name get the name of this sheet.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"D10"` or `(4, 10)` or a `Coordinate`
+    /// # Return value
+    /// * `Vec<Address>` - empty if the cell has no formula.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("D10").set_formula("SUM(A1:A3)+Sheet2!B1");
+    /// let precedents = worksheet.get_precedents("D10");
+    /// assert_eq!(precedents[0].get_address(), "Sheet1!A1:A3");
+    /// assert_eq!(precedents[1].get_address(), "Sheet2!B1");
+    /// ```
+    pub fn get_precedents<T>(&self, coordinate: T) -> Vec<Address>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let formula = match self.cell_collection.get((col, row)) {
+            Some(v) if v.is_formula() => v.get_formula(),
+            _ => return Vec::new(),
+        };
+        get_formula_references(formula)
+            .into_iter()
+            .map(|(sheet_name, range)| {
+                let mut address = Address::default();
+                address.get_range_mut().set_range(range);
+                if sheet_name.is_empty() {
+                    address.set_sheet_name(self.get_name());
+                } else {
+                    address.set_sheet_name(sheet_name);
+                }
+                address
+            })
+            .collect()
+    }
+
+    /// Set values from rows of data in one pass.
+    /// # Arguments
+    /// * `coordinate` - Top left cell. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
+    /// * `rows` - Rows of values. Each row is written to the right of `coordinate`.
+    /// # Return value
+    /// * `&mut Self` - Self.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_values("B2", &[vec![1, 2, 3], vec![4, 5, 6]]);
+    /// worksheet.set_values("B4", &[["a", "b"]]);
+    /// assert_eq!(worksheet.get_value("D3"), "6");
+    /// ```
+    pub fn set_values<T, R, V>(&mut self, coordinate: T, rows: &[R]) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+        R: AsRef<[V]>,
+        V: IntoCellValue + Clone,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.cell_collection
+            .reserve(rows.iter().map(|v| v.as_ref().len()).sum());
+        for (row_offset, values) in rows.iter().enumerate() {
+            let row_num = row + row_offset as u32;
+            for (col_offset, value) in values.as_ref().iter().enumerate() {
+                self.get_or_insert_cell(col + col_offset as u32, row_num)
+                    .get_cell_value_mut()
+                    .set_raw_value(value.clone().into_cell_value());
+            }
+        }
+        self
+    }
+
+    /// Get values of a range, row by row.
+    /// A whole column or row range ends at the highest column or row of the worksheet.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "A1:D10", "A:C" or a `CellRange`
+    /// # Return value
+    /// * `Vec<Vec<&CellRawValue>>` - Values. Missing cells are `CellRawValue::Empty`.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_values("A1", &[vec![1, 2], vec![3, 4]]);
+    /// let values = worksheet.get_values("A1:B2");
+    /// assert_eq!(values[1][0].to_string(), "3");
+    /// ```
+    pub fn get_values<S: Into<String>>(&self, range: S) -> Vec<Vec<&CellRawValue>> {
+        let range_upper = range.into().to_uppercase();
+        let (row_start, row_end, col_start, col_end) = get_start_and_end_point(&range_upper);
+        let (highest_col, highest_row) = self.get_highest_column_and_row();
+        let row_start = row_start.max(1);
+        let row_end = if row_end == 0 { highest_row } else { row_end };
+        let col_start = col_start.max(1);
+        let col_end = if col_end == 0 { highest_col } else { col_end };
+        (row_start..=row_end)
+            .map(|row_num| {
+                (col_start..=col_end)
+                    .map(|col_num| {
+                        self.cell_collection
+                            .get_cell_value((col_num, row_num))
+                            .get_raw_value()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Get statistics of the values of a range.
+    /// Only the cells that exist are visited, so whole columns can be used.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "C2:C500", "C:C" or a `CellRange`
+    /// # Return value
+    /// * `ColumnStats` - count, numeric count, min, max, sum, mean and distinct values.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_values("C2", &[[1], [3], [3]]);
+    /// let stats = worksheet.column_stats("C2:C500");
+    /// assert_eq!(stats.get_count(), &3);
+    /// assert_eq!(stats.get_mean(), Some(7.0 / 3.0));
+    /// assert_eq!(stats.get_distinct_values(), &vec!["1", "3"]);
+    /// ```
+    pub fn column_stats<S: Into<String>>(&self, range: S) -> ColumnStats {
+        let range_upper = range.into().to_uppercase();
+        let mut cells: Vec<&Cell> = self
+            .cell_collection
+            .get_range(get_start_and_end_point(&range_upper))
+            .collect();
+        cells.sort_by_key(|v| {
+            (
+                *v.get_coordinate().get_row_num(),
+                *v.get_coordinate().get_col_num(),
+            )
+        });
+        let mut stats = ColumnStats::default();
+        for cell in cells {
+            stats.add_value(cell.get_raw_value());
+        }
+        stats
+    }
+
     // ************************
     // Cell
     // ************************
@@ -164,6 +350,52 @@ impl Worksheet {
         self.cell_collection.get_collection_sorted()
     }
 
+    /// Get a hash of the values, formulas and styles of the cells.
+    /// The hash does not depend on the order the cells were added in,
+    /// so it can be kept to find out whether the worksheet changed between runs.
+    /// Cells holding nothing but the default style are ignored.
+    /// # Return value
+    /// * `String` - md5 hash in hexadecimal.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let hash = worksheet.content_hash();
+    /// worksheet.get_cell_mut("A1").set_value("TEST1");
+    /// assert_ne!(worksheet.content_hash(), hash);
+    /// ```
+    pub fn content_hash(&self) -> String {
+        let empty_style = Style::default();
+        let mut hasher = md5::Md5::new();
+        for cell in self.cell_collection.get_collection_sorted() {
+            if cell.is_style_only()
+                && cell
+                    .get_style()
+                    .is_same_effective(&empty_style, &self.default_style)
+            {
+                continue;
+            }
+            let value = match cell.get_raw_value() {
+                CellRawValue::RichText(v) => v.get_hash_code(),
+                CellRawValue::Lazy(v) => CellValue::guess_typed_data(v).to_string(),
+                v => v.to_string(),
+            };
+            let data_type = match cell.get_raw_value() {
+                CellRawValue::Lazy(v) => CellValue::guess_typed_data(v).get_data_type().to_string(),
+                v => v.get_data_type().to_string(),
+            };
+            hasher.update(format!(
+                "{}\u{1}{}\u{1}{}\u{1}{}\u{1}{}\u{2}",
+                cell.get_coordinate(),
+                data_type,
+                value,
+                cell.get_formula(),
+                cell.get_style().get_hash_code(),
+            ));
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
     /// Get Cell List in mutable.
     pub fn get_cell_collection_mut(&mut self) -> Vec<&mut Cell> {
         self.cell_collection.get_collection_mut()
@@ -206,7 +438,7 @@ impl Worksheet {
 
     /// Get cell.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `Option` - Cell in the Some.
     /// # Examples
@@ -217,6 +449,7 @@ impl Worksheet {
     /// // or pass in a tuple `(col, row)`, both col and row starting at `1`
     /// let cell = worksheet.get_cell((1, 1));
     /// ```
+    /// The lookup is a hash map access and does not scan the sheet.
     pub fn get_cell<T>(&self, coordinate: T) -> Option<&Cell>
     where
         T: Into<CellCoordinates>,
@@ -226,7 +459,7 @@ impl Worksheet {
 
     /// Get cell with mutable.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `&mut Cell` - Cell with mutable.
     /// # Examples
@@ -242,8 +475,35 @@ impl Worksheet {
         T: Into<CellCoordinates>,
     {
         let CellCoordinates { col, row } = coordinate.into();
+        self.get_or_insert_cell(col, row)
+    }
+
+    // New cells take the style of their row, or failing that their column.
+    fn get_or_insert_cell(&mut self, col: u32, row: u32) -> &mut Cell {
         self.get_row_dimension_mut(&row);
-        self.cell_collection.get_mut((col, row))
+        let inherited_style = match self.cell_collection.get((col, row)) {
+            Some(_) => None,
+            None => self.get_inherited_style(&col, &row).cloned(),
+        };
+        let cell = self.cell_collection.get_mut((col, row));
+        if let Some(v) = inherited_style {
+            cell.set_style_shared(v);
+        }
+        cell
+    }
+
+    fn get_inherited_style(&self, col: &u32, row: &u32) -> Option<&Arc<Style>> {
+        if let Some(v) = self.get_row_dimension(row) {
+            if !v.get_style().is_empty() {
+                return Some(v.get_style_shared());
+            }
+        }
+        if let Some(v) = self.get_column_dimension_by_number(col) {
+            if !v.get_style().is_empty() {
+                return Some(v.get_style_shared());
+            }
+        }
+        None
     }
 
     pub fn get_collection_by_column(&self, column_num: &u32) -> Vec<&Cell> {
@@ -275,7 +535,7 @@ impl Worksheet {
 
     /// Remove Cell
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Examples
     /// ```
     /// worksheet.remove_cell("A1");
@@ -290,9 +550,152 @@ impl Worksheet {
         self.cell_collection.remove(&col, &row)
     }
 
+    /// Clear a range of cells.
+    /// Only the cells that exist are visited, so whole columns or rows can be cleared cheaply.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "A1:Z100", "A:C", "2:5" or a `CellRange`
+    /// * `mode` - What to remove.
+    /// # Return value
+    /// * `&mut Self` - Self.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value("TEST1");
+    /// worksheet.clear_range("A1:Z100", ClearMode::Contents);
+    /// assert_eq!(worksheet.get_value("A1"), "");
+    /// ```
+    pub fn clear_range<S: Into<String>>(&mut self, range: S, mode: ClearMode) -> &mut Self {
+        let range_upper = range.into().to_uppercase();
+        self.cell_collection
+            .clear_range(get_start_and_end_point(&range_upper), mode);
+        self
+    }
+
+    /// Remove the style only cells of a range whose style makes no difference,
+    /// because it is the style they would get from their row, their column or the first cell format of the workbook.
+    /// Formatting whole columns cell by cell often leaves many of them.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "A1:Z100", "A:C", "2:5" or a `CellRange`
+    /// # Return value
+    /// * `usize` - Number of removed cells.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1");
+    /// assert_eq!(worksheet.prune_empty_styled_cells("A:A"), 1);
+    /// ```
+    pub fn prune_empty_styled_cells<S: Into<String>>(&mut self, range: S) -> usize {
+        let range_upper = range.into().to_uppercase();
+        let (row_start, row_end, col_start, col_end) = get_start_and_end_point(&range_upper);
+        let row_end = if row_end == 0 { u32::MAX } else { row_end };
+        let col_end = if col_end == 0 { u32::MAX } else { col_end };
+        let mut remove_list: Vec<(u32, u32)> = Vec::new();
+        for ((row, col), cell) in self.cell_collection.get_collection_to_hashmap() {
+            if !(row_start..=row_end).contains(row)
+                || !(col_start..=col_end).contains(col)
+                || !cell.is_style_only()
+            {
+                continue;
+            }
+            let is_inherited = match self.get_inherited_style(col, row) {
+                Some(v) if Arc::ptr_eq(v, cell.get_style_shared()) => true,
+                Some(v) => v.is_same_effective(cell.get_style(), &self.default_style),
+                None => self
+                    .default_style
+                    .is_same_effective(cell.get_style(), &self.default_style),
+            };
+            if is_inherited {
+                remove_list.push((*col, *row));
+            }
+        }
+        for (col, row) in &remove_list {
+            self.cell_collection.remove(col, row);
+        }
+        remove_list.len()
+    }
+
+    /// Replace the formulas of a range of cells with their cached results, like pasting values.
+    /// Formulas without a cached result become empty.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "A1:Z100", "A:C", "2:5" or a `CellRange`
+    /// # Return value
+    /// * `&mut Self` - Self.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_formula("1+1").set_formula_result(2);
+    /// worksheet.freeze_formulas("A1:Z100");
+    /// assert!(!worksheet.get_cell("A1").unwrap().is_formula());
+    /// assert_eq!(worksheet.get_value("A1"), "2");
+    /// ```
+    pub fn freeze_formulas<S: Into<String>>(&mut self, range: S) -> &mut Self {
+        let range_upper = range.into().to_uppercase();
+        for cell in self
+            .cell_collection
+            .get_range_mut(get_start_and_end_point(&range_upper))
+        {
+            cell.get_cell_value_mut().remove_formula();
+        }
+        self
+    }
+
+    /// Remove the control characters not allowed in xml from the text cells.
+    /// Vertical tab and form feed become line breaks.
+    /// Formula cells are left as they are.
+    /// # Return value
+    /// * `usize` - number of changed cells.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value_string("a\u{0B}b");
+    /// assert_eq!(worksheet.sanitize_text(), 1);
+    /// assert_eq!(worksheet.get_value("A1"), "a\nb");
+    /// ```
+    pub fn sanitize_text(&mut self) -> usize {
+        let mut count = 0;
+        for cell in self.get_cell_collection_mut() {
+            if cell.is_formula() {
+                continue;
+            }
+            let sanitized = match cell.get_raw_value() {
+                CellRawValue::String(v) => match strip_control_characters(v) {
+                    Cow::Owned(value) => Some(CellRawValue::String(value.into())),
+                    Cow::Borrowed(_) => None,
+                },
+                CellRawValue::RichText(v) => {
+                    let mut rich_text = v.clone();
+                    let mut is_changed = false;
+                    for element in rich_text.get_rich_text_elements_mut() {
+                        if let Cow::Owned(text) = strip_control_characters(element.get_text()) {
+                            element.set_text(text);
+                            is_changed = true;
+                        }
+                    }
+                    match is_changed {
+                        true => Some(CellRawValue::RichText(rich_text)),
+                        false => None,
+                    }
+                }
+                _ => None,
+            };
+            if let Some(value) = sanitized {
+                cell.get_cell_value_mut().set_raw_value(value);
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Get cell value.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `&CellValue` - CellValue.
     /// # Examples
@@ -312,7 +715,7 @@ impl Worksheet {
 
     /// Get cell value with mutable.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `&mut CellValue` - CellValue with mutable.
     /// # Examples
@@ -351,7 +754,7 @@ impl Worksheet {
 
     /// Get style.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `&Style` - Style.
     /// # Examples
@@ -366,12 +769,50 @@ impl Worksheet {
     where
         T: Into<CellCoordinates>,
     {
-        self.cell_collection.get_style(coordinate)
+        let CellCoordinates { col, row } = coordinate.into();
+        if self.cell_collection.get((col, row)).is_none() {
+            if let Some(v) = self.get_inherited_style(&col, &row) {
+                return v;
+            }
+        }
+        self.cell_collection.get_style((col, row))
+    }
+
+    /// Get the style Excel applies to the cell.
+    /// An existing cell uses its own style. Otherwise the style of the row is used,
+    /// then the style of the column.
+    /// Parts left unset are taken from the Normal cell style and the default font of the workbook.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
+    /// # Return value
+    /// * `Style` - Resolved style.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet(&0).unwrap();
+    /// let style = worksheet.get_effective_style("B5");
+    /// assert_eq!(style.get_font().unwrap().get_size(), &11.0);
+    /// ```
+    pub fn get_effective_style<T>(&self, coordinate: T) -> Style
+    where
+        T: Into<CellCoordinates>,
+    {
+        let mut style = self.get_style(coordinate).clone();
+        style.set_missing_value(&self.default_style);
+        style.set_missing_default_value();
+        style
+    }
+
+    /// (This method is crate only.)
+    /// Set the style of the workbook that cells fall back to.
+    pub(crate) fn set_default_style_crate(&mut self, value: Style) -> &mut Self {
+        self.default_style = value;
+        self
     }
 
     /// Get style with mutable.
     /// # Arguments
-    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)`
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(&1, &1)` or a `Coordinate`
     /// # Return value
     /// * `&mut Style` - Style with mutable.
     /// # Examples
@@ -387,8 +828,7 @@ impl Worksheet {
         T: Into<CellCoordinates>,
     {
         let CellCoordinates { col, row } = coordinate.into();
-        self.get_row_dimension_mut(&row);
-        self.cell_collection.get_mut((col, row)).get_style_mut()
+        self.get_or_insert_cell(col, row).get_style_mut()
     }
 
     pub fn set_style<T>(&mut self, coordinate: T, style: Style) -> &mut Self
@@ -403,7 +843,7 @@ impl Worksheet {
 
     /// Set style by range.
     /// # Arguments
-    /// * `range` - Specify the range. ex) "A1:B2"
+    /// * `range` - Specify the range. ex) "A1:B2" or a `CellRange`
     /// * `style` - Style
     /// # Return value
     /// * `&mut Self` - Self.
@@ -415,8 +855,9 @@ impl Worksheet {
     /// style.get_borders_mut().get_bottom_mut().set_border_style(umya_spreadsheet::Border::BORDER_MEDIUM);
     /// worksheet.set_style_by_range("A1:A3", style);
     /// ```
-    pub fn set_style_by_range(&mut self, range: &str, style: Style) -> &mut Self {
-        let range_upper = range.to_uppercase();
+    pub fn set_style_by_range<S: Into<String>>(&mut self, range: S, style: Style) -> &mut Self {
+        let style = Arc::new(style);
+        let range_upper = range.into().to_uppercase();
         let coordinate_list = get_coordinate_list(&range_upper);
 
         let (col_num_start, row_num_start) = coordinate_list[0];
@@ -424,7 +865,7 @@ impl Worksheet {
             let (_, row_num_end) = coordinate_list[1];
             for row_num in row_num_start..=row_num_end {
                 self.get_row_dimension_mut(&row_num)
-                    .set_style(style.clone());
+                    .set_style_shared(style.clone());
             }
             return self;
         }
@@ -432,17 +873,151 @@ impl Worksheet {
             let (col_num_end, _) = coordinate_list[1];
             for col_num in col_num_start..=col_num_end {
                 self.get_column_dimension_by_number_mut(&col_num)
-                    .set_style(style.clone());
+                    .set_style_shared(style.clone());
             }
             return self;
         }
 
         for (col_num, row_num) in coordinate_list {
-            self.set_style((col_num, row_num), style.clone());
+            self.get_row_dimension_mut(&row_num);
+            self.cell_collection
+                .get_mut((&col_num, &row_num))
+                .set_style_shared(style.clone());
+        }
+        self
+    }
+
+    /// Set one style to all the cells of a range.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "A1:Z1000", "A:C", "2:5" or a `CellRange`
+    /// * `style` - Style
+    /// # Return value
+    /// * `&mut Self` - Self.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut style = umya_spreadsheet::Style::default();
+    /// style.get_font_mut().set_bold(true);
+    /// worksheet.set_style_range("A1:Z1000", &style);
+    /// ```
+    pub fn set_style_range<S: Into<String>>(&mut self, range: S, style: &Style) -> &mut Self {
+        self.set_style_by_range(range, style.clone())
+    }
+
+    /// Draw a border around the outside of a range of cells.
+    /// Only the outer edges of the cells on the edge of the range are changed.
+    /// # Arguments
+    /// * `range` - range. ex) "B2:F10"
+    /// * `style` - border style.
+    /// * `color` - border color.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut color = Color::default();
+    /// color.set_argb(Color::COLOR_BLACK);
+    /// worksheet.set_border_around("B2:F10", BorderStyleValues::Medium, color);
+    /// ```
+    pub fn set_border_around<S: Into<String>>(
+        &mut self,
+        range: S,
+        style: BorderStyleValues,
+        color: Color,
+    ) -> &mut Self {
+        let (col_start, row_start, col_end, row_end) = match self.get_border_bounds(range) {
+            Some(v) => v,
+            None => return self,
+        };
+        let mut border = Border::default();
+        border.set_style(style).set_color(color);
+
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                if col != col_start && col != col_end && row != row_start && row != row_end {
+                    continue;
+                }
+                let borders = self.get_style_mut((col, row)).get_borders_mut();
+                if col == col_start {
+                    borders.set_left(border.clone());
+                }
+                if col == col_end {
+                    borders.set_right(border.clone());
+                }
+                if row == row_start {
+                    borders.set_top(border.clone());
+                }
+                if row == row_end {
+                    borders.set_bottom(border.clone());
+                }
+            }
+        }
+        self
+    }
+
+    /// Draw the inner grid lines of a range of cells.
+    /// Only the edges shared between two cells of the range are changed.
+    /// # Arguments
+    /// * `range` - range. ex) "B2:F10"
+    /// * `style` - border style.
+    /// * `color` - border color.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_border_inside("B2:F10", BorderStyleValues::Thin, Color::default());
+    /// ```
+    pub fn set_border_inside<S: Into<String>>(
+        &mut self,
+        range: S,
+        style: BorderStyleValues,
+        color: Color,
+    ) -> &mut Self {
+        let (col_start, row_start, col_end, row_end) = match self.get_border_bounds(range) {
+            Some(v) => v,
+            None => return self,
+        };
+        let mut border = Border::default();
+        border.set_style(style).set_color(color);
+
+        for row in row_start..=row_end {
+            for col in col_start..=col_end {
+                let borders = self.get_style_mut((col, row)).get_borders_mut();
+                if col != col_start {
+                    borders.set_left(border.clone());
+                }
+                if col != col_end {
+                    borders.set_right(border.clone());
+                }
+                if row != row_start {
+                    borders.set_top(border.clone());
+                }
+                if row != row_end {
+                    borders.set_bottom(border.clone());
+                }
+            }
         }
         self
     }
 
+    // (col_start, row_start, col_end, row_end) of a cell range.
+    // Whole row or column ranges are not supported.
+    fn get_border_bounds<S: Into<String>>(&self, range: S) -> Option<(u32, u32, u32, u32)> {
+        let range_upper = range.into().to_uppercase();
+        let (row_a, row_b, col_a, col_b) = get_start_and_end_point(&range_upper);
+        if col_a == 0 || row_a == 0 {
+            return None;
+        }
+        Some((
+            col_a.min(col_b),
+            row_a.min(row_b),
+            col_a.max(col_b),
+            row_a.max(row_b),
+        ))
+    }
+
     // ************************
     // Comment
     // ************************
@@ -507,6 +1082,85 @@ impl Worksheet {
         self.conditional_formatting_collection.push(value);
     }
 
+    /// Add a conditional formatting rule highlighting duplicate values.
+    /// # Arguments
+    /// * `range` - range. ex) "A:A", "A1:A100"
+    /// * `style` - style applied to duplicated cells.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut style = Style::default();
+    /// style.set_background_color(Color::COLOR_RED);
+    /// worksheet.add_conditional_duplicates("A:A", style);
+    /// ```
+    pub fn add_conditional_duplicates<S: Into<String>>(
+        &mut self,
+        range: S,
+        style: Style,
+    ) -> &mut Self {
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::DuplicateValues)
+            .set_style(style);
+        self.add_conditional_rule(range, rule)
+    }
+
+    /// Add a formula based conditional formatting rule.
+    /// # Arguments
+    /// * `range` - range. ex) "B2:B100"
+    /// * `formula` - formula relative to the top-left cell of the range. ex) "=$B2>100"
+    /// * `style` - style applied when the formula is true.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// let mut style = Style::default();
+    /// style.get_font_mut().set_bold(true);
+    /// worksheet.add_conditional_formula("B2:B100", "=$B2>100", style);
+    /// ```
+    pub fn add_conditional_formula<S: Into<String>, F: Into<String>>(
+        &mut self,
+        range: S,
+        formula: F,
+        style: Style,
+    ) -> &mut Self {
+        let formula = formula.into();
+        let mut obj = Formula::default();
+        obj.set_string_value(formula.strip_prefix('=').unwrap_or(&formula));
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::Expression)
+            .set_formula(obj)
+            .set_style(style);
+        self.add_conditional_rule(range, rule)
+    }
+
+    // Add a rule to a new ConditionalFormatting with the next free priority.
+    fn add_conditional_rule<S: Into<String>>(
+        &mut self,
+        range: S,
+        mut rule: ConditionalFormattingRule,
+    ) -> &mut Self {
+        let priority = self
+            .conditional_formatting_collection
+            .iter()
+            .flat_map(|v| v.get_conditional_collection())
+            .map(|v| *v.get_priority())
+            .max()
+            .unwrap_or(0);
+        rule.set_priority(priority + 1);
+
+        let mut conditional_formatting = ConditionalFormatting::default();
+        conditional_formatting
+            .get_sequence_of_references_mut()
+            .set_sqref(range.into().to_uppercase());
+        conditional_formatting.add_conditional_collection(rule);
+        self.conditional_formatting_collection
+            .push(conditional_formatting);
+        self
+    }
+
     // ************************
     // Hyperlink
     // ************************
@@ -599,11 +1253,83 @@ impl Worksheet {
         self.auto_filter = Some(auto_filter);
     }
 
+    /// (This method is crate only.)
+    /// Set Auto Filter.
+    pub(crate) fn set_auto_filter_crate(&mut self, value: AutoFilter) {
+        self.auto_filter = Some(value);
+    }
+
     // Remove Auto Filter.
     pub fn remove_auto_filter(&mut self) {
         self.auto_filter = None;
     }
 
+    /// Get the distinct values of a column of the auto filter, like the filter dropdown of Excel.
+    /// The values are formatted and sorted; numbers come first, then texts.
+    /// When the filter range is only the header row, the rows below are read down to the last value of the column.
+    /// An empty string is added last if there is a blank cell.
+    /// # Arguments
+    /// * `column` - Column of the auto filter. ex) `"B"`
+    /// # Return value
+    /// * `Vec<String>` - Values. Empty if the column is not part of the auto filter.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_values("A1", &[["Name", "Team"], ["Ann", "Red"], ["Bob", "Blue"], ["Cid", "Red"]]);
+    /// worksheet.set_auto_filter("A1:B1");
+    /// assert_eq!(worksheet.get_filter_values("B"), vec!["Blue", "Red"]);
+    /// ```
+    pub fn get_filter_values<S: AsRef<str>>(&self, column: S) -> Vec<String> {
+        let auto_filter = match self.get_auto_filter() {
+            Some(v) => v,
+            None => return Vec::new(),
+        };
+        let range_upper = auto_filter.get_range().get_range().to_uppercase();
+        let (header_row, row_end, col_start, col_end) = get_start_and_end_point(&range_upper);
+        let col = column_index_from_string(column.as_ref().to_uppercase());
+        if col < col_start || (col_end != 0 && col > col_end) {
+            return Vec::new();
+        }
+        let row_end = match row_end > header_row {
+            true => row_end,
+            false => self
+                .cell_collection
+                .get_range((header_row + 1, 0, col, col))
+                .filter(|v| !v.get_raw_value().is_empty())
+                .map(|v| *v.get_coordinate().get_row_num())
+                .max()
+                .unwrap_or(header_row),
+        };
+
+        let mut values: Vec<(&CellRawValue, String)> = self
+            .cell_collection
+            .get_range((header_row + 1, row_end, col, col))
+            .filter(|v| !v.get_raw_value().is_empty())
+            .map(|v| (v.get_raw_value(), v.get_formatted_value()))
+            .collect();
+        let has_blank = values.len() < (row_end - header_row) as usize;
+        values.sort_by(
+            |(a, a_text), (b, b_text)| match (a.get_number(), b.get_number()) {
+                (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => a_text.to_lowercase().cmp(&b_text.to_lowercase()),
+            },
+        );
+        let mut found: HashSet<String> = HashSet::new();
+        let mut result: Vec<String> = Vec::new();
+        for (_, text) in values {
+            if found.insert(text.clone()) {
+                result.push(text);
+            }
+        }
+        if has_blank {
+            result.push(String::new());
+        }
+        result
+    }
+
     // ************************
     // Column Dimensions
     // ************************
@@ -658,6 +1384,82 @@ impl Worksheet {
         self.get_column_dimensions_crate_mut().get_column_mut(col)
     }
 
+    /// Get Column Dimensions of a column range in mutable.
+    /// Missing columns are created.
+    /// # Arguments
+    /// * `range` - Column range. ex) "B:D", "F"
+    /// # Return value
+    /// * `Vec<&mut Column>` - in column order.
+    pub fn get_column_dimensions_by_range_mut(&mut self, range: &str) -> Vec<&mut Column> {
+        let col_list = get_column_index_list(&range.to_uppercase());
+        for col in &col_list {
+            self.get_column_dimension_by_number_mut(col);
+        }
+        let mut result: Vec<&mut Column> = self
+            .get_column_dimensions_crate_mut()
+            .get_column_collection_mut()
+            .iter_mut()
+            .filter(|column| col_list.contains(column.get_col_num()))
+            .collect();
+        result.sort_by_key(|column| *column.get_col_num());
+        result
+    }
+
+    /// Set the width of a column range.
+    /// # Arguments
+    /// * `range` - Column range. ex) "B:D"
+    /// * `value` - Width.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_column_width("B:D", 18.0);
+    /// ```
+    pub fn set_column_width(&mut self, range: &str, value: f64) -> &mut Self {
+        for column in self.get_column_dimensions_by_range_mut(range) {
+            column.set_width(value);
+        }
+        self
+    }
+
+    /// Hide a column range.
+    /// # Arguments
+    /// * `range` - Column range. ex) "F:H"
+    pub fn hide_columns(&mut self, range: &str) -> &mut Self {
+        for column in self.get_column_dimensions_by_range_mut(range) {
+            column.set_hidden(true);
+        }
+        self
+    }
+
+    /// Unhide a column range.
+    /// A column hidden with a zero width gets the default width back.
+    /// # Arguments
+    /// * `range` - Column range. ex) "F:H"
+    pub fn unhide_columns(&mut self, range: &str) -> &mut Self {
+        for column in self.get_column_dimensions_by_range_mut(range) {
+            column.set_hidden(false);
+            if column.get_width() == &0f64 {
+                column.set_width(*Column::default().get_width());
+            }
+        }
+        self
+    }
+
+    /// Hide a column.
+    /// # Arguments
+    /// * `column` - Column Char. ex) "C"
+    pub fn hide_column(&mut self, column: &str) -> &mut Self {
+        self.hide_columns(column)
+    }
+
+    /// Unhide a column.
+    /// # Arguments
+    /// * `column` - Column Char. ex) "C"
+    pub fn unhide_column(&mut self, column: &str) -> &mut Self {
+        self.unhide_columns(column)
+    }
+
     /// (This method is crate only.)
     /// Get Column Dimension.
     pub(crate) fn get_column_dimensions_crate(&self) -> &Columns {
@@ -713,6 +1515,369 @@ impl Worksheet {
         self.row_dimensions.get_row_dimension_mut(row)
     }
 
+    /// Hide a row.
+    /// # Arguments
+    /// * `row` - Row number. ex) 7
+    pub fn hide_row(&mut self, row: &u32) -> &mut Self {
+        self.get_row_dimension_mut(row).set_hidden(true);
+        self
+    }
+
+    /// Unhide a row.
+    /// A row hidden with a zero custom height gets the default height back.
+    /// # Arguments
+    /// * `row` - Row number. ex) 7
+    pub fn unhide_row(&mut self, row: &u32) -> &mut Self {
+        let row_dimension = self.get_row_dimension_mut(row);
+        row_dimension.set_hidden(false);
+        if *row_dimension.get_custom_height() && row_dimension.get_height() == &0f64 {
+            row_dimension.set_custom_height(false);
+        }
+        self
+    }
+
+    /// Hide a range of rows.
+    /// # Arguments
+    /// * `rows` - Row numbers. ex) 7..=20
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.hide_rows(7..=20);
+    /// ```
+    pub fn hide_rows(&mut self, rows: RangeInclusive<u32>) -> &mut Self {
+        for row in rows {
+            self.hide_row(&row);
+        }
+        self
+    }
+
+    /// Unhide a range of rows.
+    /// # Arguments
+    /// * `rows` - Row numbers. ex) 7..=20
+    pub fn unhide_rows(&mut self, rows: RangeInclusive<u32>) -> &mut Self {
+        for row in rows {
+            self.unhide_row(&row);
+        }
+        self
+    }
+
+    /// Get a handle to work on a whole row.
+    /// # Arguments
+    /// * `row` - Row number. ex) 5
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_row_mut(&5).set_values(&[1, 2, 3]).hide();
+    /// assert_eq!(worksheet.get_value("C5"), "3");
+    /// assert!(*worksheet.get_row_dimension(&5).unwrap().get_hidden());
+    /// ```
+    pub fn get_row_mut(&mut self, row: &u32) -> RowMut {
+        RowMut::new(self, *row)
+    }
+
+    /// Copy the formatting of a row to the following rows.
+    /// Row height, row style and the style of each cell in the row are copied.
+    /// # Arguments
+    /// * `src_row` - Row to copy from. ex) 2
+    /// * `dst_row` - First row to copy to. ex) 3
+    /// * `num_rows` - Number of rows to copy to. ex) 10
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_row_dimension_mut(&2).set_height(30.0);
+    /// worksheet.insert_new_row(&3, &10);
+    /// worksheet.copy_row_style(&2, &3, &10);
+    /// assert_eq!(worksheet.get_row_dimension(&12).unwrap().get_height(), &30.0);
+    /// ```
+    pub fn copy_row_style(&mut self, src_row: &u32, dst_row: &u32, num_rows: &u32) -> &mut Self {
+        let src = self.get_row_dimension(src_row).cloned();
+        let cell_style_list: Vec<(u32, Arc<Style>)> = self
+            .cell_collection
+            .get_collection_by_row(src_row)
+            .iter()
+            .map(|v| {
+                (
+                    *v.get_coordinate().get_col_num(),
+                    v.get_style_shared().clone(),
+                )
+            })
+            .collect();
+        for row_num in *dst_row..dst_row + num_rows {
+            let row = self.get_row_dimension_mut(&row_num);
+            if let Some(src) = &src {
+                row.set_height(*src.get_height())
+                    .set_custom_height(*src.get_custom_height())
+                    .set_style_shared(src.get_style_shared().clone());
+            }
+            for (col_num, style) in &cell_style_list {
+                self.cell_collection
+                    .get_mut((col_num, &row_num))
+                    .set_style_shared(style.clone());
+            }
+        }
+        self
+    }
+
+    /// Copy the formatting of a column to the following columns.
+    /// Column width, column style and the style of each cell in the column are copied.
+    /// # Arguments
+    /// * `src_col` - Column to copy from. ex) 2
+    /// * `dst_col` - First column to copy to. ex) 3
+    /// * `num_cols` - Number of columns to copy to. ex) 10
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_column_dimension_by_number_mut(&2).set_width(30.0);
+    /// worksheet.copy_column_style(&2, &3, &2);
+    /// assert_eq!(worksheet.get_column_dimension("D").unwrap().get_width(), &30.0);
+    /// ```
+    pub fn copy_column_style(&mut self, src_col: &u32, dst_col: &u32, num_cols: &u32) -> &mut Self {
+        let src = self.get_column_dimension_by_number(src_col).cloned();
+        let cell_style_list: Vec<(u32, Arc<Style>)> = self
+            .cell_collection
+            .get_collection_by_column(src_col)
+            .iter()
+            .map(|v| {
+                (
+                    *v.get_coordinate().get_row_num(),
+                    v.get_style_shared().clone(),
+                )
+            })
+            .collect();
+        for col_num in *dst_col..dst_col + num_cols {
+            if let Some(src) = &src {
+                self.get_column_dimension_by_number_mut(&col_num)
+                    .set_width(*src.get_width())
+                    .set_best_fit(*src.get_best_fit())
+                    .set_auto_width(*src.get_auto_width())
+                    .set_style_shared(src.get_style_shared().clone());
+            }
+            for (row_num, style) in &cell_style_list {
+                self.get_row_dimension_mut(row_num);
+                self.cell_collection
+                    .get_mut((&col_num, row_num))
+                    .set_style_shared(style.clone());
+            }
+        }
+        self
+    }
+
+    /// Capture the formatting of a range.
+    /// Cell styles, custom row heights and merged cells inside the range are kept.
+    /// # Arguments
+    /// * `range` - Range. ex) "A1:H20"
+    /// # Return value
+    /// * `FormatSnapshot` - Formatting to stamp out with `apply_formatting`.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_style_mut("B2").get_font_mut().set_bold(true);
+    /// let snapshot = worksheet.capture_formatting("A1:H20");
+    /// worksheet.apply_formatting(&snapshot, "A30");
+    /// assert!(*worksheet.get_style("B31").get_font().unwrap().get_bold());
+    /// ```
+    pub fn capture_formatting<S: Into<String>>(&self, range: S) -> FormatSnapshot {
+        let (row_start, row_end, col_start, col_end) =
+            get_start_and_end_point(&range.into().to_uppercase());
+        let mut snapshot = FormatSnapshot::new(col_end - col_start + 1, row_end - row_start + 1);
+        for cell in self.cell_collection.get_collection() {
+            let col_num = *cell.get_coordinate().get_col_num();
+            let row_num = *cell.get_coordinate().get_row_num();
+            if (col_start..=col_end).contains(&col_num) && (row_start..=row_end).contains(&row_num)
+            {
+                snapshot.add_style(
+                    col_num - col_start,
+                    row_num - row_start,
+                    cell.get_style_shared().clone(),
+                );
+            }
+        }
+        for row_num in row_start..=row_end {
+            if let Some(row) = self.get_row_dimension(&row_num) {
+                if *row.get_custom_height() {
+                    snapshot.add_row_height(row_num - row_start, *row.get_height());
+                }
+            }
+        }
+        for merge_cell in self.get_merge_cells() {
+            let (r_start, r_end, c_start, c_end) = get_start_and_end_point(&merge_cell.get_range());
+            if c_start >= col_start && c_end <= col_end && r_start >= row_start && r_end <= row_end
+            {
+                snapshot.add_merge_cell(
+                    c_start - col_start,
+                    r_start - row_start,
+                    c_end - col_start,
+                    r_end - row_start,
+                );
+            }
+        }
+        snapshot
+    }
+
+    /// Apply captured formatting with its top left cell at the coordinate.
+    /// Cells of the target range that were unstyled in the snapshot get the default style,
+    /// and merged cells overlapping the target range are replaced.
+    /// # Arguments
+    /// * `snapshot` - Formatting taken with `capture_formatting`.
+    /// * `coordinate` - Top left cell of the target range. ex) `"A30"` or `(1, 30)`
+    pub fn apply_formatting<T>(&mut self, snapshot: &FormatSnapshot, coordinate: T) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let col_end = col + snapshot.get_column_count() - 1;
+        let row_end = row + snapshot.get_row_count() - 1;
+
+        let default_style = Arc::new(Style::default());
+        for cell in self.cell_collection.get_collection_mut() {
+            let col_num = *cell.get_coordinate().get_col_num();
+            let row_num = *cell.get_coordinate().get_row_num();
+            if (col..=col_end).contains(&col_num) && (row..=row_end).contains(&row_num) {
+                cell.set_style_shared(default_style.clone());
+            }
+        }
+        for (col_offset, row_offset, style) in snapshot.get_styles() {
+            self.get_row_dimension_mut(&(row + row_offset));
+            self.cell_collection
+                .get_mut((col + col_offset, row + row_offset))
+                .set_style_shared(style.clone());
+        }
+        for (row_offset, height) in snapshot.get_row_heights() {
+            self.get_row_dimension_mut(&(row + row_offset))
+                .set_height(*height);
+        }
+
+        self.get_merge_cells_mut().retain(|merge_cell| {
+            let (r_start, r_end, c_start, c_end) = get_start_and_end_point(&merge_cell.get_range());
+            c_end < col || c_start > col_end || r_end < row || r_start > row_end
+        });
+        for (c_start, r_start, c_end, r_end) in snapshot.get_merge_cells() {
+            let range = format!(
+                "{}:{}",
+                coordinate_from_index(&(col + c_start), &(row + r_start)),
+                coordinate_from_index(&(col + c_end), &(row + r_end)),
+            );
+            self.add_merge_cells(range);
+        }
+        self
+    }
+
+    /// Fill the `{{placeholder}}` tokens of the cells, rich text and header/footer.
+    /// A row with `{{list.field}}` tokens is repeated once per record of the list,
+    /// keeping the formatting of the row; it is removed when the list has no record.
+    /// A cell holding only one token takes the value as `set_value` does (ex. as a number).
+    /// Unknown tokens are left as they are.
+    /// Use `Spreadsheet::fill_template` to also adjust references from other sheets.
+    /// # Arguments
+    /// * `values` - TemplateValues
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.get_cell_mut("A1").set_value("Invoice for {{customer}}");
+    /// worksheet.get_cell_mut("A2").set_value("{{items.name}}");
+    /// let mut values = umya_spreadsheet::TemplateValues::default();
+    /// values.set_value("customer", "ACME");
+    /// values.add_record("items", &[("name", "Pen")]);
+    /// values.add_record("items", &[("name", "Ink")]);
+    /// worksheet.fill_template(&values);
+    /// assert_eq!(worksheet.get_value("A1"), "Invoice for ACME");
+    /// assert_eq!(worksheet.get_value("A3"), "Ink");
+    /// ```
+    pub fn fill_template(&mut self, values: &TemplateValues) -> &mut Self {
+        for (row, list) in self.get_template_rows(values) {
+            let count = values.get_records(&list).map_or(0, |v| v.len()) as u32;
+            if count == 0 {
+                self.remove_row(&row, &1);
+                continue;
+            }
+            if count > 1 {
+                self.insert_new_row(&(row + 1), &(count - 1));
+            }
+            self.fill_template_row(&row, &list, values);
+        }
+        self.fill_template_values(values);
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the rows with `{{list.field}}` tokens, from the bottom.
+    pub(crate) fn get_template_rows(&self, values: &TemplateValues) -> Vec<(u32, String)> {
+        let mut result: Vec<(u32, String)> = Vec::new();
+        for cell in self.cell_collection.get_collection_sorted() {
+            let row = *cell.get_coordinate().get_row_num();
+            if result.iter().any(|(v, _)| v == &row) {
+                continue;
+            }
+            if let Some(list) = values.find_list(&get_template_text(cell)) {
+                result.push((row, list.to_string()));
+            }
+        }
+        result.reverse();
+        result
+    }
+
+    /// (This method is crate only.)
+    /// Fill a repeated row and the rows inserted below it with the records of the list.
+    pub(crate) fn fill_template_row(&mut self, row: &u32, list: &str, values: &TemplateValues) {
+        let count = values.get_records(list).map_or(0, |v| v.len()) as u32;
+        let cells: Vec<Cell> = self
+            .cell_collection
+            .get_collection_by_row(row)
+            .into_iter()
+            .cloned()
+            .collect();
+        if count > 1 {
+            self.copy_row_style(row, &(row + 1), &(count - 1));
+        }
+        for index in 0..count {
+            for cell in &cells {
+                let mut cell = cell.clone();
+                if index > 0 {
+                    cell.get_coordinate_mut().set_row_num(row + index);
+                    if cell.is_formula() {
+                        let mut tokens = parse_to_tokens(format!("={}", cell.get_formula()));
+                        let formula = adjustment_insert_formula_coordinate(
+                            &mut tokens,
+                            &0,
+                            &0,
+                            row,
+                            &index,
+                            "",
+                            "",
+                            false,
+                        );
+                        cell.set_formula(formula);
+                    }
+                }
+                fill_template_cell(&mut cell, |text| {
+                    values.replace_record(text, list, index as usize)
+                });
+                self.set_cell(cell);
+            }
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Fill the `{{name}}` tokens of the cells and header/footer.
+    pub(crate) fn fill_template_values(&mut self, values: &TemplateValues) {
+        for cell in self.cell_collection.get_collection_mut() {
+            fill_template_cell(cell, |text| values.replace(text));
+        }
+        let header_footer = self.get_header_footer_mut();
+        if let Some(v) = values.replace(header_footer.get_odd_header().get_value()) {
+            header_footer.get_odd_header_mut().set_value(v);
+        }
+        if let Some(v) = values.replace(header_footer.get_odd_footer().get_value()) {
+            header_footer.get_odd_footer_mut().set_value(v);
+        }
+    }
+
     /// (This method is crate only.)
     /// Set Row Dimension.
     pub(crate) fn set_row_dimension(&mut self, value: Row) -> &mut Self {
@@ -755,6 +1920,11 @@ impl Worksheet {
     /// Has WorksheetDrawing.
     pub fn has_drawing_object(&self) -> bool {
         self.worksheet_drawing.has_drawing_object()
+            || self
+                .ole_objects
+                .get_ole_object()
+                .iter()
+                .any(|v| *v.get_two_cell_anchor().get_is_alternate_content())
     }
 
     // ************************
@@ -949,10 +2119,79 @@ impl Worksheet {
     }
 
     /// Set Active Cell.
+    /// The file opens with the cursor on this cell.
     /// # Arguments
     /// * `cell` - Cell ex) "A1"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_active_cell("C5");
+    /// ```
     pub fn set_active_cell<S: Into<String>>(&mut self, cell: S) {
-        self.active_cell = cell.into();
+        let cell = cell.into();
+        self.get_first_sheet_view_mut().set_active_cell(&cell);
+        self.active_cell = cell;
+    }
+
+    /// Set the selected ranges.
+    /// The active cell is moved to the first range unless the ranges already contain it.
+    /// # Arguments
+    /// * `ranges` - Ranges separated by a space. ex) "A1:B3 D5:D8"
+    pub fn set_selected_ranges(&mut self, ranges: &str) -> &mut Self {
+        let sheet_view = self.get_first_sheet_view_mut();
+        sheet_view.set_selected_ranges(ranges);
+        let active_cell = sheet_view
+            .get_active_cell()
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        self.active_cell = active_cell;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Sync the active cell with the sheet view after reading.
+    pub(crate) fn set_active_cell_from_sheet_views(&mut self) {
+        if let Some(v) = self
+            .sheet_views
+            .get_sheet_view_list()
+            .first()
+            .and_then(|v| v.get_active_cell())
+        {
+            self.active_cell = v.to_string();
+        }
+    }
+
+    /// Freeze the header rows and columns.
+    /// The pane, its top left cell, the selections and the frozen state are set together.
+    /// Passing 0 for both unfreezes the sheet.
+    /// # Arguments
+    /// * `rows` - Number of rows to freeze. ex) 1
+    /// * `cols` - Number of columns to freeze. ex) 0
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let mut worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.freeze_header(1, 2);
+    /// assert_eq!(worksheet.get_active_cell(), "C2");
+    /// ```
+    pub fn freeze_header(&mut self, rows: u32, cols: u32) -> &mut Self {
+        let sheet_view = self.get_first_sheet_view_mut();
+        sheet_view.freeze_panes(rows, cols);
+        let active_cell = sheet_view
+            .get_active_cell()
+            .map(|v| v.to_string())
+            .unwrap_or_default();
+        self.active_cell = active_cell;
+        self
+    }
+
+    fn get_first_sheet_view_mut(&mut self) -> &mut SheetView {
+        if self.sheet_views.get_sheet_view_list().is_empty() {
+            self.sheet_views
+                .add_sheet_view_list_mut(SheetView::default());
+        }
+        &mut self.sheet_views.get_sheet_view_list_mut()[0]
     }
 
     /// Get R Id.
@@ -1011,13 +2250,19 @@ impl Worksheet {
     }
 
     /// Calculate Worksheet Dimension.
+    /// The used range from the lowest to the highest cell (ex. "B2:F200").
+    /// "A1" for an empty worksheet.
     pub fn calculate_worksheet_dimension(&self) -> String {
         let (column, row) = self.cell_collection.get_highest_column_and_row();
         if row == 0 {
             return "A1".to_string();
         }
-        let column_str = string_from_column_index(&column);
-        format!("A1:{}{}", column_str, row)
+        let (lowest_column, lowest_row) = self.cell_collection.get_lowest_column_and_row();
+        let start = coordinate_from_index(&lowest_column, &lowest_row);
+        if lowest_column == column && lowest_row == row {
+            return start;
+        }
+        format!("{}:{}", start, coordinate_from_index(&column, &row))
     }
 
     // Get Highest Column and Row Index
@@ -1027,6 +2272,13 @@ impl Worksheet {
         self.cell_collection.get_highest_column_and_row()
     }
 
+    // Get Lowest Column and Row Index
+    /// # Return value
+    /// *`(u32, u32)` - (column, row)
+    pub fn get_lowest_column_and_row(&self) -> (u32, u32) {
+        self.cell_collection.get_lowest_column_and_row()
+    }
+
     // Get Highest Column Index
     pub fn get_highest_column(&self) -> u32 {
         let (column, _row) = self.cell_collection.get_highest_column_and_row();
@@ -1123,6 +2375,24 @@ impl Worksheet {
         self
     }
 
+    /// Get the saved custom views of the sheet.
+    pub fn get_custom_sheet_views(&self) -> &CustomSheetViews {
+        &self.custom_sheet_views
+    }
+
+    /// Get the saved custom views of the sheet in mutable.
+    pub fn get_custom_sheet_views_mut(&mut self) -> &mut CustomSheetViews {
+        &mut self.custom_sheet_views
+    }
+
+    /// Set the saved custom views of the sheet.
+    /// # Arguments
+    /// * `value` - CustomSheetViews.
+    pub fn set_custom_sheet_views(&mut self, value: CustomSheetViews) -> &mut Self {
+        self.custom_sheet_views = value;
+        self
+    }
+
     // Get Ole Objects.
     pub fn get_ole_objects(&self) -> &OleObjects {
         &self.ole_objects
@@ -1141,6 +2411,49 @@ impl Worksheet {
         self
     }
 
+    /// Get Form Controls.
+    pub fn get_form_controls(&self) -> &Vec<FormControl> {
+        &self.form_controls
+    }
+
+    /// Get Form Controls in mutable.
+    pub fn get_form_controls_mut(&mut self) -> &mut Vec<FormControl> {
+        &mut self.form_controls
+    }
+
+    /// Add Form Control.
+    /// # Arguments
+    /// * `value` - FormControl. (see `FormControl::new_button`)
+    pub fn add_form_control(&mut self, value: FormControl) -> &mut Self {
+        self.form_controls.push(value);
+        self
+    }
+
+    /// Remove all Form Controls.
+    pub fn remove_form_controls(&mut self) -> &mut Self {
+        self.form_controls.clear();
+        self
+    }
+
+    /// Get legacy shapes that are not comments, OLE objects or form controls.
+    /// They are kept as read and written back with the legacy drawing.
+    pub fn get_vml_shapes(&self) -> &Vec<VmlShape> {
+        &self.vml_shapes
+    }
+
+    /// Get legacy shapes in mutable.
+    pub fn get_vml_shapes_mut(&mut self) -> &mut Vec<VmlShape> {
+        &mut self.vml_shapes
+    }
+
+    /// Add a legacy shape.
+    /// # Arguments
+    /// * `value` - Shape.
+    pub fn add_vml_shape(&mut self, value: VmlShape) -> &mut Self {
+        self.vml_shapes.push(value);
+        self
+    }
+
     /// Get Defined Name (Vec).
     pub fn get_defined_names(&self) -> &Vec<DefinedName> {
         &self.defined_names
@@ -1195,6 +2508,39 @@ impl Worksheet {
         self
     }
 
+    /// Print the gridlines.
+    /// # Arguments
+    /// * `value` - true: print the gridlines.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet.set_print_gridlines(true).set_print_headings(true);
+    /// ```
+    pub fn set_print_gridlines(&mut self, value: bool) -> &mut Self {
+        self.print_options.set_grid_lines(value);
+        self
+    }
+
+    /// Print the row and column headings.
+    /// # Arguments
+    /// * `value` - true: print the headings.
+    pub fn set_print_headings(&mut self, value: bool) -> &mut Self {
+        self.print_options.set_headings(value);
+        self
+    }
+
+    /// Center the printed page.
+    /// # Arguments
+    /// * `horizontally` - true: center horizontally.
+    /// * `vertically` - true: center vertically.
+    pub fn set_print_centered(&mut self, horizontally: bool, vertically: bool) -> &mut Self {
+        self.print_options
+            .set_horizontal_centered(horizontally)
+            .set_vertical_centered(vertically);
+        self
+    }
+
     /// Get Column Breaks.
     pub fn get_column_breaks(&self) -> &ColumnBreaks {
         &self.column_breaks
@@ -1213,6 +2559,24 @@ impl Worksheet {
         self
     }
 
+    /// Get Ignored Errors.
+    pub fn get_ignored_errors(&self) -> &IgnoredErrors {
+        &self.ignored_errors
+    }
+
+    /// Get Ignored Errors in mutable.
+    pub fn get_ignored_errors_mut(&mut self) -> &mut IgnoredErrors {
+        &mut self.ignored_errors
+    }
+
+    /// Set Ignored Errors.
+    /// # Arguments
+    /// * `value` - IgnoredErrors.
+    pub fn set_ignored_errors(&mut self, value: IgnoredErrors) -> &mut Self {
+        self.ignored_errors = value;
+        self
+    }
+
     /// Get Row Breaks.
     pub fn get_row_breaks(&self) -> &RowBreaks {
         &self.row_breaks
@@ -1296,6 +2660,24 @@ impl Worksheet {
         self
     }
 
+    /// Get the sheet properties (sheetPr).
+    pub fn get_sheet_properties(&self) -> &SheetProperties {
+        &self.sheet_properties
+    }
+
+    /// Get the sheet properties (sheetPr) in mutable.
+    pub fn get_sheet_properties_mut(&mut self) -> &mut SheetProperties {
+        &mut self.sheet_properties
+    }
+
+    /// Set the sheet properties (sheetPr).
+    /// # Arguments
+    /// * `value` - SheetProperties
+    pub fn set_sheet_properties(&mut self, value: SheetProperties) -> &mut Self {
+        self.sheet_properties = value;
+        self
+    }
+
     /// Outputs all images contained in the worksheet.
     /// # Return value
     /// * `&Vec<Image>` - Image Object List.
@@ -1370,6 +2752,192 @@ impl Worksheet {
         self
     }
 
+    /// Outputs all text boxes contained in the worksheet.
+    /// # Return value
+    /// * `&Vec<TextBox>` - TextBox Object List.
+    pub fn get_text_box_collection(&self) -> &Vec<TextBox> {
+        self.get_worksheet_drawing().get_text_box_collection()
+    }
+
+    /// Outputs all text boxes contained in the worksheet.
+    /// # Return value
+    /// * `&mut Vec<TextBox>` - TextBox Object List.
+    pub fn get_text_box_collection_mut(&mut self) -> &mut Vec<TextBox> {
+        self.get_worksheet_drawing_mut()
+            .get_text_box_collection_mut()
+    }
+
+    pub fn add_text_box(&mut self, value: TextBox) -> &mut Self {
+        self.get_worksheet_drawing_mut().add_text_box(value);
+        self
+    }
+
+    /// Outputs all shapes contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&Shape>` - Shape Object List.
+    pub fn get_shape_collection(&self) -> Vec<&Shape> {
+        self.get_worksheet_drawing().get_shape_collection()
+    }
+
+    /// Outputs all shapes contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&mut Shape>` - Shape Object List.
+    pub fn get_shape_collection_mut(&mut self) -> Vec<&mut Shape> {
+        self.get_worksheet_drawing_mut().get_shape_collection_mut()
+    }
+
+    /// Add a shape anchored between two cells.
+    /// A shape without id is given a new one, which connectors can refer to.
+    /// # Arguments
+    /// * `value` - Shape. (see `Shape::new_shape`)
+    /// * `from_marker` - Top left of the shape.
+    /// * `to_marker` - Bottom right of the shape.
+    pub fn add_shape(
+        &mut self,
+        mut value: Shape,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> &mut Self {
+        if value
+            .get_non_visual_shape_properties()
+            .get_non_visual_drawing_properties()
+            .get_id()
+            == &0
+        {
+            let id = self.get_worksheet_drawing().get_next_shape_id();
+            value
+                .get_non_visual_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_id(id);
+        }
+        let mut anchor = TwoCellAnchor::default();
+        anchor.set_from_marker(from_marker);
+        anchor.set_to_marker(to_marker);
+        anchor.set_shape(value);
+        self.get_worksheet_drawing_mut()
+            .add_two_cell_anchor_collection(anchor);
+        self
+    }
+
+    /// Outputs all connectors contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&ConnectionShape>` - ConnectionShape Object List.
+    pub fn get_connection_shape_collection(&self) -> Vec<&ConnectionShape> {
+        self.get_worksheet_drawing()
+            .get_connection_shape_collection()
+    }
+
+    /// Outputs all connectors contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&mut ConnectionShape>` - ConnectionShape Object List.
+    pub fn get_connection_shape_collection_mut(&mut self) -> Vec<&mut ConnectionShape> {
+        self.get_worksheet_drawing_mut()
+            .get_connection_shape_collection_mut()
+    }
+
+    /// Add a connector drawn between two cell anchors.
+    /// Glue it to shapes with `ConnectionShape::set_start_shape` and `set_end_shape`.
+    /// # Arguments
+    /// * `value` - ConnectionShape. (see `ConnectionShape::new_connector`)
+    /// * `from_marker` - Start point of the connector.
+    /// * `to_marker` - End point of the connector.
+    pub fn add_connector(
+        &mut self,
+        mut value: ConnectionShape,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> &mut Self {
+        if value
+            .get_non_visual_connection_shape_properties()
+            .get_non_visual_drawing_properties()
+            .get_id()
+            == &0
+        {
+            let id = self.get_worksheet_drawing().get_next_shape_id();
+            value
+                .get_non_visual_connection_shape_properties_mut()
+                .get_non_visual_drawing_properties_mut()
+                .set_id(id);
+        }
+        let mut anchor = TwoCellAnchor::default();
+        anchor.set_from_marker(from_marker);
+        anchor.set_to_marker(to_marker);
+        anchor.set_connection_shape(value);
+        self.get_worksheet_drawing_mut()
+            .add_two_cell_anchor_collection(anchor);
+        self
+    }
+
+    /// Outputs all group shapes contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&GroupShape>` - GroupShape Object List.
+    pub fn get_group_shape_collection(&self) -> Vec<&GroupShape> {
+        self.get_worksheet_drawing().get_group_shape_collection()
+    }
+
+    /// Outputs all group shapes contained in the worksheet.
+    /// # Return value
+    /// * `Vec<&mut GroupShape>` - GroupShape Object List.
+    pub fn get_group_shape_collection_mut(&mut self) -> Vec<&mut GroupShape> {
+        self.get_worksheet_drawing_mut()
+            .get_group_shape_collection_mut()
+    }
+
+    /// Add a group of shapes anchored between two cells.
+    /// The group and its members without id are given new ones.
+    /// # Arguments
+    /// * `value` - GroupShape. (see `GroupShape::new_group`)
+    /// * `from_marker` - Top left of the group.
+    /// * `to_marker` - Bottom right of the group.
+    pub fn add_group_shape(
+        &mut self,
+        mut value: GroupShape,
+        from_marker: MarkerType,
+        to_marker: MarkerType,
+    ) -> &mut Self {
+        let mut next_id = self.get_worksheet_drawing().get_next_shape_id();
+        value.set_missing_id(&mut next_id);
+        let mut anchor = TwoCellAnchor::default();
+        anchor.set_from_marker(from_marker);
+        anchor.set_to_marker(to_marker);
+        anchor.set_group_shape(value);
+        self.get_worksheet_drawing_mut()
+            .add_two_cell_anchor_collection(anchor);
+        self
+    }
+
+    /// Draw the drawing objects anchored at the cell on top of all others.
+    /// # Arguments
+    /// * `coordinate` - Top left cell of the objects. ex) `"B2"`
+    pub fn bring_to_front<T>(&mut self, coordinate: T) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_worksheet_drawing_mut().bring_to_front(&col, &row);
+        self
+    }
+
+    /// Draw the drawing objects anchored at the cell below all others.
+    /// # Arguments
+    /// * `coordinate` - Top left cell of the objects. ex) `"B2"`
+    pub fn send_to_back<T>(&mut self, coordinate: T) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_worksheet_drawing_mut().send_to_back(&col, &row);
+        self
+    }
+
+    /// Images, charts, text boxes and shapes of the worksheet as one list.
+    /// They can be moved, resized, removed and duplicated through it.
+    /// # Return value
+    /// * `Drawings` - Drawing object list.
+    pub fn get_drawings_mut(&mut self) -> Drawings<'_> {
+        Drawings::new(self.get_worksheet_drawing_mut())
+    }
+
     pub fn get_chart<T>(&self, coordinate: T) -> Option<&Chart>
     where
         T: Into<CellCoordinates>,
@@ -1433,6 +3001,28 @@ impl Worksheet {
                 result.push(media_object);
             }
         }
+        if let Some(media_object) = self.get_background_image() {
+            let mut is_new = true;
+            for v in &result {
+                if v.get_image_name() == media_object.get_image_name() {
+                    is_new = false;
+                }
+            }
+            if is_new {
+                result.push(media_object);
+            }
+        }
+        for media_object in self.get_header_footer().get_media_object() {
+            let mut is_new = true;
+            for v in &result {
+                if v.get_image_name() == media_object.get_image_name() {
+                    is_new = false;
+                }
+            }
+            if is_new {
+                result.push(media_object);
+            }
+        }
         result
     }
 
@@ -1476,6 +3066,113 @@ impl Worksheet {
         self
     }
 
+    /// Replace formula and defined name references to `sheet_name` with `#REF!`.
+    pub(crate) fn remove_sheet_reference(&mut self, sheet_name: &str) {
+        for cell in self.cell_collection.get_collection_mut() {
+            cell.get_cell_value_mut().remove_sheet_reference(sheet_name);
+        }
+        for defined_name in &mut self.defined_names {
+            if defined_name.is_sheet_reference(sheet_name) {
+                defined_name.set_address("#REF!");
+            }
+        }
+    }
+
+    pub(crate) fn rename_sheet_reference(&mut self, sheet_name: &str, new_sheet_name: &str) {
+        for cell in self.cell_collection.get_collection_mut() {
+            cell.get_cell_value_mut()
+                .rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.rename_sheet_reference(sheet_name, new_sheet_name);
+        }
+        for conditional_formatting in &mut self.conditional_formatting_collection {
+            for rule in conditional_formatting.get_conditional_collection_mut() {
+                rule.rename_sheet_reference(sheet_name, new_sheet_name);
+            }
+        }
+        if let Some(v) = &mut self.data_validations {
+            for data_validation in v.get_data_validation_list_mut() {
+                data_validation.rename_sheet_reference(sheet_name, new_sheet_name);
+            }
+        }
+        if let Some(v) = &mut self.data_validations_2010 {
+            for data_validation in v.get_data_validation_list_mut() {
+                data_validation.rename_sheet_reference(sheet_name, new_sheet_name);
+            }
+        }
+        for chart in self.get_chart_collection_mut() {
+            chart.replace_sheet_name(sheet_name, new_sheet_name);
+        }
+    }
+
+    pub(crate) fn validate(&self, issues: &mut Vec<ValidationIssue>) {
+        if let Some(raw_data) = &self.raw_data_of_worksheet {
+            for relationships in raw_data.get_relationships_list() {
+                for relationship in relationships.get_relationship_list() {
+                    if relationship.get_target_mode() != "External"
+                        && relationship.get_raw_file().get_file_data().is_empty()
+                    {
+                        issues.push(ValidationIssue::new(
+                            ValidationIssueKind::BrokenRelationship,
+                            self.get_name(),
+                            format!(
+                                "relationship {} refers to the missing part {}",
+                                relationship.get_id(),
+                                relationship.get_raw_file().get_file_target()
+                            ),
+                        ));
+                    }
+                }
+            }
+            return;
+        }
+
+        for cell in self.get_cell_collection() {
+            let coordinate = cell.get_coordinate();
+            if coordinate.get_col_num() > &16384 || coordinate.get_row_num() > &1048576 {
+                issues.push(ValidationIssue::new(
+                    ValidationIssueKind::OutOfRangeReference,
+                    self.get_name(),
+                    format!("cell {} is out of range", coordinate.get_coordinate()),
+                ));
+            }
+        }
+
+        let mut merge_bounds: Vec<(String, (u32, u32, u32, u32))> = Vec::new();
+        for range in self.get_merge_cells() {
+            let col_start = range.get_coordinate_start_col().map_or(1, |v| *v.get_num());
+            let row_start = range.get_coordinate_start_row().map_or(1, |v| *v.get_num());
+            let col_end = range
+                .get_coordinate_end_col()
+                .map_or(16384, |v| *v.get_num());
+            let row_end = range
+                .get_coordinate_end_row()
+                .map_or(1048576, |v| *v.get_num());
+            if col_end > 16384 || row_end > 1048576 {
+                issues.push(ValidationIssue::new(
+                    ValidationIssueKind::OutOfRangeReference,
+                    self.get_name(),
+                    format!("merged range {} is out of range", range.get_range()),
+                ));
+            }
+            for (other, (o_col_start, o_row_start, o_col_end, o_row_end)) in &merge_bounds {
+                if col_start <= *o_col_end
+                    && *o_col_start <= col_end
+                    && row_start <= *o_row_end
+                    && *o_row_start <= row_end
+                {
+                    issues.push(ValidationIssue::new(
+                        ValidationIssueKind::OverlappingMergeCells,
+                        self.get_name(),
+                        format!("merged range {} overlaps {}", range.get_range(), other),
+                    ));
+                }
+            }
+            merge_bounds.push((range.get_range(), (col_start, row_start, col_end, row_end)));
+        }
+    }
+
     pub fn get_sheet_protection(&self) -> Option<&SheetProtection> {
         self.sheet_protection.as_ref()
     }
@@ -1495,6 +3192,49 @@ impl Worksheet {
         self
     }
 
+    /// Get Background Image.
+    pub fn get_background_image(&self) -> Option<&MediaObject> {
+        self.background_image.as_ref()
+    }
+
+    /// Set Background Image.
+    /// The picture is tiled behind the cells (watermark-style).
+    /// # Arguments
+    /// * `value` - Image file data (png, jpeg, gif, bmp or tiff).
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let data = std::fs::read("./images/sample1.png").unwrap();
+    /// book.get_sheet_mut(&0)
+    ///     .unwrap()
+    ///     .set_background_image(data);
+    /// ```
+    pub fn set_background_image(&mut self, value: Vec<u8>) -> &mut Self {
+        let extension = match image::guess_format(&value) {
+            Ok(image::ImageFormat::Jpeg) => "jpeg",
+            Ok(image::ImageFormat::Gif) => "gif",
+            Ok(image::ImageFormat::Bmp) => "bmp",
+            Ok(image::ImageFormat::Tiff) => "tiff",
+            _ => "png",
+        };
+        let image_name = format!("background_{:x}.{}", md5::Md5::digest(&value), extension);
+        let mut obj = MediaObject::default();
+        obj.set_image_name(image_name).set_image_data(value);
+        self.background_image = Some(obj);
+        self
+    }
+
+    pub(crate) fn set_background_image_crate(&mut self, value: MediaObject) -> &mut Self {
+        self.background_image = Some(value);
+        self
+    }
+
+    /// Remove Background Image.
+    pub fn remove_background_image(&mut self) -> &mut Self {
+        self.background_image = None;
+        self
+    }
+
     /// (This method is crate only.)
     /// Has Ole Objects.
     pub(crate) fn has_ole_objects(&self) -> bool {
@@ -1504,7 +3244,16 @@ impl Worksheet {
     /// (This method is crate only.)
     /// Has Legacy Drawing.
     pub(crate) fn has_legacy_drawing(&self) -> bool {
-        self.has_comments() || self.has_ole_objects()
+        self.has_comments()
+            || self.has_ole_objects()
+            || self.has_form_controls()
+            || !self.vml_shapes.is_empty()
+    }
+
+    /// (This method is crate only.)
+    /// Has Form Controls.
+    pub(crate) fn has_form_controls(&self) -> bool {
+        !self.form_controls.is_empty()
     }
 
     /// Moving a section of the sheet
@@ -1559,6 +3308,107 @@ impl Worksheet {
         self
     }
 }
+fn get_template_text(cell: &Cell) -> String {
+    match cell.get_raw_value() {
+        CellRawValue::String(_) | CellRawValue::RichText(_) | CellRawValue::Lazy(_)
+            if !cell.is_formula() =>
+        {
+            cell.get_value().to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+fn fill_template_cell<F>(cell: &mut Cell, replace: F)
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if cell.is_formula() {
+        return;
+    }
+    match cell.get_raw_value().clone() {
+        CellRawValue::String(v) | CellRawValue::Lazy(v) => {
+            if let Some(value) = replace(&v) {
+                if TemplateValues::is_placeholder_only(&v) {
+                    cell.set_value(value);
+                } else {
+                    cell.set_value_string(value);
+                }
+            }
+        }
+        CellRawValue::RichText(mut v) => {
+            let mut is_replaced = false;
+            for element in v.get_rich_text_elements_mut() {
+                if let Some(value) = replace(element.get_text()) {
+                    element.set_text(value);
+                    is_replaced = true;
+                }
+            }
+            if is_replaced {
+                cell.set_rich_text(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Iterates over the cells in no particular order.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// book.get_sheet_mut(&0).unwrap().get_cell_mut("A1").set_value("TEST1");
+/// for sheet in &book {
+///     for cell in sheet {
+///         assert_eq!(cell.get_value(), "TEST1");
+///     }
+/// }
+/// ```
+impl<'a> IntoIterator for &'a Worksheet {
+    type Item = &'a Cell;
+    type IntoIter = <&'a Cells as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cell_collection.into_iter()
+    }
+}
+impl<'a> IntoIterator for &'a mut Worksheet {
+    type Item = &'a mut Cell;
+    type IntoIter = <&'a mut Cells as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&mut self.cell_collection).into_iter()
+    }
+}
+impl<T> Index<T> for Worksheet
+where
+    T: Into<CellCoordinates>,
+{
+    type Output = Cell;
+
+    /// Get Cell.
+    /// # Panics
+    /// Panics if the cell does not exist. Use `worksheet[coordinate]` on a mutable worksheet to create it.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.get_sheet_mut(&0).unwrap();
+    /// worksheet["A1"].set_value("TEST1");
+    /// assert_eq!(worksheet[(1, 1)].get_value(), "TEST1");
+    /// ```
+    fn index(&self, coordinate: T) -> &Cell {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.get_cell((col, row))
+            .unwrap_or_else(|| panic!("Cell {} does not exist", coordinate_from_index(&col, &row)))
+    }
+}
+impl<T> IndexMut<T> for Worksheet
+where
+    T: Into<CellCoordinates>,
+{
+    fn index_mut(&mut self, coordinate: T) -> &mut Cell {
+        self.get_cell_mut(coordinate)
+    }
+}
 impl AdjustmentCoordinate for Worksheet {
     fn adjustment_insert_coordinate(
         &mut self,
@@ -1620,6 +3470,26 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // form controls
+        for form_control in &mut self.form_controls {
+            form_control.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
+        // vml shapes
+        for shape in &mut self.vml_shapes {
+            shape.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // conditional styles
         for conditional_styles in &mut self.conditional_formatting_collection {
             conditional_styles.adjustment_insert_coordinate(
@@ -1649,6 +3519,14 @@ impl AdjustmentCoordinate for Worksheet {
                 offset_row_num,
             );
         };
+
+        // ignored errors
+        self.ignored_errors.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
     }
 
     fn adjustment_remove_coordinate(
@@ -1727,6 +3605,29 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // form controls
+        self.form_controls.retain(|x| {
+            !(x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num))
+        });
+        for form_control in &mut self.form_controls {
+            form_control.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
+        // vml shapes
+        for shape in &mut self.vml_shapes {
+            shape.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // conditional styles
         self.conditional_formatting_collection.retain(|x| {
             !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
@@ -1774,6 +3675,14 @@ impl AdjustmentCoordinate for Worksheet {
                 offset_row_num,
             );
         };
+
+        // ignored errors
+        self.ignored_errors.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
     }
 }
 impl AdjustmentCoordinateWithSheet for Worksheet {
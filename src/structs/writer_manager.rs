@@ -2,14 +2,20 @@ use helper::const_str::*;
 use quick_xml::Writer;
 use std::io;
 use std::io::Cursor;
+use structs::MediaObject;
 use structs::Spreadsheet;
 use structs::XlsxError;
+use structs::XlsxWriterOption;
 use writer::driver::*;
 pub struct WriterManager<W: io::Seek + io::Write> {
     files: Vec<String>,
     arv: zip::ZipWriter<W>,
     is_light: bool,
     table_no: i32,
+    option: XlsxWriterOption,
+    in_cell_images: Vec<MediaObject>,
+    value_metadata_count: u32,
+    bytes_written: u64,
 }
 
 impl<W: io::Seek + io::Write> WriterManager<W> {
@@ -19,9 +25,23 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
             arv,
             is_light: false,
             table_no: 0,
+            option: XlsxWriterOption::default(),
+            in_cell_images: Vec::new(),
+            value_metadata_count: 0,
+            bytes_written: 0,
         }
     }
 
+    pub fn set_option(&mut self, value: XlsxWriterOption) -> &mut Self {
+        self.is_light = *value.get_is_light();
+        self.option = value;
+        self
+    }
+
+    pub fn get_option(&self) -> &XlsxWriterOption {
+        &self.option
+    }
+
     pub fn set_is_light(&mut self, value: bool) -> &mut Self {
         self.is_light = value;
         self
@@ -40,20 +60,67 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         self.table_no
     }
 
+    /// (This method is crate only.)
+    /// Register an in-cell image and return its value metadata index (vm).
+    pub(crate) fn add_in_cell_image(&mut self, value: &MediaObject) -> u32 {
+        self.in_cell_images.push(value.clone());
+        self.get_in_cell_image_offset()
+    }
+
+    /// (This method is crate only.)
+    /// Value metadata index (vm) of the last in-cell image.
+    /// The images follow the value metadata read from the file.
+    pub(crate) fn get_in_cell_image_offset(&self) -> u32 {
+        self.value_metadata_count + self.in_cell_images.len() as u32
+    }
+
+    pub(crate) fn set_value_metadata_count(&mut self, value: u32) -> &mut Self {
+        self.value_metadata_count = value;
+        self
+    }
+
+    pub(crate) fn get_in_cell_images(&self) -> &Vec<MediaObject> {
+        &self.in_cell_images
+    }
+
+    /// (This method is crate only.)
+    /// Uncompressed bytes of the parts added so far.
+    pub(crate) fn get_bytes_written(&self) -> &u64 {
+        &self.bytes_written
+    }
+
     pub(crate) fn add_writer(
         &mut self,
         target: &str,
         writer: Writer<Cursor<Vec<u8>>>,
     ) -> Result<(), XlsxError> {
         if !self.check_file_exist(target) {
+            self.bytes_written += writer.get_ref().get_ref().len() as u64;
             make_file_from_writer(target, &mut self.arv, writer, None, &self.is_light)?;
             self.files.push(target.to_string());
         }
         Ok(())
     }
 
+    /// Adds a part whose content is written by `f` directly into the archive.
+    #[cfg(not(feature = "rayon"))]
+    pub(crate) fn add_stream<F>(&mut self, target: &str, f: F) -> Result<(), XlsxError>
+    where
+        F: FnOnce(&mut ByteCounter<zip::ZipWriter<W>>) -> io::Result<()>,
+    {
+        if !self.check_file_exist(target) {
+            start_file(target, &mut self.arv, None, &self.is_light)?;
+            let mut out = ByteCounter::new(&mut self.arv);
+            f(&mut out)?;
+            self.bytes_written += out.count;
+            self.files.push(target.to_string());
+        }
+        Ok(())
+    }
+
     pub(crate) fn add_bin(&mut self, target: &str, data: &[u8]) -> Result<(), XlsxError> {
         if !self.check_file_exist(target) {
+            self.bytes_written += data.len() as u64;
             make_file_from_bin(target, &mut self.arv, data, None, &self.is_light)?;
             self.files.push(target.to_string());
         }
@@ -119,6 +186,21 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         }
     }
 
+    pub(crate) fn add_file_at_ctrl_prop(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/ctrlProp{}.xml", PKG_CTRL_PROPS, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_chart(
         &mut self,
         writer: Writer<Cursor<Vec<u8>>>,
@@ -180,6 +262,18 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         return Ok(table_no);
     }
 
+    pub(crate) fn add_file_at_query_table(&mut self, writer: &[u8]) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{}/queryTable{}.xml", PKG_QUERY_TABLES, index);
+            if !self.check_file_exist(&file_path) {
+                self.add_bin(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn has_extension(&self, extension: &str) -> bool {
         let extension = format!(".{}", extension);
         self.files.iter().any(|file| file.ends_with(&extension))
@@ -198,9 +292,16 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
 
             // Override workbook
             if file.starts_with("/xl/workbook.xml") {
-                content_type = match spreadsheet.get_has_macros() {
-                    true => WORKBOOK_MACRO_TYPE,
-                    false => WORKBOOK_TYPE,
+                let is_macro = spreadsheet.get_has_macros() || self.option.is_macro_enabled;
+                let is_template = self
+                    .option
+                    .is_template
+                    .unwrap_or(*spreadsheet.get_is_template());
+                content_type = match (is_macro, is_template) {
+                    (true, true) => WORKBOOK_MACRO_TEMPLATE_TYPE,
+                    (true, false) => WORKBOOK_MACRO_TYPE,
+                    (false, true) => WORKBOOK_TEMPLATE_TYPE,
+                    (false, false) => WORKBOOK_TYPE,
                 };
             }
 
@@ -214,11 +315,47 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                 content_type = TABLE_TYPE;
             }
 
+            // Override queryTable
+            if file.starts_with("/xl/queryTables/queryTable") {
+                content_type = QUERY_TABLE_TYPE;
+            }
+
+            // Override connections
+            if file.starts_with("/xl/connections.xml") {
+                content_type = CONNECTIONS_TYPE;
+            }
+
+            // Override calcChain
+            if file.starts_with("/xl/calcChain.xml") {
+                content_type = CALC_CHAIN_TYPE;
+            }
+
             // Override comments
             if file.starts_with("/xl/comments") {
                 content_type = COMMENTS_TYPE;
             }
 
+            // Override ctrlProps
+            if file.starts_with("/xl/ctrlProps/ctrlProp") {
+                content_type = CTRL_PROP_TYPE;
+            }
+
+            // Override rich data
+            if file.starts_with("/xl/metadata.xml") {
+                content_type = SHEET_METADATA_TYPE;
+            }
+            if file.starts_with("/xl/richData/rdrichvaluestructure.xml") {
+                content_type = RD_RICH_VALUE_STRUCTURE_TYPE;
+            } else if file.starts_with("/xl/richData/rdrichvalue.xml") {
+                content_type = RD_RICH_VALUE_TYPE;
+            }
+            if file.starts_with("/xl/richData/rdRichValueTypes.xml") {
+                content_type = RD_RICH_VALUE_TYPES_TYPE;
+            }
+            if file.starts_with("/xl/richData/richValueRel.xml") {
+                content_type = RICH_VALUE_REL_TYPE;
+            }
+
             // Override theme
             if file.starts_with("/xl/theme/theme") {
                 content_type = THEME_TYPE;
@@ -234,9 +371,16 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                 content_type = SHARED_STRINGS_TYPE;
             }
 
-            // Override drawing
+            // Override drawing (or the user shapes of a chart kept as it was read)
             if file.starts_with("/xl/drawings/drawing") {
                 content_type = DRAWING_TYPE;
+                if spreadsheet
+                    .get_backup_context_types()
+                    .iter()
+                    .any(|(part_name, v)| part_name == &file && v == CHART_SHAPES_TYPE)
+                {
+                    content_type = CHART_SHAPES_TYPE;
+                }
             }
 
             // Override chart
@@ -278,6 +422,20 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
                 }
             }
 
+            // Override unknown extension (written as Default in the original file)
+            if content_type.is_empty() {
+                let extension = file.rsplit('.').next().unwrap_or("").to_lowercase();
+                if !DEFAULT_EXTENSIONS.contains(&extension.as_str()) {
+                    for (old_extension, old_content_type) in
+                        spreadsheet.get_backup_default_context_types()
+                    {
+                        if old_extension == &extension {
+                            content_type = old_content_type;
+                        }
+                    }
+                }
+            }
+
             if !content_type.is_empty() {
                 list.push((file, content_type.to_string()));
             }
@@ -285,3 +443,30 @@ impl<W: io::Seek + io::Write> WriterManager<W> {
         list
     }
 }
+
+/// Writer that counts the bytes written through it.
+#[cfg(not(feature = "rayon"))]
+pub(crate) struct ByteCounter<'a, T: io::Write> {
+    inner: &'a mut T,
+    count: u64,
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<'a, T: io::Write> ByteCounter<'a, T> {
+    fn new(inner: &'a mut T) -> Self {
+        Self { inner, count: 0 }
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<'a, T: io::Write> io::Write for ByteCounter<'a, T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let size = self.inner.write(buf)?;
+        self.count += size as u64;
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
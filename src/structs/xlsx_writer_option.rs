@@ -0,0 +1,172 @@
+use structs::CalcChainValues;
+use structs::CompatibilityValues;
+
+#[derive(Clone, Default, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct XlsxWriterOption {
+    pub(crate) is_light: bool,
+    pub(crate) image_resize_to_display: bool,
+    pub(crate) image_jpeg_quality: Option<u8>,
+    pub(crate) is_macro_enabled: bool,
+    pub(crate) is_template: Option<bool>,
+    pub(crate) is_dimension_omitted: bool,
+    pub(crate) calc_chain: CalcChainValues,
+    pub(crate) compatibility: CompatibilityValues,
+    pub(crate) is_text_sanitized: bool,
+}
+impl XlsxWriterOption {
+    /// Get Is Light.
+    /// When true, zip entries are stored without compression.
+    pub fn get_is_light(&self) -> &bool {
+        &self.is_light
+    }
+
+    /// Set Is Light.
+    /// # Arguments
+    /// * `value` - true: no compression. false: deflate.
+    pub fn set_is_light(&mut self, value: bool) -> &mut Self {
+        self.is_light = value;
+        self
+    }
+
+    /// Get Image Resize To Display.
+    pub fn get_image_resize_to_display(&self) -> &bool {
+        &self.image_resize_to_display
+    }
+
+    /// Set Image Resize To Display.
+    /// When true, embedded PNG/JPEG images larger than their displayed size
+    /// are downscaled to the display dimensions before being written.
+    /// # Arguments
+    /// * `value` - true: resize. false: keep original data.
+    pub fn set_image_resize_to_display(&mut self, value: bool) -> &mut Self {
+        self.image_resize_to_display = value;
+        self
+    }
+
+    /// Get Image Jpeg Quality.
+    pub fn get_image_jpeg_quality(&self) -> Option<&u8> {
+        self.image_jpeg_quality.as_ref()
+    }
+
+    /// Set Image Jpeg Quality.
+    /// Embedded JPEG images are re-encoded with this quality (1-100).
+    /// # Arguments
+    /// * `value` - Jpeg Quality.
+    pub fn set_image_jpeg_quality(&mut self, value: u8) -> &mut Self {
+        self.image_jpeg_quality = Some(value.clamp(1, 100));
+        self
+    }
+
+    /// Remove Image Jpeg Quality.
+    pub fn remove_image_jpeg_quality(&mut self) -> &mut Self {
+        self.image_jpeg_quality = None;
+        self
+    }
+
+    /// Get Is Macro Enabled.
+    pub fn get_is_macro_enabled(&self) -> &bool {
+        &self.is_macro_enabled
+    }
+
+    /// Set Is Macro Enabled.
+    /// When true, the workbook is written with the macro-enabled (xlsm) content type
+    /// even if it has no VBA project.
+    /// It is set automatically when the file is saved with the `.xlsm` extension.
+    /// # Arguments
+    /// * `value` - true: xlsm. false: decided by the VBA project.
+    pub fn set_is_macro_enabled(&mut self, value: bool) -> &mut Self {
+        self.is_macro_enabled = value;
+        self
+    }
+
+    /// Get Is Template.
+    pub fn get_is_template(&self) -> Option<&bool> {
+        self.is_template.as_ref()
+    }
+
+    /// Set Is Template.
+    /// When true, the workbook is written with the template (xltx/xltm) content type.
+    /// When not set, [`Spreadsheet::get_is_template`](crate::structs::Spreadsheet::get_is_template) is used.
+    /// It is set automatically from the extension when the file is saved to a path.
+    /// # Arguments
+    /// * `value` - true: template. false: workbook.
+    pub fn set_is_template(&mut self, value: bool) -> &mut Self {
+        self.is_template = Some(value);
+        self
+    }
+
+    /// Remove Is Template.
+    pub fn remove_is_template(&mut self) -> &mut Self {
+        self.is_template = None;
+        self
+    }
+
+    /// Get Is Dimension Omitted.
+    pub fn get_is_dimension_omitted(&self) -> &bool {
+        &self.is_dimension_omitted
+    }
+
+    /// Set Is Dimension Omitted.
+    /// By default, the used range of each worksheet is written as its `dimension`.
+    /// # Arguments
+    /// * `value` - true: no `dimension` element. false: write the used range.
+    pub fn set_is_dimension_omitted(&mut self, value: bool) -> &mut Self {
+        self.is_dimension_omitted = value;
+        self
+    }
+
+    /// Get Is Text Sanitized.
+    pub fn get_is_text_sanitized(&self) -> &bool {
+        &self.is_text_sanitized
+    }
+
+    /// Set Is Text Sanitized.
+    /// When true, the text cells are written without the control characters not allowed in xml,
+    /// as by `Spreadsheet::sanitize_text`. The spreadsheet itself is not changed.
+    /// # Arguments
+    /// * `value` - true: sanitize the text cells. false: write them as they are. (default)
+    pub fn set_is_text_sanitized(&mut self, value: bool) -> &mut Self {
+        self.is_text_sanitized = value;
+        self
+    }
+
+    /// Get Calc Chain.
+    pub fn get_calc_chain(&self) -> &CalcChainValues {
+        &self.calc_chain
+    }
+
+    /// Set Calc Chain.
+    /// Decides how the calculation chain (xl/calcChain.xml) is written.
+    /// The calculation chain of a read file is never kept, as it goes stale once formulas are edited.
+    /// * `Omit` - no calculation chain. (default)
+    /// * `Regenerate` - a calculation chain listing every formula cell.
+    /// * `ForceRecalculation` - no calculation chain, and Excel recalculates the workbook when it is opened.
+    /// # Arguments
+    /// * `value` - CalcChainValues.
+    pub fn set_calc_chain(&mut self, value: CalcChainValues) -> &mut Self {
+        self.calc_chain = value;
+        self
+    }
+
+    /// Get Compatibility.
+    pub fn get_compatibility(&self) -> &CompatibilityValues {
+        &self.compatibility
+    }
+
+    /// Set Compatibility.
+    /// Decides which extensions the styles part (xl/styles.xml) and the worksheets are written with.
+    /// * `Excel2016` - the markup Excel writes: mc:Ignorable, x14ac:knownFonts, x14ac:dyDescent, default tableStyles and the x14 slicer styles extension. (default)
+    /// * `Minimal` - plain SpreadsheetML only, for parsers that reject the extensions.
+    /// # Arguments
+    /// * `value` - CompatibilityValues.
+    pub fn set_compatibility(&mut self, value: CompatibilityValues) -> &mut Self {
+        self.compatibility = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Has any image compression setting.
+    pub(crate) fn has_image_compression(&self) -> bool {
+        self.image_resize_to_display || self.image_jpeg_quality.is_some()
+    }
+}
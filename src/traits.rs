@@ -11,3 +11,9 @@ pub use self::adjustment_coordinate_with_2sheet::*;
 
 mod adjustment_value;
 pub use self::adjustment_value::*;
+
+mod into_cell_value;
+pub use self::into_cell_value::*;
+
+mod into_serial_number;
+pub use self::into_serial_number::*;
@@ -0,0 +1,69 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use structs::CellRawValue;
+use traits::IntoSerialNumber;
+
+/// Conversion into a typed cell value.
+/// Strings are kept as text; numbers and booleans keep their type.
+/// Dates, times and durations become serial numbers.
+pub trait IntoCellValue {
+    fn into_cell_value(self) -> CellRawValue;
+}
+
+impl IntoCellValue for CellRawValue {
+    fn into_cell_value(self) -> CellRawValue {
+        self
+    }
+}
+
+impl IntoCellValue for String {
+    fn into_cell_value(self) -> CellRawValue {
+        CellRawValue::String(self)
+    }
+}
+
+impl IntoCellValue for &str {
+    fn into_cell_value(self) -> CellRawValue {
+        CellRawValue::String(self.to_string())
+    }
+}
+
+impl IntoCellValue for bool {
+    fn into_cell_value(self) -> CellRawValue {
+        CellRawValue::Bool(self)
+    }
+}
+
+impl<T: IntoCellValue> IntoCellValue for Option<T> {
+    fn into_cell_value(self) -> CellRawValue {
+        match self {
+            Some(v) => v.into_cell_value(),
+            None => CellRawValue::Empty,
+        }
+    }
+}
+
+macro_rules! impl_into_cell_value_for_number {
+    ($($t:ty),*) => {
+        $(
+            impl IntoCellValue for $t {
+                fn into_cell_value(self) -> CellRawValue {
+                    CellRawValue::Numeric(self as f64)
+                }
+            }
+        )*
+    };
+}
+impl_into_cell_value_for_number!(f64, f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_into_cell_value_for_serial_number {
+    ($($t:ty),*) => {
+        $(
+            impl IntoCellValue for $t {
+                fn into_cell_value(self) -> CellRawValue {
+                    CellRawValue::Numeric(self.into_serial_number())
+                }
+            }
+        )*
+    };
+}
+impl_into_cell_value_for_serial_number!(NaiveDate, NaiveDateTime, NaiveTime, Duration);
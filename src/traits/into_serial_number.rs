@@ -0,0 +1,67 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use helper::date::*;
+use structs::NumberingFormat;
+
+/// Conversion into an excel serial number.
+/// Dates become serial dates; times and durations become fractions of a day.
+pub trait IntoSerialNumber {
+    fn into_serial_number(self) -> f64;
+
+    /// Format code that displays the value.
+    fn get_format_code(&self) -> &'static str {
+        NumberingFormat::FORMAT_GENERAL
+    }
+}
+
+impl IntoSerialNumber for NaiveDate {
+    fn into_serial_number(self) -> f64 {
+        convert_naive_date(&self)
+    }
+
+    fn get_format_code(&self) -> &'static str {
+        NumberingFormat::FORMAT_DATE_YYYYMMDD
+    }
+}
+
+impl IntoSerialNumber for NaiveDateTime {
+    fn into_serial_number(self) -> f64 {
+        convert_naive_date_time(&self)
+    }
+
+    fn get_format_code(&self) -> &'static str {
+        "yyyy-mm-dd hh:mm:ss"
+    }
+}
+
+impl IntoSerialNumber for NaiveTime {
+    fn into_serial_number(self) -> f64 {
+        convert_naive_time(&self)
+    }
+
+    fn get_format_code(&self) -> &'static str {
+        NumberingFormat::FORMAT_DATE_TIME4
+    }
+}
+
+impl IntoSerialNumber for Duration {
+    fn into_serial_number(self) -> f64 {
+        convert_duration(&self)
+    }
+
+    fn get_format_code(&self) -> &'static str {
+        "[h]:mm:ss"
+    }
+}
+
+macro_rules! impl_into_serial_number_for_number {
+    ($($t:ty),*) => {
+        $(
+            impl IntoSerialNumber for $t {
+                fn into_serial_number(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+impl_into_serial_number_for_number!(f64, f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
@@ -3,8 +3,11 @@ use std::io;
 use std::path::Path;
 use std::string::FromUtf8Error;
 
+use helper::sanitize::sanitize_text;
 use std::fmt::Write;
+use structs::CellRawValue;
 use structs::CsvEncodeValues;
+use structs::CsvFormatValues;
 use structs::CsvWriterOption;
 use structs::Spreadsheet;
 use structs::XlsxError;
@@ -26,26 +29,83 @@ pub fn write_writer<W: io::Seek + io::Write>(
     // get max column and row.
     let (max_column, max_row) = worksheet.get_highest_column_and_row();
 
-    let mut data = String::from("");
+    let separator = match (option.get_csv_format_value(), option.get_locale()) {
+        (CsvFormatValues::Tsv, _) => String::from("\t"),
+        (CsvFormatValues::FixedWidth, _) => String::from(""),
+        (_, Some(v)) => v.get_list_separator().to_string(),
+        (_, None) => String::from(","),
+    };
+
+    let mut table: Vec<Vec<String>> = Vec::new();
     for row in 0u32..max_row {
         let mut row_vec: Vec<String> = Vec::new();
         for column in 0u32..max_column {
             // get value.
-            let mut value = match worksheet.get_cell((column + 1, row + 1)) {
-                Some(cell) => cell.get_cell_value().get_value().into(),
+            let cell = worksheet.get_cell((column + 1, row + 1));
+            let mut value = match cell {
+                Some(cell) => match (option.get_locale(), cell.get_cell_value().get_raw_value()) {
+                    (Some(locale), CellRawValue::Numeric(_)) => {
+                        locale.localize_number(&cell.get_cell_value().get_value())
+                    }
+                    _ => cell.get_cell_value().get_value().into(),
+                },
                 None => String::from(""),
             };
             // do trim.
             if *option.get_do_trim() {
                 value = value.trim().to_string();
             }
-            // wrap_with_char.
-            if option.get_wrap_with_char() != "" {
-                value = format! {"{}{}{}", option.get_wrap_with_char(), value, option.get_wrap_with_char()};
+            // do sanitize.
+            if *option.get_do_sanitize()
+                && cell.map_or(false, |v| {
+                    !v.is_formula()
+                        && matches!(
+                            v.get_raw_value(),
+                            CellRawValue::String(_) | CellRawValue::RichText(_)
+                        )
+                })
+            {
+                value = sanitize_text(&value).into_owned();
             }
             row_vec.push(value);
         }
-        write!(data, "{}", row_vec.join(",")).unwrap();
+        table.push(row_vec);
+    }
+
+    // column widths of fixed width output.
+    let mut widths: Vec<usize> = Vec::new();
+    if option.get_csv_format_value() == &CsvFormatValues::FixedWidth {
+        for column in 0..max_column as usize {
+            let width = match option.get_column_widths().get(column) {
+                Some(v) => *v,
+                None => table
+                    .iter()
+                    .map(|row_vec| row_vec[column].chars().count())
+                    .max()
+                    .unwrap_or(0),
+            };
+            widths.push(width);
+        }
+    }
+
+    let mut data = String::from("");
+    for mut row_vec in table {
+        for (column, value) in row_vec.iter_mut().enumerate() {
+            if option.get_csv_format_value() == &CsvFormatValues::FixedWidth {
+                // pad or cut to the column width.
+                let width = widths[column];
+                *value = format!("{:<width$}", value.chars().take(width).collect::<String>());
+            } else if option.get_csv_format_value() == &CsvFormatValues::Tsv
+                && value.contains(|c| matches!(c, '\t' | '\r' | '\n' | '"'))
+            {
+                // quote a value with a tab or a line break, doubling the quotes in it.
+                *value = format!("\"{}\"", value.replace('"', "\"\""));
+            } else if option.get_wrap_with_char() != "" {
+                // wrap_with_char.
+                *value = format! {"{}{}{}", option.get_wrap_with_char(), value, option.get_wrap_with_char()};
+            }
+        }
+        write!(data, "{}", row_vec.join(&separator)).unwrap();
         write!(data, "\r\n").unwrap();
     }
 
@@ -4,8 +4,8 @@ use std::borrow::Cow;
 use std::io;
 use std::io::{Cursor, Write};
 
-pub(crate) fn write_start_tag<'a, S>(
-    writer: &mut Writer<Cursor<Vec<u8>>>,
+pub(crate) fn write_start_tag<'a, S, W: io::Write>(
+    writer: &mut Writer<W>,
     tag_name: S,
     attributes: Vec<(&str, &str)>,
     empty_flag: bool,
@@ -24,28 +24,28 @@ pub(crate) fn write_start_tag<'a, S>(
     }
 }
 
-pub(crate) fn write_end_tag<'a, S>(writer: &mut Writer<Cursor<Vec<u8>>>, tag_name: S)
+pub(crate) fn write_end_tag<'a, S, W: io::Write>(writer: &mut Writer<W>, tag_name: S)
 where
     S: Into<Cow<'a, str>>,
 {
     writer.write_event(Event::End(BytesEnd::new(tag_name.into())));
 }
 
-pub(crate) fn write_text_node<'a, S>(writer: &mut Writer<Cursor<Vec<u8>>>, data: S)
+pub(crate) fn write_text_node<'a, S, W: io::Write>(writer: &mut Writer<W>, data: S)
 where
     S: Into<Cow<'a, str>>,
 {
     writer.write_event(Event::Text(BytesText::new(&data.into())));
 }
 
-pub(crate) fn write_text_node_no_escape<'a, S>(writer: &mut Writer<Cursor<Vec<u8>>>, data: S)
+pub(crate) fn write_text_node_no_escape<'a, S, W: io::Write>(writer: &mut Writer<W>, data: S)
 where
     S: Into<Cow<'a, str>>,
 {
     writer.get_mut().write(data.into().as_bytes());
 }
 
-pub(crate) fn write_new_line(writer: &mut Writer<Cursor<Vec<u8>>>) {
+pub(crate) fn write_new_line<W: io::Write>(writer: &mut Writer<W>) {
     write_text_node(writer, "\r\n");
 }
 
@@ -65,6 +65,16 @@ pub(crate) fn make_file_from_bin<W: io::Seek + io::Write>(
     writer: &[u8],
     dir: Option<&str>,
     is_light: &bool,
+) -> Result<(), io::Error> {
+    start_file(path, arv, dir, is_light)?;
+    arv.write_all(writer)
+}
+
+pub(crate) fn start_file<W: io::Seek + io::Write>(
+    path: &str,
+    arv: &mut zip::ZipWriter<W>,
+    dir: Option<&str>,
+    is_light: &bool,
 ) -> Result<(), io::Error> {
     let zip_opt = if *is_light {
         zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored)
@@ -72,7 +82,7 @@ pub(crate) fn make_file_from_bin<W: io::Seek + io::Write>(
         zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::DEFLATE)
     };
     arv.start_file(to_path(path, dir), zip_opt)?;
-    arv.write_all(writer)
+    Ok(())
 }
 
 pub(crate) fn to_path<'a>(path: &'a str, dir: Option<&'a str>) -> Cow<'a, str> {
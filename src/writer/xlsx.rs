@@ -1,5 +1,8 @@
 use super::driver;
+use crate::trace_event;
+use crate::trace_span;
 use crate::XlsxError;
+use helper::const_str::PKG_SHEET;
 use helper::crypt::*;
 use std::fmt;
 use std::fs;
@@ -8,12 +11,23 @@ use std::io;
 use std::io::Read;
 use std::path::Path;
 use std::string::FromUtf8Error;
+#[cfg(feature = "rayon")]
+use std::sync::{Arc, RwLock};
+use structs::CompatibilityValues;
+use structs::PartSize;
+use structs::Progress;
+use structs::SizeEstimate;
 use structs::Spreadsheet;
 use structs::WriterManager;
+use structs::XlsxWriterOption;
+#[cfg(feature = "rayon")]
+use structs::{SharedStringTable, Stylesheet};
 
+mod calc_chain;
 mod chart;
 mod comment;
 mod content_types;
+mod ctrl_prop;
 mod doc_props_app;
 mod doc_props_core;
 mod doc_props_custom;
@@ -23,6 +37,7 @@ mod embeddings;
 mod media;
 mod printer_settings;
 mod rels;
+mod rich_data;
 mod shared_strings;
 mod styles;
 mod table;
@@ -35,10 +50,93 @@ mod workbook_rels;
 mod worksheet;
 mod worksheet_rels;
 
-fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Vec<u8>, XlsxError> {
+#[cfg(feature = "rayon")]
+fn write_worksheets_parallel<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    shared_string_table: &Arc<RwLock<SharedStringTable>>,
+    stylesheet: &mut Stylesheet,
+    writer_manager: &mut WriterManager<W>,
+    progress: &mut dyn FnMut(&Progress),
+) -> Result<(), XlsxError> {
+    use rayon::prelude::*;
+
+    // Styles, shared strings and in-cell images are registered up front in
+    // sheet order, so the sheets can be serialized independently and the
+    // output is the same as a sequential write.
+    let worksheets = spreadsheet.get_sheet_collection_no_check();
+    let mut in_cell_image_list = Vec::new();
+    for worksheet in worksheets {
+        in_cell_image_list.push(match worksheet.is_deserialized() {
+            true => worksheet::prepare(worksheet, shared_string_table, stylesheet, writer_manager),
+            false => Vec::new(),
+        });
+    }
+
+    let has_macros = spreadsheet.get_has_macros();
+    let has_dimension = !writer_manager.get_option().get_is_dimension_omitted();
+    let is_minimal =
+        writer_manager.get_option().get_compatibility() == &CompatibilityValues::Minimal;
+    let stylesheet: &Stylesheet = stylesheet;
+    let data_list: Vec<Option<Vec<u8>>> = worksheets
+        .par_iter()
+        .enumerate()
+        .zip(in_cell_image_list)
+        .map(
+            |((index, worksheet), in_cell_images)| -> io::Result<Option<Vec<u8>>> {
+                if !worksheet.is_deserialized() {
+                    return Ok(None);
+                }
+                trace_span!("write_sheet", sheet = worksheet.get_name());
+                let mut stylesheet = stylesheet.clone();
+                let mut in_cell_images = in_cell_images.into_iter();
+                let mut data = Vec::new();
+                worksheet::write_xml(
+                    &mut data,
+                    worksheet,
+                    shared_string_table.clone(),
+                    &mut stylesheet,
+                    has_macros,
+                    has_dimension,
+                    is_minimal,
+                    index + 1,
+                    &mut |_| in_cell_images.next().unwrap(),
+                )?;
+                trace_event!(DEBUG, bytes = data.len(), "wrote sheet");
+                Ok(Some(data))
+            },
+        )
+        .collect::<Result<_, io::Error>>()?;
+
+    let mut worksheet_no = 1;
+    for (worksheet, data) in worksheets.iter().zip(data_list) {
+        match data {
+            Some(v) => worksheet::add_bin(&worksheet_no, &v, writer_manager)?,
+            None => worksheet
+                .get_raw_data_of_worksheet()
+                .write(&worksheet_no, writer_manager)?,
+        }
+        progress(&Progress::new(
+            worksheet_no as usize,
+            worksheets.len(),
+            *writer_manager.get_bytes_written(),
+        ));
+        worksheet_no += 1;
+    }
+    Ok(())
+}
+
+fn make_buffer(
+    spreadsheet: &Spreadsheet,
+    option: &XlsxWriterOption,
+    progress: &mut dyn FnMut(&Progress),
+) -> Result<std::vec::Vec<u8>, XlsxError> {
+    trace_span!("write_xlsx");
     let arv = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
     let mut writer_manager = WriterManager::new(arv);
-    writer_manager.set_is_light(is_light);
+    writer_manager.set_option(option.clone());
+    if let Some(metadata) = spreadsheet.get_metadata() {
+        writer_manager.set_value_metadata_count(metadata.get_value_metadata().len() as u32);
+    }
 
     // Add docProps App
     doc_props_app::write(spreadsheet, &mut writer_manager)?;
@@ -61,28 +159,57 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     // worksheet
     let shared_string_table = spreadsheet.get_shared_string_table();
     let mut stylesheet = spreadsheet.get_stylesheet().clone();
-    let mut worksheet_no = 1;
-    for worksheet in spreadsheet.get_sheet_collection_no_check() {
-        if worksheet.is_deserialized() {
-            // from deserialized.
-            worksheet::write(
-                &worksheet_no,
-                worksheet,
-                shared_string_table.clone(),
-                &mut stylesheet,
-                spreadsheet.get_has_macros(),
-                &mut writer_manager,
-            )?;
-        } else {
-            // from no deserialized.
-            worksheet
-                .get_raw_data_of_worksheet()
-                .write(&worksheet_no, &mut writer_manager)?;
+    let parts_total = spreadsheet.get_sheet_collection_no_check().len();
+    progress(&Progress::new(
+        0,
+        parts_total,
+        *writer_manager.get_bytes_written(),
+    ));
+    #[cfg(feature = "rayon")]
+    write_worksheets_parallel(
+        spreadsheet,
+        &shared_string_table,
+        &mut stylesheet,
+        &mut writer_manager,
+        progress,
+    )?;
+    #[cfg(not(feature = "rayon"))]
+    {
+        let mut worksheet_no = 1;
+        for worksheet in spreadsheet.get_sheet_collection_no_check() {
+            trace_span!("write_sheet", sheet = worksheet.get_name());
+            if worksheet.is_deserialized() {
+                // from deserialized.
+                worksheet::write(
+                    &worksheet_no,
+                    worksheet,
+                    shared_string_table.clone(),
+                    &mut stylesheet,
+                    spreadsheet.get_has_macros(),
+                    &mut writer_manager,
+                )?;
+            } else {
+                // from no deserialized.
+                worksheet
+                    .get_raw_data_of_worksheet()
+                    .write(&worksheet_no, &mut writer_manager)?;
+            }
+            trace_event!(
+                DEBUG,
+                bytes_written = *writer_manager.get_bytes_written(),
+                "wrote sheet"
+            );
+            progress(&Progress::new(
+                worksheet_no as usize,
+                parts_total,
+                *writer_manager.get_bytes_written(),
+            ));
+            worksheet_no += 1;
         }
-        worksheet_no += 1;
     }
 
     // Objects associated with worksheets
+    let display_size_list = media::get_display_size_list(spreadsheet, option);
     let mut worksheet_no = 0;
     for worksheet in spreadsheet.get_sheet_collection_no_check() {
         worksheet_no += 1;
@@ -93,7 +220,10 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         // from deserialized.
         // Add charts
         let mut chart_no_list: Vec<String> = Vec::new();
-        for chart in worksheet.get_worksheet_drawing().get_chart_collection() {
+        for chart in worksheet
+            .get_worksheet_drawing()
+            .get_chart_collection_in_z_order()
+        {
             let chart_space = chart.get_chart_space();
             let chart_no = chart::write(chart_space, spreadsheet, &mut writer_manager)?;
             chart_no_list.push(chart_no);
@@ -112,11 +242,22 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         )?;
 
         // Add vml drawing
-        let vml_drawing_no = vml_drawing::write(worksheet, &mut writer_manager)?;
+        let vml_drawing_no =
+            vml_drawing::write(worksheet, worksheet_no as usize, &mut writer_manager)?;
 
         // Add vml drawing rels
         vml_drawing_rels::write(worksheet, &vml_drawing_no, &mut writer_manager)?;
 
+        // Add vml drawing of header/footer
+        let vml_drawing_hf_no = vml_drawing::write_header_footer(
+            worksheet,
+            spreadsheet.get_sheet_count() + worksheet_no as usize,
+            &mut writer_manager,
+        )?;
+
+        // Add vml drawing rels of header/footer
+        vml_drawing_rels::write_header_footer(worksheet, &vml_drawing_hf_no, &mut writer_manager)?;
+
         // Add comment
         let comment_no = comment::write(worksheet, &mut writer_manager)?;
 
@@ -125,7 +266,7 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
             embeddings::write(worksheet, &mut writer_manager)?;
 
         // Add Media
-        media::write(worksheet, &mut writer_manager)?;
+        media::write(worksheet, &display_size_list, &mut writer_manager)?;
 
         // Add printer_settings
         let printer_settings_no = match worksheet.get_page_setup().get_object_data() {
@@ -136,17 +277,22 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
         // Add tables
         let table_no_list = table::write(worksheet, &mut writer_manager)?;
 
+        // Add form control properties
+        let ctrl_prop_no_list = ctrl_prop::write(worksheet, &mut writer_manager)?;
+
         // Add worksheet rels
         worksheet_rels::write(
             worksheet,
             &worksheet_no.to_string(),
             &drawing_no,
             &vml_drawing_no,
+            &vml_drawing_hf_no,
             &comment_no,
             &ole_object_no_list,
             &excel_no_list,
             &printer_settings_no,
             &table_no_list,
+            &ctrl_prop_no_list,
             &mut writer_manager,
         )?;
     }
@@ -160,6 +306,31 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     // Add Styles
     styles::write(&stylesheet, &mut writer_manager)?;
 
+    // Add calcChain
+    calc_chain::write(spreadsheet, &mut writer_manager)?;
+
+    // Add in-cell images (merged into the rich data read from the file)
+    rich_data::write(spreadsheet, &mut writer_manager)?;
+
+    // Add rich data (cell metadata and rich values)
+    for raw_part in spreadsheet.get_rich_data_parts() {
+        raw_part.write(&mut writer_manager)?;
+    }
+
+    // Add connections
+    if let Some(raw_part) = spreadsheet.get_connections_part() {
+        raw_part.write(&mut writer_manager)?;
+    }
+
+    // Add unknown parts
+    for raw_part in spreadsheet
+        .get_unknown_parts()
+        .iter()
+        .chain(spreadsheet.get_unknown_root_parts())
+    {
+        raw_part.write(&mut writer_manager)?;
+    }
+
     // Add workbook
     workbook::write(spreadsheet, &mut writer_manager)?;
 
@@ -170,23 +341,91 @@ fn make_buffer(spreadsheet: &Spreadsheet, is_light: bool) -> Result<std::vec::Ve
     // Add Content_Types
     content_types::write(spreadsheet, &mut writer_manager)?;
 
+    progress(&Progress::new(
+        parts_total,
+        parts_total,
+        *writer_manager.get_bytes_written(),
+    ));
+
     let result = writer_manager.get_arv_mut().finish()?;
+    trace_event!(
+        DEBUG,
+        sheets = parts_total,
+        bytes_written = *writer_manager.get_bytes_written(),
+        "wrote workbook"
+    );
     Ok(result.into_inner())
 }
 
+/// Get the sizes of the parts of the xlsx file without saving it.
+/// The workbook is written in memory, so runaway output (ex. exploded styles) can be found before writing it out.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// # Return value
+/// * `Result<SizeEstimate, XlsxError>` - OK is the sizes of the parts. Err is error message.
+/// # Examples
+/// ```
+/// let book = umya_spreadsheet::new_file();
+/// let estimate = umya_spreadsheet::writer::xlsx::estimate(&book).unwrap();
+/// let sheet_size = estimate.get_sheet("Sheet1").unwrap().get_size();
+/// println!("{} bytes, styles {} bytes", sheet_size, estimate.get_styles_size());
+/// ```
+pub fn estimate(spreadsheet: &Spreadsheet) -> Result<SizeEstimate, XlsxError> {
+    estimate_with_option(spreadsheet, &XlsxWriterOption::default())
+}
+
+/// Get the sizes of the parts of the xlsx file written with option, without saving it.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `option` - options.
+/// # Return value
+/// * `Result<SizeEstimate, XlsxError>` - OK is the sizes of the parts. Err is error message.
+pub fn estimate_with_option(
+    spreadsheet: &Spreadsheet,
+    option: &XlsxWriterOption,
+) -> Result<SizeEstimate, XlsxError> {
+    // writing updates the shared strings, so estimate against a copy of them.
+    let mut spreadsheet = spreadsheet.clone();
+    let shared_string_table = spreadsheet
+        .get_shared_string_table()
+        .read()
+        .unwrap()
+        .clone();
+    spreadsheet.set_shared_string_table(shared_string_table);
+    let buffer = make_buffer(&spreadsheet, option, &mut |_| {})?;
+    let file_size = buffer.len() as u64;
+    let mut arv = zip::read::ZipArchive::new(std::io::Cursor::new(buffer))?;
+    let mut parts: Vec<PartSize> = Vec::new();
+    for index in 0..arv.len() {
+        let file = arv.by_index(index)?;
+        parts.push(PartSize::new(
+            file.name(),
+            file.size(),
+            file.compressed_size(),
+        ));
+    }
+    let sheet_paths = spreadsheet
+        .get_sheet_collection_no_check()
+        .iter()
+        .enumerate()
+        .map(|(index, worksheet)| {
+            (
+                worksheet.get_name().to_string(),
+                format!("{PKG_SHEET}{}.xml", index + 1),
+            )
+        })
+        .collect();
+    Ok(SizeEstimate::new(parts, sheet_paths, file_size))
+}
+
 /// write spreadsheet file to arbitrary writer.
 /// # Arguments
 /// * `spreadsheet` - Spreadsheet structs object.
 /// * `writer` - writer to write to.
 /// # Return value
 /// * `Result` - OK is void. Err is error message.
-pub fn write_writer<W: io::Write>(
-    spreadsheet: &Spreadsheet,
-    mut writer: W,
-) -> Result<(), XlsxError> {
-    let buffer = make_buffer(spreadsheet, false)?;
-    writer.write_all(&buffer)?;
-    Ok(())
+pub fn write_writer<W: io::Write>(spreadsheet: &Spreadsheet, writer: W) -> Result<(), XlsxError> {
+    write_writer_with_option(spreadsheet, writer, &XlsxWriterOption::default())
 }
 
 /// write spreadsheet file to arbitrary writer.
@@ -197,9 +436,47 @@ pub fn write_writer<W: io::Write>(
 /// * `Result` - OK is void. Err is error message.
 pub fn write_writer_light<W: io::Write>(
     spreadsheet: &Spreadsheet,
-    mut writer: W,
+    writer: W,
 ) -> Result<(), XlsxError> {
-    let buffer = make_buffer(spreadsheet, true)?;
+    let mut option = XlsxWriterOption::default();
+    option.set_is_light(true);
+    write_writer_with_option(spreadsheet, writer, &option)
+}
+
+/// write spreadsheet file to arbitrary writer with option.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer to write to.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn write_writer_with_option<W: io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer: W,
+    option: &XlsxWriterOption,
+) -> Result<(), XlsxError> {
+    write_writer_with_progress(spreadsheet, writer, option, |_| {})
+}
+
+/// write spreadsheet file to arbitrary writer, reporting the progress.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `writer` - writer to write to.
+/// * `option` - options.
+/// * `progress` - called before the first worksheet, after each worksheet and when all parts are added.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+pub fn write_writer_with_progress<W, F>(
+    spreadsheet: &Spreadsheet,
+    mut writer: W,
+    option: &XlsxWriterOption,
+    mut progress: F,
+) -> Result<(), XlsxError>
+where
+    W: io::Write,
+    F: FnMut(&Progress),
+{
+    let buffer = make_buffer(spreadsheet, option, &mut progress)?;
     writer.write_all(&buffer)?;
     Ok(())
 }
@@ -217,19 +494,7 @@ pub fn write_writer_light<W: io::Write>(
 /// let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
 /// ```
 pub fn write<P: AsRef<Path>>(spreadsheet: &Spreadsheet, path: P) -> Result<(), XlsxError> {
-    let extension = path.as_ref().extension().unwrap().to_str().unwrap();
-    let path_tmp = path
-        .as_ref()
-        .with_extension(format!("{}{}", extension, "tmp"));
-    if let Err(v) = write_writer(
-        spreadsheet,
-        &mut io::BufWriter::new(fs::File::create(&path_tmp)?),
-    ) {
-        fs::remove_file(path_tmp)?;
-        return Err(v);
-    }
-    fs::rename(path_tmp, path)?;
-    Ok(())
+    write_with_option(spreadsheet, path, &XlsxWriterOption::default())
 }
 
 /// write spreadsheet file.
@@ -245,13 +510,81 @@ pub fn write<P: AsRef<Path>>(spreadsheet: &Spreadsheet, path: P) -> Result<(), X
 /// let _ = umya_spreadsheet::writer::xlsx::write_light(&book, path);
 /// ```
 pub fn write_light<P: AsRef<Path>>(spreadsheet: &Spreadsheet, path: P) -> Result<(), XlsxError> {
+    let mut option = XlsxWriterOption::default();
+    option.set_is_light(true);
+    write_with_option(spreadsheet, path, &option)
+}
+
+/// write spreadsheet file with option.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// * `option` - options.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let mut option = umya_spreadsheet::structs::XlsxWriterOption::default();
+/// option.set_image_resize_to_display(true);
+/// option.set_image_jpeg_quality(75);
+/// let path = std::path::Path::new("./tests/result_files/zzz.xlsx");
+/// let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option);
+/// ```
+pub fn write_with_option<P: AsRef<Path>>(
+    spreadsheet: &Spreadsheet,
+    path: P,
+    option: &XlsxWriterOption,
+) -> Result<(), XlsxError> {
+    write_with_progress(spreadsheet, path, option, |_| {})
+}
+
+/// write spreadsheet file with option, reporting the progress.
+/// # Arguments
+/// * `spreadsheet` - Spreadsheet structs object.
+/// * `path` - file path to save.
+/// * `option` - options.
+/// * `progress` - called before the first worksheet, after each worksheet and when all parts are added.
+/// # Return value
+/// * `Result` - OK is void. Err is error message.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let option = umya_spreadsheet::structs::XlsxWriterOption::default();
+/// let path = std::path::Path::new("./tests/result_files/zzz_progress.xlsx");
+/// let _ = umya_spreadsheet::writer::xlsx::write_with_progress(&book, path, &option, |progress| {
+///     println!("{} bytes", progress.get_bytes());
+/// });
+/// ```
+pub fn write_with_progress<P, F>(
+    spreadsheet: &Spreadsheet,
+    path: P,
+    option: &XlsxWriterOption,
+    progress: F,
+) -> Result<(), XlsxError>
+where
+    P: AsRef<Path>,
+    F: FnMut(&Progress),
+{
     let extension = path.as_ref().extension().unwrap().to_str().unwrap();
     let path_tmp = path
         .as_ref()
         .with_extension(format!("{}{}", extension, "tmp"));
-    if let Err(v) = write_writer_light(
+    let mut option = option.clone();
+    if extension.eq_ignore_ascii_case("xlsm") || extension.eq_ignore_ascii_case("xltm") {
+        option.set_is_macro_enabled(true);
+    }
+    if extension.eq_ignore_ascii_case("xltx") || extension.eq_ignore_ascii_case("xltm") {
+        option.set_is_template(true);
+    }
+    if extension.eq_ignore_ascii_case("xlsx") || extension.eq_ignore_ascii_case("xlsm") {
+        option.set_is_template(false);
+    }
+    if let Err(v) = write_writer_with_progress(
         spreadsheet,
         &mut io::BufWriter::new(fs::File::create(&path_tmp)?),
+        &option,
+        progress,
     ) {
         fs::remove_file(path_tmp)?;
         return Err(v);
@@ -282,7 +615,7 @@ pub fn write_with_password<P: AsRef<Path>>(
     let path_tmp = path
         .as_ref()
         .with_extension(format!("{}{}", extension, "tmp"));
-    let buffer = match make_buffer(spreadsheet, false) {
+    let buffer = match make_buffer(spreadsheet, &XlsxWriterOption::default(), &mut |_| {}) {
         Ok(v) => v,
         Err(v) => {
             fs::remove_file(path_tmp)?;
@@ -319,7 +652,9 @@ pub fn write_with_password_light<P: AsRef<Path>>(
     let path_tmp = path
         .as_ref()
         .with_extension(format!("{}{}", extension, "tmp"));
-    let buffer = match make_buffer(spreadsheet, true) {
+    let mut option = XlsxWriterOption::default();
+    option.set_is_light(true);
+    let buffer = match make_buffer(spreadsheet, &option, &mut |_| {}) {
         Ok(v) => v,
         Err(v) => {
             fs::remove_file(path_tmp)?;
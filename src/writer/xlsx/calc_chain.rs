@@ -0,0 +1,82 @@
+use super::driver::*;
+use super::XlsxError;
+use helper::const_str::*;
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use reader::xlsx::worksheet::read_lite;
+use std::io;
+use structs::CalcChainValues;
+use structs::Spreadsheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    if writer_mng.get_option().get_calc_chain() != &CalcChainValues::Regenerate {
+        return Ok(());
+    }
+
+    // formula cells by sheet index
+    let mut cells: Vec<(usize, String)> = Vec::new();
+    for (index, worksheet) in spreadsheet
+        .get_sheet_collection_no_check()
+        .iter()
+        .enumerate()
+    {
+        // the cells of a sheet that was not read are taken from its raw data.
+        let lazy_cells = match worksheet.is_deserialized() {
+            true => None,
+            false => Some(read_lite(
+                worksheet.get_raw_data_of_worksheet(),
+                &spreadsheet.get_shared_string_table().read().unwrap(),
+                spreadsheet.get_stylesheet(),
+            )?),
+        };
+        let cell_list = match &lazy_cells {
+            Some(v) => v.get_collection_sorted(),
+            None => worksheet.get_cell_collection_sorted(),
+        };
+        for cell in cell_list {
+            if cell.get_cell_value().is_formula() {
+                cells.push((index + 1, cell.get_coordinate().to_string()));
+            }
+        }
+    }
+    if cells.is_empty() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // calcChain
+    write_start_tag(
+        &mut writer,
+        "calcChain",
+        vec![("xmlns", SHEET_MAIN_NS)],
+        false,
+    );
+
+    // c
+    let mut current_index = 0;
+    for (index, coordinate) in &cells {
+        let index_str = index.to_string();
+        let mut attributes: Vec<(&str, &str)> = vec![("r", coordinate.as_str())];
+        if index != &current_index {
+            attributes.push(("i", &index_str));
+            current_index = *index;
+        }
+        write_start_tag(&mut writer, "c", attributes, true);
+    }
+
+    write_end_tag(&mut writer, "calcChain");
+
+    writer_mng.add_writer(PKG_CALC_CHAIN, writer)
+}
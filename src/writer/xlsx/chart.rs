@@ -1,5 +1,6 @@
 use super::driver::*;
 use super::XlsxError;
+use helper::const_str::*;
 use quick_xml::events::{BytesDecl, Event};
 use quick_xml::Writer;
 use std::io;
@@ -12,6 +13,21 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     spreadsheet: &Spreadsheet,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<String, XlsxError> {
+    // Unsupported chart types are written back as they were read.
+    if let Some(v) = chart_space.get_raw_data() {
+        let writer = Writer::new(io::Cursor::new(v.get_file_data().clone()));
+        let file_no = writer_mng.add_file_at_chart(writer)?;
+        // the parts it refers to keep their paths, so the relative targets are still valid.
+        let rels_target = format!("{}/_rels/chart{}.xml.rels", PKG_CHARTS, file_no);
+        for (index, relationships) in chart_space.get_raw_relationships().iter().enumerate() {
+            match index {
+                0 => relationships.write_to(writer_mng, Some(&rels_target))?,
+                _ => relationships.write_to(writer_mng, None)?,
+            }
+        }
+        return Ok(file_no.to_string());
+    }
+
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
     // XML header
     writer.write_event(Event::Decl(BytesDecl::new(
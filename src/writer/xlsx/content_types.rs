@@ -91,6 +91,26 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
     }
 
+    // Default gif
+    if writer_mng.has_extension("gif") {
+        write_start_tag(
+            &mut writer,
+            "Default",
+            vec![("Extension", "gif"), ("ContentType", "image/gif")],
+            true,
+        );
+    }
+
+    // Default bmp
+    if writer_mng.has_extension("bmp") {
+        write_start_tag(
+            &mut writer,
+            "Default",
+            vec![("Extension", "bmp"), ("ContentType", "image/bmp")],
+            true,
+        );
+    }
+
     // Default tiff
     if writer_mng.has_extension("tiff") {
         write_start_tag(
@@ -0,0 +1,32 @@
+use quick_xml::events::{BytesDecl, Event};
+use quick_xml::Writer;
+use std::io;
+
+use super::driver::*;
+use super::XlsxError;
+use structs::Worksheet;
+use structs::WriterManager;
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<Vec<String>, XlsxError> {
+    let mut ctrl_prop_no_list: Vec<String> = Vec::new();
+    for form_control in worksheet.get_form_controls() {
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+        // XML header
+        writer.write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )));
+        write_new_line(&mut writer);
+
+        // formControlPr
+        form_control.write_control_properties(&mut writer);
+
+        let file_no = writer_mng.add_file_at_ctrl_prop(writer)?;
+        ctrl_prop_no_list.push(file_no.to_string());
+    }
+    Ok(ctrl_prop_no_list)
+}
@@ -29,20 +29,20 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     // relationships
     write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
 
-    let mut r_id = 1;
-    for chart_no in chart_no_list {
-        is_write = write_relationship(
-            &mut writer,
-            &r_id,
-            CHART_NS,
-            format!("../charts/chart{}.xml", chart_no).as_str(),
-            "",
-        );
-        r_id += 1;
-    }
-
+    let mut chart_no_iter = chart_no_list.iter();
     let mut r_id = 1;
     for (key, value) in rel_list {
+        if key == "CHART" {
+            if let Some(chart_no) = chart_no_iter.next() {
+                is_write = write_relationship(
+                    &mut writer,
+                    &r_id,
+                    CHART_NS,
+                    format!("../charts/chart{}.xml", chart_no).as_str(),
+                    "",
+                );
+            }
+        }
         if key == "IMAGE" {
             is_write = write_relationship(
                 &mut writer,
@@ -52,6 +52,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
                 "",
             );
         }
+        if key == "HYPERLINK" {
+            is_write = write_relationship(&mut writer, &r_id, HYPERLINK_NS, value, "External");
+        }
         r_id += 1;
     }
     write_end_tag(&mut writer, "Relationships");
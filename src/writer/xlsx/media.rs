@@ -1,17 +1,134 @@
 use std::io;
+use std::io::Cursor;
 
 use super::XlsxError;
+use hashbrown::HashMap;
 use helper::const_str::*;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use image::ImageFormat;
+use structs::Spreadsheet;
 use structs::Worksheet;
 use structs::WriterManager;
+use structs::XlsxWriterOption;
 
 pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
+    display_size_list: &HashMap<String, (u32, u32)>,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
+    let option = writer_mng.get_option().clone();
     for image in worksheet.get_media_object_collection() {
         let file_name = format!("{PKG_MEDIA}/{}", image.get_image_name());
-        writer_mng.add_bin(&file_name, image.get_image_data())?;
+        if writer_mng.check_file_exist(&file_name) {
+            continue;
+        }
+        let compressed = match option.has_image_compression() {
+            true => compress_image(
+                image.get_image_data(),
+                display_size_list.get(image.get_image_name()),
+                &option,
+            ),
+            false => None,
+        };
+        match compressed {
+            Some(v) => writer_mng.add_bin(&file_name, &v)?,
+            None => writer_mng.add_bin(&file_name, image.get_image_data())?,
+        }
     }
     Ok(())
 }
+
+// Largest displayed size (pixel) of each image name in the workbook.
+// A media file is written once, so an image shown on several sheets is kept at its largest size.
+pub(crate) fn get_display_size_list(
+    spreadsheet: &Spreadsheet,
+    option: &XlsxWriterOption,
+) -> HashMap<String, (u32, u32)> {
+    let mut result: HashMap<String, (u32, u32)> = HashMap::new();
+    if !option.has_image_compression() {
+        return result;
+    }
+    for image in spreadsheet
+        .get_sheet_collection_no_check()
+        .iter()
+        .flat_map(|worksheet| worksheet.get_image_collection())
+    {
+        let mut size: Option<(i64, i64)> = None;
+        if let Some(anchor) = image.get_one_cell_anchor() {
+            size = Some((*anchor.get_extent().get_cx(), *anchor.get_extent().get_cy()));
+        }
+        if let Some(anchor) = image.get_two_cell_anchor() {
+            if let Some(picture) = anchor.get_picture() {
+                if let Some(transform) = picture.get_shape_properties().get_transform2d() {
+                    size = Some((
+                        *transform.get_extents().get_cx(),
+                        *transform.get_extents().get_cy(),
+                    ));
+                }
+            }
+        }
+        let (cx, cy) = match size {
+            Some(v) => v,
+            None => continue,
+        };
+        let width = (cx / 9525).max(0) as u32;
+        let height = (cy / 9525).max(0) as u32;
+        for media_object in image.get_media_object() {
+            let entry = result
+                .entry(media_object.get_image_name().to_string())
+                .or_insert((0, 0));
+            entry.0 = entry.0.max(width);
+            entry.1 = entry.1.max(height);
+        }
+    }
+    result
+}
+
+// Re-encode the image data. Returns None when the original data should be kept.
+fn compress_image(
+    data: &[u8],
+    display_size: Option<&(u32, u32)>,
+    option: &XlsxWriterOption,
+) -> Option<Vec<u8>> {
+    let format = image::guess_format(data).ok()?;
+    if format != ImageFormat::Png && format != ImageFormat::Jpeg {
+        return None;
+    }
+    let mut img = image::load_from_memory_with_format(data, format).ok()?;
+
+    let mut is_changed = false;
+    if *option.get_image_resize_to_display() {
+        if let Some((width, height)) = display_size {
+            if *width > 0 && *height > 0 && (img.width() > *width || img.height() > *height) {
+                img = img.resize(*width, *height, FilterType::Lanczos3);
+                is_changed = true;
+            }
+        }
+    }
+
+    let mut buf = Cursor::new(Vec::new());
+    if format == ImageFormat::Jpeg {
+        let quality = match option.get_image_jpeg_quality() {
+            Some(v) => *v,
+            None if is_changed => 90,
+            None => return None,
+        };
+        let encoder = JpegEncoder::new_with_quality(&mut buf, quality);
+        DynamicImage::ImageRgb8(img.to_rgb8())
+            .write_with_encoder(encoder)
+            .ok()?;
+    } else {
+        if !is_changed {
+            return None;
+        }
+        img.write_to(&mut buf, ImageFormat::Png).ok()?;
+    }
+
+    let result = buf.into_inner();
+    if result.len() >= data.len() {
+        return None;
+    }
+    Some(result)
+}
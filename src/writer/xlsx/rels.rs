@@ -55,6 +55,19 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
     }
 
+    // unknown parts (thumbnail, custom UI, ...)
+    let mut index = 6;
+    for raw_part in spreadsheet.get_unknown_root_parts() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            raw_part.get_type(),
+            raw_part.get_root_target(),
+            "",
+        );
+        index += 1;
+    }
+
     write_end_tag(&mut writer, "Relationships");
 
     let target = "_rels/.rels";
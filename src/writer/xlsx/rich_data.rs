@@ -0,0 +1,589 @@
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, Event};
+use quick_xml::Reader;
+use quick_xml::Writer;
+use std::io;
+
+use super::driver::*;
+use super::XlsxError;
+use helper::const_str::*;
+use reader::driver::get_attribute;
+use structs::raw::RawPart;
+use structs::MediaObject;
+use structs::Spreadsheet;
+use structs::WriterManager;
+
+/// Write the rich values of the in-cell images.
+/// When the workbook already has rich data (or cell metadata), the images are appended to it.
+pub(crate) fn write<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let images = writer_mng.get_in_cell_images().clone();
+    if images.is_empty() {
+        return Ok(());
+    }
+    let value_metadata_count = spreadsheet
+        .get_metadata()
+        .map_or(0, |v| v.get_value_metadata().len());
+
+    // xl/richData/richValueRel.xml
+    let rel_offset = write_rich_value_rel(spreadsheet, &images, writer_mng)?;
+
+    // xl/richData/rdrichvaluestructure.xml
+    let (target, data) = get_part_data(
+        spreadsheet,
+        RD_RICH_VALUE_STRUCTURE_NS,
+        PKG_RD_RICH_VALUE_STRUCTURE,
+        &format!(
+            r#"<rvStructures xmlns="{}" count="0"></rvStructures>"#,
+            RICH_DATA_NS
+        ),
+    );
+    let structure_index = count_children(&data, b"rvStructures", b"s")?;
+    let writer = copy_part(&data, |writer, ev| {
+        append_children(
+            writer,
+            ev,
+            b"rvStructures",
+            Some(structure_index + 1),
+            |writer| {
+                write_start_tag(writer, "s", vec![("t", "_localImage")], false);
+                write_start_tag(
+                    writer,
+                    "k",
+                    vec![("n", "_rvRel:LocalImageIdentifier"), ("t", "i")],
+                    true,
+                );
+                write_start_tag(writer, "k", vec![("n", "CalcOrigin"), ("t", "i")], true);
+                write_end_tag(writer, "s");
+            },
+        )
+    })?;
+    writer_mng.add_writer(&target, writer)?;
+
+    // xl/richData/rdrichvalue.xml
+    let (target, data) = get_part_data(
+        spreadsheet,
+        RD_RICH_VALUE_NS,
+        PKG_RD_RICH_VALUE,
+        &format!(r#"<rvData xmlns="{}" count="0"></rvData>"#, RICH_DATA_NS),
+    );
+    let value_offset = count_children(&data, b"rvData", b"rv")?;
+    let structure_index = structure_index.to_string();
+    let writer = copy_part(&data, |writer, ev| {
+        append_children(
+            writer,
+            ev,
+            b"rvData",
+            Some(value_offset + images.len()),
+            |writer| {
+                for i in 0..images.len() {
+                    write_start_tag(writer, "rv", vec![("s", &structure_index)], false);
+                    write_start_tag(writer, "v", vec![], false);
+                    write_text_node(writer, (rel_offset + i).to_string());
+                    write_end_tag(writer, "v");
+                    write_start_tag(writer, "v", vec![], false);
+                    write_text_node(writer, "5");
+                    write_end_tag(writer, "v");
+                    write_end_tag(writer, "rv");
+                }
+            },
+        )
+    })?;
+    writer_mng.add_writer(&target, writer)?;
+
+    // xl/richData/rdRichValueTypes.xml (kept as read when the workbook has one)
+    if find_part(spreadsheet, RD_RICH_VALUE_TYPES_NS).is_none() {
+        let mut writer = new_writer();
+        write_start_tag(
+            &mut writer,
+            "rvTypesInfo",
+            vec![
+                ("xmlns", RICH_DATA2_NS),
+                ("xmlns:mc", MC_NS),
+                ("mc:Ignorable", "x"),
+                ("xmlns:x", SHEET_MAIN_NS),
+            ],
+            false,
+        );
+        write_start_tag(&mut writer, "global", vec![], false);
+        write_start_tag(&mut writer, "keyFlags", vec![], false);
+        for (key, flags) in [
+            (
+                "_Self",
+                vec!["ExcludeFromFile", "ExcludeFromCalcComparison"],
+            ),
+            ("_DisplayString", vec!["ExcludeFromCalcComparison"]),
+            ("_Flags", vec!["ExcludeFromCalcComparison"]),
+            ("_Format", vec!["ExcludeFromCalcComparison"]),
+            ("_SubLabel", vec!["ExcludeFromCalcComparison"]),
+            ("_Attribution", vec!["ExcludeFromCalcComparison"]),
+            ("_Icon", vec!["ExcludeFromCalcComparison"]),
+            ("_Display", vec!["ExcludeFromCalcComparison"]),
+            ("_CanonicalPropertyNames", vec!["ExcludeFromCalcComparison"]),
+            ("_ClassificationId", vec!["ExcludeFromCalcComparison"]),
+        ] {
+            write_start_tag(&mut writer, "key", vec![("name", key)], false);
+            for flag in flags {
+                write_start_tag(
+                    &mut writer,
+                    "flag",
+                    vec![("name", flag), ("value", "1")],
+                    true,
+                );
+            }
+            write_end_tag(&mut writer, "key");
+        }
+        write_end_tag(&mut writer, "keyFlags");
+        write_end_tag(&mut writer, "global");
+        write_end_tag(&mut writer, "rvTypesInfo");
+        writer_mng.add_writer(PKG_RD_RICH_VALUE_TYPES, writer)?;
+    }
+
+    // xl/metadata.xml
+    write_metadata(
+        spreadsheet,
+        images.len(),
+        value_offset,
+        value_metadata_count,
+        writer_mng,
+    )?;
+
+    // xl/media
+    for image in &images {
+        let target = format!("{}/{}", PKG_MEDIA, image.get_image_name());
+        writer_mng.add_bin(&target, image.get_image_data())?;
+    }
+
+    Ok(())
+}
+
+/// Write the relationships to the images.
+/// Returns the index of the first image in the list.
+fn write_rich_value_rel<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    images: &[MediaObject],
+    writer_mng: &mut WriterManager<W>,
+) -> Result<usize, XlsxError> {
+    let (target, data) = get_part_data(
+        spreadsheet,
+        RICH_VALUE_REL_NS,
+        PKG_RICH_VALUE_REL,
+        &format!(
+            r#"<richValueRels xmlns="{}" xmlns:r="{}"></richValueRels>"#,
+            RICH_VALUE_REL_MAIN_NS, REL_OFC_NS
+        ),
+    );
+    let rel_offset = count_children(&data, b"richValueRels", b"rel")?;
+
+    // xl/richData/_rels/richValueRel.xml.rels
+    let (rels_target, relationship_list) = match find_part(spreadsheet, RICH_VALUE_REL_NS) {
+        Some(raw_part) => {
+            let file = raw_part.get_part_file();
+            let rels_target = format!("{}/{}", file.get_path(), file.make_rel_name());
+            let relationship_list = raw_part
+                .get_relationships_list()
+                .iter()
+                .find(|v| v.get_file_target() == rels_target)
+                .map(|v| v.get_relationship_list().as_slice())
+                .unwrap_or_default();
+            (rels_target, relationship_list)
+        }
+        None => (PKG_RICH_VALUE_REL_RELS.to_string(), Default::default()),
+    };
+    let mut r_id_list: Vec<String> = Vec::new();
+    let mut index = relationship_list.len();
+    while r_id_list.len() < images.len() {
+        index += 1;
+        let r_id = format!("rId{}", index);
+        if !relationship_list.iter().any(|v| v.get_id() == r_id) {
+            r_id_list.push(r_id);
+        }
+    }
+    let mut writer = new_writer();
+    write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
+    for relationship in relationship_list {
+        relationship.write_to(&mut writer);
+    }
+    for (r_id, image) in r_id_list.iter().zip(images) {
+        let target = format!("../media/{}", image.get_image_name());
+        write_start_tag(
+            &mut writer,
+            "Relationship",
+            vec![("Id", r_id), ("Type", IMAGE_NS), ("Target", &target)],
+            true,
+        );
+    }
+    write_end_tag(&mut writer, "Relationships");
+    writer_mng.add_writer(&rels_target, writer)?;
+
+    let writer = copy_part(&data, |writer, ev| {
+        append_children(writer, ev, b"richValueRels", None, |writer| {
+            for r_id in &r_id_list {
+                write_start_tag(
+                    writer,
+                    "rel",
+                    vec![("xmlns:r", REL_OFC_NS), ("r:id", r_id)],
+                    true,
+                );
+            }
+        })
+    })?;
+    writer_mng.add_writer(&target, writer)?;
+    Ok(rel_offset)
+}
+
+/// Add the XLRICHVALUE metadata of the images to xl/metadata.xml.
+fn write_metadata<W: io::Seek + io::Write>(
+    spreadsheet: &Spreadsheet,
+    image_count: usize,
+    value_offset: usize,
+    value_metadata_count: usize,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let (target, data) = get_part_data(
+        spreadsheet,
+        SHEET_METADATA_NS,
+        PKG_METADATA,
+        &format!(r#"<metadata xmlns="{}"></metadata>"#, SHEET_MAIN_NS),
+    );
+    let metadata_types: Vec<String> = spreadsheet
+        .get_metadata()
+        .map(|v| v.get_metadata_types().clone())
+        .unwrap_or_default();
+    let has_type = metadata_types.iter().any(|v| v == "XLRICHVALUE");
+    let type_index = match metadata_types.iter().position(|v| v == "XLRICHVALUE") {
+        Some(v) => v + 1,
+        None => metadata_types.len() + 1,
+    };
+    let future_blocks = count_rich_value_blocks(&data)?;
+    let future_offset = future_blocks.unwrap_or(0);
+    let type_index = type_index.to_string();
+
+    let write_type = |writer: &mut Writer<io::Cursor<Vec<u8>>>| {
+        write_start_tag(
+            writer,
+            "metadataType",
+            vec![
+                ("name", "XLRICHVALUE"),
+                ("minSupportedVersion", "120000"),
+                ("copy", "1"),
+                ("pasteAll", "1"),
+                ("pasteValues", "1"),
+                ("merge", "1"),
+                ("splitFirst", "1"),
+                ("rowColShift", "1"),
+                ("clearFormats", "1"),
+                ("clearComments", "1"),
+                ("assign", "1"),
+                ("coerce", "1"),
+            ],
+            true,
+        );
+    };
+    let write_future_blocks = |writer: &mut Writer<io::Cursor<Vec<u8>>>| {
+        for i in 0..image_count {
+            write_start_tag(writer, "bk", vec![], false);
+            write_start_tag(writer, "extLst", vec![], false);
+            write_start_tag(
+                writer,
+                "ext",
+                vec![("uri", "{3e2802c4-a4d2-4d8b-9148-e3be6c30e623}")],
+                false,
+            );
+            write_start_tag(
+                writer,
+                "xlrd:rvb",
+                vec![
+                    ("xmlns:xlrd", RICH_DATA_NS),
+                    ("i", &(value_offset + i).to_string()),
+                ],
+                true,
+            );
+            write_end_tag(writer, "ext");
+            write_end_tag(writer, "extLst");
+            write_end_tag(writer, "bk");
+        }
+    };
+    let write_future = |writer: &mut Writer<io::Cursor<Vec<u8>>>| {
+        write_start_tag(
+            writer,
+            "futureMetadata",
+            vec![("name", "XLRICHVALUE"), ("count", &image_count.to_string())],
+            false,
+        );
+        write_future_blocks(writer);
+        write_end_tag(writer, "futureMetadata");
+    };
+    let write_value_blocks = |writer: &mut Writer<io::Cursor<Vec<u8>>>| {
+        for i in 0..image_count {
+            write_start_tag(writer, "bk", vec![], false);
+            write_start_tag(
+                writer,
+                "rc",
+                vec![("t", &type_index), ("v", &(future_offset + i).to_string())],
+                true,
+            );
+            write_end_tag(writer, "bk");
+        }
+    };
+
+    // the elements are added in the order of the schema.
+    let mut has_types_element = false;
+    let mut has_future = future_blocks.is_some();
+    let mut has_value_metadata = false;
+    let mut in_future = false;
+    let mut depth = 0;
+    let writer = copy_part(&data, |writer, ev| {
+        let (name, is_start) = match ev {
+            Event::Start(e) | Event::Empty(e) => (e.name().into_inner().to_vec(), true),
+            Event::End(e) => (e.name().into_inner().to_vec(), false),
+            _ => return false,
+        };
+        // elements directly under the root, and the end of the root
+        let is_top_start = is_start && depth == 1;
+        let is_root_end = !is_start && depth == 1;
+        match ev {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            _ => {}
+        }
+        if !has_future
+            && ((is_top_start
+                && matches!(
+                    name.as_slice(),
+                    b"cellMetadata" | b"valueMetadata" | b"extLst"
+                ))
+                || is_root_end)
+        {
+            if !has_types_element {
+                has_types_element = true;
+                write_start_tag(writer, "metadataTypes", vec![("count", "1")], false);
+                write_type(writer);
+                write_end_tag(writer, "metadataTypes");
+            }
+            has_future = true;
+            write_future(writer);
+        }
+        if !has_value_metadata && ((is_top_start && name == b"extLst") || is_root_end) {
+            has_value_metadata = true;
+            write_start_tag(
+                writer,
+                "valueMetadata",
+                vec![("count", &image_count.to_string())],
+                false,
+            );
+            write_value_blocks(writer);
+            write_end_tag(writer, "valueMetadata");
+        }
+        match ev {
+            Event::Start(e) if name == b"metadataTypes" => {
+                has_types_element = true;
+                if !has_type {
+                    write_start_with_count(writer, e, metadata_types.len() + 1);
+                    return true;
+                }
+            }
+            Event::End(_) if name == b"metadataTypes" && !has_type => write_type(writer),
+            Event::Start(e)
+                if name == b"futureMetadata"
+                    && matches!(get_attribute(e, b"name"), Ok(Some(v)) if v == "XLRICHVALUE") =>
+            {
+                in_future = true;
+                write_start_with_count(writer, e, future_offset + image_count);
+                return true;
+            }
+            Event::End(_) if name == b"futureMetadata" && in_future => {
+                in_future = false;
+                write_future_blocks(writer);
+            }
+            Event::Start(e) if name == b"valueMetadata" => {
+                has_value_metadata = true;
+                write_start_with_count(writer, e, value_metadata_count + image_count);
+                return true;
+            }
+            Event::End(_) if name == b"valueMetadata" => write_value_blocks(writer),
+            _ => {}
+        }
+        false
+    })?;
+    writer_mng.add_writer(&target, writer)
+}
+
+fn find_part<'a>(spreadsheet: &'a Spreadsheet, r_type: &str) -> Option<&'a RawPart> {
+    spreadsheet
+        .get_rich_data_parts()
+        .iter()
+        .find(|v| v.get_type() == r_type)
+}
+
+/// Target and content of a rich data part. A new part is made from `template`.
+fn get_part_data(
+    spreadsheet: &Spreadsheet,
+    r_type: &str,
+    default_target: &str,
+    template: &str,
+) -> (String, Vec<u8>) {
+    match find_part(spreadsheet, r_type) {
+        Some(v) => (
+            v.get_part_file().get_file_target().to_string(),
+            v.get_part_file().get_file_data().clone(),
+        ),
+        None => (
+            default_target.to_string(),
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n{}",
+                template
+            )
+            .into_bytes(),
+        ),
+    }
+}
+
+/// Count the `child` elements directly under the root element `parent`.
+fn count_children(data: &[u8], parent: &[u8], child: &[u8]) -> Result<usize, XlsxError> {
+    let mut reader = Reader::from_reader(data);
+    let mut buf = Vec::new();
+    let mut depth = 0;
+    let mut count = 0;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => {
+                if depth == 1 && e.name().into_inner() == child {
+                    count += 1;
+                }
+                if depth > 0 || e.name().into_inner() == parent {
+                    depth += 1;
+                }
+            }
+            Event::Empty(e) => {
+                if depth == 1 && e.name().into_inner() == child {
+                    count += 1;
+                }
+            }
+            Event::End(_) => depth -= 1,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(count)
+}
+
+/// Count the blocks of the XLRICHVALUE future metadata. (None when there is none.)
+fn count_rich_value_blocks(data: &[u8]) -> Result<Option<usize>, XlsxError> {
+    let mut reader = Reader::from_reader(data);
+    let mut buf = Vec::new();
+    let mut depth = 0;
+    let mut count = None;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => {
+                if depth == 1 && e.name().into_inner() == b"bk" {
+                    count = count.map(|v| v + 1);
+                }
+                if depth > 0 {
+                    depth += 1;
+                } else if e.name().into_inner() == b"futureMetadata"
+                    && get_attribute(&e, b"name")?.as_deref() == Some("XLRICHVALUE")
+                {
+                    depth += 1;
+                    count = Some(0);
+                }
+            }
+            Event::End(_) if depth > 0 => depth -= 1,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(count)
+}
+
+/// Copy a part event by event.
+/// `edit` may write elements before an event, and returns true when it has written the event itself.
+fn copy_part<F>(data: &[u8], mut edit: F) -> Result<Writer<io::Cursor<Vec<u8>>>, XlsxError>
+where
+    F: FnMut(&mut Writer<io::Cursor<Vec<u8>>>, &Event) -> bool,
+{
+    let mut reader = Reader::from_reader(data);
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    let mut buf = Vec::new();
+    loop {
+        let ev = reader.read_event_into(&mut buf)?;
+        if let Event::Eof = ev {
+            break;
+        }
+        if !edit(&mut writer, &ev) {
+            writer.write_event(ev);
+        }
+        buf.clear();
+    }
+    Ok(writer)
+}
+
+/// Add elements at the end of the root element `parent` and update its `count`.
+fn append_children<F>(
+    writer: &mut Writer<io::Cursor<Vec<u8>>>,
+    ev: &Event,
+    parent: &[u8],
+    count: Option<usize>,
+    write_children: F,
+) -> bool
+where
+    F: Fn(&mut Writer<io::Cursor<Vec<u8>>>),
+{
+    match ev {
+        Event::Start(e) if e.name().into_inner() == parent => {
+            match count {
+                Some(v) => write_start_with_count(writer, e, v),
+                None => {
+                    writer.write_event(Event::Start(e.borrow()));
+                }
+            }
+            true
+        }
+        Event::Empty(e) if e.name().into_inner() == parent => {
+            match count {
+                Some(v) => write_start_with_count(writer, e, v),
+                None => {
+                    writer.write_event(Event::Start(e.borrow()));
+                }
+            }
+            write_children(writer);
+            writer.write_event(Event::End(BytesEnd::new(
+                String::from_utf8_lossy(parent).into_owned(),
+            )));
+            true
+        }
+        Event::End(e) if e.name().into_inner() == parent => {
+            write_children(writer);
+            false
+        }
+        _ => false,
+    }
+}
+
+fn write_start_with_count(writer: &mut Writer<io::Cursor<Vec<u8>>>, e: &BytesStart, count: usize) {
+    let mut elem = e.to_owned();
+    elem.clear_attributes();
+    for attr in e.attributes().with_checks(false).flatten() {
+        if attr.key.into_inner() != b"count" {
+            elem.push_attribute(attr);
+        }
+    }
+    elem.push_attribute(("count", count.to_string().as_str()));
+    writer.write_event(Event::Start(elem));
+}
+
+fn new_writer() -> Writer<io::Cursor<Vec<u8>>> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+    writer
+}
@@ -32,7 +32,11 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     )));
     write_new_line(&mut writer);
 
-    shared_string_table.write().unwrap().write_to(&mut writer);
+    let is_text_sanitized = *writer_mng.get_option().get_is_text_sanitized();
+    shared_string_table
+        .write()
+        .unwrap()
+        .write_to(&mut writer, is_text_sanitized);
 
     writer_mng.add_writer(PKG_SHARED_STRINGS, writer)
 }
@@ -20,7 +20,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     )));
     write_new_line(&mut writer);
 
-    stylesheet.write_to(&mut writer);
+    stylesheet.write_to(&mut writer, writer_mng.get_option().get_compatibility());
 
     writer_mng.add_writer(PKG_STYLES, writer)
 }
@@ -34,19 +34,19 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
         // table start
         let table_no = writer_mng.next_table_no();
-        write_start_tag(
-            &mut writer,
-            "table",
-            vec![
-                ("xmlns", SHEET_MAIN_NS),
-                ("id", &table_no.to_string()),
-                ("name", table.get_name()),
-                ("displayName", table.get_display_name()),
-                ("ref", &area),
-                ("totalsRowShown", "0"),
-            ],
-            false,
-        );
+        let table_no_str = table_no.to_string();
+        let mut attributes: Vec<(&str, &str)> = vec![
+            ("xmlns", SHEET_MAIN_NS),
+            ("id", &table_no_str),
+            ("name", table.get_name()),
+            ("displayName", table.get_display_name()),
+            ("ref", &area),
+        ];
+        if table.has_query_table() {
+            attributes.push(("tableType", "queryTable"));
+        }
+        attributes.push(("totalsRowShown", "0"));
+        write_start_tag(&mut writer, "table", attributes, false);
 
         // autoFilter
         write_start_tag(&mut writer, "autoFilter", vec![("ref", &area)], true);
@@ -61,12 +61,20 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
         let mut col_id = 1;
         for col in cols.iter() {
-            write_start_tag(
-                &mut writer,
-                "tableColumn",
-                vec![("id", &col_id.to_string()), ("name", col.get_name())],
-                true,
-            );
+            let col_id_str = col_id.to_string();
+            let mut attributes: Vec<(&str, &str)> = vec![("id", &col_id_str)];
+            if !col.get_unique_name().is_empty() {
+                attributes.push(("uniqueName", col.get_unique_name()));
+            }
+            attributes.push(("name", col.get_name()));
+            let query_table_field_id_str = col
+                .get_query_table_field_id()
+                .map(|v| v.to_string())
+                .unwrap_or_default();
+            if col.get_query_table_field_id().is_some() {
+                attributes.push(("queryTableFieldId", &query_table_field_id_str));
+            }
+            write_start_tag(&mut writer, "tableColumn", attributes, true);
             col_id += 1;
         }
         write_end_tag(&mut writer, "tableColumns");
@@ -103,7 +111,47 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         write_end_tag(&mut writer, "table");
 
         writer_mng.add_file_at_table(writer, table_no)?;
+
+        // queryTable
+        if let Some(query_table) = table.get_query_table() {
+            let query_table_no = writer_mng.add_file_at_query_table(query_table.get_raw_data())?;
+            write_rels(table_no, query_table_no, writer_mng)?;
+        }
+
         table_no_list.push(table_no.to_string());
     }
     Ok(table_no_list)
 }
+
+fn write_rels<W: io::Seek + io::Write>(
+    table_no: i32,
+    query_table_no: i32,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer.write_event(Event::Decl(BytesDecl::new(
+        "1.0",
+        Some("UTF-8"),
+        Some("yes"),
+    )));
+    write_new_line(&mut writer);
+
+    // relationships
+    write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
+    let target = format!("../queryTables/queryTable{}.xml", query_table_no);
+    write_start_tag(
+        &mut writer,
+        "Relationship",
+        vec![
+            ("Id", "rId1"),
+            ("Type", QUERY_TABLE_NS),
+            ("Target", &target),
+        ],
+        true,
+    );
+    write_end_tag(&mut writer, "Relationships");
+
+    let file_path = format!("{}/_rels/table{}.xml.rels", PKG_TABLES, table_no);
+    writer_mng.add_writer(&file_path, writer)
+}
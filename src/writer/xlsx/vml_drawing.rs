@@ -1,177 +1,79 @@
-use super::driver::*;
 use super::XlsxError;
-use helper::const_str::*;
 use quick_xml::Writer;
 use std::io;
+use std::str::FromStr;
+use structs::vml::ShapeTypeValues;
+use structs::vml::VmlDrawing;
 use structs::Worksheet;
 use structs::WriterManager;
 
+/// Write the legacy shapes of the worksheet.
+/// # Arguments
+/// * `id_map` - Block of shape ids, the sheet number. The worksheet part refers to the same ids.
 pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
+    id_map: usize,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<String, XlsxError> {
-    if !worksheet.has_legacy_drawing() {
+    let vml_drawing = make_vml_drawing(worksheet);
+    if vml_drawing.is_empty() {
         return Ok(String::from(""));
     }
 
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
-    // xml
-    write_start_tag(
-        &mut writer,
-        "xml",
-        vec![
-            ("xmlns:v", VML_NS),
-            ("xmlns:o", OFFICE_NS),
-            ("xmlns:x", EXCEL_NS),
-        ],
-        false,
-    );
+    vml_drawing.write_to(&mut writer, &id_map);
 
-    // o:shapelayout
-    write_start_tag(&mut writer, "o:shapelayout", vec![("v:ext", "edit")], false);
-
-    // o:idmap
-    write_start_tag(
-        &mut writer,
-        "o:idmap",
-        vec![("v:ext", "edit"), ("data", "1")],
-        true,
-    );
-
-    write_end_tag(&mut writer, "o:shapelayout");
-
-    let mut id = 1000 + 25;
-
-    // ole_object
-    if worksheet.has_ole_objects() {
-        // v:shapetype
-        write_start_tag(
-            &mut writer,
-            "v:shapetype",
-            vec![
-                ("id", "_x0000_t75"),
-                ("coordsize", "21600,21600"),
-                ("o:spt", "75"),
-                ("o:preferrelative", "t"),
-                ("path", "m@4@5l@4@11@9@11@9@5xe"),
-                ("filled", "f"),
-                ("stroked", "f"),
-            ],
-            false,
-        );
-
-        // v:stroke
-        write_start_tag(&mut writer, "v:stroke", vec![("joinstyle", "miter")], true);
-
-        // v:formulas
-        write_start_tag(&mut writer, "v:formulas", vec![], false);
-        write_start_tag(
-            &mut writer,
-            "v:f",
-            vec![("eqn", "if lineDrawn pixelLineWidth 0")],
-            true,
-        );
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "sum @0 1 0")], true);
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "sum 0 0 @1")], true);
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "prod @2 1 2")], true);
-        write_start_tag(
-            &mut writer,
-            "v:f",
-            vec![("eqn", "prod @3 21600 pixelWidth")],
-            true,
-        );
-        write_start_tag(
-            &mut writer,
-            "v:f",
-            vec![("eqn", "prod @3 21600 pixelHeight")],
-            true,
-        );
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "sum @0 0 1")], true);
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "prod @6 1 2")], true);
-        write_start_tag(
-            &mut writer,
-            "v:f",
-            vec![("eqn", "prod @7 21600 pixelWidth")],
-            true,
-        );
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "sum @8 21600 0")], true);
-        write_start_tag(
-            &mut writer,
-            "v:f",
-            vec![("eqn", "prod @7 21600 pixelHeight")],
-            true,
-        );
-        write_start_tag(&mut writer, "v:f", vec![("eqn", "sum @10 21600 0")], true);
-        write_end_tag(&mut writer, "v:formulas");
-
-        // v:path
-        write_start_tag(
-            &mut writer,
-            "v:path",
-            vec![
-                ("o:extrusionok", "f"),
-                ("gradientshapeok", "t"),
-                ("o:connecttype", "rect"),
-            ],
-            true,
-        );
-
-        // o:lock
-        write_start_tag(
-            &mut writer,
-            "o:lock",
-            vec![("v:ext", "edit"), ("aspectratio", "t")],
-            true,
-        );
-
-        write_end_tag(&mut writer, "v:shapetype");
+    let file_no = writer_mng.add_file_at_vml_drawing(writer)?;
+    Ok(file_no.to_string())
+}
 
-        let mut r_id = 1;
-        for ole_object in worksheet.get_ole_objects().get_ole_object() {
-            // v:shape
-            ole_object.get_shape().write_to(&mut writer, &id, &r_id);
-            r_id += 1;
-            id += 1;
-        }
+/// Collect the legacy shapes of the worksheet.
+/// The order fixes the shape ids referenced from the worksheet part.
+pub(crate) fn make_vml_drawing(worksheet: &Worksheet) -> VmlDrawing<'_> {
+    let mut vml_drawing = VmlDrawing::default();
+    for ole_object in worksheet.get_ole_objects().get_ole_object() {
+        vml_drawing.add_shape(ole_object.get_shape(), ShapeTypeValues::Picture);
     }
+    for comment in worksheet.get_comments() {
+        vml_drawing.add_shape(comment.get_shape(), ShapeTypeValues::TextBox);
+    }
+    for form_control in worksheet.get_form_controls() {
+        vml_drawing.add_form_control(form_control);
+    }
+    for shape in worksheet.get_vml_shapes() {
+        match ShapeTypeValues::from_str(shape.get_type()) {
+            Ok(shape_type) => vml_drawing.add_shape(shape, shape_type),
+            Err(_) => vml_drawing.add_custom_shape(shape),
+        };
+    }
+    vml_drawing
+}
 
-    // comment
-    if worksheet.has_comments() {
-        // v:shapetype
-        write_start_tag(
-            &mut writer,
-            "v:shapetype",
-            vec![
-                ("id", "_x0000_t202"),
-                ("coordsize", "21600,21600"),
-                ("o:spt", "202"),
-                ("path", "m,l,21600r21600,l21600,xe"),
-            ],
-            false,
-        );
-
-        // v:stroke
-        write_start_tag(&mut writer, "v:stroke", vec![("joinstyle", "miter")], true);
-
-        // v:path
-        write_start_tag(
-            &mut writer,
-            "v:path",
-            vec![("gradientshapeok", "t"), ("o:connecttype", "rect")],
-            true,
-        );
-
-        write_end_tag(&mut writer, "v:shapetype");
-
-        for comment in worksheet.get_comments() {
-            // v:shape
-            comment.get_shape().write_to(&mut writer, &id, &0);
-            id += 1;
-        }
+/// Write the pictures of the header/footer.
+/// # Arguments
+/// * `id_map` - Block of shape ids, after the blocks of the sheets.
+pub(crate) fn write_header_footer<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    id_map: usize,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<String, XlsxError> {
+    let vml_drawing = make_header_footer_vml_drawing(worksheet);
+    if vml_drawing.is_empty() {
+        return Ok(String::from(""));
     }
 
-    write_end_tag(&mut writer, "xml");
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    vml_drawing.write_to(&mut writer, &id_map);
 
     let file_no = writer_mng.add_file_at_vml_drawing(writer)?;
     Ok(file_no.to_string())
 }
+
+/// Collect the pictures of the header/footer.
+pub(crate) fn make_header_footer_vml_drawing(worksheet: &Worksheet) -> VmlDrawing<'_> {
+    let mut vml_drawing = VmlDrawing::default();
+    for image in worksheet.get_header_footer().get_images() {
+        vml_drawing.add_header_footer_image(image);
+    }
+    vml_drawing
+}
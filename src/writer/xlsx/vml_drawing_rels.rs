@@ -3,8 +3,10 @@ use quick_xml::Writer;
 use std::io;
 
 use super::driver::*;
+use super::vml_drawing;
 use super::XlsxError;
 use helper::const_str::*;
+use structs::vml::VmlDrawing;
 use structs::Worksheet;
 use structs::WriterManager;
 
@@ -12,6 +14,30 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
     vml_drawing_no: &str,
     writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    write_vml_drawing_rels(
+        &vml_drawing::make_vml_drawing(worksheet),
+        vml_drawing_no,
+        writer_mng,
+    )
+}
+
+pub(crate) fn write_header_footer<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    vml_drawing_no: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    write_vml_drawing_rels(
+        &vml_drawing::make_header_footer_vml_drawing(worksheet),
+        vml_drawing_no,
+        writer_mng,
+    )
+}
+
+fn write_vml_drawing_rels<W: io::Seek + io::Write>(
+    vml_drawing: &VmlDrawing,
+    vml_drawing_no: &str,
+    writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let mut is_write = false;
 
@@ -28,17 +54,15 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_start_tag(&mut writer, "Relationships", vec![("xmlns", REL_NS)], false);
 
     let mut r_id = 1;
-    for ole_object in worksheet.get_ole_objects().get_ole_object() {
-        if let Some(v) = ole_object.get_shape().get_image_data() {
-            is_write = write_relationship(
-                &mut writer,
-                &r_id,
-                IMAGE_NS,
-                format!("../media/{}", v.get_image_name()).as_str(),
-                "",
-            );
-            r_id += 1;
-        }
+    for image_name in vml_drawing.get_image_names() {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id,
+            IMAGE_NS,
+            format!("../media/{}", image_name).as_str(),
+            "",
+        );
+        r_id += 1;
     }
 
     write_end_tag(&mut writer, "Relationships");
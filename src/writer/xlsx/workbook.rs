@@ -5,6 +5,7 @@ use std::io;
 use super::driver::*;
 use super::XlsxError;
 use helper::const_str::*;
+use structs::CalcChainValues;
 use structs::Spreadsheet;
 use structs::WriterManager;
 
@@ -46,7 +47,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let mut attributes: Vec<(&str, &str)> = Vec::new();
     attributes.push(("filterPrivacy", "1"));
     //attributes.push(("defaultThemeVersion", "124226"));
-    if spreadsheet.get_has_macros() {
+    if spreadsheet.get_has_macros() || spreadsheet.get_code_name().is_some() {
         attributes.push((
             "codeName",
             &spreadsheet.get_code_name().unwrap_or("ThisWorkbook"),
@@ -104,19 +105,33 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     }
 
     // calcPr
-    write_start_tag(
-        &mut writer,
-        "calcPr",
-        vec![
-            ("calcId", "122211"),
-            //("calcId", "999999"),
-            //("calcMode", "auto"),
-            //("calcCompleted", if recalc_required {"1"} else {"0"}),
-            //("fullCalcOnLoad", if recalc_required {"0"} else {"1"}),
-            //("forceFullCalc", if recalc_required {"0"} else {"1"}),
-        ],
-        true,
-    );
+    let mut attributes: Vec<(&str, &str)> = vec![
+        ("calcId", "122211"),
+        //("calcId", "999999"),
+        //("calcMode", "auto"),
+        //("calcCompleted", if recalc_required {"1"} else {"0"}),
+        //("fullCalcOnLoad", if recalc_required {"0"} else {"1"}),
+        //("forceFullCalc", if recalc_required {"0"} else {"1"}),
+    ];
+    if *spreadsheet.get_precision_as_displayed() {
+        attributes.push(("fullPrecision", "0"));
+    }
+    if *spreadsheet.get_force_full_calculation()
+        || writer_mng.get_option().get_calc_chain() == &CalcChainValues::ForceRecalculation
+    {
+        attributes.push(("fullCalcOnLoad", "1"));
+    }
+    write_start_tag(&mut writer, "calcPr", attributes, true);
+
+    // customWorkbookViews
+    let custom_workbook_views = spreadsheet.get_custom_workbook_views();
+    if !custom_workbook_views.is_empty() {
+        write_start_tag(&mut writer, "customWorkbookViews", vec![], false);
+        for custom_workbook_view in custom_workbook_views {
+            custom_workbook_view.write_to(&mut writer);
+        }
+        write_end_tag(&mut writer, "customWorkbookViews");
+    }
 
     // pivotCaches
     let pivot_cache_definition_collection = spreadsheet.get_pivot_caches();
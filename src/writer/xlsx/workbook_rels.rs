@@ -75,6 +75,81 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         index += 1;
     }
 
+    // relationship calcChain.xml
+    if writer_mng.check_file_exist(PKG_CALC_CHAIN) {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            CALC_CHAIN_NS,
+            "calcChain.xml",
+            "",
+        );
+        index += 1;
+    }
+
+    // relationships rich data
+    for raw_part in spreadsheet.get_rich_data_parts() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            raw_part.get_type(),
+            &raw_part.get_target(),
+            "",
+        );
+        index += 1;
+    }
+
+    // relationships connections
+    if let Some(raw_part) = spreadsheet.get_connections_part() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            raw_part.get_type(),
+            &raw_part.get_target(),
+            "",
+        );
+        index += 1;
+    }
+
+    // relationships unknown parts
+    for raw_part in spreadsheet.get_unknown_parts() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            raw_part.get_type(),
+            &raw_part.get_target(),
+            "",
+        );
+        index += 1;
+    }
+
+    // relationships in-cell images (the parts read from the file are already related)
+    if !writer_mng.get_in_cell_images().is_empty() {
+        for (r_type, target) in [
+            (SHEET_METADATA_NS, PKG_METADATA),
+            (RD_RICH_VALUE_STRUCTURE_NS, PKG_RD_RICH_VALUE_STRUCTURE),
+            (RD_RICH_VALUE_NS, PKG_RD_RICH_VALUE),
+            (RD_RICH_VALUE_TYPES_NS, PKG_RD_RICH_VALUE_TYPES),
+            (RICH_VALUE_REL_NS, PKG_RICH_VALUE_REL),
+        ] {
+            if spreadsheet
+                .get_rich_data_parts()
+                .iter()
+                .any(|v| v.get_type() == r_type)
+            {
+                continue;
+            }
+            write_relationship(
+                &mut writer,
+                &index.to_string(),
+                r_type,
+                target.strip_prefix("xl/").unwrap(),
+                "",
+            );
+            index += 1;
+        }
+    }
+
     // relationships for vbaProject if needed
     if spreadsheet.get_has_macros() {
         write_relationship(
@@ -8,11 +8,14 @@ use std::io;
 use std::sync::Arc;
 use std::sync::RwLock;
 use structs::Cell;
+use structs::CompatibilityValues;
+use structs::MediaObject;
 use structs::SharedStringTable;
 use structs::Stylesheet;
 use structs::Worksheet;
 use structs::WriterManager;
 
+#[cfg(not(feature = "rayon"))]
 pub(crate) fn write<W: io::Seek + io::Write>(
     sheet_no: &i32,
     worksheet: &Worksheet,
@@ -21,6 +24,104 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     has_macros: bool,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
+    let target = format!("{PKG_SHEET}{}.xml", sheet_no);
+    let has_dimension = !writer_mng.get_option().get_is_dimension_omitted();
+    let is_minimal = writer_mng.get_option().get_compatibility() == &CompatibilityValues::Minimal;
+    let in_cell_image_offset = writer_mng.get_in_cell_image_offset();
+    let mut in_cell_images: Vec<MediaObject> = Vec::new();
+    writer_mng.add_stream(&target, |out| {
+        write_xml(
+            out,
+            worksheet,
+            shared_string_table,
+            stylesheet,
+            has_macros,
+            has_dimension,
+            is_minimal,
+            *sheet_no as usize,
+            &mut |image| {
+                in_cell_images.push(image.clone());
+                in_cell_image_offset + in_cell_images.len() as u32
+            },
+        )
+    })?;
+    for image in &in_cell_images {
+        writer_mng.add_in_cell_image(image);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+pub(crate) fn add_bin<W: io::Seek + io::Write>(
+    sheet_no: &i32,
+    data: &[u8],
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let target = format!("{PKG_SHEET}{}.xml", sheet_no);
+    writer_mng.add_bin(&target, data)
+}
+
+/// Registers the styles, shared strings and in-cell images of the worksheet
+/// in the order `write_xml` meets them, and returns the meta index of
+/// each in-cell image.
+#[cfg(feature = "rayon")]
+pub(crate) fn prepare<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    shared_string_table: &Arc<RwLock<SharedStringTable>>,
+    stylesheet: &mut Stylesheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Vec<u32> {
+    let mut shared_string_table = shared_string_table.write().unwrap();
+    let mut in_cell_image_list = Vec::new();
+
+    let mut columns = worksheet.get_column_dimensions().clone();
+    columns.sort_by(|a, b| a.get_col_num().cmp(b.get_col_num()));
+    for column in &columns {
+        stylesheet.set_style_shared(column.get_style_shared());
+    }
+
+    let mut row_dimensions = worksheet.get_row_dimensions();
+    row_dimensions.sort_by(|a, b| a.get_row_num().cmp(b.get_row_num()));
+    let cells = worksheet.get_cell_collection_sorted();
+    let mut cells_iter = cells.iter().peekable();
+    for row in &row_dimensions {
+        stylesheet.set_style_shared(row.get_style_shared());
+        while let Some(cell) = cells_iter.peek() {
+            if row.get_row_num() != cell.get_coordinate().get_row_num() {
+                break;
+            }
+            let cell = cells_iter.next().unwrap();
+            if let Some(image) = cell.get_image() {
+                in_cell_image_list.push(writer_mng.add_in_cell_image(image));
+            }
+            cell.prepare_write(&mut shared_string_table, stylesheet);
+        }
+    }
+
+    for conditional_formatting in worksheet.get_conditional_formatting_collection() {
+        for rule in conditional_formatting.get_conditional_collection() {
+            if let Some(v) = rule.get_style() {
+                stylesheet.get_differential_formats_mut().set_style(v);
+            }
+        }
+    }
+
+    in_cell_image_list
+}
+
+/// Writes the worksheet XML to `out`.
+/// The rows are written straight through, so the sheet data is never held in memory as a whole.
+pub(crate) fn write_xml<O: io::Write>(
+    out: &mut O,
+    worksheet: &Worksheet,
+    shared_string_table: Arc<RwLock<SharedStringTable>>,
+    stylesheet: &mut Stylesheet,
+    has_macros: bool,
+    has_dimension: bool,
+    is_minimal: bool,
+    vml_id_map: usize,
+    add_in_cell_image: &mut dyn FnMut(&MediaObject) -> u32,
+) -> io::Result<()> {
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
 
     // XML header
@@ -32,29 +133,27 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_new_line(&mut writer);
 
     // worksheet
-    write_start_tag(
-        &mut writer,
-        "worksheet",
-        vec![
-            ("xmlns", SHEET_MAIN_NS),
-            ("xmlns:r", REL_OFC_NS),
-            ("xmlns:xdr", SHEET_DRAWING_NS),
-            ("xmlns:x14", SHEET_MS_MAIN_NS),
-            ("xmlns:mc", MC_NS),
-            ("mc:Ignorable", "x14ac"),
-            ("xmlns:x14ac", SHEETML_AC_NS),
-        ],
-        false,
-    );
+    let mut attributes: Vec<(&str, &str)> = vec![
+        ("xmlns", SHEET_MAIN_NS),
+        ("xmlns:r", REL_OFC_NS),
+        ("xmlns:xdr", SHEET_DRAWING_NS),
+        ("xmlns:x14", SHEET_MS_MAIN_NS),
+    ];
+    if !is_minimal {
+        attributes.push(("xmlns:mc", MC_NS));
+        attributes.push(("mc:Ignorable", "x14ac"));
+        attributes.push(("xmlns:x14ac", SHEETML_AC_NS));
+    }
+    write_start_tag(&mut writer, "worksheet", attributes, false);
 
     // sheetPr
-    let mut attributes: Vec<(&str, &str)> = Vec::new();
-    if has_macros {
+    let mut attributes: Vec<(&str, &str)> = worksheet.get_sheet_properties().get_attributes();
+    if has_macros || worksheet.has_code_name() {
         let code_name = match worksheet.has_code_name() {
             true => worksheet.get_code_name().as_ref().unwrap(),
             false => worksheet.get_name(),
         };
-        attributes.push(("codeName", code_name));
+        attributes.insert(0, ("codeName", code_name));
     }
 
     // tabColor
@@ -78,12 +177,14 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     //], true);
 
     // dimension
-    write_start_tag(
-        &mut writer,
-        "dimension",
-        vec![("ref", worksheet.calculate_worksheet_dimension().as_str())],
-        true,
-    );
+    if has_dimension {
+        write_start_tag(
+            &mut writer,
+            "dimension",
+            vec![("ref", worksheet.calculate_worksheet_dimension().as_str())],
+            true,
+        );
+    }
 
     // sheetViews
     worksheet.get_sheets_views().write_to(&mut writer);
@@ -91,7 +192,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     // sheetFormatPr
     worksheet
         .get_sheet_format_properties()
-        .write_to(&mut writer);
+        .write_to(&mut writer, !is_minimal);
 
     // cols
     let mut column_dimensions = worksheet.get_column_dimensions_crate().clone();
@@ -104,6 +205,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     // sheetData
     let has_sheet_data = worksheet.has_sheet_data();
     write_start_tag(&mut writer, "sheetData", vec![], !has_sheet_data);
+    out.write_all(&writer.into_inner().into_inner())?;
+    // each row is written to `out` as soon as it is complete.
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
 
     // row dimensions sort.
     let mut row_dimensions = worksheet.get_row_dimensions();
@@ -151,7 +255,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         // row
         if cells_in_row.is_empty() {
             let spans = "0:0".to_string();
-            row.write_to(&mut writer, stylesheet, spans, true);
+            row.write_to(&mut writer, stylesheet, spans, true, !is_minimal);
         } else {
             let (first_num, last_num) = (
                 cells_in_row.first().unwrap().get_coordinate().get_col_num(),
@@ -159,9 +263,21 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             );
             let spans = format!("{first_num}:{last_num}");
 
-            row.write_to(&mut writer, stylesheet, spans, false);
+            row.write_to(&mut writer, stylesheet, spans, false, !is_minimal);
             // c
             for cell in cells_in_row {
+                if let Some(image) = cell.get_image() {
+                    // in-cell image
+                    let mut cell = cell.clone();
+                    cell.set_value_meta_index(add_in_cell_image(image));
+                    cell.write_to(
+                        &mut writer,
+                        &shared_string_table,
+                        stylesheet,
+                        &formula_shared_list,
+                    );
+                    continue;
+                }
                 cell.write_to(
                     &mut writer,
                     &shared_string_table,
@@ -172,6 +288,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
             write_end_tag(&mut writer, "row");
         }
+        out.write_all(writer.get_ref().get_ref())?;
+        writer.get_mut().get_mut().clear();
+        writer.get_mut().set_position(0);
     }
 
     if has_sheet_data {
@@ -185,14 +304,12 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     // autoFilter
     if let Some(v) = worksheet.get_auto_filter() {
-        write_start_tag(
-            &mut writer,
-            "autoFilter",
-            vec![("ref", &v.get_range().get_range())],
-            true,
-        );
+        v.write_to(&mut writer);
     }
 
+    // customSheetViews
+    worksheet.get_custom_sheet_views().write_to(&mut writer);
+
     // mergeCells
     worksheet.get_merge_cells_crate().write_to(&mut writer);
 
@@ -226,6 +343,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
                 attributes.push(("r:id", r_id_str.as_str()));
                 r_id += 1;
             }
+            if !hyperlink.get_tooltip().is_empty() {
+                attributes.push(("tooltip", hyperlink.get_tooltip()));
+            }
             write_start_tag(&mut writer, "hyperlink", attributes, true);
         }
 
@@ -252,6 +372,9 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     // colBreaks
     worksheet.get_column_breaks().write_to(&mut writer);
 
+    // ignoredErrors
+    worksheet.get_ignored_errors().write_to(&mut writer);
+
     if worksheet.has_drawing_object() {
         // drawing
         let r_id_str = format!("rId{}", &r_id);
@@ -276,6 +399,51 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
+    // legacyDrawingHF
+    if worksheet.get_header_footer().has_image() {
+        let r_id_str = format!("rId{}", &r_id);
+        write_start_tag(
+            &mut writer,
+            "legacyDrawingHF",
+            vec![("r:id", r_id_str.as_str())],
+            true,
+        );
+        r_id += 1;
+    }
+
+    // picture
+    if worksheet.get_background_image().is_some() {
+        let r_id_str = format!("rId{}", &r_id);
+        write_start_tag(
+            &mut writer,
+            "picture",
+            vec![("r:id", r_id_str.as_str())],
+            true,
+        );
+        r_id += 1;
+    }
+
+    // oleObjects
+    // the shapes of the vml drawing start at the first id of its block.
+    let ole_id = vml_id_map * 1024 + 1;
+    worksheet
+        .get_ole_objects()
+        .write_to(&mut writer, &mut r_id, &ole_id);
+
+    // controls
+    if worksheet.has_form_controls() {
+        let ole_count = worksheet.get_ole_objects().get_ole_object().len();
+        let comment_count = worksheet.get_comments().len();
+        let mut shape_id = ole_id + ole_count + comment_count;
+        write_start_tag(&mut writer, "controls", vec![], false);
+        for form_control in worksheet.get_form_controls() {
+            form_control.write_control(&mut writer, &shape_id, &r_id);
+            shape_id += 1;
+            r_id += 1;
+        }
+        write_end_tag(&mut writer, "controls");
+    }
+
     // tableParts
     if worksheet.has_table() {
         let tables = worksheet.get_tables();
@@ -298,12 +466,6 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         write_end_tag(&mut writer, "tableParts");
     }
 
-    // oleObjects
-    let ole_id = 1000 + 25;
-    worksheet
-        .get_ole_objects()
-        .write_to(&mut writer, &r_id, &ole_id);
-
     // extLst
     if worksheet.get_data_validations_2010().is_some() {
         write_start_tag(&mut writer, "extLst", vec![], false);
@@ -318,6 +480,5 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 
     write_end_tag(&mut writer, "worksheet");
 
-    let target = format!("{PKG_SHEET}{}.xml", sheet_no);
-    writer_mng.add_writer(&target, writer)
+    out.write_all(&writer.into_inner().into_inner())
 }
@@ -14,11 +14,13 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet_no: &str,
     drawing_no: &str,
     vml_drawing_no: &str,
+    vml_drawing_hf_no: &str,
     comment_no: &str,
     ole_object_no_list: &[String],
     excel_no_list: &[String],
     printer_settings_no: &str,
     table_no_list: &[String],
+    ctrl_prop_no_list: &[String],
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let mut is_write = false;
@@ -92,13 +94,25 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
-    // write table relationships
-    for table_no in table_no_list.iter() {
+    // Write vmlDrawing relationship of header/footer
+    if worksheet.get_header_footer().has_image() {
         is_write = write_relationship(
             &mut writer,
             r_id.to_string().as_str(),
-            TABLE_NS,
-            format!("../tables/table{}.xml", table_no.to_string().as_str()).as_str(),
+            VML_DRAWING_NS,
+            format!("../drawings/vmlDrawing{}.vml", vml_drawing_hf_no).as_str(),
+            "",
+        );
+        r_id += 1;
+    }
+
+    // Write background image relationship
+    if let Some(v) = worksheet.get_background_image() {
+        is_write = write_relationship(
+            &mut writer,
+            r_id.to_string().as_str(),
+            IMAGE_NS,
+            format!("../media/{}", v.get_image_name()).as_str(),
             "",
         );
         r_id += 1;
@@ -147,6 +161,30 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
+    // Write form control properties
+    for ctrl_prop_no in ctrl_prop_no_list.iter() {
+        is_write = write_relationship(
+            &mut writer,
+            r_id.to_string().as_str(),
+            CTRL_PROP_NS,
+            format!("../ctrlProps/ctrlProp{}.xml", ctrl_prop_no).as_str(),
+            "",
+        );
+        r_id += 1;
+    }
+
+    // write table relationships
+    for table_no in table_no_list.iter() {
+        is_write = write_relationship(
+            &mut writer,
+            r_id.to_string().as_str(),
+            TABLE_NS,
+            format!("../tables/table{}.xml", table_no.to_string().as_str()).as_str(),
+            "",
+        );
+        r_id += 1;
+    }
+
     // Write comments relationship
     if !worksheet.get_comments().is_empty() {
         is_write = write_relationship(
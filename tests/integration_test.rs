@@ -3,6 +3,7 @@
 
 extern crate chrono;
 extern crate umya_spreadsheet;
+extern crate zip;
 use std::time::Instant;
 
 use umya_spreadsheet::{NumberingFormat, Style};
@@ -1541,3 +1542,4024 @@ fn issue_184() {
         .get_argb_with_theme(theme);
     assert_eq!(color, "A78470");
 }
+
+#[test]
+fn write_with_image_compression() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B3");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    image
+        .get_one_cell_anchor_mut()
+        .unwrap()
+        .get_extent_mut()
+        .set_cx(100 * 9525)
+        .set_cy(50 * 9525);
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .add_image(image);
+
+    let mut option = umya_spreadsheet::structs::XlsxWriterOption::default();
+    option.set_image_resize_to_display(true);
+    option.set_image_jpeg_quality(75);
+    let path = std::path::Path::new("./tests/result_files/image_compression.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option);
+
+    // the picture is scaled down to the displayed size, keeping its ratio.
+    let original = std::fs::read("./images/sample1.png").unwrap();
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let name = archive
+        .file_names()
+        .find(|v| v.starts_with("xl/media/"))
+        .unwrap()
+        .to_string();
+    let mut data = Vec::new();
+    std::io::Read::read_to_end(&mut archive.by_name(&name).unwrap(), &mut data).unwrap();
+    assert!(data.len() < original.len());
+    let compressed = image::load_from_memory(&data).unwrap();
+    assert_eq!(compressed.height(), 50);
+    assert!(compressed.width() <= 100);
+
+    // without the option the picture is kept as is.
+    let path = std::path::Path::new("./tests/result_files/image_no_compression.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut data = Vec::new();
+    std::io::Read::read_to_end(&mut archive.by_name(&name).unwrap(), &mut data).unwrap();
+    assert_eq!(data, original);
+
+    // a picture shown on two sheets is kept at its largest displayed size.
+    book.new_sheet("Sheet2").unwrap();
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B3");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    image
+        .get_one_cell_anchor_mut()
+        .unwrap()
+        .get_extent_mut()
+        .set_cx(120 * 9525)
+        .set_cy(60 * 9525);
+    book.get_sheet_by_name_mut("Sheet2")
+        .unwrap()
+        .add_image(image);
+    let path = std::path::Path::new("./tests/result_files/image_compression_2.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option);
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    assert_eq!(
+        archive
+            .file_names()
+            .filter(|v| v.starts_with("xl/media/"))
+            .count(),
+        1
+    );
+    let mut data = Vec::new();
+    std::io::Read::read_to_end(&mut archive.by_name(&name).unwrap(), &mut data).unwrap();
+    let compressed = image::load_from_memory(&data).unwrap();
+    assert_eq!(compressed.height(), 60);
+    assert!(compressed.width() <= 120);
+}
+
+#[test]
+fn header_footer_image() {
+    let mut book = umya_spreadsheet::new_file();
+    let header_footer = book
+        .get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_header_footer_mut();
+    header_footer.get_odd_header_mut().set_value("&C&G");
+    let mut image = umya_spreadsheet::structs::HeaderFooterImage::default();
+    image.new_image(
+        "./images/sample1.png",
+        umya_spreadsheet::structs::HeaderFooterImagePositionValues::CenterHeader,
+    );
+    header_footer.add_image(image);
+    let mut shape = umya_spreadsheet::structs::vml::Shape::default();
+    shape.set_type("#_x0000_t201");
+    shape
+        .get_client_data_mut()
+        .set_object_type(umya_spreadsheet::structs::vml::spreadsheet::ObjectValues::List);
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .add_vml_shape(shape);
+
+    let path = std::path::Path::new("./tests/result_files/header_footer_image.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    // the pictures get their own block of shape ids.
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut vml = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/drawings/vmlDrawing2.vml").unwrap(),
+        &mut vml,
+    )
+    .unwrap();
+    assert!(vml.contains(r#"data="2""#));
+    assert!(vml.contains(r#"id="CH" o:spid="_x0000_s2049""#));
+    assert!(vml.contains(r#"o:relid="rId1""#));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let images = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_header_footer()
+        .get_images();
+    assert_eq!(images.len(), 1);
+    assert_eq!(
+        images[0].get_position(),
+        &umya_spreadsheet::structs::HeaderFooterImagePositionValues::CenterHeader
+    );
+    assert_eq!(images[0].get_image().get_image_name(), "sample1.png");
+}
+
+#[test]
+fn sheet_background_image() {
+    let mut book = umya_spreadsheet::new_file();
+    let data = std::fs::read("./images/sample1.png").unwrap();
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .set_background_image(data.clone());
+
+    let path = std::path::Path::new("./tests/result_files/background_image.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let image = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_background_image()
+        .unwrap();
+    assert_eq!(image.get_image_data(), &data);
+}
+
+#[test]
+fn text_box() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("E6");
+
+    let mut rich_text = umya_spreadsheet::structs::RichText::default();
+    let mut element = umya_spreadsheet::structs::TextElement::default();
+    element.set_text("Total\n");
+    element
+        .get_run_properties_mut()
+        .set_bold(true)
+        .set_size(14.0);
+    rich_text.add_rich_text_elements(element);
+    let mut element = umya_spreadsheet::structs::TextElement::default();
+    element.set_text("Sales are up 12%");
+    rich_text.add_rich_text_elements(element);
+
+    let mut text_box = umya_spreadsheet::structs::TextBox::default();
+    text_box.new_text_box(from_marker, to_marker);
+    text_box.set_rich_text(&rich_text);
+    text_box.set_fill_color("FFFFF2CC");
+    text_box.set_border("FFBF9000", 12700);
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .add_text_box(text_box);
+
+    let path = std::path::Path::new("./tests/result_files/text_box.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let text_box_list = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_text_box_collection();
+    assert_eq!(text_box_list.len(), 1);
+    assert_eq!(text_box_list[0].get_text(), "Total\nSales are up 12%");
+    assert_eq!(text_box_list[0].get_coordinate(), "B2");
+
+    // a text box without a shape
+    let mut text_box = umya_spreadsheet::structs::TextBox::default();
+    assert!(text_box.get_shape().is_none());
+    assert_eq!(text_box.get_text(), "");
+    text_box.set_text("Note");
+    assert_eq!(text_box.get_text(), "Note");
+    assert!(*text_box
+        .get_shape()
+        .unwrap()
+        .get_non_visual_shape_properties()
+        .get_text_box());
+}
+
+#[test]
+fn add_shape() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    let mut shape = umya_spreadsheet::structs::drawing::spreadsheet::Shape::default();
+    shape.new_shape(umya_spreadsheet::structs::ShapeType::FlowChartDecision);
+    shape.set_text("Approved?");
+    shape.set_fill_color("FFDDEBF7");
+    shape.set_border("FF2F5597", 12700);
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("D5");
+    worksheet.add_shape(shape, from_marker, to_marker);
+
+    let path = std::path::Path::new("./tests/result_files/shape.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let shape_list = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_shape_collection();
+    assert_eq!(shape_list.len(), 1);
+    assert_eq!(shape_list[0].get_text(), "Approved?");
+    assert_eq!(
+        shape_list[0]
+            .get_shape_properties()
+            .get_geometry()
+            .get_geometry(),
+        "flowChartDecision"
+    );
+}
+
+#[test]
+fn add_connector() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    for (from, to) in [("B2", "D4"), ("G2", "I4")] {
+        let mut shape = umya_spreadsheet::structs::drawing::spreadsheet::Shape::default();
+        shape.new_shape(umya_spreadsheet::structs::ShapeType::FlowChartProcess);
+        let mut from_marker =
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate(from);
+        let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        to_marker.set_coordinate(to);
+        worksheet.add_shape(shape, from_marker, to_marker);
+    }
+    let ids: Vec<u32> = worksheet
+        .get_shape_collection()
+        .iter()
+        .map(|shape| {
+            *shape
+                .get_non_visual_shape_properties()
+                .get_non_visual_drawing_properties()
+                .get_id()
+        })
+        .collect();
+    assert_ne!(ids[0], ids[1]);
+
+    let mut connector = umya_spreadsheet::structs::drawing::spreadsheet::ConnectionShape::default();
+    connector.new_connector(umya_spreadsheet::structs::ConnectorType::Elbow);
+    connector.set_line("FF2F5597", 19050);
+    connector.set_tail_arrow(umya_spreadsheet::structs::ArrowType::Triangle);
+    connector.set_start_shape(ids[0], 3);
+    connector.set_end_shape(ids[1], 1);
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E3");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("G3");
+    worksheet.add_connector(connector, from_marker, to_marker);
+
+    let path = std::path::Path::new("./tests/result_files/connector.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let connector_list = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_connection_shape_collection();
+    assert_eq!(connector_list.len(), 1);
+    let connector = connector_list[0];
+    assert_eq!(
+        connector
+            .get_shape_properties()
+            .get_geometry()
+            .get_geometry(),
+        "bentConnector3"
+    );
+    let outline = connector.get_shape_properties().get_outline().unwrap();
+    assert_eq!(outline.get_width(), &19050);
+    assert_eq!(outline.get_tail_end().unwrap().get_type(), "triangle");
+    let connection = connector
+        .get_non_visual_connection_shape_properties()
+        .get_non_visual_connector_shape_drawing_properties();
+    assert_eq!(connection.get_start_connection().unwrap().get_id(), &ids[0]);
+    assert_eq!(connection.get_end_connection().unwrap().get_id(), &ids[1]);
+}
+
+#[test]
+fn group_shape_and_z_order() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    let mut group = umya_spreadsheet::structs::drawing::spreadsheet::GroupShape::default();
+    group.new_group();
+    let mut shape = umya_spreadsheet::structs::drawing::spreadsheet::Shape::default();
+    shape.new_shape(umya_spreadsheet::structs::ShapeType::Rectangle);
+    group.add_shape(shape, 0, 0, 914400, 457200);
+    let mut shape = umya_spreadsheet::structs::drawing::spreadsheet::Shape::default();
+    shape.new_shape(umya_spreadsheet::structs::ShapeType::Ellipse);
+    group.add_shape(shape, 1828800, 0, 914400, 457200);
+    let mut connector = umya_spreadsheet::structs::drawing::spreadsheet::ConnectionShape::default();
+    connector.new_connector(umya_spreadsheet::structs::ConnectorType::Straight);
+    group.add_connector(connector, 914400, 228600, 914400, 0);
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("D2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("H6");
+    worksheet.add_group_shape(group, from_marker, to_marker);
+
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B2");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    worksheet.add_image(image);
+
+    // The group was added first, so it is behind the image until it is brought to front.
+    worksheet.bring_to_front("D2");
+
+    let path = std::path::Path::new("./tests/result_files/group_shape.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let group_list = worksheet.get_group_shape_collection();
+    assert_eq!(group_list.len(), 1);
+    assert_eq!(group_list[0].get_shape_collection().len(), 2);
+    assert_eq!(group_list[0].get_connection_shape_collection().len(), 1);
+
+    let image_z_order = worksheet
+        .get_image("B2")
+        .unwrap()
+        .get_one_cell_anchor()
+        .unwrap()
+        .get_z_order();
+    let group_z_order = worksheet
+        .get_image("D2")
+        .unwrap()
+        .get_two_cell_anchor()
+        .unwrap()
+        .get_z_order();
+    assert!(group_z_order > image_z_order);
+}
+
+#[test]
+fn form_control_button() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("D4");
+    let mut button = umya_spreadsheet::structs::FormControl::default();
+    button
+        .new_button(from_marker, to_marker)
+        .set_text("Run")
+        .set_macro_name("Macro1");
+    worksheet.add_form_control(button);
+    worksheet.get_cell_mut("F1").set_value("Key");
+    worksheet.get_cell_mut("F2").set_value("a");
+    let mut table = umya_spreadsheet::structs::Table::new("Table1", ("F1", "F2"));
+    table.add_column(umya_spreadsheet::structs::TableColumn::new("Key"));
+    worksheet.add_table(table);
+
+    let path = std::path::Path::new("./tests/result_files/form_control.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    // controls come before tableParts, and refer to the ctrlProp part.
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let mut sheet_xml = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/worksheets/sheet1.xml").unwrap(),
+        &mut sheet_xml,
+    )
+    .unwrap();
+    let mut sheet_rels = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/worksheets/_rels/sheet1.xml.rels")
+            .unwrap(),
+        &mut sheet_rels,
+    )
+    .unwrap();
+    assert!(sheet_xml.find("<controls").unwrap() < sheet_xml.find("<tableParts").unwrap());
+    let control = &sheet_xml[sheet_xml.find("<control ").unwrap()..];
+    let r_id = &control[control.find("r:id=\"").unwrap() + 6..];
+    let r_id = &r_id[..r_id.find('"').unwrap()];
+    let relationship = &sheet_rels[sheet_rels.find(&format!("Id=\"{}\"", r_id)).unwrap()..];
+    let relationship = &relationship[..relationship.find("/>").unwrap()];
+    assert!(relationship.contains("ctrlProp"));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let form_controls = worksheet.get_form_controls();
+    assert_eq!(form_controls.len(), 1);
+    assert_eq!(form_controls[0].get_text(), "Run");
+    assert_eq!(form_controls[0].get_macro_name(), "Macro1");
+    assert_eq!(form_controls[0].get_coordinate(), "B2");
+}
+
+#[test]
+fn vml_shape_ids_per_sheet() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Sheet2").unwrap();
+    for (index, text) in ["One", "Two"].iter().enumerate() {
+        let mut from_marker =
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate("B2");
+        let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        to_marker.set_coordinate("D4");
+        let mut button = umya_spreadsheet::structs::FormControl::default();
+        button.new_button(from_marker, to_marker).set_text(*text);
+        book.get_sheet_mut(&index).unwrap().add_form_control(button);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/vml_shape_ids_per_sheet.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    // each sheet gets its own block of shape ids.
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let read_part = |archive: &mut zip::ZipArchive<std::fs::File>, name: &str| {
+        let mut xml = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut xml).unwrap();
+        xml
+    };
+    let vml = read_part(&mut archive, "xl/drawings/vmlDrawing2.vml");
+    assert!(vml.contains(r#"data="2""#));
+    assert!(vml.contains(r#"id="_x0000_s2049""#));
+    let sheet_xml = read_part(&mut archive, "xl/worksheets/sheet2.xml");
+    assert!(sheet_xml.contains(r#"shapeId="2049""#));
+    let vml = read_part(&mut archive, "xl/drawings/vmlDrawing1.vml");
+    assert!(vml.contains(r#"data="1""#));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let form_controls = book.get_sheet(&1).unwrap().get_form_controls();
+    assert_eq!(form_controls.len(), 1);
+    assert_eq!(form_controls[0].get_text(), "Two");
+}
+
+#[test]
+fn form_control_check_box_and_option_button() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("D3");
+    let mut check_box = umya_spreadsheet::structs::FormControl::default();
+    check_box
+        .new_check_box(from_marker, to_marker)
+        .set_text("Enabled")
+        .set_link_cell("$A$1")
+        .set_checked(true);
+    worksheet.add_form_control(check_box);
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B5");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("D6");
+    let mut option_button = umya_spreadsheet::structs::FormControl::default();
+    option_button
+        .new_option_button(from_marker, to_marker)
+        .set_text("Option")
+        .set_link_cell("$A$2");
+    worksheet.add_form_control(option_button);
+
+    let path = std::path::Path::new("./tests/result_files/form_control_check_box.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let form_controls = worksheet.get_form_controls();
+    assert_eq!(form_controls.len(), 2);
+    assert_eq!(
+        form_controls[0].get_control_type(),
+        &umya_spreadsheet::structs::FormControlValues::CheckBox
+    );
+    assert_eq!(form_controls[0].get_text(), "Enabled");
+    assert_eq!(form_controls[0].get_link_cell(), "$A$1");
+    assert!(*form_controls[0].get_checked());
+    assert_eq!(
+        form_controls[1].get_control_type(),
+        &umya_spreadsheet::structs::FormControlValues::OptionButton
+    );
+    assert_eq!(form_controls[1].get_link_cell(), "$A$2");
+    assert!(!*form_controls[1].get_checked());
+
+    // the ctrlProp part is found from the control, not from the order of the relationships.
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let path = std::path::Path::new("./tests/result_files/form_control_check_box_rels.xlsx");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let mut data = Vec::new();
+        std::io::Read::read_to_end(&mut file, &mut data).unwrap();
+        if file.name() == "xl/worksheets/_rels/sheet1.xml.rels" {
+            let xml = String::from_utf8(data).unwrap();
+            let relationships: Vec<&str> = xml
+                .split("<Relationship ")
+                .filter(|v| v.contains("ctrlProp"))
+                .map(|v| &v[..v.find("/>").unwrap() + 2])
+                .collect();
+            assert_eq!(relationships.len(), 2);
+            data = xml
+                .replace(relationships[0], "{0}")
+                .replace(relationships[1], relationships[0])
+                .replace("{0}", relationships[1])
+                .into_bytes();
+        }
+        zip.start_file(
+            file.name().to_string(),
+            zip::write::SimpleFileOptions::default(),
+        )
+        .unwrap();
+        std::io::Write::write_all(&mut zip, &data).unwrap();
+    }
+    zip.finish().unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let form_controls = book.get_sheet(&0).unwrap().get_form_controls();
+    assert_eq!(
+        form_controls[0].get_control_type(),
+        &umya_spreadsheet::structs::FormControlValues::CheckBox
+    );
+    assert_eq!(form_controls[1].get_link_cell(), "$A$2");
+}
+
+#[test]
+fn embed_file_as_ole_object() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("C5");
+    worksheet
+        .get_ole_objects_mut()
+        .embed_file(
+            "memo.txt",
+            b"umya-spreadsheet",
+            None,
+            "memo.txt",
+            from_marker,
+            to_marker,
+        )
+        .unwrap();
+
+    let path = std::path::Path::new("./tests/result_files/embed_file.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    let ole_objects = worksheet.get_ole_objects().get_ole_object();
+    assert_eq!(ole_objects.len(), 1);
+    assert_eq!(ole_objects[0].get_prog_id(), "Package");
+    assert_eq!(ole_objects[0].get_object_extension(), "bin");
+    assert!(ole_objects[0]
+        .get_embedded_object_properties()
+        .get_image()
+        .get_image_name()
+        .ends_with(".emf"));
+}
+
+#[test]
+fn extract_embedded_file() {
+    let path = std::path::Path::new("./tests/result_files/embed_file_extract.xlsx");
+    let mut book = umya_spreadsheet::new_file();
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("B2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("C5");
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_ole_objects_mut()
+        .embed_file(
+            "report.pdf",
+            b"%PDF-1.4 sample",
+            None,
+            "Report",
+            from_marker,
+            to_marker,
+        )
+        .unwrap();
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let ole_object = &book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_ole_objects()
+        .get_ole_object()[0];
+    assert_eq!(
+        ole_object.get_embedded_file_name(),
+        Some(String::from("report.pdf"))
+    );
+    assert_eq!(
+        ole_object.get_embedded_file_data(),
+        Some(b"%PDF-1.4 sample".to_vec())
+    );
+    assert_eq!(
+        ole_object.get_embedded_file_type(),
+        Some(String::from("pdf"))
+    );
+
+    // the storage is marked as a Packager object.
+    let comp = cfb::CompoundFile::open(std::io::Cursor::new(ole_object.get_object_data().unwrap()))
+        .unwrap();
+    assert_eq!(
+        comp.root_entry().clsid().to_string(),
+        "0003000c-0000-0000-c000-000000000046"
+    );
+
+    // malformed data is returned as is.
+    let mut native = vec![0u8; 4];
+    native.extend_from_slice(&2u16.to_le_bytes());
+    native.extend_from_slice(b"a\0b\0\0\0\x03\0");
+    native.extend_from_slice(&u32::MAX.to_le_bytes());
+    let mut comp = cfb::CompoundFile::create(std::io::Cursor::new(Vec::new())).unwrap();
+    std::io::Write::write_all(
+        &mut comp.create_stream("\u{1}Ole10Native").unwrap(),
+        &native,
+    )
+    .unwrap();
+    comp.flush().unwrap();
+    let data = comp.into_inner().into_inner();
+    let (file_name, file_data) = umya_spreadsheet::helper::ole::get_embedded_file(&data);
+    assert_eq!(file_name, "");
+    assert_eq!(file_data, data);
+
+    let mut ole_object = umya_spreadsheet::structs::OleObject::default();
+    assert!(ole_object
+        .embed_file_from_path(
+            "./tests/test_files/not_found.pdf",
+            None,
+            "Report",
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default(),
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default(),
+        )
+        .is_err());
+}
+
+#[test]
+fn inject_vba_project() {
+    let source = umya_spreadsheet::reader::xlsx::read("./tests/test_files/aaa.xlsm").unwrap();
+    let vba_project = source.get_vba_project().unwrap().clone();
+
+    let mut book = umya_spreadsheet::new_file();
+    book.set_vba_project(vba_project.clone());
+    let path = std::path::Path::new("./tests/result_files/vba_project.xlsm");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(book.get_has_macros());
+    assert_eq!(book.get_vba_project(), Some(&vba_project));
+}
+
+#[test]
+fn write_and_read_template() {
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value("template");
+    let path = std::path::Path::new("./tests/result_files/template.xltx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(*book.get_is_template());
+
+    let book = umya_spreadsheet::reader::xlsx::read_template(path).unwrap();
+    assert!(!*book.get_is_template());
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "template");
+}
+
+#[test]
+fn document_properties() {
+    let mut book = umya_spreadsheet::new_file();
+    book.get_properties_mut()
+        .set_title("Report")
+        .set_subject("Sales")
+        .set_author("Alice")
+        .set_keywords("sales,2024")
+        .set_created("2024-01-02T03:04:05Z")
+        .set_modified("2024-02-03T04:05:06Z")
+        .set_company("ACME")
+        .set_manager("Bob")
+        .set_application("umya-spreadsheet");
+    let path = std::path::Path::new("./tests/result_files/document_properties.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let properties = book.get_properties();
+    assert_eq!(properties.get_title(), "Report");
+    assert_eq!(properties.get_subject(), "Sales");
+    assert_eq!(properties.get_author(), "Alice");
+    assert_eq!(properties.get_creator(), "Alice");
+    assert_eq!(properties.get_keywords(), "sales,2024");
+    assert_eq!(properties.get_created(), "2024-01-02T03:04:05Z");
+    assert_eq!(properties.get_modified(), "2024-02-03T04:05:06Z");
+    assert_eq!(properties.get_company(), "ACME");
+    assert_eq!(properties.get_manager(), "Bob");
+    assert_eq!(properties.get_application(), "umya-spreadsheet");
+    assert_eq!(properties.get_app_version(), "14.0300");
+}
+
+#[test]
+fn workbook_view() {
+    let mut book = umya_spreadsheet::new_file();
+    let _ = book.new_sheet("Sheet2");
+    let _ = book.new_sheet("Sheet3");
+    book.set_active_sheet(2);
+    book.get_workbook_view_mut()
+        .set_first_sheet(1)
+        .set_x_window(0)
+        .set_y_window(0)
+        .set_window_width(20000)
+        .set_window_height(12000)
+        .set_tab_ratio(750);
+    let path = std::path::Path::new("./tests/result_files/workbook_view.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let view = book.get_workbook_view();
+    assert_eq!(view.get_active_tab(), &2);
+    assert_eq!(view.get_first_sheet(), &1);
+    assert_eq!(view.get_x_window(), &0);
+    assert_eq!(view.get_window_width(), &20000);
+    assert_eq!(view.get_window_height(), &12000);
+    assert_eq!(view.get_tab_ratio(), &750);
+    assert_eq!(book.get_active_sheet().get_name(), "Sheet3");
+}
+
+#[test]
+fn ignored_errors() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value_string("0001");
+    let mut ignored_error = umya_spreadsheet::IgnoredError::default();
+    ignored_error
+        .set_sqref("A1:A10 C1")
+        .set_number_stored_as_text(true);
+    worksheet
+        .get_ignored_errors_mut()
+        .add_ignored_error_list(ignored_error);
+    let path = std::path::Path::new("./tests/result_files/ignored_errors.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let list = worksheet.get_ignored_errors().get_ignored_error_list();
+    assert_eq!(list.len(), 1);
+    assert_eq!(
+        list[0].get_sequence_of_references().get_sqref(),
+        "A1:A10 C1"
+    );
+    assert!(*list[0].get_number_stored_as_text());
+    assert!(!*list[0].get_formula());
+
+    worksheet.insert_new_row(&1, &2);
+    assert_eq!(
+        worksheet.get_ignored_errors().get_ignored_error_list()[0]
+            .get_sequence_of_references()
+            .get_sqref(),
+        "A3:A12 C3"
+    );
+}
+
+#[test]
+fn phonetic_run() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut run = umya_spreadsheet::PhoneticRun::default();
+    run.set_text("トウキョウ").set_start_base(0).set_end_base(2);
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value_with_phonetic("東京", vec![run]);
+    let path = std::path::Path::new("./tests/result_files/phonetic_run.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let cell = book.get_sheet(&0).unwrap().get_cell("A1").unwrap();
+    assert_eq!(cell.get_value(), "東京");
+    let runs = cell.get_cell_value().get_phonetic_runs().unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].get_text(), "トウキョウ");
+    assert_eq!(runs[0].get_start_base(), &0);
+    assert_eq!(runs[0].get_end_base(), &2);
+}
+
+#[test]
+fn rich_data_metadata_round_trip() {
+    let path = std::path::Path::new("./tests/test_files/issue_206.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let cell = book.get_sheet(&0).unwrap().get_cell("J24").unwrap();
+    assert_eq!(cell.get_cell_meta_index(), &1);
+
+    let path = std::path::Path::new("./tests/result_files/rich_data_metadata.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let cell = book.get_sheet(&0).unwrap().get_cell("J24").unwrap();
+    assert_eq!(cell.get_cell_meta_index(), &1);
+}
+
+#[test]
+fn in_cell_image() {
+    let mut book = umya_spreadsheet::new_file();
+    let data = std::fs::read("./images/sample1.png").unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("B2").set_image(&data);
+    worksheet.get_cell_mut("B3").set_image(&data);
+    assert!(worksheet.get_cell("B2").unwrap().get_image().is_some());
+    let path = std::path::Path::new("./tests/result_files/in_cell_image.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_cell("B2").unwrap().get_value_meta_index(), &1);
+    assert_eq!(worksheet.get_cell("B3").unwrap().get_value_meta_index(), &2);
+    assert!(worksheet
+        .get_cell("B2")
+        .unwrap()
+        .get_cell_value()
+        .is_error());
+
+    // images added to a workbook with rich data are appended to it.
+    let mut book = book;
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("B4")
+        .set_image(&std::fs::read("./images/sample2.png").unwrap());
+    let path = std::path::Path::new("./tests/result_files/in_cell_image_2.xlsx");
+    assert!(umya_spreadsheet::writer::xlsx::write(&book, path).is_ok());
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_cell("B2").unwrap().get_value_meta_index(), &1);
+    assert_eq!(worksheet.get_cell("B4").unwrap().get_value_meta_index(), &3);
+    let metadata = book.get_metadata().unwrap();
+    assert_eq!(
+        metadata.get_metadata_types(),
+        &vec![String::from("XLRICHVALUE")]
+    );
+    assert_eq!(metadata.get_value_metadata().len(), 3);
+    assert_eq!(
+        metadata
+            .get_value_metadata_by_index(&3)
+            .unwrap()
+            .get_value_index(),
+        &2
+    );
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut rich_value = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/richData/rdrichvalue.xml").unwrap(),
+        &mut rich_value,
+    )
+    .unwrap();
+    assert!(rich_value.contains(r#"count="3""#));
+    let mut rels = String::new();
+    std::io::Read::read_to_string(
+        &mut archive
+            .by_name("xl/richData/_rels/richValueRel.xml.rels")
+            .unwrap(),
+        &mut rels,
+    )
+    .unwrap();
+    assert_eq!(rels.matches("<Relationship ").count(), 3);
+
+    // or to the cell metadata of dynamic array formulas.
+    let path = std::path::Path::new("./tests/test_files/issue_206.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("A30")
+        .set_image(&std::fs::read("./images/sample1.png").unwrap());
+    let path = std::path::Path::new("./tests/result_files/in_cell_image_3.xlsx");
+    assert!(umya_spreadsheet::writer::xlsx::write(&book, path).is_ok());
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let metadata = book.get_metadata().unwrap();
+    assert_eq!(
+        metadata.get_metadata_types(),
+        &vec![String::from("XLDAPR"), String::from("XLRICHVALUE")]
+    );
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert!(metadata.is_dynamic_array(worksheet.get_cell("J24").unwrap().get_cell_meta_index()));
+    let record = metadata
+        .get_value_metadata_by_index(worksheet.get_cell("A30").unwrap().get_value_meta_index())
+        .unwrap();
+    assert_eq!(record.get_type_name(), "XLRICHVALUE");
+    assert_eq!(record.get_value_index(), &0);
+}
+
+#[test]
+fn query_table_and_connections() {
+    let path = std::path::Path::new("./tests/test_files/query_table.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let connection = &book.get_connections()[0];
+    assert_eq!(connection.get_id(), &1);
+    assert_eq!(connection.get_name(), "Query - Query1");
+    assert_eq!(connection.get_command(), "SELECT * FROM [Query1]");
+    assert_eq!(connection.get_connection_type(), &5);
+    assert!(!connection.get_refresh_on_load());
+    let table = &book.get_sheet(&0).unwrap().get_tables()[0];
+    let query_table = table.get_query_table().unwrap();
+    assert_eq!(query_table.get_name(), "Query1");
+    assert_eq!(query_table.get_connection_id(), &1);
+    assert!(query_table.get_refresh_on_load());
+
+    let path = std::path::Path::new("./tests/result_files/query_table.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_connections().len(), 1);
+    let table = &book.get_sheet(&0).unwrap().get_tables()[0];
+    assert_eq!(table.get_columns()[0].get_query_table_field_id(), Some(&1));
+    assert_eq!(table.get_query_table().unwrap().get_name(), "Query1");
+}
+
+#[test]
+fn read_malformed_file() {
+    let path = std::path::Path::new("./tests/test_files/broken_sheet.xlsx");
+    let result = umya_spreadsheet::reader::xlsx::read(path);
+    assert!(matches!(
+        result,
+        Err(umya_spreadsheet::XlsxError::Format(_)) | Err(umya_spreadsheet::XlsxError::Xml(_))
+    ));
+
+    let mut book = umya_spreadsheet::reader::xlsx::lazy_read(path).unwrap();
+    assert!(book.try_read_sheet_collection().is_err());
+    assert!(book.try_read_sheet(0).is_err());
+    assert!(book.try_get_sheet_mut(&0).is_err());
+    assert!(matches!(book.try_get_sheet_mut(&9), Ok(None)));
+}
+
+#[test]
+fn read_malformed_attribute() {
+    let book = umya_spreadsheet::new_file();
+    let path = std::path::Path::new("./tests/result_files/malformed_attribute_src.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let path = std::path::Path::new("./tests/result_files/malformed_attribute.xlsx");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let mut data = Vec::new();
+        std::io::Read::read_to_end(&mut file, &mut data).unwrap();
+        if file.name() == "xl/worksheets/sheet1.xml" {
+            let xml = String::from_utf8(data).unwrap();
+            assert!(xml.contains("workbookViewId=\"0\""));
+            data = xml
+                .replace("workbookViewId=\"0\"", "workbookViewId=\"abc\"")
+                .into_bytes();
+        }
+        zip.start_file(
+            file.name().to_string(),
+            zip::write::SimpleFileOptions::default(),
+        )
+        .unwrap();
+        std::io::Write::write_all(&mut zip, &data).unwrap();
+    }
+    zip.finish().unwrap();
+
+    assert!(matches!(
+        umya_spreadsheet::reader::xlsx::read(path),
+        Err(umya_spreadsheet::XlsxError::Format(_))
+    ));
+
+    let book = umya_spreadsheet::reader::xlsx::read_with_recovery(path).unwrap();
+    let warnings = book.get_read_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("sheet1.xml"));
+    assert!(warnings[0].contains("abc"));
+    assert_eq!(book.get_sheet_count(), 1);
+}
+
+#[test]
+fn read_with_recovery() {
+    let path = std::path::Path::new("./tests/test_files/missing_part.xlsx");
+    assert!(umya_spreadsheet::reader::xlsx::read(path).is_err());
+    assert!(umya_spreadsheet::reader::xlsx::lazy_read(path).is_err());
+    let book = umya_spreadsheet::reader::xlsx::read_with_recovery(path).unwrap();
+    assert_eq!(book.get_read_warnings().len(), 1);
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert!(worksheet.get_tables().is_empty());
+    assert_eq!(worksheet.get_value("A1"), "table");
+
+    let path = std::path::Path::new("./tests/test_files/broken_sheet.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read_with_recovery(path).unwrap();
+    assert!(!book.get_read_warnings().is_empty());
+    assert_eq!(book.get_sheet_count(), 1);
+
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read_with_recovery(path).unwrap();
+    assert!(book.get_read_warnings().is_empty());
+}
+
+#[test]
+fn validate_workbook() {
+    use umya_spreadsheet::ValidationIssueKind;
+
+    let mut book = umya_spreadsheet::new_file();
+    assert!(book.validate().is_empty());
+
+    let _ = book.new_sheet("sheet1");
+    let _ = book.new_sheet("a/b");
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.add_merge_cells("A1:B2");
+    worksheet.add_merge_cells("B2:C3");
+    worksheet.add_merge_cells("D1:E2");
+    worksheet.get_cell_mut((16385, 1)).set_value("x");
+
+    let kinds: Vec<ValidationIssueKind> = book
+        .validate()
+        .iter()
+        .map(|v| v.get_kind().clone())
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ValidationIssueKind::DuplicateSheetName,
+            ValidationIssueKind::InvalidSheetName,
+            ValidationIssueKind::OutOfRangeReference,
+            ValidationIssueKind::OverlappingMergeCells,
+        ]
+    );
+}
+
+#[test]
+fn unknown_part_passthrough() {
+    let path = std::path::Path::new("./tests/test_files/unknown_part.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let names = book.get_unknown_part_names();
+    assert!(names.contains(&"customXml/item1.xml"));
+    assert!(names.contains(&"customUI/customUI14.xml"));
+
+    let path = std::path::Path::new("./tests/result_files/unknown_part.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let names = book.get_unknown_part_names();
+    assert!(names.contains(&"customXml/item1.xml"));
+    assert!(names.contains(&"customUI/customUI14.xml"));
+}
+
+#[test]
+fn compact_cell_value() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    for cell in worksheet.get_cell_collection() {
+        if cell.get_data_type() == "n" {
+            assert!(cell.get_value_number().is_some());
+        }
+    }
+}
+
+#[test]
+fn shared_style_handle() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let mut style = umya_spreadsheet::Style::default();
+    style.set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    worksheet.set_style_by_range("A1:A1000", style);
+
+    let a1 = worksheet.get_cell("A1").unwrap().get_style_shared().clone();
+    let a1000 = worksheet
+        .get_cell("A1000")
+        .unwrap()
+        .get_style_shared()
+        .clone();
+    assert!(std::sync::Arc::ptr_eq(&a1, &a1000));
+
+    // copy on write
+    worksheet
+        .get_style_mut("A2")
+        .set_background_color(umya_spreadsheet::Color::COLOR_BLUE);
+    assert_eq!(
+        worksheet
+            .get_style("A1")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_RED
+    );
+    assert_eq!(
+        worksheet
+            .get_style("A2")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_BLUE
+    );
+}
+
+#[test]
+fn coordinate_and_cell_range() {
+    let coordinate: umya_spreadsheet::Coordinate = "$AB$12".parse().unwrap();
+    assert_eq!(coordinate.get_col_num(), &28);
+    assert_eq!(coordinate.get_row_num(), &12);
+    assert_eq!(coordinate.to_string(), "$AB$12");
+    assert!("A0".parse::<umya_spreadsheet::Coordinate>().is_err());
+    assert!("1A".parse::<umya_spreadsheet::Coordinate>().is_err());
+
+    let range: umya_spreadsheet::CellRange = "A1:B2".parse().unwrap();
+    assert_eq!(range.to_string(), "A1:B2");
+    assert_eq!(
+        range.get_coordinate_list(),
+        vec![(1, 1), (2, 1), (1, 2), (2, 2)]
+    );
+    assert!(range.contains((2, 2)));
+    assert!(!range.contains((3, 1)));
+    assert!("A1:B2:C3".parse::<umya_spreadsheet::CellRange>().is_err());
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut(&coordinate).set_value("test");
+    assert_eq!(worksheet.get_value((28, 12)), "test");
+    let mut style = umya_spreadsheet::Style::default();
+    style.set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    worksheet.set_style_by_range(&range, style);
+    assert!(worksheet.get_style("B2").get_background_color().is_some());
+}
+
+#[test]
+fn index_sugar() {
+    let mut book = umya_spreadsheet::new_file();
+    book["Sheet1"]["B3"].set_value("Hello");
+    book[0][(3, 3)].set_value_number(1);
+    assert_eq!(book["Sheet1"]["B3"].get_value(), "Hello");
+    assert_eq!(book[0]["C3"].get_value(), "1");
+}
+
+#[test]
+fn iterate_sheets_and_cells() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Sheet2").unwrap();
+    for sheet in &mut book {
+        sheet.get_cell_mut("A1").set_value("a");
+        sheet.get_cell_mut("B2").set_value("b");
+    }
+    for sheet in &mut book {
+        for cell in sheet {
+            cell.set_value("c");
+        }
+    }
+    let count = (&book)
+        .into_iter()
+        .flatten()
+        .filter(|cell| cell.get_value() == "c")
+        .count();
+    assert_eq!(count, 4);
+
+    // a lazily read book can be iterated too.
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::lazy_read(path).unwrap();
+    let sheet_count = (&book).into_iter().count();
+    assert_eq!(sheet_count, book.get_sheet_count());
+    let sheet_count_mut = (&mut book).into_iter().count();
+    assert_eq!(sheet_count_mut, sheet_count);
+    assert_eq!(book.get_sheet_collection().len(), sheet_count);
+}
+
+#[test]
+fn set_and_get_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let rows = vec![vec![1.5, 2.0], vec![3.0, 4.0]];
+    worksheet.set_values("B2", &rows);
+    worksheet.set_values((2, 4), &[vec![Some("x"), None]]);
+
+    assert_eq!(worksheet.get_value_number("C3"), Some(4.0));
+    assert_eq!(worksheet.get_value("B4"), "x");
+
+    let values = worksheet.get_values("B2:C4");
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0][0].to_string(), "1.5");
+    assert_eq!(values[2][1], &umya_spreadsheet::CellRawValue::Empty);
+
+    // whole columns and rows end at the highest row and column.
+    let values = worksheet.get_values("A:C");
+    assert_eq!(values.len(), 4);
+    assert_eq!(values[0].len(), 3);
+    assert_eq!(values[1][1].to_string(), "1.5");
+    assert_eq!(values[3][1].to_string(), "x");
+    let values = worksheet.get_values("3:4");
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0].len(), 3);
+    assert_eq!(values[0][2].to_string(), "4");
+}
+
+#[test]
+fn clear_range_modes() {
+    use umya_spreadsheet::ClearMode;
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    for coordinate in ["A1", "B2", "C3"] {
+        worksheet.get_cell_mut(coordinate).set_value("x");
+        worksheet
+            .get_style_mut(coordinate)
+            .set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    }
+
+    worksheet.clear_range("A1:B2", ClearMode::Contents);
+    assert_eq!(worksheet.get_value("A1"), "");
+    assert!(worksheet.get_style("A1").get_background_color().is_some());
+
+    worksheet.clear_range("A:A", ClearMode::Formats);
+    assert!(worksheet.get_style("A1").get_background_color().is_none());
+    assert!(worksheet.get_style("B2").get_background_color().is_some());
+
+    worksheet.clear_range("3:3", ClearMode::All);
+    assert!(worksheet.get_cell("C3").is_none());
+    assert!(worksheet.get_cell("B2").is_some());
+}
+
+#[test]
+fn remove_sheet_with_references() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Old").unwrap();
+    book.new_sheet("Other").unwrap();
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_formula("SUM(Old!A1:A3)+Sheet1!B1");
+    book.get_sheet_by_name_mut("Other")
+        .unwrap()
+        .add_defined_name("OtherName", "Old!$A$1")
+        .unwrap();
+    let mut scoped = umya_spreadsheet::DefinedName::default();
+    scoped.set_address("Old!$B$1");
+    scoped.set_local_sheet_id(1);
+    book.add_defined_names(scoped);
+    let mut later = umya_spreadsheet::DefinedName::default();
+    later.set_address("Other!$B$1");
+    later.set_local_sheet_id(2);
+    book.add_defined_names(later);
+
+    book.remove_sheet_by_name("Old").unwrap();
+
+    assert_eq!(book.get_sheet_count(), 2);
+    let formula = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_cell("A1")
+        .unwrap()
+        .get_formula()
+        .to_string();
+    assert_eq!(formula, "SUM(#REF!)+Sheet1!B1");
+    let other = book.get_sheet_by_name("Other").unwrap();
+    assert_eq!(other.get_defined_names()[0].get_address(), "#REF!");
+    assert_eq!(book.get_defined_names().len(), 1);
+    assert_eq!(book.get_defined_names()[0].get_local_sheet_id(), &1);
+}
+
+#[test]
+fn rename_sheet_with_references() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Old").unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    worksheet.get_cell_mut("A1").set_formula("SUM(Old!A1:A3)");
+
+    let mut formula = umya_spreadsheet::Formula::default();
+    formula.set_string_value("Old!$A$1>0");
+    let mut rule = umya_spreadsheet::ConditionalFormattingRule::default();
+    rule.set_type(umya_spreadsheet::ConditionalFormatValues::Expression)
+        .set_formula(formula);
+    let mut conditional_formatting = umya_spreadsheet::ConditionalFormatting::default();
+    conditional_formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("B1");
+    conditional_formatting.add_conditional_collection(rule);
+    worksheet.add_conditional_formatting_collection(conditional_formatting);
+
+    let mut data_validation = umya_spreadsheet::DataValidation::default();
+    data_validation
+        .set_type(umya_spreadsheet::DataValidationValues::List)
+        .set_formula1("Old!$A$1:$A$3");
+    data_validation
+        .get_sequence_of_references_mut()
+        .set_sqref("C1");
+    let mut data_validations = umya_spreadsheet::DataValidations::default();
+    data_validations.add_data_validation_list(data_validation);
+    worksheet.set_data_validations(data_validations);
+
+    let mut defined_name = umya_spreadsheet::DefinedName::default();
+    defined_name.set_address("Old!$B$1");
+    book.add_defined_names(defined_name);
+
+    book.set_sheet_name(1, "New").unwrap();
+
+    let worksheet = book.get_sheet_by_name("Sheet1").unwrap();
+    assert_eq!(
+        worksheet.get_cell("A1").unwrap().get_formula(),
+        "SUM(New!A1:A3)"
+    );
+    let rule =
+        &worksheet.get_conditional_formatting_collection()[0].get_conditional_collection()[0];
+    assert_eq!(rule.get_formula().unwrap().get_address_str(), "New!$A$1>0");
+    let data_validation = &worksheet
+        .get_data_validations()
+        .unwrap()
+        .get_data_validation_list()[0];
+    assert_eq!(data_validation.get_formula1(), "New!$A$1:$A$3");
+    assert_eq!(book.get_defined_names()[0].get_address(), "'New'!$B$1");
+    assert!(book.get_sheet_by_name("New").is_some());
+}
+
+#[test]
+fn copy_row_and_column_style() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_row_dimension_mut(&2).set_height(25.0);
+    worksheet
+        .get_style_mut("B2")
+        .set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    worksheet.copy_row_style(&2, &3, &2);
+    assert_eq!(worksheet.get_row_dimension(&4).unwrap().get_height(), &25.0);
+    assert!(worksheet.get_style("B4").get_background_color().is_some());
+    assert!(worksheet.get_style("B5").get_background_color().is_none());
+
+    worksheet.get_column_dimension_mut("B").set_width(40.0);
+    worksheet.copy_column_style(&2, &3, &1);
+    assert_eq!(
+        worksheet.get_column_dimension("C").unwrap().get_width(),
+        &40.0
+    );
+    assert!(worksheet.get_style("C2").get_background_color().is_some());
+}
+
+#[test]
+fn row_and_column_default_style() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_column_dimension_mut("C")
+        .get_style_mut()
+        .set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    worksheet
+        .get_row_dimension_mut(&5)
+        .get_style_mut()
+        .set_background_color(umya_spreadsheet::Color::COLOR_BLUE);
+
+    assert_eq!(
+        worksheet
+            .get_style("C1")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_RED
+    );
+    worksheet.get_cell_mut("C2").set_value("x");
+    worksheet.get_cell_mut("A5").set_value("y");
+    assert_eq!(
+        worksheet
+            .get_cell("C2")
+            .unwrap()
+            .get_style()
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_RED
+    );
+    assert_eq!(
+        worksheet
+            .get_cell("A5")
+            .unwrap()
+            .get_style()
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_BLUE
+    );
+    assert!(worksheet.get_style("A1").get_background_color().is_none());
+}
+
+#[test]
+fn border_around_and_inside() {
+    use umya_spreadsheet::BorderStyleValues;
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.set_border_around(
+        "B2:D4",
+        BorderStyleValues::Medium,
+        umya_spreadsheet::Color::default(),
+    );
+    worksheet.set_border_inside(
+        "B2:D4",
+        BorderStyleValues::Thin,
+        umya_spreadsheet::Color::default(),
+    );
+
+    let borders = worksheet.get_style("B2").get_borders().unwrap();
+    assert_eq!(borders.get_left().get_style(), &BorderStyleValues::Medium);
+    assert_eq!(borders.get_top().get_style(), &BorderStyleValues::Medium);
+    assert_eq!(borders.get_right().get_style(), &BorderStyleValues::Thin);
+    assert_eq!(borders.get_bottom().get_style(), &BorderStyleValues::Thin);
+
+    let borders = worksheet.get_style("C3").get_borders().unwrap();
+    assert_eq!(borders.get_left().get_style(), &BorderStyleValues::Thin);
+    assert_eq!(borders.get_right().get_style(), &BorderStyleValues::Thin);
+
+    let borders = worksheet.get_style("D4").get_borders().unwrap();
+    assert_eq!(borders.get_right().get_style(), &BorderStyleValues::Medium);
+    assert_eq!(borders.get_bottom().get_style(), &BorderStyleValues::Medium);
+
+    assert!(worksheet.get_style("E5").get_borders().is_none());
+}
+
+#[test]
+fn conditional_helpers() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let mut style = umya_spreadsheet::Style::default();
+    style.set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    worksheet.add_conditional_duplicates("a:a", style.clone());
+    worksheet.add_conditional_formula("B2:B100", "=$B2>100", style);
+
+    let list = worksheet.get_conditional_formatting_collection();
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].get_sequence_of_references().get_sqref(), "A:A");
+    let rule = &list[0].get_conditional_collection()[0];
+    assert_eq!(
+        rule.get_type(),
+        &umya_spreadsheet::ConditionalFormatValues::DuplicateValues
+    );
+    assert_eq!(rule.get_priority(), &1);
+
+    let rule = &list[1].get_conditional_collection()[0];
+    assert_eq!(
+        rule.get_type(),
+        &umya_spreadsheet::ConditionalFormatValues::Expression
+    );
+    assert_eq!(rule.get_priority(), &2);
+    assert_eq!(rule.get_formula().unwrap().get_address_str(), "$B2>100");
+
+    let path = std::path::Path::new("./tests/result_files/conditional_helpers.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn locale_parse_and_format() {
+    let mut locale = umya_spreadsheet::Locale::default();
+    locale
+        .set_decimal_separator(',')
+        .set_thousands_separator('.')
+        .set_list_separator(';')
+        .set_currency_symbol("€");
+    let mut month_names: Vec<String> = vec![String::from("Januar")];
+    month_names.extend(locale.get_month_names()[1..].iter().cloned());
+    locale.set_month_names(month_names);
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_value_with_locale("1.234,56", &locale);
+    worksheet
+        .get_cell_mut("B1")
+        .set_value_with_locale("abc", &locale);
+    assert_eq!(worksheet.get_value("A1"), "1234.56");
+    assert_eq!(worksheet.get_value("B1"), "abc");
+
+    worksheet
+        .get_style_mut("A1")
+        .get_number_format_mut()
+        .set_format_code("$#,##0.00");
+    assert_eq!(
+        worksheet
+            .get_cell("A1")
+            .unwrap()
+            .get_formatted_value_with_locale(&locale),
+        "€1.234,56"
+    );
+    assert_eq!(
+        worksheet.get_cell("A1").unwrap().get_formatted_value(),
+        "$1,234.56"
+    );
+
+    // only the separators of the number are localized.
+    assert_eq!(
+        locale.localize_number("1,234.5 pcs., net."),
+        "1.234,5 pcs., net."
+    );
+    assert_eq!(locale.localize_number("-0.25%"), "-0,25%");
+
+    worksheet.get_cell_mut("C1").set_value_number(45306);
+    worksheet
+        .get_style_mut("C1")
+        .get_number_format_mut()
+        .set_format_code("mmmm d");
+    assert_eq!(
+        worksheet
+            .get_cell("C1")
+            .unwrap()
+            .get_formatted_value_with_locale(&locale),
+        "Januar 15"
+    );
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_locale(locale);
+    let path = std::path::Path::new("./tests/result_files/locale.csv");
+    umya_spreadsheet::writer::csv::write(&book, path, Some(&option)).unwrap();
+    let data = std::fs::read_to_string(path).unwrap();
+    assert_eq!(data, "1234,56;abc;45306\r\n");
+}
+
+#[test]
+fn formula_cached_result() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value_number(40);
+    worksheet.get_cell_mut("A2").set_value_number(2);
+    worksheet
+        .get_cell_mut("A3")
+        .set_formula("SUM(A1:A2)")
+        .set_formula_result(42);
+    worksheet
+        .get_cell_mut("B3")
+        .set_formula("\"total\"")
+        .set_formula_result("total");
+    worksheet.get_cell_mut("C3").set_formula("1/0");
+
+    assert_eq!(
+        worksheet.get_cell("A3").unwrap().get_formula(),
+        "SUM(A1:A2)"
+    );
+    assert_eq!(
+        worksheet
+            .get_cell("A3")
+            .unwrap()
+            .get_formula_cached_result(),
+        Some(&umya_spreadsheet::CellRawValue::Numeric(42.0))
+    );
+    assert!(worksheet
+        .get_cell("C3")
+        .unwrap()
+        .get_formula_cached_result()
+        .is_none());
+    assert!(worksheet
+        .get_cell("A1")
+        .unwrap()
+        .get_formula_cached_result()
+        .is_none());
+
+    let path = std::path::Path::new("./tests/result_files/formula_cached_result.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(
+        worksheet.get_cell("A3").unwrap().get_formula(),
+        "SUM(A1:A2)"
+    );
+    assert_eq!(worksheet.get_value("A3"), "42");
+    assert_eq!(worksheet.get_cell("B3").unwrap().get_formula(), "\"total\"");
+    assert_eq!(
+        worksheet
+            .get_cell("B3")
+            .unwrap()
+            .get_formula_cached_result(),
+        Some(&umya_spreadsheet::CellRawValue::String("total".into()))
+    );
+}
+
+#[test]
+fn read_formulas_as_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value_number(1);
+    worksheet
+        .get_cell_mut("A2")
+        .set_formula("A1*2")
+        .set_formula_result(2);
+    let path = std::path::Path::new("./tests/result_files/read_formulas_as_values.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read_values_only(path).unwrap();
+    let cell = book.get_sheet(&0).unwrap().get_cell("A2").unwrap();
+    assert!(!cell.is_formula());
+    assert_eq!(cell.get_value(), "2");
+}
+
+#[test]
+fn freeze_formulas_in_range() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_formula("1+1")
+        .set_formula_result(2);
+    worksheet
+        .get_cell_mut("B1")
+        .set_formula("\"a\"")
+        .set_formula_result("a");
+    worksheet
+        .get_cell_mut("C1")
+        .set_formula("2+2")
+        .set_formula_result(4);
+
+    worksheet.freeze_formulas("A1:B1");
+    assert!(!worksheet.get_cell("A1").unwrap().is_formula());
+    assert_eq!(worksheet.get_value("A1"), "2");
+    assert!(!worksheet.get_cell("B1").unwrap().is_formula());
+    assert_eq!(worksheet.get_value("B1"), "a");
+    assert!(worksheet.get_cell("C1").unwrap().is_formula());
+
+    worksheet.freeze_formulas("C:C");
+    assert!(!worksheet.get_cell("C1").unwrap().is_formula());
+    assert_eq!(worksheet.get_value("C1"), "4");
+}
+
+#[test]
+fn compare_workbooks() {
+    use umya_spreadsheet::compare::{diff, Difference};
+
+    let mut book_a = umya_spreadsheet::new_file();
+    book_a.new_sheet("Old").unwrap();
+    let worksheet = book_a.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value("same");
+    worksheet.get_cell_mut("B2").set_value_number(1);
+    worksheet.get_cell_mut("C3").set_formula("B2*2");
+    worksheet.add_defined_name("Total", "Sheet1!$B$2").unwrap();
+
+    let mut book_b = book_a.clone();
+    book_b.remove_sheet_by_name("Old").unwrap();
+    book_b.new_sheet("New").unwrap();
+    let worksheet = book_b.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("B2").set_value_number(2);
+    worksheet.get_cell_mut("C3").set_formula("B2*3");
+    worksheet.get_style_mut("A1").get_font_mut().set_bold(true);
+    worksheet.get_defined_names_mut().clear();
+    worksheet.add_defined_name("Total", "Sheet1!$B$3").unwrap();
+
+    let differences = diff(&book_a, &book_b);
+    assert_eq!(
+        differences,
+        vec![
+            Difference::SheetRemoved {
+                sheet_name: "Old".to_string()
+            },
+            Difference::SheetAdded {
+                sheet_name: "New".to_string()
+            },
+            Difference::CellStyleChanged {
+                sheet_name: "Sheet1".to_string(),
+                coordinate: "A1".to_string()
+            },
+            Difference::CellValueChanged {
+                sheet_name: "Sheet1".to_string(),
+                coordinate: "B2".to_string(),
+                before: "1".to_string(),
+                after: "2".to_string()
+            },
+            Difference::CellFormulaChanged {
+                sheet_name: "Sheet1".to_string(),
+                coordinate: "C3".to_string(),
+                before: "B2*2".to_string(),
+                after: "B2*3".to_string()
+            },
+            Difference::DefinedNameChanged {
+                name: "Sheet1!Total".to_string(),
+                before: "'Sheet1'!$B$2".to_string(),
+                after: "'Sheet1'!$B$3".to_string()
+            },
+        ]
+    );
+    assert!(diff(&book_a, &book_a).is_empty());
+}
+
+#[test]
+fn merge_workbooks() {
+    let mut book_a = umya_spreadsheet::new_file();
+    book_a
+        .get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value("a");
+
+    let mut book_b = umya_spreadsheet::new_file();
+    book_b.new_sheet("Data").unwrap();
+    let worksheet = book_b.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value("b");
+    worksheet
+        .get_style_mut("A1")
+        .set_background_color(umya_spreadsheet::Color::COLOR_RED);
+    worksheet
+        .get_cell_mut("B1")
+        .set_formula("Sheet1!A1&Data!A1");
+    let mut table = umya_spreadsheet::structs::Table::new("Table1", ("A1", "A2"));
+    table.add_column(umya_spreadsheet::structs::TableColumn::new("b"));
+    worksheet.add_table(table);
+    let mut data_validation = umya_spreadsheet::structs::DataValidation::default();
+    data_validation
+        .set_type(umya_spreadsheet::structs::DataValidationValues::List)
+        .set_formula1("Sheet1!$A$1:$A$3");
+    data_validation
+        .get_sequence_of_references_mut()
+        .set_sqref("C1");
+    let mut data_validations = umya_spreadsheet::structs::DataValidations::default();
+    data_validations.add_data_validation_list(data_validation);
+    worksheet.set_data_validations(data_validations);
+    let mut formula = umya_spreadsheet::structs::Formula::default();
+    formula.set_string_value("Sheet1!$A$1<>\"\"");
+    let mut rule = umya_spreadsheet::structs::ConditionalFormattingRule::default();
+    rule.set_type(umya_spreadsheet::structs::ConditionalFormatValues::Expression)
+        .set_formula(formula);
+    let mut conditional_formatting = umya_spreadsheet::structs::ConditionalFormatting::default();
+    conditional_formatting
+        .get_sequence_of_references_mut()
+        .set_sqref("D1");
+    conditional_formatting.add_conditional_collection(rule);
+    worksheet.add_conditional_formatting_collection(conditional_formatting);
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("E1");
+    to_marker.set_coordinate("H8");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$3"],
+    );
+    worksheet.add_chart(chart);
+    let worksheet = book_a.get_sheet_mut(&0).unwrap();
+    let mut table = umya_spreadsheet::structs::Table::new("Table1", ("A1", "A2"));
+    table.add_column(umya_spreadsheet::structs::TableColumn::new("a"));
+    worksheet.add_table(table);
+
+    let book = umya_spreadsheet::Spreadsheet::merge(
+        &[&book_a, &book_b],
+        &umya_spreadsheet::MergeOption::default(),
+    )
+    .unwrap();
+    assert_eq!(book.get_sheet_count(), 3);
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "a");
+    let worksheet = book.get_sheet_by_name("Sheet1 (2)").unwrap();
+    assert_eq!(worksheet.get_value("A1"), "b");
+    assert_eq!(
+        worksheet
+            .get_style("A1")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_RED
+    );
+    assert_eq!(
+        worksheet.get_cell("B1").unwrap().get_formula(),
+        "'Sheet1 (2)'!A1&Data!A1"
+    );
+    assert_eq!(worksheet.get_tables()[0].get_name(), "Table1_2");
+    assert_eq!(
+        worksheet
+            .get_data_validations()
+            .unwrap()
+            .get_data_validation_list()[0]
+            .get_formula1(),
+        "'Sheet1 (2)'!$A$1:$A$3"
+    );
+    assert_eq!(
+        worksheet.get_conditional_formatting_collection()[0].get_conditional_collection()[0]
+            .get_formula()
+            .unwrap()
+            .get_address_str(),
+        "'Sheet1 (2)'!$A$1<>\"\""
+    );
+    assert_eq!(
+        worksheet.get_chart_collection()[0].get_series_formulas(),
+        vec!["'Sheet1 (2)'!$A$1:$A$3"]
+    );
+    assert_eq!(
+        book.get_sheet(&0).unwrap().get_tables()[0].get_name(),
+        "Table1"
+    );
+    assert!(book.get_sheet_by_name("Data").is_some());
+
+    let mut option = umya_spreadsheet::MergeOption::default();
+    option.set_rename_sheet(false);
+    assert!(umya_spreadsheet::Spreadsheet::merge(&[&book_a, &book_b], &option).is_err());
+}
+
+#[test]
+fn precedents_and_dependents() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Data").unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Data").unwrap();
+    worksheet.get_cell_mut("A1").set_value_number(10);
+    worksheet.get_cell_mut("B1").set_formula("A1*2");
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_cell_mut("D10")
+        .set_formula("SUM(A1:A3)+Data!$A$1+'Data'!B1");
+    worksheet.get_cell_mut("E1").set_formula("SUM(Data!A:A)");
+
+    let precedents: Vec<String> = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_precedents("D10")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(precedents, vec!["Sheet1!A1:A3", "Data!$A$1", "Data!B1"]);
+    assert!(book.get_sheet(&0).unwrap().get_precedents("A1").is_empty());
+
+    let dependents: Vec<String> = book
+        .get_dependents("Data", "A1")
+        .iter()
+        .map(|v| v.get_address())
+        .collect();
+    assert_eq!(dependents, vec!["Sheet1!E1", "Sheet1!D10", "Data!B1"]);
+    let dependents = book.get_dependents("Sheet1", "A2");
+    assert_eq!(dependents.len(), 1);
+    assert_eq!(dependents[0].get_address(), "Sheet1!D10");
+}
+
+#[test]
+fn reanchor_drawings_on_row_changes() {
+    use umya_spreadsheet::structs::drawing::spreadsheet::EditAsValues;
+    use umya_spreadsheet::structs::drawing::spreadsheet::MarkerType;
+
+    let new_chart = |from: &str, to: &str| {
+        let mut from_marker = MarkerType::default();
+        let mut to_marker = MarkerType::default();
+        from_marker.set_coordinate(from);
+        to_marker.set_coordinate(to);
+        let mut chart = umya_spreadsheet::structs::Chart::default();
+        chart.new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$A$1:$A$2"],
+        );
+        chart
+    };
+    let anchor_of = |worksheet: &umya_spreadsheet::Worksheet, index: usize| {
+        let anchor = worksheet.get_chart_collection()[index].get_two_cell_anchor();
+        (
+            anchor.get_from_marker().get_coordinate(),
+            anchor.get_to_marker().get_coordinate(),
+        )
+    };
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.add_chart(new_chart("B3", "D8"));
+    let mut chart = new_chart("F3", "H8");
+    chart
+        .get_two_cell_anchor_mut()
+        .set_edit_as(EditAsValues::Absolute);
+    worksheet.add_chart(chart);
+
+    worksheet.insert_new_row(&1, &2);
+    assert_eq!(anchor_of(worksheet, 0), ("B5".into(), "D10".into()));
+    assert_eq!(anchor_of(worksheet, 1), ("F3".into(), "H8".into()));
+
+    // rows inside the chart shrink it.
+    worksheet.remove_row(&6, &2);
+    assert_eq!(anchor_of(worksheet, 0), ("B5".into(), "D8".into()));
+
+    // the top edge moves to the start of the removed rows.
+    worksheet.remove_row(&4, &3);
+    assert_eq!(anchor_of(worksheet, 0), ("B4".into(), "D5".into()));
+    assert_eq!(worksheet.get_chart_collection().len(), 2);
+
+    // removing every row it covers removes the chart.
+    worksheet.remove_row(&1, &4);
+    assert_eq!(worksheet.get_chart_collection().len(), 1);
+}
+
+#[test]
+fn row_handle() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("E5").set_value("last");
+
+    let mut style = umya_spreadsheet::Style::default();
+    style.get_font_mut().set_bold(true);
+    let mut row = worksheet.get_row_mut(&5);
+    row.set_values(&["a", "b", "c"])
+        .set_height(25.0)
+        .set_style(style)
+        .hide();
+    let values: Vec<String> = row.iter_cells().map(|v| v.get_value().into()).collect();
+    assert_eq!(values, vec!["a", "b", "c", "last"]);
+    for cell in row.iter_cells_mut() {
+        cell.set_value("x");
+    }
+
+    assert_eq!(worksheet.get_value("B5"), "x");
+    assert!(*worksheet.get_style("E5").get_font().unwrap().get_bold());
+    let row_dimension = worksheet.get_row_dimension(&5).unwrap();
+    assert_eq!(row_dimension.get_height(), &25.0);
+    assert!(*row_dimension.get_hidden());
+    assert!(*row_dimension.get_style().get_font().unwrap().get_bold());
+
+    worksheet.get_row_mut(&5).unhide();
+    assert!(!*worksheet.get_row_dimension(&5).unwrap().get_hidden());
+}
+
+#[test]
+fn read_and_write_with_progress() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Sheet2").unwrap();
+    book.get_sheet_mut(&1)
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_value("data");
+
+    let mut write_list: Vec<umya_spreadsheet::Progress> = Vec::new();
+    let path = std::path::Path::new("./tests/result_files/progress.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_progress(
+        &book,
+        path,
+        &umya_spreadsheet::XlsxWriterOption::default(),
+        |progress| write_list.push(progress.clone()),
+    )
+    .unwrap();
+    let parts: Vec<usize> = write_list
+        .iter()
+        .map(|v| *v.get_parts_processed())
+        .collect();
+    assert_eq!(parts, vec![0, 1, 2, 2]);
+    assert!(write_list.iter().all(|v| v.get_parts_total() == &2));
+    assert!(write_list[3].get_bytes() > write_list[2].get_bytes());
+
+    let mut read_list: Vec<umya_spreadsheet::Progress> = Vec::new();
+    let book = umya_spreadsheet::reader::xlsx::read_with_progress(path, |progress| {
+        read_list.push(progress.clone())
+    })
+    .unwrap();
+    let parts: Vec<usize> = read_list.iter().map(|v| *v.get_parts_processed()).collect();
+    assert_eq!(parts, vec![0, 1, 2]);
+    assert!(read_list[2].get_bytes() > read_list[1].get_bytes());
+    assert_eq!(book.get_sheet(&1).unwrap().get_value("A1"), "data");
+}
+
+#[test]
+fn read_with_limits() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.set_values("A1", &[vec![1, 2, 3], vec![4, 5, 6]]);
+    worksheet.get_cell_mut("A3").set_value("a long text");
+    let path = std::path::Path::new("./tests/result_files/read_limits.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+
+    let mut limits = umya_spreadsheet::ReadLimits::default();
+    limits
+        .set_max_part_size(10 * 1024 * 1024)
+        .set_max_cell_count(7)
+        .set_max_string_length(11);
+    let book = umya_spreadsheet::reader::xlsx::read_with_limits(path, &limits).unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A3"), "a long text");
+
+    let is_limit_exceeded = |limits: &umya_spreadsheet::ReadLimits| {
+        matches!(
+            umya_spreadsheet::reader::xlsx::read_with_limits(path, limits),
+            Err(umya_spreadsheet::XlsxError::LimitExceeded(_))
+        )
+    };
+    let mut cell_limits = umya_spreadsheet::ReadLimits::default();
+    cell_limits.set_max_cell_count(6);
+    assert!(is_limit_exceeded(&cell_limits));
+    let mut string_limits = umya_spreadsheet::ReadLimits::default();
+    string_limits.set_max_string_length(10);
+    assert!(is_limit_exceeded(&string_limits));
+    let mut part_limits = umya_spreadsheet::ReadLimits::default();
+    part_limits.set_max_part_size(100);
+    assert!(is_limit_exceeded(&part_limits));
+
+    // a shared string is checked even if no cell refers to it.
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let path = std::path::Path::new("./tests/result_files/read_limits_shared_strings.xlsx");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let mut data = Vec::new();
+        std::io::Read::read_to_end(&mut file, &mut data).unwrap();
+        if file.name() == "xl/sharedStrings.xml" {
+            data = String::from_utf8(data)
+                .unwrap()
+                .replace("</sst>", "<si><t>an unused longer text</t></si></sst>")
+                .into_bytes();
+        }
+        zip.start_file(
+            file.name().to_string(),
+            zip::write::SimpleFileOptions::default(),
+        )
+        .unwrap();
+        std::io::Write::write_all(&mut zip, &data).unwrap();
+    }
+    zip.finish().unwrap();
+    assert!(umya_spreadsheet::reader::xlsx::read(path).is_ok());
+    let mut string_limits = umya_spreadsheet::ReadLimits::default();
+    string_limits.set_max_string_length(11);
+    assert!(matches!(
+        umya_spreadsheet::reader::xlsx::read_with_limits(path, &string_limits),
+        Err(umya_spreadsheet::XlsxError::LimitExceeded(_))
+    ));
+}
+
+#[test]
+fn spreadsheet_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<umya_spreadsheet::Spreadsheet>();
+    assert_send_sync::<umya_spreadsheet::Worksheet>();
+    assert_send_sync::<umya_spreadsheet::Cell>();
+    assert_send_sync::<umya_spreadsheet::Style>();
+    assert_send_sync::<umya_spreadsheet::Chart>();
+    assert_send_sync::<umya_spreadsheet::XlsxError>();
+
+    let book = umya_spreadsheet::new_file();
+    let book = std::thread::spawn(move || {
+        let mut book = book;
+        book.get_sheet_mut(&0)
+            .unwrap()
+            .get_cell_mut("A1")
+            .set_value("thread");
+        book
+    })
+    .join()
+    .unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "thread");
+}
+
+#[test]
+fn clone_and_compare_charts() {
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("D11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart
+        .new_chart(
+            umya_spreadsheet::structs::ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Sheet1!$A$1:$A$10"],
+        )
+        .set_title("Chart Title");
+
+    let mut copied = chart.clone();
+    assert_eq!(chart, copied);
+    assert_eq!(chart.get_plot_area_mut(), copied.get_plot_area_mut());
+    assert_eq!(chart.get_two_cell_anchor(), copied.get_two_cell_anchor());
+
+    copied.set_title("Other Title");
+    assert_ne!(chart, copied);
+
+    let mut anchor = chart.get_two_cell_anchor().clone();
+    anchor.get_from_marker_mut().set_coordinate("E1");
+    assert_ne!(chart.get_two_cell_anchor(), &anchor);
+}
+
+#[test]
+fn cell_access_by_defined_name() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("B2").set_value_number(0.1);
+    worksheet.get_cell_mut("A1").set_value_number(1);
+    worksheet.get_cell_mut("A3").set_value_number(3);
+    worksheet
+        .add_defined_name("TaxRate", "Sheet1!$B$2")
+        .unwrap();
+    worksheet
+        .add_defined_name("Prices", "Sheet1!$A$1:$A$3")
+        .unwrap();
+
+    assert_eq!(
+        book.get_cell_by_defined_name("taxrate")
+            .unwrap()
+            .get_value(),
+        "0.1"
+    );
+    assert!(book.get_cell_by_defined_name("Missing").is_none());
+    book.get_cell_by_defined_name_mut("TaxRate")
+        .unwrap()
+        .set_value_number(0.2);
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("B2"), "0.2");
+
+    let values: Vec<String> = book
+        .get_range_by_defined_name("Prices")
+        .iter()
+        .map(|v| v.get_value().to_string())
+        .collect();
+    assert_eq!(values, vec!["1", "3"]);
+
+    for cell in book.get_range_by_defined_name_mut("Prices") {
+        cell.set_value("x");
+    }
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A2"), "x");
+    assert_eq!(book.get_range_by_defined_name("Prices").len(), 3);
+}
+
+#[test]
+fn image_hyperlink_click() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B3");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    image.set_hyperlink("https://example.com/?a=1&b=2");
+    assert_eq!(image.get_hyperlink(), Some("https://example.com/?a=1&b=2"));
+    book.get_sheet_by_name_mut("Sheet1")
+        .unwrap()
+        .add_image(image);
+
+    let path = std::path::Path::new("./tests/result_files/image_hyperlink.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let image = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_image("B3")
+        .unwrap();
+    assert_eq!(image.get_hyperlink(), Some("https://example.com/?a=1&b=2"));
+    assert_eq!(image.get_image_name(), "sample1.png");
+}
+
+#[test]
+fn keep_printer_settings() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let object_data = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_page_setup()
+        .get_object_data()
+        .cloned()
+        .unwrap();
+    assert_eq!(object_data.len(), 220);
+    book.get_sheet_mut(&1)
+        .unwrap()
+        .get_page_setup_mut()
+        .remove_object_data();
+
+    let path = std::path::Path::new("./tests/result_files/printer_settings.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(
+        book.get_sheet(&0)
+            .unwrap()
+            .get_page_setup()
+            .get_object_data(),
+        Some(&object_data)
+    );
+    assert!(book
+        .get_sheet(&1)
+        .unwrap()
+        .get_page_setup()
+        .get_object_data()
+        .is_none());
+}
+
+#[test]
+fn custom_views() {
+    let mut book = umya_spreadsheet::new_file();
+
+    let mut workbook_view = umya_spreadsheet::CustomWorkbookView::default();
+    workbook_view
+        .set_name("Filtered")
+        .set_guid("{5B3F1C2A-0D4E-4F6A-9B8C-7D6E5F4A3B2C}")
+        .set_active_sheet_id(1);
+    book.add_custom_workbook_view(workbook_view);
+
+    let mut page_margins = umya_spreadsheet::PageMargins::default();
+    page_margins.set_left(1.5);
+    let mut filter = umya_spreadsheet::Filter::default();
+    filter.set_value("Red");
+    let mut filters = umya_spreadsheet::Filters::default();
+    filters.set_blank(true).add_filter(filter);
+    let mut filter_column = umya_spreadsheet::FilterColumn::default();
+    filter_column.set_column_id(1).set_filters(filters);
+    let mut custom_filter = umya_spreadsheet::CustomFilter::default();
+    custom_filter.set_operator("greaterThan").set_value("10");
+    let mut custom_filters = umya_spreadsheet::CustomFilters::default();
+    custom_filters.add_custom_filter(custom_filter);
+    let mut filter_column_2 = umya_spreadsheet::FilterColumn::default();
+    filter_column_2
+        .set_column_id(2)
+        .set_custom_filters(custom_filters);
+    let mut auto_filter = umya_spreadsheet::AutoFilter::default();
+    auto_filter.get_range_mut().set_range("A1:C5");
+    auto_filter
+        .add_filter_column(filter_column)
+        .add_filter_column(filter_column_2);
+    let mut sheet_view = umya_spreadsheet::CustomSheetView::default();
+    sheet_view
+        .set_guid("{5B3F1C2A-0D4E-4F6A-9B8C-7D6E5F4A3B2C}")
+        .set_filter(true)
+        .set_page_margins(page_margins)
+        .set_auto_filter(auto_filter);
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_page_margins_mut().set_left(0.5);
+    sheet
+        .get_custom_sheet_views_mut()
+        .add_custom_sheet_view(sheet_view);
+
+    let path = std::path::Path::new("./tests/result_files/custom_views.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let workbook_views = book.get_custom_workbook_views();
+    assert_eq!(workbook_views.len(), 1);
+    assert_eq!(workbook_views[0].get_name(), "Filtered");
+    assert_eq!(workbook_views[0].get_active_sheet_id(), &1);
+
+    let sheet = book.get_sheet(&0).unwrap();
+    let sheet_views = sheet.get_custom_sheet_views().get_custom_sheet_view_list();
+    assert_eq!(sheet_views.len(), 1);
+    assert_eq!(
+        sheet_views[0].get_guid(),
+        "{5B3F1C2A-0D4E-4F6A-9B8C-7D6E5F4A3B2C}"
+    );
+    assert!(*sheet_views[0].get_filter());
+    assert_eq!(sheet_views[0].get_page_margins().unwrap().get_left(), &1.5);
+    assert_eq!(sheet.get_page_margins().get_left(), &0.5);
+    let auto_filter = sheet_views[0].get_auto_filter().unwrap();
+    assert_eq!(auto_filter.get_range().get_range(), "A1:C5");
+    let filter_columns = auto_filter.get_filter_column_list();
+    assert_eq!(filter_columns.len(), 2);
+    assert_eq!(filter_columns[0].get_column_id(), &1);
+    let filters = filter_columns[0].get_filters().unwrap();
+    assert!(*filters.get_blank());
+    assert_eq!(filters.get_filter_list()[0].get_value(), "Red");
+    assert_eq!(filter_columns[1].get_column_id(), &2);
+    let custom_filter = &filter_columns[1]
+        .get_custom_filters()
+        .unwrap()
+        .get_custom_filter_list()[0];
+    assert_eq!(custom_filter.get_operator(), "greaterThan");
+    assert_eq!(custom_filter.get_value(), "10");
+}
+
+#[test]
+fn edit_drawings() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B3");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    worksheet.add_image(image);
+
+    let mut shape = umya_spreadsheet::structs::drawing::spreadsheet::Shape::default();
+    shape.new_shape(umya_spreadsheet::structs::ShapeType::Rectangle);
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("D2");
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    to_marker.set_coordinate("F6");
+    worksheet.add_shape(shape, from_marker, to_marker);
+
+    let mut drawings = worksheet.get_drawings_mut();
+    assert_eq!(drawings.len(), 2);
+    assert_eq!(
+        drawings.get_kind(0),
+        Some(umya_spreadsheet::DrawingKind::Image)
+    );
+    assert_eq!(
+        drawings.get_kind(1),
+        Some(umya_spreadsheet::DrawingKind::Shape)
+    );
+    assert_eq!(drawings.find("D2"), vec![1]);
+
+    assert!(drawings.move_to(0, "C5"));
+    assert!(drawings.resize(0, "E10"));
+    assert_eq!(drawings.duplicate(1, "H2"), Some(2));
+    assert_eq!(drawings.get_to_coordinate(2), Some(String::from("J6")));
+    assert!(drawings.remove(1));
+    assert!(!drawings.remove(2));
+
+    let path = std::path::Path::new("./tests/result_files/edit_drawings.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let drawings = book.get_sheet_mut(&0).unwrap().get_drawings_mut();
+    assert_eq!(drawings.len(), 2);
+    assert_eq!(drawings.get_coordinate(0), Some(String::from("C5")));
+    assert_eq!(drawings.get_to_coordinate(0), Some(String::from("E10")));
+    assert_eq!(drawings.get_coordinate(1), Some(String::from("H2")));
+    assert_eq!(
+        drawings.get_kind(1),
+        Some(umya_spreadsheet::DrawingKind::Shape)
+    );
+}
+
+#[test]
+fn group_drawings() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_by_name_mut("Sheet1").unwrap();
+    for (from, to) in [("D2", "F6"), ("H2", "J4")] {
+        let mut shape = umya_spreadsheet::structs::drawing::spreadsheet::Shape::default();
+        shape.new_shape(umya_spreadsheet::structs::ShapeType::Rectangle);
+        let mut from_marker =
+            umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate(from);
+        let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+        to_marker.set_coordinate(to);
+        worksheet.add_shape(shape, from_marker, to_marker);
+    }
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B3");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    worksheet.add_image(image);
+
+    let mut drawings = worksheet.get_drawings_mut();
+    assert_eq!(drawings.group(&[0]), None);
+    assert_eq!(drawings.group(&[0, 1]), None);
+    assert_eq!(drawings.group(&[1, 2]), Some(1));
+    assert_eq!(drawings.len(), 2);
+    assert_eq!(
+        drawings.get_kind(1),
+        Some(umya_spreadsheet::DrawingKind::GroupShape)
+    );
+    assert_eq!(drawings.get_coordinate(1), Some(String::from("D2")));
+    assert_eq!(drawings.get_to_coordinate(1), Some(String::from("J6")));
+
+    // a copied image gets a new id.
+    assert_eq!(drawings.duplicate(0, "B10"), Some(1));
+    let ids: Vec<u32> = worksheet
+        .get_image_collection()
+        .iter()
+        .map(|v| {
+            *v.get_one_cell_anchor()
+                .unwrap()
+                .get_picture()
+                .unwrap()
+                .get_non_visual_picture_properties()
+                .get_non_visual_drawing_properties()
+                .get_id()
+        })
+        .collect();
+    assert_ne!(ids[0], ids[1]);
+
+    let path = std::path::Path::new("./tests/result_files/group_drawings.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert_eq!(worksheet.get_group_shape_collection().len(), 1);
+    assert_eq!(
+        worksheet.get_group_shape_collection()[0]
+            .get_shape_collection()
+            .len(),
+        2
+    );
+    let mut drawings = worksheet.get_drawings_mut();
+    assert_eq!(drawings.ungroup(0), Vec::<usize>::new());
+    assert_eq!(drawings.ungroup(1), vec![2, 3]);
+    assert_eq!(drawings.get_coordinate(2), Some(String::from("D2")));
+    assert_eq!(drawings.get_to_coordinate(2), Some(String::from("F6")));
+    assert_eq!(drawings.get_coordinate(3), Some(String::from("H2")));
+    assert_eq!(drawings.get_to_coordinate(3), Some(String::from("J4")));
+}
+
+#[test]
+fn effective_style() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_column_dimension_mut("B")
+        .get_style_mut()
+        .set_background_color("FFFF0000");
+    worksheet
+        .get_row_dimension_mut(&5)
+        .get_style_mut()
+        .get_font_mut()
+        .set_bold(true);
+    worksheet
+        .get_style_mut("B7")
+        .get_font_mut()
+        .set_italic(true);
+
+    // row style wins over column style
+    let style = worksheet.get_effective_style("B5");
+    assert!(*style.get_font().unwrap().get_bold());
+    assert!(style.get_background_color().is_none());
+
+    // column style
+    let style = worksheet.get_effective_style("B6");
+    assert_eq!(style.get_background_color().unwrap().get_argb(), "FFFF0000");
+    assert_eq!(style.get_font().unwrap().get_size(), &11.0);
+
+    // the cell's own style
+    let style = worksheet.get_effective_style("B7");
+    assert!(*style.get_font().unwrap().get_italic());
+
+    // default style
+    let style = worksheet.get_effective_style("D9");
+    assert_eq!(style.get_font().unwrap().get_name(), "Calibri");
+    assert_eq!(
+        style.get_number_format().unwrap().get_format_code(),
+        umya_spreadsheet::NumberingFormat::FORMAT_GENERAL
+    );
+
+    // the default font of the workbook
+    let path = std::path::Path::new("./tests/test_files/aaa_2.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let style = book.get_sheet(&0).unwrap().get_effective_style("Z100");
+    assert_eq!(style.get_font().unwrap().get_name(), "Yu Gothic");
+    let worksheet = book.new_sheet("Effective").unwrap();
+    let style = worksheet.get_effective_style("A1");
+    assert_eq!(style.get_font().unwrap().get_name(), "Yu Gothic");
+}
+
+#[test]
+fn prune_empty_styled_cells() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_column_dimension_mut("B")
+        .get_style_mut()
+        .set_background_color("FFFFFF00");
+    for row in 1..=10 {
+        worksheet.get_cell_mut((2, row));
+    }
+    worksheet.get_cell_mut("B3").set_value("keep");
+    worksheet.get_style_mut("B4").get_font_mut().set_bold(true);
+    worksheet.get_cell_mut("A1");
+
+    assert!(worksheet.get_cell("B1").unwrap().is_style_only());
+    assert!(!worksheet.get_cell("B3").unwrap().is_style_only());
+
+    assert_eq!(worksheet.prune_empty_styled_cells("B:B"), 8);
+    assert!(worksheet.get_cell("B1").is_none());
+    assert!(worksheet.get_cell("B3").is_some());
+    assert!(worksheet.get_cell("B4").is_some());
+    assert!(worksheet.get_cell("A1").is_some());
+    assert_eq!(
+        worksheet
+            .get_style("B1")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        "FFFFFF00"
+    );
+
+    // a style that only spells out the first cell format of the workbook
+    let path = std::path::Path::new("./tests/test_files/aaa_2.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let style = worksheet.get_effective_style("Z100");
+    worksheet.set_style("Z100", style);
+    assert_eq!(worksheet.prune_empty_styled_cells("Z:Z"), 1);
+    assert!(worksheet.get_cell("Z100").is_none());
+}
+
+#[test]
+fn write_tsv_and_fixed_width() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.get_cell_mut("A1").set_value("CODE");
+    sheet.get_cell_mut("B1").set_value("NAME");
+    sheet.get_cell_mut("A2").set_value("1");
+    sheet.get_cell_mut("B2").set_value("Widget");
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_csv_format_value(umya_spreadsheet::structs::CsvFormatValues::Tsv);
+    let path = std::path::Path::new("./tests/result_files/tsv.txt");
+    umya_spreadsheet::writer::csv::write(&book, path, Some(&option)).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        "CODE\tNAME\r\n1\tWidget\r\n"
+    );
+
+    // a value with a tab, a line break or a quote is quoted.
+    let mut book_escape = umya_spreadsheet::new_file();
+    let sheet_escape = book_escape.get_sheet_mut(&0).unwrap();
+    sheet_escape.get_cell_mut("A1").set_value("a\tb");
+    sheet_escape.get_cell_mut("B1").set_value("line1\nline2");
+    sheet_escape.get_cell_mut("C1").set_value("say \"hi\"");
+    let path = std::path::Path::new("./tests/result_files/tsv_escape.txt");
+    umya_spreadsheet::writer::csv::write(&book_escape, path, Some(&option)).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        "\"a\tb\"\t\"line1\nline2\"\t\"say \"\"hi\"\"\"\r\n"
+    );
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_csv_format_value(umya_spreadsheet::structs::CsvFormatValues::FixedWidth);
+    option.set_column_widths(vec![3]);
+    let path = std::path::Path::new("./tests/result_files/fixed_width.txt");
+    umya_spreadsheet::writer::csv::write(&book, path, Some(&option)).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        "CODNAME  \r\n1  Widget\r\n"
+    );
+}
+
+#[test]
+fn verify_protection_password() {
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_sheet_protection_mut()
+        .set_password("secret");
+    book.get_workbook_protection_mut()
+        .set_workbook_password_raw("83AF");
+
+    let path = std::path::Path::new("./tests/result_files/verify_password.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet_protection = book.get_sheet(&0).unwrap().get_sheet_protection().unwrap();
+    assert!(sheet_protection.verify_password("secret"));
+    assert!(!sheet_protection.verify_password("Secret"));
+
+    let workbook_protection = book.get_workbook_protection().unwrap();
+    assert!(workbook_protection.verify_workbook_password("password"));
+    assert!(!workbook_protection.verify_workbook_password("passw0rd"));
+    assert!(workbook_protection.verify_revisions_password(""));
+
+    assert_eq!(
+        umya_spreadsheet::helper::crypt::get_legacy_password_hash("password"),
+        "83AF"
+    );
+
+    let salt = "qkPhpbpxZX1dG0GjeHVn5w==";
+    assert!(umya_spreadsheet::helper::crypt::get_password_hash(
+        "secret",
+        "SHA-512",
+        salt,
+        u32::MAX
+    )
+    .is_none());
+    assert!(!umya_spreadsheet::helper::crypt::verify_password_hash(
+        "secret",
+        "SHA-512",
+        "",
+        salt,
+        u32::MAX
+    ));
+}
+
+#[test]
+fn read_metadata() {
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let metadata = umya_spreadsheet::reader::xlsx::read_metadata(path).unwrap();
+
+    let sheets = metadata.get_sheets();
+    assert_eq!(sheets.len(), 7);
+    assert_eq!(sheets[0].get_name(), "Sheet1");
+    assert_eq!(sheets[0].get_dimension(), Some("A1:K47"));
+    assert!(sheets[0].is_visible());
+    assert_eq!(sheets[1].get_dimension(), Some("A4:W27"));
+    assert_eq!(sheets[6].get_name(), "Sheet7");
+
+    assert!(metadata
+        .get_defined_names()
+        .iter()
+        .any(|v| v.get_name() == "名前"));
+    assert!(metadata
+        .get_defined_names()
+        .iter()
+        .any(|v| v.get_name() == "_xlnm._FilterDatabase"));
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(
+        metadata.get_properties().get_creator(),
+        book.get_properties().get_creator()
+    );
+}
+
+#[test]
+fn worksheet_dimension() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert_eq!(worksheet.calculate_worksheet_dimension(), "A1");
+    worksheet.get_cell_mut("C4").set_value("x");
+    assert_eq!(worksheet.calculate_worksheet_dimension(), "C4");
+    worksheet.get_cell_mut("F200").set_value("y");
+    worksheet.get_cell_mut("B10").set_value("z");
+    assert_eq!(worksheet.calculate_worksheet_dimension(), "B4:F200");
+
+    let path = std::path::Path::new("./tests/result_files/worksheet_dimension.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let metadata = umya_spreadsheet::reader::xlsx::read_metadata(path).unwrap();
+    assert_eq!(metadata.get_sheets()[0].get_dimension(), Some("B4:F200"));
+
+    let mut option = umya_spreadsheet::XlsxWriterOption::default();
+    option.set_is_dimension_omitted(true);
+    let _ = umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option);
+    let metadata = umya_spreadsheet::reader::xlsx::read_metadata(path).unwrap();
+    assert_eq!(metadata.get_sheets()[0].get_dimension(), None);
+}
+
+#[test]
+fn column_range_by_letters() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.set_column_width("B:D", 18.0);
+    worksheet.hide_columns("f:h");
+    assert_eq!(worksheet.get_column_dimensions_by_range_mut("B:H").len(), 7);
+
+    let path = std::path::Path::new("./tests/result_files/column_range_by_letters.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    for col in ["B", "C", "D"] {
+        assert_eq!(
+            worksheet.get_column_dimension(col).unwrap().get_width(),
+            &18.0
+        );
+    }
+    for col in ["F", "G", "H"] {
+        assert!(worksheet.get_column_dimension(col).unwrap().get_hidden());
+    }
+    assert!(!worksheet.get_column_dimension("D").unwrap().get_hidden());
+
+    worksheet.unhide_columns("G");
+    assert!(!worksheet.get_column_dimension("G").unwrap().get_hidden());
+    assert!(worksheet.get_column_dimension("H").unwrap().get_hidden());
+}
+
+#[test]
+fn hide_and_unhide_rows_and_columns() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.hide_row(&3);
+    worksheet.hide_rows(7..=20);
+    worksheet.hide_column("C");
+    worksheet.get_row_dimension_mut(&25).set_height(0.0);
+    worksheet.get_column_dimension_mut("E").set_width(0.0);
+
+    let path = std::path::Path::new("./tests/result_files/hide_and_unhide.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert!(worksheet.get_row_dimension(&3).unwrap().get_hidden());
+    assert!(worksheet.get_row_dimension(&7).unwrap().get_hidden());
+    assert!(worksheet.get_row_dimension(&20).unwrap().get_hidden());
+    assert!(worksheet.get_row_dimension(&21).is_none());
+    assert!(worksheet.get_column_dimension("C").unwrap().get_hidden());
+
+    worksheet.unhide_rows(7..=10);
+    assert!(!worksheet.get_row_dimension(&8).unwrap().get_hidden());
+    assert!(worksheet.get_row_dimension(&11).unwrap().get_hidden());
+    worksheet.unhide_column("C");
+    assert!(!worksheet.get_column_dimension("C").unwrap().get_hidden());
+
+    worksheet.unhide_row(&25);
+    assert!(!worksheet
+        .get_row_dimension(&25)
+        .unwrap()
+        .get_custom_height());
+    worksheet.unhide_column("E");
+    assert!(worksheet.get_column_dimension("E").unwrap().get_width() > &0.0);
+}
+
+#[test]
+fn active_cell_and_selection() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.set_active_cell("C5");
+    let _ = book.new_sheet("Sheet2");
+    let worksheet = book.get_sheet_mut(&1).unwrap();
+    worksheet.set_selected_ranges("B2:C4 E6:E8");
+    assert_eq!(worksheet.get_active_cell(), "B2");
+    worksheet.set_active_cell("E7");
+
+    let path = std::path::Path::new("./tests/result_files/active_cell_and_selection.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_active_cell(), "C5");
+    let sheet_view = &worksheet.get_sheets_views().get_sheet_view_list()[0];
+    let selection = sheet_view.get_active_selection().unwrap();
+    assert_eq!(selection.get_sequence_of_references().get_sqref(), "C5");
+
+    let worksheet = book.get_sheet(&1).unwrap();
+    assert_eq!(worksheet.get_active_cell(), "E7");
+    let sheet_view = &worksheet.get_sheets_views().get_sheet_view_list()[0];
+    let selection = sheet_view.get_active_selection().unwrap();
+    assert_eq!(
+        selection.get_sequence_of_references().get_sqref(),
+        "B2:C4 E6:E8"
+    );
+    assert!(selection.contains(&3, &4));
+    assert!(!selection.contains(&4, &4));
+}
+
+#[test]
+fn print_options() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .set_print_gridlines(true)
+        .set_print_headings(true)
+        .set_print_centered(true, false);
+
+    let path = std::path::Path::new("./tests/result_files/print_options.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let print_options = book.get_sheet(&0).unwrap().get_print_options();
+    assert!(print_options.get_grid_lines());
+    assert!(print_options.get_headings());
+    assert!(print_options.get_horizontal_centered());
+    assert!(!print_options.get_vertical_centered());
+}
+
+#[test]
+fn chart_facade() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("H11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::BarChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$10", "Sheet1!$B$1:$B$10"],
+    );
+    chart.set_title("Sales");
+    book.get_sheet_mut(&0).unwrap().add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_facade.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let chart = &worksheet.get_chart_collection()[0];
+    assert!(matches!(
+        chart.get_chart_type(),
+        Some(umya_spreadsheet::structs::ChartType::BarChart)
+    ));
+    assert_eq!(chart.get_title().as_deref(), Some("Sales"));
+    assert_eq!(chart.get_coordinate(), "C1");
+    assert_eq!(chart.get_to_coordinate(), "H11");
+    assert_eq!(
+        chart.get_series_formulas(),
+        vec!["Sheet1!$A$1:$A$10", "Sheet1!$B$1:$B$10"]
+    );
+
+    worksheet.get_chart_collection_mut()[0].set_series_formula(1, "Sheet1!$C$1:$C$10");
+    assert_eq!(
+        worksheet.get_chart_collection()[0].get_series_formulas(),
+        vec!["Sheet1!$A$1:$A$10", "Sheet1!$C$1:$C$10"]
+    );
+}
+
+#[test]
+fn retarget_chart_series_ranges() {
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("H11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Old!$A$1:$A$10", "Other!$B$2:$B$10"],
+    );
+
+    chart.replace_sheet_name("Old", "New Sheet");
+    assert_eq!(
+        chart.get_series_formulas(),
+        vec!["'New Sheet'!$A$1:$A$10", "Other!$B$2:$B$10"]
+    );
+
+    chart.shift_series_ranges(10, 1);
+    assert_eq!(
+        chart.get_series_formulas(),
+        vec!["'New Sheet'!$B$11:$B$20", "Other!$C$12:$C$20"]
+    );
+
+    chart.shift_series_ranges(-20, -5);
+    assert_eq!(
+        chart.get_series_formulas(),
+        vec!["'New Sheet'!$A$1:$A$1", "Other!$A$1:$A$1"]
+    );
+}
+
+#[test]
+fn pie_chart_styling() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("H11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::DoughnutChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$4", "Sheet1!$B$1:$B$4"],
+    );
+    chart
+        .set_first_slice_angle(90)
+        .set_hole_size(60)
+        .set_point_explosion(0, 2, 25);
+    book.get_sheet_mut(&0).unwrap().add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/pie_chart_styling.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let chart = &book.get_sheet(&0).unwrap().get_chart_collection()[0];
+    let doughnut_chart = chart
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area()
+        .get_doughnut_chart()
+        .unwrap();
+    assert_eq!(doughnut_chart.get_first_slice_angle().get_val(), &90);
+    assert_eq!(doughnut_chart.get_hole_size().get_val(), &60);
+    let series_list = doughnut_chart
+        .get_area_chart_series_list()
+        .get_area_chart_series();
+    assert_eq!(series_list.len(), 1);
+    let series = &series_list[0];
+    assert_eq!(
+        series
+            .get_values()
+            .unwrap()
+            .get_number_reference()
+            .get_formula()
+            .get_address_str(),
+        "Sheet1!$B$1:$B$4"
+    );
+    assert_eq!(series.get_data_points().len(), 1);
+    let data_point = &series.get_data_points()[0];
+    assert_eq!(data_point.get_index().get_val(), &2);
+    assert_eq!(data_point.get_explosion().unwrap().get_val(), &25);
+}
+
+#[test]
+fn chart_plot_area_layout() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("H11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$10"],
+    );
+    chart.set_plot_area_layout(0.1, 0.15, 0.8, 0.7);
+    book.get_sheet_mut(&0).unwrap().add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_plot_area_layout.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let chart = &book.get_sheet(&0).unwrap().get_chart_collection()[0];
+    let manual_layout = chart
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area()
+        .get_layout()
+        .get_manual_layout()
+        .unwrap();
+    assert_eq!(
+        manual_layout.get_layout_target().unwrap().get_val(),
+        &umya_spreadsheet::drawing::charts::LayoutTargetValues::Inner
+    );
+    assert_eq!(manual_layout.get_left().unwrap().get_val(), &0.1);
+    assert_eq!(manual_layout.get_top().unwrap().get_val(), &0.15);
+    assert_eq!(manual_layout.get_width().unwrap().get_val(), &0.8);
+    assert_eq!(manual_layout.get_height().unwrap().get_val(), &0.7);
+}
+
+#[test]
+fn chart_blanks_and_axis_crossing() {
+    let mut book = umya_spreadsheet::new_file();
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("C1");
+    to_marker.set_coordinate("H11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::LineChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$10"],
+    );
+    chart
+        .set_display_blanks_as(umya_spreadsheet::drawing::charts::DisplayBlanksAsValues::Gap)
+        .set_value_axis_crosses(umya_spreadsheet::drawing::charts::CrossesValues::Maximum)
+        .set_category_axis_crosses_at(2.5);
+    book.get_sheet_mut(&0).unwrap().add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chart_blanks_and_axis_crossing.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let chart = book.get_sheet(&0).unwrap().get_chart_collection()[0]
+        .get_chart_space()
+        .get_chart();
+    assert_eq!(
+        chart.get_display_blanks_as().get_val(),
+        &umya_spreadsheet::drawing::charts::DisplayBlanksAsValues::Gap
+    );
+    let plot_area = chart.get_plot_area();
+    assert_eq!(
+        plot_area.get_value_axis()[0].get_crosses().get_val(),
+        &umya_spreadsheet::drawing::charts::CrossesValues::Maximum
+    );
+    assert!(plot_area.get_value_axis()[0].get_crosses_at().is_none());
+    assert_eq!(
+        plot_area.get_category_axis()[0]
+            .get_crosses_at()
+            .unwrap()
+            .get_val(),
+        &2.5
+    );
+}
+
+#[test]
+fn preserve_unsupported_chart() {
+    let path = std::path::Path::new("./tests/test_files/unsupported_chart.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let charts: Vec<&umya_spreadsheet::structs::Chart> = book
+        .get_sheet_collection()
+        .iter()
+        .flat_map(|v| v.get_chart_collection())
+        .collect();
+    assert_eq!(charts.len(), 2);
+    assert_eq!(charts.iter().filter(|v| !v.is_support()).count(), 1);
+
+    let path = std::path::Path::new("./tests/result_files/unsupported_chart.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = zip::ZipArchive::new(file).unwrap();
+    assert!(archive.by_name("xl/charts/style2.xml").is_ok());
+    assert!(archive.by_name("xl/charts/colors2.xml").is_ok());
+    let mut content_types = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("[Content_Types].xml").unwrap(),
+        &mut content_types,
+    )
+    .unwrap();
+    assert!(content_types.contains("/xl/charts/style2.xml"));
+    let rels_names: Vec<String> = archive
+        .file_names()
+        .filter(|v| v.starts_with("xl/charts/_rels/"))
+        .map(|v| v.to_string())
+        .collect();
+    assert!(!rels_names.is_empty());
+    let mut has_style_rel = false;
+    for name in &rels_names {
+        let mut rels = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut rels).unwrap();
+        has_style_rel |= rels.contains("style2.xml");
+    }
+    assert!(has_style_rel);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let charts: Vec<&umya_spreadsheet::structs::Chart> = book
+        .get_sheet_collection()
+        .iter()
+        .flat_map(|v| v.get_chart_collection())
+        .collect();
+    assert_eq!(charts.len(), 2);
+    assert_eq!(charts.iter().filter(|v| !v.is_support()).count(), 1);
+}
+
+#[test]
+fn keep_legacy_vml_shapes() {
+    let path = std::path::Path::new("./tests/test_files/issue_189.xlsx");
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert_eq!(worksheet.get_form_controls().len(), 1);
+    assert!(worksheet.get_vml_shapes().is_empty());
+
+    let mut shape = umya_spreadsheet::structs::vml::Shape::default();
+    shape.set_type("#_x0000_t201");
+    let client_data = shape.get_client_data_mut();
+    client_data.set_object_type(umya_spreadsheet::structs::vml::spreadsheet::ObjectValues::List);
+    let anchor = client_data.get_anchor_mut();
+    anchor.set_left_column(4);
+    anchor.set_top_row(2);
+    anchor.set_right_column(6);
+    anchor.set_bottom_row(8);
+    worksheet.add_vml_shape(shape);
+
+    let path = std::path::Path::new("./tests/result_files/keep_legacy_vml_shapes.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_form_controls().len(), 1);
+    assert_eq!(worksheet.get_vml_shapes().len(), 1);
+    let client_data = worksheet.get_vml_shapes()[0].get_client_data();
+    assert_eq!(
+        client_data.get_object_type(),
+        &umya_spreadsheet::structs::vml::spreadsheet::ObjectValues::List
+    );
+    assert_eq!(client_data.get_anchor().get_left_column(), &4);
+
+    // shapes of other shape types are kept with their shape type.
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let path = std::path::Path::new("./tests/result_files/keep_legacy_vml_shapes_custom.xlsx");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let mut data = Vec::new();
+        std::io::Read::read_to_end(&mut file, &mut data).unwrap();
+        if file.name() == "xl/drawings/vmlDrawing1.vml" {
+            let xml = String::from_utf8(data).unwrap().replace(
+                "</xml>",
+                r##"<v:shapetype id="_x0000_t32" coordsize="21600,21600" o:spt="32" path="m,l21600,21600e"><v:path arrowok="t" fillok="f"/></v:shapetype><v:shape id="_x0000_s9" type="#_x0000_t32" style="position:absolute"><x:ClientData ObjectType="Line"/></v:shape></xml>"##,
+            );
+            data = xml.into_bytes();
+        }
+        zip.start_file(
+            file.name().to_string(),
+            zip::write::SimpleFileOptions::default(),
+        )
+        .unwrap();
+        std::io::Write::write_all(&mut zip, &data).unwrap();
+    }
+    zip.finish().unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_sheet(&0).unwrap().get_vml_shapes().len(), 2);
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut vml = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/drawings/vmlDrawing1.vml").unwrap(),
+        &mut vml,
+    )
+    .unwrap();
+    assert_eq!(vml.matches(r#"<v:shapetype id="_x0000_t32""#).count(), 1);
+    assert!(vml.contains(r##"type="#_x0000_t32""##));
+}
+
+#[test]
+fn capture_and_apply_formatting() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_style_mut("A1").get_font_mut().set_bold(true);
+    worksheet
+        .get_style_mut("C3")
+        .set_background_color(umya_spreadsheet::Color::COLOR_YELLOW);
+    worksheet.get_row_dimension_mut(&2).set_height(30.0);
+    worksheet.add_merge_cells("A1:C1");
+    worksheet.get_cell_mut("B2").set_value("keep");
+
+    let snapshot = worksheet.capture_formatting("A1:C3");
+    assert_eq!(snapshot.get_column_count(), &3);
+    assert_eq!(snapshot.get_row_count(), &3);
+
+    worksheet
+        .get_style_mut("F11")
+        .get_font_mut()
+        .set_italic(true);
+    worksheet.apply_formatting(&snapshot, "E10");
+
+    assert!(*worksheet.get_style("E10").get_font().unwrap().get_bold());
+    assert_eq!(
+        worksheet
+            .get_style("G12")
+            .get_background_color()
+            .unwrap()
+            .get_argb(),
+        umya_spreadsheet::Color::COLOR_YELLOW
+    );
+    assert!(!worksheet
+        .get_style("F11")
+        .get_font()
+        .map_or(false, |v| *v.get_italic()));
+    assert_eq!(
+        worksheet.get_row_dimension(&11).unwrap().get_height(),
+        &30.0
+    );
+    assert!(worksheet
+        .get_merge_cells()
+        .iter()
+        .any(|v| v.get_range() == "E10:G10"));
+    assert_eq!(worksheet.get_value("F11"), "");
+}
+
+#[test]
+fn freeze_header() {
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_mut(&0).unwrap().freeze_header(2, 1);
+
+    let path = std::path::Path::new("./tests/result_files/freeze_header.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert_eq!(worksheet.get_active_cell(), "B3");
+    let sheet_view = &worksheet.get_sheets_views().get_sheet_view_list()[0];
+    let pane = sheet_view.get_pane().unwrap();
+    assert_eq!(pane.get_horizontal_split(), &1.0);
+    assert_eq!(pane.get_vertical_split(), &2.0);
+    assert_eq!(pane.get_top_left_cell().to_string(), "B3");
+    assert!(matches!(
+        pane.get_state(),
+        umya_spreadsheet::structs::PaneStateValues::Frozen
+    ));
+    assert_eq!(sheet_view.get_selection().len(), 3);
+
+    worksheet.freeze_header(0, 0);
+    let sheet_view = &worksheet.get_sheets_views().get_sheet_view_list()[0];
+    assert!(sheet_view.get_pane().is_none());
+    assert!(sheet_view.get_selection().is_empty());
+}
+
+#[test]
+fn set_value_detect() {
+    let mut book = umya_spreadsheet::new_file();
+    let locale = umya_spreadsheet::Locale::default();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_value_detect("2024-01-31", &locale);
+    worksheet
+        .get_cell_mut("A2")
+        .set_value_detect("12.5%", &locale);
+    worksheet
+        .get_cell_mut("A3")
+        .set_value_detect("$1,234.50", &locale);
+    worksheet
+        .get_cell_mut("A4")
+        .set_value_detect("true", &locale);
+    worksheet
+        .get_cell_mut("A5")
+        .set_value_detect("abc", &locale);
+
+    let cell = worksheet.get_cell("A1").unwrap();
+    assert_eq!(cell.get_value(), "45322");
+    assert_eq!(
+        cell.get_style()
+            .get_number_format()
+            .unwrap()
+            .get_format_code(),
+        umya_spreadsheet::NumberingFormat::FORMAT_DATE_YYYYMMDD
+    );
+    let cell = worksheet.get_cell("A2").unwrap();
+    assert_eq!(cell.get_value_number(), Some(0.125));
+    assert_eq!(
+        cell.get_style()
+            .get_number_format()
+            .unwrap()
+            .get_format_code(),
+        umya_spreadsheet::NumberingFormat::FORMAT_PERCENTAGE_00
+    );
+    let cell = worksheet.get_cell("A3").unwrap();
+    assert_eq!(cell.get_value_number(), Some(1234.5));
+    assert_eq!(
+        cell.get_style()
+            .get_number_format()
+            .unwrap()
+            .get_format_code(),
+        umya_spreadsheet::NumberingFormat::FORMAT_CURRENCY_USD_SIMPLE
+    );
+    assert_eq!(worksheet.get_value("A4"), "TRUE");
+    assert_eq!(worksheet.get_value("A5"), "abc");
+}
+
+#[test]
+fn precision_as_displayed() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let cell = worksheet.get_cell_mut("A1");
+    cell.set_value_number(1234.5678);
+    cell.get_style_mut()
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_NUMBER_COMMA_SEPARATED1);
+    let cell = worksheet.get_cell_mut("A2");
+    cell.set_value_number(0.12345);
+    cell.get_style_mut()
+        .get_number_format_mut()
+        .set_format_code(umya_spreadsheet::NumberingFormat::FORMAT_PERCENTAGE);
+    worksheet.get_cell_mut("A3").set_value_number(1.23456);
+    book.set_precision_as_displayed(true);
+    book.round_to_display_precision();
+
+    let path = std::path::Path::new("./tests/result_files/precision_as_displayed.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(*book.get_precision_as_displayed());
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_value("A1"), "1234.57");
+    assert_eq!(worksheet.get_value("A2"), "0.12");
+    assert_eq!(worksheet.get_value("A3"), "1.23456");
+}
+
+#[test]
+fn calc_chain_option() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Sheet2").unwrap();
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("A3")
+        .set_formula("SUM(A1:A2)");
+    book.get_sheet_mut(&1)
+        .unwrap()
+        .get_cell_mut("B1")
+        .set_formula("Sheet1!A3*2");
+
+    for (calc_chain, file_name) in vec![
+        (
+            umya_spreadsheet::structs::CalcChainValues::Regenerate,
+            "calc_chain_regenerate.xlsx",
+        ),
+        (
+            umya_spreadsheet::structs::CalcChainValues::ForceRecalculation,
+            "calc_chain_force_recalculation.xlsx",
+        ),
+    ] {
+        let mut option = umya_spreadsheet::structs::XlsxWriterOption::default();
+        option.set_calc_chain(calc_chain);
+        let path = std::path::Path::new("./tests/result_files").join(file_name);
+        umya_spreadsheet::writer::xlsx::write_with_option(&book, &path, &option).unwrap();
+
+        let book = umya_spreadsheet::reader::xlsx::read(&path).unwrap();
+        assert_eq!(
+            book.get_sheet(&0)
+                .unwrap()
+                .get_cell("A3")
+                .unwrap()
+                .get_formula(),
+            "SUM(A1:A2)"
+        );
+        assert_eq!(
+            book.get_sheet(&1)
+                .unwrap()
+                .get_cell("B1")
+                .unwrap()
+                .get_formula(),
+            "Sheet1!A3*2"
+        );
+
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&path).unwrap()).unwrap();
+        let mut workbook = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name("xl/workbook.xml").unwrap(),
+            &mut workbook,
+        )
+        .unwrap();
+        let calc_chain = archive.by_name("xl/calcChain.xml").ok().map(|mut v| {
+            let mut xml = String::new();
+            std::io::Read::read_to_string(&mut v, &mut xml).unwrap();
+            xml
+        });
+        match option.get_calc_chain() {
+            umya_spreadsheet::structs::CalcChainValues::Regenerate => {
+                assert!(calc_chain
+                    .unwrap()
+                    .contains("<c r=\"A3\" i=\"1\"/><c r=\"B1\" i=\"2\"/></calcChain>"));
+                assert!(!workbook.contains("fullCalcOnLoad"));
+            }
+            _ => {
+                assert!(calc_chain.is_none());
+                assert!(workbook.contains("fullCalcOnLoad=\"1\""));
+            }
+        }
+    }
+
+    // the sheets of a lazily read workbook are listed too.
+    let path = std::path::Path::new("./tests/result_files/calc_chain_regenerate.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::lazy_read(path).unwrap();
+    let mut option = umya_spreadsheet::structs::XlsxWriterOption::default();
+    option.set_calc_chain(umya_spreadsheet::structs::CalcChainValues::Regenerate);
+    let path = std::path::Path::new("./tests/result_files/calc_chain_lazy_read.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option).unwrap();
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut calc_chain = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/calcChain.xml").unwrap(),
+        &mut calc_chain,
+    )
+    .unwrap();
+    assert!(calc_chain.contains("<c r=\"A3\" i=\"1\"/><c r=\"B1\" i=\"2\"/></calcChain>"));
+}
+
+#[test]
+fn cell_metadata() {
+    let path = std::path::Path::new("./tests/test_files/issue_206.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let metadata = book.get_metadata().unwrap();
+    assert_eq!(metadata.get_metadata_types(), &vec![String::from("XLDAPR")]);
+    let cell = book.get_sheet(&0).unwrap().get_cell("J24").unwrap();
+    assert_eq!(cell.get_cell_meta_index(), &1);
+    let record = metadata
+        .get_cell_metadata_by_index(cell.get_cell_meta_index())
+        .unwrap();
+    assert_eq!(record.get_type_name(), "XLDAPR");
+    assert_eq!(record.get_value_index(), &0);
+    assert!(metadata.is_dynamic_array(cell.get_cell_meta_index()));
+    assert!(!metadata.is_dynamic_array(&2));
+
+    let path = std::path::Path::new("./tests/result_files/cell_metadata.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let cell = book.get_sheet(&0).unwrap().get_cell("J24").unwrap();
+    assert!(book
+        .get_metadata()
+        .unwrap()
+        .is_dynamic_array(cell.get_cell_meta_index()));
+}
+
+#[test]
+fn fill_template() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet
+        .get_cell_mut("A1")
+        .set_value("Invoice for {{customer}}");
+    worksheet
+        .get_header_footer_mut()
+        .get_odd_header_mut()
+        .set_value("&C{{customer}}");
+    worksheet
+        .get_cell_mut("A3")
+        .set_value("{{items.name}}")
+        .get_style_mut()
+        .get_font_mut()
+        .set_bold(true);
+    worksheet.get_cell_mut("B3").set_value("{{items.price}}");
+    worksheet.get_cell_mut("C3").set_formula("B3*2");
+    worksheet.get_row_dimension_mut(&3).set_height(30.0);
+    worksheet.get_cell_mut("A4").set_value("Total");
+    let mut rich_text = umya_spreadsheet::RichText::default();
+    let mut element = umya_spreadsheet::TextElement::default();
+    element.set_text("Due {{due}}");
+    rich_text.add_rich_text_elements(element);
+    worksheet.get_cell_mut("A5").set_rich_text(rich_text);
+    worksheet.get_cell_mut("A6").set_value("{{unknown}}");
+
+    let mut values = umya_spreadsheet::TemplateValues::default();
+    values
+        .set_value("customer", "ACME")
+        .set_value("due", "2024-01-31");
+    values
+        .add_record("items", &[("name", "Pen"), ("price", "1.5")])
+        .add_record("items", &[("name", "Ink"), ("price", "3")])
+        .add_record("items", &[("name", "Pad"), ("price", "2")]);
+    book.fill_template(&values);
+
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_value("A1"), "Invoice for ACME");
+    assert_eq!(
+        worksheet.get_header_footer().get_odd_header().get_value(),
+        "&CACME"
+    );
+    assert_eq!(worksheet.get_value("A3"), "Pen");
+    assert_eq!(worksheet.get_value("A5"), "Pad");
+    assert_eq!(
+        worksheet.get_cell("B4").unwrap().get_value_number(),
+        Some(3.0)
+    );
+    assert_eq!(worksheet.get_cell("C3").unwrap().get_formula(), "B3*2");
+    assert_eq!(worksheet.get_cell("C4").unwrap().get_formula(), "B4*2");
+    assert_eq!(worksheet.get_cell("C5").unwrap().get_formula(), "B5*2");
+    assert!(worksheet
+        .get_cell("A4")
+        .unwrap()
+        .get_style()
+        .get_font()
+        .map_or(false, |v| *v.get_bold()));
+    assert_eq!(worksheet.get_row_dimension(&5).unwrap().get_height(), &30.0);
+    assert_eq!(worksheet.get_value("A6"), "Total");
+    assert_eq!(worksheet.get_value("A7"), "Due 2024-01-31");
+    assert_eq!(worksheet.get_value("A8"), "{{unknown}}");
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value("{{items.name}}");
+    worksheet.get_cell_mut("A2").set_value("{{other.name}}");
+    worksheet.get_cell_mut("A3").set_value("end");
+    let mut values = umya_spreadsheet::TemplateValues::default();
+    values.add_list("items");
+    worksheet.fill_template(&values);
+    assert_eq!(worksheet.get_value("A1"), "{{other.name}}");
+    assert_eq!(worksheet.get_value("A2"), "end");
+}
+
+#[test]
+fn worksheet_content_hash() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value("TEST1");
+    worksheet.get_cell_mut("B2").set_value_number(10);
+    worksheet.get_cell_mut("C3").set_formula("B2*2");
+    let hash = worksheet.content_hash();
+
+    let mut book2 = umya_spreadsheet::new_file();
+    let worksheet2 = book2.get_sheet_mut(&0).unwrap();
+    worksheet2.get_cell_mut("C3").set_formula("B2*2");
+    worksheet2.get_cell_mut("B2").set_value_number(10);
+    worksheet2.get_cell_mut("A1").set_value("TEST1");
+    worksheet2.get_cell_mut("D4");
+    assert_eq!(worksheet2.content_hash(), hash);
+
+    worksheet2.get_style_mut("A1").get_font_mut().set_bold(true);
+    let styled_hash = worksheet2.content_hash();
+    assert_ne!(styled_hash, hash);
+
+    worksheet2.get_cell_mut("C3").set_formula("B2*3");
+    assert_ne!(worksheet2.content_hash(), styled_hash);
+
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let book2 = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(
+        book.get_sheet(&0).unwrap().content_hash(),
+        book2.get_sheet(&0).unwrap().content_hash()
+    );
+}
+
+#[test]
+fn image_size_in_pixels() {
+    let mut marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    marker.set_coordinate("B3");
+    let mut image = umya_spreadsheet::structs::Image::default();
+    image.new_image("./images/sample1.png", marker);
+    assert_eq!(image.get_natural_size(), Some((580, 318)));
+    assert_eq!(image.get_size_in_pixels(96.0), Some((580.0, 318.0)));
+
+    image.set_size_in_pixels(290, 159, 96.0).unwrap();
+    assert_eq!(
+        *image.get_one_cell_anchor().unwrap().get_extent().get_cx(),
+        290 * 9525
+    );
+    assert_eq!(image.get_size_in_pixels(96.0), Some((290.0, 159.0)));
+    assert_eq!(image.get_size_in_pixels(192.0), Some((580.0, 318.0)));
+
+    image.reset_size(144.0).unwrap();
+    assert_eq!(image.get_size_in_pixels(144.0), Some((580.0, 318.0)));
+
+    image.set_width_keep_ratio(290, 96.0).unwrap();
+    assert_eq!(image.get_size_in_pixels(96.0), Some((290.0, 159.0)));
+
+    assert!(image.set_size_in_pixels(290, 159, 0.0).is_err());
+    assert!(image.set_size_in_pixels(290, 159, -96.0).is_err());
+    assert_eq!(image.get_size_in_pixels(0.0), None);
+    assert_eq!(image.get_size_in_pixels(96.0), Some((290.0, 159.0)));
+
+    // an image anchored to two cells
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let mut two_cell_image = book
+        .get_sheet_by_name("Sheet1")
+        .unwrap()
+        .get_image_collection()
+        .iter()
+        .find(|v| v.get_two_cell_anchor().is_some())
+        .unwrap()
+        .clone();
+    let size = two_cell_image.get_size_in_pixels(96.0);
+    assert!(two_cell_image.set_size_in_pixels(10, 10, 96.0).is_err());
+    assert_eq!(two_cell_image.get_size_in_pixels(96.0), size);
+    assert!(two_cell_image.get_natural_size().is_some());
+
+    let mut book = umya_spreadsheet::new_file();
+    book.get_sheet_mut(&0).unwrap().add_image(image);
+    let path = std::path::Path::new("./tests/result_files/image_size_in_pixels.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn force_full_calculation() {
+    let mut book = umya_spreadsheet::new_file();
+    assert!(!*book.get_force_full_calculation());
+    book.get_sheet_mut(&0)
+        .unwrap()
+        .get_cell_mut("A1")
+        .set_formula("SUM(B1:B3)");
+    book.set_force_full_calculation(true);
+
+    let path = std::path::Path::new("./tests/result_files/force_full_calculation.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let mut book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(*book.get_force_full_calculation());
+
+    book.set_force_full_calculation(false);
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert!(!*book.get_force_full_calculation());
+}
+
+#[test]
+fn sheet_properties() {
+    let mut book = umya_spreadsheet::new_file();
+    book.set_code_name("Workbook1");
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet.set_code_name("DataSheet");
+    assert!(*sheet.get_sheet_properties().get_published());
+    sheet
+        .get_sheet_properties_mut()
+        .set_sync_horizontal(true)
+        .set_sync_vertical(true)
+        .set_sync_reference("B2")
+        .set_transition_evaluation(true)
+        .set_transition_entry(true)
+        .set_filter_mode(true)
+        .set_published(false);
+
+    let path = std::path::Path::new("./tests/result_files/sheet_properties.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_code_name(), Some("Workbook1"));
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_code_name(), Some("DataSheet"));
+    let properties = sheet.get_sheet_properties();
+    assert!(*properties.get_sync_horizontal());
+    assert!(*properties.get_sync_vertical());
+    assert_eq!(properties.get_sync_reference(), "B2");
+    assert!(*properties.get_transition_evaluation());
+    assert!(*properties.get_transition_entry());
+    assert!(*properties.get_filter_mode());
+    assert!(!*properties.get_published());
+    assert!(*properties.get_enable_format_conditions_calculation());
+}
+
+#[test]
+fn read_strict_ooxml() {
+    let path = std::path::Path::new("./tests/test_files/strict_ooxml.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_theme().get_name(), "Strict Theme");
+    assert!(book.get_unknown_part_names().is_empty());
+
+    let sheet = book.get_sheet(&0).unwrap();
+    assert_eq!(sheet.get_value("A1"), "STRICT");
+    assert_eq!(sheet.get_value("B1"), "42");
+    assert_eq!(sheet.get_value("C1"), "45322.5");
+
+    let path = std::path::Path::new("./tests/result_files/strict_ooxml.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+}
+
+#[test]
+fn minimal_compatibility_option() {
+    let mut book = umya_spreadsheet::new_file();
+    let cell = book.get_sheet_mut(&0).unwrap().get_cell_mut("A1");
+    cell.set_value("Bold");
+    cell.get_style_mut().get_font_mut().set_bold(true);
+
+    let mut option = umya_spreadsheet::structs::XlsxWriterOption::default();
+    assert_eq!(
+        option.get_compatibility(),
+        &umya_spreadsheet::structs::CompatibilityValues::Excel2016
+    );
+    option.set_compatibility(umya_spreadsheet::structs::CompatibilityValues::Minimal);
+    let path = std::path::Path::new("./tests/result_files/minimal_compatibility.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option).unwrap();
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let cell = book.get_sheet(&0).unwrap().get_cell("A1").unwrap();
+    assert_eq!(cell.get_value(), "Bold");
+    assert!(*cell.get_style().get_font().unwrap().get_bold());
+
+    // the worksheets are written without the extensions too.
+    let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let path = std::path::Path::new("./tests/result_files/minimal_compatibility_sheet.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option).unwrap();
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    for name in ["xl/styles.xml", "xl/worksheets/sheet1.xml"] {
+        let mut xml = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name(name).unwrap(), &mut xml).unwrap();
+        assert!(!xml.contains("mc:Ignorable"), "{}", name);
+        assert!(!xml.contains("x14ac:"), "{}", name);
+    }
+
+    let path = std::path::Path::new("./tests/result_files/default_compatibility_sheet.xlsx");
+    umya_spreadsheet::writer::xlsx::write(&book, path).unwrap();
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut xml = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name("xl/worksheets/sheet1.xml").unwrap(),
+        &mut xml,
+    )
+    .unwrap();
+    assert!(xml.contains("mc:Ignorable=\"x14ac\""));
+    assert!(xml.contains("x14ac:dyDescent"));
+}
+
+#[test]
+fn repeated_number_formats_are_shared() {
+    let mut book = umya_spreadsheet::new_file();
+    let sheet = book.get_sheet_mut(&0).unwrap();
+    sheet
+        .get_style_mut("A1")
+        .get_number_format_mut()
+        .set_format_code("0.000\" pcs\"");
+    sheet
+        .get_style_mut("A2")
+        .get_number_format_mut()
+        .set_format_code("0.000\" pcs\"");
+    sheet.get_style_mut("A2").get_font_mut().set_bold(true);
+
+    let path = std::path::Path::new("./tests/result_files/repeated_number_formats.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let sheet = book.get_sheet(&0).unwrap();
+    let get_code = |coordinate: &str| {
+        sheet
+            .get_cell(coordinate)
+            .unwrap()
+            .get_style()
+            .get_number_format()
+            .unwrap()
+            .get_format_code()
+            .as_ptr()
+    };
+    // two cell formats, one format code allocation.
+    assert_eq!(get_code("A1"), get_code("A2"));
+}
+
+#[test]
+fn set_style_range() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    let mut style = umya_spreadsheet::Style::default();
+    style.get_font_mut().set_bold(true);
+    worksheet.set_style_range("A1:Z1000", &style);
+    worksheet.get_cell_mut("B2").set_value("bold");
+
+    let path = std::path::Path::new("./tests/result_files/set_style_range.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    let a1 = worksheet.get_cell("A1").unwrap().get_style_shared();
+    let z1000 = worksheet.get_cell("Z1000").unwrap().get_style_shared();
+    assert!(*a1.get_font().unwrap().get_bold());
+    // a single cell format is written for the whole range.
+    assert!(std::sync::Arc::ptr_eq(a1, z1000));
+    assert_eq!(worksheet.get_cell("B2").unwrap().get_value(), "bold");
+}
+
+#[test]
+fn set_hyperlink_url() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Sheet2").unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_hyperlink_url(
+        "https://example.com",
+        "Click here",
+        "Open example.com",
+    );
+    worksheet
+        .get_cell_mut("A2")
+        .set_hyperlink_location("'Sheet2'!A1", "Go to Sheet2", "");
+
+    let path = std::path::Path::new("./tests/result_files/set_hyperlink_url.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    let cell = worksheet.get_cell("A1").unwrap();
+    assert_eq!(cell.get_value(), "Click here");
+    let hyperlink = cell.get_hyperlink().unwrap();
+    assert_eq!(hyperlink.get_url(), "https://example.com");
+    assert_eq!(hyperlink.get_tooltip(), "Open example.com");
+    assert!(!*hyperlink.get_location());
+    let font = cell.get_style().get_font().unwrap();
+    assert_eq!(
+        font.get_underline(),
+        umya_spreadsheet::Font::UNDERLINE_SINGLE
+    );
+    assert_eq!(font.get_color().get_theme_index(), &10);
+
+    let cell = worksheet.get_cell("A2").unwrap();
+    assert_eq!(cell.get_value(), "Go to Sheet2");
+    let hyperlink = cell.get_hyperlink().unwrap();
+    assert_eq!(hyperlink.get_url(), "'Sheet2'!A1");
+    assert_eq!(hyperlink.get_tooltip(), "");
+    assert!(*hyperlink.get_location());
+
+    // the location is escaped once per write, not once more per round trip.
+    let path = std::path::Path::new("./tests/result_files/set_hyperlink_url_2.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let hyperlink = book
+        .get_sheet(&0)
+        .unwrap()
+        .get_cell("A2")
+        .unwrap()
+        .get_hyperlink()
+        .unwrap();
+    assert_eq!(hyperlink.get_url(), "'Sheet2'!A1");
+}
+
+#[test]
+fn sanitize_text() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value_string("=1+2");
+    worksheet
+        .get_cell_mut("A2")
+        .set_value_string("a\u{0B}b\u{01}");
+    worksheet.get_cell_mut("A3").set_value_string("plain");
+    worksheet.get_cell_mut("A4").set_formula("1+2");
+    worksheet.get_cell_mut("A5").set_value_string("-5");
+
+    let mut option = umya_spreadsheet::structs::CsvWriterOption::default();
+    option.set_do_sanitize(true);
+    let path = std::path::Path::new("./tests/result_files/sanitize_text.csv");
+    umya_spreadsheet::writer::csv::write(&book, path, Some(&option)).unwrap();
+    let csv = std::fs::read_to_string(path).unwrap();
+    assert!(csv.starts_with("'=1+2\r\n"));
+    assert!(!csv.contains('\u{0B}'));
+    assert!(csv.contains("\r\n-5\r\n"));
+
+    let mut option = umya_spreadsheet::structs::XlsxWriterOption::default();
+    option.set_is_text_sanitized(true);
+    let path = std::path::Path::new("./tests/result_files/sanitize_text.xlsx");
+    umya_spreadsheet::writer::xlsx::write_with_option(&book, path, &option).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_value("A1"), "=1+2");
+
+    let read_book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let read_sheet = read_book.get_sheet(&0).unwrap();
+    assert_eq!(read_sheet.get_value("A1"), "=1+2");
+    assert_eq!(read_sheet.get_value("A2"), "a\nb");
+    assert_eq!(read_sheet.get_value("A5"), "-5");
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_value("A2"), "a\u{0B}b\u{01}");
+
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert_eq!(worksheet.sanitize_text(), 1);
+    assert_eq!(worksheet.get_value("A1"), "=1+2");
+    assert_eq!(worksheet.get_value("A2"), "a\nb");
+    assert_eq!(worksheet.get_value("A3"), "plain");
+    assert_eq!(worksheet.get_cell("A4").unwrap().get_formula(), "1+2");
+    assert_eq!(worksheet.sanitize_text(), 0);
+}
+
+#[test]
+fn chrono_values() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let time = chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value_date_time(date);
+    worksheet
+        .get_cell_mut("A2")
+        .set_value_date_time(date.and_time(time));
+    worksheet.get_cell_mut("A3").set_value_date_time(time);
+    worksheet
+        .get_cell_mut("A4")
+        .set_value_date_time(chrono::Duration::hours(36));
+    worksheet.set_values("B1", &[[date, date.succ_opt().unwrap()]]);
+
+    assert_eq!(worksheet.get_value("A1"), "45322");
+    assert_eq!(worksheet.get_value("A2"), "45322.5");
+    assert_eq!(worksheet.get_value("A3"), "0.5");
+    assert_eq!(worksheet.get_value("A4"), "1.5");
+    assert_eq!(worksheet.get_value("C1"), "45323");
+    let format_code = |coordinate: &str| {
+        worksheet
+            .get_cell(coordinate)
+            .unwrap()
+            .get_style()
+            .get_number_format()
+            .unwrap()
+            .get_format_code()
+            .to_string()
+    };
+    assert_eq!(format_code("A1"), "yyyy-mm-dd");
+    assert_eq!(format_code("A2"), "yyyy-mm-dd hh:mm:ss");
+    assert_eq!(format_code("A3"), "h:mm:ss");
+    assert_eq!(format_code("A4"), "[h]:mm:ss");
+
+    let mut from_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    let mut to_marker = umya_spreadsheet::structs::drawing::spreadsheet::MarkerType::default();
+    from_marker.set_coordinate("D1");
+    to_marker.set_coordinate("J11");
+    let mut chart = umya_spreadsheet::structs::Chart::default();
+    chart.new_chart(
+        umya_spreadsheet::structs::ChartType::ScatterChart,
+        from_marker,
+        to_marker,
+        vec!["Sheet1!$A$1:$A$2", "Sheet1!$B$1:$C$1"],
+    );
+    chart
+        .set_value_axis_range(date, date.succ_opt().unwrap())
+        .set_value_axis_crosses_at(date);
+    book.get_sheet_mut(&0).unwrap().add_chart(chart);
+
+    let path = std::path::Path::new("./tests/result_files/chrono_values.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    let worksheet = book.get_sheet(&0).unwrap();
+    assert_eq!(worksheet.get_value("A2"), "45322.5");
+    let value_axis = &worksheet.get_chart_collection()[0]
+        .get_chart_space()
+        .get_chart()
+        .get_plot_area()
+        .get_value_axis()[0];
+    let scaling = value_axis.get_scaling();
+    assert_eq!(scaling.get_min_axis_value().unwrap().get_val(), &45322.0);
+    assert_eq!(scaling.get_max_axis_value().unwrap().get_val(), &45323.0);
+    assert_eq!(value_axis.get_crosses_at().unwrap().get_val(), &45322.0);
+}
+
+#[test]
+fn duplicate_sheet_with_suffix() {
+    let mut book = umya_spreadsheet::new_file();
+    book.set_sheet_name(0, "Report").unwrap();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value("Total");
+    worksheet.get_cell_mut("A2").set_formula("SUM(B1:B2)");
+
+    let worksheet = book.duplicate_sheet("Report").unwrap();
+    assert_eq!(worksheet.get_name(), "Report (2)");
+    worksheet.get_cell_mut("A1").set_value("Copy");
+    assert_eq!(
+        book.duplicate_sheet("Report").unwrap().get_name(),
+        "Report (3)"
+    );
+    assert_eq!(
+        book.duplicate_sheet("Report (2)").unwrap().get_name(),
+        "Report (4)"
+    );
+    assert!(book.duplicate_sheet("Missing").is_err());
+
+    let long_name = "A".repeat(31);
+    book.new_sheet(long_name.as_str()).unwrap();
+    let worksheet = book.duplicate_sheet(&long_name).unwrap();
+    assert_eq!(worksheet.get_name().chars().count(), 31);
+    assert!(worksheet.get_name().ends_with(" (2)"));
+
+    let path = std::path::Path::new("./tests/result_files/duplicate_sheet.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(book.get_sheet_count(), 6);
+    assert_eq!(book.get_sheet(&0).unwrap().get_value("A1"), "Total");
+    let worksheet = book.get_sheet_by_name("Report (2)").unwrap();
+    assert_eq!(worksheet.get_value("A1"), "Copy");
+    assert_eq!(
+        worksheet.get_cell("A2").unwrap().get_formula(),
+        "SUM(B1:B2)"
+    );
+}
+
+#[test]
+fn duplicate_sheet_with_table() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("A1").set_value("Name");
+    worksheet.get_cell_mut("B1").set_value("Amount");
+    let mut table = umya_spreadsheet::structs::Table::new("Table1", ("A1", "B3"));
+    table.add_column(umya_spreadsheet::structs::TableColumn::new("Name"));
+    table.add_column(umya_spreadsheet::structs::TableColumn::new("Amount"));
+    worksheet.add_table(table);
+
+    book.duplicate_sheet("Sheet1").unwrap();
+    book.duplicate_sheet("Sheet1").unwrap();
+    let get_table_names = |book: &umya_spreadsheet::Spreadsheet, name: &str| {
+        let table = &book.get_sheet_by_name(name).unwrap().get_tables()[0];
+        (
+            table.get_name().to_string(),
+            table.get_display_name().to_string(),
+        )
+    };
+    assert_eq!(
+        get_table_names(&book, "Sheet1 (2)"),
+        ("Table1_2".to_string(), "Table1_2".to_string())
+    );
+    assert_eq!(
+        get_table_names(&book, "Sheet1 (3)"),
+        ("Table1_3".to_string(), "Table1_3".to_string())
+    );
+
+    let path = std::path::Path::new("./tests/result_files/duplicate_sheet_with_table.xlsx");
+    let _ = umya_spreadsheet::writer::xlsx::write(&book, path);
+
+    let book = umya_spreadsheet::reader::xlsx::read(path).unwrap();
+    assert_eq!(get_table_names(&book, "Sheet1").0, "Table1");
+    assert_eq!(get_table_names(&book, "Sheet1 (2)").0, "Table1_2");
+    assert_eq!(get_table_names(&book, "Sheet1 (3)").0, "Table1_3");
+}
+
+#[test]
+fn column_stats() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    worksheet.get_cell_mut("C1").set_value("Amount");
+    worksheet.get_cell_mut("C2").set_value_number(10);
+    worksheet.get_cell_mut("C3").set_value_number(-2.5);
+    worksheet.get_cell_mut("C4").set_value("n/a");
+    worksheet.get_cell_mut("C6").set_value_number(10);
+    worksheet.get_cell_mut("D2").set_value_number(100);
+
+    let stats = worksheet.column_stats("C2:C500");
+    assert_eq!(stats.get_count(), &4);
+    assert_eq!(stats.get_numeric_count(), &3);
+    assert_eq!(stats.get_min(), Some(-2.5));
+    assert_eq!(stats.get_max(), Some(10.0));
+    assert_eq!(stats.get_sum(), &17.5);
+    assert_eq!(stats.get_mean(), Some(17.5 / 3.0));
+    assert_eq!(stats.get_distinct_values(), &vec!["10", "-2.5", "n/a"]);
+
+    let stats = worksheet.column_stats("C:C");
+    assert_eq!(stats.get_count(), &5);
+    assert_eq!(stats.get_distinct_values()[0], "Amount");
+
+    let stats = worksheet.column_stats("E1:E10");
+    assert_eq!(stats.get_count(), &0);
+    assert_eq!(stats.get_min(), None);
+    assert_eq!(stats.get_mean(), None);
+}
+
+#[test]
+fn get_filter_values() {
+    let mut book = umya_spreadsheet::new_file();
+    let worksheet = book.get_sheet_mut(&0).unwrap();
+    assert!(worksheet.get_filter_values("B").is_empty());
+
+    worksheet.get_cell_mut("A1").set_value("Name");
+    worksheet.get_cell_mut("B1").set_value("Team");
+    worksheet.get_cell_mut("C1").set_value("Score");
+    worksheet.get_cell_mut("A2").set_value("Ann");
+    worksheet.get_cell_mut("B2").set_value("red");
+    worksheet.get_cell_mut("C2").set_value_number(10);
+    worksheet.get_cell_mut("A3").set_value("Bob");
+    worksheet.get_cell_mut("B3").set_value("Blue");
+    worksheet.get_cell_mut("C3").set_value_number(2);
+    worksheet.get_cell_mut("A4").set_value("Cid");
+    worksheet.get_cell_mut("C4").set_value_number(10);
+    worksheet.get_cell_mut("A5").set_value("Dan");
+    worksheet.get_cell_mut("B5").set_value("red");
+    worksheet.get_cell_mut("C5").set_value("n/a");
+    worksheet.set_auto_filter("A1:C1");
+
+    assert_eq!(worksheet.get_filter_values("B"), vec!["Blue", "red", ""]);
+    assert_eq!(worksheet.get_filter_values("c"), vec!["2", "10", "n/a"]);
+    assert!(worksheet.get_filter_values("D").is_empty());
+
+    worksheet.set_auto_filter("A1:C3");
+    assert_eq!(worksheet.get_filter_values("B"), vec!["Blue", "red"]);
+}
+
+#[test]
+fn estimate_xlsx_size() {
+    let mut book = umya_spreadsheet::new_file();
+    book.new_sheet("Data").unwrap();
+    let worksheet = book.get_sheet_by_name_mut("Data").unwrap();
+    for row in 1..=200u32 {
+        worksheet
+            .get_cell_mut((1, row))
+            .set_value(format!("text {}", row));
+        worksheet.get_cell_mut((2, row)).set_value_number(row);
+    }
+
+    let estimate = umya_spreadsheet::writer::xlsx::estimate(&book).unwrap();
+    let sheet1 = *estimate.get_sheet("Sheet1").unwrap().get_size();
+    let data = *estimate.get_sheet("Data").unwrap().get_size();
+    assert!(data > sheet1);
+    assert_eq!(
+        estimate
+            .get_part("xl/worksheets/sheet2.xml")
+            .unwrap()
+            .get_size(),
+        &data
+    );
+    assert!(estimate.get_sheet("Missing").is_none());
+    assert!(estimate.get_shared_strings_size() > 0);
+    assert!(estimate.get_styles_size() > 0);
+    assert_eq!(estimate.get_media_size(), 0);
+    assert!(estimate.get_total_size() > *estimate.get_file_size());
+    assert_eq!(
+        umya_spreadsheet::writer::xlsx::estimate(&book)
+            .unwrap()
+            .get_file_size(),
+        estimate.get_file_size()
+    );
+
+    let mut buffer: Vec<u8> = Vec::new();
+    umya_spreadsheet::writer::xlsx::write_writer(&book, &mut buffer).unwrap();
+    assert_eq!(estimate.get_file_size(), &(buffer.len() as u64));
+}